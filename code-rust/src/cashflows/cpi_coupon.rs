@@ -0,0 +1,249 @@
+use crate::indexes::inflation_index::{
+    CpiInterpolationType, ZeroInflationIndex, cpi_lagged_fixing,
+};
+use crate::termstructures::inflation::zero_inflation_term_structure::ZeroInflationTermStructure;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::time::period::Period;
+use crate::types::{Rate, Real, Time};
+
+/*
+Coupon paying a fixed rate on a notional indexed to CPI (QuantLib
+CPICoupon): nominal * fixed_rate * accrual * I(end) / base_cpi, where
+I(end) is the CPI observed at the accrual end date with the
+observation lag. The coupon is paid at the accrual end date.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CpiCoupon {
+    nominal: Real,
+    fixed_rate: Rate,
+    base_cpi: Real,
+    accrual_start_date: Date,
+    accrual_end_date: Date,
+    accrual_period: Time,
+    observation_lag: Period,
+    interpolation: CpiInterpolationType,
+}
+impl CpiCoupon {
+    // Constructor
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        nominal: Real,
+        fixed_rate: Rate,
+        base_cpi: Real,
+        accrual_start_date: Date,
+        accrual_end_date: Date,
+        day_counter: &dyn DayCounter,
+        observation_lag: Period,
+        interpolation: CpiInterpolationType,
+    ) -> CpiCoupon {
+        if base_cpi <= 0.0 {
+            panic!("base CPI ({base_cpi}) must be positive");
+        }
+        if accrual_end_date <= accrual_start_date {
+            panic!(
+                "accrual end date ({accrual_end_date}) must be after the start date ({accrual_start_date})"
+            );
+        }
+        CpiCoupon {
+            nominal,
+            fixed_rate,
+            base_cpi,
+            accrual_start_date,
+            accrual_end_date,
+            accrual_period: day_counter.year_fraction(accrual_start_date, accrual_end_date),
+            observation_lag,
+            interpolation,
+        }
+    }
+
+    // Inspectors
+    pub fn nominal(&self) -> Real {
+        self.nominal
+    }
+    pub fn fixed_rate(&self) -> Rate {
+        self.fixed_rate
+    }
+    pub fn base_cpi(&self) -> Real {
+        self.base_cpi
+    }
+    pub fn accrual_start_date(&self) -> Date {
+        self.accrual_start_date
+    }
+    pub fn accrual_end_date(&self) -> Date {
+        self.accrual_end_date
+    }
+    pub fn accrual_period(&self) -> Time {
+        self.accrual_period
+    }
+    // Payment date
+    pub fn date(&self) -> Date {
+        self.accrual_end_date
+    }
+
+    pub fn index_fixing(
+        &self,
+        index: &ZeroInflationIndex,
+        forecast: Option<&dyn ZeroInflationTermStructure>,
+    ) -> Real {
+        cpi_lagged_fixing(
+            index,
+            self.accrual_end_date,
+            self.observation_lag,
+            self.interpolation,
+            forecast,
+        )
+    }
+    pub fn amount(
+        &self,
+        index: &ZeroInflationIndex,
+        forecast: Option<&dyn ZeroInflationTermStructure>,
+    ) -> Real {
+        self.nominal * self.fixed_rate * self.accrual_period * self.index_fixing(index, forecast)
+            / self.base_cpi
+    }
+}
+
+/*
+Notional indexed to CPI (QuantLib CPICashFlow): notional * I(date) /
+base_cpi, or only its growth notional * (I(date) / base_cpi - 1).
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CpiCashFlow {
+    notional: Real,
+    base_cpi: Real,
+    date: Date,
+    observation_lag: Period,
+    interpolation: CpiInterpolationType,
+    growth_only: bool,
+}
+impl CpiCashFlow {
+    // Constructor
+    pub fn new(
+        notional: Real,
+        base_cpi: Real,
+        date: Date,
+        observation_lag: Period,
+        interpolation: CpiInterpolationType,
+        growth_only: bool,
+    ) -> CpiCashFlow {
+        if base_cpi <= 0.0 {
+            panic!("base CPI ({base_cpi}) must be positive");
+        }
+        CpiCashFlow {
+            notional,
+            base_cpi,
+            date,
+            observation_lag,
+            interpolation,
+            growth_only,
+        }
+    }
+
+    // Inspectors
+    pub fn notional(&self) -> Real {
+        self.notional
+    }
+    pub fn base_cpi(&self) -> Real {
+        self.base_cpi
+    }
+    pub fn date(&self) -> Date {
+        self.date
+    }
+    pub fn growth_only(&self) -> bool {
+        self.growth_only
+    }
+
+    pub fn amount(
+        &self,
+        index: &ZeroInflationIndex,
+        forecast: Option<&dyn ZeroInflationTermStructure>,
+    ) -> Real {
+        let ratio: Real = cpi_lagged_fixing(
+            index,
+            self.date,
+            self.observation_lag,
+            self.interpolation,
+            forecast,
+        ) / self.base_cpi;
+        if self.growth_only {
+            self.notional * (ratio - 1.0)
+        } else {
+            self.notional * ratio
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::frequency::Frequency;
+    use crate::time::time_unit::TimeUnit;
+
+    fn index() -> ZeroInflationIndex {
+        let mut index: ZeroInflationIndex =
+            ZeroInflationIndex::new("CPI", Frequency::Monthly, Period::new(1, TimeUnit::Months));
+        index.add_fixing(Date::new(1, Month::March, 2025), 102.0);
+        index.add_fixing(Date::new(1, Month::April, 2025), 103.0);
+        index
+    }
+
+    #[test]
+    fn amounts_are_indexed_to_the_lagged_cpi() {
+        let lag: Period = Period::new(3, TimeUnit::Months);
+        let coupon: CpiCoupon = CpiCoupon::new(
+            1000.0,
+            0.01,
+            100.0,
+            Date::new(1, Month::June, 2024),
+            Date::new(1, Month::June, 2025),
+            &Actual365Fixed::new(),
+            lag,
+            CpiInterpolationType::Flat,
+        );
+        assert_eq!(coupon.date(), Date::new(1, Month::June, 2025));
+        assert_eq!(coupon.index_fixing(&index(), None), 102.0);
+        assert!((coupon.amount(&index(), None) - 1000.0 * 0.01 * 1.02).abs() < 1.0e-12);
+
+        let cases: [(bool, Real); 2] = [(false, 1025.0), (true, 25.0)];
+        for (growth_only, expected) in cases {
+            let cash_flow: CpiCashFlow = CpiCashFlow::new(
+                1000.0,
+                100.0,
+                Date::new(16, Month::June, 2025),
+                lag,
+                CpiInterpolationType::Linear,
+                growth_only,
+            );
+            assert!((cash_flow.amount(&index(), None) - expected).abs() < 1.0e-12);
+        }
+    }
+
+    #[test]
+    fn invalid_cash_flows_panic() {
+        let date: Date = Date::new(1, Month::June, 2025);
+        let lag: Period = Period::new(3, TimeUnit::Months);
+        let result = std::panic::catch_unwind(|| {
+            CpiCashFlow::new(1000.0, 0.0, date, lag, CpiInterpolationType::Flat, false)
+        });
+        assert!(result.is_err(), "expected panic for a null base CPI");
+        let result = std::panic::catch_unwind(|| {
+            CpiCoupon::new(
+                1000.0,
+                0.01,
+                100.0,
+                date,
+                date,
+                &Actual365Fixed::new(),
+                lag,
+                CpiInterpolationType::Flat,
+            )
+        });
+        assert!(
+            result.is_err(),
+            "expected panic for an empty accrual period"
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use crate::time::date::Date;
+use crate::types::{Rate, Real, Size};
+
+/*
+Discrete dividend paid by a stock, keyed by its ex-dividend date
+(QuantLib Dividend, FixedDividend and FractionalDividend):
+    - Fixed: a cash amount
+    - Fractional: a fraction of the underlying price on the ex-dividend
+      date, so its amount depends on the underlying
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Dividend {
+    Fixed { date: Date, amount: Real },
+    Fractional { date: Date, rate: Rate },
+}
+impl Dividend {
+    // Constructors
+    pub fn fixed(date: Date, amount: Real) -> Dividend {
+        if amount < 0.0 {
+            panic!("negative dividend amount ({amount}) not allowed");
+        }
+        Dividend::Fixed { date, amount }
+    }
+    pub fn fractional(date: Date, rate: Rate) -> Dividend {
+        if !(0.0..1.0).contains(&rate) {
+            panic!("dividend rate ({rate}) must be in [0, 1)");
+        }
+        Dividend::Fractional { date, rate }
+    }
+
+    // Inspectors
+    pub fn date(&self) -> Date {
+        match self {
+            Dividend::Fixed { date, .. } | Dividend::Fractional { date, .. } => *date,
+        }
+    }
+    // Amount paid when the underlying is at `underlying` on the ex-dividend date
+    pub fn amount(&self, underlying: Real) -> Real {
+        match self {
+            Dividend::Fixed { amount, .. } => *amount,
+            Dividend::Fractional { rate, .. } => rate * underlying,
+        }
+    }
+}
+
+/*
+Dividends of a stock sorted by ex-dividend date (QuantLib
+DividendSchedule). from_cash_amounts builds a schedule of fixed
+dividends from parallel dates and amounts, like QuantLib DividendVector.
+*/
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct DividendSchedule {
+    dividends: Vec<Dividend>,
+}
+impl DividendSchedule {
+    // Constructors
+    pub fn new(dividends: &[Dividend]) -> DividendSchedule {
+        let mut dividends: Vec<Dividend> = dividends.to_vec();
+        dividends.sort_by_key(|d| d.date());
+        DividendSchedule { dividends }
+    }
+    pub fn from_cash_amounts(dates: &[Date], amounts: &[Real]) -> DividendSchedule {
+        if dates.len() != amounts.len() {
+            panic!(
+                "different number of dividend dates ({}) and amounts ({})",
+                dates.len(),
+                amounts.len()
+            );
+        }
+        let dividends: Vec<Dividend> = dates
+            .iter()
+            .zip(amounts.iter())
+            .map(|(date, amount)| Dividend::fixed(*date, *amount))
+            .collect();
+        DividendSchedule::new(&dividends)
+    }
+
+    // Inspectors
+    pub fn dividends(&self) -> &[Dividend] {
+        &self.dividends
+    }
+    pub fn len(&self) -> Size {
+        self.dividends.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.dividends.is_empty()
+    }
+    // Dividends going ex strictly after `from` and no later than `to`
+    pub fn between(&self, from: Date, to: Date) -> impl Iterator<Item = &Dividend> {
+        self.dividends
+            .iter()
+            .filter(move |d| d.date() > from && d.date() <= to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+    use std::panic;
+
+    #[test]
+    fn amounts_of_fixed_and_fractional_dividends() {
+        let date: Date = Date::new(15, Month::March, 2025);
+        let fixed: Dividend = Dividend::fixed(date, 2.5);
+        let fractional: Dividend = Dividend::fractional(date, 0.02);
+
+        assert_eq!(fixed.date(), date);
+        assert_eq!(fixed.amount(80.0), 2.5);
+        assert_eq!(fractional.amount(80.0), 1.6);
+    }
+
+    #[test]
+    fn schedule_is_sorted_by_date() {
+        let dates: [Date; 3] = [
+            Date::new(15, Month::September, 2025),
+            Date::new(15, Month::March, 2025),
+            Date::new(15, Month::June, 2025),
+        ];
+        let schedule: DividendSchedule =
+            DividendSchedule::from_cash_amounts(&dates, &[3.0, 1.0, 2.0]);
+
+        assert_eq!(schedule.len(), 3);
+        let amounts: Vec<Real> = schedule.dividends().iter().map(|d| d.amount(0.0)).collect();
+        assert_eq!(amounts, vec![1.0, 2.0, 3.0]);
+        let between: Vec<Date> = schedule
+            .between(dates[1], dates[0])
+            .map(|d| d.date())
+            .collect();
+        assert_eq!(between, vec![dates[2], dates[0]]);
+        assert!(DividendSchedule::default().is_empty());
+    }
+
+    #[test]
+    fn invalid_dividends_panic() {
+        let date: Date = Date::new(15, Month::March, 2025);
+        assert!(panic::catch_unwind(|| Dividend::fixed(date, -1.0)).is_err());
+        assert!(panic::catch_unwind(|| Dividend::fractional(date, 1.0)).is_err());
+        assert!(panic::catch_unwind(|| DividendSchedule::from_cash_amounts(&[date], &[])).is_err());
+    }
+}
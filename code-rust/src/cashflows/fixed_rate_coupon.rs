@@ -0,0 +1,107 @@
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Rate, Real, Time};
+
+/*
+Coupon paying a fixed rate on its nominal over the accrual period
+(QuantLib FixedRateCoupon), paid on the accrual end date. Interest
+accrues with the day counter the coupon was built with, which must be
+given again to accrued_amount.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct FixedRateCoupon {
+    nominal: Real,
+    rate: Rate,
+    accrual_start_date: Date,
+    accrual_end_date: Date,
+    accrual_period: Time,
+}
+impl FixedRateCoupon {
+    // Constructor
+    pub fn new(
+        nominal: Real,
+        rate: Rate,
+        accrual_start_date: Date,
+        accrual_end_date: Date,
+        day_counter: &dyn DayCounter,
+    ) -> FixedRateCoupon {
+        if accrual_end_date <= accrual_start_date {
+            panic!(
+                "accrual end date ({accrual_end_date}) must be after the start date ({accrual_start_date})"
+            );
+        }
+        FixedRateCoupon {
+            nominal,
+            rate,
+            accrual_start_date,
+            accrual_end_date,
+            accrual_period: day_counter.year_fraction(accrual_start_date, accrual_end_date),
+        }
+    }
+
+    // Inspectors
+    pub fn nominal(&self) -> Real {
+        self.nominal
+    }
+    pub fn rate(&self) -> Rate {
+        self.rate
+    }
+    pub fn accrual_start_date(&self) -> Date {
+        self.accrual_start_date
+    }
+    pub fn accrual_end_date(&self) -> Date {
+        self.accrual_end_date
+    }
+    pub fn accrual_period(&self) -> Time {
+        self.accrual_period
+    }
+    // Payment date
+    pub fn date(&self) -> Date {
+        self.accrual_end_date
+    }
+
+    pub fn amount(&self) -> Real {
+        self.nominal * self.rate * self.accrual_period
+    }
+    // Interest accrued from the accrual start to date, none outside the accrual period
+    pub fn accrued_amount(&self, date: Date, day_counter: &dyn DayCounter) -> Real {
+        if date <= self.accrual_start_date || date >= self.accrual_end_date {
+            return 0.0;
+        }
+        self.nominal * self.rate * day_counter.year_fraction(self.accrual_start_date, date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual360::Actual360;
+
+    #[test]
+    fn amount_and_accrued_interest() {
+        let start: Date = Date::new(15, Month::January, 2025);
+        let coupon: FixedRateCoupon =
+            FixedRateCoupon::new(100.0, 0.036, start, start + 180, &Actual360::new());
+        assert_eq!(coupon.date(), start + 180);
+        assert_eq!(coupon.accrual_period(), 0.5);
+        assert!((coupon.amount() - 1.8).abs() < 1.0e-12);
+        let cases: [(Date, Real); 4] = [
+            (start - 1, 0.0),
+            (start, 0.0),
+            (start + 90, 0.9),
+            (start + 180, 0.0),
+        ];
+        for (date, expected) in cases {
+            let accrued: Real = coupon.accrued_amount(date, &Actual360::new());
+            assert!((accrued - expected).abs() < 1.0e-12, "{date}: {accrued}");
+        }
+        let result = std::panic::catch_unwind(|| {
+            FixedRateCoupon::new(100.0, 0.036, start, start, &Actual360::new())
+        });
+        assert!(
+            result.is_err(),
+            "expected panic for an empty accrual period"
+        );
+    }
+}
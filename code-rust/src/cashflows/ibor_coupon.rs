@@ -0,0 +1,224 @@
+use crate::indexes::ibor_index::IborIndex;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Rate, Real, Time};
+
+/*
+Coupon paying an Ibor rate over its accrual period, paid on the accrual
+end date (QuantLib IborCoupon).
+
+The rate fixes on the accrual start date unless a fixing date is set,
+as IborLeg does from the fixing days of its index. A coupon fixed
+before the reference date of the forecast curve pays the stored fixing
+of the index, and so does one fixing on it if the fixing is known;
+otherwise the rate is the simple forward of the curve over the accrual
+period, as for QuantLib par coupons.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct IborCoupon {
+    nominal: Real,
+    accrual_start_date: Date,
+    accrual_end_date: Date,
+    accrual_period: Time,
+    fixing_date: Date,
+}
+impl IborCoupon {
+    // Constructor
+    pub fn new(
+        nominal: Real,
+        accrual_start_date: Date,
+        accrual_end_date: Date,
+        day_counter: &dyn DayCounter,
+    ) -> IborCoupon {
+        if accrual_end_date <= accrual_start_date {
+            panic!(
+                "accrual end date ({accrual_end_date}) must be after the start date ({accrual_start_date})"
+            );
+        }
+        IborCoupon {
+            nominal,
+            accrual_start_date,
+            accrual_end_date,
+            accrual_period: day_counter.year_fraction(accrual_start_date, accrual_end_date),
+            fixing_date: accrual_start_date,
+        }
+    }
+
+    // Modifiers
+    pub fn with_fixing_date(mut self, fixing_date: Date) -> IborCoupon {
+        self.fixing_date = fixing_date;
+        self
+    }
+
+    // Inspectors
+    pub fn nominal(&self) -> Real {
+        self.nominal
+    }
+    pub fn accrual_start_date(&self) -> Date {
+        self.accrual_start_date
+    }
+    pub fn accrual_end_date(&self) -> Date {
+        self.accrual_end_date
+    }
+    pub fn accrual_period(&self) -> Time {
+        self.accrual_period
+    }
+    // Payment date
+    pub fn date(&self) -> Date {
+        self.accrual_end_date
+    }
+    pub fn fixing_date(&self) -> Date {
+        self.fixing_date
+    }
+
+    pub fn rate(&self, index: &IborIndex, forecast_curve: &dyn YieldTermStructure) -> Rate {
+        let reference_date: Date = forecast_curve.reference_date();
+        if self.fixing_date < reference_date {
+            return index.fixing(self.fixing_date, None);
+        }
+        if self.fixing_date == reference_date
+            && let Some(fixing) = index.past_fixing(self.fixing_date)
+        {
+            return fixing;
+        }
+        self.forward_rate(forecast_curve)
+    }
+
+    // Simple forward of the curve over the accrual period
+    pub fn forward_rate(&self, forecast_curve: &dyn YieldTermStructure) -> Rate {
+        (forecast_curve.discount_date(self.accrual_start_date)
+            / forecast_curve.discount_date(self.accrual_end_date)
+            - 1.0)
+            / self.accrual_period
+    }
+}
+
+/*
+Builder of a leg of Ibor coupons over a schedule (QuantLib IborLeg):
+one coupon per pair of consecutive schedule dates. Notionals are
+required; when fewer notionals than coupons are given the last one is
+repeated. Coupons fix on their accrual start dates unless an index is
+given, in which case they fix its fixing days before.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct IborLeg {
+    schedule: Vec<Date>,
+    notionals: Vec<Real>,
+    fixing_dates: Vec<Date>,
+}
+impl IborLeg {
+    // Constructor
+    pub fn new(schedule: &[Date]) -> IborLeg {
+        if schedule.len() < 2 {
+            panic!("at least two schedule dates required");
+        }
+        IborLeg {
+            schedule: schedule.to_vec(),
+            notionals: Vec::new(),
+            fixing_dates: schedule[..schedule.len() - 1].to_vec(),
+        }
+    }
+
+    // Modifiers
+    pub fn with_notionals(mut self, notionals: &[Real]) -> IborLeg {
+        self.notionals = notionals.to_vec();
+        self
+    }
+    pub fn with_index(mut self, index: &IborIndex) -> IborLeg {
+        self.fixing_dates = self.schedule[..self.schedule.len() - 1]
+            .iter()
+            .map(|date| index.fixing_date(*date))
+            .collect();
+        self
+    }
+
+    pub fn coupons(&self, payment_day_counter: &dyn DayCounter) -> Vec<IborCoupon> {
+        if self.notionals.is_empty() {
+            panic!("no notional given");
+        }
+        self.schedule
+            .windows(2)
+            .enumerate()
+            .map(|(i, period)| {
+                let nominal: Real = self.notionals[i.min(self.notionals.len() - 1)];
+                IborCoupon::new(nominal, period[0], period[1], payment_day_counter)
+                    .with_fixing_date(self.fixing_dates[i])
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual360::Actual360;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::period::Period;
+    use crate::time::time_unit::TimeUnit;
+    use std::rc::Rc;
+
+    #[test]
+    fn leg_has_one_coupon_per_period() {
+        let schedule: [Date; 4] = [
+            Date::new(15, Month::January, 2025),
+            Date::new(15, Month::July, 2025),
+            Date::new(15, Month::January, 2026),
+            Date::new(15, Month::July, 2026),
+        ];
+        let coupons: Vec<IborCoupon> = IborLeg::new(&schedule)
+            .with_notionals(&[100.0, 50.0])
+            .coupons(&Actual365Fixed::new());
+        assert_eq!(coupons.len(), 3);
+        let nominals: Vec<Real> = coupons.iter().map(|c| c.nominal()).collect();
+        assert_eq!(nominals, vec![100.0, 50.0, 50.0]);
+        assert_eq!(coupons[1].fixing_date(), schedule[1]);
+        assert_eq!(coupons[1].date(), schedule[2]);
+        assert_eq!(coupons[1].accrual_period(), 184.0 / 365.0);
+    }
+
+    #[test]
+    fn leg_panics_on_invalid_input() {
+        let date: Date = Date::new(15, Month::January, 2025);
+        let result = std::panic::catch_unwind(|| IborLeg::new(&[date]));
+        assert!(result.is_err(), "expected panic for a single date");
+        let result = std::panic::catch_unwind(|| {
+            IborLeg::new(&[date, date + 180]).coupons(&Actual365Fixed::new())
+        });
+        assert!(result.is_err(), "expected panic for missing notionals");
+    }
+
+    #[test]
+    fn coupons_pay_past_fixings_and_forecast_future_ones() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let schedule: [Date; 3] = [
+            Date::new(6, Month::January, 2025),
+            Date::new(7, Month::July, 2025),
+            Date::new(6, Month::January, 2026),
+        ];
+        let mut index: IborIndex = IborIndex::euribor(Period::new(6, TimeUnit::Months));
+        let coupons: Vec<IborCoupon> = IborLeg::new(&schedule)
+            .with_notionals(&[1.0])
+            .with_index(&index)
+            .coupons(&Actual360::new());
+        // Two TARGET business days before the accrual start
+        assert_eq!(coupons[0].fixing_date(), Date::new(2, Month::January, 2025));
+        assert_eq!(coupons[1].fixing_date(), Date::new(3, Month::July, 2025));
+
+        let curve: Rc<dyn YieldTermStructure> = flat_rate(reference_date, 0.03);
+        let forward: Rate = ((0.03 * 183.0 / 365.0_f64).exp() - 1.0) * 360.0 / 183.0;
+        assert!((coupons[1].rate(&index, curve.as_ref()) - forward).abs() < 1.0e-14);
+        index.add_fixing(coupons[0].fixing_date(), 0.027);
+        assert_eq!(coupons[0].rate(&index, curve.as_ref()), 0.027);
+
+        let result = std::panic::catch_unwind(|| {
+            let index: IborIndex = IborIndex::euribor(Period::new(6, TimeUnit::Months));
+            let coupon: IborCoupon =
+                IborCoupon::new(1.0, schedule[0], schedule[1], &Actual360::new());
+            coupon.rate(&index, flat_rate(reference_date, 0.03).as_ref())
+        });
+        assert!(result.is_err(), "expected panic for a missing past fixing");
+    }
+}
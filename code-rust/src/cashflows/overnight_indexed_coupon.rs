@@ -0,0 +1,150 @@
+use crate::indexes::ibor_index::OvernightIndex;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::business_day_convention::BusinessDayConvention;
+use crate::time::calendar::Calendar;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::time::time_unit::TimeUnit;
+use crate::types::{DiscountFactor, Rate, Real, Time};
+
+/*
+Coupon paying the overnight rate compounded daily over its accrual
+period (QuantLib OvernightIndexedCoupon), paid on the accrual end date.
+
+Each business day d of the index calendar in the accrual period fixes
+f_d for the days up to the next one, and the coupon rate is
+    (prod_d (1 + f_d delta_d) - 1) / tau.
+Fixings before the reference date of the forecast curve are the stored
+ones of the index, as is the fixing on it if known. Compounding the
+overnight forwards of the curve telescopes, so the remaining days
+contribute P(d) / P(end) from the first fixing not known.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct OvernightIndexedCoupon {
+    nominal: Real,
+    accrual_start_date: Date,
+    accrual_end_date: Date,
+    accrual_period: Time,
+}
+impl OvernightIndexedCoupon {
+    // Constructor
+    pub fn new(
+        nominal: Real,
+        accrual_start_date: Date,
+        accrual_end_date: Date,
+        day_counter: &dyn DayCounter,
+    ) -> OvernightIndexedCoupon {
+        if accrual_end_date <= accrual_start_date {
+            panic!(
+                "accrual end date ({accrual_end_date}) must be after the start date ({accrual_start_date})"
+            );
+        }
+        OvernightIndexedCoupon {
+            nominal,
+            accrual_start_date,
+            accrual_end_date,
+            accrual_period: day_counter.year_fraction(accrual_start_date, accrual_end_date),
+        }
+    }
+
+    // Inspectors
+    pub fn nominal(&self) -> Real {
+        self.nominal
+    }
+    pub fn accrual_start_date(&self) -> Date {
+        self.accrual_start_date
+    }
+    pub fn accrual_end_date(&self) -> Date {
+        self.accrual_end_date
+    }
+    pub fn accrual_period(&self) -> Time {
+        self.accrual_period
+    }
+    // Payment date
+    pub fn date(&self) -> Date {
+        self.accrual_end_date
+    }
+
+    pub fn rate(&self, index: &OvernightIndex, forecast_curve: &dyn YieldTermStructure) -> Rate {
+        let calendar: &dyn Calendar = index.fixing_calendar();
+        let reference_date: Date = forecast_curve.reference_date();
+        let end: Date = self.accrual_end_date;
+        let mut compound: Real = 1.0;
+        let mut date: Date =
+            calendar.adjust(self.accrual_start_date, BusinessDayConvention::Following);
+        while date < end {
+            let fixing: Option<Rate> = if date < reference_date {
+                Some(index.fixing(date, None))
+            } else if date == reference_date {
+                index.past_fixing(date)
+            } else {
+                None
+            };
+            let Some(fixing) = fixing else {
+                let discount: DiscountFactor =
+                    forecast_curve.discount_date(date) / forecast_curve.discount_date(end);
+                compound *= discount;
+                break;
+            };
+            let next: Date = calendar
+                .advance(
+                    date,
+                    1,
+                    TimeUnit::Days,
+                    BusinessDayConvention::Following,
+                    false,
+                )
+                .min(end);
+            compound *= 1.0 + fixing * index.day_counter().year_fraction(date, next);
+            date = next;
+        }
+        (compound - 1.0) / self.accrual_period
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual360::Actual360;
+    use std::rc::Rc;
+
+    #[test]
+    fn seasoned_coupons_compound_past_fixings_with_the_forecast() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let start: Date = Date::new(6, Month::January, 2025);
+        let end: Date = Date::new(6, Month::February, 2025);
+        let coupon: OvernightIndexedCoupon =
+            OvernightIndexedCoupon::new(1.0, start, end, &Actual360::new());
+        let curve: Rc<dyn YieldTermStructure> = flat_rate(reference_date, 0.03);
+        let mut index: OvernightIndex = OvernightIndex::estr();
+        // Seven business days, the Friday fixing accruing over the weekend
+        let mut date: Date = start;
+        while date < reference_date {
+            if index.fixing_calendar().is_business_day(date) {
+                index.add_fixing(date, 0.02);
+            }
+            date += 1;
+        }
+        let past: Real =
+            (1.0 + 0.02 / 360.0_f64).powi(5) * (1.0 + 0.02 * 3.0 / 360.0) * (1.0 + 0.02 / 360.0);
+        let forward: Real = (0.03 * 22.0 / 365.0_f64).exp();
+        let expected: Rate = (past * forward - 1.0) * 360.0 / 31.0;
+        assert!((coupon.rate(&index, curve.as_ref()) - expected).abs() < 1.0e-14);
+
+        // A fixing known on the reference date replaces its forecast
+        index.add_fixing(reference_date, 0.04);
+        let forward: Real = (1.0 + 0.04 / 360.0) * (0.03 * 21.0 / 365.0_f64).exp();
+        let expected: Rate = (past * forward - 1.0) * 360.0 / 31.0;
+        assert!((coupon.rate(&index, curve.as_ref()) - expected).abs() < 1.0e-14);
+
+        let result = std::panic::catch_unwind(|| {
+            OvernightIndexedCoupon::new(1.0, start, end, &Actual360::new()).rate(
+                &OvernightIndex::estr(),
+                flat_rate(reference_date, 0.03).as_ref(),
+            )
+        });
+        assert!(result.is_err(), "expected panic for missing past fixings");
+    }
+}
@@ -0,0 +1,47 @@
+use crate::currency::Currency;
+use crate::math::rounding::Rounding;
+
+// American currencies (QuantLib currencies/america.hpp)
+
+pub const BRL: Currency = Currency::new(
+    "Brazilian real",
+    "BRL",
+    986,
+    "R$",
+    "",
+    100,
+    Rounding::none(),
+    None,
+);
+pub const CAD: Currency = Currency::new(
+    "Canadian dollar",
+    "CAD",
+    124,
+    "Can$",
+    "",
+    100,
+    Rounding::none(),
+    None,
+);
+pub const MXN: Currency = Currency::new(
+    "Mexican peso",
+    "MXN",
+    484,
+    "Mex$",
+    "",
+    100,
+    Rounding::none(),
+    None,
+);
+pub const USD: Currency = Currency::new(
+    "U.S. dollar",
+    "USD",
+    840,
+    "$",
+    "\u{A2}",
+    100,
+    Rounding::none(),
+    None,
+);
+
+pub const CURRENCIES: [Currency; 4] = [BRL, CAD, MXN, USD];
@@ -0,0 +1,67 @@
+use crate::currency::Currency;
+use crate::math::rounding::Rounding;
+
+// Asian currencies (QuantLib currencies/asia.hpp)
+
+pub const CNY: Currency = Currency::new(
+    "Chinese yuan",
+    "CNY",
+    156,
+    "Y",
+    "",
+    100,
+    Rounding::none(),
+    None,
+);
+pub const HKD: Currency = Currency::new(
+    "Hong Kong dollar",
+    "HKD",
+    344,
+    "HK$",
+    "",
+    100,
+    Rounding::none(),
+    None,
+);
+pub const INR: Currency = Currency::new(
+    "Indian rupee",
+    "INR",
+    356,
+    "Rs",
+    "",
+    100,
+    Rounding::none(),
+    None,
+);
+pub const JPY: Currency = Currency::new(
+    "Japanese yen",
+    "JPY",
+    392,
+    "\u{A5}",
+    "",
+    100,
+    Rounding::none(),
+    None,
+);
+pub const KRW: Currency = Currency::new(
+    "South-Korean won",
+    "KRW",
+    410,
+    "W",
+    "",
+    100,
+    Rounding::none(),
+    None,
+);
+pub const SGD: Currency = Currency::new(
+    "Singapore dollar",
+    "SGD",
+    702,
+    "S$",
+    "",
+    100,
+    Rounding::none(),
+    None,
+);
+
+pub const CURRENCIES: [Currency; 6] = [CNY, HKD, INR, JPY, KRW, SGD];
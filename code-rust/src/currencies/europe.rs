@@ -0,0 +1,145 @@
+use crate::currency::Currency;
+use crate::math::rounding::Rounding;
+
+/*
+European currencies (QuantLib currencies/europe.hpp), including the
+legacy currencies replaced by the euro: conversions between them go
+through EUR at the fixed rates of ExchangeRateManager.
+*/
+
+pub const CHF: Currency = Currency::new(
+    "Swiss franc",
+    "CHF",
+    756,
+    "SwF",
+    "",
+    100,
+    Rounding::none(),
+    None,
+);
+pub const DKK: Currency = Currency::new(
+    "Danish krone",
+    "DKK",
+    208,
+    "Dkr",
+    "",
+    100,
+    Rounding::none(),
+    None,
+);
+pub const EUR: Currency = Currency::new(
+    "European Euro",
+    "EUR",
+    978,
+    "",
+    "",
+    100,
+    Rounding::closest(2),
+    None,
+);
+pub const GBP: Currency = Currency::new(
+    "British pound sterling",
+    "GBP",
+    826,
+    "\u{A3}",
+    "p",
+    100,
+    Rounding::none(),
+    None,
+);
+pub const NOK: Currency = Currency::new(
+    "Norwegian krone",
+    "NOK",
+    578,
+    "NKr",
+    "",
+    100,
+    Rounding::none(),
+    None,
+);
+pub const PLN: Currency = Currency::new(
+    "Polish zloty",
+    "PLN",
+    985,
+    "zl",
+    "",
+    100,
+    Rounding::none(),
+    None,
+);
+pub const SEK: Currency = Currency::new(
+    "Swedish krona",
+    "SEK",
+    752,
+    "kr",
+    "",
+    100,
+    Rounding::none(),
+    None,
+);
+
+// Legacy currencies
+pub const BEF: Currency = Currency::new(
+    "Belgian franc",
+    "BEF",
+    56,
+    "",
+    "",
+    1,
+    Rounding::none(),
+    Some("EUR"),
+);
+pub const DEM: Currency = Currency::new(
+    "Deutsche mark",
+    "DEM",
+    276,
+    "DM",
+    "",
+    100,
+    Rounding::none(),
+    Some("EUR"),
+);
+pub const ESP: Currency = Currency::new(
+    "Spanish peseta",
+    "ESP",
+    724,
+    "Pta",
+    "",
+    100,
+    Rounding::none(),
+    Some("EUR"),
+);
+pub const FRF: Currency = Currency::new(
+    "French franc",
+    "FRF",
+    250,
+    "",
+    "",
+    100,
+    Rounding::none(),
+    Some("EUR"),
+);
+pub const ITL: Currency = Currency::new(
+    "Italian lira",
+    "ITL",
+    380,
+    "L",
+    "",
+    1,
+    Rounding::none(),
+    Some("EUR"),
+);
+pub const NLG: Currency = Currency::new(
+    "Dutch guilder",
+    "NLG",
+    528,
+    "f",
+    "",
+    100,
+    Rounding::none(),
+    Some("EUR"),
+);
+
+pub const CURRENCIES: [Currency; 13] = [
+    CHF, DKK, EUR, GBP, NOK, PLN, SEK, BEF, DEM, ESP, FRF, ITL, NLG,
+];
@@ -0,0 +1,27 @@
+use crate::currency::Currency;
+use crate::math::rounding::Rounding;
+
+// Oceanian currencies (QuantLib currencies/oceania.hpp)
+
+pub const AUD: Currency = Currency::new(
+    "Australian dollar",
+    "AUD",
+    36,
+    "A$",
+    "",
+    100,
+    Rounding::closest(2),
+    None,
+);
+pub const NZD: Currency = Currency::new(
+    "New Zealand dollar",
+    "NZD",
+    554,
+    "NZ$",
+    "",
+    100,
+    Rounding::closest(2),
+    None,
+);
+
+pub const CURRENCIES: [Currency; 2] = [AUD, NZD];
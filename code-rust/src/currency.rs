@@ -0,0 +1,135 @@
+use crate::currencies::{america, asia, europe, oceania};
+use crate::math::rounding::Rounding;
+use crate::types::Integer;
+use std::fmt::{Display, Formatter, Result};
+
+/*
+Currency specification (QuantLib Currency): ISO 4217 name, code and
+numeric code, symbols, number of fractionary parts per unit and the
+rounding applied to amounts.
+
+Currencies replaced by another one (e.g. the euro legacy currencies)
+carry the code of the currency conversions must go through, their
+triangulation currency. Two currencies are the same if their codes are.
+*/
+#[derive(Copy, Clone, Debug)]
+pub struct Currency {
+    name: &'static str,
+    code: &'static str,
+    numeric_code: Integer,
+    symbol: &'static str,
+    fraction_symbol: &'static str,
+    fractions_per_unit: Integer,
+    rounding: Rounding,
+    triangulation_code: Option<&'static str>,
+}
+impl Currency {
+    // Constructor
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        name: &'static str,
+        code: &'static str,
+        numeric_code: Integer,
+        symbol: &'static str,
+        fraction_symbol: &'static str,
+        fractions_per_unit: Integer,
+        rounding: Rounding,
+        triangulation_code: Option<&'static str>,
+    ) -> Currency {
+        Currency {
+            name,
+            code,
+            numeric_code,
+            symbol,
+            fraction_symbol,
+            fractions_per_unit,
+            rounding,
+            triangulation_code,
+        }
+    }
+    // One of the predefined currencies, by ISO 4217 code
+    pub fn from_code(code: &str) -> Option<Currency> {
+        [
+            america::CURRENCIES.as_slice(),
+            asia::CURRENCIES.as_slice(),
+            europe::CURRENCIES.as_slice(),
+            oceania::CURRENCIES.as_slice(),
+        ]
+        .concat()
+        .into_iter()
+        .find(|currency| currency.code == code)
+    }
+
+    // Inspectors
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
+    pub fn numeric_code(&self) -> Integer {
+        self.numeric_code
+    }
+    pub fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+    pub fn fraction_symbol(&self) -> &'static str {
+        self.fraction_symbol
+    }
+    pub fn fractions_per_unit(&self) -> Integer {
+        self.fractions_per_unit
+    }
+    pub fn rounding(&self) -> Rounding {
+        self.rounding
+    }
+    pub fn triangulation_currency(&self) -> Option<Currency> {
+        self.triangulation_code.map(|code| {
+            Currency::from_code(code)
+                .unwrap_or_else(|| panic!("unknown triangulation currency {code}"))
+        })
+    }
+}
+
+// Traits
+impl PartialEq for Currency {
+    fn eq(&self, other: &Currency) -> bool {
+        self.code == other.code
+    }
+}
+impl Eq for Currency {}
+impl Display for Currency {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{}", self.code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::currencies::europe::{DEM, EUR};
+
+    #[test]
+    fn currencies_are_found_by_code() {
+        let cases: [(&str, &str, Integer, Integer); 4] = [
+            ("EUR", "European Euro", 978, 100),
+            ("USD", "U.S. dollar", 840, 100),
+            ("JPY", "Japanese yen", 392, 100),
+            ("AUD", "Australian dollar", 36, 100),
+        ];
+        for (code, name, numeric_code, fractions_per_unit) in cases {
+            let currency: Currency = Currency::from_code(code).unwrap();
+            assert_eq!(currency.name(), name);
+            assert_eq!(currency.numeric_code(), numeric_code);
+            assert_eq!(currency.fractions_per_unit(), fractions_per_unit);
+            assert_eq!(format!("{currency}"), code);
+        }
+        assert!(Currency::from_code("XXX").is_none());
+    }
+
+    #[test]
+    fn legacy_currencies_triangulate_through_the_euro() {
+        assert_eq!(DEM.triangulation_currency(), Some(EUR));
+        assert_eq!(EUR.triangulation_currency(), None);
+        assert_ne!(DEM, EUR);
+    }
+}
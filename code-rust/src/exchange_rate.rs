@@ -0,0 +1,186 @@
+use crate::currency::Currency;
+use crate::money::Money;
+use crate::types::Real;
+use std::fmt::{Display, Formatter, Result};
+
+// QuantLib ExchangeRate::Type
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ExchangeRateType {
+    // Given directly by the user
+    Direct,
+    // Chained from other rates
+    Derived,
+}
+
+// Traits
+impl Display for ExchangeRateType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let rate_type: &'static str = match self {
+            ExchangeRateType::Direct => "Direct",
+            ExchangeRateType::Derived => "Derived",
+        };
+        write!(f, "{rate_type}")
+    }
+}
+
+/*
+Exchange rate between two currencies (QuantLib ExchangeRate): one unit
+of the source currency is worth rate units of the target currency.
+Rates can exchange amounts in either direction.
+
+A derived rate keeps the two rates it was chained from, sharing one
+currency, and exchanges amounts through both of them.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct ExchangeRate {
+    source: Currency,
+    target: Currency,
+    rate: Real,
+    rate_type: ExchangeRateType,
+    rate_chain: Option<Box<(ExchangeRate, ExchangeRate)>>,
+}
+impl ExchangeRate {
+    // Constructor
+    pub fn new(source: Currency, target: Currency, rate: Real) -> ExchangeRate {
+        if rate <= 0.0 {
+            panic!("exchange rate ({rate}) must be positive");
+        }
+        ExchangeRate {
+            source,
+            target,
+            rate,
+            rate_type: ExchangeRateType::Direct,
+            rate_chain: None,
+        }
+    }
+    // Rate between the two currencies the given rates do not share
+    pub fn chain(r1: &ExchangeRate, r2: &ExchangeRate) -> ExchangeRate {
+        let (source, target, rate): (Currency, Currency, Real) = if r1.source == r2.source {
+            (r1.target, r2.target, r2.rate / r1.rate)
+        } else if r1.source == r2.target {
+            (r1.target, r2.source, 1.0 / (r1.rate * r2.rate))
+        } else if r1.target == r2.source {
+            (r1.source, r2.target, r1.rate * r2.rate)
+        } else if r1.target == r2.target {
+            (r1.source, r2.source, r1.rate / r2.rate)
+        } else {
+            panic!("exchange rates {r1} and {r2} not chainable");
+        };
+        ExchangeRate {
+            source,
+            target,
+            rate,
+            rate_type: ExchangeRateType::Derived,
+            rate_chain: Some(Box::new((r1.clone(), r2.clone()))),
+        }
+    }
+
+    // Inspectors
+    pub fn source(&self) -> Currency {
+        self.source
+    }
+    pub fn target(&self) -> Currency {
+        self.target
+    }
+    pub fn rate(&self) -> Real {
+        self.rate
+    }
+    pub fn rate_type(&self) -> ExchangeRateType {
+        self.rate_type
+    }
+
+    // Amount in one of the two currencies, exchanged into the other one
+    pub fn exchange(&self, amount: &Money) -> Money {
+        match &self.rate_chain {
+            None => {
+                if amount.currency() == self.source {
+                    Money::new(amount.value() * self.rate, self.target)
+                } else if amount.currency() == self.target {
+                    Money::new(amount.value() / self.rate, self.source)
+                } else {
+                    panic!("exchange rate {self} not applicable to {amount}");
+                }
+            }
+            Some(chain) => {
+                let (first, second): &(ExchangeRate, ExchangeRate) = chain.as_ref();
+                if amount.currency() == first.source || amount.currency() == first.target {
+                    second.exchange(&first.exchange(amount))
+                } else if amount.currency() == second.source || amount.currency() == second.target {
+                    first.exchange(&second.exchange(amount))
+                } else {
+                    panic!("exchange rate {self} not applicable to {amount}");
+                }
+            }
+        }
+    }
+    // Same rate, quoted the other way round
+    pub fn inverse(&self) -> ExchangeRate {
+        ExchangeRate {
+            source: self.target,
+            target: self.source,
+            rate: 1.0 / self.rate,
+            rate_type: self.rate_type,
+            rate_chain: self.rate_chain.clone(),
+        }
+    }
+}
+
+// Traits
+impl Display for ExchangeRate {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{}/{} {}", self.source, self.target, self.rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::currencies::america::USD;
+    use crate::currencies::asia::JPY;
+    use crate::currencies::europe::{EUR, GBP};
+
+    #[test]
+    fn direct_rates_exchange_both_ways() {
+        let rate: ExchangeRate = ExchangeRate::new(EUR, USD, 1.2042);
+        let usd: Money = rate.exchange(&(50000.0 * EUR));
+        assert!((usd.value() - 60210.0).abs() < 1.0e-8);
+        assert_eq!(usd.currency(), USD);
+        let eur: Money = rate.exchange(&(60210.0 * USD));
+        assert!((eur.value() - 50000.0).abs() < 1.0e-8);
+        assert_eq!(rate.inverse().source(), USD);
+        assert!((rate.inverse().rate() - 1.0 / 1.2042).abs() < 1.0e-15);
+        let result = std::panic::catch_unwind(|| rate.exchange(&(1.0 * GBP)));
+        assert!(result.is_err(), "expected panic for a GBP amount");
+    }
+
+    #[test]
+    fn chained_rates_cover_every_orientation() {
+        // QuantLib test-suite/exchangerate.cpp: EUR/USD 1.2042, EUR/GBP 0.6612
+        let eur_usd: ExchangeRate = ExchangeRate::new(EUR, USD, 1.2042);
+        let eur_gbp: ExchangeRate = ExchangeRate::new(EUR, GBP, 0.6612);
+        let cases: [(ExchangeRate, ExchangeRate); 4] = [
+            (eur_usd.clone(), eur_gbp.clone()),
+            (eur_usd.clone(), eur_gbp.inverse()),
+            (eur_usd.inverse(), eur_gbp.clone()),
+            (eur_usd.inverse(), eur_gbp.inverse()),
+        ];
+        for (r1, r2) in cases {
+            let derived: ExchangeRate = ExchangeRate::chain(&r1, &r2);
+            assert_eq!(derived.rate_type(), ExchangeRateType::Derived);
+            assert_eq!(derived.source(), USD);
+            assert_eq!(derived.target(), GBP);
+            assert!((derived.rate() - 0.6612 / 1.2042).abs() < 1.0e-15);
+            let gbp: Money = derived.exchange(&(60210.0 * USD));
+            assert!((gbp.value() - 33060.0).abs() < 1.0e-8, "{}", gbp);
+            let usd: Money = derived.exchange(&(33060.0 * GBP));
+            assert!((usd.value() - 60210.0).abs() < 1.0e-8, "{}", usd);
+        }
+        let result = std::panic::catch_unwind(|| {
+            ExchangeRate::chain(
+                &ExchangeRate::new(EUR, USD, 1.2042),
+                &ExchangeRate::new(GBP, JPY, 190.0),
+            )
+        });
+        assert!(result.is_err(), "expected panic for unrelated rates");
+    }
+}
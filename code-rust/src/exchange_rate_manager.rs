@@ -0,0 +1,271 @@
+use crate::currencies::europe::{BEF, DEM, ESP, EUR, FRF, ITL, NLG};
+use crate::currency::Currency;
+use crate::exchange_rate::{ExchangeRate, ExchangeRateType};
+use crate::time::date::{Date, Month};
+use crate::types::{Integer, Real};
+use std::collections::BTreeMap;
+
+/*
+Repository of exchange rates (QuantLib ExchangeRateManager). Each rate
+is valid over a range of dates; a rate added later takes precedence
+over the ones already stored for the same pair of currencies.
+
+QuantLib keeps a global instance; here the manager is an ordinary value
+passed to whoever needs to convert amounts. It starts with the fixed
+conversion rates of the euro legacy currencies.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct ExchangeRateManager {
+    data: BTreeMap<(Integer, Integer), Vec<detail::Entry>>,
+}
+impl ExchangeRateManager {
+    // Constructor
+    pub fn new() -> ExchangeRateManager {
+        let mut manager: ExchangeRateManager = ExchangeRateManager {
+            data: BTreeMap::new(),
+        };
+        manager.add_known_rates();
+        manager
+    }
+
+    // Modifiers
+    pub fn add(&mut self, rate: ExchangeRate, start_date: Date, end_date: Date) {
+        if end_date < start_date {
+            panic!("end date ({end_date}) before the start date ({start_date})");
+        }
+        let key: (Integer, Integer) = detail::key(rate.source(), rate.target());
+        self.data.entry(key).or_default().insert(
+            0,
+            detail::Entry {
+                rate,
+                start_date,
+                end_date,
+            },
+        );
+    }
+    // Removes the user-added rates, keeping the known ones
+    pub fn clear(&mut self) {
+        self.data.clear();
+        self.add_known_rates();
+    }
+
+    /*
+    Rate from source to target valid at date. Direct lookups only use
+    the stored rates; derived ones go through the triangulation currency
+    of either currency, if any, or chain stored rates until target is
+    reached.
+    */
+    pub fn lookup(
+        &self,
+        source: Currency,
+        target: Currency,
+        date: Date,
+        rate_type: ExchangeRateType,
+    ) -> ExchangeRate {
+        if source == target {
+            return ExchangeRate::new(source, target, 1.0);
+        }
+        if rate_type == ExchangeRateType::Direct {
+            return self.direct_lookup(source, target, date);
+        }
+        if let Some(link) = source.triangulation_currency() {
+            if link == target {
+                self.direct_lookup(source, link, date)
+            } else {
+                ExchangeRate::chain(
+                    &self.direct_lookup(source, link, date),
+                    &self.lookup(link, target, date, rate_type),
+                )
+            }
+        } else if let Some(link) = target.triangulation_currency() {
+            if link == source {
+                self.direct_lookup(link, target, date)
+            } else {
+                ExchangeRate::chain(
+                    &self.lookup(source, link, date, rate_type),
+                    &self.direct_lookup(link, target, date),
+                )
+            }
+        } else {
+            self.smart_lookup(source, target, date, &mut Vec::new())
+                .unwrap_or_else(|| {
+                    panic!("no conversion available from {source} to {target} for {date}")
+                })
+        }
+    }
+
+    fn direct_lookup(&self, source: Currency, target: Currency, date: Date) -> ExchangeRate {
+        self.fetch(source, target, date).unwrap_or_else(|| {
+            panic!("no direct conversion available from {source} to {target} for {date}")
+        })
+    }
+    fn fetch(&self, source: Currency, target: Currency, date: Date) -> Option<ExchangeRate> {
+        self.data
+            .get(&detail::key(source, target))?
+            .iter()
+            .find(|entry| entry.start_date <= date && date <= entry.end_date)
+            .map(|entry| entry.rate.clone())
+    }
+    // Depth-first search of a chain of rates, never going back to a currency already visited
+    fn smart_lookup(
+        &self,
+        source: Currency,
+        target: Currency,
+        date: Date,
+        forbidden: &mut Vec<Currency>,
+    ) -> Option<ExchangeRate> {
+        if let Some(direct) = self.fetch(source, target, date) {
+            return Some(direct);
+        }
+        forbidden.push(source);
+        for entries in self.data.values() {
+            let rate: &ExchangeRate = &entries[0].rate;
+            let other: Currency = if rate.source() == source {
+                rate.target()
+            } else if rate.target() == source {
+                rate.source()
+            } else {
+                continue;
+            };
+            if forbidden.contains(&other) {
+                continue;
+            }
+            if let Some(head) = self.fetch(source, other, date)
+                && let Some(tail) = self.smart_lookup(other, target, date, forbidden)
+            {
+                return Some(ExchangeRate::chain(&head, &tail));
+            }
+        }
+        None
+    }
+
+    fn add_known_rates(&mut self) {
+        // Irrevocable conversion rates of the euro legacy currencies
+        let known_rates: [(Currency, Real); 6] = [
+            (BEF, 40.3399),
+            (DEM, 1.95583),
+            (ESP, 166.386),
+            (FRF, 6.55957),
+            (ITL, 1936.27),
+            (NLG, 2.20371),
+        ];
+        for (currency, rate) in known_rates {
+            self.add(
+                ExchangeRate::new(EUR, currency, rate),
+                Date::new(1, Month::January, 1999),
+                Date::max_date(),
+            );
+        }
+    }
+}
+impl Default for ExchangeRateManager {
+    fn default() -> Self {
+        ExchangeRateManager::new()
+    }
+}
+
+mod detail {
+    use super::*;
+
+    #[derive(Clone, PartialEq, Debug)]
+    pub(super) struct Entry {
+        pub(super) rate: ExchangeRate,
+        pub(super) start_date: Date,
+        pub(super) end_date: Date,
+    }
+
+    // Rates between two currencies are stored under the same key whatever their direction
+    pub(super) fn key(source: Currency, target: Currency) -> (Integer, Integer) {
+        let (n1, n2): (Integer, Integer) = (source.numeric_code(), target.numeric_code());
+        (n1.min(n2), n1.max(n2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::currencies::america::{CAD, USD};
+    use crate::currencies::asia::JPY;
+    use crate::currencies::europe::{CHF, GBP, SEK};
+    use crate::money::Money;
+
+    fn date(day: i32, month: Month) -> Date {
+        Date::new(day, month, 2025)
+    }
+
+    #[test]
+    fn lookup_picks_the_rate_valid_at_the_date() {
+        let mut manager: ExchangeRateManager = ExchangeRateManager::new();
+        manager.add(
+            ExchangeRate::new(EUR, USD, 1.10),
+            date(1, Month::January),
+            date(30, Month::June),
+        );
+        manager.add(
+            ExchangeRate::new(EUR, USD, 1.15),
+            date(1, Month::July),
+            date(31, Month::December),
+        );
+        // Later additions override earlier ones on the overlap
+        manager.add(
+            ExchangeRate::new(USD, EUR, 1.0 / 1.12),
+            date(1, Month::June),
+            date(15, Month::July),
+        );
+        let cases: [(Date, Real); 4] = [
+            (date(15, Month::March), 1.10),
+            (date(15, Month::June), 1.12),
+            (date(10, Month::July), 1.12),
+            (date(15, Month::August), 1.15),
+        ];
+        for (day, expected) in cases {
+            let rate: ExchangeRate = manager.lookup(EUR, USD, day, ExchangeRateType::Direct);
+            let usd: Money = rate.exchange(&Money::new(1.0, EUR));
+            assert!((usd.value() - expected).abs() < 1.0e-12, "{day}: {usd}");
+        }
+        manager.clear();
+        let result = std::panic::catch_unwind(|| {
+            manager.lookup(EUR, USD, date(15, Month::March), ExchangeRateType::Direct)
+        });
+        assert!(result.is_err(), "expected panic after clear");
+    }
+
+    #[test]
+    fn derived_lookups_triangulate_and_chain() {
+        let mut manager: ExchangeRateManager = ExchangeRateManager::new();
+        let (start, end): (Date, Date) = (date(1, Month::January), date(31, Month::December));
+        manager.add(ExchangeRate::new(EUR, USD, 1.2042), start, end);
+        manager.add(ExchangeRate::new(EUR, GBP, 0.6612), start, end);
+        manager.add(ExchangeRate::new(USD, JPY, 150.0), start, end);
+        manager.add(ExchangeRate::new(CHF, USD, 1.25), start, end);
+        manager.add(ExchangeRate::new(SEK, CAD, 0.13), start, end);
+        let day: Date = date(15, Month::May);
+
+        // Legacy currencies go through the euro at the fixed rates
+        let rate: ExchangeRate = manager.lookup(DEM, FRF, day, ExchangeRateType::Derived);
+        assert!((rate.rate() - 6.55957 / 1.95583).abs() < 1.0e-12);
+        let rate: ExchangeRate = manager.lookup(DEM, USD, day, ExchangeRateType::Derived);
+        assert!((rate.rate() - 1.2042 / 1.95583).abs() < 1.0e-12);
+
+        // GBP -> EUR -> USD -> CHF
+        let rate: ExchangeRate = manager.lookup(GBP, CHF, day, ExchangeRateType::Derived);
+        assert_eq!(rate.source(), GBP);
+        assert_eq!(rate.target(), CHF);
+        assert!((rate.rate() - 1.2042 / 0.6612 / 1.25).abs() < 1.0e-12);
+        // GBP -> EUR -> USD -> JPY
+        let jpy: Money = manager
+            .lookup(GBP, JPY, day, ExchangeRateType::Derived)
+            .exchange(&Money::new(1000.0, GBP));
+        assert!((jpy.value() - 1000.0 / 0.6612 * 1.2042 * 150.0).abs() < 1.0e-6);
+
+        let cases: [(Currency, Currency, ExchangeRateType, &str); 2] = [
+            (GBP, USD, ExchangeRateType::Direct, "no direct GBP/USD rate"),
+            (GBP, SEK, ExchangeRateType::Derived, "disconnected SEK"),
+        ];
+        for (source, target, rate_type, label) in cases {
+            let result =
+                std::panic::catch_unwind(|| manager.lookup(source, target, day, rate_type));
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
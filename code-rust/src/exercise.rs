@@ -0,0 +1,284 @@
+use crate::time::date::Date;
+use crate::types::Size;
+use std::fmt::{Display, Formatter, Result};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ExerciseType {
+    American,
+    Bermudan,
+    European,
+}
+
+// Traits
+impl Display for ExerciseType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let exercise_type: &'static str = match self {
+            ExerciseType::American => "American",
+            ExerciseType::Bermudan => "Bermudan",
+            ExerciseType::European => "European",
+        };
+        write!(f, "{exercise_type}")
+    }
+}
+
+/*
+Base "class" for exercise conditions (QuantLib Exercise).
+
+Every exercise only needs to know its type and its dates,
+the remaining inspectors come for free from the default methods.
+*/
+pub trait Exercise {
+    fn exercise_type(&self) -> ExerciseType;
+    fn dates(&self) -> &[Date];
+
+    // The exercise as an early exercise, if it is one (QuantLib casts to EarlyExercise)
+    fn as_early_exercise(&self) -> Option<&dyn EarlyExercise> {
+        None
+    }
+
+    // Inspectors
+    fn date(&self, index: Size) -> Date {
+        self.dates()[index]
+    }
+    fn last_date(&self) -> Date {
+        // Dates are never empty: constructors make sure of it
+        *self.dates().last().unwrap()
+    }
+}
+
+/*
+Early-exercise base (QuantLib EarlyExercise).
+
+payoff_at_expiry tells if the payoff is paid at exercise (false)
+or only at expiry (true).
+*/
+pub trait EarlyExercise: Exercise {
+    fn payoff_at_expiry(&self) -> bool;
+}
+
+// European exercise: can only be exercised at a single date
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct EuropeanExercise {
+    dates: Vec<Date>,
+}
+impl EuropeanExercise {
+    // Constructor
+    pub fn new(date: Date) -> EuropeanExercise {
+        EuropeanExercise { dates: vec![date] }
+    }
+}
+impl Exercise for EuropeanExercise {
+    fn exercise_type(&self) -> ExerciseType {
+        ExerciseType::European
+    }
+    fn dates(&self) -> &[Date] {
+        &self.dates
+    }
+}
+
+// American exercise: can be exercised at any date between earliest and latest
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AmericanExercise {
+    dates: Vec<Date>, // [earliest, latest]
+    payoff_at_expiry: bool,
+}
+impl AmericanExercise {
+    // Constructors
+    pub fn new(earliest_date: Date, latest_date: Date, payoff_at_expiry: bool) -> AmericanExercise {
+        if earliest_date > latest_date {
+            panic!("earliest > latest exercise date: {earliest_date} > {latest_date}");
+        }
+        AmericanExercise {
+            dates: vec![earliest_date, latest_date],
+            payoff_at_expiry,
+        }
+    }
+    pub fn from_latest_date(latest_date: Date, payoff_at_expiry: bool) -> AmericanExercise {
+        // No earliest date given: exercise is possible from the very first date
+        AmericanExercise::new(Date::min_date(), latest_date, payoff_at_expiry)
+    }
+}
+impl Exercise for AmericanExercise {
+    fn exercise_type(&self) -> ExerciseType {
+        ExerciseType::American
+    }
+    fn dates(&self) -> &[Date] {
+        &self.dates
+    }
+    fn as_early_exercise(&self) -> Option<&dyn EarlyExercise> {
+        Some(self)
+    }
+}
+impl EarlyExercise for AmericanExercise {
+    fn payoff_at_expiry(&self) -> bool {
+        self.payoff_at_expiry
+    }
+}
+
+// Bermudan exercise: can be exercised at a set of given dates
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BermudanExercise {
+    dates: Vec<Date>,
+    payoff_at_expiry: bool,
+}
+impl BermudanExercise {
+    // Constructor
+    pub fn new(dates: &[Date], payoff_at_expiry: bool) -> BermudanExercise {
+        if dates.is_empty() {
+            panic!("no exercise date given");
+        }
+        // Dates can be given in any order, they are stored sorted
+        let mut sorted_dates: Vec<Date> = dates.to_vec();
+        sorted_dates.sort();
+        BermudanExercise {
+            dates: sorted_dates,
+            payoff_at_expiry,
+        }
+    }
+}
+impl Exercise for BermudanExercise {
+    fn exercise_type(&self) -> ExerciseType {
+        ExerciseType::Bermudan
+    }
+    fn dates(&self) -> &[Date] {
+        &self.dates
+    }
+    fn as_early_exercise(&self) -> Option<&dyn EarlyExercise> {
+        Some(self)
+    }
+}
+impl EarlyExercise for BermudanExercise {
+    fn payoff_at_expiry(&self) -> bool {
+        self.payoff_at_expiry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+    use std::panic;
+
+    #[test]
+    fn display_exercise_type_outputs_correct_format() {
+        let cases: [(ExerciseType, &str); 3] = [
+            (ExerciseType::American, "American"),
+            (ExerciseType::Bermudan, "Bermudan"),
+            (ExerciseType::European, "European"),
+        ];
+
+        for (exercise_type, expected) in cases {
+            assert_eq!(
+                format!("{exercise_type}"),
+                expected,
+                "Failed for exercise type {:?}",
+                exercise_type
+            );
+        }
+    }
+
+    #[test]
+    fn european_exercise_has_single_date() {
+        let expiry: Date = Date::new(15, Month::March, 2026);
+        let exercise: EuropeanExercise = EuropeanExercise::new(expiry);
+
+        assert_eq!(exercise.exercise_type(), ExerciseType::European);
+        assert_eq!(exercise.dates(), &[expiry]);
+        assert_eq!(exercise.date(0), expiry);
+        assert_eq!(exercise.last_date(), expiry);
+    }
+
+    #[test]
+    fn american_exercise_stores_earliest_and_latest() {
+        let cases: [(AmericanExercise, Date, Date, bool, &str); 3] = [
+            (
+                AmericanExercise::new(
+                    Date::new(1, Month::June, 2025),
+                    Date::new(1, Month::June, 2026),
+                    false,
+                ),
+                Date::new(1, Month::June, 2025),
+                Date::new(1, Month::June, 2026),
+                false,
+                "earliest and latest",
+            ),
+            (
+                AmericanExercise::new(
+                    Date::new(1, Month::June, 2025),
+                    Date::new(1, Month::June, 2025),
+                    true,
+                ),
+                Date::new(1, Month::June, 2025),
+                Date::new(1, Month::June, 2025),
+                true,
+                "same earliest and latest",
+            ),
+            (
+                AmericanExercise::from_latest_date(Date::new(31, Month::December, 2030), false),
+                Date::min_date(),
+                Date::new(31, Month::December, 2030),
+                false,
+                "latest only",
+            ),
+        ];
+
+        for (exercise, earliest, latest, payoff_at_expiry, label) in cases {
+            assert_eq!(
+                exercise.exercise_type(),
+                ExerciseType::American,
+                "Type mismatch ({label})"
+            );
+            assert_eq!(exercise.date(0), earliest, "Earliest mismatch ({label})");
+            assert_eq!(exercise.last_date(), latest, "Latest mismatch ({label})");
+            assert_eq!(
+                exercise.payoff_at_expiry(),
+                payoff_at_expiry,
+                "Payoff at expiry mismatch ({label})"
+            );
+        }
+    }
+
+    #[test]
+    fn american_exercise_panics_if_earliest_after_latest() {
+        let result = panic::catch_unwind(|| {
+            AmericanExercise::new(
+                Date::new(2, Month::June, 2026),
+                Date::new(1, Month::June, 2026),
+                false,
+            )
+        });
+        assert!(result.is_err(), "expected panic for earliest > latest");
+    }
+
+    #[test]
+    fn bermudan_exercise_sorts_dates() {
+        let dates: [Date; 3] = [
+            Date::new(15, Month::September, 2026),
+            Date::new(15, Month::March, 2026),
+            Date::new(15, Month::June, 2026),
+        ];
+        let exercise: BermudanExercise = BermudanExercise::new(&dates, true);
+
+        assert_eq!(exercise.exercise_type(), ExerciseType::Bermudan);
+        assert_eq!(
+            exercise.dates(),
+            &[
+                Date::new(15, Month::March, 2026),
+                Date::new(15, Month::June, 2026),
+                Date::new(15, Month::September, 2026),
+            ]
+        );
+        assert_eq!(exercise.last_date(), Date::new(15, Month::September, 2026));
+        assert!(exercise.payoff_at_expiry());
+        let exercise: &dyn Exercise = &exercise;
+        assert!(exercise.as_early_exercise().unwrap().payoff_at_expiry());
+        let european: EuropeanExercise = EuropeanExercise::new(Date::new(15, Month::March, 2026));
+        assert!(european.as_early_exercise().is_none());
+    }
+
+    #[test]
+    fn bermudan_exercise_panics_without_dates() {
+        let result = panic::catch_unwind(|| BermudanExercise::new(&[], false));
+        assert!(result.is_err(), "expected panic for empty dates");
+    }
+}
@@ -0,0 +1,313 @@
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::business_day_convention::BusinessDayConvention;
+use crate::time::calendar::Calendar;
+use crate::time::calendars::target::Target;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::time::daycounters::actual360::Actual360;
+use crate::time::period::Period;
+use crate::time::time_unit::TimeUnit;
+use crate::types::{Rate, Time};
+use std::collections::BTreeMap;
+
+/*
+Interbank offered rate index (QuantLib IborIndex): a deposit rate for
+the index tenor, fixed fixing_days business days before its value date
+on the fixing calendar and accruing with the index day counter.
+
+Fixings are stored by fixing date. A fixing is the stored one if any,
+otherwise it is forecast from a yield curve as the simple forward
+(P(value) / P(maturity) - 1) / tau, which is only possible for fixing
+dates on or after the reference date of the curve: past fixings must
+have been added.
+*/
+pub struct IborIndex {
+    family_name: String,
+    tenor: Period,
+    fixing_days: i32,
+    fixing_calendar: Box<dyn Calendar>,
+    convention: BusinessDayConvention,
+    end_of_month: bool,
+    day_counter: Box<dyn DayCounter>,
+    fixings: BTreeMap<Date, Rate>,
+}
+impl IborIndex {
+    // Constructors
+    pub fn new(
+        family_name: &str,
+        tenor: Period,
+        fixing_days: i32,
+        fixing_calendar: Box<dyn Calendar>,
+        convention: BusinessDayConvention,
+        end_of_month: bool,
+        day_counter: Box<dyn DayCounter>,
+    ) -> IborIndex {
+        if tenor.length() <= 0 {
+            panic!("non-positive index tenor ({tenor}) not allowed");
+        }
+        if fixing_days < 0 {
+            panic!("negative fixing days ({fixing_days}) not allowed");
+        }
+        IborIndex {
+            family_name: family_name.to_string(),
+            tenor,
+            fixing_days,
+            fixing_calendar,
+            convention,
+            end_of_month,
+            day_counter,
+            fixings: BTreeMap::new(),
+        }
+    }
+    // Euribor (QuantLib Euribor): TARGET, 2 fixing days, Actual/360
+    pub fn euribor(tenor: Period) -> IborIndex {
+        let short_tenor: bool = matches!(tenor.units(), TimeUnit::Days | TimeUnit::Weeks);
+        IborIndex::new(
+            "Euribor",
+            tenor,
+            2,
+            Box::new(Target::new()),
+            if short_tenor {
+                BusinessDayConvention::Following
+            } else {
+                BusinessDayConvention::ModifiedFollowing
+            },
+            !short_tenor,
+            Box::new(Actual360::new()),
+        )
+    }
+
+    // Inspectors
+    pub fn name(&self) -> String {
+        let tenor: String = if self.tenor == Period::new(1, TimeUnit::Days) {
+            String::from("ON")
+        } else {
+            format!("{}", self.tenor)
+        };
+        format!("{}{} {}", self.family_name, tenor, self.day_counter.name())
+    }
+    pub fn family_name(&self) -> &str {
+        &self.family_name
+    }
+    pub fn tenor(&self) -> Period {
+        self.tenor
+    }
+    pub fn fixing_days(&self) -> i32 {
+        self.fixing_days
+    }
+    pub fn fixing_calendar(&self) -> &dyn Calendar {
+        self.fixing_calendar.as_ref()
+    }
+    pub fn business_day_convention(&self) -> BusinessDayConvention {
+        self.convention
+    }
+    pub fn end_of_month(&self) -> bool {
+        self.end_of_month
+    }
+    pub fn day_counter(&self) -> &dyn DayCounter {
+        self.day_counter.as_ref()
+    }
+    pub fn is_valid_fixing_date(&self, date: Date) -> bool {
+        self.fixing_calendar.is_business_day(date)
+    }
+
+    // Date calculations
+    pub fn fixing_date(&self, value_date: Date) -> Date {
+        self.fixing_calendar.advance(
+            value_date,
+            -self.fixing_days,
+            TimeUnit::Days,
+            BusinessDayConvention::Preceding,
+            false,
+        )
+    }
+    pub fn value_date(&self, fixing_date: Date) -> Date {
+        self.fixing_calendar.advance(
+            fixing_date,
+            self.fixing_days,
+            TimeUnit::Days,
+            BusinessDayConvention::Following,
+            false,
+        )
+    }
+    pub fn maturity_date(&self, value_date: Date) -> Date {
+        self.fixing_calendar.advance(
+            value_date,
+            self.tenor.length(),
+            self.tenor.units(),
+            self.convention,
+            self.end_of_month,
+        )
+    }
+
+    // Modifiers
+    pub fn add_fixing(&mut self, date: Date, fixing: Rate) {
+        if !self.is_valid_fixing_date(date) {
+            panic!("invalid {} fixing date {}", self.name(), date);
+        }
+        self.fixings.insert(date, fixing);
+    }
+
+    // Stored fixing for date, if any (QuantLib pastFixing)
+    pub fn past_fixing(&self, date: Date) -> Option<Rate> {
+        self.fixings.get(&date).copied()
+    }
+
+    // Fixing for date, forecast if not stored and not before the curve reference date
+    pub fn fixing(&self, date: Date, forecast: Option<&dyn YieldTermStructure>) -> Rate {
+        if !self.is_valid_fixing_date(date) {
+            panic!("invalid {} fixing date {}", self.name(), date);
+        }
+        if let Some(fixing) = self.past_fixing(date) {
+            return fixing;
+        }
+        match forecast {
+            Some(curve) if date >= curve.reference_date() => self.forecast_fixing(date, curve),
+            _ => panic!("missing {} fixing for {}", self.name(), date),
+        }
+    }
+
+    pub fn forecast_fixing(&self, fixing_date: Date, forecast: &dyn YieldTermStructure) -> Rate {
+        let value_date: Date = self.value_date(fixing_date);
+        let maturity_date: Date = self.maturity_date(value_date);
+        let tau: Time = self.day_counter.year_fraction(value_date, maturity_date);
+        (forecast.discount_date(value_date) / forecast.discount_date(maturity_date) - 1.0) / tau
+    }
+}
+
+/*
+Overnight index (QuantLib OvernightIndex): an Ibor index with a one-day
+tenor fixing on its value date unless fixing_days is given, whose
+fixings are compounded by overnight indexed coupons.
+*/
+pub struct OvernightIndex {
+    index: IborIndex,
+}
+impl OvernightIndex {
+    // Constructors
+    pub fn new(
+        family_name: &str,
+        fixing_days: i32,
+        fixing_calendar: Box<dyn Calendar>,
+        day_counter: Box<dyn DayCounter>,
+    ) -> OvernightIndex {
+        OvernightIndex {
+            index: IborIndex::new(
+                family_name,
+                Period::new(1, TimeUnit::Days),
+                fixing_days,
+                fixing_calendar,
+                BusinessDayConvention::Following,
+                false,
+                day_counter,
+            ),
+        }
+    }
+    // Euro short-term rate (QuantLib Estr): TARGET, Actual/360
+    pub fn estr() -> OvernightIndex {
+        OvernightIndex::new(
+            "ESTR",
+            0,
+            Box::new(Target::new()),
+            Box::new(Actual360::new()),
+        )
+    }
+
+    // Inspectors
+    pub fn name(&self) -> String {
+        self.index.name()
+    }
+    pub fn fixing_days(&self) -> i32 {
+        self.index.fixing_days()
+    }
+    pub fn fixing_calendar(&self) -> &dyn Calendar {
+        self.index.fixing_calendar()
+    }
+    pub fn day_counter(&self) -> &dyn DayCounter {
+        self.index.day_counter()
+    }
+
+    // Modifiers
+    pub fn add_fixing(&mut self, date: Date, fixing: Rate) {
+        self.index.add_fixing(date, fixing);
+    }
+
+    pub fn past_fixing(&self, date: Date) -> Option<Rate> {
+        self.index.past_fixing(date)
+    }
+    pub fn fixing(&self, date: Date, forecast: Option<&dyn YieldTermStructure>) -> Rate {
+        self.index.fixing(date, forecast)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::Month;
+    use std::rc::Rc;
+
+    #[test]
+    fn euribor_dates_follow_the_target_calendar() {
+        let index: IborIndex = IborIndex::euribor(Period::new(6, TimeUnit::Months));
+        assert_eq!(index.name(), "Euribor6M Actual/360");
+        // Friday fixing, value on Tuesday, 6M later on the last business day of the month
+        let fixing_date: Date = Date::new(28, Month::February, 2025);
+        assert_eq!(
+            index.value_date(fixing_date),
+            Date::new(4, Month::March, 2025)
+        );
+        assert_eq!(
+            index.fixing_date(Date::new(4, Month::March, 2025)),
+            fixing_date
+        );
+        assert_eq!(
+            index.maturity_date(Date::new(28, Month::February, 2025)),
+            Date::new(29, Month::August, 2025)
+        );
+        assert_eq!(OvernightIndex::estr().name(), "ESTRON Actual/360");
+    }
+
+    #[test]
+    fn past_fixings_are_stored_and_future_ones_forecast() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let mut index: IborIndex = IborIndex::euribor(Period::new(3, TimeUnit::Months));
+        index.add_fixing(reference_date - 7, 0.025);
+        let curve: Rc<dyn YieldTermStructure> = flat_rate(reference_date, 0.03);
+        assert_eq!(
+            index.fixing(reference_date - 7, Some(curve.as_ref())),
+            0.025
+        );
+
+        let value_date: Date = Date::new(17, Month::January, 2025);
+        let maturity_date: Date = Date::new(17, Month::April, 2025);
+        let expected: Rate = ((0.03 * 90.0 / 365.0_f64).exp() - 1.0) * 360.0 / 90.0;
+        assert_eq!(index.maturity_date(value_date), maturity_date);
+        let forecast: Rate = index.fixing(reference_date, Some(curve.as_ref()));
+        assert!((forecast - expected).abs() < 1.0e-14);
+        index.add_fixing(reference_date, 0.029);
+        assert_eq!(index.fixing(reference_date, Some(curve.as_ref())), 0.029);
+    }
+
+    #[test]
+    fn fixing_panics_on_missing_or_invalid_dates() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let cases: [(Date, bool, &str); 3] = [
+            (reference_date - 1, true, "missing past fixing"),
+            (reference_date, false, "missing fixing without curve"),
+            (Date::new(18, Month::January, 2025), true, "Saturday fixing"),
+        ];
+        for (date, with_curve, label) in cases {
+            let result = std::panic::catch_unwind(|| {
+                let index: IborIndex = IborIndex::euribor(Period::new(6, TimeUnit::Months));
+                let curve: Rc<dyn YieldTermStructure> = flat_rate(reference_date, 0.03);
+                index.fixing(date, with_curve.then_some(curve.as_ref()))
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+        let result = std::panic::catch_unwind(|| {
+            OvernightIndex::estr().add_fixing(Date::new(1, Month::May, 2025), 0.02)
+        });
+        assert!(result.is_err(), "expected panic for a holiday fixing");
+    }
+}
@@ -0,0 +1,307 @@
+use crate::termstructures::inflation::zero_inflation_term_structure::{
+    ZeroInflationTermStructure, inflation_period,
+};
+use crate::time::date::{Date, Month};
+use crate::time::frequency::Frequency;
+use crate::time::period::Period;
+use crate::time::time_unit::TimeUnit;
+use crate::types::{Rate, Real};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Result};
+
+// How CPI fixings are observed between publications (QuantLib CPI::InterpolationType)
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum CpiInterpolationType {
+    AsIndex,
+    Flat,
+    Linear,
+}
+
+// Traits
+impl Display for CpiInterpolationType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let interpolation: &'static str = match self {
+            CpiInterpolationType::AsIndex => "AsIndex",
+            CpiInterpolationType::Flat => "Flat",
+            CpiInterpolationType::Linear => "Linear",
+        };
+        write!(f, "{interpolation}")
+    }
+}
+
+/*
+Zero inflation (CPI) index (QuantLib ZeroInflationIndex): one fixing
+per inflation period of the publication frequency, published
+availability_lag after the period.
+
+Fixings are stored at the start of their period. A fixing is the
+stored one if any, otherwise it is forecast from a zero inflation curve
+as I(base) (1 + z(d))^t, which needs the fixing at the curve base date.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct ZeroInflationIndex {
+    family_name: String,
+    frequency: Frequency,
+    availability_lag: Period,
+    fixings: BTreeMap<Date, Real>,
+}
+impl ZeroInflationIndex {
+    // Constructor
+    pub fn new(
+        family_name: &str,
+        frequency: Frequency,
+        availability_lag: Period,
+    ) -> ZeroInflationIndex {
+        // Rejects frequencies without inflation periods
+        inflation_period(Date::new(1, Month::January, 2000), frequency);
+        ZeroInflationIndex {
+            family_name: family_name.to_string(),
+            frequency,
+            availability_lag,
+            fixings: BTreeMap::new(),
+        }
+    }
+
+    // Inspectors
+    pub fn name(&self) -> String {
+        format!("{} {}", self.family_name, self.frequency)
+    }
+    pub fn family_name(&self) -> &str {
+        &self.family_name
+    }
+    pub fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+    pub fn availability_lag(&self) -> Period {
+        self.availability_lag
+    }
+    pub fn last_fixing_date(&self) -> Option<Date> {
+        self.fixings.keys().next_back().copied()
+    }
+
+    // Modifiers
+    pub fn add_fixing(&mut self, date: Date, fixing: Real) {
+        if fixing <= 0.0 {
+            panic!("non-positive fixing ({fixing}) for {date}");
+        }
+        self.fixings
+            .insert(inflation_period(date, self.frequency).0, fixing);
+    }
+
+    // Fixing of the period containing date, forecast if not stored
+    pub fn fixing(&self, date: Date, forecast: Option<&dyn ZeroInflationTermStructure>) -> Real {
+        let start: Date = inflation_period(date, self.frequency).0;
+        if let Some(fixing) = self.fixings.get(&start) {
+            return *fixing;
+        }
+        let curve: &dyn ZeroInflationTermStructure = forecast.unwrap_or_else(|| {
+            panic!("missing {} fixing for {}", self.name(), start);
+        });
+        let base_fixing: Real = *self.fixings.get(&curve.base_date()).unwrap_or_else(|| {
+            panic!(
+                "missing {} fixing for the curve base date {}",
+                self.name(),
+                curve.base_date()
+            )
+        });
+        let t: Real = curve.time_from_base(start);
+        base_fixing * (1.0 + curve.zero_rate(start)).powf(t)
+    }
+}
+
+/*
+Year-on-year inflation index (QuantLib YoYInflationIndex built as a
+ratio of its underlying zero index): the fixing at d is
+I(d) / I(d - 1Y) - 1 for the periods containing both dates.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct YoYInflationIndex {
+    underlying: ZeroInflationIndex,
+}
+impl YoYInflationIndex {
+    // Constructor
+    pub fn new(underlying: ZeroInflationIndex) -> YoYInflationIndex {
+        YoYInflationIndex { underlying }
+    }
+
+    // Inspectors
+    pub fn name(&self) -> String {
+        format!("YY_{}", self.underlying.name())
+    }
+    pub fn underlying(&self) -> &ZeroInflationIndex {
+        &self.underlying
+    }
+    pub fn frequency(&self) -> Frequency {
+        self.underlying.frequency()
+    }
+
+    pub fn fixing(&self, date: Date, forecast: Option<&dyn ZeroInflationTermStructure>) -> Rate {
+        let year_before: Date = Date::advance(date, -1, TimeUnit::Years);
+        self.underlying.fixing(date, forecast) / self.underlying.fixing(year_before, forecast) - 1.0
+    }
+}
+
+/*
+CPI value observed at date with the given lag (QuantLib
+CPI::laggedFixing). Flat and AsIndex take the fixing of the period
+containing date - observation_lag; Linear interpolates between that
+fixing and the next one with the position of date in its own period.
+*/
+pub fn cpi_lagged_fixing(
+    index: &ZeroInflationIndex,
+    date: Date,
+    observation_lag: Period,
+    interpolation: CpiInterpolationType,
+    forecast: Option<&dyn ZeroInflationTermStructure>,
+) -> Real {
+    let (fixing_start, fixing_end): (Date, Date) =
+        inflation_period(date - observation_lag, index.frequency());
+    let fixing: Real = index.fixing(fixing_start, forecast);
+    if interpolation != CpiInterpolationType::Linear {
+        return fixing;
+    }
+    let (start, end): (Date, Date) = inflation_period(date, index.frequency());
+    if date == start {
+        return fixing;
+    }
+    let next_fixing: Real = index.fixing(fixing_end + 1, forecast);
+    fixing + (next_fixing - fixing) * (date - start) as Real / ((end + 1) - start) as Real
+}
+
+// Last fixing date cpi_lagged_fixing needs
+pub fn cpi_last_fixing_date(
+    date: Date,
+    observation_lag: Period,
+    interpolation: CpiInterpolationType,
+    frequency: Frequency,
+) -> Date {
+    let (fixing_start, fixing_end): (Date, Date) =
+        inflation_period(date - observation_lag, frequency);
+    if interpolation == CpiInterpolationType::Linear && date != inflation_period(date, frequency).0
+    {
+        fixing_end + 1
+    } else {
+        fixing_start
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::termstructures::inflation::interpolated_zero_inflation_curve::InterpolatedZeroInflationCurve;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+
+    fn index() -> ZeroInflationIndex {
+        let mut index: ZeroInflationIndex =
+            ZeroInflationIndex::new("CPI", Frequency::Monthly, Period::new(1, TimeUnit::Months));
+        for (month, fixing) in [
+            (Month::January, 100.0),
+            (Month::February, 100.5),
+            (Month::March, 101.0),
+        ] {
+            index.add_fixing(Date::new(15, month, 2025), fixing);
+        }
+        index
+    }
+
+    fn curve() -> InterpolatedZeroInflationCurve {
+        InterpolatedZeroInflationCurve::new(
+            Date::new(15, Month::April, 2025),
+            &[
+                Date::new(1, Month::January, 2025),
+                Date::new(1, Month::January, 2030),
+            ],
+            &[0.02, 0.02],
+            Frequency::Monthly,
+            Box::new(Actual365Fixed::new()),
+        )
+    }
+
+    #[test]
+    fn fixings_are_stored_or_forecast() {
+        let index: ZeroInflationIndex = index();
+        let curve: InterpolatedZeroInflationCurve = curve();
+        assert_eq!(index.name(), "CPI Monthly");
+        assert_eq!(
+            index.last_fixing_date(),
+            Some(Date::new(1, Month::March, 2025))
+        );
+        assert_eq!(
+            index.fixing(Date::new(28, Month::February, 2025), None),
+            100.5
+        );
+        assert_eq!(
+            index.fixing(Date::new(1, Month::March, 2025), Some(&curve)),
+            101.0
+        );
+        let forecast: Real = index.fixing(Date::new(10, Month::January, 2027), Some(&curve));
+        let t: Real = 730.0 / 365.0;
+        assert!((forecast - 100.0 * 1.02_f64.powf(t)).abs() < 1.0e-12);
+        let yoy: YoYInflationIndex = YoYInflationIndex::new(index.clone());
+        assert_eq!(yoy.name(), "YY_CPI Monthly");
+        assert!(
+            (yoy.fixing(Date::new(1, Month::January, 2027), Some(&curve))
+                - (1.02_f64.powf(t) / 1.02_f64.powf(1.0) - 1.0))
+                .abs()
+                < 1.0e-14
+        );
+    }
+
+    #[test]
+    fn lagged_fixings_interpolate_linearly_within_the_period() {
+        let index: ZeroInflationIndex = index();
+        let lag: Period = Period::new(2, TimeUnit::Months);
+        let cases: [(Date, CpiInterpolationType, Real); 4] = [
+            (
+                Date::new(1, Month::April, 2025),
+                CpiInterpolationType::Linear,
+                100.5,
+            ),
+            (
+                Date::new(16, Month::April, 2025),
+                CpiInterpolationType::Linear,
+                100.75,
+            ),
+            (
+                Date::new(16, Month::April, 2025),
+                CpiInterpolationType::Flat,
+                100.5,
+            ),
+            (
+                Date::new(30, Month::April, 2025),
+                CpiInterpolationType::AsIndex,
+                100.5,
+            ),
+        ];
+        for (date, interpolation, expected) in cases {
+            let fixing: Real = cpi_lagged_fixing(&index, date, lag, interpolation, None);
+            assert!(
+                (fixing - expected).abs() < 1.0e-12,
+                "{date} {interpolation}"
+            );
+        }
+        assert_eq!(
+            cpi_last_fixing_date(
+                Date::new(16, Month::April, 2025),
+                lag,
+                CpiInterpolationType::Linear,
+                Frequency::Monthly
+            ),
+            Date::new(1, Month::March, 2025)
+        );
+    }
+
+    #[test]
+    fn missing_or_invalid_fixings_panic() {
+        let result =
+            std::panic::catch_unwind(|| index().fixing(Date::new(1, Month::May, 2025), None));
+        assert!(result.is_err(), "expected panic for a missing fixing");
+        let result =
+            std::panic::catch_unwind(|| index().add_fixing(Date::new(1, Month::May, 2025), 0.0));
+        assert!(result.is_err(), "expected panic for a null fixing");
+        let result = std::panic::catch_unwind(|| {
+            ZeroInflationIndex::new("CPI", Frequency::Daily, Period::new(1, TimeUnit::Months))
+        });
+        assert!(result.is_err(), "expected panic for a daily index");
+    }
+}
@@ -0,0 +1,140 @@
+use crate::exercise::EuropeanExercise;
+use crate::instruments::payoffs::PlainVanillaPayoff;
+use crate::time::date::Date;
+use crate::types::{Real, Size};
+use std::fmt::{Display, Formatter, Result};
+
+// QuantLib Average::Type
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum AverageType {
+    Arithmetic,
+    Geometric,
+}
+
+// Traits
+impl Display for AverageType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let average_type: &'static str = match self {
+            AverageType::Arithmetic => "Arithmetic",
+            AverageType::Geometric => "Geometric",
+        };
+        write!(f, "{average_type}")
+    }
+}
+
+/*
+Average-price Asian option with discrete fixings (QuantLib
+DiscreteAveragingAsianOption): pays the vanilla payoff of the average
+of the underlying over the fixing dates.
+
+Fixings already published are summarized by their number and their
+running accumulator: their sum for arithmetic averages, their product
+for geometric ones (0 and 1 when no fixing is past).
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct DiscreteAveragingAsianOption {
+    average_type: AverageType,
+    running_accumulator: Real,
+    past_fixings: Size,
+    fixing_dates: Vec<Date>,
+    payoff: PlainVanillaPayoff,
+    exercise: EuropeanExercise,
+}
+impl DiscreteAveragingAsianOption {
+    // Constructor
+    pub fn new(
+        average_type: AverageType,
+        running_accumulator: Real,
+        past_fixings: Size,
+        fixing_dates: &[Date],
+        payoff: PlainVanillaPayoff,
+        exercise: EuropeanExercise,
+    ) -> DiscreteAveragingAsianOption {
+        if fixing_dates.is_empty() && past_fixings == 0 {
+            panic!("no fixing dates given");
+        }
+        let mut fixing_dates: Vec<Date> = fixing_dates.to_vec();
+        fixing_dates.sort();
+        DiscreteAveragingAsianOption {
+            average_type,
+            running_accumulator,
+            past_fixings,
+            fixing_dates,
+            payoff,
+            exercise,
+        }
+    }
+    // Option with no past fixings
+    pub fn with_future_fixings(
+        average_type: AverageType,
+        fixing_dates: &[Date],
+        payoff: PlainVanillaPayoff,
+        exercise: EuropeanExercise,
+    ) -> DiscreteAveragingAsianOption {
+        let running_accumulator: Real = match average_type {
+            AverageType::Arithmetic => 0.0,
+            AverageType::Geometric => 1.0,
+        };
+        DiscreteAveragingAsianOption::new(
+            average_type,
+            running_accumulator,
+            0,
+            fixing_dates,
+            payoff,
+            exercise,
+        )
+    }
+
+    // Inspectors
+    pub fn average_type(&self) -> AverageType {
+        self.average_type
+    }
+    pub fn running_accumulator(&self) -> Real {
+        self.running_accumulator
+    }
+    pub fn past_fixings(&self) -> Size {
+        self.past_fixings
+    }
+    // Fixings still to come, sorted
+    pub fn fixing_dates(&self) -> &[Date] {
+        &self.fixing_dates
+    }
+    pub fn payoff(&self) -> &PlainVanillaPayoff {
+        &self.payoff
+    }
+    pub fn exercise(&self) -> &EuropeanExercise {
+        &self.exercise
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::option::OptionType;
+    use crate::time::date::Month;
+
+    #[test]
+    fn fixing_dates_are_sorted() {
+        let start: Date = Date::new(15, Month::January, 2025);
+        let option: DiscreteAveragingAsianOption =
+            DiscreteAveragingAsianOption::with_future_fixings(
+                AverageType::Geometric,
+                &[start + 60, start + 30, start + 90],
+                PlainVanillaPayoff::new(OptionType::Call, 100.0),
+                EuropeanExercise::new(start + 90),
+            );
+        assert_eq!(option.fixing_dates(), &[start + 30, start + 60, start + 90]);
+        assert_eq!(option.running_accumulator(), 1.0);
+        assert_eq!(option.past_fixings(), 0);
+
+        let result = std::panic::catch_unwind(|| {
+            DiscreteAveragingAsianOption::with_future_fixings(
+                AverageType::Arithmetic,
+                &[],
+                PlainVanillaPayoff::new(OptionType::Call, 100.0),
+                EuropeanExercise::new(start + 90),
+            )
+        });
+        assert!(result.is_err(), "expected panic for no fixings");
+    }
+}
@@ -0,0 +1,111 @@
+use crate::exercise::EuropeanExercise;
+use crate::instruments::payoffs::PlainVanillaPayoff;
+use crate::types::Real;
+use std::fmt::{Display, Formatter, Result};
+
+// QuantLib Barrier::Type
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum BarrierType {
+    DownIn,
+    UpIn,
+    DownOut,
+    UpOut,
+}
+impl BarrierType {
+    // Whether the barrier is hit by the given underlying value
+    pub fn triggered(&self, underlying: Real, barrier: Real) -> bool {
+        match self {
+            BarrierType::DownIn | BarrierType::DownOut => underlying < barrier,
+            BarrierType::UpIn | BarrierType::UpOut => underlying > barrier,
+        }
+    }
+}
+
+// Traits
+impl Display for BarrierType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let barrier_type: &'static str = match self {
+            BarrierType::DownIn => "Down-and-in",
+            BarrierType::UpIn => "Up-and-in",
+            BarrierType::DownOut => "Down-and-out",
+            BarrierType::UpOut => "Up-and-out",
+        };
+        write!(f, "{barrier_type}")
+    }
+}
+
+/*
+Single-barrier option (QuantLib BarrierOption): a European option that
+comes into existence (knock-in) or ceases to exist (knock-out) when the
+underlying crosses the barrier, monitored continuously. The rebate is
+paid at expiry if a knock-in option was never activated, and when the
+barrier is hit for a knock-out one.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct BarrierOption {
+    barrier_type: BarrierType,
+    barrier: Real,
+    rebate: Real,
+    payoff: PlainVanillaPayoff,
+    exercise: EuropeanExercise,
+}
+impl BarrierOption {
+    // Constructor
+    pub fn new(
+        barrier_type: BarrierType,
+        barrier: Real,
+        rebate: Real,
+        payoff: PlainVanillaPayoff,
+        exercise: EuropeanExercise,
+    ) -> BarrierOption {
+        if barrier <= 0.0 {
+            panic!("barrier ({barrier}) must be positive");
+        }
+        if rebate < 0.0 {
+            panic!("rebate ({rebate}) must be non-negative");
+        }
+        BarrierOption {
+            barrier_type,
+            barrier,
+            rebate,
+            payoff,
+            exercise,
+        }
+    }
+
+    // Inspectors
+    pub fn barrier_type(&self) -> BarrierType {
+        self.barrier_type
+    }
+    pub fn barrier(&self) -> Real {
+        self.barrier
+    }
+    pub fn rebate(&self) -> Real {
+        self.rebate
+    }
+    pub fn payoff(&self) -> &PlainVanillaPayoff {
+        &self.payoff
+    }
+    pub fn exercise(&self) -> &EuropeanExercise {
+        &self.exercise
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn barriers_are_triggered_from_their_side() {
+        let cases: [(BarrierType, Real, bool, &str); 4] = [
+            (BarrierType::DownIn, 89.0, true, "Down-and-in"),
+            (BarrierType::DownOut, 90.0, false, "Down-and-out"),
+            (BarrierType::UpIn, 90.0, false, "Up-and-in"),
+            (BarrierType::UpOut, 91.0, true, "Up-and-out"),
+        ];
+        for (barrier_type, underlying, triggered, name) in cases {
+            assert_eq!(barrier_type.triggered(underlying, 90.0), triggered);
+            assert_eq!(format!("{barrier_type}"), name);
+        }
+    }
+}
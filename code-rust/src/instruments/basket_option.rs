@@ -0,0 +1,142 @@
+use crate::exercise::EuropeanExercise;
+use crate::instruments::payoffs::{Payoff, PlainVanillaPayoff};
+use crate::types::{Real, Size};
+
+/*
+Payoffs on several underlyings (QuantLib BasketPayoff and its
+subclasses): the prices are first reduced to a single value, which is
+then paid through the vanilla base payoff.
+    - Min: the lowest price
+    - Max: the highest price
+    - Average: the weighted sum of the prices
+    - Spread: the difference of two prices, S_1 - S_2
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub enum BasketPayoff {
+    Min(PlainVanillaPayoff),
+    Max(PlainVanillaPayoff),
+    Average(PlainVanillaPayoff, Vec<Real>),
+    Spread(PlainVanillaPayoff),
+}
+impl BasketPayoff {
+    // Average with equal weights 1 / n
+    pub fn equally_weighted_average(payoff: PlainVanillaPayoff, n: Size) -> BasketPayoff {
+        if n == 0 {
+            panic!("at least one underlying is required");
+        }
+        BasketPayoff::Average(payoff, vec![1.0 / n as Real; n])
+    }
+
+    // Inspectors
+    pub fn base_payoff(&self) -> &PlainVanillaPayoff {
+        match self {
+            BasketPayoff::Min(payoff)
+            | BasketPayoff::Max(payoff)
+            | BasketPayoff::Average(payoff, _)
+            | BasketPayoff::Spread(payoff) => payoff,
+        }
+    }
+
+    // The single value the base payoff is applied to
+    pub fn accumulate(&self, prices: &[Real]) -> Real {
+        if prices.is_empty() {
+            panic!("no prices given");
+        }
+        match self {
+            BasketPayoff::Min(_) => prices.iter().copied().fold(Real::MAX, Real::min),
+            BasketPayoff::Max(_) => prices.iter().copied().fold(Real::MIN, Real::max),
+            BasketPayoff::Average(_, weights) => {
+                if weights.len() != prices.len() {
+                    panic!(
+                        "{} weights given for {} prices",
+                        weights.len(),
+                        prices.len()
+                    );
+                }
+                weights.iter().zip(prices.iter()).map(|(w, p)| w * p).sum()
+            }
+            BasketPayoff::Spread(_) => {
+                if prices.len() != 2 {
+                    panic!("spread payoff requires 2 prices, {} given", prices.len());
+                }
+                prices[0] - prices[1]
+            }
+        }
+    }
+    pub fn value(&self, prices: &[Real]) -> Real {
+        self.base_payoff().value(self.accumulate(prices))
+    }
+}
+
+// European option on a basket of underlyings (QuantLib BasketOption)
+#[derive(Clone, PartialEq, Debug)]
+pub struct BasketOption {
+    payoff: BasketPayoff,
+    exercise: EuropeanExercise,
+}
+impl BasketOption {
+    // Constructor
+    pub fn new(payoff: BasketPayoff, exercise: EuropeanExercise) -> BasketOption {
+        BasketOption { payoff, exercise }
+    }
+
+    // Inspectors
+    pub fn payoff(&self) -> &BasketPayoff {
+        &self.payoff
+    }
+    pub fn exercise(&self) -> &EuropeanExercise {
+        &self.exercise
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::option::OptionType;
+    use std::panic;
+
+    #[test]
+    fn payoffs_reduce_the_prices() {
+        let call: PlainVanillaPayoff = PlainVanillaPayoff::new(OptionType::Call, 100.0);
+        let put: PlainVanillaPayoff = PlainVanillaPayoff::new(OptionType::Put, 10.0);
+        let prices: [Real; 3] = [90.0, 120.0, 105.0];
+        let cases: [(BasketPayoff, &[Real], Real); 5] = [
+            (BasketPayoff::Min(call), &prices, 0.0),
+            (BasketPayoff::Max(call), &prices, 20.0),
+            (
+                BasketPayoff::equally_weighted_average(call, 3),
+                &prices,
+                5.0,
+            ),
+            (
+                BasketPayoff::Average(call, vec![0.5, 0.25, 0.25]),
+                &prices,
+                1.25,
+            ),
+            (BasketPayoff::Spread(put), &[100.0, 95.0], 5.0),
+        ];
+
+        for (payoff, prices, expected) in cases {
+            assert!(
+                (payoff.value(prices) - expected).abs() < 1.0e-12,
+                "{:?}: {}",
+                payoff,
+                payoff.value(prices)
+            );
+        }
+    }
+
+    #[test]
+    fn accumulate_panics_on_wrong_number_of_prices() {
+        let call: PlainVanillaPayoff = PlainVanillaPayoff::new(OptionType::Call, 100.0);
+        let cases: [(BasketPayoff, &[Real]); 3] = [
+            (BasketPayoff::Max(call), &[]),
+            (BasketPayoff::Average(call, vec![0.5, 0.5]), &[100.0]),
+            (BasketPayoff::Spread(call), &[100.0, 90.0, 80.0]),
+        ];
+        for (payoff, prices) in cases {
+            let result = panic::catch_unwind(|| payoff.accumulate(prices));
+            assert!(result.is_err(), "expected panic for {payoff:?}");
+        }
+    }
+}
@@ -0,0 +1,507 @@
+use crate::cashflows::fixed_rate_coupon::FixedRateCoupon;
+use crate::math::solvers1d::brent::Brent;
+use crate::time::calendar::Calendar;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::time::frequency::Frequency;
+use crate::time::schedule::Schedule;
+use crate::types::{Rate, Real, Size};
+
+/*
+Bond paying fixed-rate coupons on the periods of its schedule, which
+starts on the issue date, and redeeming its notional as it amortizes
+and at maturity (QuantLib Bond). The constructors build the fixed-rate,
+zero-coupon and amortizing fixed-rate bonds of QuantLib.
+
+Settlement happens settlement_days business days of the calendar after
+the trade date, and not before the issue date. Prices are quoted per 100 of
+the notional outstanding on the settlement date, and the cash flows
+paid on that date belong to the seller. Coupons accrue with the bond
+day counter, which also measures the times of the yield calculations:
+a yield y compounded f times a year discounts a cash flow t years after
+settlement by (1 + y / f)^(-f t).
+*/
+pub struct Bond {
+    settlement_days: i32,
+    calendar: Box<dyn Calendar>,
+    issue_date: Date,
+    coupons: Vec<FixedRateCoupon>,
+    redemptions: Vec<(Date, Real)>,
+    day_counter: Box<dyn DayCounter>,
+}
+impl Bond {
+    const MAX_BRACKET_EXPANSIONS: Size = 100;
+
+    // Constructors
+    // QuantLib FixedRateBond: the face amount is redeemed at maturity
+    pub fn fixed_rate(
+        settlement_days: i32,
+        calendar: Box<dyn Calendar>,
+        face_amount: Real,
+        schedule: &Schedule,
+        coupon_rate: Rate,
+        day_counter: Box<dyn DayCounter>,
+    ) -> Bond {
+        Bond::amortizing_fixed_rate(
+            settlement_days,
+            calendar,
+            &[face_amount],
+            schedule,
+            coupon_rate,
+            day_counter,
+        )
+    }
+    // QuantLib ZeroCouponBond: the face amount is redeemed at maturity without coupons
+    pub fn zero_coupon(
+        settlement_days: i32,
+        calendar: Box<dyn Calendar>,
+        face_amount: Real,
+        issue_date: Date,
+        maturity_date: Date,
+        day_counter: Box<dyn DayCounter>,
+    ) -> Bond {
+        detail::check_settlement_days(settlement_days);
+        if face_amount <= 0.0 {
+            panic!("face amount ({face_amount}) must be positive");
+        }
+        if maturity_date <= issue_date {
+            panic!("maturity ({maturity_date}) must be after the issue date ({issue_date})");
+        }
+        Bond {
+            settlement_days,
+            calendar,
+            issue_date,
+            coupons: Vec::new(),
+            redemptions: vec![(maturity_date, face_amount)],
+            day_counter,
+        }
+    }
+    /*
+    QuantLib AmortizingFixedRateBond: notionals[i] is outstanding over
+    the i-th coupon period, the last one being repeated when fewer
+    notionals than periods are given, and each decrease is redeemed at
+    the end of the period.
+    */
+    pub fn amortizing_fixed_rate(
+        settlement_days: i32,
+        calendar: Box<dyn Calendar>,
+        notionals: &[Real],
+        schedule: &Schedule,
+        coupon_rate: Rate,
+        day_counter: Box<dyn DayCounter>,
+    ) -> Bond {
+        detail::check_settlement_days(settlement_days);
+        let schedule: &[Date] = schedule.dates();
+        if notionals.is_empty() {
+            panic!("no notional given");
+        }
+        if notionals[notionals.len() - 1] <= 0.0 {
+            panic!(
+                "final notional ({}) must be positive",
+                notionals[notionals.len() - 1]
+            );
+        }
+        if notionals.windows(2).any(|pair| pair[1] > pair[0]) {
+            panic!("notionals must not increase");
+        }
+        let periods: Size = schedule.len() - 1;
+        let notional = |i: Size| -> Real { notionals[i.min(notionals.len() - 1)] };
+        let coupons: Vec<FixedRateCoupon> = schedule
+            .windows(2)
+            .enumerate()
+            .map(|(i, period)| {
+                FixedRateCoupon::new(
+                    notional(i),
+                    coupon_rate,
+                    period[0],
+                    period[1],
+                    day_counter.as_ref(),
+                )
+            })
+            .collect();
+        let redemptions: Vec<(Date, Real)> = (0..periods)
+            .map(|i| {
+                let next: Real = if i + 1 < periods {
+                    notional(i + 1)
+                } else {
+                    0.0
+                };
+                (schedule[i + 1], notional(i) - next)
+            })
+            .filter(|(_, amount)| *amount > 0.0)
+            .collect();
+        Bond {
+            settlement_days,
+            calendar,
+            issue_date: schedule[0],
+            coupons,
+            redemptions,
+            day_counter,
+        }
+    }
+
+    // Inspectors
+    pub fn settlement_days(&self) -> i32 {
+        self.settlement_days
+    }
+    pub fn calendar(&self) -> &dyn Calendar {
+        self.calendar.as_ref()
+    }
+    pub fn issue_date(&self) -> Date {
+        self.issue_date
+    }
+    pub fn coupons(&self) -> &[FixedRateCoupon] {
+        &self.coupons
+    }
+    pub fn redemptions(&self) -> &[(Date, Real)] {
+        &self.redemptions
+    }
+    pub fn day_counter(&self) -> &dyn DayCounter {
+        self.day_counter.as_ref()
+    }
+    pub fn maturity_date(&self) -> Date {
+        self.redemptions[self.redemptions.len() - 1].0
+    }
+
+    pub fn settlement_date(&self, trade_date: Date) -> Date {
+        detail::settlement_date(
+            self.calendar.as_ref(),
+            self.settlement_days,
+            trade_date,
+            self.issue_date,
+        )
+    }
+    // Payment dates and amounts of the coupons and redemptions, sorted by date
+    pub fn cash_flows(&self) -> Vec<(Date, Real)> {
+        let mut cash_flows: Vec<(Date, Real)> = self
+            .coupons
+            .iter()
+            .map(|coupon| (coupon.date(), coupon.amount()))
+            .chain(self.redemptions.iter().copied())
+            .collect();
+        cash_flows.sort_by_key(|(date, _)| *date);
+        cash_flows
+    }
+    // Notional still to be redeemed after settlement
+    pub fn notional(&self, settlement_date: Date) -> Real {
+        self.redemptions
+            .iter()
+            .filter(|(date, _)| *date > settlement_date)
+            .map(|(_, amount)| amount)
+            .sum()
+    }
+    // Accrued interest per 100 of outstanding notional
+    pub fn accrued_amount(&self, settlement_date: Date) -> Real {
+        let notional: Real = self.notional(settlement_date);
+        if notional == 0.0 {
+            return 0.0;
+        }
+        let accrued: Real = self
+            .coupons
+            .iter()
+            .map(|coupon| coupon.accrued_amount(settlement_date, self.day_counter.as_ref()))
+            .sum();
+        100.0 * accrued / notional
+    }
+
+    pub fn dirty_price_from_yield(
+        &self,
+        bond_yield: Rate,
+        frequency: Frequency,
+        settlement_date: Date,
+    ) -> Real {
+        let f: Real = detail::compounding_frequency(frequency);
+        if bond_yield <= -f {
+            panic!("yield ({bond_yield}) must be greater than -{f}");
+        }
+        let notional: Real = self.notional(settlement_date);
+        if notional == 0.0 {
+            panic!("bond redeemed by the settlement date ({settlement_date})");
+        }
+        let value: Real = self
+            .cash_flows()
+            .iter()
+            .filter(|(date, _)| *date > settlement_date)
+            .map(|(date, amount)| {
+                let t: Real = self.day_counter.year_fraction(settlement_date, *date);
+                amount * (1.0 + bond_yield / f).powf(-f * t)
+            })
+            .sum();
+        100.0 * value / notional
+    }
+    pub fn clean_price_from_yield(
+        &self,
+        bond_yield: Rate,
+        frequency: Frequency,
+        settlement_date: Date,
+    ) -> Real {
+        self.dirty_price_from_yield(bond_yield, frequency, settlement_date)
+            - self.accrued_amount(settlement_date)
+    }
+    /*
+    Yield giving clean_price, found by Brent. The price decreases with
+    the yield; the bracket starts at [-f / 2, 100%] and its upper bound
+    doubles, or its lower bound halves its distance to -f, until the
+    price is bracketed.
+    */
+    pub fn bond_yield(
+        &self,
+        clean_price: Real,
+        frequency: Frequency,
+        settlement_date: Date,
+        accuracy: Real,
+    ) -> Rate {
+        let f: Real = detail::compounding_frequency(frequency);
+        let dirty_price: Real = clean_price + self.accrued_amount(settlement_date);
+        let error = |y: Rate| -> Real {
+            self.dirty_price_from_yield(y, frequency, settlement_date) - dirty_price
+        };
+        let (mut low, mut high): (Rate, Rate) = (-0.5 * f, 1.0);
+        let mut expansions: Size = 0;
+        while error(high) > 0.0 {
+            high *= 2.0;
+            expansions += 1;
+            if expansions > Bond::MAX_BRACKET_EXPANSIONS {
+                panic!("no yield gives the clean price {clean_price}");
+            }
+        }
+        while error(low) < 0.0 {
+            low = 0.5 * (low - f);
+            expansions += 1;
+            if expansions > Bond::MAX_BRACKET_EXPANSIONS {
+                panic!("no yield gives the clean price {clean_price}");
+            }
+        }
+        Brent::new().solve(error, accuracy, low, high)
+    }
+}
+
+pub(crate) mod detail {
+    use crate::time::business_day_convention::BusinessDayConvention;
+    use crate::time::calendar::Calendar;
+    use crate::time::date::Date;
+    use crate::time::frequency::Frequency;
+    use crate::time::time_unit::TimeUnit;
+    use crate::types::Real;
+
+    pub(crate) fn check_settlement_days(settlement_days: i32) {
+        if settlement_days < 0 {
+            panic!("negative settlement days ({settlement_days}) not allowed");
+        }
+    }
+
+    // Settlement days business days after the trade date, not before the issue date
+    pub(crate) fn settlement_date(
+        calendar: &dyn Calendar,
+        settlement_days: i32,
+        trade_date: Date,
+        issue_date: Date,
+    ) -> Date {
+        calendar
+            .advance(
+                trade_date,
+                settlement_days,
+                TimeUnit::Days,
+                BusinessDayConvention::Following,
+                false,
+            )
+            .max(issue_date)
+    }
+
+    // Compounding periods per year of a yield
+    pub(crate) fn compounding_frequency(frequency: Frequency) -> Real {
+        match frequency {
+            Frequency::NoFrequency | Frequency::Once | Frequency::OtherFrequency => {
+                panic!("{frequency} frequency not allowed for yields")
+            }
+            _ => frequency as i32 as Real,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::calendars::target::Target;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::period::Period;
+    use crate::time::time_unit::TimeUnit;
+
+    fn issue_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    // Periods of 365 days, each accruing exactly one year
+    fn schedule(years: i32) -> Schedule {
+        Schedule::unadjusted(
+            issue_date(),
+            issue_date() + 365 * years,
+            Period::new(365, TimeUnit::Days),
+        )
+    }
+
+    #[test]
+    fn fixed_rate_bond_cash_flows_and_accrued_interest() {
+        let bond: Bond = Bond::fixed_rate(
+            2,
+            Box::new(Target::new()),
+            1.0e6,
+            &schedule(3),
+            0.05,
+            Box::new(Actual365Fixed::new()),
+        );
+        let cash_flows: Vec<(Date, Real)> = bond.cash_flows();
+        assert_eq!(cash_flows.len(), 4);
+        assert_eq!(cash_flows[0], (issue_date() + 365, 5.0e4));
+        assert_eq!(cash_flows[3], (bond.maturity_date(), 1.0e6));
+        assert_eq!(bond.maturity_date(), issue_date() + 1095);
+
+        // Trading on Friday 100 days after issue settles two business days later, on Tuesday
+        let settlement_date: Date = bond.settlement_date(issue_date() + 100);
+        assert_eq!(settlement_date, issue_date() + 104);
+        assert_eq!(bond.notional(settlement_date), 1.0e6);
+        let accrued: Real = bond.accrued_amount(settlement_date);
+        assert!((accrued - 5.0 * 104.0 / 365.0).abs() < 1.0e-12);
+        // Nothing accrues on a payment date, nor after maturity
+        assert_eq!(bond.accrued_amount(issue_date() + 365), 0.0);
+        assert_eq!(bond.accrued_amount(issue_date() + 2000), 0.0);
+        assert_eq!(bond.settlement_date(issue_date() - 10), issue_date());
+    }
+
+    #[test]
+    fn prices_and_yields_are_consistent() {
+        let bond: Bond = Bond::fixed_rate(
+            0,
+            Box::new(Target::new()),
+            100.0,
+            &schedule(5),
+            0.04,
+            Box::new(Actual365Fixed::new()),
+        );
+        // On a coupon date, a bond yielding its annual coupon rate is worth par
+        let price: Real = bond.clean_price_from_yield(0.04, Frequency::Annual, issue_date() + 365);
+        assert!((price - 100.0).abs() < 1.0e-12);
+        let cases: [(Rate, Frequency, Date); 4] = [
+            (0.04, Frequency::Semiannual, issue_date() + 100),
+            (0.07, Frequency::Annual, issue_date() + 500),
+            (-0.01, Frequency::Quarterly, issue_date() + 900),
+            (0.02, Frequency::Monthly, issue_date()),
+        ];
+        for (bond_yield, frequency, settlement_date) in cases {
+            let clean: Real = bond.clean_price_from_yield(bond_yield, frequency, settlement_date);
+            let dirty: Real = bond.dirty_price_from_yield(bond_yield, frequency, settlement_date);
+            assert!((dirty - clean - bond.accrued_amount(settlement_date)).abs() < 1.0e-12);
+            let implied: Rate = bond.bond_yield(clean, frequency, settlement_date, 1.0e-12);
+            assert!(
+                (implied - bond_yield).abs() < 1.0e-10,
+                "{frequency} {bond_yield}: {implied}"
+            );
+        }
+    }
+
+    #[test]
+    fn zero_coupon_bond_discounts_its_face_amount() {
+        let bond: Bond = Bond::zero_coupon(
+            1,
+            Box::new(Target::new()),
+            100.0,
+            issue_date(),
+            issue_date() + 730,
+            Box::new(Actual365Fixed::new()),
+        );
+        assert!(bond.coupons().is_empty());
+        let settlement_date: Date = bond.settlement_date(issue_date());
+        assert_eq!(bond.accrued_amount(settlement_date), 0.0);
+        let price: Real = bond.clean_price_from_yield(0.03, Frequency::Annual, settlement_date);
+        let expected: Real = 100.0 * 1.03_f64.powf(-729.0 / 365.0);
+        assert!((price - expected).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn amortizing_bond_redeems_each_notional_decrease() {
+        let bond: Bond = Bond::amortizing_fixed_rate(
+            0,
+            Box::new(Target::new()),
+            &[100.0, 60.0, 30.0],
+            &schedule(4),
+            0.05,
+            Box::new(Actual365Fixed::new()),
+        );
+        let redemptions: [(Date, Real); 3] = [
+            (issue_date() + 365, 40.0),
+            (issue_date() + 730, 30.0),
+            (issue_date() + 1460, 30.0),
+        ];
+        assert_eq!(bond.redemptions(), &redemptions);
+        let nominals: Vec<Real> = bond.coupons().iter().map(|c| c.nominal()).collect();
+        assert_eq!(nominals, vec![100.0, 60.0, 30.0, 30.0]);
+        assert_eq!(bond.notional(issue_date() + 400), 60.0);
+        // Accrued interest and prices are per 100 of outstanding notional
+        assert!((bond.accrued_amount(issue_date() + 400) - 5.0 * 35.0 / 365.0).abs() < 1.0e-12);
+        for settlement_date in [issue_date(), issue_date() + 730] {
+            let price: Real = bond.clean_price_from_yield(0.05, Frequency::Annual, settlement_date);
+            assert!((price - 100.0).abs() < 1.0e-12);
+        }
+    }
+
+    #[test]
+    fn invalid_input_panics() {
+        let cases: [(Vec<Real>, i32, i32, &str); 4] = [
+            (vec![100.0], 0, 0, "empty schedule"),
+            (vec![], 2, 0, "no notionals"),
+            (vec![50.0, 100.0], 2, 0, "increasing notionals"),
+            (vec![100.0], 2, -1, "negative settlement days"),
+        ];
+        for (notionals, years, settlement_days, label) in cases {
+            let result = std::panic::catch_unwind(|| {
+                Bond::amortizing_fixed_rate(
+                    settlement_days,
+                    Box::new(Target::new()),
+                    &notionals,
+                    &schedule(years),
+                    0.05,
+                    Box::new(Actual365Fixed::new()),
+                )
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+        let result = std::panic::catch_unwind(|| {
+            Bond::fixed_rate(
+                0,
+                Box::new(Target::new()),
+                100.0,
+                &schedule(2),
+                0.05,
+                Box::new(Actual365Fixed::new()),
+            )
+            .dirty_price_from_yield(0.05, Frequency::Once, issue_date())
+        });
+        assert!(
+            result.is_err(),
+            "expected panic for a yield compounded once"
+        );
+    }
+
+    #[test]
+    fn yields_above_one_hundred_percent_are_found() {
+        let bond: Bond = Bond::fixed_rate(
+            0,
+            Box::new(Target::new()),
+            100.0,
+            &schedule(5),
+            0.04,
+            Box::new(Actual365Fixed::new()),
+        );
+        for bond_yield in [1.5, 25.0, -1.9] {
+            let clean: Real =
+                bond.clean_price_from_yield(bond_yield, Frequency::Semiannual, issue_date());
+            let implied: Rate =
+                bond.bond_yield(clean, Frequency::Semiannual, issue_date(), 1.0e-12);
+            assert!(
+                (implied - bond_yield).abs() < 1.0e-8 * bond_yield.abs(),
+                "{bond_yield}: {implied}"
+            );
+        }
+    }
+}
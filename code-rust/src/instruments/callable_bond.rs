@@ -0,0 +1,204 @@
+use crate::instruments::bond::Bond;
+use crate::time::calendar::Calendar;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::time::schedule::Schedule;
+use crate::types::{Rate, Real};
+use std::fmt::{Display, Formatter, Result};
+
+// QuantLib Callability::Type: the issuer may call the bond, the holder may put it
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum CallabilityType {
+    Call,
+    Put,
+}
+
+// Traits
+impl Display for CallabilityType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let callability_type: &'static str = match self {
+            CallabilityType::Call => "Call",
+            CallabilityType::Put => "Put",
+        };
+        write!(f, "{callability_type}")
+    }
+}
+
+// Right to redeem the bond on date at a clean price per 100 of notional (QuantLib Callability)
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Callability {
+    callability_type: CallabilityType,
+    price: Real,
+    date: Date,
+}
+impl Callability {
+    // Constructor
+    pub fn new(callability_type: CallabilityType, price: Real, date: Date) -> Callability {
+        if price <= 0.0 {
+            panic!("{callability_type} price ({price}) must be positive");
+        }
+        Callability {
+            callability_type,
+            price,
+            date,
+        }
+    }
+
+    // Inspectors
+    pub fn callability_type(&self) -> CallabilityType {
+        self.callability_type
+    }
+    pub fn price(&self) -> Real {
+        self.price
+    }
+    pub fn date(&self) -> Date {
+        self.date
+    }
+}
+
+/*
+Fixed-rate bond with embedded calls and puts (QuantLib
+CallableFixedRateBond): a fixed-rate bond which the issuer may redeem
+on its call dates, and the holder on its put dates, at the clean price
+of the callability plus the accrued interest. Callability dates must
+be strictly increasing and fall strictly between the issue date and
+maturity.
+*/
+pub struct CallableFixedRateBond {
+    bond: Bond,
+    callabilities: Vec<Callability>,
+}
+impl CallableFixedRateBond {
+    // Constructor
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        settlement_days: i32,
+        calendar: Box<dyn Calendar>,
+        face_amount: Real,
+        schedule: &Schedule,
+        coupon_rate: Rate,
+        day_counter: Box<dyn DayCounter>,
+        callabilities: &[Callability],
+    ) -> CallableFixedRateBond {
+        let bond: Bond = Bond::fixed_rate(
+            settlement_days,
+            calendar,
+            face_amount,
+            schedule,
+            coupon_rate,
+            day_counter,
+        );
+        if callabilities
+            .windows(2)
+            .any(|pair| pair[1].date() <= pair[0].date())
+        {
+            panic!("callability dates must be strictly increasing");
+        }
+        for callability in callabilities {
+            if callability.date() <= bond.issue_date() || callability.date() >= bond.maturity_date()
+            {
+                panic!(
+                    "{} date ({}) must be between the issue date ({}) and maturity ({})",
+                    callability.callability_type(),
+                    callability.date(),
+                    bond.issue_date(),
+                    bond.maturity_date()
+                );
+            }
+        }
+        CallableFixedRateBond {
+            bond,
+            callabilities: callabilities.to_vec(),
+        }
+    }
+
+    // Inspectors
+    pub fn bond(&self) -> &Bond {
+        &self.bond
+    }
+    pub fn callabilities(&self) -> &[Callability] {
+        &self.callabilities
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::calendars::target::Target;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::period::Period;
+    use crate::time::time_unit::TimeUnit;
+
+    fn issue_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn callable_bond(callabilities: &[Callability]) -> CallableFixedRateBond {
+        CallableFixedRateBond::new(
+            2,
+            Box::new(Target::new()),
+            100.0,
+            &Schedule::unadjusted(
+                issue_date(),
+                Date::new(15, Month::January, 2030),
+                Period::new(1, TimeUnit::Years),
+            ),
+            0.05,
+            Box::new(Actual365Fixed::new()),
+            callabilities,
+        )
+    }
+
+    #[test]
+    fn callabilities_are_kept_in_date_order() {
+        let callabilities: [Callability; 2] = [
+            Callability::new(
+                CallabilityType::Call,
+                101.0,
+                Date::new(15, Month::January, 2027),
+            ),
+            Callability::new(
+                CallabilityType::Put,
+                99.0,
+                Date::new(15, Month::January, 2028),
+            ),
+        ];
+        let bond: CallableFixedRateBond = callable_bond(&callabilities);
+        assert_eq!(bond.callabilities(), &callabilities);
+        assert_eq!(
+            bond.bond().maturity_date(),
+            Date::new(15, Month::January, 2030)
+        );
+        assert_eq!(format!("{}", callabilities[1].callability_type()), "Put");
+    }
+
+    #[test]
+    fn constructor_panics_on_invalid_callabilities() {
+        let call = |day: i32, month: Month, year: i32| {
+            Callability::new(CallabilityType::Call, 100.0, Date::new(day, month, year))
+        };
+        let cases: [(Vec<Callability>, &str); 3] = [
+            (
+                vec![
+                    call(15, Month::January, 2028),
+                    call(15, Month::January, 2027),
+                ],
+                "unsorted dates",
+            ),
+            (
+                vec![call(15, Month::January, 2025)],
+                "call on the issue date",
+            ),
+            (vec![call(15, Month::January, 2031)], "call after maturity"),
+        ];
+        for (callabilities, label) in cases {
+            let result = std::panic::catch_unwind(|| callable_bond(&callabilities));
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+        let result = std::panic::catch_unwind(|| {
+            Callability::new(CallabilityType::Put, 0.0, issue_date() + 365)
+        });
+        assert!(result.is_err(), "expected panic for a zero put price");
+    }
+}
@@ -0,0 +1,291 @@
+use crate::cashflows::ibor_coupon::IborCoupon;
+use crate::indexes::ibor_index::IborIndex;
+use crate::math::solvers1d::newton_safe::NewtonSafe;
+use crate::pricingengines::capfloor::black_cap_floor_engine::detail::calculate;
+use crate::pricingengines::capfloor::cap_floor_results::CapFloorResults;
+use crate::termstructures::volatility::volatility_type::VolatilityType;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::date::Date;
+use crate::types::{Rate, Real, Size, Volatility};
+use std::fmt::{Display, Formatter, Result};
+use std::rc::Rc;
+
+// QuantLib CapFloor::Type: a collar is long the cap and short the floor
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum CapFloorType {
+    Cap,
+    Floor,
+    Collar,
+}
+
+/*
+Cap, floor or collar on a leg of Ibor coupons (QuantLib CapFloor): a
+strip of caplets max(L - K_cap, 0) and/or floorlets max(K_floor - L, 0)
+paid at the end of each accrual period on the coupon nominal.
+
+When fewer strikes than coupons are given, the last strike is used for
+the remaining coupons. The strikes not relevant for the type are
+dropped: a cap has no floor rates and a floor no cap rates. Coupons
+fixed before the valuation date pay the fixings of the index set with
+with_index, which is then required.
+*/
+#[derive(Clone)]
+pub struct CapFloor {
+    cap_floor_type: CapFloorType,
+    floating_leg: Vec<IborCoupon>,
+    cap_rates: Vec<Rate>,
+    floor_rates: Vec<Rate>,
+    index: Option<Rc<IborIndex>>,
+}
+impl CapFloor {
+    const MIN_VOLATILITY: Volatility = 1.0e-7;
+    const MAX_VOLATILITY: Volatility = 4.0;
+
+    // Constructors
+    pub fn new(
+        cap_floor_type: CapFloorType,
+        floating_leg: Vec<IborCoupon>,
+        cap_rates: &[Rate],
+        floor_rates: &[Rate],
+    ) -> CapFloor {
+        if floating_leg.is_empty() {
+            panic!("empty floating leg");
+        }
+        let has_cap: bool = cap_floor_type != CapFloorType::Floor;
+        let has_floor: bool = cap_floor_type != CapFloorType::Cap;
+        if has_cap && cap_rates.is_empty() {
+            panic!("no cap rates given");
+        }
+        if has_floor && floor_rates.is_empty() {
+            panic!("no floor rates given");
+        }
+        let n: Size = floating_leg.len();
+        CapFloor {
+            cap_floor_type,
+            cap_rates: if has_cap {
+                detail::extend(cap_rates, n)
+            } else {
+                Vec::new()
+            },
+            floor_rates: if has_floor {
+                detail::extend(floor_rates, n)
+            } else {
+                Vec::new()
+            },
+            floating_leg,
+            index: None,
+        }
+    }
+    pub fn cap(floating_leg: Vec<IborCoupon>, cap_rates: &[Rate]) -> CapFloor {
+        CapFloor::new(CapFloorType::Cap, floating_leg, cap_rates, &[])
+    }
+    pub fn floor(floating_leg: Vec<IborCoupon>, floor_rates: &[Rate]) -> CapFloor {
+        CapFloor::new(CapFloorType::Floor, floating_leg, &[], floor_rates)
+    }
+    pub fn collar(
+        floating_leg: Vec<IborCoupon>,
+        cap_rates: &[Rate],
+        floor_rates: &[Rate],
+    ) -> CapFloor {
+        CapFloor::new(CapFloorType::Collar, floating_leg, cap_rates, floor_rates)
+    }
+
+    // Modifiers
+    pub fn with_index(mut self, index: Rc<IborIndex>) -> CapFloor {
+        self.index = Some(index);
+        self
+    }
+
+    // Inspectors
+    pub fn cap_floor_type(&self) -> CapFloorType {
+        self.cap_floor_type
+    }
+    pub fn floating_leg(&self) -> &[IborCoupon] {
+        &self.floating_leg
+    }
+    pub fn cap_rates(&self) -> &[Rate] {
+        &self.cap_rates
+    }
+    pub fn floor_rates(&self) -> &[Rate] {
+        &self.floor_rates
+    }
+    pub fn index(&self) -> Option<&IborIndex> {
+        self.index.as_deref()
+    }
+    pub fn start_date(&self) -> Date {
+        self.floating_leg[0].accrual_start_date()
+    }
+    pub fn maturity_date(&self) -> Date {
+        self.floating_leg[self.floating_leg.len() - 1].date()
+    }
+
+    /*
+    Flat volatility reproducing the target value (QuantLib
+    CapFloor::impliedVolatility): the same volatility for all the
+    optionlets, priced on the discount curve with fixing times measured
+    by its day counter. Solved with NewtonSafe on the vega over [1e-7, 4].
+    */
+    #[allow(clippy::too_many_arguments)]
+    pub fn implied_volatility(
+        &self,
+        target_value: Real,
+        discount_curve: &dyn YieldTermStructure,
+        guess: Volatility,
+        accuracy: Real,
+        max_evaluations: Size,
+        volatility_type: VolatilityType,
+        displacement: Real,
+    ) -> Volatility {
+        let results = |volatility: Volatility| -> CapFloorResults {
+            calculate(
+                self,
+                discount_curve,
+                discount_curve.reference_date(),
+                discount_curve.day_counter(),
+                &|_, _| volatility,
+                volatility_type,
+                displacement,
+            )
+        };
+        let mut solver: NewtonSafe = NewtonSafe::new();
+        solver.set_max_evaluations(max_evaluations);
+        solver.solve(
+            |volatility| results(volatility).value - target_value,
+            |volatility| results(volatility).vega,
+            accuracy,
+            guess,
+            CapFloor::MIN_VOLATILITY,
+            CapFloor::MAX_VOLATILITY,
+        )
+    }
+}
+
+mod detail {
+    use crate::types::{Rate, Size};
+
+    // Strikes for all the coupons, repeating the last one given
+    pub(super) fn extend(rates: &[Rate], n: Size) -> Vec<Rate> {
+        (0..n).map(|i| rates[i.min(rates.len() - 1)]).collect()
+    }
+}
+
+// Traits
+impl Display for CapFloorType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let cap_floor_type: &'static str = match self {
+            CapFloorType::Cap => "Cap",
+            CapFloorType::Floor => "Floor",
+            CapFloorType::Collar => "Collar",
+        };
+        write!(f, "{cap_floor_type}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cashflows::ibor_coupon::IborLeg;
+    use crate::termstructures::yieldcurves::flat_forward::FlatForward;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+
+    fn leg() -> Vec<IborCoupon> {
+        let start: Date = Date::new(15, Month::January, 2025);
+        let schedule: Vec<Date> = (1..=8).map(|k| start + 182 * k).collect();
+        IborLeg::new(&schedule)
+            .with_notionals(&[1.0e6])
+            .coupons(&Actual365Fixed::new())
+    }
+
+    #[test]
+    fn strikes_are_extended_to_the_whole_leg() {
+        let collar: CapFloor = CapFloor::collar(leg(), &[0.04, 0.05], &[0.01]);
+        assert_eq!(collar.cap_floor_type(), CapFloorType::Collar);
+        assert_eq!(
+            collar.cap_rates(),
+            &[0.04, 0.05, 0.05, 0.05, 0.05, 0.05, 0.05]
+        );
+        assert_eq!(collar.floor_rates(), &[0.01; 7]);
+        let cap: CapFloor = CapFloor::cap(leg(), &[0.04]);
+        assert!(cap.floor_rates().is_empty());
+        assert_eq!(cap.start_date(), leg()[0].accrual_start_date());
+        assert_eq!(cap.maturity_date(), leg()[6].date());
+        assert_eq!(format!("{}", CapFloorType::Floor), "Floor");
+    }
+
+    #[test]
+    fn implied_volatility_round_trip() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let discount_curve: FlatForward =
+            FlatForward::new(reference_date, 0.03, Box::new(Actual365Fixed::new()));
+        let cases: [(CapFloorType, VolatilityType, Volatility, Real); 4] = [
+            (
+                CapFloorType::Cap,
+                VolatilityType::ShiftedLognormal,
+                0.25,
+                0.0,
+            ),
+            (
+                CapFloorType::Floor,
+                VolatilityType::ShiftedLognormal,
+                0.4,
+                0.01,
+            ),
+            (CapFloorType::Cap, VolatilityType::Normal, 0.008, 0.0),
+            (CapFloorType::Collar, VolatilityType::Normal, 0.012, 0.0),
+        ];
+        for (cap_floor_type, volatility_type, volatility, displacement) in cases {
+            let cap_floor: CapFloor = CapFloor::new(cap_floor_type, leg(), &[0.035], &[0.02]);
+            let target: Real = calculate(
+                &cap_floor,
+                &discount_curve,
+                reference_date,
+                &Actual365Fixed::new(),
+                &|_, _| volatility,
+                volatility_type,
+                displacement,
+            )
+            .value;
+            let implied: Volatility = cap_floor.implied_volatility(
+                target,
+                &discount_curve,
+                0.1,
+                1.0e-12,
+                100,
+                volatility_type,
+                displacement,
+            );
+            assert!(
+                (implied - volatility).abs() < 1.0e-9,
+                "{cap_floor_type} {volatility_type}: {implied} vs {volatility}"
+            );
+        }
+    }
+
+    #[test]
+    fn new_panics_on_missing_data() {
+        let cases: [(CapFloorType, &[Rate], &[Rate], &str); 3] = [
+            (CapFloorType::Cap, &[], &[0.02], "cap without cap rates"),
+            (
+                CapFloorType::Floor,
+                &[0.04],
+                &[],
+                "floor without floor rates",
+            ),
+            (
+                CapFloorType::Collar,
+                &[0.04],
+                &[],
+                "collar without floor rates",
+            ),
+        ];
+        for (cap_floor_type, cap_rates, floor_rates, label) in cases {
+            let result = std::panic::catch_unwind(|| {
+                CapFloor::new(cap_floor_type, leg(), cap_rates, floor_rates)
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+        let result = std::panic::catch_unwind(|| CapFloor::cap(Vec::new(), &[0.04]));
+        assert!(result.is_err(), "expected panic for empty leg");
+    }
+}
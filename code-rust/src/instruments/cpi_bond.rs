@@ -0,0 +1,146 @@
+use crate::cashflows::cpi_coupon::{CpiCashFlow, CpiCoupon};
+use crate::indexes::inflation_index::{CpiInterpolationType, ZeroInflationIndex};
+use crate::termstructures::inflation::zero_inflation_term_structure::ZeroInflationTermStructure;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::time::period::Period;
+use crate::types::{Rate, Real};
+
+/*
+Inflation-linked bond (QuantLib CPIBond): CPI coupons at fixed_rate on
+each period of the schedule [start, payment_1, ..., payment_n] and the
+face amount redeemed at maturity, both indexed by the ratio of the
+lagged CPI to base_cpi.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct CpiBond {
+    face_amount: Real,
+    index: ZeroInflationIndex,
+    coupons: Vec<CpiCoupon>,
+    redemption: CpiCashFlow,
+}
+impl CpiBond {
+    // Constructor
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        face_amount: Real,
+        base_cpi: Real,
+        observation_lag: Period,
+        index: ZeroInflationIndex,
+        interpolation: CpiInterpolationType,
+        schedule: &[Date],
+        fixed_rate: Rate,
+        accrual_day_counter: &dyn DayCounter,
+    ) -> CpiBond {
+        if schedule.len() < 2 {
+            panic!("at least two schedule dates required");
+        }
+        let coupons: Vec<CpiCoupon> = schedule
+            .windows(2)
+            .map(|period| {
+                CpiCoupon::new(
+                    face_amount,
+                    fixed_rate,
+                    base_cpi,
+                    period[0],
+                    period[1],
+                    accrual_day_counter,
+                    observation_lag,
+                    interpolation,
+                )
+            })
+            .collect();
+        let redemption: CpiCashFlow = CpiCashFlow::new(
+            face_amount,
+            base_cpi,
+            schedule[schedule.len() - 1],
+            observation_lag,
+            interpolation,
+            false,
+        );
+        CpiBond {
+            face_amount,
+            index,
+            coupons,
+            redemption,
+        }
+    }
+
+    // Inspectors
+    pub fn face_amount(&self) -> Real {
+        self.face_amount
+    }
+    pub fn index(&self) -> &ZeroInflationIndex {
+        &self.index
+    }
+    pub fn coupons(&self) -> &[CpiCoupon] {
+        &self.coupons
+    }
+    pub fn redemption(&self) -> &CpiCashFlow {
+        &self.redemption
+    }
+    pub fn maturity_date(&self) -> Date {
+        self.redemption.date()
+    }
+
+    // Payment dates and amounts of the coupons followed by the redemption
+    pub fn cash_flows(
+        &self,
+        forecast: Option<&dyn ZeroInflationTermStructure>,
+    ) -> Vec<(Date, Real)> {
+        self.coupons
+            .iter()
+            .map(|coupon| (coupon.date(), coupon.amount(&self.index, forecast)))
+            .chain([(
+                self.redemption.date(),
+                self.redemption.amount(&self.index, forecast),
+            )])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::frequency::Frequency;
+    use crate::time::time_unit::TimeUnit;
+
+    #[test]
+    fn cash_flows_are_indexed_coupons_and_redemption() {
+        let mut index: ZeroInflationIndex =
+            ZeroInflationIndex::new("CPI", Frequency::Monthly, Period::new(1, TimeUnit::Months));
+        index.add_fixing(Date::new(1, Month::October, 2024), 101.0);
+        index.add_fixing(Date::new(1, Month::April, 2025), 102.0);
+        let bond: CpiBond = CpiBond::new(
+            100.0,
+            100.0,
+            Period::new(2, TimeUnit::Months),
+            index,
+            CpiInterpolationType::Flat,
+            &[
+                Date::new(1, Month::June, 2024),
+                Date::new(1, Month::December, 2024),
+                Date::new(1, Month::June, 2025),
+            ],
+            0.02,
+            &Actual365Fixed::new(),
+        );
+        let cash_flows: Vec<(Date, Real)> = bond.cash_flows(None);
+        assert_eq!(cash_flows.len(), 3);
+        assert_eq!(bond.maturity_date(), Date::new(1, Month::June, 2025));
+        let expected: [(Date, Real); 3] = [
+            (
+                Date::new(1, Month::December, 2024),
+                2.0 * 183.0 / 365.0 * 1.01,
+            ),
+            (Date::new(1, Month::June, 2025), 2.0 * 182.0 / 365.0 * 1.02),
+            (Date::new(1, Month::June, 2025), 102.0),
+        ];
+        for ((date, amount), (expected_date, expected_amount)) in cash_flows.iter().zip(expected) {
+            assert_eq!(*date, expected_date);
+            assert!((amount - expected_amount).abs() < 1.0e-12);
+        }
+    }
+}
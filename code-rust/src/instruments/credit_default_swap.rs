@@ -0,0 +1,359 @@
+use crate::time::date::{Date, Month};
+use crate::time::day_counter::DayCounter;
+use crate::time::daycounters::actual360::Actual360;
+use crate::time::period::Period;
+use crate::time::time_unit::TimeUnit;
+use crate::types::{Rate, Real};
+use std::fmt::{Display, Formatter, Result};
+
+// QuantLib Protection::Side: the buyer pays the premium and is paid on default
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ProtectionSide {
+    Seller = -1,
+    Buyer = 1,
+}
+impl ProtectionSide {
+    // +1 for the protection buyer and -1 for the seller
+    pub fn sign(&self) -> f64 {
+        *self as i32 as f64
+    }
+}
+
+// Traits
+impl Display for ProtectionSide {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let side: &'static str = match self {
+            ProtectionSide::Seller => "Seller",
+            ProtectionSide::Buyer => "Buyer",
+        };
+        write!(f, "{side}")
+    }
+}
+
+/*
+Credit default swap (QuantLib CreditDefaultSwap): the protection buyer
+pays an upfront amount (upfront times notional) on the trade date and
+a running spread on the premium schedule [start, payment_1, ...,
+payment_n]; the seller pays notional times (1 - recovery) if the
+reference entity defaults between the protection start and the
+maturity. Accrued premium is settled on default.
+
+Premium periods accrue Actual/360, the last one including its end
+date, as in the ISDA standard. Protection starts on the trade date
+(or on the schedule start for forward-starting trades); the buyer pays
+the whole first coupon and is rebated, on the trade date, the premium
+accrued from the schedule start to the protection start. There are no
+calendars yet, so dates are not adjusted to business days.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct CreditDefaultSwap {
+    side: ProtectionSide,
+    notional: Real,
+    upfront: Rate,
+    running_spread: Rate,
+    schedule: Vec<Date>,
+    trade_date: Date,
+    protection_start: Date,
+    accruals: Vec<Real>,
+}
+impl CreditDefaultSwap {
+    // Constructors
+    pub fn new(
+        side: ProtectionSide,
+        notional: Real,
+        upfront: Rate,
+        running_spread: Rate,
+        schedule: &[Date],
+        trade_date: Date,
+    ) -> CreditDefaultSwap {
+        if notional <= 0.0 {
+            panic!("notional ({notional}) must be positive");
+        }
+        if running_spread < 0.0 {
+            panic!("negative running spread ({running_spread}) not allowed");
+        }
+        if schedule.len() < 2 {
+            panic!("at least two schedule dates required");
+        }
+        if schedule.windows(2).any(|pair| pair[1] <= pair[0]) {
+            panic!("schedule dates must be increasing");
+        }
+        let maturity: Date = schedule[schedule.len() - 1];
+        if trade_date >= maturity {
+            panic!("trade date ({trade_date}) must be before the maturity ({maturity})");
+        }
+        let n: usize = schedule.len() - 1;
+        let accruals: Vec<Real> = schedule
+            .windows(2)
+            .enumerate()
+            .map(|(i, period)| detail::accrual(period[0], period[1], i == n - 1))
+            .collect();
+        CreditDefaultSwap {
+            side,
+            notional,
+            upfront,
+            running_spread,
+            schedule: schedule.to_vec(),
+            trade_date,
+            protection_start: trade_date.max(schedule[0]),
+            accruals,
+        }
+    }
+    // Standard contract traded on trade_date, on the CDS2015 schedule for tenor
+    pub fn cds2015(
+        side: ProtectionSide,
+        notional: Real,
+        upfront: Rate,
+        running_spread: Rate,
+        trade_date: Date,
+        tenor: Period,
+    ) -> CreditDefaultSwap {
+        CreditDefaultSwap::new(
+            side,
+            notional,
+            upfront,
+            running_spread,
+            &cds2015_schedule(trade_date, tenor),
+            trade_date,
+        )
+    }
+
+    // Inspectors
+    pub fn side(&self) -> ProtectionSide {
+        self.side
+    }
+    pub fn notional(&self) -> Real {
+        self.notional
+    }
+    pub fn upfront(&self) -> Rate {
+        self.upfront
+    }
+    pub fn running_spread(&self) -> Rate {
+        self.running_spread
+    }
+    pub fn schedule(&self) -> &[Date] {
+        &self.schedule
+    }
+    pub fn trade_date(&self) -> Date {
+        self.trade_date
+    }
+    pub fn protection_start(&self) -> Date {
+        self.protection_start
+    }
+    pub fn maturity(&self) -> Date {
+        self.schedule[self.schedule.len() - 1]
+    }
+    // Accrual periods of the premium coupons
+    pub fn accruals(&self) -> &[Real] {
+        &self.accruals
+    }
+    pub fn coupon_amount(&self, i: usize) -> Real {
+        self.notional * self.running_spread * self.accruals[i]
+    }
+    // Accrual period of coupon i elapsed at date
+    pub fn accrued_period(&self, i: usize, date: Date) -> Real {
+        let start: Date = self.schedule[i];
+        if date <= start {
+            return 0.0;
+        }
+        let end: Date = date.min(self.schedule[i + 1]);
+        let last: bool = i == self.schedule.len() - 2 && end == self.schedule[i + 1];
+        detail::accrual(start, end, last)
+    }
+    // Premium of coupon i accrued up to date, as paid on a default at that date
+    pub fn accrued_amount(&self, i: usize, date: Date) -> Real {
+        self.notional * self.running_spread * self.accrued_period(i, date)
+    }
+    // Premium rebated to the buyer on the trade date
+    pub fn accrual_rebate(&self) -> Real {
+        self.accrued_amount(0, self.protection_start)
+    }
+}
+
+/*
+Maturity of a standard CDS traded on trade_date (QuantLib cdsMaturity
+with the CDS2015 rule): maturities roll semi-annually on the 20th of
+March and September, to the 20th of June and December after tenor.
+*/
+pub fn cds_maturity(trade_date: Date, tenor: Period) -> Date {
+    if tenor.length() <= 0 || !matches!(tenor.units(), TimeUnit::Months | TimeUnit::Years) {
+        panic!("tenor ({tenor}) must be a positive number of months or years");
+    }
+    let mut anchor: Date = previous_twentieth(trade_date);
+    if matches!(anchor.month(), Month::June | Month::December) {
+        anchor = Date::advance(anchor, -3, TimeUnit::Months);
+    }
+    Date::advance(anchor + tenor, 3, TimeUnit::Months)
+}
+
+/*
+Premium schedule of a standard CDS (QuantLib DateGeneration::CDS2015):
+quarterly dates on the 20th of March, June, September and December,
+from the last one on or before the trade date to the maturity.
+*/
+pub fn cds2015_schedule(trade_date: Date, tenor: Period) -> Vec<Date> {
+    let maturity: Date = cds_maturity(trade_date, tenor);
+    let mut schedule: Vec<Date> = vec![previous_twentieth(trade_date)];
+    while schedule[schedule.len() - 1] < maturity {
+        let next: Date = Date::advance(schedule[schedule.len() - 1], 3, TimeUnit::Months);
+        schedule.push(next);
+    }
+    schedule
+}
+
+// Last 20th of March, June, September or December on or before date
+pub fn previous_twentieth(date: Date) -> Date {
+    let mut result: Date = Date::new(20, date.month(), date.year());
+    if result > date {
+        result = Date::advance(result, -1, TimeUnit::Months);
+    }
+    let skip: i32 = result.month() as i32 % 3;
+    Date::advance(result, -skip, TimeUnit::Months)
+}
+
+mod detail {
+    use super::*;
+
+    // Actual/360 accrual, including the end date for the last coupon
+    pub(super) fn accrual(start: Date, end: Date, last: bool) -> Real {
+        if last {
+            Actual360::including_last_day().year_fraction(start, end)
+        } else {
+            Actual360::new().year_fraction(start, end)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_and_sign_of_protection_sides() {
+        let cases: [(ProtectionSide, &str, f64); 2] = [
+            (ProtectionSide::Buyer, "Buyer", 1.0),
+            (ProtectionSide::Seller, "Seller", -1.0),
+        ];
+        for (side, name, sign) in cases {
+            assert_eq!(format!("{side}"), name);
+            assert_eq!(side.sign(), sign);
+        }
+    }
+
+    #[test]
+    fn maturities_roll_on_march_and_september_20th() {
+        let five_years: Period = Period::new(5, TimeUnit::Years);
+        let cases: [(Date, Date, Date); 5] = [
+            (
+                Date::new(15, Month::February, 2025),
+                Date::new(20, Month::December, 2024),
+                Date::new(20, Month::December, 2029),
+            ),
+            (
+                Date::new(20, Month::March, 2025),
+                Date::new(20, Month::March, 2025),
+                Date::new(20, Month::June, 2030),
+            ),
+            (
+                Date::new(1, Month::July, 2025),
+                Date::new(20, Month::June, 2025),
+                Date::new(20, Month::June, 2030),
+            ),
+            (
+                Date::new(19, Month::September, 2025),
+                Date::new(20, Month::June, 2025),
+                Date::new(20, Month::June, 2030),
+            ),
+            (
+                Date::new(25, Month::November, 2025),
+                Date::new(20, Month::September, 2025),
+                Date::new(20, Month::December, 2030),
+            ),
+        ];
+        for (trade_date, previous, maturity) in cases {
+            assert_eq!(previous_twentieth(trade_date), previous, "{trade_date}");
+            assert_eq!(
+                cds_maturity(trade_date, five_years),
+                maturity,
+                "{trade_date}"
+            );
+        }
+        assert_eq!(
+            cds_maturity(
+                Date::new(15, Month::February, 2025),
+                Period::new(6, TimeUnit::Months)
+            ),
+            Date::new(20, Month::June, 2025)
+        );
+    }
+
+    #[test]
+    fn standard_contract_schedule_and_coupons() {
+        let trade_date: Date = Date::new(15, Month::February, 2025);
+        let cds: CreditDefaultSwap = CreditDefaultSwap::cds2015(
+            ProtectionSide::Buyer,
+            1.0e7,
+            0.02,
+            0.01,
+            trade_date,
+            Period::new(1, TimeUnit::Years),
+        );
+        assert_eq!(
+            cds.schedule(),
+            &[
+                Date::new(20, Month::December, 2024),
+                Date::new(20, Month::March, 2025),
+                Date::new(20, Month::June, 2025),
+                Date::new(20, Month::September, 2025),
+                Date::new(20, Month::December, 2025),
+            ]
+        );
+        assert_eq!(cds.protection_start(), trade_date);
+        assert_eq!(cds.maturity(), Date::new(20, Month::December, 2025));
+        assert_eq!(cds.accruals()[0], 90.0 / 360.0);
+        assert_eq!(cds.accruals()[3], 92.0 / 360.0);
+        assert!((cds.coupon_amount(1) - 1.0e5 * 92.0 / 360.0).abs() < 1.0e-9);
+        assert!((cds.accrual_rebate() - 1.0e5 * 57.0 / 360.0).abs() < 1.0e-9);
+        assert_eq!(cds.accrued_amount(2, Date::new(20, Month::June, 2025)), 0.0);
+        assert!(
+            (cds.accrued_amount(3, Date::new(20, Month::December, 2025)) - cds.coupon_amount(3))
+                .abs()
+                < 1.0e-9
+        );
+    }
+
+    #[test]
+    fn invalid_contracts_panic() {
+        let trade_date: Date = Date::new(15, Month::February, 2025);
+        let schedule: Vec<Date> = vec![trade_date, trade_date + 90];
+        let cases: [(Real, Rate, Vec<Date>, Date, &str); 5] = [
+            (0.0, 0.01, schedule.clone(), trade_date, "null notional"),
+            (1.0, -0.01, schedule.clone(), trade_date, "negative spread"),
+            (1.0, 0.01, vec![trade_date], trade_date, "single date"),
+            (
+                1.0,
+                0.01,
+                vec![trade_date + 90, trade_date],
+                trade_date,
+                "decreasing schedule",
+            ),
+            (1.0, 0.01, schedule, trade_date + 90, "trade at maturity"),
+        ];
+        for (notional, spread, schedule, trade, label) in cases {
+            let result = std::panic::catch_unwind(|| {
+                CreditDefaultSwap::new(
+                    ProtectionSide::Buyer,
+                    notional,
+                    0.0,
+                    spread,
+                    &schedule,
+                    trade,
+                )
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+        let result =
+            std::panic::catch_unwind(|| cds_maturity(trade_date, Period::new(10, TimeUnit::Days)));
+        assert!(result.is_err(), "expected panic for a tenor in days");
+    }
+}
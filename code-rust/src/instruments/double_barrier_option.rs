@@ -0,0 +1,135 @@
+use crate::exercise::EuropeanExercise;
+use crate::instruments::payoffs::PlainVanillaPayoff;
+use crate::types::Real;
+use std::fmt::{Display, Formatter, Result};
+
+// QuantLib DoubleBarrier::Type
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum DoubleBarrierType {
+    // Activated when either barrier is hit
+    KnockIn,
+    // Cancelled when either barrier is hit
+    KnockOut,
+    // Activated by the lower barrier, cancelled by the upper one
+    KIKO,
+    // Cancelled by the lower barrier, activated by the upper one
+    KOKI,
+}
+
+// Traits
+impl Display for DoubleBarrierType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let barrier_type: &'static str = match self {
+            DoubleBarrierType::KnockIn => "KnockIn",
+            DoubleBarrierType::KnockOut => "KnockOut",
+            DoubleBarrierType::KIKO => "KI lo+KO hi",
+            DoubleBarrierType::KOKI => "KO lo+KI hi",
+        };
+        write!(f, "{barrier_type}")
+    }
+}
+
+/*
+Double-barrier option (QuantLib DoubleBarrierOption): a European option
+with a lower and an upper barrier, monitored continuously. The rebate
+is paid as for single barriers.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct DoubleBarrierOption {
+    barrier_type: DoubleBarrierType,
+    barrier_low: Real,
+    barrier_high: Real,
+    rebate: Real,
+    payoff: PlainVanillaPayoff,
+    exercise: EuropeanExercise,
+}
+impl DoubleBarrierOption {
+    // Constructor
+    pub fn new(
+        barrier_type: DoubleBarrierType,
+        barrier_low: Real,
+        barrier_high: Real,
+        rebate: Real,
+        payoff: PlainVanillaPayoff,
+        exercise: EuropeanExercise,
+    ) -> DoubleBarrierOption {
+        if barrier_low <= 0.0 || barrier_high <= barrier_low {
+            panic!(
+                "invalid barriers: low ({barrier_low}) must be positive and below high ({barrier_high})"
+            );
+        }
+        if rebate < 0.0 {
+            panic!("rebate ({rebate}) must be non-negative");
+        }
+        DoubleBarrierOption {
+            barrier_type,
+            barrier_low,
+            barrier_high,
+            rebate,
+            payoff,
+            exercise,
+        }
+    }
+
+    // Inspectors
+    pub fn barrier_type(&self) -> DoubleBarrierType {
+        self.barrier_type
+    }
+    pub fn barrier_low(&self) -> Real {
+        self.barrier_low
+    }
+    pub fn barrier_high(&self) -> Real {
+        self.barrier_high
+    }
+    pub fn rebate(&self) -> Real {
+        self.rebate
+    }
+    pub fn payoff(&self) -> &PlainVanillaPayoff {
+        &self.payoff
+    }
+    pub fn exercise(&self) -> &EuropeanExercise {
+        &self.exercise
+    }
+
+    // Whether the underlying is outside the corridor
+    pub fn triggered(&self, underlying: Real) -> bool {
+        underlying <= self.barrier_low || underlying >= self.barrier_high
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::option::OptionType;
+    use crate::time::date::{Date, Month};
+
+    #[test]
+    fn corridor_is_between_the_barriers() {
+        let exercise: EuropeanExercise = EuropeanExercise::new(Date::new(15, Month::July, 2025));
+        let payoff: PlainVanillaPayoff = PlainVanillaPayoff::new(OptionType::Call, 100.0);
+        let option: DoubleBarrierOption = DoubleBarrierOption::new(
+            DoubleBarrierType::KnockOut,
+            80.0,
+            120.0,
+            0.0,
+            payoff,
+            exercise.clone(),
+        );
+        assert!(option.triggered(80.0));
+        assert!(!option.triggered(100.0));
+        assert!(option.triggered(125.0));
+        assert_eq!(format!("{}", DoubleBarrierType::KIKO), "KI lo+KO hi");
+
+        let result = std::panic::catch_unwind(|| {
+            DoubleBarrierOption::new(
+                DoubleBarrierType::KnockIn,
+                120.0,
+                80.0,
+                0.0,
+                payoff,
+                exercise,
+            )
+        });
+        assert!(result.is_err(), "expected panic for inverted barriers");
+    }
+}
@@ -0,0 +1,52 @@
+use crate::instruments::swap::SwapType;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::date::Date;
+use crate::types::{Rate, Real, Spread, Time};
+
+/*
+Swap exchanging a fixed leg for a floating leg plus a spread on the
+same nominal (QuantLib FixedVsFloatingSwap): a payer swap pays the
+fixed leg. Implemented by VanillaSwap on Ibor coupons and by
+OvernightIndexedSwap on compounded overnight coupons.
+*/
+pub trait FixedVsFloatingSwap {
+    fn swap_type(&self) -> SwapType;
+    fn nominal(&self) -> Real;
+    // Start date of the swap followed by its fixed payment dates
+    fn fixed_schedule(&self) -> &[Date];
+    fn fixed_rate(&self) -> Rate;
+    fn fixed_accruals(&self) -> &[Real];
+    fn spread(&self) -> Spread;
+    // Accrual start, payment date and accrual period of each floating coupon
+    fn floating_periods(&self) -> Vec<(Date, Date, Time)>;
+    // Rate of the i-th floating coupon, from the index fixings and the forecast curve
+    fn floating_rate(&self, i: usize, forecast_curve: &dyn YieldTermStructure) -> Rate;
+
+    fn start_date(&self) -> Date {
+        self.fixed_schedule()[0]
+    }
+    fn maturity_date(&self) -> Date {
+        self.fixed_schedule()[self.fixed_schedule().len() - 1]
+    }
+}
+
+pub(crate) mod detail {
+    use crate::time::date::Date;
+
+    pub(crate) fn check_schedules(fixed_schedule: &[Date], floating_schedule: &[Date]) {
+        if fixed_schedule.len() < 2 {
+            panic!("at least two fixed schedule dates required");
+        }
+        if floating_schedule.len() < 2 {
+            panic!("at least two floating schedule dates required");
+        }
+        if fixed_schedule[0] != floating_schedule[0]
+            || fixed_schedule.last() != floating_schedule.last()
+        {
+            panic!("fixed and floating legs must start and end on the same dates");
+        }
+        if fixed_schedule.windows(2).any(|pair| pair[1] <= pair[0]) {
+            panic!("fixed schedule dates must be increasing");
+        }
+    }
+}
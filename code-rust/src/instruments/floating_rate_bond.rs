@@ -0,0 +1,228 @@
+use crate::cashflows::ibor_coupon::{IborCoupon, IborLeg};
+use crate::indexes::ibor_index::IborIndex;
+use crate::instruments::bond::detail;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::calendar::Calendar;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::time::schedule::Schedule;
+use crate::types::{Rate, Real, Spread};
+use std::rc::Rc;
+
+/*
+Bond paying Ibor coupons plus a spread on the periods of its schedule,
+which starts on the issue date, and redeeming its face amount at
+maturity (QuantLib FloatingRateBond). Coupons fix the fixing days of
+the index before their accrual start and accrue with the bond day
+counter.
+
+Coupon amounts depend on the index: those fixed before the reference
+date of the forecast curve pay the stored fixings of the index, the
+others are forecast on the curve (see IborCoupon). Settlement and
+prices follow Bond.
+*/
+pub struct FloatingRateBond {
+    settlement_days: i32,
+    calendar: Box<dyn Calendar>,
+    face_amount: Real,
+    index: Rc<IborIndex>,
+    spread: Spread,
+    coupons: Vec<IborCoupon>,
+    day_counter: Box<dyn DayCounter>,
+}
+impl FloatingRateBond {
+    // Constructor
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        settlement_days: i32,
+        calendar: Box<dyn Calendar>,
+        face_amount: Real,
+        schedule: &Schedule,
+        index: Rc<IborIndex>,
+        spread: Spread,
+        day_counter: Box<dyn DayCounter>,
+    ) -> FloatingRateBond {
+        detail::check_settlement_days(settlement_days);
+        if face_amount <= 0.0 {
+            panic!("face amount ({face_amount}) must be positive");
+        }
+        let coupons: Vec<IborCoupon> = IborLeg::new(schedule.dates())
+            .with_notionals(&[face_amount])
+            .with_index(&index)
+            .coupons(day_counter.as_ref());
+        FloatingRateBond {
+            settlement_days,
+            calendar,
+            face_amount,
+            index,
+            spread,
+            coupons,
+            day_counter,
+        }
+    }
+
+    // Inspectors
+    pub fn settlement_days(&self) -> i32 {
+        self.settlement_days
+    }
+    pub fn calendar(&self) -> &dyn Calendar {
+        self.calendar.as_ref()
+    }
+    pub fn face_amount(&self) -> Real {
+        self.face_amount
+    }
+    pub fn index(&self) -> &IborIndex {
+        self.index.as_ref()
+    }
+    pub fn spread(&self) -> Spread {
+        self.spread
+    }
+    pub fn coupons(&self) -> &[IborCoupon] {
+        &self.coupons
+    }
+    pub fn day_counter(&self) -> &dyn DayCounter {
+        self.day_counter.as_ref()
+    }
+    pub fn issue_date(&self) -> Date {
+        self.coupons[0].accrual_start_date()
+    }
+    pub fn maturity_date(&self) -> Date {
+        self.coupons[self.coupons.len() - 1].date()
+    }
+
+    pub fn settlement_date(&self, trade_date: Date) -> Date {
+        detail::settlement_date(
+            self.calendar.as_ref(),
+            self.settlement_days,
+            trade_date,
+            self.issue_date(),
+        )
+    }
+    // Rate paid by the i-th coupon, spread included
+    pub fn coupon_rate(&self, i: usize, forecast_curve: &dyn YieldTermStructure) -> Rate {
+        self.coupons[i].rate(&self.index, forecast_curve) + self.spread
+    }
+    // Payment dates and amounts of the coupons and redemption paid after the reference date of the curve
+    pub fn cash_flows(&self, forecast_curve: &dyn YieldTermStructure) -> Vec<(Date, Real)> {
+        let reference_date: Date = forecast_curve.reference_date();
+        self.coupons
+            .iter()
+            .enumerate()
+            .filter(|(_, coupon)| coupon.date() > reference_date)
+            .map(|(i, coupon)| {
+                let amount: Real = coupon.nominal()
+                    * coupon.accrual_period()
+                    * self.coupon_rate(i, forecast_curve);
+                (coupon.date(), amount)
+            })
+            .chain([(self.maturity_date(), self.face_amount)])
+            .filter(|(date, _)| *date > reference_date)
+            .collect()
+    }
+    // Notional still to be redeemed after settlement
+    pub fn notional(&self, settlement_date: Date) -> Real {
+        if self.maturity_date() > settlement_date {
+            self.face_amount
+        } else {
+            0.0
+        }
+    }
+    // Accrued interest per 100 of outstanding notional
+    pub fn accrued_amount(
+        &self,
+        settlement_date: Date,
+        forecast_curve: &dyn YieldTermStructure,
+    ) -> Real {
+        match self.coupons.iter().position(|coupon| {
+            coupon.accrual_start_date() < settlement_date && settlement_date < coupon.date()
+        }) {
+            Some(i) => {
+                let coupon: &IborCoupon = &self.coupons[i];
+                let accrual: Real = self
+                    .day_counter
+                    .year_fraction(coupon.accrual_start_date(), settlement_date);
+                100.0 * accrual * self.coupon_rate(i, forecast_curve)
+            }
+            None => 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::business_day_convention::BusinessDayConvention;
+    use crate::time::calendars::target::Target;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual360::Actual360;
+    use crate::time::period::Period;
+    use crate::time::schedule::DateGenerationRule;
+    use crate::time::time_unit::TimeUnit;
+
+    fn bond(index: IborIndex, spread: Spread) -> FloatingRateBond {
+        let schedule: Schedule = Schedule::new(
+            Date::new(15, Month::January, 2024),
+            Date::new(15, Month::January, 2027),
+            Period::new(6, TimeUnit::Months),
+            &Target::new(),
+            BusinessDayConvention::ModifiedFollowing,
+            DateGenerationRule::Backward,
+            false,
+        );
+        FloatingRateBond::new(
+            2,
+            Box::new(Target::new()),
+            100.0,
+            &schedule,
+            Rc::new(index),
+            spread,
+            Box::new(Actual360::new()),
+        )
+    }
+
+    #[test]
+    fn coupons_pay_fixings_and_forecasts_plus_spread() {
+        let reference_date: Date = Date::new(10, Month::March, 2025);
+        let curve: Rc<dyn YieldTermStructure> = flat_rate(reference_date, 0.03);
+        let mut index: IborIndex = IborIndex::euribor(Period::new(6, TimeUnit::Months));
+        index.add_fixing(Date::new(13, Month::January, 2025), 0.025);
+        let bond: FloatingRateBond = bond(index, 0.001);
+        assert_eq!(bond.coupons().len(), 6);
+        assert_eq!(bond.maturity_date(), Date::new(15, Month::January, 2027));
+        assert_eq!(
+            bond.coupons()[2].fixing_date(),
+            Date::new(13, Month::January, 2025)
+        );
+
+        let cash_flows: Vec<(Date, Real)> = bond.cash_flows(curve.as_ref());
+        // The first two coupons are paid: their fixings are not needed
+        assert_eq!(cash_flows.len(), 5);
+        assert!((cash_flows[0].1 - 100.0 * 181.0 / 360.0 * 0.026).abs() < 1.0e-12);
+        let forward: Rate = bond.coupons()[3].forward_rate(curve.as_ref());
+        assert!(
+            (cash_flows[1].1 - 100.0 * bond.coupons()[3].accrual_period() * (forward + 0.001))
+                .abs()
+                < 1.0e-12
+        );
+        assert_eq!(cash_flows[4], (bond.maturity_date(), 100.0));
+
+        // Settles on Wednesday 12 March 2025, 56 days into the coupon fixed at 2.5%
+        let settlement_date: Date = bond.settlement_date(reference_date);
+        assert_eq!(settlement_date, Date::new(12, Month::March, 2025));
+        let accrued: Real = bond.accrued_amount(settlement_date, curve.as_ref());
+        assert!((accrued - 100.0 * 56.0 / 360.0 * 0.026).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn coupons_fixed_in_the_past_need_their_fixings() {
+        let result = std::panic::catch_unwind(|| {
+            let curve: Rc<dyn YieldTermStructure> =
+                flat_rate(Date::new(10, Month::March, 2025), 0.03);
+            let bond: FloatingRateBond =
+                bond(IborIndex::euribor(Period::new(6, TimeUnit::Months)), 0.0);
+            bond.cash_flows(curve.as_ref())
+        });
+        assert!(result.is_err(), "expected panic for a missing fixing");
+    }
+}
@@ -0,0 +1,115 @@
+use crate::currency::Currency;
+use crate::exchange_rate::ExchangeRate;
+use crate::money::Money;
+use crate::time::date::Date;
+use crate::types::Real;
+
+/*
+Outright FX forward (QuantLib FxForward): on the maturity date one
+party pays the source nominal and receives the target nominal, the
+other party does the opposite. pay_source_receive_target tells which
+side the holder of the instrument is on.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct FxForward {
+    source_nominal: Money,
+    target_nominal: Money,
+    maturity_date: Date,
+    pay_source_receive_target: bool,
+}
+impl FxForward {
+    // Constructor
+    pub fn new(
+        source_nominal: Money,
+        target_nominal: Money,
+        maturity_date: Date,
+        pay_source_receive_target: bool,
+    ) -> FxForward {
+        if source_nominal.currency() == target_nominal.currency() {
+            panic!(
+                "source and target nominals must be in different currencies ({})",
+                source_nominal.currency()
+            );
+        }
+        if source_nominal.value() <= 0.0 || target_nominal.value() <= 0.0 {
+            panic!("nominals ({source_nominal}, {target_nominal}) must be positive");
+        }
+        FxForward {
+            source_nominal,
+            target_nominal,
+            maturity_date,
+            pay_source_receive_target,
+        }
+    }
+    // Forward exchanging the source nominal at the given rate
+    pub fn with_forward_rate(
+        source_nominal: Money,
+        forward_rate: &ExchangeRate,
+        maturity_date: Date,
+        pay_source_receive_target: bool,
+    ) -> FxForward {
+        FxForward::new(
+            source_nominal,
+            forward_rate.exchange(&source_nominal),
+            maturity_date,
+            pay_source_receive_target,
+        )
+    }
+
+    // Inspectors
+    pub fn source_nominal(&self) -> Money {
+        self.source_nominal
+    }
+    pub fn target_nominal(&self) -> Money {
+        self.target_nominal
+    }
+    pub fn source_currency(&self) -> Currency {
+        self.source_nominal.currency()
+    }
+    pub fn target_currency(&self) -> Currency {
+        self.target_nominal.currency()
+    }
+    pub fn maturity_date(&self) -> Date {
+        self.maturity_date
+    }
+    pub fn pay_source_receive_target(&self) -> bool {
+        self.pay_source_receive_target
+    }
+    // Agreed rate: units of target currency per unit of source currency
+    pub fn forward_rate(&self) -> ExchangeRate {
+        let rate: Real = self.target_nominal.value() / self.source_nominal.value();
+        ExchangeRate::new(self.source_currency(), self.target_currency(), rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::currencies::america::USD;
+    use crate::currencies::europe::EUR;
+    use crate::time::date::Month;
+
+    #[test]
+    fn forward_rate_is_implied_by_the_nominals() {
+        let maturity: Date = Date::new(15, Month::July, 2026);
+        let forward: FxForward = FxForward::with_forward_rate(
+            1.0e6 * EUR,
+            &ExchangeRate::new(USD, EUR, 1.0 / 1.1),
+            maturity,
+            true,
+        );
+        assert_eq!(forward.target_currency(), USD);
+        assert!((forward.target_nominal().value() - 1.1e6).abs() < 1.0e-6);
+        assert!((forward.forward_rate().rate() - 1.1).abs() < 1.0e-12);
+
+        let cases: [(Money, Money, &str); 2] = [
+            (1.0e6 * EUR, 1.1e6 * EUR, "same currency"),
+            (1.0e6 * EUR, -1.1e6 * USD, "negative nominal"),
+        ];
+        for (source, target, label) in cases {
+            let result =
+                std::panic::catch_unwind(|| FxForward::new(source, target, maturity, true));
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
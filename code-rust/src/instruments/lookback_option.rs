@@ -0,0 +1,117 @@
+use crate::exercise::EuropeanExercise;
+use crate::instruments::payoffs::{FloatingTypePayoff, PlainVanillaPayoff};
+use crate::types::Real;
+
+/*
+Lookback options on the extremum of the underlying, monitored
+continuously until expiry. minmax is the extremum observed so far
+(QuantLib ContinuousFloatingLookbackOption and
+ContinuousFixedLookbackOption):
+    - floating strike: a call pays S_T - min, a put max - S_T
+    - fixed strike: a call pays max(max - K, 0), a put max(K - min, 0)
+minmax is the running minimum for floating calls and fixed puts, the
+running maximum otherwise.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct ContinuousFloatingLookbackOption {
+    minmax: Real,
+    payoff: FloatingTypePayoff,
+    exercise: EuropeanExercise,
+}
+impl ContinuousFloatingLookbackOption {
+    // Constructor
+    pub fn new(
+        minmax: Real,
+        payoff: FloatingTypePayoff,
+        exercise: EuropeanExercise,
+    ) -> ContinuousFloatingLookbackOption {
+        detail::check_minmax(minmax);
+        ContinuousFloatingLookbackOption {
+            minmax,
+            payoff,
+            exercise,
+        }
+    }
+
+    // Inspectors
+    pub fn minmax(&self) -> Real {
+        self.minmax
+    }
+    pub fn payoff(&self) -> &FloatingTypePayoff {
+        &self.payoff
+    }
+    pub fn exercise(&self) -> &EuropeanExercise {
+        &self.exercise
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ContinuousFixedLookbackOption {
+    minmax: Real,
+    payoff: PlainVanillaPayoff,
+    exercise: EuropeanExercise,
+}
+impl ContinuousFixedLookbackOption {
+    // Constructor
+    pub fn new(
+        minmax: Real,
+        payoff: PlainVanillaPayoff,
+        exercise: EuropeanExercise,
+    ) -> ContinuousFixedLookbackOption {
+        detail::check_minmax(minmax);
+        ContinuousFixedLookbackOption {
+            minmax,
+            payoff,
+            exercise,
+        }
+    }
+
+    // Inspectors
+    pub fn minmax(&self) -> Real {
+        self.minmax
+    }
+    pub fn payoff(&self) -> &PlainVanillaPayoff {
+        &self.payoff
+    }
+    pub fn exercise(&self) -> &EuropeanExercise {
+        &self.exercise
+    }
+}
+
+mod detail {
+    use super::*;
+
+    pub(super) fn check_minmax(minmax: Real) {
+        if minmax <= 0.0 {
+            panic!("running extremum ({minmax}) must be positive");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::option::OptionType;
+    use crate::time::date::{Date, Month};
+
+    #[test]
+    fn new_panics_on_non_positive_extremum() {
+        let exercise: EuropeanExercise = EuropeanExercise::new(Date::new(15, Month::July, 2025));
+        let result = std::panic::catch_unwind(|| {
+            ContinuousFloatingLookbackOption::new(
+                0.0,
+                FloatingTypePayoff::new(OptionType::Call),
+                exercise.clone(),
+            )
+        });
+        assert!(result.is_err(), "expected panic for a floating lookback");
+        let result = std::panic::catch_unwind(|| {
+            ContinuousFixedLookbackOption::new(
+                -1.0,
+                PlainVanillaPayoff::new(OptionType::Put, 100.0),
+                exercise.clone(),
+            )
+        });
+        assert!(result.is_err(), "expected panic for a fixed lookback");
+    }
+}
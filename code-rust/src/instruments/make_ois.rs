@@ -0,0 +1,235 @@
+use crate::indexes::ibor_index::OvernightIndex;
+use crate::instruments::make_vanilla_swap::detail::{fair_rate, start_date};
+use crate::instruments::overnight_indexed_swap::OvernightIndexedSwap;
+use crate::instruments::swap::SwapType;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::business_day_convention::BusinessDayConvention;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::time::daycounters::actual360::Actual360;
+use crate::time::period::Period;
+use crate::time::schedule::{DateGenerationRule, Schedule};
+use crate::time::time_unit::TimeUnit;
+use crate::types::{Rate, Real, Spread};
+use std::rc::Rc;
+
+/*
+Builder of overnight indexed swaps (QuantLib MakeOIS): a swap lasting
+swap_tenor from forward_start after the settlement date, compounding
+the fixings of the overnight index. Without a fixed rate the swap is
+struck at its fair rate on the discount curve.
+
+Unless modified, the swap is a payer swap on a nominal of 1, settles 2
+business days after the reference date of the discount curve and both
+legs accrue Actual/360 without spread. They pay once for swaps up to
+one year and annually otherwise, on a schedule generated backward from
+the maturity date. A given effective date replaces settlement plus
+forward start. Dates are business days of the index calendar, adjusted
+Modified Following.
+*/
+pub struct MakeOis {
+    swap_tenor: Period,
+    overnight_index: Rc<OvernightIndex>,
+    fixed_rate: Option<Rate>,
+    forward_start: Period,
+    swap_type: SwapType,
+    nominal: Real,
+    settlement_days: i32,
+    effective_date: Option<Date>,
+    payment_tenor: Option<Period>,
+    fixed_leg_day_counter: Box<dyn DayCounter>,
+    overnight_leg_day_counter: Box<dyn DayCounter>,
+    overnight_leg_spread: Spread,
+    discount_curve: Option<Rc<dyn YieldTermStructure>>,
+}
+impl MakeOis {
+    // Constructor
+    pub fn new(
+        swap_tenor: Period,
+        overnight_index: Rc<OvernightIndex>,
+        fixed_rate: Option<Rate>,
+        forward_start: Period,
+    ) -> MakeOis {
+        MakeOis {
+            swap_tenor,
+            overnight_index,
+            fixed_rate,
+            forward_start,
+            swap_type: SwapType::Payer,
+            nominal: 1.0,
+            settlement_days: 2,
+            effective_date: None,
+            payment_tenor: None,
+            fixed_leg_day_counter: Box::new(Actual360::new()),
+            overnight_leg_day_counter: Box::new(Actual360::new()),
+            overnight_leg_spread: 0.0,
+            discount_curve: None,
+        }
+    }
+
+    // Modifiers
+    pub fn with_type(mut self, swap_type: SwapType) -> MakeOis {
+        self.swap_type = swap_type;
+        self
+    }
+    pub fn with_nominal(mut self, nominal: Real) -> MakeOis {
+        self.nominal = nominal;
+        self
+    }
+    pub fn with_settlement_days(mut self, settlement_days: i32) -> MakeOis {
+        self.settlement_days = settlement_days;
+        self
+    }
+    pub fn with_effective_date(mut self, effective_date: Date) -> MakeOis {
+        self.effective_date = Some(effective_date);
+        self
+    }
+    // Payment tenor of both legs
+    pub fn with_payment_tenor(mut self, tenor: Period) -> MakeOis {
+        self.payment_tenor = Some(tenor);
+        self
+    }
+    pub fn with_fixed_leg_day_counter(mut self, day_counter: Box<dyn DayCounter>) -> MakeOis {
+        self.fixed_leg_day_counter = day_counter;
+        self
+    }
+    pub fn with_overnight_leg_day_counter(mut self, day_counter: Box<dyn DayCounter>) -> MakeOis {
+        self.overnight_leg_day_counter = day_counter;
+        self
+    }
+    pub fn with_overnight_leg_spread(mut self, spread: Spread) -> MakeOis {
+        self.overnight_leg_spread = spread;
+        self
+    }
+    pub fn with_discount_curve(mut self, discount_curve: Rc<dyn YieldTermStructure>) -> MakeOis {
+        self.discount_curve = Some(discount_curve);
+        self
+    }
+
+    pub fn swap(&self) -> OvernightIndexedSwap {
+        let start: Date = start_date(
+            self.effective_date,
+            self.discount_curve.as_deref(),
+            self.overnight_index.fixing_calendar(),
+            self.settlement_days,
+            self.forward_start,
+        );
+        let maturity: Date = start + self.swap_tenor;
+        let one_year: Period = Period::new(1, TimeUnit::Years);
+        let payment_tenor: Period = self
+            .payment_tenor
+            .unwrap_or(if self.swap_tenor <= one_year {
+                self.swap_tenor
+            } else {
+                one_year
+            });
+        let schedule: Schedule = Schedule::new(
+            start,
+            maturity,
+            payment_tenor,
+            self.overnight_index.fixing_calendar(),
+            BusinessDayConvention::ModifiedFollowing,
+            DateGenerationRule::Backward,
+            false,
+        );
+        let swap = |fixed_rate: Rate| -> OvernightIndexedSwap {
+            OvernightIndexedSwap::new(
+                self.swap_type,
+                self.nominal,
+                schedule.dates(),
+                fixed_rate,
+                self.fixed_leg_day_counter.as_ref(),
+                schedule.dates(),
+                self.overnight_index.clone(),
+                self.overnight_leg_day_counter.as_ref(),
+            )
+            .with_spread(self.overnight_leg_spread)
+        };
+        match self.fixed_rate {
+            Some(fixed_rate) => swap(fixed_rate),
+            None => swap(fair_rate(&swap(0.0), self.discount_curve.as_ref())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruments::fixed_vs_floating_swap::FixedVsFloatingSwap;
+    use crate::pricingengines::swap::discounting_swap_engine::DiscountingSwapEngine;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::calendar::Calendar;
+    use crate::time::calendars::target::Target;
+    use crate::time::date::Month;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn estr() -> Rc<OvernightIndex> {
+        Rc::new(OvernightIndex::estr())
+    }
+
+    #[test]
+    fn short_swaps_pay_once_and_long_ones_annually() {
+        let cases: [(Period, usize); 3] = [
+            (Period::new(6, TimeUnit::Months), 1),
+            (Period::new(1, TimeUnit::Years), 1),
+            (Period::new(30, TimeUnit::Months), 3),
+        ];
+        for (swap_tenor, periods) in cases {
+            let swap: OvernightIndexedSwap =
+                MakeOis::new(swap_tenor, estr(), Some(0.03), swap_tenor)
+                    .with_discount_curve(flat_rate(reference_date(), 0.03))
+                    .swap();
+            // Spot two TARGET business days after the reference date
+            let adjust = |date: Date| -> Date {
+                Target::new().adjust(date, BusinessDayConvention::ModifiedFollowing)
+            };
+            let start: Date = adjust(Date::new(17, Month::January, 2025) + swap_tenor);
+            assert_eq!(swap.start_date(), start, "{swap_tenor}");
+            assert_eq!(
+                swap.maturity_date(),
+                adjust(start + swap_tenor),
+                "{swap_tenor}"
+            );
+            assert_eq!(swap.overnight_leg().len(), periods, "{swap_tenor}");
+            assert_eq!(swap.fixed_accruals().len(), periods, "{swap_tenor}");
+        }
+        let swap: OvernightIndexedSwap = MakeOis::new(
+            Period::new(2, TimeUnit::Years),
+            estr(),
+            Some(0.03),
+            Period::new(0, TimeUnit::Days),
+        )
+        .with_settlement_days(0)
+        .with_payment_tenor(Period::new(6, TimeUnit::Months))
+        .with_discount_curve(flat_rate(reference_date(), 0.03))
+        .swap();
+        assert_eq!(swap.start_date(), Date::new(15, Month::January, 2025));
+        assert_eq!(swap.overnight_leg().len(), 4);
+    }
+
+    #[test]
+    fn swaps_without_fixed_rate_are_struck_at_the_fair_rate() {
+        let swap: OvernightIndexedSwap = MakeOis::new(
+            Period::new(5, TimeUnit::Years),
+            estr(),
+            None,
+            Period::new(0, TimeUnit::Days),
+        )
+        .with_type(SwapType::Receiver)
+        .with_nominal(1.0e6)
+        .with_overnight_leg_spread(0.002)
+        .with_discount_curve(flat_rate(reference_date(), 0.03))
+        .swap();
+        assert_eq!(swap.swap_type(), SwapType::Receiver);
+        let value: Real = DiscountingSwapEngine::new(flat_rate(reference_date(), 0.03))
+            .calculate(&swap)
+            .value;
+        assert!(value.abs() < 1.0e-8);
+        // Annual compounding of 3% continuous on Actual/360 accruals, plus the spread
+        let expected: Rate = ((0.03_f64).exp() - 1.0) * 360.0 / 365.0 + 0.002;
+        assert!((swap.fixed_rate() - expected).abs() < 1.0e-4);
+    }
+}
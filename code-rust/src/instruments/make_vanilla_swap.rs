@@ -0,0 +1,309 @@
+use crate::indexes::ibor_index::IborIndex;
+use crate::instruments::swap::SwapType;
+use crate::instruments::vanilla_swap::VanillaSwap;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::business_day_convention::BusinessDayConvention;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::time::daycounters::actual360::Actual360;
+use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+use crate::time::period::Period;
+use crate::time::schedule::{DateGenerationRule, Schedule};
+use crate::time::time_unit::TimeUnit;
+use crate::types::{Rate, Real, Spread};
+use std::rc::Rc;
+
+/*
+Builder of vanilla swaps (QuantLib MakeVanillaSwap): a swap lasting
+swap_tenor from forward_start after the settlement date, with floating
+coupons on the index every index tenor. Without a fixed rate the swap
+is struck at its fair rate on the discount curve.
+
+Unless modified, the swap is a payer swap on a nominal of 1, settles
+the index fixing days after the reference date of the discount curve,
+pays annual fixed coupons accruing Actual/365 (Fixed) and floating
+coupons accruing Actual/360 without spread. A given effective date
+replaces settlement plus forward start. Dates are business days of the
+index calendar, adjusted Modified Following, and schedules are
+generated backward from the maturity date.
+*/
+pub struct MakeVanillaSwap {
+    swap_tenor: Period,
+    index: Rc<IborIndex>,
+    fixed_rate: Option<Rate>,
+    forward_start: Period,
+    swap_type: SwapType,
+    nominal: Real,
+    settlement_days: i32,
+    effective_date: Option<Date>,
+    fixed_leg_tenor: Period,
+    fixed_leg_day_counter: Box<dyn DayCounter>,
+    floating_leg_day_counter: Box<dyn DayCounter>,
+    floating_leg_spread: Spread,
+    discount_curve: Option<Rc<dyn YieldTermStructure>>,
+}
+impl MakeVanillaSwap {
+    // Constructor
+    pub fn new(
+        swap_tenor: Period,
+        index: Rc<IborIndex>,
+        fixed_rate: Option<Rate>,
+        forward_start: Period,
+    ) -> MakeVanillaSwap {
+        MakeVanillaSwap {
+            swap_tenor,
+            settlement_days: index.fixing_days(),
+            index,
+            fixed_rate,
+            forward_start,
+            swap_type: SwapType::Payer,
+            nominal: 1.0,
+            effective_date: None,
+            fixed_leg_tenor: Period::new(1, TimeUnit::Years),
+            fixed_leg_day_counter: Box::new(Actual365Fixed::new()),
+            floating_leg_day_counter: Box::new(Actual360::new()),
+            floating_leg_spread: 0.0,
+            discount_curve: None,
+        }
+    }
+
+    // Modifiers
+    pub fn with_type(mut self, swap_type: SwapType) -> MakeVanillaSwap {
+        self.swap_type = swap_type;
+        self
+    }
+    pub fn with_nominal(mut self, nominal: Real) -> MakeVanillaSwap {
+        self.nominal = nominal;
+        self
+    }
+    pub fn with_settlement_days(mut self, settlement_days: i32) -> MakeVanillaSwap {
+        self.settlement_days = settlement_days;
+        self
+    }
+    pub fn with_effective_date(mut self, effective_date: Date) -> MakeVanillaSwap {
+        self.effective_date = Some(effective_date);
+        self
+    }
+    pub fn with_fixed_leg_tenor(mut self, tenor: Period) -> MakeVanillaSwap {
+        self.fixed_leg_tenor = tenor;
+        self
+    }
+    pub fn with_fixed_leg_day_counter(
+        mut self,
+        day_counter: Box<dyn DayCounter>,
+    ) -> MakeVanillaSwap {
+        self.fixed_leg_day_counter = day_counter;
+        self
+    }
+    pub fn with_floating_leg_day_counter(
+        mut self,
+        day_counter: Box<dyn DayCounter>,
+    ) -> MakeVanillaSwap {
+        self.floating_leg_day_counter = day_counter;
+        self
+    }
+    pub fn with_floating_leg_spread(mut self, spread: Spread) -> MakeVanillaSwap {
+        self.floating_leg_spread = spread;
+        self
+    }
+    pub fn with_discount_curve(
+        mut self,
+        discount_curve: Rc<dyn YieldTermStructure>,
+    ) -> MakeVanillaSwap {
+        self.discount_curve = Some(discount_curve);
+        self
+    }
+
+    pub fn swap(&self) -> VanillaSwap {
+        let start: Date = detail::start_date(
+            self.effective_date,
+            self.discount_curve.as_deref(),
+            self.index.fixing_calendar(),
+            self.settlement_days,
+            self.forward_start,
+        );
+        let maturity: Date = start + self.swap_tenor;
+        let schedule = |tenor: Period| -> Schedule {
+            Schedule::new(
+                start,
+                maturity,
+                tenor,
+                self.index.fixing_calendar(),
+                BusinessDayConvention::ModifiedFollowing,
+                DateGenerationRule::Backward,
+                false,
+            )
+        };
+        let fixed_schedule: Schedule = schedule(self.fixed_leg_tenor);
+        let floating_schedule: Schedule = schedule(self.index.tenor());
+        let swap = |fixed_rate: Rate| -> VanillaSwap {
+            VanillaSwap::new(
+                self.swap_type,
+                self.nominal,
+                fixed_schedule.dates(),
+                fixed_rate,
+                self.fixed_leg_day_counter.as_ref(),
+                floating_schedule.dates(),
+                self.index.clone(),
+                self.floating_leg_day_counter.as_ref(),
+            )
+            .with_spread(self.floating_leg_spread)
+        };
+        match self.fixed_rate {
+            Some(fixed_rate) => swap(fixed_rate),
+            None => swap(detail::fair_rate(&swap(0.0), self.discount_curve.as_ref())),
+        }
+    }
+}
+
+pub(crate) mod detail {
+    use crate::instruments::fixed_vs_floating_swap::FixedVsFloatingSwap;
+    use crate::pricingengines::swap::discounting_swap_engine::DiscountingSwapEngine;
+    use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+    use crate::time::business_day_convention::BusinessDayConvention;
+    use crate::time::calendar::Calendar;
+    use crate::time::date::Date;
+    use crate::time::period::Period;
+    use crate::time::time_unit::TimeUnit;
+    use crate::types::Rate;
+    use std::rc::Rc;
+
+    /*
+    Effective date if given, otherwise forward start after the spot date,
+    settlement_days business days after the reference date of the
+    discount curve.
+    */
+    pub(crate) fn start_date(
+        effective_date: Option<Date>,
+        discount_curve: Option<&dyn YieldTermStructure>,
+        calendar: &dyn Calendar,
+        settlement_days: i32,
+        forward_start: Period,
+    ) -> Date {
+        if settlement_days < 0 {
+            panic!("negative settlement days ({settlement_days}) not allowed");
+        }
+        match (effective_date, discount_curve) {
+            (Some(date), _) => date,
+            (None, Some(curve)) => {
+                let spot_date: Date = calendar.advance(
+                    curve.reference_date(),
+                    settlement_days,
+                    TimeUnit::Days,
+                    BusinessDayConvention::Following,
+                    false,
+                );
+                calendar.advance(
+                    spot_date,
+                    forward_start.length(),
+                    forward_start.units(),
+                    BusinessDayConvention::ModifiedFollowing,
+                    false,
+                )
+            }
+            (None, None) => panic!("effective date or discount curve required"),
+        }
+    }
+
+    pub(crate) fn fair_rate(
+        swap: &dyn FixedVsFloatingSwap,
+        discount_curve: Option<&Rc<dyn YieldTermStructure>>,
+    ) -> Rate {
+        match discount_curve {
+            Some(curve) => {
+                DiscountingSwapEngine::new(curve.clone())
+                    .calculate(swap)
+                    .fair_rate
+            }
+            None => panic!("discount curve required for the fair rate"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruments::fixed_vs_floating_swap::FixedVsFloatingSwap;
+    use crate::pricingengines::swap::discounting_swap_engine::DiscountingSwapEngine;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::Month;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn euribor(tenor: Period) -> Rc<IborIndex> {
+        Rc::new(IborIndex::euribor(tenor))
+    }
+
+    #[test]
+    fn swaps_start_forward_after_settlement() {
+        let swap: VanillaSwap = MakeVanillaSwap::new(
+            Period::new(5, TimeUnit::Years),
+            euribor(Period::new(6, TimeUnit::Months)),
+            Some(0.04),
+            Period::new(1, TimeUnit::Years),
+        )
+        .with_type(SwapType::Receiver)
+        .with_nominal(1.0e6)
+        .with_floating_leg_spread(0.001)
+        .with_discount_curve(flat_rate(reference_date(), 0.03))
+        .swap();
+        assert_eq!(swap.swap_type(), SwapType::Receiver);
+        assert_eq!(swap.nominal(), 1.0e6);
+        assert_eq!(swap.fixed_rate(), 0.04);
+        assert_eq!(swap.spread(), 0.001);
+        // Spot on Friday 17 January 2025, one year later on a Saturday rolled to Monday
+        assert_eq!(swap.start_date(), Date::new(19, Month::January, 2026));
+        assert_eq!(swap.maturity_date(), Date::new(20, Month::January, 2031));
+        assert_eq!(swap.fixed_schedule().len(), 6);
+        assert_eq!(swap.floating_leg().len(), 10);
+        assert_eq!(
+            swap.floating_leg()[1].accrual_start_date(),
+            Date::new(20, Month::July, 2026)
+        );
+        assert_eq!(
+            swap.floating_leg()[1].fixing_date(),
+            Date::new(16, Month::July, 2026)
+        );
+        assert_eq!(swap.fixed_accruals()[0], 365.0 / 365.0);
+    }
+
+    #[test]
+    fn swaps_without_fixed_rate_are_struck_at_the_fair_rate() {
+        // 18 months on quarterly coupons and a 1-year fixed leg with a short first period
+        let swap: VanillaSwap = MakeVanillaSwap::new(
+            Period::new(18, TimeUnit::Months),
+            euribor(Period::new(3, TimeUnit::Months)),
+            None,
+            Period::new(0, TimeUnit::Days),
+        )
+        .with_effective_date(Date::new(20, Month::March, 2025))
+        .with_discount_curve(flat_rate(reference_date(), 0.03))
+        .swap();
+        assert_eq!(
+            swap.fixed_schedule(),
+            &[
+                Date::new(20, Month::March, 2025),
+                Date::new(22, Month::September, 2025),
+                Date::new(21, Month::September, 2026),
+            ]
+        );
+        let value: Real = DiscountingSwapEngine::new(flat_rate(reference_date(), 0.03))
+            .calculate(&swap)
+            .value;
+        assert!(value.abs() < 1.0e-12);
+        assert!((swap.fixed_rate() - 0.03).abs() < 2.0e-3);
+
+        let result = std::panic::catch_unwind(|| {
+            MakeVanillaSwap::new(
+                Period::new(5, TimeUnit::Years),
+                euribor(Period::new(6, TimeUnit::Months)),
+                None,
+                Period::new(0, TimeUnit::Days),
+            )
+            .swap()
+        });
+        assert!(result.is_err(), "expected panic without discount curve");
+    }
+}
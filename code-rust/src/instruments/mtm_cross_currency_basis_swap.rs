@@ -0,0 +1,131 @@
+use crate::cashflows::ibor_coupon::{IborCoupon, IborLeg};
+use crate::currency::Currency;
+use crate::money::Money;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Real, Spread};
+
+/*
+Mark-to-market cross-currency basis swap (the instrument quoted by
+QuantLib MtMCrossCurrencyBasisSwapRateHelper): two floating legs in
+different currencies over the same schedule, each paying Ibor plus a
+spread.
+
+The constant leg keeps its nominal over the life of the swap, with
+nominal exchanges at start and maturity. The nominal of the resetting
+leg is reset at the start of every period to the constant nominal
+converted at the FX rate of that date, and is exchanged at the start
+and end of each period; it is therefore only known to the pricing
+engine.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct MtMCrossCurrencyBasisSwap {
+    constant_nominal: Money,
+    constant_spread: Spread,
+    resetting_currency: Currency,
+    resetting_spread: Spread,
+    periods: Vec<IborCoupon>,
+    pay_constant_leg: bool,
+}
+impl MtMCrossCurrencyBasisSwap {
+    // Constructor
+    pub fn new(
+        constant_nominal: Money,
+        constant_spread: Spread,
+        resetting_currency: Currency,
+        resetting_spread: Spread,
+        schedule: &[Date],
+        day_counter: &dyn DayCounter,
+        pay_constant_leg: bool,
+    ) -> MtMCrossCurrencyBasisSwap {
+        if constant_nominal.currency() == resetting_currency {
+            panic!("the legs must be in different currencies ({resetting_currency})");
+        }
+        MtMCrossCurrencyBasisSwap {
+            constant_nominal,
+            constant_spread,
+            resetting_currency,
+            resetting_spread,
+            periods: IborLeg::new(schedule)
+                .with_notionals(&[constant_nominal.value()])
+                .coupons(day_counter),
+            pay_constant_leg,
+        }
+    }
+
+    // Inspectors
+    pub fn constant_nominal(&self) -> Money {
+        self.constant_nominal
+    }
+    pub fn constant_currency(&self) -> Currency {
+        self.constant_nominal.currency()
+    }
+    pub fn constant_spread(&self) -> Spread {
+        self.constant_spread
+    }
+    pub fn resetting_currency(&self) -> Currency {
+        self.resetting_currency
+    }
+    pub fn resetting_spread(&self) -> Spread {
+        self.resetting_spread
+    }
+    // Coupons of the constant leg; the resetting leg accrues over the same periods
+    pub fn constant_leg(&self) -> &[IborCoupon] {
+        &self.periods
+    }
+    pub fn pay_constant_leg(&self) -> bool {
+        self.pay_constant_leg
+    }
+    pub fn start_date(&self) -> Date {
+        self.periods[0].accrual_start_date()
+    }
+    pub fn maturity_date(&self) -> Date {
+        self.periods[self.periods.len() - 1].date()
+    }
+    // +1 if the holder receives the resetting leg, -1 otherwise
+    pub fn sign(&self) -> Real {
+        if self.pay_constant_leg { 1.0 } else { -1.0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::currencies::america::USD;
+    use crate::currencies::europe::EUR;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual360::Actual360;
+
+    #[test]
+    fn legs_share_the_schedule() {
+        let start: Date = Date::new(15, Month::January, 2025);
+        let schedule: Vec<Date> = (0..=4).map(|k| start + 91 * k).collect();
+        let swap: MtMCrossCurrencyBasisSwap = MtMCrossCurrencyBasisSwap::new(
+            1.0e6 * USD,
+            0.0,
+            EUR,
+            -0.001,
+            &schedule,
+            &Actual360::new(),
+            false,
+        );
+        assert_eq!(swap.constant_leg().len(), 4);
+        assert_eq!(swap.constant_leg()[2].nominal(), 1.0e6);
+        assert_eq!(swap.start_date(), start);
+        assert_eq!(swap.maturity_date(), schedule[4]);
+        assert_eq!(swap.sign(), -1.0);
+
+        let result = std::panic::catch_unwind(|| {
+            MtMCrossCurrencyBasisSwap::new(
+                1.0e6 * USD,
+                0.0,
+                USD,
+                0.0,
+                &schedule,
+                &Actual360::new(),
+                true,
+            )
+        });
+        assert!(result.is_err(), "expected panic for a single currency");
+    }
+}
@@ -0,0 +1,168 @@
+use crate::cashflows::overnight_indexed_coupon::OvernightIndexedCoupon;
+use crate::indexes::ibor_index::OvernightIndex;
+use crate::instruments::fixed_vs_floating_swap::{FixedVsFloatingSwap, detail::check_schedules};
+use crate::instruments::swap::SwapType;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Rate, Real, Spread, Time};
+use std::rc::Rc;
+
+/*
+Overnight indexed swap (QuantLib OvernightIndexedSwap): a payer swap
+pays the fixed rate on the fixed schedule and receives the overnight
+rate of the index compounded over each period of the overnight
+schedule plus a spread, both on the same nominal. The spread is zero
+unless set with with_spread.
+
+Schedules are given as [start, payment_1, ..., payment_n]; the two legs
+must start and end on the same dates.
+*/
+#[derive(Clone)]
+pub struct OvernightIndexedSwap {
+    swap_type: SwapType,
+    nominal: Real,
+    fixed_schedule: Vec<Date>,
+    fixed_rate: Rate,
+    fixed_accruals: Vec<Real>,
+    overnight_leg: Vec<OvernightIndexedCoupon>,
+    overnight_index: Rc<OvernightIndex>,
+    spread: Spread,
+}
+impl OvernightIndexedSwap {
+    // Constructor
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        swap_type: SwapType,
+        nominal: Real,
+        fixed_schedule: &[Date],
+        fixed_rate: Rate,
+        fixed_day_counter: &dyn DayCounter,
+        overnight_schedule: &[Date],
+        overnight_index: Rc<OvernightIndex>,
+        overnight_day_counter: &dyn DayCounter,
+    ) -> OvernightIndexedSwap {
+        check_schedules(fixed_schedule, overnight_schedule);
+        OvernightIndexedSwap {
+            swap_type,
+            nominal,
+            fixed_schedule: fixed_schedule.to_vec(),
+            fixed_rate,
+            fixed_accruals: fixed_schedule
+                .windows(2)
+                .map(|period| fixed_day_counter.year_fraction(period[0], period[1]))
+                .collect(),
+            overnight_leg: overnight_schedule
+                .windows(2)
+                .map(|period| {
+                    OvernightIndexedCoupon::new(
+                        nominal,
+                        period[0],
+                        period[1],
+                        overnight_day_counter,
+                    )
+                })
+                .collect(),
+            overnight_index,
+            spread: 0.0,
+        }
+    }
+
+    // Modifiers
+    pub fn with_spread(mut self, spread: Spread) -> OvernightIndexedSwap {
+        self.spread = spread;
+        self
+    }
+
+    // Inspectors
+    pub fn overnight_leg(&self) -> &[OvernightIndexedCoupon] {
+        &self.overnight_leg
+    }
+    pub fn overnight_index(&self) -> &OvernightIndex {
+        self.overnight_index.as_ref()
+    }
+}
+impl FixedVsFloatingSwap for OvernightIndexedSwap {
+    fn swap_type(&self) -> SwapType {
+        self.swap_type
+    }
+    fn nominal(&self) -> Real {
+        self.nominal
+    }
+    fn fixed_schedule(&self) -> &[Date] {
+        &self.fixed_schedule
+    }
+    fn fixed_rate(&self) -> Rate {
+        self.fixed_rate
+    }
+    fn fixed_accruals(&self) -> &[Real] {
+        &self.fixed_accruals
+    }
+    fn spread(&self) -> Spread {
+        self.spread
+    }
+    fn floating_periods(&self) -> Vec<(Date, Date, Time)> {
+        self.overnight_leg
+            .iter()
+            .map(|coupon| {
+                (
+                    coupon.accrual_start_date(),
+                    coupon.date(),
+                    coupon.accrual_period(),
+                )
+            })
+            .collect()
+    }
+    fn floating_rate(&self, i: usize, forecast_curve: &dyn YieldTermStructure) -> Rate {
+        self.overnight_leg[i].rate(&self.overnight_index, forecast_curve)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual360::Actual360;
+
+    #[test]
+    fn legs_follow_their_schedules() {
+        let start: Date = Date::new(15, Month::January, 2025);
+        let fixed: Vec<Date> = vec![start, start + 365];
+        let overnight: Vec<Date> = vec![start, start + 180, start + 365];
+        let swap: OvernightIndexedSwap = OvernightIndexedSwap::new(
+            SwapType::Receiver,
+            1.0e6,
+            &fixed,
+            0.03,
+            &Actual360::new(),
+            &overnight,
+            Rc::new(OvernightIndex::estr()),
+            &Actual360::new(),
+        )
+        .with_spread(0.001);
+        assert_eq!(swap.fixed_accruals(), &[365.0 / 360.0]);
+        assert_eq!(swap.spread(), 0.001);
+        assert_eq!(
+            swap.floating_periods(),
+            vec![
+                (start, start + 180, 0.5),
+                (start + 180, start + 365, 185.0 / 360.0)
+            ]
+        );
+        assert_eq!(swap.overnight_leg()[1].nominal(), 1.0e6);
+        assert_eq!(swap.maturity_date(), start + 365);
+        let result = std::panic::catch_unwind(|| {
+            OvernightIndexedSwap::new(
+                SwapType::Payer,
+                1.0,
+                &fixed,
+                0.03,
+                &Actual360::new(),
+                &overnight[..2],
+                Rc::new(OvernightIndex::estr()),
+                &Actual360::new(),
+            )
+        });
+        assert!(result.is_err(), "expected panic for different maturities");
+    }
+}
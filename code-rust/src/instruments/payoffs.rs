@@ -0,0 +1,343 @@
+use crate::option::OptionType;
+use crate::types::Real;
+
+/*
+Abstract base "class" for option payoffs (QuantLib Payoff).
+
+value(price) is the QuantLib operator()(price): what the option pays
+when the underlying is at `price`.
+*/
+pub trait Payoff {
+    fn name(&self) -> String;
+    fn description(&self) -> String;
+    fn value(&self, price: Real) -> Real;
+}
+
+// Payoff based on a type (call/put) and a strike (QuantLib StrikedTypePayoff)
+pub trait StrikedTypePayoff: Payoff {
+    fn option_type(&self) -> OptionType;
+    fn strike(&self) -> Real;
+}
+
+// Plain-vanilla payoff: max(S - K, 0) for a call, max(K - S, 0) for a put
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct PlainVanillaPayoff {
+    option_type: OptionType,
+    strike: Real,
+}
+impl PlainVanillaPayoff {
+    // Constructor
+    pub fn new(option_type: OptionType, strike: Real) -> PlainVanillaPayoff {
+        PlainVanillaPayoff {
+            option_type,
+            strike,
+        }
+    }
+}
+impl Payoff for PlainVanillaPayoff {
+    fn name(&self) -> String {
+        String::from("Vanilla")
+    }
+    fn description(&self) -> String {
+        detail::striked_description(self)
+    }
+    fn value(&self, price: Real) -> Real {
+        (self.option_type.sign() * (price - self.strike)).max(0.0)
+    }
+}
+impl StrikedTypePayoff for PlainVanillaPayoff {
+    fn option_type(&self) -> OptionType {
+        self.option_type
+    }
+    fn strike(&self) -> Real {
+        self.strike
+    }
+}
+
+// Binary cash-or-nothing payoff: pays a fixed cash amount if in the money
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CashOrNothingPayoff {
+    option_type: OptionType,
+    strike: Real,
+    cash_payoff: Real,
+}
+impl CashOrNothingPayoff {
+    // Constructor
+    pub fn new(option_type: OptionType, strike: Real, cash_payoff: Real) -> CashOrNothingPayoff {
+        CashOrNothingPayoff {
+            option_type,
+            strike,
+            cash_payoff,
+        }
+    }
+
+    // Inspectors
+    pub fn cash_payoff(&self) -> Real {
+        self.cash_payoff
+    }
+}
+impl Payoff for CashOrNothingPayoff {
+    fn name(&self) -> String {
+        String::from("CashOrNothing")
+    }
+    fn description(&self) -> String {
+        format!(
+            "{}, {} cash payoff",
+            detail::striked_description(self),
+            self.cash_payoff
+        )
+    }
+    fn value(&self, price: Real) -> Real {
+        if self.option_type.sign() * (price - self.strike) > 0.0 {
+            self.cash_payoff
+        } else {
+            0.0
+        }
+    }
+}
+impl StrikedTypePayoff for CashOrNothingPayoff {
+    fn option_type(&self) -> OptionType {
+        self.option_type
+    }
+    fn strike(&self) -> Real {
+        self.strike
+    }
+}
+
+// Binary asset-or-nothing payoff: pays the asset price if in the money
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct AssetOrNothingPayoff {
+    option_type: OptionType,
+    strike: Real,
+}
+impl AssetOrNothingPayoff {
+    // Constructor
+    pub fn new(option_type: OptionType, strike: Real) -> AssetOrNothingPayoff {
+        AssetOrNothingPayoff {
+            option_type,
+            strike,
+        }
+    }
+}
+impl Payoff for AssetOrNothingPayoff {
+    fn name(&self) -> String {
+        String::from("AssetOrNothing")
+    }
+    fn description(&self) -> String {
+        detail::striked_description(self)
+    }
+    fn value(&self, price: Real) -> Real {
+        if self.option_type.sign() * (price - self.strike) > 0.0 {
+            price
+        } else {
+            0.0
+        }
+    }
+}
+impl StrikedTypePayoff for AssetOrNothingPayoff {
+    fn option_type(&self) -> OptionType {
+        self.option_type
+    }
+    fn strike(&self) -> Real {
+        self.strike
+    }
+}
+
+/*
+Payoff with a floating strike (QuantLib FloatingTypePayoff): the strike
+is a fixing of the path, e.g. its minimum for a floating lookback call,
+so the payoff cannot be valued on the final price alone.
+*/
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct FloatingTypePayoff {
+    option_type: OptionType,
+}
+impl FloatingTypePayoff {
+    // Constructor
+    pub fn new(option_type: OptionType) -> FloatingTypePayoff {
+        FloatingTypePayoff { option_type }
+    }
+
+    // Inspectors
+    pub fn option_type(&self) -> OptionType {
+        self.option_type
+    }
+}
+impl Payoff for FloatingTypePayoff {
+    fn name(&self) -> String {
+        String::from("FloatingType")
+    }
+    fn description(&self) -> String {
+        format!("{} {}", self.name(), self.option_type)
+    }
+    fn value(&self, _price: Real) -> Real {
+        panic!("floating payoff not handled");
+    }
+}
+
+// Private
+mod detail {
+    use super::StrikedTypePayoff;
+
+    // Example: "Vanilla Call, 100 strike"
+    pub(crate) fn striked_description(payoff: &dyn StrikedTypePayoff) -> String {
+        format!(
+            "{} {}, {} strike",
+            payoff.name(),
+            payoff.option_type(),
+            payoff.strike()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_vanilla_payoff_value() {
+        let cases: [(PlainVanillaPayoff, Real, Real, &str); 6] = [
+            (
+                PlainVanillaPayoff::new(OptionType::Call, 100.0),
+                120.0,
+                20.0,
+                "call ITM",
+            ),
+            (
+                PlainVanillaPayoff::new(OptionType::Call, 100.0),
+                100.0,
+                0.0,
+                "call ATM",
+            ),
+            (
+                PlainVanillaPayoff::new(OptionType::Call, 100.0),
+                80.0,
+                0.0,
+                "call OTM",
+            ),
+            (
+                PlainVanillaPayoff::new(OptionType::Put, 100.0),
+                80.0,
+                20.0,
+                "put ITM",
+            ),
+            (
+                PlainVanillaPayoff::new(OptionType::Put, 100.0),
+                100.0,
+                0.0,
+                "put ATM",
+            ),
+            (
+                PlainVanillaPayoff::new(OptionType::Put, 100.0),
+                120.0,
+                0.0,
+                "put OTM",
+            ),
+        ];
+
+        for (payoff, price, expected, label) in cases {
+            assert_eq!(payoff.value(price), expected, "Failed for {label}");
+        }
+    }
+
+    #[test]
+    fn cash_or_nothing_payoff_value() {
+        let cases: [(CashOrNothingPayoff, Real, Real, &str); 4] = [
+            (
+                CashOrNothingPayoff::new(OptionType::Call, 100.0, 10.0),
+                120.0,
+                10.0,
+                "call ITM",
+            ),
+            (
+                CashOrNothingPayoff::new(OptionType::Call, 100.0, 10.0),
+                100.0,
+                0.0,
+                "call ATM",
+            ),
+            (
+                CashOrNothingPayoff::new(OptionType::Put, 100.0, 10.0),
+                80.0,
+                10.0,
+                "put ITM",
+            ),
+            (
+                CashOrNothingPayoff::new(OptionType::Put, 100.0, 10.0),
+                120.0,
+                0.0,
+                "put OTM",
+            ),
+        ];
+
+        for (payoff, price, expected, label) in cases {
+            assert_eq!(payoff.value(price), expected, "Failed for {label}");
+        }
+    }
+
+    #[test]
+    fn asset_or_nothing_payoff_value() {
+        let cases: [(AssetOrNothingPayoff, Real, Real, &str); 4] = [
+            (
+                AssetOrNothingPayoff::new(OptionType::Call, 100.0),
+                120.0,
+                120.0,
+                "call ITM",
+            ),
+            (
+                AssetOrNothingPayoff::new(OptionType::Call, 100.0),
+                80.0,
+                0.0,
+                "call OTM",
+            ),
+            (
+                AssetOrNothingPayoff::new(OptionType::Put, 100.0),
+                80.0,
+                80.0,
+                "put ITM",
+            ),
+            (
+                AssetOrNothingPayoff::new(OptionType::Put, 100.0),
+                120.0,
+                0.0,
+                "put OTM",
+            ),
+        ];
+
+        for (payoff, price, expected, label) in cases {
+            assert_eq!(payoff.value(price), expected, "Failed for {label}");
+        }
+    }
+
+    #[test]
+    fn payoff_descriptions() {
+        let cases: [(Box<dyn StrikedTypePayoff>, &str); 3] = [
+            (
+                Box::new(PlainVanillaPayoff::new(OptionType::Call, 100.0)),
+                "Vanilla Call, 100 strike",
+            ),
+            (
+                Box::new(CashOrNothingPayoff::new(OptionType::Put, 95.5, 10.0)),
+                "CashOrNothing Put, 95.5 strike, 10 cash payoff",
+            ),
+            (
+                Box::new(AssetOrNothingPayoff::new(OptionType::Call, 105.0)),
+                "AssetOrNothing Call, 105 strike",
+            ),
+        ];
+
+        for (payoff, expected) in cases {
+            assert_eq!(payoff.description(), expected);
+        }
+    }
+
+    #[test]
+    fn floating_type_payoff_needs_the_path() {
+        let payoff: FloatingTypePayoff = FloatingTypePayoff::new(OptionType::Put);
+        assert_eq!(payoff.description(), "FloatingType Put");
+        let result = std::panic::catch_unwind(|| payoff.value(100.0));
+        assert!(
+            result.is_err(),
+            "expected panic for a floating payoff value"
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use crate::exercise::EuropeanExercise;
+use crate::instruments::basket_option::{BasketOption, BasketPayoff};
+use crate::instruments::payoffs::PlainVanillaPayoff;
+
+/*
+European option on the spread of two underlyings (QuantLib
+SpreadOption): pays the vanilla payoff of S_1 - S_2, e.g.
+max(S_1 - S_2 - K, 0) for a call. It is the basket option with a spread
+payoff, into which it converts for the generic basket engines.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct SpreadOption {
+    payoff: PlainVanillaPayoff,
+    exercise: EuropeanExercise,
+}
+impl SpreadOption {
+    // Constructor
+    pub fn new(payoff: PlainVanillaPayoff, exercise: EuropeanExercise) -> SpreadOption {
+        SpreadOption { payoff, exercise }
+    }
+
+    // Inspectors
+    pub fn payoff(&self) -> &PlainVanillaPayoff {
+        &self.payoff
+    }
+    pub fn exercise(&self) -> &EuropeanExercise {
+        &self.exercise
+    }
+}
+// Traits
+impl From<SpreadOption> for BasketOption {
+    fn from(option: SpreadOption) -> BasketOption {
+        BasketOption::new(BasketPayoff::Spread(option.payoff), option.exercise)
+    }
+}
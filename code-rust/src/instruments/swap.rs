@@ -0,0 +1,42 @@
+use std::fmt::{Display, Formatter, Result};
+
+// QuantLib Swap::Type: the payer swap pays the fixed leg, the receiver receives it
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SwapType {
+    Receiver = -1,
+    Payer = 1,
+}
+impl SwapType {
+    // +1 for a payer and -1 for a receiver swap
+    pub fn sign(&self) -> f64 {
+        *self as i32 as f64
+    }
+}
+
+// Traits
+impl Display for SwapType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let swap_type: &'static str = match self {
+            SwapType::Receiver => "Receiver",
+            SwapType::Payer => "Payer",
+        };
+        write!(f, "{swap_type}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_and_sign_of_swap_types() {
+        let cases: [(SwapType, &str, f64); 2] = [
+            (SwapType::Payer, "Payer", 1.0),
+            (SwapType::Receiver, "Receiver", -1.0),
+        ];
+        for (swap_type, name, sign) in cases {
+            assert_eq!(format!("{swap_type}"), name);
+            assert_eq!(swap_type.sign(), sign);
+        }
+    }
+}
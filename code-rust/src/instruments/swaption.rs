@@ -0,0 +1,136 @@
+use crate::exercise::{Exercise, ExerciseType};
+use crate::instruments::fixed_vs_floating_swap::FixedVsFloatingSwap;
+use crate::instruments::swap::SwapType;
+use crate::instruments::vanilla_swap::VanillaSwap;
+use std::fmt::{Display, Formatter, Result};
+
+// QuantLib Settlement::Type: enter the swap or receive its cash value at exercise
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SettlementType {
+    Physical,
+    Cash,
+}
+
+/*
+Option to enter an interest rate swap (QuantLib Swaption), with
+European or Bermudan exercise. Physical settlement delivers the swap;
+cash settlement pays its value, computed on the par yield curve as in
+QuantLib's ParYieldCurve settlement method.
+*/
+pub struct Swaption {
+    swap: VanillaSwap,
+    exercise: Box<dyn Exercise>,
+    settlement_type: SettlementType,
+}
+impl Swaption {
+    // Constructor
+    pub fn new(
+        swap: VanillaSwap,
+        exercise: Box<dyn Exercise>,
+        settlement_type: SettlementType,
+    ) -> Swaption {
+        if exercise.exercise_type() == ExerciseType::American {
+            panic!("American exercise not supported");
+        }
+        if exercise.last_date() >= swap.maturity_date() {
+            panic!(
+                "last exercise date ({}) must be before the swap maturity ({})",
+                exercise.last_date(),
+                swap.maturity_date()
+            );
+        }
+        Swaption {
+            swap,
+            exercise,
+            settlement_type,
+        }
+    }
+
+    // Inspectors
+    pub fn swap(&self) -> &VanillaSwap {
+        &self.swap
+    }
+    pub fn exercise(&self) -> &dyn Exercise {
+        self.exercise.as_ref()
+    }
+    pub fn settlement_type(&self) -> SettlementType {
+        self.settlement_type
+    }
+    pub fn swap_type(&self) -> SwapType {
+        self.swap.swap_type()
+    }
+}
+
+// Traits
+impl Display for SettlementType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let settlement_type: &'static str = match self {
+            SettlementType::Physical => "Physical",
+            SettlementType::Cash => "Cash",
+        };
+        write!(f, "{settlement_type}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::{AmericanExercise, BermudanExercise, EuropeanExercise};
+    use crate::indexes::ibor_index::IborIndex;
+    use crate::time::date::{Date, Month};
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::period::Period;
+    use crate::time::time_unit::TimeUnit;
+    use std::rc::Rc;
+
+    fn swap() -> VanillaSwap {
+        let start: Date = Date::new(15, Month::January, 2026);
+        let schedule: Vec<Date> = (0..=3).map(|k| start + 365 * k).collect();
+        VanillaSwap::new(
+            SwapType::Payer,
+            1.0,
+            &schedule,
+            0.03,
+            &Actual365Fixed::new(),
+            &schedule,
+            Rc::new(IborIndex::euribor(Period::new(1, TimeUnit::Years))),
+            &Actual365Fixed::new(),
+        )
+    }
+
+    #[test]
+    fn swaptions_keep_swap_exercise_and_settlement() {
+        let start: Date = swap().start_date();
+        let swaption: Swaption = Swaption::new(
+            swap(),
+            Box::new(BermudanExercise::new(&[start, start + 365], false)),
+            SettlementType::Cash,
+        );
+        assert_eq!(swaption.exercise().exercise_type(), ExerciseType::Bermudan);
+        assert_eq!(swaption.settlement_type(), SettlementType::Cash);
+        assert_eq!(swaption.swap_type(), SwapType::Payer);
+        assert_eq!(swaption.swap().fixed_rate(), 0.03);
+        assert_eq!(format!("{}", SettlementType::Physical), "Physical");
+    }
+
+    #[test]
+    fn new_panics_on_invalid_exercise() {
+        let maturity: Date = swap().maturity_date();
+        let result = std::panic::catch_unwind(|| {
+            Swaption::new(
+                swap(),
+                Box::new(EuropeanExercise::new(maturity)),
+                SettlementType::Physical,
+            )
+        });
+        assert!(result.is_err(), "expected panic for exercise at maturity");
+        let result = std::panic::catch_unwind(|| {
+            Swaption::new(
+                swap(),
+                Box::new(AmericanExercise::from_latest_date(maturity - 30, false)),
+                SettlementType::Physical,
+            )
+        });
+        assert!(result.is_err(), "expected panic for American exercise");
+    }
+}
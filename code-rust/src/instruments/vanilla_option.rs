@@ -0,0 +1,109 @@
+use crate::exercise::Exercise;
+use crate::instruments::payoffs::{
+    AssetOrNothingPayoff, CashOrNothingPayoff, PlainVanillaPayoff, StrikedTypePayoff,
+};
+use crate::option::OptionType;
+use crate::types::Real;
+
+// Striked payoffs a vanilla option can have
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum VanillaPayoff {
+    PlainVanilla(PlainVanillaPayoff),
+    CashOrNothing(CashOrNothingPayoff),
+    AssetOrNothing(AssetOrNothingPayoff),
+}
+impl VanillaPayoff {
+    pub fn option_type(&self) -> OptionType {
+        self.payoff().option_type()
+    }
+    pub fn strike(&self) -> Real {
+        self.payoff().strike()
+    }
+    pub fn value(&self, price: Real) -> Real {
+        self.payoff().value(price)
+    }
+    pub fn payoff(&self) -> &dyn StrikedTypePayoff {
+        match self {
+            VanillaPayoff::PlainVanilla(payoff) => payoff,
+            VanillaPayoff::CashOrNothing(payoff) => payoff,
+            VanillaPayoff::AssetOrNothing(payoff) => payoff,
+        }
+    }
+}
+
+// Traits
+impl From<PlainVanillaPayoff> for VanillaPayoff {
+    fn from(payoff: PlainVanillaPayoff) -> VanillaPayoff {
+        VanillaPayoff::PlainVanilla(payoff)
+    }
+}
+impl From<CashOrNothingPayoff> for VanillaPayoff {
+    fn from(payoff: CashOrNothingPayoff) -> VanillaPayoff {
+        VanillaPayoff::CashOrNothing(payoff)
+    }
+}
+impl From<AssetOrNothingPayoff> for VanillaPayoff {
+    fn from(payoff: AssetOrNothingPayoff) -> VanillaPayoff {
+        VanillaPayoff::AssetOrNothing(payoff)
+    }
+}
+
+/*
+Option on a single asset with a striked payoff (QuantLib VanillaOption):
+plain vanilla, cash-or-nothing or asset-or-nothing, with European,
+American or Bermudan exercise. Which exercises are supported is up to
+the engine.
+*/
+pub struct VanillaOption {
+    payoff: VanillaPayoff,
+    exercise: Box<dyn Exercise>,
+}
+impl VanillaOption {
+    // Constructor
+    pub fn new(payoff: impl Into<VanillaPayoff>, exercise: Box<dyn Exercise>) -> VanillaOption {
+        VanillaOption {
+            payoff: payoff.into(),
+            exercise,
+        }
+    }
+
+    // Inspectors
+    pub fn payoff(&self) -> &VanillaPayoff {
+        &self.payoff
+    }
+    pub fn exercise(&self) -> &dyn Exercise {
+        self.exercise.as_ref()
+    }
+    pub fn option_type(&self) -> OptionType {
+        self.payoff.option_type()
+    }
+    pub fn strike(&self) -> Real {
+        self.payoff.strike()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::{AmericanExercise, ExerciseType};
+    use crate::time::date::{Date, Month};
+
+    #[test]
+    fn inspectors_forward_to_the_payoff_and_exercise() {
+        let expiry: Date = Date::new(15, Month::January, 2026);
+        let option: VanillaOption = VanillaOption::new(
+            CashOrNothingPayoff::new(OptionType::Put, 80.0, 10.0),
+            Box::new(AmericanExercise::from_latest_date(expiry, false)),
+        );
+        assert_eq!(option.option_type(), OptionType::Put);
+        assert_eq!(option.strike(), 80.0);
+        assert_eq!(option.payoff().value(70.0), 10.0);
+        assert_eq!(option.payoff().value(90.0), 0.0);
+        assert_eq!(
+            option.payoff().payoff().description(),
+            "CashOrNothing Put, 80 strike, 10 cash payoff"
+        );
+        assert_eq!(option.exercise().exercise_type(), ExerciseType::American);
+        assert_eq!(option.exercise().last_date(), expiry);
+    }
+}
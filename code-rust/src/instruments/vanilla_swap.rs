@@ -0,0 +1,186 @@
+use crate::cashflows::ibor_coupon::{IborCoupon, IborLeg};
+use crate::indexes::ibor_index::IborIndex;
+use crate::instruments::fixed_vs_floating_swap::{FixedVsFloatingSwap, detail::check_schedules};
+use crate::instruments::swap::SwapType;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Rate, Real, Spread, Time};
+use std::rc::Rc;
+
+/*
+Fixed-for-floating interest rate swap (QuantLib VanillaSwap): a payer
+swap pays the fixed rate on the fixed schedule and receives the Ibor
+coupons of the floating schedule plus a spread, both on the same
+nominal. The floating coupons fix on the index, whose stored fixings
+pay the coupons already fixed. The spread is zero unless set with
+with_spread.
+
+Schedules are given as [start, payment_1, ..., payment_n]; the two legs
+must start and end on the same dates. Fixed accrual periods are
+measured with the fixed-leg day counter.
+*/
+#[derive(Clone)]
+pub struct VanillaSwap {
+    swap_type: SwapType,
+    nominal: Real,
+    fixed_schedule: Vec<Date>,
+    fixed_rate: Rate,
+    fixed_accruals: Vec<Real>,
+    floating_leg: Vec<IborCoupon>,
+    index: Rc<IborIndex>,
+    spread: Spread,
+}
+impl VanillaSwap {
+    // Constructor
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        swap_type: SwapType,
+        nominal: Real,
+        fixed_schedule: &[Date],
+        fixed_rate: Rate,
+        fixed_day_counter: &dyn DayCounter,
+        floating_schedule: &[Date],
+        index: Rc<IborIndex>,
+        floating_day_counter: &dyn DayCounter,
+    ) -> VanillaSwap {
+        check_schedules(fixed_schedule, floating_schedule);
+        let floating_leg: Vec<IborCoupon> = IborLeg::new(floating_schedule)
+            .with_notionals(&[nominal])
+            .with_index(&index)
+            .coupons(floating_day_counter);
+        VanillaSwap {
+            swap_type,
+            nominal,
+            fixed_schedule: fixed_schedule.to_vec(),
+            fixed_rate,
+            fixed_accruals: fixed_schedule
+                .windows(2)
+                .map(|period| fixed_day_counter.year_fraction(period[0], period[1]))
+                .collect(),
+            floating_leg,
+            index,
+            spread: 0.0,
+        }
+    }
+
+    // Modifiers
+    pub fn with_spread(mut self, spread: Spread) -> VanillaSwap {
+        self.spread = spread;
+        self
+    }
+
+    // Inspectors
+    pub fn floating_leg(&self) -> &[IborCoupon] {
+        &self.floating_leg
+    }
+    pub fn index(&self) -> &IborIndex {
+        self.index.as_ref()
+    }
+}
+impl FixedVsFloatingSwap for VanillaSwap {
+    fn swap_type(&self) -> SwapType {
+        self.swap_type
+    }
+    fn nominal(&self) -> Real {
+        self.nominal
+    }
+    fn fixed_schedule(&self) -> &[Date] {
+        &self.fixed_schedule
+    }
+    fn fixed_rate(&self) -> Rate {
+        self.fixed_rate
+    }
+    fn fixed_accruals(&self) -> &[Real] {
+        &self.fixed_accruals
+    }
+    fn spread(&self) -> Spread {
+        self.spread
+    }
+    fn floating_periods(&self) -> Vec<(Date, Date, Time)> {
+        self.floating_leg
+            .iter()
+            .map(|coupon| {
+                (
+                    coupon.accrual_start_date(),
+                    coupon.date(),
+                    coupon.accrual_period(),
+                )
+            })
+            .collect()
+    }
+    fn floating_rate(&self, i: usize, forecast_curve: &dyn YieldTermStructure) -> Rate {
+        self.floating_leg[i].rate(&self.index, forecast_curve)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::period::Period;
+    use crate::time::time_unit::TimeUnit;
+
+    fn schedule(step: i32, periods: i32) -> Vec<Date> {
+        let start: Date = Date::new(15, Month::January, 2025);
+        (0..=periods).map(|k| start + step * k).collect()
+    }
+
+    #[test]
+    fn legs_follow_their_schedules() {
+        let swap: VanillaSwap = VanillaSwap::new(
+            SwapType::Payer,
+            1.0e6,
+            &schedule(364, 2),
+            0.04,
+            &Actual365Fixed::new(),
+            &schedule(182, 4),
+            Rc::new(IborIndex::euribor(Period::new(6, TimeUnit::Months))),
+            &Actual365Fixed::new(),
+        );
+        assert_eq!(swap.fixed_accruals(), &[364.0 / 365.0; 2]);
+        assert_eq!(swap.floating_leg().len(), 4);
+        assert_eq!(swap.floating_leg()[3].nominal(), 1.0e6);
+        // Two TARGET business days before the Wednesday accrual start
+        assert_eq!(
+            swap.floating_leg()[1].fixing_date(),
+            Date::new(14, Month::July, 2025)
+        );
+        assert_eq!(swap.index().name(), "Euribor6M Actual/360");
+        assert_eq!(swap.start_date(), schedule(364, 2)[0]);
+        assert_eq!(swap.maturity_date(), schedule(364, 2)[2]);
+    }
+
+    #[test]
+    fn new_panics_on_mismatched_legs() {
+        let cases: [(Vec<Date>, Vec<Date>, &str); 3] = [
+            (schedule(364, 2), schedule(182, 3), "different maturities"),
+            (schedule(364, 0), schedule(182, 4), "single fixed date"),
+            (
+                vec![
+                    schedule(364, 2)[0],
+                    schedule(364, 2)[2],
+                    schedule(364, 2)[1],
+                ],
+                schedule(364, 1),
+                "unsorted fixed dates",
+            ),
+        ];
+        for (fixed, floating, label) in cases {
+            let result = std::panic::catch_unwind(|| {
+                VanillaSwap::new(
+                    SwapType::Receiver,
+                    1.0,
+                    &fixed,
+                    0.04,
+                    &Actual365Fixed::new(),
+                    &floating,
+                    Rc::new(IborIndex::euribor(Period::new(1, TimeUnit::Years))),
+                    &Actual365Fixed::new(),
+                )
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use crate::indexes::inflation_index::{
+    CpiInterpolationType, ZeroInflationIndex, cpi_lagged_fixing,
+};
+use crate::instruments::swap::SwapType;
+use crate::termstructures::inflation::zero_inflation_term_structure::ZeroInflationTermStructure;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::time::period::Period;
+use crate::types::{Rate, Real, Time};
+
+/*
+Zero-coupon inflation swap (QuantLib ZeroCouponInflationSwap): both
+legs pay once, at maturity. The fixed leg pays
+nominal ((1 + fixed_rate)^T - 1), T being the year fraction from start
+to maturity; the inflation leg pays nominal (I(maturity) / I(start) - 1)
+with CPI observed with the observation lag. A payer swap pays the fixed
+leg and receives inflation.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct ZeroCouponInflationSwap {
+    swap_type: SwapType,
+    nominal: Real,
+    start_date: Date,
+    maturity_date: Date,
+    fixed_rate: Rate,
+    accrual_period: Time,
+    index: ZeroInflationIndex,
+    observation_lag: Period,
+    interpolation: CpiInterpolationType,
+}
+impl ZeroCouponInflationSwap {
+    // Constructor
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        swap_type: SwapType,
+        nominal: Real,
+        start_date: Date,
+        maturity_date: Date,
+        day_counter: &dyn DayCounter,
+        fixed_rate: Rate,
+        index: ZeroInflationIndex,
+        observation_lag: Period,
+        interpolation: CpiInterpolationType,
+    ) -> ZeroCouponInflationSwap {
+        if maturity_date <= start_date {
+            panic!("maturity ({maturity_date}) must be after the start date ({start_date})");
+        }
+        ZeroCouponInflationSwap {
+            swap_type,
+            nominal,
+            start_date,
+            maturity_date,
+            fixed_rate,
+            accrual_period: day_counter.year_fraction(start_date, maturity_date),
+            index,
+            observation_lag,
+            interpolation,
+        }
+    }
+
+    // Inspectors
+    pub fn swap_type(&self) -> SwapType {
+        self.swap_type
+    }
+    pub fn nominal(&self) -> Real {
+        self.nominal
+    }
+    pub fn start_date(&self) -> Date {
+        self.start_date
+    }
+    pub fn maturity_date(&self) -> Date {
+        self.maturity_date
+    }
+    pub fn fixed_rate(&self) -> Rate {
+        self.fixed_rate
+    }
+    pub fn index(&self) -> &ZeroInflationIndex {
+        &self.index
+    }
+    pub fn observation_lag(&self) -> Period {
+        self.observation_lag
+    }
+    pub fn interpolation(&self) -> CpiInterpolationType {
+        self.interpolation
+    }
+
+    pub fn fixed_leg_amount(&self) -> Real {
+        self.nominal * ((1.0 + self.fixed_rate).powf(self.accrual_period) - 1.0)
+    }
+    pub fn inflation_leg_amount(&self, forecast: Option<&dyn ZeroInflationTermStructure>) -> Real {
+        self.nominal * (self.index_ratio(forecast) - 1.0)
+    }
+    // Fixed rate making both legs pay the same amount
+    pub fn fair_rate(&self, forecast: Option<&dyn ZeroInflationTermStructure>) -> Rate {
+        self.index_ratio(forecast).powf(1.0 / self.accrual_period) - 1.0
+    }
+
+    fn index_ratio(&self, forecast: Option<&dyn ZeroInflationTermStructure>) -> Real {
+        let fixing = |date: Date| -> Real {
+            cpi_lagged_fixing(
+                &self.index,
+                date,
+                self.observation_lag,
+                self.interpolation,
+                forecast,
+            )
+        };
+        fixing(self.maturity_date) / fixing(self.start_date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::termstructures::inflation::interpolated_zero_inflation_curve::InterpolatedZeroInflationCurve;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::frequency::Frequency;
+    use crate::time::time_unit::TimeUnit;
+
+    #[test]
+    fn fair_rate_equates_the_legs() {
+        let mut index: ZeroInflationIndex =
+            ZeroInflationIndex::new("CPI", Frequency::Monthly, Period::new(1, TimeUnit::Months));
+        index.add_fixing(Date::new(1, Month::January, 2025), 100.0);
+        let curve: InterpolatedZeroInflationCurve = InterpolatedZeroInflationCurve::new(
+            Date::new(1, Month::April, 2025),
+            &[
+                Date::new(1, Month::January, 2025),
+                Date::new(1, Month::January, 2035),
+            ],
+            &[0.025, 0.025],
+            Frequency::Monthly,
+            Box::new(Actual365Fixed::new()),
+        );
+        let swap = |fixed_rate: Rate| -> ZeroCouponInflationSwap {
+            ZeroCouponInflationSwap::new(
+                SwapType::Payer,
+                1.0e6,
+                Date::new(1, Month::April, 2025),
+                Date::new(1, Month::April, 2030),
+                &Actual365Fixed::new(),
+                fixed_rate,
+                index.clone(),
+                Period::new(3, TimeUnit::Months),
+                CpiInterpolationType::Flat,
+            )
+        };
+        let fair_rate: Rate = swap(0.02).fair_rate(Some(&curve));
+        // Growth over the lagged dates matches the 2.5% curve rate, up to the Actual/365 years
+        assert!((fair_rate - 0.025).abs() < 2.0e-5, "{}", fair_rate);
+        let at_fair: ZeroCouponInflationSwap = swap(fair_rate);
+        assert!(
+            (at_fair.fixed_leg_amount() - at_fair.inflation_leg_amount(Some(&curve))).abs()
+                < 1.0e-8
+        );
+        assert!(swap(0.02).inflation_leg_amount(Some(&curve)) > swap(0.02).fixed_leg_amount());
+    }
+}
@@ -2,19 +2,375 @@
 // so integration tests can say: use code_rust::date::Date;
 
 pub mod time {
+    pub mod business_day_convention;
+    pub mod calendar;
+    pub mod calendars {
+        pub mod null_calendar;
+        pub mod target;
+        pub mod weekends_only;
+    }
     pub mod date;
+    pub mod day_counter;
+    pub mod daycounters {
+        pub mod actual360;
+        pub mod actual365_fixed;
+    }
     pub mod frequency;
     pub mod period;
+    pub mod schedule;
     pub mod time_unit;
     pub mod weekday;
 }
+pub mod cashflows {
+    pub mod cpi_coupon;
+    pub mod dividend;
+    pub mod fixed_rate_coupon;
+    pub mod ibor_coupon;
+    pub mod overnight_indexed_coupon;
+}
+pub mod currencies {
+    pub mod america;
+    pub mod asia;
+    pub mod europe;
+    pub mod oceania;
+}
+pub mod currency;
+pub mod exchange_rate;
+pub mod exchange_rate_manager;
+pub mod exercise;
+pub mod indexes {
+    pub mod ibor_index;
+    pub mod inflation_index;
+}
+pub mod instruments {
+    pub mod asian_option;
+    pub mod barrier_option;
+    pub mod basket_option;
+    pub mod bond;
+    pub mod callable_bond;
+    pub mod cap_floor;
+    pub mod cpi_bond;
+    pub mod credit_default_swap;
+    pub mod double_barrier_option;
+    pub mod fixed_vs_floating_swap;
+    pub mod floating_rate_bond;
+    pub mod fx_forward;
+    pub mod lookback_option;
+    pub mod make_ois;
+    pub mod make_vanilla_swap;
+    pub mod mtm_cross_currency_basis_swap;
+    pub mod overnight_indexed_swap;
+    pub mod payoffs;
+    pub mod spread_option;
+    pub mod swap;
+    pub mod swaption;
+    pub mod vanilla_option;
+    pub mod vanilla_swap;
+    pub mod zero_coupon_inflation_swap;
+}
+pub mod math {
+    pub mod distributions {
+        pub mod beta_distribution;
+        pub mod binomial_distribution;
+        pub mod bivariate_normal_distribution;
+        pub mod chi_square_distribution;
+        pub mod gamma_distribution;
+        pub mod normal_distribution;
+        pub mod poisson_distribution;
+        pub mod student_t_distribution;
+    }
+    pub mod array;
+    pub mod beta;
+    pub mod comparison;
+    pub mod complex;
+    pub mod error_function;
+    pub mod incomplete_gamma;
+    pub mod integrals {
+        pub mod gauss_kronrod_integral;
+        pub mod gauss_lobatto_integral;
+        pub mod gaussian_quadratures;
+        pub mod simpson_integral;
+        pub mod tanh_sinh_integral;
+        pub mod trapezoid_integral;
+    }
+    pub mod interpolations {
+        pub mod bicubic_spline_interpolation;
+        pub mod bilinear_interpolation;
+        pub mod cubic_interpolation;
+        pub mod linear_interpolation;
+    }
+    pub mod matrix;
+    pub mod matrixutilities {
+        pub mod cholesky_decomposition;
+        pub mod lu_decomposition;
+        pub mod pseudo_sqrt;
+        pub mod qr_decomposition;
+        pub mod svd;
+        pub mod symmetric_schur_decomposition;
+    }
+    pub mod modified_bessel;
+    pub mod optimization {
+        pub mod constraint;
+        pub mod end_criteria;
+        pub mod levenberg_marquardt;
+    }
+    pub mod randomnumbers {
+        pub mod box_muller_gaussian_rng;
+        pub mod halton_rsg;
+        pub mod inverse_cumulative_rng;
+        pub mod inverse_cumulative_rsg;
+        pub mod mt19937_uniform_rng;
+        pub mod random_sequence_generator;
+        pub mod rng_traits;
+        pub mod sobol_rsg;
+    }
+    pub mod rounding;
+    pub mod solvers1d {
+        pub mod brent;
+        pub mod newton_safe;
+    }
+    pub mod statistics {
+        pub mod convergence_statistics;
+        pub mod general_statistics;
+        pub mod incremental_statistics;
+        pub mod risk_statistics;
+        pub mod sample_accumulator;
+        pub mod sequence_statistics;
+    }
+}
+pub mod methods {
+    pub mod finitedifferences {
+        pub mod meshers {
+            pub mod concentrating_1d_mesher;
+            pub mod fdm1d_mesher;
+            pub mod fdm_black_scholes_mesher;
+            pub mod fdm_heston_variance_mesher;
+            pub mod uniform_1d_mesher;
+        }
+        pub mod operators {
+            pub mod fdm_black_scholes_op;
+            pub mod fdm_heston_op;
+            pub mod fdm_linear_op_composite;
+            pub mod first_derivative_op;
+            pub mod second_derivative_op;
+            pub mod triple_band_linear_op;
+        }
+        pub mod schemes {
+            pub mod craig_sneyd_scheme;
+            pub mod crank_nicolson_scheme;
+            pub mod douglas_scheme;
+            pub mod explicit_euler_scheme;
+            pub mod fdm_scheme;
+            pub mod fdm_scheme_desc;
+            pub mod hundsdorfer_scheme;
+            pub mod implicit_euler_scheme;
+            pub mod modified_craig_sneyd_scheme;
+        }
+        pub mod solvers {
+            pub mod fdm_backward_solver;
+        }
+        pub mod stepconditions {
+            pub mod fdm_american_step_condition;
+            pub mod fdm_bermudan_step_condition;
+            pub mod fdm_dividend_handler;
+            pub mod fdm_step_condition;
+            pub mod fdm_step_condition_composite;
+        }
+    }
+    pub mod lattices {
+        pub mod binomial_tree;
+        pub mod tree;
+        pub mod trinomial_tree;
+    }
+    pub mod montecarlo {
+        pub mod brownian_bridge;
+        pub mod mc_traits;
+        pub mod monte_carlo_model;
+        pub mod multi_path;
+        pub mod multi_path_generator;
+        pub mod path;
+        pub mod path_generator;
+        pub mod path_pricer;
+        pub mod sample;
+    }
+}
+pub mod models {
+    pub mod calibration_helper;
+    pub mod equity {
+        pub mod heston_model;
+        pub mod heston_model_helper;
+    }
+    pub mod model;
+    pub mod shortrate {
+        pub mod calibrationhelpers {
+            pub mod cap_helper;
+            pub mod swaption_helper;
+        }
+        pub mod one_factor_model;
+        pub mod onefactormodels {
+            pub mod black_karasinski;
+            pub mod cox_ingersoll_ross;
+            pub mod hull_white;
+            pub mod vasicek;
+        }
+        pub mod twofactormodels {
+            pub mod g2;
+        }
+    }
+}
+pub mod money;
+pub mod option;
+pub mod pricingengines {
+    pub mod asian {
+        pub mod analytic_discrete_geometric_average_price_asian_engine;
+        pub mod mc_discrete_arithmetic_ap_engine;
+    }
+    pub mod barrier {
+        pub mod analytic_barrier_engine;
+        pub mod analytic_double_barrier_engine;
+    }
+    pub mod basket {
+        pub mod basket_option_results;
+        pub mod kirk_spread_option_engine;
+        pub mod mc_european_basket_engine;
+        pub mod stulz_engine;
+    }
+    pub mod black_formula;
+    pub mod bond {
+        pub mod bond_results;
+        pub mod discounting_bond_engine;
+        pub mod tree_callable_fixed_rate_bond_engine;
+    }
+    pub mod capfloor {
+        pub mod bachelier_cap_floor_engine;
+        pub mod black_cap_floor_engine;
+        pub mod cap_floor_results;
+    }
+    pub mod credit {
+        pub mod cds_results;
+        pub mod isda_cds_engine;
+        pub mod mid_point_cds_engine;
+    }
+    pub mod fx {
+        pub mod cross_currency_swap_results;
+        pub mod discounting_cross_currency_swap_engine;
+        pub mod discounting_fx_forward_engine;
+        pub mod fx_forward_results;
+    }
+    pub mod greeks;
+    pub mod inflation {
+        pub mod cpi_bond_results;
+        pub mod discounting_cpi_bond_engine;
+        pub mod inflation_swap_results;
+        pub mod zero_coupon_inflation_swap_engine;
+    }
+    pub mod lookback {
+        pub mod analytic_continuous_fixed_lookback_engine;
+        pub mod analytic_continuous_floating_lookback_engine;
+    }
+    pub mod mc_results;
+    pub mod mc_simulation;
+    pub mod swap {
+        pub mod discounting_swap_engine;
+        pub mod swap_results;
+    }
+    pub mod swaption {
+        pub mod bachelier_swaption_engine;
+        pub mod black_swaption_engine;
+        pub mod swaption_results;
+        pub mod tree_swaption_engine;
+    }
+    pub mod vanilla {
+        pub mod analytic_digital_american_engine;
+        pub mod analytic_dividend_european_engine;
+        pub mod analytic_european_engine;
+        pub mod analytic_heston_engine;
+        pub mod binomial_vanilla_engine;
+        pub mod bos_vandermark_dividend_european_engine;
+        pub mod cos_heston_engine;
+        pub mod european_option_results;
+        pub mod fd_black_scholes_vanilla_engine;
+        pub mod fd_heston_vanilla_engine;
+        pub mod vanilla_option_results;
+    }
+}
+pub mod processes {
+    pub mod bates_process;
+    pub mod black_scholes_process;
+    pub mod geometric_brownian_process;
+    pub mod heston_process;
+    pub mod hull_white_process;
+    pub mod ornstein_uhlenbeck_process;
+    pub mod square_root_process;
+    pub mod stochastic_process_array;
+}
+pub mod stochastic_process;
+pub mod termstructures {
+    pub mod credit {
+        pub mod default_probability_helpers;
+        pub mod default_probability_term_structure;
+        pub mod flat_hazard_rate;
+        pub mod interpolated_default_density_curve;
+        pub mod interpolated_hazard_rate_curve;
+        pub mod interpolated_survival_probability_curve;
+        pub mod piecewise_default_curve;
+    }
+    pub mod inflation {
+        pub mod inflation_helpers;
+        pub mod interpolated_zero_inflation_curve;
+        pub mod piecewise_zero_inflation_curve;
+        pub mod seasonality;
+        pub mod zero_inflation_term_structure;
+    }
+    pub mod volatility {
+        pub mod capfloor {
+            pub mod cap_floor_term_vol_surface;
+        }
+        pub mod equityfx {
+            pub mod black_constant_vol;
+            pub mod black_variance_curve;
+            pub mod black_variance_surface;
+            pub mod black_vol_term_structure;
+            pub mod local_vol_surface;
+            pub mod local_vol_term_structure;
+        }
+        pub mod flat_smile_section;
+        pub mod interpolated_smile_section;
+        pub mod optionlet {
+            pub mod constant_optionlet_volatility;
+            pub mod optionlet_stripper1;
+            pub mod optionlet_volatility_structure;
+            pub mod stripped_optionlet_adapter;
+        }
+        pub mod sabr;
+        pub mod sabr_calibrator;
+        pub mod sabr_smile_section;
+        pub mod smile_section;
+        pub mod swaption {
+            pub mod constant_swaption_volatility;
+            pub mod sabr_swaption_volatility_cube;
+            pub mod swaption_volatility_matrix;
+            pub mod swaption_volatility_structure;
+        }
+        pub mod volatility_type;
+    }
+    pub mod yieldcurves {
+        pub mod flat_forward;
+        pub mod yield_term_structure;
+    }
+}
+pub mod time_grid;
+pub mod types;
+
 mod utilities {
     pub mod dateformatter;
 }
 
 pub mod io {
     // date formatting
-    pub use crate::time::date::io::{iso_date, long_date, short_date};
+    pub use crate::time::date::io::{formatted_date, iso_date, long_date, short_date};
+    // period formatting
+    pub use crate::time::period::io::{long_period, short_period};
     // weekday formatting
     pub use crate::time::weekday::io::{long_weekday, short_weekday, shortest_weekday};
     // dataformatters (ordinal, percent, etc.)
@@ -0,0 +1,212 @@
+use crate::types::{Real, Size};
+use std::ops::{Add, AddAssign, Deref, DerefMut, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/*
+1-D array of reals with vector arithmetic (QuantLib Array).
+
+Arrays dereference to slices, so indexing, iteration and the usual
+slice methods are available. Arithmetic between arrays is element by
+element and panics on size mismatch, as in QuantLib; arrays can also be
+combined with scalars.
+*/
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Array {
+    data: Vec<Real>,
+}
+impl Array {
+    // Constructors
+    pub fn new(size: Size, value: Real) -> Array {
+        Array {
+            data: vec![value; size],
+        }
+    }
+    // value, value + increment, value + 2 increment, ...
+    pub fn linear(size: Size, value: Real, increment: Real) -> Array {
+        Array {
+            data: (0..size).map(|i| value + increment * i as Real).collect(),
+        }
+    }
+
+    pub fn dot_product(&self, other: &Array) -> Real {
+        detail::check_sizes(self.len(), other.len());
+        self.iter().zip(other.iter()).map(|(a, b)| a * b).sum()
+    }
+    pub fn norm2(&self) -> Real {
+        self.dot_product(self).sqrt()
+    }
+    pub fn to_vec(&self) -> Vec<Real> {
+        self.data.clone()
+    }
+}
+
+// Traits
+impl From<Vec<Real>> for Array {
+    fn from(data: Vec<Real>) -> Array {
+        Array { data }
+    }
+}
+impl From<&[Real]> for Array {
+    fn from(data: &[Real]) -> Array {
+        Array {
+            data: data.to_vec(),
+        }
+    }
+}
+impl FromIterator<Real> for Array {
+    fn from_iter<I: IntoIterator<Item = Real>>(iter: I) -> Array {
+        Array {
+            data: iter.into_iter().collect(),
+        }
+    }
+}
+impl Deref for Array {
+    type Target = [Real];
+
+    fn deref(&self) -> &[Real] {
+        &self.data
+    }
+}
+impl DerefMut for Array {
+    fn deref_mut(&mut self) -> &mut [Real] {
+        &mut self.data
+    }
+}
+impl Neg for &Array {
+    type Output = Array;
+
+    fn neg(self) -> Array {
+        self.iter().map(|x| -x).collect()
+    }
+}
+impl Add for &Array {
+    type Output = Array;
+
+    fn add(self, other: &Array) -> Array {
+        detail::check_sizes(self.len(), other.len());
+        self.iter().zip(other.iter()).map(|(a, b)| a + b).collect()
+    }
+}
+impl Sub for &Array {
+    type Output = Array;
+
+    fn sub(self, other: &Array) -> Array {
+        detail::check_sizes(self.len(), other.len());
+        self.iter().zip(other.iter()).map(|(a, b)| a - b).collect()
+    }
+}
+// Element-wise product
+impl Mul for &Array {
+    type Output = Array;
+
+    fn mul(self, other: &Array) -> Array {
+        detail::check_sizes(self.len(), other.len());
+        self.iter().zip(other.iter()).map(|(a, b)| a * b).collect()
+    }
+}
+impl Add<Real> for &Array {
+    type Output = Array;
+
+    fn add(self, other: Real) -> Array {
+        self.iter().map(|a| a + other).collect()
+    }
+}
+impl Sub<Real> for &Array {
+    type Output = Array;
+
+    fn sub(self, other: Real) -> Array {
+        self.iter().map(|a| a - other).collect()
+    }
+}
+impl Mul<Real> for &Array {
+    type Output = Array;
+
+    fn mul(self, other: Real) -> Array {
+        self.iter().map(|a| a * other).collect()
+    }
+}
+impl Div<Real> for &Array {
+    type Output = Array;
+
+    fn div(self, other: Real) -> Array {
+        self.iter().map(|a| a / other).collect()
+    }
+}
+impl Mul<&Array> for Real {
+    type Output = Array;
+
+    fn mul(self, other: &Array) -> Array {
+        other * self
+    }
+}
+impl AddAssign<&Array> for Array {
+    fn add_assign(&mut self, other: &Array) {
+        detail::check_sizes(self.len(), other.len());
+        for (a, b) in self.iter_mut().zip(other.iter()) {
+            *a += b;
+        }
+    }
+}
+impl SubAssign<&Array> for Array {
+    fn sub_assign(&mut self, other: &Array) {
+        detail::check_sizes(self.len(), other.len());
+        for (a, b) in self.iter_mut().zip(other.iter()) {
+            *a -= b;
+        }
+    }
+}
+impl MulAssign<Real> for Array {
+    fn mul_assign(&mut self, other: Real) {
+        for a in self.iter_mut() {
+            *a *= other;
+        }
+    }
+}
+
+pub(crate) mod detail {
+    use super::*;
+
+    pub(crate) fn check_sizes(left: Size, right: Size) {
+        if left != right {
+            panic!("arrays with different sizes ({left}, {right}) cannot be combined");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    #[test]
+    fn arithmetic_is_element_wise() {
+        let a: Array = Array::from(vec![1.0, 2.0, 3.0]);
+        let b: Array = Array::linear(3, 0.5, 0.5);
+
+        assert_eq!(b.to_vec(), vec![0.5, 1.0, 1.5]);
+        assert_eq!((&a + &b).to_vec(), vec![1.5, 3.0, 4.5]);
+        assert_eq!((&a - &b).to_vec(), vec![0.5, 1.0, 1.5]);
+        assert_eq!((&a * &b).to_vec(), vec![0.5, 2.0, 4.5]);
+        assert_eq!((&a * 2.0).to_vec(), (2.0 * &a).to_vec());
+        assert_eq!((&a / 2.0).to_vec(), vec![0.5, 1.0, 1.5]);
+        assert_eq!((&a + 1.0).to_vec(), vec![2.0, 3.0, 4.0]);
+        assert_eq!((-&a).to_vec(), vec![-1.0, -2.0, -3.0]);
+        assert_eq!(a.dot_product(&b), 7.0);
+        assert_eq!(Array::from(vec![3.0, 4.0]).norm2(), 5.0);
+
+        let mut c: Array = Array::new(3, 1.0);
+        c += &a;
+        c -= &b;
+        c *= 2.0;
+        c[0] = 0.0;
+        assert_eq!(c.to_vec(), vec![0.0, 4.0, 5.0]);
+        assert_eq!(c.len(), 3);
+        assert_eq!(c.iter().sum::<Real>(), 9.0);
+    }
+
+    #[test]
+    fn size_mismatch_panics() {
+        let (a, b): (Array, Array) = (Array::new(2, 1.0), Array::new(3, 1.0));
+        assert!(panic::catch_unwind(|| &a + &b).is_err());
+        assert!(panic::catch_unwind(|| a.dot_product(&b)).is_err());
+    }
+}
@@ -0,0 +1,82 @@
+use crate::types::{Real, Size};
+
+/*
+Floating-point comparisons (QuantLib comparison.hpp).
+
+Two reals are "close" if they differ by less than n times the machine
+epsilon, relative to their size:
+    - close: the difference is small relative to BOTH numbers
+    - close_enough: the difference is small relative to EITHER number
+When one of the two is zero a relative check makes no sense, so the
+difference is compared against (n * epsilon)^2 instead.
+*/
+pub fn close(x: Real, y: Real) -> bool {
+    close_n(x, y, 42)
+}
+pub fn close_n(x: Real, y: Real, n: Size) -> bool {
+    if x == y {
+        return true;
+    }
+    let diff: Real = (x - y).abs();
+    let tolerance: Real = n as Real * Real::EPSILON;
+    if x * y == 0.0 {
+        return diff < tolerance * tolerance;
+    }
+    diff <= tolerance * x.abs() && diff <= tolerance * y.abs()
+}
+
+pub fn close_enough(x: Real, y: Real) -> bool {
+    close_enough_n(x, y, 42)
+}
+pub fn close_enough_n(x: Real, y: Real, n: Size) -> bool {
+    if x == y {
+        return true;
+    }
+    let diff: Real = (x - y).abs();
+    let tolerance: Real = n as Real * Real::EPSILON;
+    if x * y == 0.0 {
+        return diff < tolerance * tolerance;
+    }
+    diff <= tolerance * x.abs() || diff <= tolerance * y.abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn close_and_close_enough() {
+        let cases: [(Real, Real, bool, bool, &str); 6] = [
+            (1.0, 1.0, true, true, "equal"),
+            (
+                1.0,
+                1.0 + 10.0 * Real::EPSILON,
+                true,
+                true,
+                "few ulps apart",
+            ),
+            (1.0, 1.0 + 1.0e-10, false, false, "far apart"),
+            (0.0, 1.0e-300, true, true, "zero and tiny"),
+            (0.0, 1.0e-20, false, false, "zero and small"),
+            (1.0e-10, -1.0e-10, false, false, "opposite signs"),
+        ];
+
+        for (x, y, expected_close, expected_close_enough, label) in cases {
+            assert_eq!(close(x, y), expected_close, "close ({label})");
+            assert_eq!(
+                close_enough(x, y),
+                expected_close_enough,
+                "close_enough ({label})"
+            );
+        }
+    }
+
+    #[test]
+    fn close_enough_is_weaker_than_close() {
+        // Relative to 1e-3 the gap is large, relative to 1 it is tiny
+        let (x, y): (Real, Real) = (1.0, 1.0 - 1.0e-14);
+        assert!(!close_n(x, y, 1));
+        assert!(close_enough_n(x, y, 100));
+        assert!(close_n(x, y, 100));
+    }
+}
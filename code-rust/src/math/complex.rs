@@ -0,0 +1,198 @@
+use crate::types::Real;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/*
+Complex number, the std::complex<Real> QuantLib relies on for
+characteristic functions.
+
+exp, ln and sqrt return the principal values: the argument lies in
+(-pi, pi] and sqrt has a non-negative real part.
+*/
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Complex {
+    pub re: Real,
+    pub im: Real,
+}
+impl Complex {
+    // Constructor
+    pub fn new(re: Real, im: Real) -> Complex {
+        Complex { re, im }
+    }
+
+    // Inspectors
+    pub fn norm(&self) -> Real {
+        self.re.hypot(self.im)
+    }
+    pub fn arg(&self) -> Real {
+        self.im.atan2(self.re)
+    }
+    pub fn conj(&self) -> Complex {
+        Complex::new(self.re, -self.im)
+    }
+
+    pub fn exp(&self) -> Complex {
+        let modulus: Real = self.re.exp();
+        Complex::new(modulus * self.im.cos(), modulus * self.im.sin())
+    }
+    pub fn ln(&self) -> Complex {
+        Complex::new(self.norm().ln(), self.arg())
+    }
+    pub fn sqrt(&self) -> Complex {
+        if self.re == 0.0 && self.im == 0.0 {
+            return Complex::default();
+        }
+        // Avoids the cancellation of sqrt((|z| - re) / 2) when re > 0
+        let t: Real = (0.5 * (self.norm() + self.re.abs())).sqrt();
+        if self.re >= 0.0 {
+            Complex::new(t, 0.5 * self.im / t)
+        } else {
+            Complex::new(0.5 * self.im.abs() / t, t.copysign(self.im))
+        }
+    }
+}
+
+// Traits
+impl From<Real> for Complex {
+    fn from(re: Real) -> Complex {
+        Complex::new(re, 0.0)
+    }
+}
+impl Neg for Complex {
+    type Output = Complex;
+    fn neg(self) -> Complex {
+        Complex::new(-self.re, -self.im)
+    }
+}
+impl Add for Complex {
+    type Output = Complex;
+    fn add(self, other: Complex) -> Complex {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+impl Sub for Complex {
+    type Output = Complex;
+    fn sub(self, other: Complex) -> Complex {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+impl Mul for Complex {
+    type Output = Complex;
+    fn mul(self, other: Complex) -> Complex {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+impl Div for Complex {
+    type Output = Complex;
+    fn div(self, other: Complex) -> Complex {
+        // Smith's algorithm, scaling by the larger component of the divisor
+        if other.re.abs() >= other.im.abs() {
+            let ratio: Real = other.im / other.re;
+            let denominator: Real = other.re + other.im * ratio;
+            Complex::new(
+                (self.re + self.im * ratio) / denominator,
+                (self.im - self.re * ratio) / denominator,
+            )
+        } else {
+            let ratio: Real = other.re / other.im;
+            let denominator: Real = other.re * ratio + other.im;
+            Complex::new(
+                (self.re * ratio + self.im) / denominator,
+                (self.im * ratio - self.re) / denominator,
+            )
+        }
+    }
+}
+impl Add<Real> for Complex {
+    type Output = Complex;
+    fn add(self, other: Real) -> Complex {
+        Complex::new(self.re + other, self.im)
+    }
+}
+impl Sub<Real> for Complex {
+    type Output = Complex;
+    fn sub(self, other: Real) -> Complex {
+        Complex::new(self.re - other, self.im)
+    }
+}
+impl Mul<Real> for Complex {
+    type Output = Complex;
+    fn mul(self, other: Real) -> Complex {
+        Complex::new(self.re * other, self.im * other)
+    }
+}
+impl Div<Real> for Complex {
+    type Output = Complex;
+    fn div(self, other: Real) -> Complex {
+        Complex::new(self.re / other, self.im / other)
+    }
+}
+impl Add<Complex> for Real {
+    type Output = Complex;
+    fn add(self, other: Complex) -> Complex {
+        other + self
+    }
+}
+impl Sub<Complex> for Real {
+    type Output = Complex;
+    fn sub(self, other: Complex) -> Complex {
+        Complex::new(self - other.re, -other.im)
+    }
+}
+impl Mul<Complex> for Real {
+    type Output = Complex;
+    fn mul(self, other: Complex) -> Complex {
+        other * self
+    }
+}
+impl Div<Complex> for Real {
+    type Output = Complex;
+    fn div(self, other: Complex) -> Complex {
+        Complex::from(self) / other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    fn assert_close(calculated: Complex, expected: Complex) {
+        assert!(
+            (calculated - expected).norm() < 1.0e-14,
+            "{calculated:?} vs {expected:?}"
+        );
+    }
+
+    #[test]
+    fn arithmetic_follows_the_field_rules() {
+        let z: Complex = Complex::new(1.5, -2.0);
+        let w: Complex = Complex::new(-0.5, 3.0);
+        assert_close(z * w, Complex::new(5.25, 5.5));
+        assert_close(z * w / w, z);
+        assert_close((z + w) - w, z);
+        assert_close(1.0 / Complex::new(0.0, 1.0), Complex::new(0.0, -1.0));
+        assert_close(2.0 - z, Complex::new(0.5, 2.0));
+        assert_eq!((z * z.conj()).im, 0.0);
+        assert_eq!(z.conj().norm(), 2.5);
+    }
+
+    #[test]
+    fn elementary_functions_return_principal_values() {
+        assert_close(Complex::new(0.0, PI).exp(), Complex::from(-1.0));
+        assert_close(Complex::from(-1.0).ln(), Complex::new(0.0, PI));
+        assert_close(Complex::new(0.3, -2.0).exp().ln(), Complex::new(0.3, -2.0));
+        let roots: [(Complex, Complex); 4] = [
+            (Complex::from(4.0), Complex::from(2.0)),
+            (Complex::from(-4.0), Complex::new(0.0, 2.0)),
+            (Complex::new(0.0, 2.0), Complex::new(1.0, 1.0)),
+            (Complex::new(-3.0, -4.0), Complex::new(1.0, -2.0)),
+        ];
+        for (z, expected) in roots {
+            assert_close(z.sqrt(), expected);
+            assert!(z.sqrt().re >= 0.0);
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::types::{Real, Size};
+
+/*
+Peizer-Pratt method 2 inversion (QuantLib PeizerPrattMethod2Inversion):
+the probability p such that the binomial distribution B(n, p)
+approximates N(z), with n odd.
+
+    h = (z / (n + 1/3 + 0.1 / (n + 1)))^2
+    p = 1/2 + sign(z) sqrt(1/4 (1 - exp(-h (n + 1/6))))
+
+Used by the Leisen-Reimer tree to match the Black-Scholes d1 and d2.
+*/
+pub fn peizer_pratt_method2_inversion(z: Real, n: Size) -> Real {
+    if n % 2 == 0 {
+        panic!("n ({n}) must be an odd number");
+    }
+    let n: Real = n as Real;
+    let mut result: Real = z / (n + 1.0 / 3.0 + 0.1 / (n + 1.0));
+    result *= result;
+    result = (-result * (n + 1.0 / 6.0)).exp();
+    let sign: Real = if z > 0.0 { 1.0 } else { -1.0 };
+    0.5 + sign * (0.25 * (1.0 - result)).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    #[test]
+    fn inversion_is_symmetric_and_monotonic() {
+        assert_eq!(peizer_pratt_method2_inversion(0.0, 11), 0.5);
+        for z in [0.1, 0.5, 1.0, 2.5] {
+            let p: Real = peizer_pratt_method2_inversion(z, 101);
+            assert!(p > 0.5 && p < 1.0);
+            assert!((p + peizer_pratt_method2_inversion(-z, 101) - 1.0).abs() < 1.0e-15);
+        }
+        assert!(
+            peizer_pratt_method2_inversion(1.0, 101) < peizer_pratt_method2_inversion(1.5, 101)
+        );
+    }
+
+    #[test]
+    fn inversion_panics_on_even_n() {
+        assert!(panic::catch_unwind(|| peizer_pratt_method2_inversion(1.0, 10)).is_err());
+    }
+}
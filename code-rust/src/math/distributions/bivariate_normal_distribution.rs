@@ -0,0 +1,330 @@
+use crate::math::distributions::normal_distribution::CumulativeNormalDistribution;
+use crate::types::Real;
+use std::f64::consts::PI;
+
+/*
+Cumulative bivariate normal distribution (QuantLib
+BivariateCumulativeNormalDistributionWe04DP):
+    M(x, y, rho) = P(X <= x, Y <= y)
+for standard normals X and Y with correlation rho.
+
+Uses Genz's algorithm (Statistics and Computing 14, 2004, as translated
+by West): Gauss-Legendre quadrature of Plackett's integral over
+asin(rho) with 6, 12 or 20 points depending on |rho|, and for
+|rho| > 0.925 a series expansion of the integrand near |rho| = 1. The
+result is accurate to about 1e-15.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct BivariateCumulativeNormalDistribution {
+    correlation: Real,
+    cumulative_normal: CumulativeNormalDistribution,
+}
+impl BivariateCumulativeNormalDistribution {
+    // Gauss-Legendre weights and abscissas (negative half) with 6, 12 and 20 points
+    const WEIGHTS: [&'static [Real]; 3] = [
+        &[0.1713244923791705, 0.3607615730481384, 0.4679139345726904],
+        &[
+            0.04717533638651177,
+            0.1069393259953183,
+            0.1600783285433464,
+            0.2031674267230659,
+            0.2334925365383547,
+            0.2491470458134029,
+        ],
+        &[
+            0.01761400713915212,
+            0.04060142980038694,
+            0.06267204833410906,
+            0.08327674157670475,
+            0.1019301198172404,
+            0.1181945319615184,
+            0.1316886384491766,
+            0.1420961093183821,
+            0.1491729864726037,
+            0.1527533871307259,
+        ],
+    ];
+    const ABSCISSAS: [&'static [Real]; 3] = [
+        &[-0.9324695142031522, -0.6612093864662647, -0.238619186083197],
+        &[
+            -0.9815606342467191,
+            -0.904117256370475,
+            -0.769902674194305,
+            -0.5873179542866171,
+            -0.3678314989981802,
+            -0.1252334085114692,
+        ],
+        &[
+            -0.9931285991850949,
+            -0.9639719272779138,
+            -0.912234428251326,
+            -0.8391169718222188,
+            -0.7463319064601508,
+            -0.636053680726515,
+            -0.5108670019508271,
+            -0.3737060887154196,
+            -0.2277858511416451,
+            -0.07652652113349733,
+        ],
+    ];
+
+    // Constructor
+    pub fn new(correlation: Real) -> BivariateCumulativeNormalDistribution {
+        if !(-1.0..=1.0).contains(&correlation) {
+            panic!("correlation ({correlation}) must be in [-1, 1]");
+        }
+        BivariateCumulativeNormalDistribution {
+            correlation,
+            cumulative_normal: CumulativeNormalDistribution::default(),
+        }
+    }
+
+    // Inspectors
+    pub fn correlation(&self) -> Real {
+        self.correlation
+    }
+
+    pub fn value(&self, x: Real, y: Real) -> Real {
+        // Genz computes the upper probability P(X > h, Y > k)
+        self.upper_probability(-x, -y)
+    }
+
+    fn upper_probability(&self, h: Real, k: Real) -> Real {
+        let phi = |x: Real| -> Real { self.cumulative_normal.value(x) };
+        let r: Real = self.correlation;
+        let ng: usize = if r.abs() < 0.3 {
+            0
+        } else if r.abs() < 0.75 {
+            1
+        } else {
+            2
+        };
+        let (weights, abscissas): (&[Real], &[Real]) = (
+            BivariateCumulativeNormalDistribution::WEIGHTS[ng],
+            BivariateCumulativeNormalDistribution::ABSCISSAS[ng],
+        );
+
+        let mut hk: Real = h * k;
+        if r.abs() < 0.925 {
+            let hs: Real = 0.5 * (h * h + k * k);
+            let asr: Real = r.asin();
+            let mut bvn: Real = 0.0;
+            for (w, x) in weights.iter().zip(abscissas.iter()) {
+                for sign in [-1.0, 1.0] {
+                    let sn: Real = (0.5 * asr * (sign * x + 1.0)).sin();
+                    bvn += w * ((sn * hk - hs) / (1.0 - sn * sn)).exp();
+                }
+            }
+            return bvn * asr / (4.0 * PI) + phi(-h) * phi(-k);
+        }
+
+        let k: Real = if r < 0.0 {
+            hk = -hk;
+            -k
+        } else {
+            k
+        };
+        let mut bvn: Real = 0.0;
+        if r.abs() < 1.0 {
+            let a_s: Real = (1.0 - r) * (1.0 + r);
+            let mut a: Real = a_s.sqrt();
+            let bs: Real = (h - k) * (h - k);
+            let c: Real = (4.0 - hk) / 8.0;
+            let d: Real = (12.0 - hk) / 16.0;
+            bvn = a
+                * (-0.5 * (bs / a_s + hk)).exp()
+                * (1.0 - c * (bs - a_s) * (1.0 - d * bs / 5.0) / 3.0 + c * d * a_s * a_s / 5.0);
+            if hk > -160.0 {
+                let b: Real = bs.sqrt();
+                bvn -= (-0.5 * hk).exp()
+                    * (2.0 * PI).sqrt()
+                    * phi(-b / a)
+                    * b
+                    * (1.0 - c * bs * (1.0 - d * bs / 5.0) / 3.0);
+            }
+            a *= 0.5;
+            for (w, x) in weights.iter().zip(abscissas.iter()) {
+                for sign in [-1.0, 1.0] {
+                    let xs: Real = (a * (sign * x + 1.0)).powi(2);
+                    let rs: Real = (1.0 - xs).sqrt();
+                    bvn += a
+                        * w
+                        * ((-bs / (2.0 * xs) - hk / (1.0 + rs)).exp() / rs
+                            - (-0.5 * (bs / xs + hk)).exp() * (1.0 + c * xs * (1.0 + d * xs)));
+                }
+            }
+            bvn = -bvn / (2.0 * PI);
+        }
+        if r > 0.0 {
+            bvn + phi(-h.max(k))
+        } else {
+            -bvn + (phi(-h) - phi(-k)).max(0.0)
+        }
+    }
+}
+
+/*
+Cumulative bivariate normal distribution by Drezner's algorithm
+(QuantLib BivariateCumulativeNormalDistributionDr78, after Z. Drezner,
+"Computation of the bivariate normal integral", Mathematics of
+Computation 32, 1978).
+
+For x, y <= 0 and rho <= 0 the integral is computed by a 5 x 5 point
+Gauss quadrature; the other sign combinations are reduced to that case.
+Faster but less accurate than the Genz algorithm: the absolute error
+stays below 1e-6. The correlation must lie in the open interval (-1, 1).
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct BivariateCumulativeNormalDistributionDr78 {
+    correlation: Real,
+    // 1 - rho^2
+    complement: Real,
+    cumulative_normal: CumulativeNormalDistribution,
+}
+impl BivariateCumulativeNormalDistributionDr78 {
+    const WEIGHTS: [Real; 5] = [
+        0.24840615,
+        0.39233107,
+        0.21141819,
+        0.03324666,
+        0.00082485334,
+    ];
+    const ABSCISSAS: [Real; 5] = [0.10024215, 0.48281397, 1.0609498, 1.7797294, 2.6697604];
+
+    // Constructor
+    pub fn new(correlation: Real) -> BivariateCumulativeNormalDistributionDr78 {
+        if correlation <= -1.0 || correlation >= 1.0 {
+            panic!("correlation ({correlation}) must be in (-1, 1)");
+        }
+        BivariateCumulativeNormalDistributionDr78 {
+            correlation,
+            complement: 1.0 - correlation * correlation,
+            cumulative_normal: CumulativeNormalDistribution::default(),
+        }
+    }
+
+    // Inspectors
+    pub fn correlation(&self) -> Real {
+        self.correlation
+    }
+
+    pub fn value(&self, x: Real, y: Real) -> Real {
+        let (n_x, n_y): (Real, Real) = (
+            self.cumulative_normal.value(x),
+            self.cumulative_normal.value(y),
+        );
+        if 1.0 - n_x.max(n_y) < 1.0e-15 || n_x.min(n_y) < 1.0e-15 {
+            return n_x.min(n_y);
+        }
+        let rho: Real = self.correlation;
+        let sign = |z: Real| -> Real { if z > 0.0 { 1.0 } else { -1.0 } };
+        if x <= 0.0 && y <= 0.0 && rho <= 0.0 {
+            let scale: Real = (2.0 * self.complement).sqrt();
+            let (a, b): (Real, Real) = (x / scale, y / scale);
+            let (weights, abscissas): (&[Real; 5], &[Real; 5]) = (
+                &BivariateCumulativeNormalDistributionDr78::WEIGHTS,
+                &BivariateCumulativeNormalDistributionDr78::ABSCISSAS,
+            );
+            let mut sum: Real = 0.0;
+            for (w_i, y_i) in weights.iter().zip(abscissas.iter()) {
+                for (w_j, y_j) in weights.iter().zip(abscissas.iter()) {
+                    sum += w_i
+                        * w_j
+                        * (a * (2.0 * y_i - a)
+                            + b * (2.0 * y_j - b)
+                            + 2.0 * rho * (y_i - a) * (y_j - b))
+                            .exp();
+                }
+            }
+            self.complement.sqrt() / PI * sum
+        } else if x <= 0.0 && y >= 0.0 && rho >= 0.0 {
+            n_x - BivariateCumulativeNormalDistributionDr78::new(-rho).value(x, -y)
+        } else if x >= 0.0 && y <= 0.0 && rho >= 0.0 {
+            n_y - BivariateCumulativeNormalDistributionDr78::new(-rho).value(-x, y)
+        } else if x >= 0.0 && y >= 0.0 && rho <= 0.0 {
+            n_x + n_y - 1.0 + self.value(-x, -y)
+        } else {
+            // x y rho > 0: split along the ray through (x, y)
+            let norm: Real = (x * x - 2.0 * rho * x * y + y * y).sqrt();
+            let rho_x: Real = (rho * x - y) * sign(x) / norm;
+            let rho_y: Real = (rho * y - x) * sign(y) / norm;
+            let delta: Real = 0.25 * (1.0 - sign(x) * sign(y));
+            BivariateCumulativeNormalDistributionDr78::new(rho_x).value(x, 0.0)
+                + BivariateCumulativeNormalDistributionDr78::new(rho_y).value(y, 0.0)
+                - delta
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    #[test]
+    fn values_match_numerical_integration() {
+        // Reference values from high-precision quadrature of phi(x) N((y - rho x) / sqrt(1 - rho^2))
+        let cases: [(Real, Real, Real, Real); 7] = [
+            (0.0, 0.0, 0.5, 1.0 / 3.0),
+            (1.0, -0.5, 0.2, 0.2757558084962052),
+            (-1.5, 0.3, -0.6, 0.010000426577621448),
+            (0.7, 1.2, 0.95, 0.7560828325720843),
+            (-0.4, -2.0, -0.97, 4.08837073597557e-25),
+            (2.5, -1.0, 0.999, 0.15865525393145705),
+            (-3.0, -3.0, 0.8, 0.00037209239626781635),
+        ];
+
+        for (x, y, rho, expected) in cases {
+            let value: Real = BivariateCumulativeNormalDistribution::new(rho).value(x, y);
+            assert!(
+                (value - expected).abs() < 1.0e-14,
+                "M({x}, {y}, {rho}) = {value}, expected {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn limiting_correlations() {
+        let n: CumulativeNormalDistribution = CumulativeNormalDistribution::default();
+        let cases: [(Real, Real); 3] = [(0.3, -0.8), (-1.2, 1.5), (2.0, 0.1)];
+
+        for (x, y) in cases {
+            let independent: Real = BivariateCumulativeNormalDistribution::new(0.0).value(x, y);
+            assert!((independent - n.value(x) * n.value(y)).abs() < 1.0e-15);
+            let comonotonic: Real = BivariateCumulativeNormalDistribution::new(1.0).value(x, y);
+            assert!((comonotonic - n.value(x.min(y))).abs() < 1.0e-15);
+            let countermonotonic: Real =
+                BivariateCumulativeNormalDistribution::new(-1.0).value(x, y);
+            assert!((countermonotonic - (n.value(x) + n.value(y) - 1.0).max(0.0)).abs() < 1.0e-15);
+        }
+    }
+
+    #[test]
+    fn drezner_matches_genz() {
+        let mut rho: Real = -0.95;
+        while rho < 0.96 {
+            for x in [-3.0, -1.2, -0.3, 0.0, 0.5, 1.7, 2.8] {
+                for y in [-2.5, -0.8, 0.0, 0.4, 1.1, 3.0] {
+                    let genz: Real = BivariateCumulativeNormalDistribution::new(rho).value(x, y);
+                    let drezner: Real =
+                        BivariateCumulativeNormalDistributionDr78::new(rho).value(x, y);
+                    assert!(
+                        (drezner - genz).abs() < 1.0e-6,
+                        "M({x}, {y}, {rho}): Drezner {drezner} vs Genz {genz}"
+                    );
+                }
+            }
+            rho += 0.05;
+        }
+        let result = panic::catch_unwind(|| BivariateCumulativeNormalDistributionDr78::new(1.0));
+        assert!(result.is_err(), "expected panic for unit correlation");
+    }
+
+    #[test]
+    fn new_panics_on_invalid_correlation() {
+        for rho in [-1.01, 1.5] {
+            let result = panic::catch_unwind(|| BivariateCumulativeNormalDistribution::new(rho));
+            assert!(result.is_err(), "expected panic for correlation {rho}");
+        }
+    }
+}
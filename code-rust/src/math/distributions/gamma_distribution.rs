@@ -0,0 +1,116 @@
+use crate::math::incomplete_gamma::incomplete_gamma_function;
+use crate::types::Real;
+
+/*
+Gamma function (QuantLib GammaFunction), through the Lanczos
+approximation of ln(Gamma(x)) for x > 0:
+    Gamma(x) = sqrt(2 pi) / x * S(x) (x + 5.5)^(x + 0.5) exp(-(x + 5.5))
+with S(x) = c0 + sum_i c_i / (x + i), accurate to about 2e-10.
+*/
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct GammaFunction;
+impl GammaFunction {
+    const C0: Real = 1.000000000190015;
+    const C: [Real; 6] = [
+        76.18009172947146,
+        -86.50532032941677,
+        24.01409824083091,
+        -1.231739572450155,
+        0.001208650973866179,
+        -0.5395239384953e-5,
+    ];
+
+    pub fn log_value(&self, x: Real) -> Real {
+        if x <= 0.0 {
+            panic!("positive argument required ({x})");
+        }
+        let mut temp: Real = x + 5.5;
+        temp -= (x + 0.5) * temp.ln();
+        let mut ser: Real = GammaFunction::C0;
+        for (i, c) in GammaFunction::C.iter().enumerate() {
+            ser += c / (x + (i + 1) as Real);
+        }
+        -temp + (2.5066282746310005 * ser / x).ln()
+    }
+
+    pub fn value(&self, x: Real) -> Real {
+        self.log_value(x).exp()
+    }
+}
+
+/*
+Cumulative gamma distribution with shape a and unit scale (QuantLib
+CumulativeGammaDistribution): the regularized incomplete gamma function
+P(a, x), null for x <= 0.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CumulativeGammaDistribution {
+    a: Real,
+}
+impl CumulativeGammaDistribution {
+    // Constructor
+    pub fn new(a: Real) -> CumulativeGammaDistribution {
+        if a <= 0.0 {
+            panic!("invalid parameter for gamma distribution ({a})");
+        }
+        CumulativeGammaDistribution { a }
+    }
+
+    pub fn value(&self, x: Real) -> Real {
+        if x <= 0.0 {
+            0.0
+        } else {
+            incomplete_gamma_function(self.a, x)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_factorials_and_half_integers() {
+        let gamma: GammaFunction = GammaFunction;
+        let mut factorial: Real = 1.0;
+        for n in 1..20 {
+            assert!(
+                ((gamma.value(n as Real) - factorial) / factorial).abs() < 1.0e-9,
+                "Gamma({}) = {} vs {}",
+                n,
+                gamma.value(n as Real),
+                factorial
+            );
+            factorial *= n as Real;
+        }
+        let sqrt_pi: Real = std::f64::consts::PI.sqrt();
+        assert!((gamma.value(0.5) - sqrt_pi).abs() < 1.0e-9);
+        assert!((gamma.value(1.5) - 0.5 * sqrt_pi).abs() < 1.0e-9);
+    }
+
+    #[test]
+    fn cumulative_gamma_matches_reference_values() {
+        // Reference values from mpmath gammainc(a, 0, x, regularized=True)
+        let cases: [(Real, Real, Real); 3] = [
+            (2.0, 1.0, 0.2642411176571154),
+            (0.5, 2.0, 0.9544997361036416),
+            (7.5, 5.0, 0.18026008049639853),
+        ];
+        for (a, x, expected) in cases {
+            let calculated: Real = CumulativeGammaDistribution::new(a).value(x);
+            assert!(
+                (calculated - expected).abs() < 1.0e-9,
+                "P({a}, {x}) = {calculated}, expected {expected}"
+            );
+        }
+        assert_eq!(CumulativeGammaDistribution::new(2.0).value(-1.0), 0.0);
+        let result = std::panic::catch_unwind(|| CumulativeGammaDistribution::new(0.0));
+        assert!(result.is_err(), "expected panic for null shape");
+    }
+
+    #[test]
+    fn log_value_panics_on_non_positive_argument() {
+        let result = std::panic::catch_unwind(|| GammaFunction.log_value(0.0));
+        assert!(result.is_err());
+    }
+}
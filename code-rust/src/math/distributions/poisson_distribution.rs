@@ -0,0 +1,148 @@
+use crate::math::distributions::gamma_distribution::GammaFunction;
+use crate::math::incomplete_gamma::complementary_incomplete_gamma_function;
+use crate::types::{Real, Size};
+
+// Poisson probability P(N = k) = exp(-mu) mu^k / k! (QuantLib PoissonDistribution)
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct PoissonDistribution {
+    mu: Real,
+}
+impl PoissonDistribution {
+    // Constructor
+    pub fn new(mu: Real) -> PoissonDistribution {
+        if mu < 0.0 {
+            panic!("mu must be non negative ({mu} not allowed)");
+        }
+        PoissonDistribution { mu }
+    }
+
+    pub fn value(&self, k: Size) -> Real {
+        if self.mu == 0.0 {
+            return if k == 0 { 1.0 } else { 0.0 };
+        }
+        (k as Real * self.mu.ln() - self.mu - detail::ln_factorial(k)).exp()
+    }
+}
+
+/*
+Cumulative Poisson distribution P(N <= k) (QuantLib
+CumulativePoissonDistribution): Q(k + 1, mu), with Q the complementary
+regularized incomplete gamma function.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CumulativePoissonDistribution {
+    mu: Real,
+}
+impl CumulativePoissonDistribution {
+    // Constructor
+    pub fn new(mu: Real) -> CumulativePoissonDistribution {
+        if mu < 0.0 {
+            panic!("mu must be non negative ({mu} not allowed)");
+        }
+        CumulativePoissonDistribution { mu }
+    }
+
+    pub fn value(&self, k: Size) -> Real {
+        complementary_incomplete_gamma_function(k as Real + 1.0, self.mu)
+    }
+}
+
+/*
+Inverse cumulative Poisson distribution (QuantLib InverseCumulativePoisson):
+the smallest k with P(N <= k) >= x, found by summing the probabilities.
+Returned as a real, Real::MAX for x = 1.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct InverseCumulativePoisson {
+    lambda: Real,
+}
+impl InverseCumulativePoisson {
+    // Constructor
+    pub fn new(lambda: Real) -> InverseCumulativePoisson {
+        if lambda <= 0.0 {
+            panic!("lambda must be positive ({lambda} not allowed)");
+        }
+        InverseCumulativePoisson { lambda }
+    }
+
+    pub fn value(&self, x: Real) -> Real {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("undefined outside the (0, 1) interval ({x})");
+        }
+        if x == 1.0 {
+            return Real::MAX;
+        }
+        // P(N = k) by recurrence from P(N = 0)
+        let mut probability: Real = (-self.lambda).exp();
+        let mut sum: Real = probability;
+        let mut k: Size = 0;
+        while sum < x {
+            k += 1;
+            probability *= self.lambda / k as Real;
+            sum += probability;
+        }
+        k as Real
+    }
+}
+
+pub(crate) mod detail {
+    use super::*;
+
+    // ln(k!), summed exactly below 171 where k! is finite
+    pub(crate) fn ln_factorial(k: Size) -> Real {
+        if k < 171 {
+            (2..=k).map(|j| (j as Real).ln()).sum()
+        } else {
+            GammaFunction.log_value(k as Real + 1.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn probabilities_match_reference_values() {
+        // Reference values from mpmath: probability and cumulated probability
+        let cases: [(Real, Size, Real, Real); 4] = [
+            (2.5, 0, 0.0820849986238988, 0.0820849986238988),
+            (2.5, 3, 0.21376301724973645, 0.757576133133066),
+            (10.0, 15, 0.03471806963068412, 0.9512595966960213),
+            (0.3, 2, 0.0333368199306773, 0.9964005068169105),
+        ];
+        for (mu, k, expected, expected_cumulative) in cases {
+            let probability: Real = PoissonDistribution::new(mu).value(k);
+            assert!(
+                (probability / expected - 1.0).abs() < 1.0e-13,
+                "P(N = {k}) = {probability}, expected {expected}"
+            );
+            let cumulative: Real = CumulativePoissonDistribution::new(mu).value(k);
+            assert!(
+                (cumulative - expected_cumulative).abs() < 1.0e-9,
+                "P(N <= {k}) = {cumulative}, expected {expected_cumulative}"
+            );
+        }
+        assert_eq!(PoissonDistribution::new(0.0).value(0), 1.0);
+        assert_eq!(PoissonDistribution::new(0.0).value(2), 0.0);
+    }
+
+    #[test]
+    fn inverse_cumulative_finds_the_quantile() {
+        let inverse: InverseCumulativePoisson = InverseCumulativePoisson::new(2.5);
+        let cumulative: CumulativePoissonDistribution = CumulativePoissonDistribution::new(2.5);
+        for x in [0.0, 0.05, 0.3, 0.5, 0.757, 0.758, 0.99, 0.999999] {
+            let k: Size = inverse.value(x) as Size;
+            assert!(
+                cumulative.value(k) >= x - 1.0e-12,
+                "quantile {k} too low for {x}"
+            );
+            if k > 0 {
+                assert!(cumulative.value(k - 1) < x, "quantile {k} too high for {x}");
+            }
+        }
+        assert_eq!(inverse.value(1.0), Real::MAX);
+        let result = std::panic::catch_unwind(|| inverse.value(1.5));
+        assert!(result.is_err(), "expected panic for x above 1");
+    }
+}
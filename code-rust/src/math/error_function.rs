@@ -0,0 +1,272 @@
+// Coefficients are kept digit for digit as published in fdlibm
+#![allow(clippy::excessive_precision)]
+
+use crate::types::Real;
+
+/*
+Error function erf(x) and complementary error function erfc(x) = 1 - erf(x).
+
+Same algorithm as QuantLib ErrorFunction, which is a port of the
+Sun Microsystems fdlibm implementation (s_erf.c):
+    - |x| < 0.84375    -> rational approximation of erf(x)/x
+    - |x| < 1.25       -> rational approximation around x = 1
+    - larger |x|       -> erfc(x) = exp(-x^2 - 0.5625 + R/S) / x
+
+erfc is computed directly (not as 1 - erf) so it stays accurate in the
+tails, where 1 - erf(x) would lose all the significant digits.
+*/
+pub fn erf(x: Real) -> Real {
+    let ax: Real = x.abs();
+
+    if ax < 0.84375 {
+        if ax < 3.7252902984e-09 {
+            // |x| < 2^-28: erf(x) ~ x * 2/sqrt(pi)
+            return x + detail::EFX * x;
+        }
+        let z: Real = x * x;
+        return x + x * detail::small_ratio(z);
+    }
+    if ax < 1.25 {
+        let erf_near_one: Real = detail::ERX + detail::near_one_ratio(ax - 1.0);
+        return if x >= 0.0 {
+            erf_near_one
+        } else {
+            -erf_near_one
+        };
+    }
+    if ax >= 6.0 {
+        // erf(6) = 1 - 2e-17, i.e. 1 in double precision
+        return if x >= 0.0 { 1.0 } else { -1.0 };
+    }
+
+    let tail: Real = detail::tail(ax);
+    if x >= 0.0 { 1.0 - tail } else { tail - 1.0 }
+}
+
+pub fn erfc(x: Real) -> Real {
+    let ax: Real = x.abs();
+
+    if ax < 0.84375 {
+        if ax < 1.3877787807814457e-17 {
+            // |x| < 2^-56
+            return 1.0 - x;
+        }
+        let z: Real = x * x;
+        let y: Real = detail::small_ratio(z);
+        return if x < 0.25 {
+            1.0 - (x + x * y)
+        } else {
+            0.5 - (x * y + (x - 0.5))
+        };
+    }
+    if ax < 1.25 {
+        let ratio: Real = detail::near_one_ratio(ax - 1.0);
+        return if x >= 0.0 {
+            1.0 - detail::ERX - ratio
+        } else {
+            1.0 + (detail::ERX + ratio)
+        };
+    }
+    if ax < 28.0 {
+        if x < -6.0 {
+            return 2.0;
+        }
+        let tail: Real = detail::tail(ax);
+        return if x > 0.0 { tail } else { 2.0 - tail };
+    }
+
+    if x > 0.0 { 0.0 } else { 2.0 }
+}
+
+// Private
+mod detail {
+    use crate::types::Real;
+
+    pub(crate) const ERX: Real = 8.45062911510467529297e-01;
+    pub(crate) const EFX: Real = 1.28379167095512586316e-01;
+
+    // Coefficients for approximation to erf in [0, 0.84375]
+    const PP: [Real; 5] = [
+        1.28379167095512558561e-01,
+        -3.25042107247001499370e-01,
+        -2.84817495755985104766e-02,
+        -5.77027029648944159157e-03,
+        -2.37630166566501626084e-05,
+    ];
+    const QQ: [Real; 5] = [
+        3.97917223959155352819e-01,
+        6.50222499887672944485e-02,
+        5.08130628187576562776e-03,
+        1.32494738004321644526e-04,
+        -3.96022827877536812320e-06,
+    ];
+
+    // Coefficients for approximation to erf in [0.84375, 1.25]
+    const PA: [Real; 7] = [
+        -2.36211856075265944077e-03,
+        4.14856118683748331666e-01,
+        -3.72207876035701323847e-01,
+        3.18346619901161753674e-01,
+        -1.10894694282396677476e-01,
+        3.54783043256182359371e-02,
+        -2.16637559486879084300e-03,
+    ];
+    const QA: [Real; 6] = [
+        1.06420880400844228286e-01,
+        5.40397917702171048937e-01,
+        7.18286544141962662868e-02,
+        1.26171219808761642112e-01,
+        1.36370839120290507362e-02,
+        1.19844998467991074170e-02,
+    ];
+
+    // Coefficients for approximation to erfc in [1.25, 1/0.35]
+    const RA: [Real; 8] = [
+        -9.86494403484714822705e-03,
+        -6.93858572707181764372e-01,
+        -1.05586262253232909814e+01,
+        -6.23753324503260060396e+01,
+        -1.62396669462573470355e+02,
+        -1.84605092906711035994e+02,
+        -8.12874355063065934246e+01,
+        -9.81432934416914548592e+00,
+    ];
+    const SA: [Real; 8] = [
+        1.96512716674392571292e+01,
+        1.37657754143519042600e+02,
+        4.34565877475229228821e+02,
+        6.45387271733267880336e+02,
+        4.29008140027567833386e+02,
+        1.08635005541779435134e+02,
+        6.57024977031928170135e+00,
+        -6.04244152148580987438e-02,
+    ];
+
+    // Coefficients for approximation to erfc in [1/0.35, 28]
+    const RB: [Real; 7] = [
+        -9.86494292470009928597e-03,
+        -7.99283237680523006574e-01,
+        -1.77579549177547519889e+01,
+        -1.60636384855821916062e+02,
+        -6.37566443368389627722e+02,
+        -1.02509513161107724954e+03,
+        -4.83519191608651397019e+02,
+    ];
+    const SB: [Real; 7] = [
+        3.03380607434824582924e+01,
+        3.25792512996573918826e+02,
+        1.53672958608443695994e+03,
+        3.19985821950859553908e+03,
+        2.55305040643316442583e+03,
+        4.74528541206955367215e+02,
+        -2.24409524465858183362e+01,
+    ];
+
+    // Horner scheme: c[0] + x * (c[1] + x * (c[2] + ...))
+    fn polynomial(coefficients: &[Real], x: Real) -> Real {
+        coefficients.iter().rev().fold(0.0, |acc, c| acc * x + c)
+    }
+    // 1 + x * (c[0] + x * (c[1] + ...))
+    fn one_plus_polynomial(coefficients: &[Real], x: Real) -> Real {
+        1.0 + x * polynomial(coefficients, x)
+    }
+
+    // erf(x)/x - 1 for |x| < 0.84375, with z = x^2
+    pub(crate) fn small_ratio(z: Real) -> Real {
+        polynomial(&PP, z) / one_plus_polynomial(&QQ, z)
+    }
+    // erf(1 + s) - erx for |s| < 0.25
+    pub(crate) fn near_one_ratio(s: Real) -> Real {
+        polynomial(&PA, s) / one_plus_polynomial(&QA, s)
+    }
+    // erfc(ax) for 1.25 <= ax < 28
+    pub(crate) fn tail(ax: Real) -> Real {
+        let s: Real = 1.0 / (ax * ax);
+        let (r, s): (Real, Real) = if ax < 1.0 / 0.35 {
+            (polynomial(&RA, s), one_plus_polynomial(&SA, s))
+        } else {
+            (polynomial(&RB, s), one_plus_polynomial(&SB, s))
+        };
+        // z is ax with the low 32 bits cleared: z*z is then exact and the
+        // rounding error is moved into the (z - ax) * (z + ax) term
+        let z: Real = Real::from_bits(ax.to_bits() & 0xffff_ffff_0000_0000);
+        (-z * z - 0.5625).exp() * ((z - ax) * (z + ax) + r / s).exp() / ax
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn erf_matches_reference_values() {
+        // Reference values from the C library erf/erfc
+        let cases: [(Real, Real); 10] = [
+            (0.0, 0.0),
+            (1.0e-10, 1.1283791670955126e-10),
+            (0.1, 0.1124629160182849),
+            (0.5, 0.5204998778130465),
+            (0.84375, 0.7672256612323416),
+            (1.0, 0.8427007929497149),
+            (1.5, 0.9661051464753108),
+            (2.0, 0.9953222650189527),
+            (3.0, 0.9999779095030014),
+            (6.5, 1.0),
+        ];
+
+        for (x, expected) in cases {
+            assert!(
+                (erf(x) - expected).abs() <= 1.0e-15,
+                "erf({}) = {}, expected {}",
+                x,
+                erf(x),
+                expected
+            );
+            // erf is odd
+            assert!(
+                (erf(-x) + expected).abs() <= 1.0e-15,
+                "erf({}) = {}, expected {}",
+                -x,
+                erf(-x),
+                -expected
+            );
+        }
+    }
+
+    #[test]
+    fn erfc_matches_reference_values_in_the_tails() {
+        let cases: [(Real, Real); 8] = [
+            (0.1, 0.8875370839817152),
+            (0.5, 0.4795001221869535),
+            (1.0, 0.15729920705028513),
+            (2.0, 0.004677734981047265),
+            (3.0, 2.2090496998585438e-05),
+            (5.0, 1.5374597944280351e-12),
+            (10.0, 2.088487583762545e-45),
+            (-2.0, 1.9953222650189528),
+        ];
+
+        for (x, expected) in cases {
+            let relative_error: Real = (erfc(x) - expected).abs() / expected;
+            assert!(
+                relative_error <= 1.0e-14,
+                "erfc({}) = {}, expected {}",
+                x,
+                erfc(x),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn erf_plus_erfc_is_one() {
+        let mut x: Real = -5.0;
+        while x <= 5.0 {
+            assert!(
+                (erf(x) + erfc(x) - 1.0).abs() <= 1.0e-15,
+                "erf + erfc != 1 at x = {x}"
+            );
+            x += 0.05;
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use crate::math::distributions::gamma_distribution::GammaFunction;
+use crate::types::{Real, Size};
+
+const ACCURACY: Real = 1.0e-15;
+const MAX_ITERATIONS: Size = 10000;
+
+/*
+Regularized incomplete gamma function (QuantLib incompleteGammaFunction)
+    P(a, x) = 1 / Gamma(a) int_0^x t^(a - 1) exp(-t) dt
+for a > 0 and x >= 0, i.e. the cumulative gamma distribution.
+
+As in Numerical Recipes (gammp), P is summed as a series for x < a + 1
+and obtained from the continued fraction of Q = 1 - P otherwise, both
+converging fast in their region. The accuracy is limited by the one of
+ln(Gamma(a)), about 2e-10 relative.
+*/
+pub fn incomplete_gamma_function(a: Real, x: Real) -> Real {
+    detail::check_arguments(a, x);
+    if x == 0.0 {
+        0.0
+    } else if x < a + 1.0 {
+        detail::series(a, x)
+    } else {
+        1.0 - detail::continued_fraction(a, x)
+    }
+}
+
+// Q(a, x) = 1 - P(a, x), without cancellation when P is close to 1
+pub fn complementary_incomplete_gamma_function(a: Real, x: Real) -> Real {
+    detail::check_arguments(a, x);
+    if x == 0.0 {
+        1.0
+    } else if x < a + 1.0 {
+        1.0 - detail::series(a, x)
+    } else {
+        detail::continued_fraction(a, x)
+    }
+}
+
+pub(crate) mod detail {
+    use super::*;
+
+    pub(crate) fn check_arguments(a: Real, x: Real) {
+        if a <= 0.0 {
+            panic!("non-positive a ({a}) not allowed");
+        }
+        if x < 0.0 {
+            panic!("negative x ({x}) not allowed");
+        }
+    }
+
+    // x^a exp(-x) / Gamma(a)
+    fn prefactor(a: Real, x: Real) -> Real {
+        (a * x.ln() - x - GammaFunction.log_value(a)).exp()
+    }
+
+    // P(a, x) = x^a exp(-x) / Gamma(a + 1) sum_n x^n / ((a + 1) ... (a + n))
+    pub(crate) fn series(a: Real, x: Real) -> Real {
+        let mut ap: Real = a;
+        let mut term: Real = 1.0 / a;
+        let mut sum: Real = term;
+        for _ in 0..MAX_ITERATIONS {
+            ap += 1.0;
+            term *= x / ap;
+            sum += term;
+            if term.abs() < sum.abs() * ACCURACY {
+                return sum * prefactor(a, x);
+            }
+        }
+        panic!("incomplete gamma series: too few iterations ({MAX_ITERATIONS})");
+    }
+
+    // Q(a, x) by the modified Lentz evaluation of its continued fraction
+    pub(crate) fn continued_fraction(a: Real, x: Real) -> Real {
+        let tiny: Real = Real::MIN_POSITIVE / Real::EPSILON;
+        let mut b: Real = x + 1.0 - a;
+        let mut c: Real = 1.0 / tiny;
+        let mut d: Real = 1.0 / b;
+        let mut h: Real = d;
+        for i in 1..=MAX_ITERATIONS {
+            let an: Real = -(i as Real) * (i as Real - a);
+            b += 2.0;
+            d = an * d + b;
+            if d.abs() < tiny {
+                d = tiny;
+            }
+            c = b + an / c;
+            if c.abs() < tiny {
+                c = tiny;
+            }
+            d = 1.0 / d;
+            let delta: Real = d * c;
+            h *= delta;
+            if (delta - 1.0).abs() < ACCURACY {
+                return h * prefactor(a, x);
+            }
+        }
+        panic!("incomplete gamma continued fraction: too few iterations ({MAX_ITERATIONS})");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::error_function::erf;
+
+    #[test]
+    fn matches_reference_values() {
+        // Reference values from mpmath gammainc(a, 0, x, regularized=True)
+        let cases: [(Real, Real, Real); 6] = [
+            (1.0, 2.0, 0.8646647167633873),
+            (0.5, 0.3, 0.5614219739190001),
+            (3.0, 1.5, 0.19115316946194187),
+            (10.0, 12.0, 0.7576078383294877),
+            (2.5, 20.0, 0.99999985066321),
+            (100.0, 90.0, 0.15822098918643017),
+        ];
+        for (a, x, expected) in cases {
+            let p: Real = incomplete_gamma_function(a, x);
+            assert!(
+                (p - expected).abs() < 1.0e-9 * expected,
+                "P({a}, {x}) = {p}, expected {expected}"
+            );
+            let q: Real = complementary_incomplete_gamma_function(a, x);
+            assert!((p + q - 1.0).abs() < 1.0e-14);
+        }
+    }
+
+    #[test]
+    fn special_cases() {
+        // P(1, x) = 1 - exp(-x) and P(1/2, x) = erf(sqrt(x))
+        for x in [0.1, 1.0, 3.0, 10.0] {
+            assert!((incomplete_gamma_function(1.0, x) - (1.0 - (-x).exp())).abs() < 1.0e-12);
+            assert!((incomplete_gamma_function(0.5, x) - erf(x.sqrt())).abs() < 1.0e-9);
+        }
+        // Far tail of Q: exp(-50) for a = 1
+        let q: Real = complementary_incomplete_gamma_function(1.0, 50.0);
+        assert!((q / (-50.0_f64).exp() - 1.0).abs() < 1.0e-9);
+        assert_eq!(incomplete_gamma_function(2.0, 0.0), 0.0);
+
+        for (a, x) in [(0.0, 1.0), (1.0, -1.0)] {
+            let result = std::panic::catch_unwind(|| incomplete_gamma_function(a, x));
+            assert!(result.is_err(), "expected panic for ({a}, {x})");
+        }
+    }
+}
@@ -0,0 +1,343 @@
+use crate::types::{Real, Size};
+
+/*
+Adaptive Gauss-Kronrod integration (QuantLib GaussKronrodAdaptive).
+
+Each interval is integrated with the 7-point Gauss rule and its 15-point
+Kronrod extension, the difference estimating the error of the latter.
+Intervals whose error exceeds the tolerance are bisected, each half
+getting half the tolerance. Integrating panics when a bisection would
+exceed max_evaluations function evaluations.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct GaussKronrodAdaptive {
+    max_evaluations: Size,
+    abs_accuracy: Real,
+}
+impl GaussKronrodAdaptive {
+    // Constructor
+    pub fn new(max_evaluations: Size, abs_accuracy: Real) -> GaussKronrodAdaptive {
+        if abs_accuracy <= 0.0 {
+            panic!("absolute accuracy ({abs_accuracy}) must be positive");
+        }
+        if max_evaluations < 15 {
+            panic!(
+                "at least 15 evaluations ({max_evaluations} given) are needed by the 15-point rule"
+            );
+        }
+        GaussKronrodAdaptive {
+            max_evaluations,
+            abs_accuracy,
+        }
+    }
+
+    // Inspectors
+    pub fn max_evaluations(&self) -> Size {
+        self.max_evaluations
+    }
+    pub fn abs_accuracy(&self) -> Real {
+        self.abs_accuracy
+    }
+
+    pub fn integrate<F>(&self, f: F, a: Real, b: Real) -> Real
+    where
+        F: Fn(Real) -> Real,
+    {
+        let mut evaluations: Size = 0;
+        self.step(&f, a, b, self.abs_accuracy, &mut evaluations)
+    }
+
+    fn step<F>(&self, f: &F, a: Real, b: Real, tolerance: Real, evaluations: &mut Size) -> Real
+    where
+        F: Fn(Real) -> Real,
+    {
+        let (kronrod, gauss): (Real, Real) = detail::kronrod_15(f, a, b);
+        *evaluations += 15;
+        if (kronrod - gauss).abs() <= tolerance {
+            return kronrod;
+        }
+        if *evaluations + 30 > self.max_evaluations {
+            panic!(
+                "max number of evaluations ({}) reached",
+                self.max_evaluations
+            );
+        }
+        let m: Real = 0.5 * (a + b);
+        if m <= a || b <= m {
+            panic!("interval contains no more machine numbers");
+        }
+        self.step(f, a, m, 0.5 * tolerance, evaluations)
+            + self.step(f, m, b, 0.5 * tolerance, evaluations)
+    }
+}
+
+/*
+Non-adaptive Gauss-Kronrod integration: the 10-point Gauss rule and its
+21-point Kronrod extension (the first QUADPACK QNG pair) are applied on
+1, 2, 4, ... equal subintervals, until the summed error estimate falls
+below the absolute accuracy or the relative accuracy times the result,
+whichever is larger. Nodes do not nest across refinements, so each one
+costs 21 evaluations per subinterval; integrating panics when the next
+refinement would exceed max_evaluations.
+
+Cheaper than the adaptive version for smooth integrands, far more
+expensive for integrands with localized features.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct GaussKronrodNonAdaptive {
+    max_evaluations: Size,
+    abs_accuracy: Real,
+    rel_accuracy: Real,
+}
+impl GaussKronrodNonAdaptive {
+    // Constructor
+    pub fn new(
+        max_evaluations: Size,
+        abs_accuracy: Real,
+        rel_accuracy: Real,
+    ) -> GaussKronrodNonAdaptive {
+        if abs_accuracy <= 0.0 && rel_accuracy <= 0.0 {
+            panic!("either the absolute or the relative accuracy must be positive");
+        }
+        GaussKronrodNonAdaptive {
+            max_evaluations,
+            abs_accuracy,
+            rel_accuracy,
+        }
+    }
+
+    // Inspectors
+    pub fn max_evaluations(&self) -> Size {
+        self.max_evaluations
+    }
+    pub fn abs_accuracy(&self) -> Real {
+        self.abs_accuracy
+    }
+    pub fn rel_accuracy(&self) -> Real {
+        self.rel_accuracy
+    }
+
+    pub fn integrate<F>(&self, f: F, a: Real, b: Real) -> Real
+    where
+        F: Fn(Real) -> Real,
+    {
+        let mut evaluations: Size = 0;
+        let mut intervals: Size = 1;
+        loop {
+            if evaluations + 21 * intervals > self.max_evaluations {
+                panic!(
+                    "max number of evaluations ({}) reached",
+                    self.max_evaluations
+                );
+            }
+            let h: Real = (b - a) / intervals as Real;
+            let (mut result, mut error): (Real, Real) = (0.0, 0.0);
+            for i in 0..intervals {
+                let left: Real = a + i as Real * h;
+                let (kronrod, gauss): (Real, Real) = detail::kronrod_21(&f, left, left + h);
+                result += kronrod;
+                error += (kronrod - gauss).abs();
+            }
+            evaluations += 21 * intervals;
+            if error <= self.abs_accuracy.max(self.rel_accuracy * result.abs()) {
+                return result;
+            }
+            intervals *= 2;
+        }
+    }
+}
+
+pub(crate) mod detail {
+    use crate::types::{Real, Size};
+
+    // Kronrod nodes on [0, 1), the Gauss ones being those of odd index
+    const X15: [Real; 8] = [
+        0.9914553711208126,
+        0.9491079123427585,
+        0.8648644233597691,
+        0.7415311855993945,
+        0.5860872354676911,
+        0.4058451513773972,
+        0.20778495500789848,
+        0.0,
+    ];
+    const W15: [Real; 8] = [
+        0.02293532201052922,
+        0.06309209262997855,
+        0.10479001032225018,
+        0.14065325971552592,
+        0.1690047266392679,
+        0.19035057806478542,
+        0.20443294007529889,
+        0.20948214108472782,
+    ];
+    const W7: [Real; 4] = [
+        0.1294849661688697,
+        0.27970539148927664,
+        0.3818300505051189,
+        0.4179591836734694,
+    ];
+    const X21: [Real; 11] = [
+        0.9956571630258081,
+        0.9739065285171717,
+        0.9301574913557082,
+        0.8650633666889845,
+        0.7808177265864169,
+        0.6794095682990244,
+        0.5627571346686047,
+        0.4333953941292472,
+        0.2943928627014602,
+        0.14887433898163122,
+        0.0,
+    ];
+    const W21: [Real; 11] = [
+        0.011694638867371874,
+        0.032558162307964725,
+        0.054755896574352,
+        0.07503967481091996,
+        0.0931254545836976,
+        0.10938715880229764,
+        0.12349197626206585,
+        0.13470921731147334,
+        0.14277593857706008,
+        0.1477391049013385,
+        0.1494455540029169,
+    ];
+    const W10: [Real; 5] = [
+        0.06667134430868814,
+        0.1494513491505806,
+        0.21908636251598204,
+        0.26926671930999635,
+        0.29552422471475287,
+    ];
+
+    // (Kronrod, Gauss) values of a symmetric pair of rules on [a, b]
+    fn kronrod_gauss<F>(
+        f: &F,
+        a: Real,
+        b: Real,
+        x: &[Real],
+        wk: &[Real],
+        wg: &[Real],
+    ) -> (Real, Real)
+    where
+        F: Fn(Real) -> Real,
+    {
+        let (m, h): (Real, Real) = (0.5 * (a + b), 0.5 * (b - a));
+        let (mut kronrod, mut gauss): (Real, Real) = (0.0, 0.0);
+        let last: Size = x.len() - 1;
+        for (i, (xi, w)) in x.iter().zip(wk.iter()).enumerate() {
+            let y: Real = if i == last {
+                f(m)
+            } else {
+                f(m - xi * h) + f(m + xi * h)
+            };
+            kronrod += w * y;
+            if i % 2 == 1 {
+                gauss += wg[i / 2] * y;
+            }
+        }
+        (kronrod * h, gauss * h)
+    }
+
+    pub(crate) fn kronrod_15<F>(f: &F, a: Real, b: Real) -> (Real, Real)
+    where
+        F: Fn(Real) -> Real,
+    {
+        kronrod_gauss(f, a, b, &X15, &W15, &W7)
+    }
+
+    pub(crate) fn kronrod_21<F>(f: &F, a: Real, b: Real) -> (Real, Real)
+    where
+        F: Fn(Real) -> Real,
+    {
+        kronrod_gauss(f, a, b, &X21, &W21, &W10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    #[allow(clippy::type_complexity)]
+    const CASES: [(fn(Real) -> Real, Real, Real, Real, &str); 4] = [
+        (|x| x * x * x, 0.0, 2.0, 4.0, "cubic"),
+        (|x| x.sin(), 0.0, PI, 2.0, "sine"),
+        (
+            |x| (-x * x).exp(),
+            -10.0,
+            10.0,
+            1.7724538509055159,
+            "gaussian",
+        ),
+        (|x| 1.0 / x, 1.0, 100.0, 4.605170185988092, "hyperbola"),
+    ];
+
+    #[test]
+    fn rules_are_exact_on_polynomials() {
+        // Degrees 3 n + 1 (Kronrod) and 2 n - 1 (Gauss)
+        let (kronrod, gauss): (Real, Real) = detail::kronrod_15(&|x: Real| x.powi(22), 0.0, 1.0);
+        assert!((kronrod - 1.0 / 23.0).abs() < 1.0e-15);
+        assert!((gauss - 1.0 / 23.0).abs() > 1.0e-10);
+        let (_, gauss): (Real, Real) = detail::kronrod_15(&|x: Real| x.powi(13), 0.0, 1.0);
+        assert!((gauss - 1.0 / 14.0).abs() < 1.0e-15);
+
+        let (kronrod, gauss): (Real, Real) = detail::kronrod_21(&|x: Real| x.powi(31), 0.0, 1.0);
+        assert!((kronrod - 1.0 / 32.0).abs() < 1.0e-15);
+        assert!((gauss - 1.0 / 32.0).abs() > 1.0e-10);
+        let (_, gauss): (Real, Real) = detail::kronrod_21(&|x: Real| x.powi(19), 0.0, 1.0);
+        assert!((gauss - 1.0 / 20.0).abs() < 1.0e-15);
+    }
+
+    #[test]
+    fn integrate_smooth_functions() {
+        let adaptive: GaussKronrodAdaptive = GaussKronrodAdaptive::new(10000, 1.0e-12);
+        let non_adaptive: GaussKronrodNonAdaptive =
+            GaussKronrodNonAdaptive::new(100000, 1.0e-12, 0.0);
+        for (f, a, b, expected, label) in CASES {
+            let calculated: Real = adaptive.integrate(f, a, b);
+            assert!(
+                (calculated - expected).abs() < 1.0e-11,
+                "adaptive {label}: {calculated} vs {expected}"
+            );
+            let calculated: Real = non_adaptive.integrate(f, a, b);
+            assert!(
+                (calculated - expected).abs() < 1.0e-11,
+                "non adaptive {label}: {calculated} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn adaptive_bisection_resolves_kinks() {
+        let adaptive: GaussKronrodAdaptive = GaussKronrodAdaptive::new(10000, 1.0e-12);
+        assert!((adaptive.integrate(|x| x.abs(), -1.0, 2.0) - 2.5).abs() < 1.0e-11);
+    }
+
+    #[test]
+    fn integrate_panics_on_too_many_evaluations() {
+        // A kink away from the uniform partitions needs too many refinements
+        let non_adaptive: GaussKronrodNonAdaptive =
+            GaussKronrodNonAdaptive::new(100000, 1.0e-12, 0.0);
+        let result = std::panic::catch_unwind(|| non_adaptive.integrate(|x| x.abs(), -1.0, 2.0));
+        assert!(
+            result.is_err(),
+            "expected panic for a kink without adaptation"
+        );
+
+        let adaptive: GaussKronrodAdaptive = GaussKronrodAdaptive::new(100, 1.0e-14);
+        let result = std::panic::catch_unwind(|| adaptive.integrate(|x| x.sqrt(), 0.0, 1.0));
+        assert!(
+            result.is_err(),
+            "expected panic for adaptive max evaluations"
+        );
+
+        let non_adaptive: GaussKronrodNonAdaptive = GaussKronrodNonAdaptive::new(100, 1.0e-14, 0.0);
+        let result = std::panic::catch_unwind(|| non_adaptive.integrate(|x| x.sqrt(), 0.0, 1.0));
+        assert!(
+            result.is_err(),
+            "expected panic for non adaptive max evaluations"
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use crate::types::{Real, Size};
+
+/*
+Adaptive Gauss-Lobatto integration (QuantLib GaussLobattoIntegral),
+after W. Gander and W. Gautschi, "Adaptive Quadrature - Revisited"
+(2000).
+
+Each interval is integrated with the 4-point Gauss-Lobatto rule and its
+7-point Kronrod extension; when they disagree by more than the
+tolerance the interval is split at the Lobatto nodes and the pieces are
+integrated recursively. The tolerance is estimated once on the whole
+interval from a 13-point rule: the absolute accuracy, or the relative
+accuracy times that estimate if tighter. With use_convergence_estimate
+the tolerance is also scaled by the observed convergence rate of the
+rules. Integrating panics after max_evaluations function evaluations.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct GaussLobattoIntegral {
+    max_evaluations: Size,
+    abs_accuracy: Real,
+    rel_accuracy: Option<Real>,
+    use_convergence_estimate: bool,
+}
+impl GaussLobattoIntegral {
+    const ALPHA: Real = 0.816496580927726; // sqrt(2 / 3)
+    const BETA: Real = 0.4472135954999579; // 1 / sqrt(5)
+    const X1: Real = 0.9428824156954797;
+    const X2: Real = 0.6418533423457813;
+    const X3: Real = 0.2363831996621499;
+
+    // Constructor
+    pub fn new(
+        max_evaluations: Size,
+        abs_accuracy: Real,
+        rel_accuracy: Option<Real>,
+        use_convergence_estimate: bool,
+    ) -> GaussLobattoIntegral {
+        if abs_accuracy <= 0.0 {
+            panic!("absolute accuracy ({abs_accuracy}) must be positive");
+        }
+        if let Some(rel_accuracy) = rel_accuracy
+            && rel_accuracy <= 0.0
+        {
+            panic!("relative accuracy ({rel_accuracy}) must be positive");
+        }
+        GaussLobattoIntegral {
+            max_evaluations,
+            abs_accuracy,
+            rel_accuracy,
+            use_convergence_estimate,
+        }
+    }
+
+    // Inspectors
+    pub fn max_evaluations(&self) -> Size {
+        self.max_evaluations
+    }
+    pub fn abs_accuracy(&self) -> Real {
+        self.abs_accuracy
+    }
+    pub fn rel_accuracy(&self) -> Option<Real> {
+        self.rel_accuracy
+    }
+
+    pub fn integrate<F>(&self, f: F, a: Real, b: Real) -> Real
+    where
+        F: Fn(Real) -> Real,
+    {
+        let mut evaluations: Size = 0;
+        let tolerance: Real = self.abs_tolerance(&f, a, b, &mut evaluations);
+        evaluations += 2;
+        self.step(&f, a, b, f(a), f(b), tolerance, &mut evaluations)
+    }
+
+    fn abs_tolerance<F>(&self, f: &F, a: Real, b: Real, evaluations: &mut Size) -> Real
+    where
+        F: Fn(Real) -> Real,
+    {
+        let (m, h): (Real, Real) = (0.5 * (a + b), 0.5 * (b - a));
+        let y1: Real = f(a);
+        let y3: Real = f(m - GaussLobattoIntegral::ALPHA * h);
+        let y5: Real = f(m - GaussLobattoIntegral::BETA * h);
+        let y7: Real = f(m);
+        let y9: Real = f(m + GaussLobattoIntegral::BETA * h);
+        let y11: Real = f(m + GaussLobattoIntegral::ALPHA * h);
+        let y13: Real = f(b);
+        let f1: Real = f(m - GaussLobattoIntegral::X1 * h);
+        let f2: Real = f(m + GaussLobattoIntegral::X1 * h);
+        let f3: Real = f(m - GaussLobattoIntegral::X2 * h);
+        let f4: Real = f(m + GaussLobattoIntegral::X2 * h);
+        let f5: Real = f(m - GaussLobattoIntegral::X3 * h);
+        let f6: Real = f(m + GaussLobattoIntegral::X3 * h);
+        *evaluations += 13;
+
+        let estimate: Real = h
+            * (0.015827191973480183 * (y1 + y13)
+                + 0.09427384021885005 * (f1 + f2)
+                + 0.1550719873365854 * (y3 + y11)
+                + 0.18882157396018245 * (f3 + f4)
+                + 0.19977340522685853 * (y5 + y9)
+                + 0.22492646533333953 * (f5 + f6)
+                + 0.24261107190140773 * y7);
+        if estimate == 0.0 && [f1, f2, f3, f4, f5, f6].iter().any(|y| *y != 0.0) {
+            panic!("can not calculate absolute accuracy from relative accuracy");
+        }
+
+        let mut rate: Real = 1.0;
+        if self.use_convergence_estimate {
+            let integral2: Real = h / 6.0 * (y1 + y13 + 5.0 * (y5 + y9));
+            let integral1: Real = h / 1470.0
+                * (77.0 * (y1 + y13) + 432.0 * (y3 + y11) + 625.0 * (y5 + y9) + 672.0 * y7);
+            if (integral2 - estimate).abs() != 0.0 {
+                rate = (integral1 - estimate).abs() / (integral2 - estimate).abs();
+            }
+            if rate == 0.0 || rate > 1.0 {
+                rate = 1.0;
+            }
+        }
+
+        // Returned as the magnitude that absorbs the tolerance in floating point
+        let tolerance: Real = match self.rel_accuracy {
+            Some(rel_accuracy) => self
+                .abs_accuracy
+                .min(estimate.abs() * rel_accuracy.max(Real::EPSILON)),
+            None => self.abs_accuracy,
+        };
+        tolerance / (rate * Real::EPSILON)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn step<F>(
+        &self,
+        f: &F,
+        a: Real,
+        b: Real,
+        fa: Real,
+        fb: Real,
+        tolerance: Real,
+        evaluations: &mut Size,
+    ) -> Real
+    where
+        F: Fn(Real) -> Real,
+    {
+        if *evaluations >= self.max_evaluations {
+            panic!(
+                "max number of evaluations ({}) reached",
+                self.max_evaluations
+            );
+        }
+        let (m, h): (Real, Real) = (0.5 * (a + b), 0.5 * (b - a));
+        let mll: Real = m - GaussLobattoIntegral::ALPHA * h;
+        let ml: Real = m - GaussLobattoIntegral::BETA * h;
+        let mr: Real = m + GaussLobattoIntegral::BETA * h;
+        let mrr: Real = m + GaussLobattoIntegral::ALPHA * h;
+        let (fmll, fml, fm, fmr, fmrr): (Real, Real, Real, Real, Real) =
+            (f(mll), f(ml), f(m), f(mr), f(mrr));
+        *evaluations += 5;
+
+        let integral2: Real = h / 6.0 * (fa + fb + 5.0 * (fml + fmr));
+        let integral1: Real = h / 1470.0
+            * (77.0 * (fa + fb) + 432.0 * (fmll + fmrr) + 625.0 * (fml + fmr) + 672.0 * fm);
+        if tolerance + (integral1 - integral2) == tolerance || mll <= a || b <= mrr {
+            if m <= a || b <= m {
+                panic!("interval contains no more machine numbers");
+            }
+            return integral1;
+        }
+        self.step(f, a, mll, fa, fmll, tolerance, evaluations)
+            + self.step(f, mll, ml, fmll, fml, tolerance, evaluations)
+            + self.step(f, ml, m, fml, fm, tolerance, evaluations)
+            + self.step(f, m, mr, fm, fmr, tolerance, evaluations)
+            + self.step(f, mr, mrr, fmr, fmrr, tolerance, evaluations)
+            + self.step(f, mrr, b, fmrr, fb, tolerance, evaluations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    #[allow(clippy::type_complexity)]
+    fn integrates_smooth_functions() {
+        let integrator: GaussLobattoIntegral =
+            GaussLobattoIntegral::new(10000, 1.0e-12, None, true);
+        let cases: [(fn(Real) -> Real, Real, Real, Real, &str); 4] = [
+            (|x| x * x * x, 0.0, 2.0, 4.0, "cubic"),
+            (|x| x.sin(), 0.0, PI, 2.0, "sine"),
+            (|x| (-x * x).exp(), -10.0, 10.0, PI.sqrt(), "gaussian"),
+            (|x| 1.0 / x, 1.0, 100.0, 100.0_f64.ln(), "hyperbola"),
+        ];
+        for (f, a, b, expected, label) in cases {
+            let calculated: Real = integrator.integrate(f, a, b);
+            assert!(
+                (calculated - expected).abs() < 1.0e-10,
+                "{label}: {calculated} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn relative_accuracy_tightens_small_integrals() {
+        let integrator: GaussLobattoIntegral =
+            GaussLobattoIntegral::new(10000, 1.0, Some(1.0e-10), false);
+        let calculated: Real = integrator.integrate(|x| 1.0e-6 * x.exp(), 0.0, 1.0);
+        let expected: Real = 1.0e-6 * (1.0_f64.exp() - 1.0);
+        assert!((calculated / expected - 1.0).abs() < 1.0e-10);
+    }
+
+    #[test]
+    fn integrate_panics_on_too_many_evaluations() {
+        let integrator: GaussLobattoIntegral = GaussLobattoIntegral::new(20, 1.0e-14, None, true);
+        let result = std::panic::catch_unwind(|| integrator.integrate(|x| x.sqrt(), 0.0, 1.0));
+        assert!(result.is_err(), "expected panic for max evaluations");
+        let result = std::panic::catch_unwind(|| GaussLobattoIntegral::new(100, 0.0, None, true));
+        assert!(result.is_err(), "expected panic for null accuracy");
+    }
+}
@@ -0,0 +1,388 @@
+use crate::math::distributions::gamma_distribution::GammaFunction;
+use crate::types::{Real, Size};
+use std::f64::consts::PI;
+
+/*
+Gauss-Laguerre quadrature (QuantLib GaussLaguerreIntegration):
+    int_0^inf x^s exp(-x) f(x) dx ~ sum_i w_i f(x_i)
+exact for polynomials f of degree up to 2n - 1.
+
+The nodes x_i are the roots of the generalized Laguerre polynomial
+L_n^s, found by Newton iterations on the three-term recurrence from
+the asymptotic first guesses of Numerical Recipes (gaulag); the weights
+    w_i = -Gamma(n + s) / (Gamma(n) n L'_n(x_i) L_{n-1}(x_i))
+are computed through logarithms, the polynomials getting large at the
+far nodes. Nodes are sorted in increasing order.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct GaussLaguerreIntegration {
+    x: Vec<Real>,
+    weights: Vec<Real>,
+}
+impl GaussLaguerreIntegration {
+    const ACCURACY: Real = 3.0e-14;
+    const MAX_ITERATIONS: Size = 100;
+
+    // Constructor
+    pub fn new(n: Size, s: Real) -> GaussLaguerreIntegration {
+        if n == 0 {
+            panic!("at least one node required");
+        }
+        if s <= -1.0 {
+            panic!("s ({s}) must be greater than -1");
+        }
+        let gamma: GammaFunction = GammaFunction;
+        let log_gamma_ratio: Real = gamma.log_value(n as Real + s) - gamma.log_value(n as Real);
+        let mut x: Vec<Real> = Vec::with_capacity(n);
+        let mut weights: Vec<Real> = Vec::with_capacity(n);
+        let mut z: Real = 0.0;
+        for i in 0..n {
+            z = match i {
+                0 => (1.0 + s) * (3.0 + 0.92 * s) / (1.0 + 2.4 * n as Real + 1.8 * s),
+                1 => z + (15.0 + 6.25 * s) / (1.0 + 0.9 * s + 2.5 * n as Real),
+                _ => {
+                    let ai: Real = (i - 1) as Real;
+                    z + ((1.0 + 2.55 * ai) / (1.9 * ai) + 1.26 * ai * s / (1.0 + 3.5 * ai))
+                        * (z - x[i - 2])
+                        / (1.0 + 0.3 * s)
+                }
+            };
+            let mut iteration: Size = 0;
+            let (derivative, previous): (Real, Real) = loop {
+                let (value, previous): (Real, Real) = detail::laguerre(n, s, z);
+                let derivative: Real = (n as Real * value - (n as Real + s) * previous) / z;
+                let step: Real = value / derivative;
+                z -= step;
+                if step.abs() <= GaussLaguerreIntegration::ACCURACY * z.abs().max(1.0) {
+                    break (derivative, previous);
+                }
+                iteration += 1;
+                if iteration > GaussLaguerreIntegration::MAX_ITERATIONS {
+                    panic!("root {i} of the Laguerre polynomial did not converge");
+                }
+            };
+            x.push(z);
+            weights.push(
+                (log_gamma_ratio - (n as Real).ln() - derivative.abs().ln() - previous.abs().ln())
+                    .exp(),
+            );
+        }
+        GaussLaguerreIntegration { x, weights }
+    }
+
+    // Inspectors
+    pub fn order(&self) -> Size {
+        self.x.len()
+    }
+    pub fn x(&self) -> &[Real] {
+        &self.x
+    }
+    pub fn weights(&self) -> &[Real] {
+        &self.weights
+    }
+
+    pub fn value<F>(&self, f: F) -> Real
+    where
+        F: Fn(Real) -> Real,
+    {
+        self.x
+            .iter()
+            .zip(&self.weights)
+            .map(|(x, w)| w * f(*x))
+            .sum()
+    }
+}
+
+/*
+Gauss-Hermite quadrature (QuantLib GaussHermiteIntegration with mu = 0):
+    int_-inf^inf exp(-x^2) f(x) dx ~ sum_i w_i f(x_i)
+exact for polynomials f of degree up to 2n - 1.
+
+The nodes are the roots of the Hermite polynomial H_n, found by Newton
+iterations on the recurrence of the orthonormal polynomials from the
+first guesses of Numerical Recipes (gauher); then w_i = 2 / H'_n(x_i)^2
+in that normalization. Nodes are sorted in increasing order.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct GaussHermiteIntegration {
+    x: Vec<Real>,
+    weights: Vec<Real>,
+}
+impl GaussHermiteIntegration {
+    const ACCURACY: Real = 3.0e-14;
+    const MAX_ITERATIONS: Size = 100;
+
+    // Constructor
+    pub fn new(n: Size) -> GaussHermiteIntegration {
+        if n == 0 {
+            panic!("at least one node required");
+        }
+        let mut x: Vec<Real> = vec![0.0; n];
+        let mut weights: Vec<Real> = vec![0.0; n];
+        let mut z: Real = 0.0;
+        // Largest roots first, the others by symmetry
+        for i in 0..n.div_ceil(2) {
+            z = match i {
+                0 => {
+                    let m: Real = (2 * n + 1) as Real;
+                    m.sqrt() - 1.85575 * m.powf(-0.16667)
+                }
+                1 => z - 1.14 * (n as Real).powf(0.426) / z,
+                2 => 1.86 * z - 0.86 * x[n - 1],
+                3 => 1.91 * z - 0.91 * x[n - 2],
+                _ => 2.0 * z - x[n + 1 - i],
+            };
+            let mut iteration: Size = 0;
+            let derivative: Real = loop {
+                let (value, previous): (Real, Real) = detail::hermite(n, z);
+                let derivative: Real = (2.0 * n as Real).sqrt() * previous;
+                let step: Real = value / derivative;
+                z -= step;
+                if step.abs() <= GaussHermiteIntegration::ACCURACY * z.abs().max(1.0) {
+                    break derivative;
+                }
+                iteration += 1;
+                if iteration > GaussHermiteIntegration::MAX_ITERATIONS {
+                    panic!("root {i} of the Hermite polynomial did not converge");
+                }
+            };
+            x[n - 1 - i] = z;
+            x[i] = -z;
+            weights[i] = 2.0 / (derivative * derivative);
+            weights[n - 1 - i] = weights[i];
+        }
+        GaussHermiteIntegration { x, weights }
+    }
+
+    // Inspectors
+    pub fn order(&self) -> Size {
+        self.x.len()
+    }
+    pub fn x(&self) -> &[Real] {
+        &self.x
+    }
+    pub fn weights(&self) -> &[Real] {
+        &self.weights
+    }
+
+    pub fn value<F>(&self, f: F) -> Real
+    where
+        F: Fn(Real) -> Real,
+    {
+        self.x
+            .iter()
+            .zip(&self.weights)
+            .map(|(x, w)| w * f(*x))
+            .sum()
+    }
+}
+
+/*
+Gauss-Legendre quadrature (QuantLib GaussLegendreIntegration):
+    int_-1^1 f(x) dx ~ sum_i w_i f(x_i)
+exact for polynomials f of degree up to 2n - 1.
+
+The nodes are the roots of the Legendre polynomial P_n, found by Newton
+iterations from the Chebyshev-like first guesses of Numerical Recipes
+(gauleg), and w_i = 2 / ((1 - x_i^2) P'_n(x_i)^2). Nodes are sorted in
+increasing order; integrate maps the rule onto a finite interval.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct GaussLegendreIntegration {
+    x: Vec<Real>,
+    weights: Vec<Real>,
+}
+impl GaussLegendreIntegration {
+    const ACCURACY: Real = 3.0e-14;
+    const MAX_ITERATIONS: Size = 100;
+
+    // Constructor
+    pub fn new(n: Size) -> GaussLegendreIntegration {
+        if n == 0 {
+            panic!("at least one node required");
+        }
+        let mut x: Vec<Real> = vec![0.0; n];
+        let mut weights: Vec<Real> = vec![0.0; n];
+        for i in 0..n.div_ceil(2) {
+            let mut z: Real = (PI * (i as Real + 0.75) / (n as Real + 0.5)).cos();
+            let mut iteration: Size = 0;
+            let derivative: Real = loop {
+                let (value, previous): (Real, Real) = detail::legendre(n, z);
+                let derivative: Real = n as Real * (z * value - previous) / (z * z - 1.0);
+                let step: Real = value / derivative;
+                z -= step;
+                if step.abs() <= GaussLegendreIntegration::ACCURACY {
+                    break derivative;
+                }
+                iteration += 1;
+                if iteration > GaussLegendreIntegration::MAX_ITERATIONS {
+                    panic!("root {i} of the Legendre polynomial did not converge");
+                }
+            };
+            x[i] = -z;
+            x[n - 1 - i] = z;
+            weights[i] = 2.0 / ((1.0 - z * z) * derivative * derivative);
+            weights[n - 1 - i] = weights[i];
+        }
+        GaussLegendreIntegration { x, weights }
+    }
+
+    // Inspectors
+    pub fn order(&self) -> Size {
+        self.x.len()
+    }
+    pub fn x(&self) -> &[Real] {
+        &self.x
+    }
+    pub fn weights(&self) -> &[Real] {
+        &self.weights
+    }
+
+    pub fn value<F>(&self, f: F) -> Real
+    where
+        F: Fn(Real) -> Real,
+    {
+        self.x
+            .iter()
+            .zip(&self.weights)
+            .map(|(x, w)| w * f(*x))
+            .sum()
+    }
+    // int_a^b f(x) dx, with x = (a + b) / 2 + (b - a) / 2 y
+    pub fn integrate<F>(&self, f: F, a: Real, b: Real) -> Real
+    where
+        F: Fn(Real) -> Real,
+    {
+        let (m, h): (Real, Real) = (0.5 * (a + b), 0.5 * (b - a));
+        h * self.value(|y| f(m + h * y))
+    }
+}
+
+mod detail {
+    use crate::types::{Real, Size};
+
+    // L_n^s(z) and L_{n-1}^s(z) from the three-term recurrence
+    pub(super) fn laguerre(n: Size, s: Real, z: Real) -> (Real, Real) {
+        let (mut p1, mut p2): (Real, Real) = (1.0, 0.0);
+        for j in 1..=n {
+            let p3: Real = p2;
+            p2 = p1;
+            p1 = ((2.0 * j as Real - 1.0 + s - z) * p2 - (j as Real - 1.0 + s) * p3) / j as Real;
+        }
+        (p1, p2)
+    }
+
+    // Orthonormal Hermite polynomials of orders n and n - 1 at z
+    pub(super) fn hermite(n: Size, z: Real) -> (Real, Real) {
+        // pi^(-1/4)
+        let (mut p1, mut p2): (Real, Real) = (0.7511255444649425, 0.0);
+        for j in 1..=n {
+            let p3: Real = p2;
+            p2 = p1;
+            p1 = z * (2.0 / j as Real).sqrt() * p2 - ((j as Real - 1.0) / j as Real).sqrt() * p3;
+        }
+        (p1, p2)
+    }
+
+    // P_n(z) and P_{n-1}(z) from the three-term recurrence
+    pub(super) fn legendre(n: Size, z: Real) -> (Real, Real) {
+        let (mut p1, mut p2): (Real, Real) = (1.0, 0.0);
+        for j in 1..=n {
+            let p3: Real = p2;
+            p2 = p1;
+            p1 = ((2.0 * j as Real - 1.0) * z * p2 - (j as Real - 1.0) * p3) / j as Real;
+        }
+        (p1, p2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integrates_polynomials_exactly() {
+        // int_0^inf x^k exp(-x) dx = k!
+        let quadrature: GaussLaguerreIntegration = GaussLaguerreIntegration::new(6, 0.0);
+        let mut factorial: Real = 1.0;
+        for k in 0..12 {
+            if k > 0 {
+                factorial *= k as Real;
+            }
+            let calculated: Real = quadrature.value(|x| x.powi(k));
+            assert!(
+                (calculated / factorial - 1.0).abs() < 1.0e-10,
+                "x^{k}: {calculated} vs {factorial}"
+            );
+        }
+        assert!(quadrature.x().windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn generalized_weight_and_high_orders() {
+        // int_0^inf sqrt(x) exp(-x) x dx = Gamma(5 / 2) = 3 sqrt(pi) / 4
+        let quadrature: GaussLaguerreIntegration = GaussLaguerreIntegration::new(4, 0.5);
+        let expected: Real = 0.75 * PI.sqrt();
+        assert!((quadrature.value(|x| x) - expected).abs() < 1.0e-9);
+
+        // int_0^inf exp(-x) cos(x) dx = 1 / 2, over a range of orders
+        for n in [16, 64, 128, 200] {
+            let quadrature: GaussLaguerreIntegration = GaussLaguerreIntegration::new(n, 0.0);
+            assert_eq!(quadrature.order(), n);
+            let sum: Real = quadrature.weights().iter().sum();
+            assert!((sum - 1.0).abs() < 1.0e-9, "order {n}: {sum}");
+            assert!((quadrature.value(|x| x.cos()) - 0.5).abs() < 1.0e-9);
+        }
+    }
+
+    #[test]
+    fn hermite_quadrature_of_gaussian_moments() {
+        // int x^2k exp(-x^2) dx = Gamma(k + 1/2), odd moments vanish
+        let sqrt_pi: Real = PI.sqrt();
+        for n in [1, 5, 10, 20, 64] {
+            let quadrature: GaussHermiteIntegration = GaussHermiteIntegration::new(n);
+            assert_eq!(quadrature.order(), n);
+            assert!((quadrature.weights().iter().sum::<Real>() - sqrt_pi).abs() < 1.0e-13);
+            assert!(quadrature.x().windows(2).all(|pair| pair[0] < pair[1]));
+            if n >= 2 {
+                assert!((quadrature.value(|x| x * x) - 0.5 * sqrt_pi).abs() < 1.0e-13);
+                assert!(quadrature.value(|x| x * x * x).abs() < 1.0e-13);
+            }
+        }
+        // int cos(x) exp(-x^2) dx = sqrt(pi) exp(-1/4)
+        let quadrature: GaussHermiteIntegration = GaussHermiteIntegration::new(20);
+        let expected: Real = sqrt_pi * (-0.25_f64).exp();
+        assert!((quadrature.value(|x| x.cos()) - expected).abs() < 1.0e-14);
+    }
+
+    #[test]
+    fn legendre_quadrature_is_exact_on_polynomials() {
+        for n in [1, 2, 7, 16, 100] {
+            let quadrature: GaussLegendreIntegration = GaussLegendreIntegration::new(n);
+            assert!((quadrature.weights().iter().sum::<Real>() - 2.0).abs() < 1.0e-13);
+            // int_0^2 x^(2n - 1) dx = 2^(2n) / (2n)
+            let k: i32 = 2 * n as i32 - 1;
+            let expected: Real = 2.0_f64.powi(k + 1) / (k + 1) as Real;
+            let calculated: Real = quadrature.integrate(|x| x.powi(k), 0.0, 2.0);
+            assert!(
+                (calculated / expected - 1.0).abs() < 1.0e-12,
+                "order {n}: {calculated} vs {expected}"
+            );
+        }
+        let quadrature: GaussLegendreIntegration = GaussLegendreIntegration::new(20);
+        assert!((quadrature.integrate(|x| x.sin(), 0.0, PI) - 2.0).abs() < 1.0e-14);
+    }
+
+    #[test]
+    fn new_panics_on_invalid_parameters() {
+        let cases: [(Size, Real, &str); 2] = [(0, 0.0, "no nodes"), (4, -1.0, "s = -1")];
+        for (n, s, label) in cases {
+            let result = std::panic::catch_unwind(|| GaussLaguerreIntegration::new(n, s));
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+        let result = std::panic::catch_unwind(|| GaussHermiteIntegration::new(0));
+        assert!(result.is_err(), "expected panic for no Hermite nodes");
+        let result = std::panic::catch_unwind(|| GaussLegendreIntegration::new(0));
+        assert!(result.is_err(), "expected panic for no Legendre nodes");
+    }
+}
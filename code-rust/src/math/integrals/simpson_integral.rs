@@ -0,0 +1,92 @@
+use crate::math::integrals::trapezoid_integral::detail::halve;
+use crate::types::{Real, Size};
+
+/*
+Integral by successive refinements of Simpson's rule (QuantLib
+SimpsonIntegral): each halving of the trapezoid rule T_2n gives the
+Simpson value (4 T_2n - T_n) / 3, until two successive values differ
+by less than the accuracy. At least five refinements are made;
+integrating panics if no convergence is reached within max_iterations.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SimpsonIntegral {
+    accuracy: Real,
+    max_iterations: Size,
+}
+impl SimpsonIntegral {
+    // Constructor
+    pub fn new(accuracy: Real, max_iterations: Size) -> SimpsonIntegral {
+        if accuracy <= 0.0 {
+            panic!("accuracy ({accuracy}) must be positive");
+        }
+        SimpsonIntegral {
+            accuracy,
+            max_iterations,
+        }
+    }
+
+    // Inspectors
+    pub fn accuracy(&self) -> Real {
+        self.accuracy
+    }
+    pub fn max_iterations(&self) -> Size {
+        self.max_iterations
+    }
+
+    pub fn integrate<F>(&self, f: F, a: Real, b: Real) -> Real
+    where
+        F: Fn(Real) -> Real,
+    {
+        if a == b {
+            return 0.0;
+        }
+        let mut intervals: Size = 1;
+        let mut trapezoid: Real = 0.5 * (b - a) * (f(a) + f(b));
+        let mut simpson: Real = trapezoid;
+        for i in 1..self.max_iterations {
+            let refined: Real = halve(&f, a, b, trapezoid, intervals);
+            intervals *= 2;
+            let refined_simpson: Real = (4.0 * refined - trapezoid) / 3.0;
+            if (refined_simpson - simpson).abs() <= self.accuracy && i > 5 {
+                return refined_simpson;
+            }
+            trapezoid = refined;
+            simpson = refined_simpson;
+        }
+        panic!("max number of iterations ({}) reached", self.max_iterations);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    #[allow(clippy::type_complexity)]
+    fn integrates_smooth_functions() {
+        let integrator: SimpsonIntegral = SimpsonIntegral::new(1.0e-10, 30);
+        let cases: [(fn(Real) -> Real, Real, Real, Real, &str); 4] = [
+            (|x| x * x * x, 0.0, 2.0, 4.0, "cubic"),
+            (|x| x.sin(), 0.0, PI, 2.0, "sine"),
+            (|x| (-x * x).exp(), -10.0, 10.0, PI.sqrt(), "gaussian"),
+            (|x| 1.0 / x, 1.0, 100.0, 100.0_f64.ln(), "hyperbola"),
+        ];
+        for (f, a, b, expected, label) in cases {
+            let calculated: Real = integrator.integrate(f, a, b);
+            assert!(
+                (calculated - expected).abs() < 1.0e-9,
+                "{label}: {calculated} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn integrate_panics_on_too_many_iterations() {
+        let integrator: SimpsonIntegral = SimpsonIntegral::new(1.0e-14, 8);
+        let result = std::panic::catch_unwind(|| integrator.integrate(|x| x.sqrt(), 0.0, 1.0));
+        assert!(result.is_err(), "expected panic for max iterations");
+        let result = std::panic::catch_unwind(|| SimpsonIntegral::new(0.0, 10));
+        assert!(result.is_err(), "expected panic for null accuracy");
+    }
+}
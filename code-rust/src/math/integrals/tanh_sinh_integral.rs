@@ -0,0 +1,166 @@
+use crate::types::{Real, Size};
+use std::f64::consts::FRAC_PI_2;
+
+/*
+Tanh-sinh (double exponential) integration (QuantLib TanhSinhIntegral),
+after H. Takahasi and M. Mori (1974).
+
+The substitution x = tanh(pi / 2 sinh(t)) maps [a, b] onto the real
+line with weights decaying doubly exponentially, so that the trapezoid
+rule in t converges very fast, even for integrands with endpoint
+singularities. Points are taken in |t| <= 4 and the step is halved,
+reusing the previous points, until two successive values differ by
+less than the absolute accuracy, or the relative accuracy times the
+result if looser. Distances to the endpoints are computed directly, so
+that the integrand is never evaluated at a or b. Integrating panics
+after max_evaluations function evaluations.
+
+Singularities at a non-zero endpoint are only resolved down to the
+spacing of floating-point numbers there: for 1 / sqrt(b - x) the
+accuracy is limited to about sqrt(epsilon), some 1e-8.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct TanhSinhIntegral {
+    max_evaluations: Size,
+    abs_accuracy: Real,
+    rel_accuracy: Real,
+}
+impl TanhSinhIntegral {
+    const MAX_T: Real = 4.0;
+
+    // Constructor
+    pub fn new(max_evaluations: Size, abs_accuracy: Real, rel_accuracy: Real) -> TanhSinhIntegral {
+        if abs_accuracy <= 0.0 && rel_accuracy <= 0.0 {
+            panic!("either the absolute or the relative accuracy must be positive");
+        }
+        TanhSinhIntegral {
+            max_evaluations,
+            abs_accuracy,
+            rel_accuracy,
+        }
+    }
+
+    // Inspectors
+    pub fn max_evaluations(&self) -> Size {
+        self.max_evaluations
+    }
+    pub fn abs_accuracy(&self) -> Real {
+        self.abs_accuracy
+    }
+    pub fn rel_accuracy(&self) -> Real {
+        self.rel_accuracy
+    }
+
+    pub fn integrate<F>(&self, f: F, a: Real, b: Real) -> Real
+    where
+        F: Fn(Real) -> Real,
+    {
+        if a == b {
+            return 0.0;
+        }
+        let mut evaluations: Size = 0;
+        let mut h: Real = 1.0;
+        // Sum of w(t) f(x(t)) over all points so far
+        let mut sum: Real = detail::term(&f, a, b, 0.0, &mut evaluations);
+        let mut k: Size = 1;
+        while k as Real * h <= TanhSinhIntegral::MAX_T {
+            let t: Real = k as Real * h;
+            sum += detail::term(&f, a, b, t, &mut evaluations)
+                + detail::term(&f, a, b, -t, &mut evaluations);
+            k += 1;
+        }
+        let mut integral: Real = h * sum;
+        loop {
+            h *= 0.5;
+            let mut k: Size = 1;
+            while k as Real * h <= TanhSinhIntegral::MAX_T {
+                let t: Real = k as Real * h;
+                sum += detail::term(&f, a, b, t, &mut evaluations)
+                    + detail::term(&f, a, b, -t, &mut evaluations);
+                k += 2;
+            }
+            if evaluations > self.max_evaluations {
+                panic!(
+                    "max number of evaluations ({}) reached",
+                    self.max_evaluations
+                );
+            }
+            let refined: Real = h * sum;
+            let tolerance: Real = self.abs_accuracy.max(self.rel_accuracy * refined.abs());
+            if (refined - integral).abs() <= tolerance {
+                return refined;
+            }
+            integral = refined;
+        }
+    }
+}
+
+pub(crate) mod detail {
+    use super::*;
+
+    // w(t) f(x(t)), scaled to [a, b]; zero where x(t) rounds to an endpoint
+    pub(crate) fn term<F>(f: &F, a: Real, b: Real, t: Real, evaluations: &mut Size) -> Real
+    where
+        F: Fn(Real) -> Real,
+    {
+        let u: Real = FRAC_PI_2 * t.sinh();
+        let cosh_u: Real = u.cosh();
+        let weight: Real = 0.5 * (b - a) * FRAC_PI_2 * t.cosh() / (cosh_u * cosh_u);
+        // (b - a) (1 - tanh|u|) / 2, without cancellation
+        let distance: Real = (b - a) / ((2.0 * u.abs()).exp() + 1.0);
+        let x: Real = if t < 0.0 { a + distance } else { b - distance };
+        if x <= a.min(b) || x >= a.max(b) {
+            return 0.0;
+        }
+        *evaluations += 1;
+        weight * f(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    #[allow(clippy::type_complexity)]
+    fn integrates_smooth_and_singular_functions() {
+        let integrator: TanhSinhIntegral = TanhSinhIntegral::new(10000, 1.0e-12, 0.0);
+        let cases: [(fn(Real) -> Real, Real, Real, Real, &str); 5] = [
+            (|x| x * x * x, 0.0, 2.0, 4.0, "cubic"),
+            (|x| x.sin(), 0.0, PI, 2.0, "sine"),
+            (|x| 1.0 / x, 1.0, 100.0, 100.0_f64.ln(), "hyperbola"),
+            (|x| 1.0 / x.sqrt(), 0.0, 1.0, 2.0, "inverse square root"),
+            (|x| x.ln(), 0.0, 1.0, -1.0, "logarithm"),
+        ];
+        for (f, a, b, expected, label) in cases {
+            let calculated: Real = integrator.integrate(f, a, b);
+            assert!(
+                (calculated - expected).abs() < 1.0e-10,
+                "{label}: {calculated} vs {expected}"
+            );
+            // Reversed bounds change the sign
+            assert!((integrator.integrate(f, b, a) + calculated).abs() < 1.0e-10);
+        }
+    }
+
+    #[test]
+    fn singularities_away_from_zero_limit_the_accuracy() {
+        let f = |x: Real| 1.0 / (1.0 - x * x).sqrt();
+        let integrator: TanhSinhIntegral = TanhSinhIntegral::new(10000, 1.0e-7, 0.0);
+        assert!((integrator.integrate(f, -1.0, 1.0) - PI).abs() < 1.0e-7);
+
+        let integrator: TanhSinhIntegral = TanhSinhIntegral::new(10000, 1.0e-12, 0.0);
+        let result = std::panic::catch_unwind(|| integrator.integrate(f, -1.0, 1.0));
+        assert!(result.is_err(), "expected panic for unreachable accuracy");
+    }
+
+    #[test]
+    fn integrate_panics_on_too_many_evaluations() {
+        let integrator: TanhSinhIntegral = TanhSinhIntegral::new(50, 1.0e-14, 0.0);
+        let result = std::panic::catch_unwind(|| integrator.integrate(|x| x.abs(), -1.0, 2.0));
+        assert!(result.is_err(), "expected panic for max evaluations");
+        let result = std::panic::catch_unwind(|| TanhSinhIntegral::new(100, 0.0, 0.0));
+        assert!(result.is_err(), "expected panic for null accuracies");
+    }
+}
@@ -0,0 +1,156 @@
+use crate::types::{Real, Size};
+
+/*
+How the trapezoid integral refines its partition (QuantLib Default and
+MidPoint integration policies):
+- Default halves the intervals, reusing the previous points: the
+  trapezoid rule, error O(h^2),
+- MidPoint splits them in three, reusing the previous midpoints: the
+  midpoint rule, which never evaluates the integrand at the endpoints.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum TrapezoidRule {
+    Default,
+    MidPoint,
+}
+
+/*
+Integral by successive refinements of the trapezoid (or midpoint) rule
+(QuantLib TrapezoidIntegral), until two successive values differ by
+less than the accuracy. At least five refinements are made; integrating
+panics if no convergence is reached within max_iterations.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct TrapezoidIntegral {
+    accuracy: Real,
+    max_iterations: Size,
+    rule: TrapezoidRule,
+}
+impl TrapezoidIntegral {
+    // Constructor
+    pub fn new(accuracy: Real, max_iterations: Size, rule: TrapezoidRule) -> TrapezoidIntegral {
+        if accuracy <= 0.0 {
+            panic!("accuracy ({accuracy}) must be positive");
+        }
+        TrapezoidIntegral {
+            accuracy,
+            max_iterations,
+            rule,
+        }
+    }
+
+    // Inspectors
+    pub fn accuracy(&self) -> Real {
+        self.accuracy
+    }
+    pub fn max_iterations(&self) -> Size {
+        self.max_iterations
+    }
+    pub fn rule(&self) -> TrapezoidRule {
+        self.rule
+    }
+
+    pub fn integrate<F>(&self, f: F, a: Real, b: Real) -> Real
+    where
+        F: Fn(Real) -> Real,
+    {
+        if a == b {
+            return 0.0;
+        }
+        let mut intervals: Size = 1;
+        let mut integral: Real = match self.rule {
+            TrapezoidRule::Default => 0.5 * (b - a) * (f(a) + f(b)),
+            TrapezoidRule::MidPoint => (b - a) * f(0.5 * (a + b)),
+        };
+        for i in 1..self.max_iterations {
+            let refined: Real = match self.rule {
+                TrapezoidRule::Default => {
+                    let refined: Real = detail::halve(&f, a, b, integral, intervals);
+                    intervals *= 2;
+                    refined
+                }
+                TrapezoidRule::MidPoint => {
+                    let refined: Real = detail::third(&f, a, b, integral, intervals);
+                    intervals *= 3;
+                    refined
+                }
+            };
+            if (refined - integral).abs() <= self.accuracy && i > 5 {
+                return refined;
+            }
+            integral = refined;
+        }
+        panic!("max number of iterations ({}) reached", self.max_iterations);
+    }
+}
+
+pub(crate) mod detail {
+    use crate::types::{Real, Size};
+
+    // Trapezoid rule on 2 n intervals from its value on n intervals
+    pub(crate) fn halve<F>(f: &F, a: Real, b: Real, integral: Real, n: Size) -> Real
+    where
+        F: Fn(Real) -> Real,
+    {
+        let dx: Real = (b - a) / n as Real;
+        let sum: Real = (0..n).map(|j| f(a + (j as Real + 0.5) * dx)).sum();
+        0.5 * (integral + dx * sum)
+    }
+
+    // Midpoint rule on 3 n intervals from its value on n intervals
+    pub(crate) fn third<F>(f: &F, a: Real, b: Real, integral: Real, n: Size) -> Real
+    where
+        F: Fn(Real) -> Real,
+    {
+        let dx: Real = (b - a) / n as Real;
+        let sum: Real = (0..n)
+            .map(|j| {
+                let x: Real = a + j as Real * dx;
+                f(x + dx / 6.0) + f(x + 5.0 * dx / 6.0)
+            })
+            .sum();
+        (integral + dx * sum) / 3.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    #[allow(clippy::type_complexity)]
+    fn integrates_smooth_functions_with_both_rules() {
+        let cases: [(fn(Real) -> Real, Real, Real, Real, &str); 3] = [
+            (|x| x * x, 0.0, 1.0, 1.0 / 3.0, "parabola"),
+            (|x| x.sin(), 0.0, PI, 2.0, "sine"),
+            (|x| (-x * x).exp(), -10.0, 10.0, PI.sqrt(), "gaussian"),
+        ];
+        for rule in [TrapezoidRule::Default, TrapezoidRule::MidPoint] {
+            let integrator: TrapezoidIntegral = TrapezoidIntegral::new(1.0e-8, 30, rule);
+            for (f, a, b, expected, label) in cases {
+                let calculated: Real = integrator.integrate(f, a, b);
+                assert!(
+                    (calculated - expected).abs() < 1.0e-7,
+                    "{label} with {rule:?}: {calculated} vs {expected}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn midpoint_rule_avoids_the_endpoints() {
+        let integrator: TrapezoidIntegral =
+            TrapezoidIntegral::new(1.0e-4, 30, TrapezoidRule::MidPoint);
+        let calculated: Real = integrator.integrate(|x| x.ln(), 0.0, 1.0);
+        assert!((calculated + 1.0).abs() < 1.0e-3);
+    }
+
+    #[test]
+    fn integrate_panics_on_too_many_iterations() {
+        let integrator: TrapezoidIntegral =
+            TrapezoidIntegral::new(1.0e-12, 8, TrapezoidRule::Default);
+        let result = std::panic::catch_unwind(|| integrator.integrate(|x| x.sqrt(), 0.0, 1.0));
+        assert!(result.is_err(), "expected panic for max iterations");
+    }
+}
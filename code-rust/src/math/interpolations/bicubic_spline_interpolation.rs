@@ -0,0 +1,87 @@
+use crate::math::interpolations::bilinear_interpolation::detail::check_grid;
+use crate::math::interpolations::cubic_interpolation::CubicNaturalSpline;
+use crate::math::matrix::Matrix;
+use crate::types::Real;
+
+/*
+Bicubic spline of z = f(x, y) on a grid (QuantLib BicubicSpline), with
+z[j][i] = f(x_i, y_j) as in BilinearInterpolation. Each row of z is
+interpolated along x by a natural cubic spline; the values of the rows
+at x are then interpolated along y by another natural cubic spline.
+Outside the grid the end cubics are extended.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct BicubicSpline {
+    y: Vec<Real>,
+    row_splines: Vec<CubicNaturalSpline>,
+}
+impl BicubicSpline {
+    // Constructor
+    pub fn new(x: &[Real], y: &[Real], z: &Matrix) -> BicubicSpline {
+        check_grid(x, y, z);
+        BicubicSpline {
+            y: y.to_vec(),
+            row_splines: z
+                .to_rows()
+                .iter()
+                .map(|row| CubicNaturalSpline::new(x, row))
+                .collect(),
+        }
+    }
+
+    pub fn value(&self, x: Real, y: Real) -> Real {
+        let section: Vec<Real> = self
+            .row_splines
+            .iter()
+            .map(|spline| spline.value(x))
+            .collect();
+        CubicNaturalSpline::new(&self.y, &section).value(y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(x: &[Real], y: &[Real], f: impl Fn(Real, Real) -> Real) -> Matrix {
+        Matrix::from_rows(
+            &y.iter()
+                .map(|y| x.iter().map(|x| f(*x, *y)).collect())
+                .collect::<Vec<Vec<Real>>>(),
+        )
+    }
+
+    #[test]
+    fn reproduces_nodes_and_bilinear_functions() {
+        let x: [Real; 4] = [0.0, 0.5, 1.5, 3.0];
+        let y: [Real; 3] = [1.0, 2.0, 4.0];
+        let f = |x: Real, y: Real| -> Real { 1.0 - x + 2.0 * y + 0.25 * x * y };
+        let spline: BicubicSpline = BicubicSpline::new(&x, &y, &grid(&x, &y, f));
+        for (xi, yi) in [(0.25, 1.5), (2.0, 3.0), (-1.0, 5.0)] {
+            assert!((spline.value(xi, yi) - f(xi, yi)).abs() < 1.0e-13);
+        }
+
+        let g = |x: Real, y: Real| -> Real { (x * y).sin() };
+        let spline: BicubicSpline = BicubicSpline::new(&x, &y, &grid(&x, &y, g));
+        for xi in x {
+            for yi in y {
+                assert!((spline.value(xi, yi) - g(xi, yi)).abs() < 1.0e-14);
+            }
+        }
+    }
+
+    #[test]
+    fn approximates_smooth_surfaces() {
+        // Up to the natural end conditions, which do not hold for ln near y = 1
+        let x: Vec<Real> = (0..=30).map(|i| i as Real * 0.1).collect();
+        let y: Vec<Real> = (0..=20).map(|j| 1.0 + j as Real * 0.1).collect();
+        let f = |x: Real, y: Real| -> Real { x.sin() * y.ln() };
+        let spline: BicubicSpline = BicubicSpline::new(&x, &y, &grid(&x, &y, f));
+        for (xi, yi) in [(0.73, 1.27), (1.5, 2.05), (2.42, 2.91)] {
+            assert!(
+                (spline.value(xi, yi) - f(xi, yi)).abs() < 5.0e-5,
+                "value at ({xi}, {yi})"
+            );
+        }
+    }
+}
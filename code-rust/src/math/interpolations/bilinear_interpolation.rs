@@ -0,0 +1,143 @@
+use crate::math::matrix::Matrix;
+use crate::types::{Real, Size};
+
+/*
+Bilinear interpolation of z = f(x, y) on a grid (QuantLib
+BilinearInterpolation). As in QuantLib Interpolation2D the matrix z has
+a row per y_j and a column per x_i, so that z[j][i] = f(x_i, y_j); both
+x and y must be sorted. Inside a cell
+    f(x, y) = (1 - u)(1 - v) z_00 + u (1 - v) z_01 + (1 - u) v z_10 + u v z_11
+with u and v the positions of x and y in the cell; outside the grid the
+edge cells are extended.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct BilinearInterpolation {
+    x: Vec<Real>,
+    y: Vec<Real>,
+    z: Matrix,
+}
+impl BilinearInterpolation {
+    // Constructor
+    pub fn new(x: &[Real], y: &[Real], z: &Matrix) -> BilinearInterpolation {
+        detail::check_grid(x, y, z);
+        BilinearInterpolation {
+            x: x.to_vec(),
+            y: y.to_vec(),
+            z: z.clone(),
+        }
+    }
+
+    pub fn value(&self, x: Real, y: Real) -> Real {
+        let i: Size = detail::locate(&self.x, x);
+        let j: Size = detail::locate(&self.y, y);
+        let u: Real = (x - self.x[i]) / (self.x[i + 1] - self.x[i]);
+        let v: Real = (y - self.y[j]) / (self.y[j + 1] - self.y[j]);
+        (1.0 - u) * (1.0 - v) * self.z[j][i]
+            + u * (1.0 - v) * self.z[j][i + 1]
+            + (1.0 - u) * v * self.z[j + 1][i]
+            + u * v * self.z[j + 1][i + 1]
+    }
+}
+
+pub(crate) mod detail {
+    use crate::math::matrix::Matrix;
+    use crate::types::{Real, Size};
+
+    // Checks the grid of a 2-D interpolation: z[j][i] = f(x_i, y_j)
+    pub(crate) fn check_grid(x: &[Real], y: &[Real], z: &Matrix) {
+        if x.len() < 2 || y.len() < 2 {
+            panic!(
+                "not enough points to interpolate: at least 2 x and 2 y required, {} and {} provided",
+                x.len(),
+                y.len()
+            );
+        }
+        if z.rows() != y.len() || z.columns() != x.len() {
+            panic!(
+                "{}x{} z matrix given for {} y and {} x values",
+                z.rows(),
+                z.columns(),
+                y.len(),
+                x.len()
+            );
+        }
+        if x.windows(2).any(|w| w[1] <= w[0]) {
+            panic!("unsorted x values");
+        }
+        if y.windows(2).any(|w| w[1] <= w[0]) {
+            panic!("unsorted y values");
+        }
+    }
+
+    // Index of the segment [x_i, x_{i+1}] used for x
+    pub(crate) fn locate(x: &[Real], x0: Real) -> Size {
+        let upper: Size = x.partition_point(|xi| *xi <= x0);
+        upper.clamp(1, x.len() - 1) - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    #[test]
+    fn reproduces_bilinear_functions() {
+        let x: [Real; 3] = [1.0, 2.0, 4.0];
+        let y: [Real; 4] = [0.0, 0.5, 1.0, 3.0];
+        let f = |x: Real, y: Real| -> Real { 1.0 + 2.0 * x - y + 0.5 * x * y };
+        let z: Matrix = Matrix::from_rows(
+            &y.iter()
+                .map(|y| x.iter().map(|x| f(*x, *y)).collect())
+                .collect::<Vec<Vec<Real>>>(),
+        );
+        let interpolation: BilinearInterpolation = BilinearInterpolation::new(&x, &y, &z);
+        for (xi, yi) in [
+            (1.0, 0.0),
+            (1.5, 0.25),
+            (3.0, 2.0),
+            (4.0, 3.0),
+            (0.0, -1.0),
+            (5.0, 4.0),
+        ] {
+            assert!(
+                (interpolation.value(xi, yi) - f(xi, yi)).abs() < 1.0e-14,
+                "value at ({xi}, {yi})"
+            );
+        }
+    }
+
+    #[test]
+    fn interpolates_linearly_along_the_cell_edges() {
+        let z: Matrix = Matrix::from_rows(&[vec![0.0, 1.0], vec![2.0, 5.0]]);
+        let interpolation: BilinearInterpolation =
+            BilinearInterpolation::new(&[0.0, 1.0], &[0.0, 1.0], &z);
+        assert_eq!(interpolation.value(0.5, 0.0), 0.5);
+        assert_eq!(interpolation.value(0.0, 0.5), 1.0);
+        assert_eq!(interpolation.value(0.5, 0.5), 2.0);
+        assert_eq!(interpolation.value(1.0, 1.0), 5.0);
+    }
+
+    #[test]
+    fn new_panics_on_invalid_grids() {
+        let cases: [(Vec<Real>, Vec<Real>, Matrix, &str); 3] = [
+            (
+                vec![0.0, 1.0],
+                vec![0.0, 1.0],
+                Matrix::new(2, 3, 0.0),
+                "mismatched z",
+            ),
+            (
+                vec![1.0, 0.0],
+                vec![0.0, 1.0],
+                Matrix::new(2, 2, 0.0),
+                "unsorted x",
+            ),
+            (vec![0.0], vec![0.0, 1.0], Matrix::new(2, 1, 0.0), "one x"),
+        ];
+        for (x, y, z, label) in cases {
+            let result = panic::catch_unwind(|| BilinearInterpolation::new(&x, &y, &z));
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use crate::types::{Real, Size};
+
+/*
+Natural cubic spline through the points (x_i, y_i) (QuantLib
+CubicInterpolation with Spline derivatives and zero second derivative
+at both ends).
+
+On [x_i, x_{i+1}], with h = x_{i+1} - x_i, the spline is the cubic with
+second derivatives M_i and M_{i+1} at the nodes; the M's solve the
+tridiagonal system
+    h_{i-1} M_{i-1} + 2 (h_{i-1} + h_i) M_i + h_i M_{i+1}
+        = 6 ((y_{i+1} - y_i) / h_i - (y_i - y_{i-1}) / h_{i-1})
+with M_0 = M_n = 0. Outside [x_0, x_n] the end cubics are extended.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct CubicNaturalSpline {
+    x: Vec<Real>,
+    y: Vec<Real>,
+    second_derivatives: Vec<Real>,
+}
+impl CubicNaturalSpline {
+    // Constructor
+    pub fn new(x: &[Real], y: &[Real]) -> CubicNaturalSpline {
+        let n: Size = x.len();
+        if n < 2 {
+            panic!("not enough points to interpolate: at least 2 required, {n} provided");
+        }
+        if n != y.len() {
+            panic!("different number of x ({}) and y ({}) values", n, y.len());
+        }
+        if x.windows(2).any(|w| w[1] <= w[0]) {
+            panic!("unsorted x values");
+        }
+
+        // Thomas algorithm on the inner nodes
+        let mut second_derivatives: Vec<Real> = vec![0.0; n];
+        if n > 2 {
+            let m: Size = n - 2;
+            let mut c_prime: Vec<Real> = vec![0.0; m];
+            let mut d_prime: Vec<Real> = vec![0.0; m];
+            for k in 0..m {
+                let i: Size = k + 1;
+                let h_left: Real = x[i] - x[i - 1];
+                let h_right: Real = x[i + 1] - x[i];
+                let diagonal: Real = 2.0 * (h_left + h_right);
+                let rhs: Real = 6.0 * ((y[i + 1] - y[i]) / h_right - (y[i] - y[i - 1]) / h_left);
+                if k == 0 {
+                    c_prime[k] = h_right / diagonal;
+                    d_prime[k] = rhs / diagonal;
+                } else {
+                    let denominator: Real = diagonal - h_left * c_prime[k - 1];
+                    c_prime[k] = h_right / denominator;
+                    d_prime[k] = (rhs - h_left * d_prime[k - 1]) / denominator;
+                }
+            }
+            second_derivatives[m] = d_prime[m - 1];
+            for k in (0..m - 1).rev() {
+                second_derivatives[k + 1] = d_prime[k] - c_prime[k] * second_derivatives[k + 2];
+            }
+        }
+
+        CubicNaturalSpline {
+            x: x.to_vec(),
+            y: y.to_vec(),
+            second_derivatives,
+        }
+    }
+
+    pub fn value(&self, x: Real) -> Real {
+        let (i, h, a, b): (Size, Real, Real, Real) = self.coordinates(x);
+        let (m0, m1): (Real, Real) = (self.second_derivatives[i], self.second_derivatives[i + 1]);
+        a * self.y[i]
+            + b * self.y[i + 1]
+            + ((a * a * a - a) * m0 + (b * b * b - b) * m1) * h * h / 6.0
+    }
+    pub fn derivative(&self, x: Real) -> Real {
+        let (i, h, a, b): (Size, Real, Real, Real) = self.coordinates(x);
+        let (m0, m1): (Real, Real) = (self.second_derivatives[i], self.second_derivatives[i + 1]);
+        (self.y[i + 1] - self.y[i]) / h - (3.0 * a * a - 1.0) * h * m0 / 6.0
+            + (3.0 * b * b - 1.0) * h * m1 / 6.0
+    }
+    pub fn second_derivative(&self, x: Real) -> Real {
+        let (i, _, a, b): (Size, Real, Real, Real) = self.coordinates(x);
+        a * self.second_derivatives[i] + b * self.second_derivatives[i + 1]
+    }
+
+    // Segment index, its length and the weights a = (x_{i+1} - x) / h, b = 1 - a
+    fn coordinates(&self, x: Real) -> (Size, Real, Real, Real) {
+        let upper: Size = self.x.partition_point(|xi| *xi <= x);
+        let i: Size = upper.clamp(1, self.x.len() - 1) - 1;
+        let h: Real = self.x[i + 1] - self.x[i];
+        let a: Real = (self.x[i + 1] - x) / h;
+        (i, h, a, 1.0 - a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reproduces_nodes_and_straight_lines() {
+        let x: [Real; 5] = [0.0, 0.5, 1.5, 2.0, 3.0];
+        let line: Vec<Real> = x.iter().map(|x| 2.0 * x - 1.0).collect();
+        let spline: CubicNaturalSpline = CubicNaturalSpline::new(&x, &line);
+        for t in [-1.0, 0.0, 0.25, 1.0, 2.7, 4.0] {
+            assert!((spline.value(t) - (2.0 * t - 1.0)).abs() < 1.0e-14);
+            assert!((spline.derivative(t) - 2.0).abs() < 1.0e-14);
+            assert!(spline.second_derivative(t).abs() < 1.0e-14);
+        }
+
+        let y: [Real; 5] = [1.0, -2.0, 0.5, 3.0, 1.0];
+        let spline: CubicNaturalSpline = CubicNaturalSpline::new(&x, &y);
+        for (xi, yi) in x.iter().zip(y.iter()) {
+            assert!((spline.value(*xi) - yi).abs() < 1.0e-14);
+        }
+        assert_eq!(spline.second_derivative(0.0), 0.0);
+        assert!(spline.second_derivative(3.0).abs() < 1.0e-14);
+    }
+
+    #[test]
+    fn approximates_smooth_functions_and_their_derivatives() {
+        let x: Vec<Real> = (0..=60).map(|i| i as Real * 0.05).collect();
+        let y: Vec<Real> = x.iter().map(|x| x.sin()).collect();
+        let spline: CubicNaturalSpline = CubicNaturalSpline::new(&x, &y);
+        for t in [0.5, 1.01, 1.7, 2.33] {
+            assert!((spline.value(t) - t.sin()).abs() < 1.0e-6, "value at {t}");
+            assert!(
+                (spline.derivative(t) - t.cos()).abs() < 1.0e-4,
+                "derivative at {t}"
+            );
+            assert!(
+                (spline.second_derivative(t) + t.sin()).abs() < 1.0e-2,
+                "second derivative at {t}"
+            );
+        }
+    }
+
+    #[test]
+    fn derivatives_are_continuous_at_the_nodes() {
+        let x: [Real; 4] = [0.0, 1.0, 2.5, 3.0];
+        let spline: CubicNaturalSpline = CubicNaturalSpline::new(&x, &[0.0, 2.0, -1.0, 1.0]);
+        let eps: Real = 1.0e-9;
+        for xi in &x[1..3] {
+            assert!((spline.derivative(xi - eps) - spline.derivative(xi + eps)).abs() < 1.0e-6);
+            assert!(
+                (spline.second_derivative(xi - eps) - spline.second_derivative(xi + eps)).abs()
+                    < 1.0e-6
+            );
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use crate::types::{Real, Size};
+
+/*
+Linear interpolation between the points (x_i, y_i) (QuantLib
+LinearInterpolation). The x_i must be sorted; outside [x_0, x_n] the
+first and last segments are extended.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct LinearInterpolation {
+    x: Vec<Real>,
+    y: Vec<Real>,
+}
+impl LinearInterpolation {
+    // Constructor
+    pub fn new(x: &[Real], y: &[Real]) -> LinearInterpolation {
+        if x.len() < 2 {
+            panic!(
+                "not enough points to interpolate: at least 2 required, {} provided",
+                x.len()
+            );
+        }
+        if x.len() != y.len() {
+            panic!(
+                "different number of x ({}) and y ({}) values",
+                x.len(),
+                y.len()
+            );
+        }
+        if x.windows(2).any(|w| w[1] <= w[0]) {
+            panic!("unsorted x values");
+        }
+        LinearInterpolation {
+            x: x.to_vec(),
+            y: y.to_vec(),
+        }
+    }
+
+    pub fn value(&self, x: Real) -> Real {
+        let i: Size = self.locate(x);
+        let slope: Real = (self.y[i + 1] - self.y[i]) / (self.x[i + 1] - self.x[i]);
+        self.y[i] + (x - self.x[i]) * slope
+    }
+    pub fn derivative(&self, x: Real) -> Real {
+        let i: Size = self.locate(x);
+        (self.y[i + 1] - self.y[i]) / (self.x[i + 1] - self.x[i])
+    }
+
+    // Index of the segment [x_i, x_{i+1}] used for x
+    fn locate(&self, x: Real) -> Size {
+        let upper: Size = self.x.partition_point(|xi| *xi <= x);
+        upper.clamp(1, self.x.len() - 1) - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    #[test]
+    fn interpolates_and_extrapolates_linearly() {
+        let interpolation: LinearInterpolation =
+            LinearInterpolation::new(&[1.0, 2.0, 4.0], &[1.0, 3.0, 4.0]);
+        let cases: [(Real, Real, Real); 6] = [
+            (1.0, 1.0, 2.0),
+            (1.5, 2.0, 2.0),
+            (2.0, 3.0, 0.5),
+            (3.0, 3.5, 0.5),
+            (0.0, -1.0, 2.0),
+            (6.0, 5.0, 0.5),
+        ];
+
+        for (x, value, derivative) in cases {
+            assert_eq!(interpolation.value(x), value, "value at {x}");
+            assert_eq!(interpolation.derivative(x), derivative, "derivative at {x}");
+        }
+    }
+
+    #[test]
+    fn new_panics_on_invalid_points() {
+        let cases: [(&[Real], &[Real], &str); 3] = [
+            (&[1.0], &[1.0], "one point"),
+            (&[1.0, 2.0], &[1.0], "sizes differ"),
+            (&[2.0, 1.0], &[1.0, 2.0], "unsorted"),
+        ];
+        for (x, y, label) in cases {
+            let result = panic::catch_unwind(|| LinearInterpolation::new(x, y));
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
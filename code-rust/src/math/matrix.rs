@@ -0,0 +1,322 @@
+use crate::math::array::Array;
+use crate::math::matrixutilities::lu_decomposition::LuDecomposition;
+use crate::types::{Real, Size};
+use std::fmt::{Display, Formatter, Result};
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+/*
+Dense matrix of reals stored by rows (QuantLib Matrix).
+
+m[i] is the i-th row as a slice, so elements are read and written as
+m[i][j]. Arithmetic panics on incompatible sizes: sums need the same
+shape, products need the columns of the left operand to match the rows
+(or size) of the right one. The determinant and the inverse come from
+the LU decomposition with partial pivoting.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct Matrix {
+    rows: Size,
+    columns: Size,
+    data: Vec<Real>,
+}
+impl Matrix {
+    // Constructors
+    pub fn new(rows: Size, columns: Size, value: Real) -> Matrix {
+        Matrix {
+            rows,
+            columns,
+            data: vec![value; rows * columns],
+        }
+    }
+    pub fn identity(size: Size) -> Matrix {
+        let mut m: Matrix = Matrix::new(size, size, 0.0);
+        for i in 0..size {
+            m[i][i] = 1.0;
+        }
+        m
+    }
+    pub fn from_rows(rows: &[Vec<Real>]) -> Matrix {
+        let columns: Size = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != columns) {
+            panic!("rows of different lengths given");
+        }
+        Matrix {
+            rows: rows.len(),
+            columns,
+            data: rows.iter().flatten().copied().collect(),
+        }
+    }
+    pub fn from_diagonal(diagonal: &[Real]) -> Matrix {
+        let mut m: Matrix = Matrix::new(diagonal.len(), diagonal.len(), 0.0);
+        for (i, d) in diagonal.iter().enumerate() {
+            m[i][i] = *d;
+        }
+        m
+    }
+    // a b^T
+    pub fn outer_product(a: &[Real], b: &[Real]) -> Matrix {
+        Matrix {
+            rows: a.len(),
+            columns: b.len(),
+            data: a
+                .iter()
+                .flat_map(|x| b.iter().map(move |y| x * y))
+                .collect(),
+        }
+    }
+
+    // Inspectors
+    pub fn rows(&self) -> Size {
+        self.rows
+    }
+    pub fn columns(&self) -> Size {
+        self.columns
+    }
+    pub fn is_square(&self) -> bool {
+        self.rows == self.columns
+    }
+    pub fn column(&self, j: Size) -> Array {
+        (0..self.rows).map(|i| self[i][j]).collect()
+    }
+    pub fn diagonal(&self) -> Array {
+        (0..self.rows.min(self.columns))
+            .map(|i| self[i][i])
+            .collect()
+    }
+    pub fn to_rows(&self) -> Vec<Vec<Real>> {
+        self.data
+            .chunks(self.columns.max(1))
+            .take(self.rows)
+            .map(|row| row.to_vec())
+            .collect()
+    }
+
+    pub fn transpose(&self) -> Matrix {
+        let mut t: Matrix = Matrix::new(self.columns, self.rows, 0.0);
+        for i in 0..self.rows {
+            for j in 0..self.columns {
+                t[j][i] = self[i][j];
+            }
+        }
+        t
+    }
+    pub fn determinant(&self) -> Real {
+        LuDecomposition::new(self).determinant()
+    }
+    pub fn inverse(&self) -> Matrix {
+        LuDecomposition::new(self).inverse()
+    }
+    // Largest absolute difference with a matrix of the same shape
+    pub fn max_abs_difference(&self, other: &Matrix) -> Real {
+        detail::check_shapes(self, other);
+        self.data
+            .iter()
+            .zip(other.data.iter())
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0, Real::max)
+    }
+    // sqrt(sum_ij m_ij^2)
+    pub fn frobenius_norm(&self) -> Real {
+        self.data.iter().map(|x| x * x).sum::<Real>().sqrt()
+    }
+}
+
+// Traits
+impl Index<Size> for Matrix {
+    type Output = [Real];
+
+    fn index(&self, i: Size) -> &[Real] {
+        &self.data[i * self.columns..(i + 1) * self.columns]
+    }
+}
+impl IndexMut<Size> for Matrix {
+    fn index_mut(&mut self, i: Size) -> &mut [Real] {
+        &mut self.data[i * self.columns..(i + 1) * self.columns]
+    }
+}
+impl Display for Matrix {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        for i in 0..self.rows {
+            let row: Vec<String> = self[i].iter().map(|x| format!("{x}")).collect();
+            writeln!(f, "| {} |", row.join(" "))?;
+        }
+        Ok(())
+    }
+}
+impl Neg for &Matrix {
+    type Output = Matrix;
+
+    fn neg(self) -> Matrix {
+        self * -1.0
+    }
+}
+impl Add for &Matrix {
+    type Output = Matrix;
+
+    fn add(self, other: &Matrix) -> Matrix {
+        detail::check_shapes(self, other);
+        Matrix {
+            rows: self.rows,
+            columns: self.columns,
+            data: self
+                .data
+                .iter()
+                .zip(&other.data)
+                .map(|(a, b)| a + b)
+                .collect(),
+        }
+    }
+}
+impl Sub for &Matrix {
+    type Output = Matrix;
+
+    fn sub(self, other: &Matrix) -> Matrix {
+        detail::check_shapes(self, other);
+        Matrix {
+            rows: self.rows,
+            columns: self.columns,
+            data: self
+                .data
+                .iter()
+                .zip(&other.data)
+                .map(|(a, b)| a - b)
+                .collect(),
+        }
+    }
+}
+impl Mul for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, other: &Matrix) -> Matrix {
+        if self.columns != other.rows {
+            panic!(
+                "matrices with incompatible sizes ({}x{} and {}x{}) cannot be multiplied",
+                self.rows, self.columns, other.rows, other.columns
+            );
+        }
+        let mut product: Matrix = Matrix::new(self.rows, other.columns, 0.0);
+        for i in 0..self.rows {
+            for k in 0..self.columns {
+                let a: Real = self[i][k];
+                for j in 0..other.columns {
+                    product[i][j] += a * other[k][j];
+                }
+            }
+        }
+        product
+    }
+}
+impl Mul<&Array> for &Matrix {
+    type Output = Array;
+
+    fn mul(self, other: &Array) -> Array {
+        if self.columns != other.len() {
+            panic!(
+                "vector size ({}) differs from the matrix columns ({})",
+                other.len(),
+                self.columns
+            );
+        }
+        (0..self.rows)
+            .map(|i| self[i].iter().zip(other.iter()).map(|(a, b)| a * b).sum())
+            .collect()
+    }
+}
+impl Mul<Real> for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, other: Real) -> Matrix {
+        Matrix {
+            rows: self.rows,
+            columns: self.columns,
+            data: self.data.iter().map(|a| a * other).collect(),
+        }
+    }
+}
+impl Mul<&Matrix> for Real {
+    type Output = Matrix;
+
+    fn mul(self, other: &Matrix) -> Matrix {
+        other * self
+    }
+}
+
+pub(crate) mod detail {
+    use super::*;
+
+    pub(crate) fn check_shapes(left: &Matrix, right: &Matrix) {
+        if left.rows != right.rows || left.columns != right.columns {
+            panic!(
+                "matrices with different sizes ({}x{} and {}x{}) cannot be combined",
+                left.rows, left.columns, right.rows, right.columns
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    fn m() -> Matrix {
+        Matrix::from_rows(&[vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]])
+    }
+
+    #[test]
+    fn construction_and_access() {
+        let mut a: Matrix = m();
+        assert_eq!((a.rows(), a.columns()), (2, 3));
+        assert!(!a.is_square());
+        assert_eq!(a[1][2], 6.0);
+        assert_eq!(a.column(1).to_vec(), vec![2.0, 5.0]);
+        assert_eq!(a.diagonal().to_vec(), vec![1.0, 5.0]);
+        assert_eq!(
+            a.transpose().to_rows(),
+            vec![vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]]
+        );
+        a[0][1] = -2.0;
+        assert_eq!(a[0], [1.0, -2.0, 3.0]);
+        assert_eq!(Matrix::identity(2), Matrix::from_diagonal(&[1.0, 1.0]));
+        assert_eq!(
+            Matrix::outer_product(&[1.0, 2.0], &[3.0, 4.0]).to_rows(),
+            vec![vec![3.0, 4.0], vec![6.0, 8.0]]
+        );
+        assert_eq!(format!("{}", Matrix::identity(2)), "| 1 0 |\n| 0 1 |\n");
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a: Matrix = m();
+        let product: Matrix = &a * &a.transpose();
+        assert_eq!(product.to_rows(), vec![vec![14.0, 32.0], vec![32.0, 77.0]]);
+        assert_eq!(
+            (&a * &Array::from(vec![1.0, 0.0, -1.0])).to_vec(),
+            vec![-2.0, -2.0]
+        );
+        assert_eq!((&a + &a), (2.0 * &a));
+        assert_eq!((&a - &a), Matrix::new(2, 3, 0.0));
+        assert_eq!((-&a)[1][0], -4.0);
+        assert_eq!(Matrix::from_rows(&[vec![3.0, 4.0]]).frobenius_norm(), 5.0);
+    }
+
+    #[test]
+    fn determinant_and_inverse() {
+        let a: Matrix = Matrix::from_rows(&[
+            vec![4.0, 3.0, 2.0],
+            vec![2.0, 1.0, 3.0],
+            vec![3.0, 2.0, 1.0],
+        ]);
+        assert!((a.determinant() - 3.0).abs() < 1.0e-14);
+        let identity: Matrix = &a * &a.inverse();
+        assert!(identity.max_abs_difference(&Matrix::identity(3)) < 1.0e-14);
+    }
+
+    #[test]
+    fn incompatible_sizes_panic() {
+        assert!(panic::catch_unwind(|| &m() * &m()).is_err());
+        assert!(panic::catch_unwind(|| &m() + &m().transpose()).is_err());
+        assert!(panic::catch_unwind(|| &m() * &Array::new(2, 1.0)).is_err());
+        assert!(panic::catch_unwind(|| Matrix::from_rows(&[vec![1.0], vec![1.0, 2.0]])).is_err());
+    }
+}
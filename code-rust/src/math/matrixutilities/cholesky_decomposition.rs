@@ -0,0 +1,109 @@
+use crate::math::array::Array;
+use crate::math::matrix::Matrix;
+use crate::types::{Real, Size};
+
+/*
+Cholesky decomposition (QuantLib CholeskyDecomposition): the lower
+triangular L with L L^T = A, for a symmetric positive definite A.
+
+With flexible = true positive semi-definite matrices are accepted as
+well: the columns of null pivots are set to zero, so that L L^T = A
+still holds. Otherwise a non positive pivot panics.
+*/
+pub fn cholesky_decomposition(matrix: &Matrix, flexible: bool) -> Matrix {
+    if !matrix.is_square() {
+        panic!(
+            "Cholesky decomposition requires a square matrix ({}x{} given)",
+            matrix.rows(),
+            matrix.columns()
+        );
+    }
+    let n: Size = matrix.rows();
+    let mut l: Matrix = Matrix::new(n, n, 0.0);
+    for i in 0..n {
+        for j in i..n {
+            let sum: Real = matrix[i][j] - (0..i).map(|k| l[i][k] * l[j][k]).sum::<Real>();
+            if i == j {
+                if sum <= 0.0 {
+                    if !flexible || sum < -1.0e-10 * matrix[i][i].abs().max(1.0) {
+                        panic!("matrix is not positive definite");
+                    }
+                    // Null pivot of a semi-definite matrix: the column is zero
+                    break;
+                }
+                l[i][i] = sum.sqrt();
+            } else {
+                l[j][i] = sum / l[i][i];
+            }
+        }
+    }
+    l
+}
+
+// x such that L L^T x = b, with L from cholesky_decomposition (non-singular)
+pub fn cholesky_solve_for(l: &Matrix, b: &Array) -> Array {
+    let n: Size = l.rows();
+    if b.len() != n {
+        panic!(
+            "vector size ({}) differs from the matrix size ({})",
+            b.len(),
+            n
+        );
+    }
+    let mut x: Array = b.clone();
+    for i in 0..n {
+        for k in 0..i {
+            x[i] -= l[i][k] * x[k];
+        }
+        x[i] /= l[i][i];
+    }
+    for i in (0..n).rev() {
+        for k in i + 1..n {
+            x[i] -= l[k][i] * x[k];
+        }
+        x[i] /= l[i][i];
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    #[test]
+    fn factor_reproduces_the_matrix() {
+        let a: Matrix = Matrix::from_rows(&[
+            vec![4.0, 12.0, -16.0],
+            vec![12.0, 37.0, -43.0],
+            vec![-16.0, -43.0, 98.0],
+        ]);
+        let l: Matrix = cholesky_decomposition(&a, false);
+        let expected: Matrix = Matrix::from_rows(&[
+            vec![2.0, 0.0, 0.0],
+            vec![6.0, 1.0, 0.0],
+            vec![-8.0, 5.0, 3.0],
+        ]);
+        assert!(l.max_abs_difference(&expected) < 1.0e-14);
+
+        let b: Array = Array::from(vec![1.0, 2.0, 3.0]);
+        let x: Array = cholesky_solve_for(&l, &b);
+        assert!((&(&a * &x) - &b).norm2() < 1.0e-12);
+    }
+
+    #[test]
+    fn flexible_decomposition_of_semi_definite_matrices() {
+        // Perfectly correlated first two variables
+        let a: Matrix = Matrix::from_rows(&[
+            vec![1.0, 1.0, 0.5],
+            vec![1.0, 1.0, 0.5],
+            vec![0.5, 0.5, 1.0],
+        ]);
+        let l: Matrix = cholesky_decomposition(&a, true);
+        assert!((&l * &l.transpose()).max_abs_difference(&a) < 1.0e-14);
+        assert!(panic::catch_unwind(|| cholesky_decomposition(&a, false)).is_err());
+
+        let indefinite: Matrix = Matrix::from_rows(&[vec![1.0, 2.0], vec![2.0, 1.0]]);
+        assert!(panic::catch_unwind(|| cholesky_decomposition(&indefinite, true)).is_err());
+    }
+}
@@ -0,0 +1,145 @@
+use crate::math::array::Array;
+use crate::math::matrix::Matrix;
+use crate::types::{Real, Size};
+
+/*
+LU decomposition with partial pivoting of a square matrix, P A = L U,
+with L unit lower triangular and U upper triangular, both stored in a
+single matrix (what QuantLib uses boost's lu_factorize for in inverse
+and determinant).
+
+The decomposition itself never fails: singular matrices have a zero
+pivot, hence a zero determinant, and panic only when solving or
+inverting.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct LuDecomposition {
+    lu: Matrix,
+    pivots: Vec<Size>,
+    sign: Real,
+}
+impl LuDecomposition {
+    // Constructor
+    pub fn new(matrix: &Matrix) -> LuDecomposition {
+        if !matrix.is_square() {
+            panic!(
+                "LU decomposition requires a square matrix ({}x{} given)",
+                matrix.rows(),
+                matrix.columns()
+            );
+        }
+        let n: Size = matrix.rows();
+        let mut lu: Matrix = matrix.clone();
+        let mut pivots: Vec<Size> = (0..n).collect();
+        let mut sign: Real = 1.0;
+        for k in 0..n {
+            let p: Size = (k..n)
+                .max_by(|a, b| lu[*a][k].abs().partial_cmp(&lu[*b][k].abs()).unwrap())
+                .unwrap();
+            if p != k {
+                for j in 0..n {
+                    let tmp: Real = lu[k][j];
+                    lu[k][j] = lu[p][j];
+                    lu[p][j] = tmp;
+                }
+                pivots.swap(k, p);
+                sign = -sign;
+            }
+            let pivot: Real = lu[k][k];
+            if pivot == 0.0 {
+                continue;
+            }
+            for i in k + 1..n {
+                let factor: Real = lu[i][k] / pivot;
+                lu[i][k] = factor;
+                for j in k + 1..n {
+                    lu[i][j] -= factor * lu[k][j];
+                }
+            }
+        }
+        LuDecomposition { lu, pivots, sign }
+    }
+
+    // Inspectors
+    pub fn determinant(&self) -> Real {
+        self.sign * self.lu.diagonal().iter().product::<Real>()
+    }
+    pub fn is_singular(&self) -> bool {
+        self.lu.diagonal().contains(&0.0)
+    }
+
+    // x such that A x = b
+    pub fn solve(&self, b: &Array) -> Array {
+        let n: Size = self.lu.rows();
+        if b.len() != n {
+            panic!(
+                "vector size ({}) differs from the matrix size ({})",
+                b.len(),
+                n
+            );
+        }
+        if self.is_singular() {
+            panic!("singular matrix");
+        }
+        let mut x: Array = self.pivots.iter().map(|p| b[*p]).collect();
+        for i in 0..n {
+            for k in 0..i {
+                x[i] -= self.lu[i][k] * x[k];
+            }
+        }
+        for i in (0..n).rev() {
+            for k in i + 1..n {
+                x[i] -= self.lu[i][k] * x[k];
+            }
+            x[i] /= self.lu[i][i];
+        }
+        x
+    }
+    pub fn inverse(&self) -> Matrix {
+        let n: Size = self.lu.rows();
+        let mut inverse: Matrix = Matrix::new(n, n, 0.0);
+        for j in 0..n {
+            let mut e: Array = Array::new(n, 0.0);
+            e[j] = 1.0;
+            let column: Array = self.solve(&e);
+            for i in 0..n {
+                inverse[i][j] = column[i];
+            }
+        }
+        inverse
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    #[test]
+    fn solve_reproduces_the_right_hand_side() {
+        // The first pivot is zero: rows must be swapped
+        let a: Matrix = Matrix::from_rows(&[
+            vec![0.0, 2.0, 1.0],
+            vec![1.0, -1.0, 4.0],
+            vec![3.0, 1.0, -2.0],
+        ]);
+        let lu: LuDecomposition = LuDecomposition::new(&a);
+        let b: Array = Array::from(vec![1.0, 2.0, 3.0]);
+        let x: Array = lu.solve(&b);
+
+        let residual: Array = &(&a * &x) - &b;
+        assert!(residual.norm2() < 1.0e-14);
+        // det = 0 (2 + 4) - 2 (-2 - 12) + 1 (1 + 3)
+        assert!((lu.determinant() - 32.0).abs() < 1.0e-13);
+    }
+
+    #[test]
+    fn singular_matrices_cannot_be_solved() {
+        let a: Matrix = Matrix::from_rows(&[vec![1.0, 2.0], vec![2.0, 4.0]]);
+        let lu: LuDecomposition = LuDecomposition::new(&a);
+        assert!(lu.is_singular());
+        assert_eq!(lu.determinant(), 0.0);
+        assert!(panic::catch_unwind(|| lu.solve(&Array::new(2, 1.0))).is_err());
+        assert!(panic::catch_unwind(|| LuDecomposition::new(&Matrix::new(2, 3, 1.0))).is_err());
+    }
+}
@@ -0,0 +1,228 @@
+use crate::math::comparison::close;
+use crate::math::matrix::Matrix;
+use crate::math::matrixutilities::cholesky_decomposition::cholesky_decomposition;
+use crate::math::matrixutilities::symmetric_schur_decomposition::SymmetricSchurDecomposition;
+use crate::types::{Real, Size};
+
+/*
+How pseudo_sqrt treats matrices that are not positive semi-definite
+(QuantLib SalvagingAlgorithm):
+- None panics,
+- Spectral floors the negative eigenvalues at zero,
+- Higham replaces a correlation matrix by the nearest one (in Frobenius
+  norm) that is positive semi-definite.
+*/
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SalvagingAlgorithm {
+    None,
+    Spectral,
+    Higham,
+}
+
+/*
+Pseudo square root S of a symmetric matrix, S S^T = A (QuantLib
+pseudoSqrt), as used to correlate Gaussian draws.
+
+Without salvaging the root is the (flexible) Cholesky factor. The
+spectral root is V sqrt(D) with the floored eigenvalues; its rows are
+then rescaled so that S S^T keeps the diagonal of A. The Higham root is
+the spectral one of the repaired matrix. The matrix must be square and
+symmetric.
+*/
+pub fn pseudo_sqrt(matrix: &Matrix, salvaging: SalvagingAlgorithm) -> Matrix {
+    detail::check_symmetry(matrix);
+    match salvaging {
+        SalvagingAlgorithm::None => {
+            let schur: SymmetricSchurDecomposition = SymmetricSchurDecomposition::new(matrix);
+            let smallest: Real = schur.eigenvalues()[matrix.rows() - 1];
+            if smallest < -1.0e-16 {
+                panic!("matrix is not positive semi-definite (smallest eigenvalue {smallest})");
+            }
+            cholesky_decomposition(matrix, true)
+        }
+        SalvagingAlgorithm::Spectral => {
+            detail::spectral_root(&SymmetricSchurDecomposition::new(matrix), matrix)
+        }
+        SalvagingAlgorithm::Higham => {
+            let repaired: Matrix = nearest_correlation_matrix(matrix, 1.0e-12, 1000);
+            detail::spectral_root(&SymmetricSchurDecomposition::new(&repaired), &repaired)
+        }
+    }
+}
+
+/*
+Nearest correlation matrix to a symmetric one (Higham, 2002): alternating
+projections on the positive semi-definite matrices and on those with
+unit diagonal, with Dykstra's correction on the former. Iterations stop
+when the relative change of the iterate falls below the tolerance.
+*/
+pub fn nearest_correlation_matrix(
+    matrix: &Matrix,
+    tolerance: Real,
+    max_iterations: Size,
+) -> Matrix {
+    let n: Size = matrix.rows();
+    let mut y: Matrix = matrix.clone();
+    let mut correction: Matrix = Matrix::new(n, n, 0.0);
+    for _ in 0..max_iterations {
+        let r: Matrix = &y - &correction;
+        let x: Matrix = detail::project_on_semi_definite(&r);
+        correction = &x - &r;
+        let mut next: Matrix = x;
+        for i in 0..n {
+            next[i][i] = 1.0;
+        }
+        let change: Real = (&next - &y).frobenius_norm() / next.frobenius_norm();
+        y = next;
+        if change <= tolerance {
+            break;
+        }
+    }
+    y
+}
+
+pub(crate) mod detail {
+    use super::*;
+
+    pub(crate) fn check_symmetry(matrix: &Matrix) {
+        if !matrix.is_square() {
+            panic!(
+                "non square matrix: {} rows, {} columns",
+                matrix.rows(),
+                matrix.columns()
+            );
+        }
+        for i in 0..matrix.rows() {
+            for j in 0..i {
+                if !close(matrix[i][j], matrix[j][i]) {
+                    panic!(
+                        "non symmetric matrix: [{}][{}] = {}, [{}][{}] = {}",
+                        i, j, matrix[i][j], j, i, matrix[j][i]
+                    );
+                }
+            }
+        }
+    }
+
+    // V max(D, 0) V^T
+    pub(crate) fn project_on_semi_definite(matrix: &Matrix) -> Matrix {
+        let schur: SymmetricSchurDecomposition = SymmetricSchurDecomposition::new(matrix);
+        let root: Matrix = floored_root(&schur);
+        &root * &root.transpose()
+    }
+
+    // V sqrt(max(D, 0))
+    pub(crate) fn floored_root(schur: &SymmetricSchurDecomposition) -> Matrix {
+        let roots: Vec<Real> = schur
+            .eigenvalues()
+            .iter()
+            .map(|l| l.max(0.0).sqrt())
+            .collect();
+        schur.eigenvectors() * &Matrix::from_diagonal(&roots)
+    }
+
+    // Floored root with rows rescaled to the diagonal of the original matrix
+    pub(crate) fn spectral_root(schur: &SymmetricSchurDecomposition, matrix: &Matrix) -> Matrix {
+        let mut root: Matrix = floored_root(schur);
+        for i in 0..root.rows() {
+            let norm: Real = root[i].iter().map(|x| x * x).sum::<Real>().sqrt();
+            if norm > 0.0 {
+                let scale: Real = matrix[i][i].sqrt() / norm;
+                for j in 0..root.columns() {
+                    root[i][j] *= scale;
+                }
+            }
+        }
+        root
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    fn product(root: &Matrix) -> Matrix {
+        root * &root.transpose()
+    }
+
+    #[test]
+    fn roots_of_positive_definite_matrices_agree() {
+        let a: Matrix = Matrix::from_rows(&[
+            vec![1.0, 0.9, 0.7],
+            vec![0.9, 1.0, 0.4],
+            vec![0.7, 0.4, 1.0],
+        ]);
+        let cases: [SalvagingAlgorithm; 3] = [
+            SalvagingAlgorithm::None,
+            SalvagingAlgorithm::Spectral,
+            SalvagingAlgorithm::Higham,
+        ];
+        for salvaging in cases.iter() {
+            let root: Matrix = pseudo_sqrt(&a, *salvaging);
+            assert!(
+                product(&root).max_abs_difference(&a) < 1.0e-10,
+                "root of {salvaging:?} salvaging"
+            );
+        }
+    }
+
+    #[test]
+    fn salvaging_of_an_invalid_correlation_matrix() {
+        // Higham's example: not positive semi-definite
+        let a: Matrix = Matrix::from_rows(&[
+            vec![1.0, 1.0, 0.0],
+            vec![1.0, 1.0, 1.0],
+            vec![0.0, 1.0, 1.0],
+        ]);
+        assert!(panic::catch_unwind(|| pseudo_sqrt(&a, SalvagingAlgorithm::None)).is_err());
+
+        let expected: Matrix = Matrix::from_rows(&[
+            vec![1.0, 0.7607, 0.1573],
+            vec![0.7607, 1.0, 0.7607],
+            vec![0.1573, 0.7607, 1.0],
+        ]);
+        let nearest: Matrix = nearest_correlation_matrix(&a, 1.0e-12, 1000);
+        assert!(nearest.max_abs_difference(&expected) < 1.0e-4);
+
+        let higham: Matrix = product(&pseudo_sqrt(&a, SalvagingAlgorithm::Higham));
+        assert!(higham.max_abs_difference(&expected) < 1.0e-4);
+
+        // Unit diagonal, but further from the original matrix
+        let spectral: Matrix = product(&pseudo_sqrt(&a, SalvagingAlgorithm::Spectral));
+        for i in 0..3 {
+            assert!((spectral[i][i] - 1.0).abs() < 1.0e-14);
+        }
+        assert!((&spectral - &a).frobenius_norm() > (&higham - &a).frobenius_norm());
+        let smallest: Real = SymmetricSchurDecomposition::new(&spectral).eigenvalues()[2];
+        assert!(smallest > -1.0e-14);
+    }
+
+    #[test]
+    fn non_square_or_non_symmetric_matrices_panic() {
+        let cases: [(Matrix, &str); 2] = [
+            (
+                Matrix::from_rows(&[vec![1.0, 0.5, 0.0], vec![0.5, 1.0, 0.0]]),
+                "non square matrix",
+            ),
+            (
+                Matrix::from_rows(&[vec![1.0, 0.5], vec![0.4, 1.0]]),
+                "non symmetric matrix",
+            ),
+        ];
+        let algorithms: [SalvagingAlgorithm; 3] = [
+            SalvagingAlgorithm::None,
+            SalvagingAlgorithm::Spectral,
+            SalvagingAlgorithm::Higham,
+        ];
+        for (matrix, label) in cases.iter() {
+            for salvaging in algorithms {
+                let result = panic::catch_unwind(|| pseudo_sqrt(matrix, salvaging));
+                assert!(
+                    result.is_err(),
+                    "expected panic for {label} ({salvaging:?})"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use crate::math::array::Array;
+use crate::math::matrix::Matrix;
+use crate::types::{Real, Size};
+
+/*
+QR decomposition by Householder reflections (QuantLib qrDecomposition,
+without column pivoting): A = Q R for an m x n matrix, with Q the m x k
+matrix of orthonormal columns and R the k x n upper triangular one,
+k = min(m, n).
+*/
+pub fn qr_decomposition(matrix: &Matrix) -> (Matrix, Matrix) {
+    let (m, n): (Size, Size) = (matrix.rows(), matrix.columns());
+    let k: Size = m.min(n);
+    let mut r: Matrix = matrix.clone();
+    let mut q: Matrix = Matrix::identity(m);
+    for j in 0..k.min(m - 1) {
+        let mut v: Vec<Real> = (j..m).map(|i| r[i][j]).collect();
+        let norm: Real = v.iter().map(|x| x * x).sum::<Real>().sqrt();
+        if norm == 0.0 {
+            continue;
+        }
+        // Reflect onto -sign(x_0) |x| e_0, avoiding cancellations
+        v[0] += if v[0] < 0.0 { -norm } else { norm };
+        let v_norm2: Real = v.iter().map(|x| x * x).sum();
+        for c in 0..n {
+            let f: Real = 2.0 * (j..m).map(|i| v[i - j] * r[i][c]).sum::<Real>() / v_norm2;
+            for i in j..m {
+                r[i][c] -= f * v[i - j];
+            }
+        }
+        for row in 0..m {
+            let f: Real = 2.0 * (j..m).map(|i| q[row][i] * v[i - j]).sum::<Real>() / v_norm2;
+            for i in j..m {
+                q[row][i] -= f * v[i - j];
+            }
+        }
+    }
+    let mut thin_q: Matrix = Matrix::new(m, k, 0.0);
+    let mut thin_r: Matrix = Matrix::new(k, n, 0.0);
+    for i in 0..m {
+        thin_q[i].copy_from_slice(&q[i][..k]);
+    }
+    for i in 0..k {
+        for c in i..n {
+            thin_r[i][c] = r[i][c];
+        }
+    }
+    (thin_q, thin_r)
+}
+
+/*
+Least-squares solution of A x = b (QuantLib qrSolve) for an m x n matrix
+of full column rank, m >= n: x = R^-1 Q^T b.
+*/
+pub fn qr_solve(matrix: &Matrix, b: &Array) -> Array {
+    let (m, n): (Size, Size) = (matrix.rows(), matrix.columns());
+    if b.len() != m {
+        panic!(
+            "vector size ({}) differs from the number of rows ({})",
+            b.len(),
+            m
+        );
+    }
+    if m < n {
+        panic!("least squares need at least as many rows ({m}) as columns ({n})");
+    }
+    let (q, r): (Matrix, Matrix) = qr_decomposition(matrix);
+    let mut x: Array = &q.transpose() * b;
+    for i in (0..n).rev() {
+        if r[i][i] == 0.0 {
+            panic!("matrix is not of full column rank");
+        }
+        for c in i + 1..n {
+            x[i] -= r[i][c] * x[c];
+        }
+        x[i] /= r[i][i];
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn factors_are_orthonormal_and_triangular() {
+        let matrices: [Matrix; 3] = [
+            Matrix::from_rows(&[
+                vec![12.0, -51.0, 4.0],
+                vec![6.0, 167.0, -68.0],
+                vec![-4.0, 24.0, -41.0],
+            ]),
+            Matrix::from_rows(&[vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]),
+            Matrix::from_rows(&[vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]),
+        ];
+        for a in matrices.iter() {
+            let (q, r): (Matrix, Matrix) = qr_decomposition(a);
+            let k: Size = q.columns();
+            assert!((&q * &r).max_abs_difference(a) < 1.0e-12);
+            assert!((&q.transpose() * &q).max_abs_difference(&Matrix::identity(k)) < 1.0e-14);
+            for i in 0..r.rows() {
+                for j in 0..i {
+                    assert_eq!(r[i][j], 0.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn least_squares_fit_of_a_line() {
+        // y = 1 + 2 x exactly, then a fit through noisy points
+        let a: Matrix = Matrix::from_rows(&[
+            vec![1.0, 0.0],
+            vec![1.0, 1.0],
+            vec![1.0, 2.0],
+            vec![1.0, 3.0],
+        ]);
+        let x: Array = qr_solve(&a, &Array::from(vec![1.0, 3.0, 5.0, 7.0]));
+        assert!((x[0] - 1.0).abs() < 1.0e-14 && (x[1] - 2.0).abs() < 1.0e-14);
+
+        // Slope sxy / sxx = 8 / 5, intercept 3.5 - 1.6 * 1.5
+        let x: Array = qr_solve(&a, &Array::from(vec![1.0, 3.0, 4.0, 6.0]));
+        assert!((x[0] - 1.1).abs() < 1.0e-13 && (x[1] - 1.6).abs() < 1.0e-13);
+    }
+}
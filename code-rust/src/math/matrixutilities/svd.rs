@@ -0,0 +1,197 @@
+use crate::math::array::Array;
+use crate::math::matrix::Matrix;
+use crate::types::{Real, Size};
+
+const MAX_SWEEPS: Size = 100;
+
+/*
+Singular value decomposition A = U S V^T (QuantLib SVD) of an m x n
+matrix, computed with one-sided Jacobi rotations (Hestenes): the columns
+of A are rotated pairwise until orthogonal, their norms being the
+singular values.
+
+With k = min(m, n), U is m x k, V is n x k and the singular values are
+sorted in decreasing order. Columns of U belonging to null singular
+values are left at zero.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct Svd {
+    u: Matrix,
+    singular_values: Array,
+    v: Matrix,
+}
+impl Svd {
+    // Constructor
+    pub fn new(matrix: &Matrix) -> Svd {
+        if matrix.rows() < matrix.columns() {
+            // A^T = U S V^T, hence A = V S U^T
+            let svd: Svd = Svd::new(&matrix.transpose());
+            return Svd {
+                u: svd.v,
+                singular_values: svd.singular_values,
+                v: svd.u,
+            };
+        }
+        let (m, n): (Size, Size) = (matrix.rows(), matrix.columns());
+        let mut u: Matrix = matrix.clone();
+        let mut v: Matrix = Matrix::identity(n);
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated: bool = false;
+            for p in 0..n {
+                for q in p + 1..n {
+                    let (mut alpha, mut beta, mut gamma): (Real, Real, Real) = (0.0, 0.0, 0.0);
+                    for i in 0..m {
+                        alpha += u[i][p] * u[i][p];
+                        beta += u[i][q] * u[i][q];
+                        gamma += u[i][p] * u[i][q];
+                    }
+                    if gamma.abs() <= Real::EPSILON * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+                    let zeta: Real = (beta - alpha) / (2.0 * gamma);
+                    let t: Real = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                    let c: Real = 1.0 / (1.0 + t * t).sqrt();
+                    let s: Real = c * t;
+                    detail::rotate_columns(&mut u, p, q, c, s);
+                    detail::rotate_columns(&mut v, p, q, c, s);
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        let norms: Vec<Real> = (0..n).map(|j| u.column(j).norm2()).collect();
+        let mut order: Vec<Size> = (0..n).collect();
+        order.sort_by(|a, b| norms[*b].partial_cmp(&norms[*a]).unwrap());
+        let mut sorted_u: Matrix = Matrix::new(m, n, 0.0);
+        let mut sorted_v: Matrix = Matrix::new(n, n, 0.0);
+        for (j, k) in order.iter().enumerate() {
+            for i in 0..m {
+                if norms[*k] > 0.0 {
+                    sorted_u[i][j] = u[i][*k] / norms[*k];
+                }
+            }
+            for i in 0..n {
+                sorted_v[i][j] = v[i][*k];
+            }
+        }
+        Svd {
+            u: sorted_u,
+            singular_values: order.iter().map(|k| norms[*k]).collect(),
+            v: sorted_v,
+        }
+    }
+
+    // Inspectors
+    pub fn u(&self) -> &Matrix {
+        &self.u
+    }
+    pub fn v(&self) -> &Matrix {
+        &self.v
+    }
+    pub fn singular_values(&self) -> &Array {
+        &self.singular_values
+    }
+    pub fn s(&self) -> Matrix {
+        Matrix::from_diagonal(&self.singular_values)
+    }
+    pub fn norm2(&self) -> Real {
+        self.singular_values[0]
+    }
+    pub fn cond(&self) -> Real {
+        self.singular_values[0] / self.singular_values[self.singular_values.len() - 1]
+    }
+    pub fn rank(&self) -> Size {
+        let threshold: Real =
+            self.u.rows().max(self.v.rows()) as Real * self.singular_values[0] * Real::EPSILON;
+        self.singular_values
+            .iter()
+            .filter(|s| **s > threshold)
+            .count()
+    }
+
+    // Least-squares (minimum norm) solution of A x = b: V S^+ U^T b
+    pub fn solve_for(&self, b: &Array) -> Array {
+        let mut w: Array = &self.u.transpose() * b;
+        let rank: Size = self.rank();
+        for (i, s) in self.singular_values.iter().enumerate() {
+            w[i] = if i < rank { w[i] / s } else { 0.0 };
+        }
+        &self.v * &w
+    }
+}
+
+pub(crate) mod detail {
+    use crate::math::matrix::Matrix;
+    use crate::types::{Real, Size};
+
+    // Columns p and q of m multiplied by the rotation [[c, s], [-s, c]]
+    pub(crate) fn rotate_columns(m: &mut Matrix, p: Size, q: Size, c: Real, s: Real) {
+        for i in 0..m.rows() {
+            let (x, y): (Real, Real) = (m[i][p], m[i][q]);
+            m[i][p] = c * x - s * y;
+            m[i][q] = s * x + c * y;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decomposition_reproduces_the_matrix() {
+        let matrices: [Matrix; 3] = [
+            Matrix::from_rows(&[vec![3.0, 2.0, 2.0], vec![2.0, 3.0, -2.0]]),
+            Matrix::from_rows(&[
+                vec![1.0, 2.0, 3.0],
+                vec![4.0, 5.0, 6.0],
+                vec![7.0, 8.0, 10.0],
+                vec![-1.0, 0.5, 2.0],
+            ]),
+            // Rank one
+            Matrix::outer_product(&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]),
+        ];
+        for a in matrices.iter() {
+            let svd: Svd = Svd::new(a);
+            let rebuilt: Matrix = &(svd.u() * &svd.s()) * &svd.v().transpose();
+            assert!(rebuilt.max_abs_difference(a) < 1.0e-12);
+            let k: Size = svd.singular_values().len();
+            let vtv: Matrix = &svd.v().transpose() * svd.v();
+            assert!(vtv.max_abs_difference(&Matrix::identity(k)) < 1.0e-14);
+            let s: &Array = svd.singular_values();
+            assert!(s.windows(2).all(|w| w[0] >= w[1]));
+        }
+    }
+
+    #[test]
+    fn singular_values_of_known_matrices() {
+        // Singular values 5 and 3
+        let svd: Svd = Svd::new(&Matrix::from_rows(&[
+            vec![3.0, 2.0, 2.0],
+            vec![2.0, 3.0, -2.0],
+        ]));
+        assert!((svd.norm2() - 5.0).abs() < 1.0e-14);
+        assert!((svd.cond() - 5.0 / 3.0).abs() < 1.0e-14);
+        assert_eq!(svd.rank(), 2);
+
+        // |a| |b| for the outer product of a and b
+        let rank_one: Svd = Svd::new(&Matrix::outer_product(&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]));
+        assert!((rank_one.norm2() - (14.0_f64 * 77.0).sqrt()).abs() < 1.0e-12);
+        assert_eq!(rank_one.rank(), 1);
+    }
+
+    #[test]
+    fn solve_for_matches_least_squares() {
+        let a: Matrix = Matrix::from_rows(&[
+            vec![1.0, 0.0],
+            vec![1.0, 1.0],
+            vec![1.0, 2.0],
+            vec![1.0, 3.0],
+        ]);
+        let x: Array = Svd::new(&a).solve_for(&Array::from(vec![1.0, 3.0, 4.0, 6.0]));
+        assert!((x[0] - 1.1).abs() < 1.0e-13 && (x[1] - 1.6).abs() < 1.0e-13);
+    }
+}
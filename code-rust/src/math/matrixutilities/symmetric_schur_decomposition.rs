@@ -0,0 +1,130 @@
+use crate::math::array::Array;
+use crate::math::matrix::Matrix;
+use crate::math::matrixutilities::svd::detail::rotate_columns;
+use crate::types::{Real, Size};
+
+const MAX_SWEEPS: Size = 100;
+
+/*
+Eigenvalues and eigenvectors of a real symmetric matrix A = V D V^T
+(QuantLib SymmetricSchurDecomposition), by cyclic Jacobi rotations
+annihilating the off-diagonal elements.
+
+Eigenvalues are sorted in decreasing order and the eigenvectors, the
+columns of V, are normalized with a non-negative first component.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct SymmetricSchurDecomposition {
+    eigenvalues: Array,
+    eigenvectors: Matrix,
+}
+impl SymmetricSchurDecomposition {
+    // Constructor
+    pub fn new(matrix: &Matrix) -> SymmetricSchurDecomposition {
+        if !matrix.is_square() {
+            panic!(
+                "symmetric Schur decomposition requires a square matrix ({}x{} given)",
+                matrix.rows(),
+                matrix.columns()
+            );
+        }
+        let n: Size = matrix.rows();
+        let scale: Real = matrix.frobenius_norm();
+        for i in 0..n {
+            for j in 0..i {
+                if (matrix[i][j] - matrix[j][i]).abs() > 1.0e-12 * scale {
+                    panic!("matrix is not symmetric");
+                }
+            }
+        }
+
+        let mut a: Matrix = matrix.clone();
+        let mut v: Matrix = Matrix::identity(n);
+        for _ in 0..MAX_SWEEPS {
+            let off_diagonal: Real = (0..n)
+                .flat_map(|i| (0..n).filter(move |j| *j != i).map(move |j| (i, j)))
+                .map(|(i, j)| a[i][j] * a[i][j])
+                .sum::<Real>()
+                .sqrt();
+            if off_diagonal <= Real::EPSILON * scale {
+                break;
+            }
+            for p in 0..n {
+                for q in p + 1..n {
+                    if a[p][q] == 0.0 {
+                        continue;
+                    }
+                    let theta: Real = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                    let t: Real = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                    let c: Real = 1.0 / (t * t + 1.0).sqrt();
+                    let s: Real = c * t;
+                    // A P, then P^T (A P)
+                    rotate_columns(&mut a, p, q, c, s);
+                    for k in 0..n {
+                        let (x, y): (Real, Real) = (a[p][k], a[q][k]);
+                        a[p][k] = c * x - s * y;
+                        a[q][k] = s * x + c * y;
+                    }
+                    rotate_columns(&mut v, p, q, c, s);
+                }
+            }
+        }
+
+        let diagonal: Array = a.diagonal();
+        let mut order: Vec<Size> = (0..n).collect();
+        order.sort_by(|i, j| diagonal[*j].partial_cmp(&diagonal[*i]).unwrap());
+        let mut eigenvectors: Matrix = Matrix::new(n, n, 0.0);
+        for (j, k) in order.iter().enumerate() {
+            let sign: Real = if v[0][*k] < 0.0 { -1.0 } else { 1.0 };
+            for i in 0..n {
+                eigenvectors[i][j] = sign * v[i][*k];
+            }
+        }
+        SymmetricSchurDecomposition {
+            eigenvalues: order.iter().map(|k| diagonal[*k]).collect(),
+            eigenvectors,
+        }
+    }
+
+    // Inspectors
+    pub fn eigenvalues(&self) -> &Array {
+        &self.eigenvalues
+    }
+    pub fn eigenvectors(&self) -> &Matrix {
+        &self.eigenvectors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    #[test]
+    fn decomposition_reproduces_the_matrix() {
+        // Second differences: eigenvalues 2 - 2 cos(k pi / 4)
+        let a: Matrix = Matrix::from_rows(&[
+            vec![2.0, -1.0, 0.0],
+            vec![-1.0, 2.0, -1.0],
+            vec![0.0, -1.0, 2.0],
+        ]);
+        let schur: SymmetricSchurDecomposition = SymmetricSchurDecomposition::new(&a);
+        let sqrt2: Real = 2.0_f64.sqrt();
+        let expected: [Real; 3] = [2.0 + sqrt2, 2.0, 2.0 - sqrt2];
+        for (computed, expected) in schur.eigenvalues().iter().zip(expected.iter()) {
+            assert!((computed - expected).abs() < 1.0e-14);
+        }
+
+        let v: &Matrix = schur.eigenvectors();
+        let d: Matrix = Matrix::from_diagonal(schur.eigenvalues());
+        assert!((&(v * &d) * &v.transpose()).max_abs_difference(&a) < 1.0e-14);
+        assert!((&v.transpose() * v).max_abs_difference(&Matrix::identity(3)) < 1.0e-14);
+        assert!((0..3).all(|j| v[0][j] >= 0.0));
+    }
+
+    #[test]
+    fn non_symmetric_matrices_panic() {
+        let a: Matrix = Matrix::from_rows(&[vec![1.0, 2.0], vec![0.0, 1.0]]);
+        assert!(panic::catch_unwind(|| SymmetricSchurDecomposition::new(&a)).is_err());
+    }
+}
@@ -0,0 +1,197 @@
+use crate::math::distributions::gamma_distribution::GammaFunction;
+use crate::types::{Real, Size};
+use std::f64::consts::PI;
+
+const MAX_TERMS: Size = 100000;
+
+/*
+Modified Bessel functions of the first and second kind I_nu(x) and
+K_nu(x) of real order, for x >= 0 and x > 0 respectively (QuantLib
+modifiedBesselFunction_i and modifiedBesselFunction_k).
+
+- I_nu, nu >= 0, is the power series sum_k (x/2)^(2k + nu) / (k!
+  Gamma(k + nu + 1)), whose terms are all positive, rescaled on the fly
+  to avoid overflows; for x > max(100, nu^2) the asymptotic expansion
+  in 1 / x is used instead. Gamma(nu + 1) limits the relative accuracy
+  to about 2e-10 for non-integer orders.
+- K_nu is the integral of exp(-x cosh(t)) cosh(nu t) over t > 0, by the
+  trapezoid rule, which converges exponentially fast for such analytic
+  and doubly exponentially decaying integrands. It is accurate to
+  machine precision for all orders, integers included.
+- Negative orders: K_-nu = K_nu and I_-nu = I_nu + 2 / pi sin(nu pi) K_nu.
+
+The exponentially weighted versions exp(-x) I_nu(x) and exp(x) K_nu(x)
+stay finite where the functions themselves overflow or underflow.
+*/
+pub fn modified_bessel_function_i(nu: Real, x: Real) -> Real {
+    modified_bessel_function_i_exponentially_weighted(nu, x) * x.exp()
+}
+
+pub fn modified_bessel_function_k(nu: Real, x: Real) -> Real {
+    modified_bessel_function_k_exponentially_weighted(nu, x) * (-x).exp()
+}
+
+// exp(-x) I_nu(x)
+pub fn modified_bessel_function_i_exponentially_weighted(nu: Real, x: Real) -> Real {
+    if x < 0.0 {
+        panic!("negative argument ({x}) not allowed");
+    }
+    if nu >= 0.0 {
+        return detail::weighted_i(nu, x);
+    }
+    let weighted_i: Real = detail::weighted_i(-nu, x);
+    let sine: Real = (-nu * PI).sin();
+    if sine == 0.0 || x == 0.0 {
+        // Integer orders, or the null argument where K diverges
+        return if sine == 0.0 {
+            weighted_i
+        } else {
+            Real::INFINITY
+        };
+    }
+    weighted_i + 2.0 / PI * sine * (-2.0 * x).exp() * detail::weighted_k(-nu, x)
+}
+
+// exp(x) K_nu(x)
+pub fn modified_bessel_function_k_exponentially_weighted(nu: Real, x: Real) -> Real {
+    if x <= 0.0 {
+        panic!("non-positive argument ({x}) not allowed");
+    }
+    detail::weighted_k(nu.abs(), x)
+}
+
+pub(crate) mod detail {
+    use super::*;
+
+    // exp(-x) I_nu(x) for nu >= 0
+    pub(crate) fn weighted_i(nu: Real, x: Real) -> Real {
+        if x == 0.0 {
+            return if nu == 0.0 { 1.0 } else { 0.0 };
+        }
+        if x > (nu * nu).max(100.0) {
+            return asymptotic_weighted_i(nu, x);
+        }
+        // Terms relative to the first one (x/2)^nu / Gamma(nu + 1), times exp(-x)
+        let log_first: Real = nu * (0.5 * x).ln() - log_gamma_plus_one(nu) - x;
+        let y: Real = 0.25 * x * x;
+        let (mut term, mut sum, mut log_scale): (Real, Real, Real) = (1.0, 0.0, 0.0);
+        for k in 0..MAX_TERMS {
+            sum += term;
+            term *= y / ((k + 1) as Real * (k as Real + 1.0 + nu));
+            if term < Real::EPSILON * sum {
+                return (log_first + log_scale + sum.ln()).exp();
+            }
+            if sum > 1.0e300 {
+                sum *= 1.0e-300;
+                term *= 1.0e-300;
+                log_scale += 300.0 * 10.0_f64.ln();
+            }
+        }
+        panic!("modified Bessel series: too few terms ({MAX_TERMS})");
+    }
+
+    // 1 / sqrt(2 pi x) sum_k (-1)^k a_k(nu) / x^k, truncated at its smallest term
+    fn asymptotic_weighted_i(nu: Real, x: Real) -> Real {
+        let mu: Real = 4.0 * nu * nu;
+        let (mut term, mut sum): (Real, Real) = (1.0, 1.0);
+        for k in 1..MAX_TERMS {
+            let odd: Real = (2 * k - 1) as Real;
+            let next: Real = -term * (mu - odd * odd) / (k as Real * 8.0 * x);
+            if next.abs() >= term.abs() || next.abs() < Real::EPSILON * sum.abs() {
+                break;
+            }
+            term = next;
+            sum += term;
+        }
+        sum / (2.0 * PI * x).sqrt()
+    }
+
+    // exp(x) K_nu(x) for nu >= 0, x > 0
+    pub(crate) fn weighted_k(nu: Real, x: Real) -> Real {
+        // exp(-x (cosh(t) - 1)) cosh(nu t), written to avoid overflows
+        let integrand = |t: Real| -> Real {
+            let s: Real = (0.5 * t).sinh();
+            (-2.0 * x * s * s + nu * t).exp() * 0.5 * (1.0 + (-2.0 * nu * t).exp())
+        };
+        // Fine enough for the width of the peak, at sinh(t) = nu / x
+        let h: Real = 0.5 / x.max(nu).max(25.0).sqrt();
+        let peak: Real = (nu / x).asinh();
+        let mut sum: Real = 0.5 * integrand(0.0);
+        for k in 1..MAX_TERMS {
+            let t: Real = k as Real * h;
+            let term: Real = integrand(t);
+            sum += term;
+            if t > peak && term < 1.0e-18 * sum {
+                return h * sum;
+            }
+        }
+        panic!("modified Bessel integral: too many terms ({MAX_TERMS})");
+    }
+
+    // ln(Gamma(nu + 1)), exact for integer orders
+    fn log_gamma_plus_one(nu: Real) -> Real {
+        if nu.fract() == 0.0 && nu < 171.0 {
+            (2..=nu as Size).map(|k| (k as Real).ln()).sum()
+        } else {
+            GammaFunction.log_value(nu + 1.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_match_reference_tables() {
+        // Reference values from mpmath besseli and besselk
+        let cases: [(Real, Real, Real, Real); 10] = [
+            (0.0, 1.0, 1.2660658777520083, 0.42102443824070833),
+            (1.0, 1.0, 0.565159103992485, 0.6019072301972346),
+            (0.5, 2.0, 2.046236863089055, 0.11993777196806145),
+            (2.3, 0.1, 0.0003795495898842524, 572.0968669282897),
+            (1.7, 7.5, 218.1062049863421, 0.00029855365503380956),
+            (0.0, 150.0, 4.543597466270579e63, 7.336371406107646e-67),
+            (10.0, 3.0, 1.946439347061297e-5, 2459.6204220569468),
+            (0.3, 40.0, 14877812549676172.0, 8.40219326135314e-19),
+            (-0.4, 2.0, 2.188514141787165, 0.11772913317042333),
+            (3.0, 1.0e-3, 2.08333346354167e-11, 7999999000.000125),
+        ];
+        for (nu, x, expected_i, expected_k) in cases {
+            let i: Real = modified_bessel_function_i(nu, x);
+            let k: Real = modified_bessel_function_k(nu, x);
+            assert!(
+                (i / expected_i - 1.0).abs() < 1.0e-9,
+                "I_{nu}({x}) = {i}, expected {expected_i}"
+            );
+            assert!(
+                (k / expected_k - 1.0).abs() < 1.0e-13,
+                "K_{nu}({x}) = {k}, expected {expected_k}"
+            );
+        }
+    }
+
+    #[test]
+    fn half_integer_orders_and_weighting() {
+        // I_1/2(x) = sqrt(2 / (pi x)) sinh(x), K_1/2(x) = sqrt(pi / (2 x)) exp(-x)
+        for x in [0.01, 0.7, 5.0, 30.0, 500.0] {
+            let i: Real = modified_bessel_function_i_exponentially_weighted(0.5, x);
+            let expected_i: Real = (2.0 / (PI * x)).sqrt() * 0.5 * (1.0 - (-2.0 * x).exp());
+            assert!((i / expected_i - 1.0).abs() < 1.0e-9, "I at {x}");
+            let k: Real = modified_bessel_function_k_exponentially_weighted(0.5, x);
+            assert!(
+                (k / (PI / (2.0 * x)).sqrt() - 1.0).abs() < 1.0e-14,
+                "K at {x}"
+            );
+        }
+        // Weighted values are finite where I overflows
+        assert!(modified_bessel_function_i(1.0, 1000.0).is_infinite());
+        let weighted: Real = modified_bessel_function_i_exponentially_weighted(1.0, 1000.0);
+        assert!((weighted * (2.0 * PI * 1000.0).sqrt() - (1.0 - 3.0 / 8000.0)).abs() < 1.0e-6);
+        assert_eq!(modified_bessel_function_i(0.0, 0.0), 1.0);
+        assert_eq!(modified_bessel_function_i(2.0, 0.0), 0.0);
+
+        let result = std::panic::catch_unwind(|| modified_bessel_function_k(1.0, 0.0));
+        assert!(result.is_err(), "expected panic for K at zero");
+    }
+}
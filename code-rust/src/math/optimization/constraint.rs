@@ -0,0 +1,62 @@
+use crate::types::Real;
+
+/*
+Constraint on a single optimization parameter (QuantLib NoConstraint,
+PositiveConstraint and BoundaryConstraint). A model gives one per
+parameter; trial points violating any of them are rejected by the
+optimizer.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Constraint {
+    NoConstraint,
+    Positive,
+    Boundary { low: Real, high: Real },
+}
+impl Constraint {
+    pub fn test(&self, x: Real) -> bool {
+        match self {
+            Constraint::NoConstraint => true,
+            Constraint::Positive => x > 0.0,
+            Constraint::Boundary { low, high } => (*low..=*high).contains(&x),
+        }
+    }
+}
+
+// Whether every parameter satisfies its constraint
+pub fn test_all(constraints: &[Constraint], params: &[Real]) -> bool {
+    if constraints.len() != params.len() {
+        panic!(
+            "{} constraints given for {} parameters",
+            constraints.len(),
+            params.len()
+        );
+    }
+    constraints.iter().zip(params).all(|(c, x)| c.test(*x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constraints_accept_and_reject_values() {
+        let boundary: Constraint = Constraint::Boundary {
+            low: -1.0,
+            high: 1.0,
+        };
+        let cases: [(Constraint, Real, bool); 7] = [
+            (Constraint::NoConstraint, -1.0e10, true),
+            (Constraint::Positive, 1.0e-10, true),
+            (Constraint::Positive, 0.0, false),
+            (boundary, -1.0, true),
+            (boundary, 0.5, true),
+            (boundary, 1.0 + 1.0e-12, false),
+            (boundary, -2.0, false),
+        ];
+        for (constraint, x, expected) in cases {
+            assert_eq!(constraint.test(x), expected, "{constraint:?} at {x}");
+        }
+        assert!(test_all(&[Constraint::Positive, boundary], &[0.1, -0.5]));
+        assert!(!test_all(&[Constraint::Positive, boundary], &[0.1, 1.5]));
+    }
+}
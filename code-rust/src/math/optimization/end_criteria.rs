@@ -0,0 +1,60 @@
+use crate::types::{Real, Size};
+
+// Reason why an optimization stopped (QuantLib EndCriteria::Type)
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum EndCriteriaType {
+    MaxIterations,
+    StationaryPoint,
+    StationaryFunctionValue,
+    ZeroGradientNorm,
+}
+
+/*
+Stopping criteria of an optimization (QuantLib EndCriteria):
+    - max_iterations: hard limit on the number of iterations
+    - root_epsilon: stop when a step moves the parameters by less than
+      root_epsilon (relative to their size)
+    - function_epsilon: stop when the cost function decreases by less
+      than function_epsilon (relative to its value), or falls below it
+    - gradient_norm_epsilon: stop when the gradient vanishes
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct EndCriteria {
+    max_iterations: Size,
+    root_epsilon: Real,
+    function_epsilon: Real,
+    gradient_norm_epsilon: Real,
+}
+impl EndCriteria {
+    // Constructor
+    pub fn new(
+        max_iterations: Size,
+        root_epsilon: Real,
+        function_epsilon: Real,
+        gradient_norm_epsilon: Real,
+    ) -> EndCriteria {
+        if max_iterations == 0 {
+            panic!("at least one iteration is required");
+        }
+        EndCriteria {
+            max_iterations,
+            root_epsilon,
+            function_epsilon,
+            gradient_norm_epsilon,
+        }
+    }
+
+    // Inspectors
+    pub fn max_iterations(&self) -> Size {
+        self.max_iterations
+    }
+    pub fn root_epsilon(&self) -> Real {
+        self.root_epsilon
+    }
+    pub fn function_epsilon(&self) -> Real {
+        self.function_epsilon
+    }
+    pub fn gradient_norm_epsilon(&self) -> Real {
+        self.gradient_norm_epsilon
+    }
+}
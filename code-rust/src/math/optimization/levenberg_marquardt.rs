@@ -0,0 +1,304 @@
+use crate::math::optimization::constraint::{Constraint, test_all};
+use crate::math::optimization::end_criteria::{EndCriteria, EndCriteriaType};
+use crate::types::{Real, Size};
+
+// Outcome of a minimization
+#[derive(Clone, PartialEq, Debug)]
+pub struct OptimizationResult {
+    pub parameters: Vec<Real>,
+    // Sum of the squared residuals at the parameters found
+    pub value: Real,
+    pub iterations: Size,
+    pub end_criteria: EndCriteriaType,
+}
+
+/*
+Levenberg-Marquardt least-squares minimization (QuantLib
+LevenbergMarquardt).
+
+Minimizes the sum of squares of the residuals r(x). Each iteration
+solves the damped normal equations
+    (J^T J + lambda diag(J^T J)) dx = -J^T r
+with the Jacobian J estimated by forward differences of relative size
+epsfcn. A step is accepted if it satisfies the constraints and lowers
+the cost, in which case lambda is divided by 10; otherwise lambda is
+multiplied by 10 and the step recomputed, moving towards a short
+gradient-descent step.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct LevenbergMarquardt {
+    epsfcn: Real,
+}
+impl LevenbergMarquardt {
+    const INITIAL_LAMBDA: Real = 1.0e-3;
+    const MAX_LAMBDA: Real = 1.0e16;
+
+    // Constructor
+    pub fn new(epsfcn: Real) -> LevenbergMarquardt {
+        if epsfcn <= 0.0 {
+            panic!("epsfcn ({epsfcn}) must be positive");
+        }
+        LevenbergMarquardt { epsfcn }
+    }
+
+    pub fn minimize<F>(
+        &self,
+        residuals: F,
+        constraints: &[Constraint],
+        initial: &[Real],
+        end_criteria: &EndCriteria,
+    ) -> OptimizationResult
+    where
+        F: Fn(&[Real]) -> Vec<Real>,
+    {
+        if initial.is_empty() {
+            panic!("no parameters to optimize");
+        }
+        if !test_all(constraints, initial) {
+            panic!("initial guess {initial:?} violates the constraints");
+        }
+        let mut x: Vec<Real> = initial.to_vec();
+        let mut r: Vec<Real> = residuals(&x);
+        let mut cost: Real = detail::sum_of_squares(&r);
+        let mut lambda: Real = LevenbergMarquardt::INITIAL_LAMBDA;
+        let n: Size = x.len();
+
+        for iteration in 1..=end_criteria.max_iterations() {
+            let jacobian: Vec<Vec<Real>> = self.jacobian(&residuals, constraints, &x, &r);
+            // Normal equations J^T J and gradient J^T r
+            let mut jtj: Vec<Vec<Real>> = vec![vec![0.0; n]; n];
+            let mut gradient: Vec<Real> = vec![0.0; n];
+            for (k, row) in jacobian.iter().enumerate() {
+                for i in 0..n {
+                    gradient[i] += row[i] * r[k];
+                    for j in 0..n {
+                        jtj[i][j] += row[i] * row[j];
+                    }
+                }
+            }
+            if gradient
+                .iter()
+                .all(|g| g.abs() <= end_criteria.gradient_norm_epsilon())
+            {
+                return detail::result(x, cost, iteration, EndCriteriaType::ZeroGradientNorm);
+            }
+
+            let (step, new_r, new_cost): (Vec<Real>, Vec<Real>, Real) = loop {
+                let mut damped: Vec<Vec<Real>> = jtj.clone();
+                for (i, row) in damped.iter_mut().enumerate() {
+                    row[i] += lambda * jtj[i][i].max(Real::EPSILON);
+                }
+                let minus_gradient: Vec<Real> = gradient.iter().map(|g| -g).collect();
+                let step: Vec<Real> = detail::solve(damped, minus_gradient);
+                let trial: Vec<Real> = x.iter().zip(&step).map(|(a, b)| a + b).collect();
+                if test_all(constraints, &trial) {
+                    let trial_r: Vec<Real> = residuals(&trial);
+                    let trial_cost: Real = detail::sum_of_squares(&trial_r);
+                    if trial_cost < cost {
+                        lambda = (lambda / 10.0).max(Real::EPSILON);
+                        break (step, trial_r, trial_cost);
+                    }
+                }
+                lambda *= 10.0;
+                if lambda > LevenbergMarquardt::MAX_LAMBDA {
+                    // No step along the gradient lowers the cost any more
+                    return detail::result(x, cost, iteration, EndCriteriaType::StationaryPoint);
+                }
+            };
+
+            let improvement: Real = cost - new_cost;
+            for (xi, dx) in x.iter_mut().zip(&step) {
+                *xi += dx;
+            }
+            r = new_r;
+            cost = new_cost;
+
+            let step_norm: Real = detail::norm(&step);
+            if step_norm
+                <= end_criteria.root_epsilon() * (detail::norm(&x) + end_criteria.root_epsilon())
+            {
+                return detail::result(x, cost, iteration, EndCriteriaType::StationaryPoint);
+            }
+            if cost <= end_criteria.function_epsilon()
+                || improvement <= end_criteria.function_epsilon() * cost
+            {
+                return detail::result(
+                    x,
+                    cost,
+                    iteration,
+                    EndCriteriaType::StationaryFunctionValue,
+                );
+            }
+        }
+        detail::result(
+            x,
+            cost,
+            end_criteria.max_iterations(),
+            EndCriteriaType::MaxIterations,
+        )
+    }
+
+    // Forward differences, stepping backwards when forwards leaves the constraints
+    fn jacobian<F>(
+        &self,
+        residuals: &F,
+        constraints: &[Constraint],
+        x: &[Real],
+        r: &[Real],
+    ) -> Vec<Vec<Real>>
+    where
+        F: Fn(&[Real]) -> Vec<Real>,
+    {
+        let mut jacobian: Vec<Vec<Real>> = vec![vec![0.0; x.len()]; r.len()];
+        for j in 0..x.len() {
+            let mut h: Real = self.epsfcn.sqrt() * x[j].abs().max(1.0);
+            let mut shifted: Vec<Real> = x.to_vec();
+            shifted[j] = x[j] + h;
+            if !test_all(constraints, &shifted) {
+                h = -h;
+                shifted[j] = x[j] + h;
+            }
+            let shifted_r: Vec<Real> = residuals(&shifted);
+            for (k, row) in jacobian.iter_mut().enumerate() {
+                row[j] = (shifted_r[k] - r[k]) / h;
+            }
+        }
+        jacobian
+    }
+}
+impl Default for LevenbergMarquardt {
+    fn default() -> Self {
+        LevenbergMarquardt::new(1.0e-8)
+    }
+}
+
+mod detail {
+    use super::OptimizationResult;
+    use crate::math::optimization::end_criteria::EndCriteriaType;
+    use crate::types::{Real, Size};
+
+    pub(super) fn sum_of_squares(r: &[Real]) -> Real {
+        r.iter().map(|x| x * x).sum()
+    }
+
+    pub(super) fn norm(x: &[Real]) -> Real {
+        sum_of_squares(x).sqrt()
+    }
+
+    pub(super) fn result(
+        parameters: Vec<Real>,
+        value: Real,
+        iterations: Size,
+        end_criteria: EndCriteriaType,
+    ) -> OptimizationResult {
+        OptimizationResult {
+            parameters,
+            value,
+            iterations,
+            end_criteria,
+        }
+    }
+
+    // Gaussian elimination with partial pivoting
+    pub(super) fn solve(mut a: Vec<Vec<Real>>, mut b: Vec<Real>) -> Vec<Real> {
+        let n: Size = b.len();
+        for col in 0..n {
+            let pivot: Size = (col..n)
+                .max_by(|i, j| a[*i][col].abs().partial_cmp(&a[*j][col].abs()).unwrap())
+                .unwrap();
+            if a[pivot][col] == 0.0 {
+                panic!("singular normal equations");
+            }
+            a.swap(col, pivot);
+            b.swap(col, pivot);
+            for row in col + 1..n {
+                let factor: Real = a[row][col] / a[col][col];
+                for k in col..n {
+                    a[row][k] -= factor * a[col][k];
+                }
+                b[row] -= factor * b[col];
+            }
+        }
+        let mut x: Vec<Real> = vec![0.0; n];
+        for row in (0..n).rev() {
+            let tail: Real = (row + 1..n).map(|k| a[row][k] * x[k]).sum();
+            x[row] = (b[row] - tail) / a[row][row];
+        }
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn end_criteria() -> EndCriteria {
+        EndCriteria::new(1000, 1.0e-12, 1.0e-16, 1.0e-16)
+    }
+
+    #[test]
+    fn minimizes_the_rosenbrock_function() {
+        let result: OptimizationResult = LevenbergMarquardt::default().minimize(
+            |x: &[Real]| vec![10.0 * (x[1] - x[0] * x[0]), 1.0 - x[0]],
+            &[Constraint::NoConstraint, Constraint::NoConstraint],
+            &[-1.2, 1.0],
+            &end_criteria(),
+        );
+        assert!((result.parameters[0] - 1.0).abs() < 1.0e-6, "{result:?}");
+        assert!((result.parameters[1] - 1.0).abs() < 1.0e-6, "{result:?}");
+        assert!(result.value < 1.0e-12);
+        assert_ne!(result.end_criteria, EndCriteriaType::MaxIterations);
+    }
+
+    #[test]
+    fn fits_an_exponential_decay() {
+        // y = 2 exp(-0.5 t), sampled without noise
+        let times: [Real; 6] = [0.0, 0.5, 1.0, 2.0, 3.0, 5.0];
+        let values: Vec<Real> = times.iter().map(|t| 2.0 * (-0.5 * t).exp()).collect();
+        let result: OptimizationResult = LevenbergMarquardt::default().minimize(
+            |x: &[Real]| {
+                times
+                    .iter()
+                    .zip(&values)
+                    .map(|(t, y)| x[0] * (-x[1] * t).exp() - y)
+                    .collect()
+            },
+            &[Constraint::Positive, Constraint::Positive],
+            &[1.0, 1.0],
+            &end_criteria(),
+        );
+        assert!((result.parameters[0] - 2.0).abs() < 1.0e-8);
+        assert!((result.parameters[1] - 0.5).abs() < 1.0e-8);
+    }
+
+    #[test]
+    fn constraints_are_respected() {
+        // Unconstrained minimum at x = -1, the boundary stops it at 0
+        let result: OptimizationResult = LevenbergMarquardt::default().minimize(
+            |x: &[Real]| vec![x[0] + 1.0],
+            &[Constraint::Boundary {
+                low: 0.0,
+                high: 10.0,
+            }],
+            &[5.0],
+            &end_criteria(),
+        );
+        assert!(result.parameters[0] >= 0.0);
+        assert!(result.parameters[0] < 1.0e-3, "{result:?}");
+    }
+
+    #[test]
+    fn minimize_panics_on_invalid_input() {
+        let result = std::panic::catch_unwind(|| {
+            LevenbergMarquardt::default().minimize(
+                |x: &[Real]| vec![x[0]],
+                &[Constraint::Positive],
+                &[-1.0],
+                &end_criteria(),
+            )
+        });
+        assert!(result.is_err(), "expected panic for infeasible guess");
+        let result = std::panic::catch_unwind(|| LevenbergMarquardt::new(0.0));
+        assert!(result.is_err(), "expected panic for null epsfcn");
+    }
+}
@@ -0,0 +1,105 @@
+use crate::math::randomnumbers::rng_traits::RandomNumberGenerator;
+use crate::methods::montecarlo::sample::Sample;
+use crate::types::Real;
+
+/*
+Gaussian random number generator (QuantLib BoxMullerGaussianRng).
+
+Polar form of the Box-Muller transform: draw (x1, x2) uniformly in the
+square [-1, 1]^2 until r = x1^2 + x2^2 falls inside the unit circle, then
+    x1 * sqrt(-2 ln(r) / r),  x2 * sqrt(-2 ln(r) / r)
+are two independent standard Gaussians, returned one at a time.
+Since a variable number of uniforms is consumed per draw, it must not be
+used on top of low-discrepancy sequences.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct BoxMullerGaussianRng<R: RandomNumberGenerator> {
+    uniform_generator: R,
+    return_first: bool,
+    first_value: Real,
+    second_value: Real,
+    first_weight: Real,
+    second_weight: Real,
+}
+impl<R: RandomNumberGenerator> BoxMullerGaussianRng<R> {
+    // Constructor
+    pub fn new(uniform_generator: R) -> BoxMullerGaussianRng<R> {
+        BoxMullerGaussianRng {
+            uniform_generator,
+            return_first: true,
+            first_value: 0.0,
+            second_value: 0.0,
+            first_weight: 0.0,
+            second_weight: 0.0,
+        }
+    }
+}
+impl<R: RandomNumberGenerator> RandomNumberGenerator for BoxMullerGaussianRng<R> {
+    fn next(&mut self) -> Sample<Real> {
+        if !self.return_first {
+            self.return_first = true;
+            return Sample::new(self.second_value, self.second_weight);
+        }
+
+        let (x1, x2, r, weight1, weight2): (Real, Real, Real, Real, Real) = loop {
+            let first: Sample<Real> = self.uniform_generator.next();
+            let second: Sample<Real> = self.uniform_generator.next();
+            let x1: Real = first.value * 2.0 - 1.0;
+            let x2: Real = second.value * 2.0 - 1.0;
+            let r: Real = x1 * x1 + x2 * x2;
+            if r < 1.0 && r != 0.0 {
+                break (x1, x2, r, first.weight, second.weight);
+            }
+        };
+        let ratio: Real = (-2.0 * r.ln() / r).sqrt();
+        self.first_value = x1 * ratio;
+        self.second_value = x2 * ratio;
+        self.first_weight = weight1 * weight2;
+        self.second_weight = self.first_weight;
+        self.return_first = false;
+        Sample::new(self.first_value, self.first_weight)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::randomnumbers::mt19937_uniform_rng::MersenneTwisterUniformRng;
+
+    #[test]
+    fn draws_have_standard_gaussian_moments() {
+        let mut rng: BoxMullerGaussianRng<MersenneTwisterUniformRng> =
+            BoxMullerGaussianRng::new(MersenneTwisterUniformRng::new(42));
+        let n: usize = 200_000;
+        let (mut sum, mut sum_squares, mut sum_fourth): (Real, Real, Real) = (0.0, 0.0, 0.0);
+        for _ in 0..n {
+            let x: Real = rng.next().value;
+            sum += x;
+            sum_squares += x * x;
+            sum_fourth += x * x * x * x;
+        }
+        let n: Real = n as Real;
+        assert!((sum / n).abs() < 0.01, "mean {}", sum / n);
+        assert!(
+            (sum_squares / n - 1.0).abs() < 0.01,
+            "variance {}",
+            sum_squares / n
+        );
+        assert!(
+            (sum_fourth / n - 3.0).abs() < 0.05,
+            "fourth moment {}",
+            sum_fourth / n
+        );
+    }
+
+    #[test]
+    fn same_seed_same_stream() {
+        let mut rng1: BoxMullerGaussianRng<MersenneTwisterUniformRng> =
+            BoxMullerGaussianRng::new(MersenneTwisterUniformRng::new(7));
+        let mut rng2: BoxMullerGaussianRng<MersenneTwisterUniformRng> =
+            BoxMullerGaussianRng::new(MersenneTwisterUniformRng::new(7));
+        for _ in 0..101 {
+            assert_eq!(rng1.next(), rng2.next());
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use crate::math::randomnumbers::rng_traits::SequenceGenerator;
+use crate::methods::montecarlo::sample::Sample;
+use crate::types::{Real, Size};
+
+/*
+Halton low-discrepancy sequence generator (QuantLib HaltonRsg, without
+the random start and shift options).
+
+Dimension k of the n-th point is the radical inverse of n in the k-th
+prime base p: the digits of n in base p mirrored around the decimal point,
+    n = sum_i d_i p^i  ->  x = sum_i d_i p^{-(i+1)}
+The counter starts at 1, so the first point is (1/2, 1/3, 1/5, ...).
+Quality degrades in high dimensions, where Sobol is to be preferred.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct HaltonRsg {
+    dimensionality: Size,
+    sequence_counter: u64,
+    sequence: Sample<Vec<Real>>,
+    bases: Vec<u64>,
+}
+impl HaltonRsg {
+    // Constructor
+    pub fn new(dimensionality: Size) -> HaltonRsg {
+        if dimensionality == 0 {
+            panic!("dimensionality must be greater than 0");
+        }
+        HaltonRsg {
+            dimensionality,
+            sequence_counter: 0,
+            sequence: Sample::new(vec![0.0; dimensionality], 1.0),
+            bases: detail::primes(dimensionality),
+        }
+    }
+}
+impl SequenceGenerator for HaltonRsg {
+    fn dimension(&self) -> Size {
+        self.dimensionality
+    }
+    fn next_sequence(&mut self) -> &Sample<Vec<Real>> {
+        self.sequence_counter += 1;
+        for (x, base) in self.sequence.value.iter_mut().zip(self.bases.iter()) {
+            *x = detail::radical_inverse(self.sequence_counter, *base);
+        }
+        &self.sequence
+    }
+    fn last_sequence(&self) -> &Sample<Vec<Real>> {
+        &self.sequence
+    }
+}
+
+// Private
+mod detail {
+    use crate::types::{Real, Size};
+
+    // First n prime numbers
+    pub(crate) fn primes(n: Size) -> Vec<u64> {
+        let mut primes: Vec<u64> = Vec::with_capacity(n);
+        let mut candidate: u64 = 2;
+        while primes.len() < n {
+            if primes
+                .iter()
+                .take_while(|p| **p * **p <= candidate)
+                .all(|p| candidate % p != 0)
+            {
+                primes.push(candidate);
+            }
+            candidate += 1;
+        }
+        primes
+    }
+
+    pub(crate) fn radical_inverse(mut n: u64, base: u64) -> Real {
+        let inverse_base: Real = 1.0 / base as Real;
+        let mut factor: Real = inverse_base;
+        let mut x: Real = 0.0;
+        while n > 0 {
+            x += (n % base) as Real * factor;
+            factor *= inverse_base;
+            n /= base;
+        }
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_points_are_radical_inverses() {
+        let expected: [[Real; 3]; 4] = [
+            [1.0 / 2.0, 1.0 / 3.0, 1.0 / 5.0],
+            [1.0 / 4.0, 2.0 / 3.0, 2.0 / 5.0],
+            [3.0 / 4.0, 1.0 / 9.0, 3.0 / 5.0],
+            [1.0 / 8.0, 4.0 / 9.0, 4.0 / 5.0],
+        ];
+        let mut rsg: HaltonRsg = HaltonRsg::new(3);
+        assert_eq!(rsg.dimension(), 3);
+        for point in expected {
+            let sample: &Sample<Vec<Real>> = rsg.next_sequence();
+            for (x, e) in sample.value.iter().zip(point.iter()) {
+                assert!((x - e).abs() < 1.0e-15, "{x} vs {e}");
+            }
+        }
+        assert!((rsg.last_sequence().value[2] - 0.8).abs() < 1.0e-15);
+    }
+
+    #[test]
+    fn bases_are_the_first_primes() {
+        assert_eq!(detail::primes(10), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    }
+
+    #[test]
+    fn integrates_smooth_functions() {
+        // Mean of x * y * z over the unit cube is 1/8
+        let mut rsg: HaltonRsg = HaltonRsg::new(3);
+        let n: usize = 10_000;
+        let mut sum: Real = 0.0;
+        for _ in 0..n {
+            let point: &Vec<Real> = &rsg.next_sequence().value;
+            sum += point[0] * point[1] * point[2];
+        }
+        assert!((sum / n as Real - 0.125).abs() < 1.0e-3);
+    }
+}
@@ -0,0 +1,49 @@
+use crate::math::distributions::normal_distribution::InverseCumulativeNormal;
+use crate::math::randomnumbers::rng_traits::RandomNumberGenerator;
+use crate::methods::montecarlo::sample::Sample;
+use crate::types::Real;
+
+/*
+Gaussian random number generator (QuantLib InverseCumulativeRng).
+
+Maps each uniform draw u to N^{-1}(u). Exactly one uniform is consumed
+per Gaussian, which keeps the correspondence between draws and
+dimensions (unlike Box-Muller).
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct InverseCumulativeRng<R: RandomNumberGenerator> {
+    uniform_generator: R,
+    inverse_cumulative: InverseCumulativeNormal,
+}
+impl<R: RandomNumberGenerator> InverseCumulativeRng<R> {
+    // Constructor
+    pub fn new(uniform_generator: R) -> InverseCumulativeRng<R> {
+        InverseCumulativeRng {
+            uniform_generator,
+            inverse_cumulative: InverseCumulativeNormal::default(),
+        }
+    }
+}
+impl<R: RandomNumberGenerator> RandomNumberGenerator for InverseCumulativeRng<R> {
+    fn next(&mut self) -> Sample<Real> {
+        let sample: Sample<Real> = self.uniform_generator.next();
+        Sample::new(self.inverse_cumulative.value(sample.value), sample.weight)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::randomnumbers::mt19937_uniform_rng::MersenneTwisterUniformRng;
+
+    #[test]
+    fn draws_are_inverse_normal_of_uniforms() {
+        let mut rng: InverseCumulativeRng<MersenneTwisterUniformRng> =
+            InverseCumulativeRng::new(MersenneTwisterUniformRng::new(42));
+        let mut uniform: MersenneTwisterUniformRng = MersenneTwisterUniformRng::new(42);
+        let inverse: InverseCumulativeNormal = InverseCumulativeNormal::default();
+        for _ in 0..100 {
+            assert_eq!(rng.next().value, inverse.value(uniform.next_real()));
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::math::distributions::normal_distribution::InverseCumulativeNormal;
+use crate::math::randomnumbers::rng_traits::SequenceGenerator;
+use crate::methods::montecarlo::sample::Sample;
+use crate::types::{Real, Size};
+
+/*
+Gaussian sequence generator (QuantLib InverseCumulativeRsg).
+
+Maps every coordinate of a uniform sequence through N^{-1}. This is the
+way to get Gaussian low-discrepancy sequences: the stratification of
+the uniform points carries over to the Gaussian ones.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct InverseCumulativeRsg<S: SequenceGenerator> {
+    uniform_sequence_generator: S,
+    inverse_cumulative: InverseCumulativeNormal,
+    sequence: Sample<Vec<Real>>,
+}
+impl<S: SequenceGenerator> InverseCumulativeRsg<S> {
+    // Constructor
+    pub fn new(uniform_sequence_generator: S) -> InverseCumulativeRsg<S> {
+        let dimension: Size = uniform_sequence_generator.dimension();
+        InverseCumulativeRsg {
+            uniform_sequence_generator,
+            inverse_cumulative: InverseCumulativeNormal::default(),
+            sequence: Sample::new(vec![0.0; dimension], 1.0),
+        }
+    }
+}
+impl<S: SequenceGenerator> SequenceGenerator for InverseCumulativeRsg<S> {
+    fn dimension(&self) -> Size {
+        self.sequence.value.len()
+    }
+    fn next_sequence(&mut self) -> &Sample<Vec<Real>> {
+        let uniforms: &Sample<Vec<Real>> = self.uniform_sequence_generator.next_sequence();
+        for (x, u) in self.sequence.value.iter_mut().zip(uniforms.value.iter()) {
+            *x = self.inverse_cumulative.value(*u);
+        }
+        self.sequence.weight = uniforms.weight;
+        &self.sequence
+    }
+    fn last_sequence(&self) -> &Sample<Vec<Real>> {
+        &self.sequence
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::randomnumbers::sobol_rsg::SobolRsg;
+
+    #[test]
+    fn maps_sobol_points_to_gaussians() {
+        let mut rsg: InverseCumulativeRsg<SobolRsg> = InverseCumulativeRsg::new(SobolRsg::new(2));
+        let inverse: InverseCumulativeNormal = InverseCumulativeNormal::default();
+
+        assert_eq!(rsg.dimension(), 2);
+        // Sobol starts at (0.5, 0.5), i.e. the Gaussian origin
+        assert!(rsg.next_sequence().value.iter().all(|x| x.abs() < 1.0e-15));
+        assert_eq!(
+            rsg.next_sequence().value,
+            vec![inverse.value(0.75), inverse.value(0.25)]
+        );
+        assert_eq!(rsg.last_sequence().weight, 1.0);
+    }
+}
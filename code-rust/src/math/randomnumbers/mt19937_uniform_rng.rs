@@ -0,0 +1,132 @@
+use crate::math::randomnumbers::rng_traits::RandomNumberGenerator;
+use crate::methods::montecarlo::sample::Sample;
+use crate::types::Real;
+
+/*
+Uniform random number generator (QuantLib MersenneTwisterUniformRng).
+
+Mersenne Twister MT19937 by M. Matsumoto and T. Nishimura: period
+2^19937 - 1, 623-dimensional equidistribution. The state is seeded with
+the reference init_genrand routine, so a given seed always produces the
+same stream (and the same stream as any other MT19937 implementation).
+
+next_real maps the 32-bit integers to (0, 1), never returning 0 or 1.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct MersenneTwisterUniformRng {
+    mt: Vec<u32>,
+    mti: usize,
+}
+impl MersenneTwisterUniformRng {
+    const N: usize = 624;
+    const M: usize = 397;
+    const MATRIX_A: u32 = 0x9908_b0df;
+    const UPPER_MASK: u32 = 0x8000_0000;
+    const LOWER_MASK: u32 = 0x7fff_ffff;
+
+    // Constructor
+    pub fn new(seed: u64) -> MersenneTwisterUniformRng {
+        let n: usize = MersenneTwisterUniformRng::N;
+        let mut mt: Vec<u32> = vec![0; n];
+        mt[0] = seed as u32;
+        for i in 1..n {
+            mt[i] = 1_812_433_253u32
+                .wrapping_mul(mt[i - 1] ^ (mt[i - 1] >> 30))
+                .wrapping_add(i as u32);
+        }
+        MersenneTwisterUniformRng { mt, mti: n }
+    }
+
+    // Uniform integer in [0, 2^32 - 1]
+    pub fn next_int32(&mut self) -> u32 {
+        if self.mti >= MersenneTwisterUniformRng::N {
+            self.twist();
+        }
+        let mut y: u32 = self.mt[self.mti];
+        self.mti += 1;
+
+        // Tempering
+        y ^= y >> 11;
+        y ^= (y << 7) & 0x9d2c_5680;
+        y ^= (y << 15) & 0xefc6_0000;
+        y ^ (y >> 18)
+    }
+    // Uniform real in (0, 1)
+    pub fn next_real(&mut self) -> Real {
+        (self.next_int32() as Real + 0.5) / 4_294_967_296.0
+    }
+
+    // Generates the next N words of the state at once
+    fn twist(&mut self) {
+        let (n, m): (usize, usize) = (MersenneTwisterUniformRng::N, MersenneTwisterUniformRng::M);
+        for k in 0..n {
+            let y: u32 = (self.mt[k] & MersenneTwisterUniformRng::UPPER_MASK)
+                | (self.mt[(k + 1) % n] & MersenneTwisterUniformRng::LOWER_MASK);
+            let mag: u32 = if y & 1 == 0 {
+                0
+            } else {
+                MersenneTwisterUniformRng::MATRIX_A
+            };
+            self.mt[k] = self.mt[(k + m) % n] ^ (y >> 1) ^ mag;
+        }
+        self.mti = 0;
+    }
+}
+impl RandomNumberGenerator for MersenneTwisterUniformRng {
+    fn next(&mut self) -> Sample<Real> {
+        Sample::new(self.next_real(), 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_reference_mt19937_stream() {
+        // Reference outputs of MT19937 seeded with 5489 (the C++ std::mt19937
+        // default), including the 10000th draw required by the standard
+        let mut rng: MersenneTwisterUniformRng = MersenneTwisterUniformRng::new(5489);
+        assert_eq!(rng.next_int32(), 3_499_211_612);
+        assert_eq!(rng.next_int32(), 581_869_302);
+        assert_eq!(rng.next_int32(), 3_890_346_734);
+        for _ in 3..9_999 {
+            rng.next_int32();
+        }
+        assert_eq!(rng.next_int32(), 4_123_659_995);
+    }
+
+    #[test]
+    fn same_seed_same_stream() {
+        let mut rng1: MersenneTwisterUniformRng = MersenneTwisterUniformRng::new(42);
+        let mut rng2: MersenneTwisterUniformRng = MersenneTwisterUniformRng::new(42);
+        let mut rng3: MersenneTwisterUniformRng = MersenneTwisterUniformRng::new(43);
+        let mut differs: bool = false;
+        for _ in 0..1_000 {
+            let x: Real = rng1.next().value;
+            assert_eq!(x, rng2.next().value);
+            differs |= x != rng3.next().value;
+        }
+        assert!(differs, "different seeds gave the same stream");
+    }
+
+    #[test]
+    fn reals_are_uniform_in_open_unit_interval() {
+        let mut rng: MersenneTwisterUniformRng = MersenneTwisterUniformRng::new(1);
+        let n: usize = 100_000;
+        let mut sum: Real = 0.0;
+        let mut sum_squares: Real = 0.0;
+        for _ in 0..n {
+            let sample: Sample<Real> = rng.next();
+            assert!(sample.value > 0.0 && sample.value < 1.0);
+            assert_eq!(sample.weight, 1.0);
+            sum += sample.value;
+            sum_squares += sample.value * sample.value;
+        }
+        let mean: Real = sum / n as Real;
+        let variance: Real = sum_squares / n as Real - mean * mean;
+        // 5 standard errors
+        assert!((mean - 0.5).abs() < 5.0 * (1.0 / 12.0 / n as Real).sqrt());
+        assert!((variance - 1.0 / 12.0).abs() < 1.0e-3);
+    }
+}
@@ -0,0 +1,68 @@
+use crate::math::randomnumbers::rng_traits::{RandomNumberGenerator, SequenceGenerator};
+use crate::methods::montecarlo::sample::Sample;
+use crate::types::{Real, Size};
+
+/*
+Random sequence generator (QuantLib RandomSequenceGenerator).
+
+Builds a d-dimensional sequence out of d consecutive draws of a scalar
+generator. The weight of the sequence is the product of the weights
+of the draws.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct RandomSequenceGenerator<R: RandomNumberGenerator> {
+    dimensionality: Size,
+    rng: R,
+    sequence: Sample<Vec<Real>>,
+}
+impl<R: RandomNumberGenerator> RandomSequenceGenerator<R> {
+    // Constructor
+    pub fn new(dimensionality: Size, rng: R) -> RandomSequenceGenerator<R> {
+        if dimensionality == 0 {
+            panic!("dimensionality must be greater than 0");
+        }
+        RandomSequenceGenerator {
+            dimensionality,
+            rng,
+            sequence: Sample::new(vec![0.0; dimensionality], 1.0),
+        }
+    }
+}
+impl<R: RandomNumberGenerator> SequenceGenerator for RandomSequenceGenerator<R> {
+    fn dimension(&self) -> Size {
+        self.dimensionality
+    }
+    fn next_sequence(&mut self) -> &Sample<Vec<Real>> {
+        self.sequence.weight = 1.0;
+        for x in self.sequence.value.iter_mut() {
+            let sample: Sample<Real> = self.rng.next();
+            *x = sample.value;
+            self.sequence.weight *= sample.weight;
+        }
+        &self.sequence
+    }
+    fn last_sequence(&self) -> &Sample<Vec<Real>> {
+        &self.sequence
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::randomnumbers::mt19937_uniform_rng::MersenneTwisterUniformRng;
+
+    #[test]
+    fn sequences_are_consecutive_draws() {
+        let mut rsg: RandomSequenceGenerator<MersenneTwisterUniformRng> =
+            RandomSequenceGenerator::new(3, MersenneTwisterUniformRng::new(42));
+        let mut rng: MersenneTwisterUniformRng = MersenneTwisterUniformRng::new(42);
+
+        assert_eq!(rsg.dimension(), 3);
+        for _ in 0..5 {
+            let expected: Vec<Real> = (0..3).map(|_| rng.next_real()).collect();
+            assert_eq!(rsg.next_sequence().value, expected);
+            assert_eq!(rsg.last_sequence().value, expected);
+            assert_eq!(rsg.last_sequence().weight, 1.0);
+        }
+    }
+}
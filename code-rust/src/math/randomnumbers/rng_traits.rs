@@ -0,0 +1,47 @@
+use crate::math::randomnumbers::inverse_cumulative_rsg::InverseCumulativeRsg;
+use crate::math::randomnumbers::mt19937_uniform_rng::MersenneTwisterUniformRng;
+use crate::math::randomnumbers::random_sequence_generator::RandomSequenceGenerator;
+use crate::math::randomnumbers::sobol_rsg::SobolRsg;
+use crate::methods::montecarlo::sample::Sample;
+use crate::types::{Real, Size};
+
+/*
+Random number generators come in two flavours (QuantLib rngtraits.hpp):
+    - RandomNumberGenerator: one number at a time (Mersenne Twister,
+      Box-Muller, ...)
+    - SequenceGenerator: one point of a d-dimensional sequence at a time
+      (Sobol, Halton, or d draws of a scalar generator)
+
+Path generators consume Gaussian sequences of dimension
+steps * factors; the two usual recipes are below.
+*/
+pub trait RandomNumberGenerator {
+    fn next(&mut self) -> Sample<Real>;
+}
+
+pub trait SequenceGenerator {
+    fn dimension(&self) -> Size;
+    fn next_sequence(&mut self) -> &Sample<Vec<Real>>;
+    fn last_sequence(&self) -> &Sample<Vec<Real>>;
+}
+
+// Mersenne Twister draws mapped to Gaussians by the inverse cumulative normal
+pub type PseudoRandomRsg = InverseCumulativeRsg<RandomSequenceGenerator<MersenneTwisterUniformRng>>;
+pub struct PseudoRandom;
+impl PseudoRandom {
+    pub fn make_sequence_generator(dimension: Size, seed: u64) -> PseudoRandomRsg {
+        InverseCumulativeRsg::new(RandomSequenceGenerator::new(
+            dimension,
+            MersenneTwisterUniformRng::new(seed),
+        ))
+    }
+}
+
+// Sobol points mapped to Gaussians by the inverse cumulative normal
+pub type LowDiscrepancyRsg = InverseCumulativeRsg<SobolRsg>;
+pub struct LowDiscrepancy;
+impl LowDiscrepancy {
+    pub fn make_sequence_generator(dimension: Size) -> LowDiscrepancyRsg {
+        InverseCumulativeRsg::new(SobolRsg::new(dimension))
+    }
+}
@@ -0,0 +1,263 @@
+use crate::math::randomnumbers::rng_traits::SequenceGenerator;
+use crate::methods::montecarlo::sample::Sample;
+use crate::types::{Real, Size};
+
+/*
+Sobol low-discrepancy sequence generator (QuantLib SobolRsg).
+
+Each dimension k is generated by a primitive polynomial over GF(2) of
+degree s_k with coefficients a_1 ... a_{s-1}, and initial direction
+integers m_1 ... m_s (odd, m_i < 2^i). The remaining direction
+numbers follow the recurrence
+    v_j = a_1 v_{j-1} ^ ... ^ a_{s-1} v_{j-s+1} ^ v_{j-s} ^ (v_{j-s} >> s)
+The first dimension is the van der Corput sequence in base 2.
+
+Points are generated in Gray code order (Antonov-Saleev): the n-th point
+is the previous one XOR the direction number indexed by the rightmost
+zero bit of n-1. The all-zero point is skipped, so the first draw is
+(0.5, ..., 0.5).
+
+Primitive polynomials and initial direction integers are the Joe-Kuo
+D6 ones (S. Joe and F. Y. Kuo, "Constructing Sobol sequences with
+better two-dimensional projections", 2008), of which the first 37
+dimensions are tabulated: larger dimensionalities are rejected.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct SobolRsg {
+    dimensionality: Size,
+    sequence_counter: u32,
+    first_draw: bool,
+    sequence: Sample<Vec<Real>>,
+    integer_sequence: Vec<u32>,
+    direction_integers: Vec<[u32; 32]>,
+}
+impl SobolRsg {
+    const BITS: usize = 32;
+    // Van der Corput and the tabulated Joe-Kuo dimensions
+    const MAX_DIMENSIONALITY: Size = detail::JOE_KUO_D6.len() + 1;
+    // 1 / 2^32: maps 32-bit integers into [0, 1)
+    const NORMALIZATION_FACTOR: Real = 0.5 / (1u32 << 31) as Real;
+
+    // Constructor
+    pub fn new(dimensionality: Size) -> SobolRsg {
+        if dimensionality == 0 {
+            panic!("dimensionality must be greater than 0");
+        }
+        if dimensionality > SobolRsg::MAX_DIMENSIONALITY {
+            panic!(
+                "dimensionality ({}) exceeds the {} dimensions of the tabulated Joe-Kuo D6 direction integers",
+                dimensionality,
+                SobolRsg::MAX_DIMENSIONALITY
+            );
+        }
+
+        let bits: usize = SobolRsg::BITS;
+        let mut direction_integers: Vec<[u32; 32]> = vec![[0; 32]; dimensionality];
+
+        // Van der Corput in base 2
+        for (j, v) in direction_integers[0].iter_mut().enumerate() {
+            *v = 1u32 << (bits - j - 1);
+        }
+        for (v, (degree, coefficients, m)) in direction_integers
+            .iter_mut()
+            .skip(1)
+            .zip(detail::JOE_KUO_D6)
+        {
+            for (j, m_j) in m.iter().enumerate() {
+                v[j] = m_j << (bits - j - 1);
+            }
+            for j in degree..bits {
+                v[j] = v[j - degree] ^ (v[j - degree] >> degree);
+                for l in 1..degree {
+                    if (coefficients >> (degree - 1 - l)) & 1 == 1 {
+                        v[j] ^= v[j - l];
+                    }
+                }
+            }
+        }
+
+        let integer_sequence: Vec<u32> = direction_integers.iter().map(|v| v[0]).collect();
+        SobolRsg {
+            dimensionality,
+            sequence_counter: 0,
+            first_draw: true,
+            sequence: Sample::new(vec![0.0; dimensionality], 1.0),
+            integer_sequence,
+            direction_integers,
+        }
+    }
+
+    // Next point of the sequence as 32-bit integers
+    pub fn next_int32_sequence(&mut self) -> &[u32] {
+        if self.first_draw {
+            self.first_draw = false;
+            return &self.integer_sequence;
+        }
+        self.sequence_counter = self
+            .sequence_counter
+            .checked_add(1)
+            .unwrap_or_else(|| panic!("period exceeded"));
+
+        // Rightmost zero bit of the counter
+        let j: usize = self.sequence_counter.trailing_ones() as usize;
+        for (x, v) in self
+            .integer_sequence
+            .iter_mut()
+            .zip(self.direction_integers.iter())
+        {
+            *x ^= v[j];
+        }
+        &self.integer_sequence
+    }
+}
+impl SequenceGenerator for SobolRsg {
+    fn dimension(&self) -> Size {
+        self.dimensionality
+    }
+    fn next_sequence(&mut self) -> &Sample<Vec<Real>> {
+        self.next_int32_sequence();
+        for (x, n) in self
+            .sequence
+            .value
+            .iter_mut()
+            .zip(self.integer_sequence.iter())
+        {
+            *x = *n as Real * SobolRsg::NORMALIZATION_FACTOR;
+        }
+        &self.sequence
+    }
+    fn last_sequence(&self) -> &Sample<Vec<Real>> {
+        &self.sequence
+    }
+}
+
+// Private
+mod detail {
+    // (degree s, coefficients a, initial direction integers m_1..m_s)
+    // for dimensions 2, 3, ... (new-joe-kuo-6.21201)
+    pub(crate) const JOE_KUO_D6: [(usize, u32, &[u32]); 36] = [
+        (1, 0, &[1]),
+        (2, 1, &[1, 3]),
+        (3, 1, &[1, 3, 1]),
+        (3, 2, &[1, 1, 1]),
+        (4, 1, &[1, 1, 3, 3]),
+        (4, 4, &[1, 3, 5, 13]),
+        (5, 2, &[1, 1, 5, 5, 17]),
+        (5, 4, &[1, 1, 5, 5, 5]),
+        (5, 7, &[1, 1, 7, 11, 19]),
+        (5, 11, &[1, 1, 5, 1, 1]),
+        (5, 13, &[1, 1, 1, 3, 11]),
+        (5, 14, &[1, 3, 5, 5, 31]),
+        (6, 1, &[1, 3, 3, 9, 7, 49]),
+        (6, 13, &[1, 1, 1, 15, 21, 21]),
+        (6, 16, &[1, 3, 1, 13, 27, 49]),
+        (6, 19, &[1, 1, 1, 15, 7, 5]),
+        (6, 22, &[1, 3, 1, 15, 13, 25]),
+        (6, 25, &[1, 1, 5, 5, 19, 61]),
+        (7, 1, &[1, 3, 7, 11, 23, 15, 103]),
+        (7, 4, &[1, 3, 7, 13, 13, 15, 69]),
+        (7, 7, &[1, 1, 3, 13, 7, 35, 63]),
+        (7, 8, &[1, 3, 5, 9, 1, 25, 53]),
+        (7, 14, &[1, 3, 1, 13, 9, 35, 107]),
+        (7, 19, &[1, 3, 1, 5, 27, 61, 31]),
+        (7, 21, &[1, 1, 5, 11, 19, 41, 61]),
+        (7, 28, &[1, 3, 5, 3, 3, 13, 69]),
+        (7, 31, &[1, 1, 7, 13, 1, 19, 1]),
+        (7, 32, &[1, 3, 7, 5, 13, 19, 59]),
+        (7, 37, &[1, 1, 3, 9, 25, 29, 41]),
+        (7, 41, &[1, 3, 5, 13, 23, 1, 55]),
+        (7, 42, &[1, 3, 7, 3, 13, 59, 17]),
+        (7, 50, &[1, 3, 1, 3, 5, 53, 69]),
+        (7, 55, &[1, 1, 5, 5, 23, 33, 13]),
+        (7, 56, &[1, 1, 7, 7, 1, 61, 123]),
+        (7, 59, &[1, 1, 7, 9, 13, 61, 49]),
+        (7, 62, &[1, 3, 3, 5, 3, 55, 33]),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    #[test]
+    fn first_points_match_the_gray_code_sequence() {
+        let expected: [[Real; 2]; 7] = [
+            [0.5, 0.5],
+            [0.75, 0.25],
+            [0.25, 0.75],
+            [0.375, 0.375],
+            [0.875, 0.875],
+            [0.625, 0.125],
+            [0.125, 0.625],
+        ];
+        let mut rsg: SobolRsg = SobolRsg::new(2);
+        assert_eq!(rsg.dimension(), 2);
+        for point in expected {
+            let sample: &Sample<Vec<Real>> = rsg.next_sequence();
+            assert_eq!(sample.value, point.to_vec());
+            assert_eq!(sample.weight, 1.0);
+        }
+        assert_eq!(rsg.last_sequence().value, vec![0.125, 0.625]);
+    }
+
+    #[test]
+    fn every_dimension_is_stratified() {
+        // The first 2^m - 1 points, plus the skipped origin, put exactly one
+        // point in each interval [i / 2^m, (i + 1) / 2^m) in every dimension
+        let dimensionality: Size = 37;
+        let m: u32 = 10;
+        let n: usize = 1 << m;
+        let mut rsg: SobolRsg = SobolRsg::new(dimensionality);
+        let mut counts: Vec<Vec<usize>> = vec![vec![0; n]; dimensionality];
+        for row in counts.iter_mut() {
+            row[0] = 1;
+        }
+        for _ in 1..n {
+            let point: Vec<Real> = rsg.next_sequence().value.clone();
+            for (k, x) in point.iter().enumerate() {
+                assert!(*x > 0.0 && *x < 1.0);
+                counts[k][(x * n as Real) as usize] += 1;
+            }
+        }
+        for (k, row) in counts.iter().enumerate() {
+            assert!(
+                row.iter().all(|c| *c == 1),
+                "dimension {} not stratified",
+                k + 1
+            );
+        }
+    }
+
+    #[test]
+    fn two_dimensional_projections_integrate_well() {
+        // Mean of x * y over the unit square is 1/4
+        let mut rsg: SobolRsg = SobolRsg::new(37);
+        let n: usize = 4_095;
+        let mut sums: Vec<Real> = vec![0.0; 36];
+        for _ in 0..n {
+            let point: &Vec<Real> = &rsg.next_sequence().value;
+            for k in 0..36 {
+                sums[k] += point[k] * point[k + 1];
+            }
+        }
+        for (k, sum) in sums.iter().enumerate() {
+            let error: Real = (sum / n as Real - 0.25).abs();
+            assert!(
+                error < 2.0e-3,
+                "dimensions ({}, {}): error {}",
+                k + 1,
+                k + 2,
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn new_panics_for_unsupported_dimensionality() {
+        for dimensionality in [0, 38] {
+            let result = panic::catch_unwind(|| SobolRsg::new(dimensionality));
+            assert!(result.is_err(), "expected panic for {dimensionality}");
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use crate::types::{Integer, Real};
+use std::fmt::{Display, Formatter, Result};
+
+// How the last kept digit is chosen (QuantLib Rounding::Type)
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum RoundingType {
+    // Do not round: return the number unmodified
+    None,
+    // The first decimal place past the precision is rounded up
+    Up,
+    // All decimal places past the precision are truncated
+    Down,
+    // Rounded up if the first decimal place past the precision is at least the digit
+    Closest,
+    // Positive numbers are rounded to the closest, negative ones truncated
+    Floor,
+    // Negative numbers are rounded to the closest, positive ones truncated
+    Ceiling,
+}
+
+// Traits
+impl Display for RoundingType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let rounding_type: &'static str = match self {
+            RoundingType::None => "None",
+            RoundingType::Up => "Up",
+            RoundingType::Down => "Down",
+            RoundingType::Closest => "Closest",
+            RoundingType::Floor => "Floor",
+            RoundingType::Ceiling => "Ceiling",
+        };
+        write!(f, "{rounding_type}")
+    }
+}
+
+/*
+Rounding of a number to a given number of decimal places (QuantLib
+Rounding). The rounding digit is the value of the first discarded
+decimal from which Closest, Floor and Ceiling round away from zero;
+QuantLib's default is 5.
+*/
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Rounding {
+    rounding_type: RoundingType,
+    precision: Integer,
+    digit: Integer,
+}
+impl Rounding {
+    const DEFAULT_DIGIT: Integer = 5;
+
+    // Constructor
+    pub const fn new(rounding_type: RoundingType, precision: Integer, digit: Integer) -> Rounding {
+        Rounding {
+            rounding_type,
+            precision,
+            digit,
+        }
+    }
+    pub const fn none() -> Rounding {
+        Rounding::new(RoundingType::None, 0, Rounding::DEFAULT_DIGIT)
+    }
+    pub const fn up(precision: Integer) -> Rounding {
+        Rounding::new(RoundingType::Up, precision, Rounding::DEFAULT_DIGIT)
+    }
+    pub const fn down(precision: Integer) -> Rounding {
+        Rounding::new(RoundingType::Down, precision, Rounding::DEFAULT_DIGIT)
+    }
+    pub const fn closest(precision: Integer) -> Rounding {
+        Rounding::new(RoundingType::Closest, precision, Rounding::DEFAULT_DIGIT)
+    }
+    pub const fn floor(precision: Integer) -> Rounding {
+        Rounding::new(RoundingType::Floor, precision, Rounding::DEFAULT_DIGIT)
+    }
+    pub const fn ceiling(precision: Integer) -> Rounding {
+        Rounding::new(RoundingType::Ceiling, precision, Rounding::DEFAULT_DIGIT)
+    }
+
+    // Inspectors
+    pub fn rounding_type(&self) -> RoundingType {
+        self.rounding_type
+    }
+    pub fn precision(&self) -> Integer {
+        self.precision
+    }
+    pub fn digit(&self) -> Integer {
+        self.digit
+    }
+
+    pub fn apply(&self, value: Real) -> Real {
+        if self.rounding_type == RoundingType::None {
+            return value;
+        }
+        let multiplier: Real = 10.0_f64.powi(self.precision);
+        let negative: bool = value < 0.0;
+        let scaled: Real = value.abs() * multiplier;
+        let mut rounded: Real = scaled.trunc();
+        // Guard against representation noise, e.g. 1.005 * 100 = 100.49999...
+        let fraction: Real = ((scaled - rounded) * 1.0e9).round() / 1.0e9;
+        let round_away: bool = fraction >= self.digit as Real / 10.0;
+        let away: bool = match self.rounding_type {
+            RoundingType::None | RoundingType::Down => false,
+            RoundingType::Up => fraction != 0.0,
+            RoundingType::Closest => round_away,
+            RoundingType::Floor => !negative && round_away,
+            RoundingType::Ceiling => negative && round_away,
+        };
+        if away {
+            rounded += 1.0;
+        }
+        if negative {
+            -rounded / multiplier
+        } else {
+            rounded / multiplier
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounding_follows_its_type() {
+        // QuantLib test-suite/rounding.cpp values
+        let cases: [(Real, Integer, Real, Real, Real, Real, Real); 8] = [
+            // value, precision, closest, up, down, floor, ceiling
+            (0.86313513, 5, 0.86314, 0.86314, 0.86313, 0.86314, 0.86313),
+            (0.86313, 5, 0.86313, 0.86313, 0.86313, 0.86313, 0.86313),
+            (7.64555346, 1, 7.6, 7.7, 7.6, 7.6, 7.6),
+            (0.13961605, 2, 0.14, 0.14, 0.13, 0.14, 0.13),
+            (0.03133226, 5, 0.03133, 0.03134, 0.03133, 0.03133, 0.03133),
+            (-0.93744653, 1, -0.9, -1.0, -0.9, -0.9, -0.9),
+            (-0.92152815, 2, -0.92, -0.93, -0.92, -0.92, -0.92),
+            (-0.96576575, 4, -0.9658, -0.9658, -0.9657, -0.9657, -0.9658),
+        ];
+        for (value, precision, closest, up, down, floor, ceiling) in cases {
+            let roundings: [(Rounding, Real); 5] = [
+                (Rounding::closest(precision), closest),
+                (Rounding::up(precision), up),
+                (Rounding::down(precision), down),
+                (Rounding::floor(precision), floor),
+                (Rounding::ceiling(precision), ceiling),
+            ];
+            for (rounding, expected) in roundings {
+                assert!(
+                    (rounding.apply(value) - expected).abs() < 1.0e-12,
+                    "{} rounding of {}: {} vs {}",
+                    rounding.rounding_type(),
+                    value,
+                    rounding.apply(value),
+                    expected
+                );
+            }
+        }
+        assert_eq!(Rounding::none().apply(0.123456789), 0.123456789);
+        assert_eq!(Rounding::closest(2).apply(1.005), 1.01);
+    }
+}
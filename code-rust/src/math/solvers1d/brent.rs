@@ -0,0 +1,173 @@
+use crate::types::{Real, Size};
+
+/*
+Brent 1-D solver (QuantLib Brent).
+
+Inverse quadratic interpolation, falling back to bisection whenever the
+interpolated step would leave the bracket or shrink it too slowly. It
+needs no derivative and converges as long as the root is bracketed by
+[x_min, x_max].
+*/
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Brent {
+    max_evaluations: Size,
+}
+impl Brent {
+    const MAX_FUNCTION_EVALUATIONS: Size = 100;
+
+    // Constructor
+    pub fn new() -> Brent {
+        Brent {
+            max_evaluations: Brent::MAX_FUNCTION_EVALUATIONS,
+        }
+    }
+
+    // Inspectors / Modifiers
+    pub fn max_evaluations(&self) -> Size {
+        self.max_evaluations
+    }
+    pub fn set_max_evaluations(&mut self, max_evaluations: Size) {
+        self.max_evaluations = max_evaluations;
+    }
+
+    pub fn solve<F>(&self, f: F, accuracy: Real, x_min: Real, x_max: Real) -> Real
+    where
+        F: Fn(Real) -> Real,
+    {
+        if accuracy <= 0.0 {
+            panic!("accuracy ({accuracy}) must be positive");
+        }
+        let accuracy: Real = accuracy.max(Real::EPSILON);
+        if x_min >= x_max {
+            panic!("invalid range: x_min ({x_min}) >= x_max ({x_max})");
+        }
+
+        // b is the best estimate, a the previous one and c the bracketing counterpart of b
+        let (mut a, mut b): (Real, Real) = (x_min, x_max);
+        let (mut f_a, mut f_b): (Real, Real) = (f(a), f(b));
+        if f_a == 0.0 {
+            return a;
+        }
+        if f_b == 0.0 {
+            return b;
+        }
+        if f_a * f_b > 0.0 {
+            panic!("root not bracketed: f[{x_min},{x_max}] -> [{f_a}, {f_b}]");
+        }
+        let mut evaluation_number: Size = 2;
+        let (mut c, mut f_c): (Real, Real) = (a, f_a);
+        let mut d: Real = b - a;
+        let mut e: Real = d;
+
+        while evaluation_number <= self.max_evaluations {
+            if f_b * f_c > 0.0 {
+                c = a;
+                f_c = f_a;
+                d = b - a;
+                e = d;
+            }
+            if f_c.abs() < f_b.abs() {
+                a = b;
+                b = c;
+                c = a;
+                f_a = f_b;
+                f_b = f_c;
+                f_c = f_a;
+            }
+            let tolerance: Real = 2.0 * Real::EPSILON * b.abs() + 0.5 * accuracy;
+            let x_mid: Real = 0.5 * (c - b);
+            if x_mid.abs() <= tolerance || f_b == 0.0 {
+                return b;
+            }
+            if e.abs() >= tolerance && f_a.abs() > f_b.abs() {
+                // Inverse quadratic interpolation (secant if only two points)
+                let s: Real = f_b / f_a;
+                let (mut p, mut q): (Real, Real) = if a == c {
+                    (2.0 * x_mid * s, 1.0 - s)
+                } else {
+                    let q: Real = f_a / f_c;
+                    let r: Real = f_b / f_c;
+                    (
+                        s * (2.0 * x_mid * q * (q - r) - (b - a) * (r - 1.0)),
+                        (q - 1.0) * (r - 1.0) * (s - 1.0),
+                    )
+                };
+                if p > 0.0 {
+                    q = -q;
+                }
+                p = p.abs();
+                let min1: Real = 3.0 * x_mid * q - (tolerance * q).abs();
+                let min2: Real = (e * q).abs();
+                if 2.0 * p < min1.min(min2) {
+                    e = d;
+                    d = p / q;
+                } else {
+                    d = x_mid;
+                    e = d;
+                }
+            } else {
+                // Bisection
+                d = x_mid;
+                e = d;
+            }
+            a = b;
+            f_a = f_b;
+            b += if d.abs() > tolerance {
+                d
+            } else {
+                tolerance.copysign(x_mid)
+            };
+            f_b = f(b);
+            evaluation_number += 1;
+        }
+
+        panic!(
+            "maximum number of function evaluations ({}) exceeded",
+            self.max_evaluations
+        );
+    }
+}
+impl Default for Brent {
+    fn default() -> Self {
+        Brent::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    #[test]
+    #[allow(clippy::type_complexity)]
+    fn solve_finds_roots() {
+        let solver: Brent = Brent::new();
+        let cases: [(fn(Real) -> Real, Real, Real, Real, &str); 3] = [
+            (|x| x * x - 1.0, 0.0, 3.0, 1.0, "x^2 - 1"),
+            (|x| x.cos() - x, -1.0, 2.0, 0.7390851332151607, "cos(x) - x"),
+            (|x| x.exp() - 10.0, -5.0, 10.0, 10.0_f64.ln(), "exp(x) - 10"),
+        ];
+        for (f, x_min, x_max, expected, label) in cases {
+            let root: Real = solver.solve(f, 1.0e-12, x_min, x_max);
+            assert!((root - expected).abs() < 1.0e-10, "{label}: got {root}");
+        }
+    }
+
+    #[test]
+    fn solve_panics_on_invalid_inputs() {
+        let cases: [(Real, Real, Real, Size, &str); 4] = [
+            (1.0e-10, 2.0, 3.0, 100, "root not bracketed"),
+            (1.0e-10, 3.0, 0.0, 100, "invalid range"),
+            (0.0, 0.0, 3.0, 100, "zero accuracy"),
+            (1.0e-15, 0.0, 3.0, 3, "too few evaluations"),
+        ];
+        for (accuracy, x_min, x_max, max_evaluations, label) in cases {
+            let result = panic::catch_unwind(|| {
+                let mut solver: Brent = Brent::new();
+                solver.set_max_evaluations(max_evaluations);
+                solver.solve(|x| x * x - 1.0, accuracy, x_min, x_max)
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use crate::types::{Real, Size};
+
+/*
+Safe Newton 1-D solver (QuantLib NewtonSafe).
+
+Newton-Raphson steps are taken while they stay inside the bracketing
+interval and reduce the step fast enough; otherwise the solver falls
+back to a bisection step. It therefore always converges as long as the
+root is bracketed by [x_min, x_max].
+*/
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct NewtonSafe {
+    max_evaluations: Size,
+}
+impl NewtonSafe {
+    const MAX_FUNCTION_EVALUATIONS: Size = 100;
+
+    // Constructor
+    pub fn new() -> NewtonSafe {
+        NewtonSafe {
+            max_evaluations: NewtonSafe::MAX_FUNCTION_EVALUATIONS,
+        }
+    }
+
+    // Inspectors / Modifiers
+    pub fn max_evaluations(&self) -> Size {
+        self.max_evaluations
+    }
+    pub fn set_max_evaluations(&mut self, max_evaluations: Size) {
+        self.max_evaluations = max_evaluations;
+    }
+
+    pub fn solve<F, D>(
+        &self,
+        f: F,
+        derivative: D,
+        accuracy: Real,
+        guess: Real,
+        x_min: Real,
+        x_max: Real,
+    ) -> Real
+    where
+        F: Fn(Real) -> Real,
+        D: Fn(Real) -> Real,
+    {
+        if accuracy <= 0.0 {
+            panic!("accuracy ({accuracy}) must be positive");
+        }
+        // Avoid a convergence check that can never be met
+        let accuracy: Real = accuracy.max(Real::EPSILON);
+
+        if x_min >= x_max {
+            panic!("invalid range: x_min ({x_min}) >= x_max ({x_max})");
+        }
+        if !(x_min..=x_max).contains(&guess) {
+            panic!("guess ({guess}) outside the range [{x_min}, {x_max}]");
+        }
+
+        let f_x_min: Real = f(x_min);
+        if f_x_min == 0.0 {
+            return x_min;
+        }
+        let f_x_max: Real = f(x_max);
+        if f_x_max == 0.0 {
+            return x_max;
+        }
+        let mut evaluation_number: Size = 2;
+
+        if f_x_min * f_x_max >= 0.0 {
+            panic!("root not bracketed: f[{x_min},{x_max}] -> [{f_x_min}, {f_x_max}]");
+        }
+
+        // Orient the search so that f(x_low) < 0
+        let (mut x_low, mut x_high): (Real, Real) = if f_x_min < 0.0 {
+            (x_min, x_max)
+        } else {
+            (x_max, x_min)
+        };
+
+        // The "stepsize before last"
+        let mut dx_old: Real = x_max - x_min;
+        let mut dx: Real = dx_old;
+
+        let mut root: Real = guess;
+        let mut f_root: Real = f(root);
+        let mut df_root: Real = derivative(root);
+        evaluation_number += 1;
+
+        while evaluation_number <= self.max_evaluations {
+            let out_of_range: bool =
+                ((root - x_high) * df_root - f_root) * ((root - x_low) * df_root - f_root) > 0.0;
+            let too_slow: bool = (2.0 * f_root).abs() > (dx_old * df_root).abs();
+
+            if out_of_range || too_slow {
+                // Bisection
+                dx_old = dx;
+                dx = (x_high - x_low) / 2.0;
+                root = x_low + dx;
+            } else {
+                // Newton
+                dx_old = dx;
+                dx = f_root / df_root;
+                root -= dx;
+            }
+
+            if dx.abs() < accuracy {
+                return root;
+            }
+
+            f_root = f(root);
+            df_root = derivative(root);
+            evaluation_number += 1;
+
+            if f_root < 0.0 {
+                x_low = root;
+            } else {
+                x_high = root;
+            }
+        }
+
+        panic!(
+            "maximum number of function evaluations ({}) exceeded",
+            self.max_evaluations
+        );
+    }
+}
+impl Default for NewtonSafe {
+    fn default() -> Self {
+        NewtonSafe::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    #[test]
+    fn solve_finds_roots() {
+        let solver: NewtonSafe = NewtonSafe::new();
+        let accuracy: Real = 1.0e-12;
+
+        // x^2 - 1 = 0
+        let root: Real = solver.solve(|x| x * x - 1.0, |x| 2.0 * x, accuracy, 0.5, 0.0, 3.0);
+        assert!((root - 1.0).abs() < 1.0e-10, "x^2 - 1: got {root}");
+
+        // cos(x) - x = 0 (Dottie number)
+        let root: Real = solver.solve(
+            |x| x.cos() - x,
+            |x| -x.sin() - 1.0,
+            accuracy,
+            0.0,
+            -1.0,
+            2.0,
+        );
+        assert!(
+            (root - 0.7390851332151607).abs() < 1.0e-10,
+            "cos(x) - x: got {root}"
+        );
+
+        // exp(x) - 10 = 0, guess far from the root
+        let root: Real = solver.solve(|x| x.exp() - 10.0, |x| x.exp(), accuracy, 10.0, -5.0, 10.0);
+        assert!(
+            (root - 10.0_f64.ln()).abs() < 1.0e-10,
+            "exp(x) - 10: got {root}"
+        );
+    }
+
+    #[test]
+    fn solve_returns_bound_if_it_is_the_root() {
+        let solver: NewtonSafe = NewtonSafe::new();
+        let root: Real = solver.solve(|x| x, |_| 1.0, 1.0e-10, 0.5, 0.0, 1.0);
+        assert_eq!(root, 0.0);
+    }
+
+    #[test]
+    fn solve_panics_on_invalid_inputs() {
+        let solver: NewtonSafe = NewtonSafe::new();
+        let cases: [(Real, Real, Real, Real, &str); 4] = [
+            (1.0e-10, 0.5, 2.0, 3.0, "root not bracketed"),
+            (1.0e-10, 4.0, 0.0, 3.0, "guess outside range"),
+            (1.0e-10, 0.5, 3.0, 0.0, "invalid range"),
+            (0.0, 0.5, 0.0, 3.0, "zero accuracy"),
+        ];
+
+        for (accuracy, guess, x_min, x_max, label) in cases {
+            let result = panic::catch_unwind(|| {
+                solver.solve(|x| x * x - 1.0, |x| 2.0 * x, accuracy, guess, x_min, x_max)
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+
+    #[test]
+    fn solve_panics_when_max_evaluations_exceeded() {
+        let mut solver: NewtonSafe = NewtonSafe::new();
+        solver.set_max_evaluations(3);
+        assert_eq!(solver.max_evaluations(), 3);
+
+        let result = panic::catch_unwind(|| {
+            solver.solve(|x| x.exp() - 10.0, |x| x.exp(), 1.0e-15, 10.0, -5.0, 10.0)
+        });
+        assert!(result.is_err(), "expected panic for too few evaluations");
+    }
+}
@@ -0,0 +1,128 @@
+use crate::math::statistics::incremental_statistics::IncrementalStatistics;
+use crate::math::statistics::sample_accumulator::SampleAccumulator;
+use crate::types::{Real, Size};
+
+/*
+Statistics recording how the mean converges (QuantLib
+ConvergenceStatistics with DoublingConvergenceSteps).
+
+The wrapped accumulator is fed as usual, and (samples, mean) is added
+to the convergence table whenever the number of samples reaches
+1, 3, 7, 15, ..., 2^k - 1: doubling the samples should roughly divide
+the Monte Carlo error by sqrt(2), which the table lets one check. The
+accumulator given to the constructor is reset.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct ConvergenceStatistics<S: SampleAccumulator = IncrementalStatistics> {
+    statistics: S,
+    table: Vec<(Size, Real)>,
+    sample_size: Size,
+}
+impl<S: SampleAccumulator> ConvergenceStatistics<S> {
+    const INITIAL_SAMPLES: Size = 1;
+
+    // Constructor
+    pub fn new(statistics: S) -> ConvergenceStatistics<S> {
+        let mut convergence: ConvergenceStatistics<S> = ConvergenceStatistics {
+            statistics,
+            table: Vec::new(),
+            sample_size: ConvergenceStatistics::<S>::INITIAL_SAMPLES,
+        };
+        convergence.reset();
+        convergence
+    }
+
+    // Modifiers
+    pub fn add(&mut self, value: Real, weight: Real) {
+        self.statistics.add(value, weight);
+        if self.statistics.samples() == self.sample_size {
+            self.table.push((self.sample_size, self.statistics.mean()));
+            self.sample_size = 2 * self.sample_size + 1;
+        }
+    }
+    pub fn add_sequence(&mut self, values: &[Real]) {
+        for value in values {
+            self.add(*value, 1.0);
+        }
+    }
+    pub fn reset(&mut self) {
+        self.statistics.reset();
+        self.table.clear();
+        self.sample_size = ConvergenceStatistics::<S>::INITIAL_SAMPLES;
+    }
+
+    // Inspectors
+    pub fn statistics(&self) -> &S {
+        &self.statistics
+    }
+    // (samples, mean) pairs
+    pub fn convergence_table(&self) -> &[(Size, Real)] {
+        &self.table
+    }
+}
+impl<S: SampleAccumulator> Default for ConvergenceStatistics<S> {
+    fn default() -> Self {
+        ConvergenceStatistics::new(S::default())
+    }
+}
+impl<S: SampleAccumulator> SampleAccumulator for ConvergenceStatistics<S> {
+    fn add(&mut self, value: Real, weight: Real) {
+        ConvergenceStatistics::add(self, value, weight)
+    }
+    fn reset(&mut self) {
+        ConvergenceStatistics::reset(self)
+    }
+    fn samples(&self) -> Size {
+        self.statistics.samples()
+    }
+    fn weight_sum(&self) -> Real {
+        self.statistics.weight_sum()
+    }
+    fn mean(&self) -> Real {
+        self.statistics.mean()
+    }
+    fn variance(&self) -> Real {
+        self.statistics.variance()
+    }
+    fn min(&self) -> Real {
+        self.statistics.min()
+    }
+    fn max(&self) -> Real {
+        self.statistics.max()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::statistics::general_statistics::GeneralStatistics;
+
+    #[test]
+    fn table_is_filled_at_doubling_sample_sizes() {
+        let mut statistics: ConvergenceStatistics = ConvergenceStatistics::default();
+        let values: Vec<Real> = (1..=20).map(|i| i as Real).collect();
+        statistics.add_sequence(&values);
+
+        // The mean of 1, ..., n is (n + 1) / 2
+        assert_eq!(
+            statistics.convergence_table(),
+            &[(1, 1.0), (3, 2.0), (7, 4.0), (15, 8.0)]
+        );
+        assert_eq!(statistics.samples(), 20);
+        assert_eq!(statistics.statistics().mean(), 10.5);
+
+        statistics.reset();
+        assert!(statistics.convergence_table().is_empty());
+        statistics.add(5.0, 1.0);
+        assert_eq!(statistics.convergence_table(), &[(1, 5.0)]);
+    }
+
+    #[test]
+    fn wraps_any_accumulator() {
+        let mut statistics: ConvergenceStatistics<GeneralStatistics> =
+            ConvergenceStatistics::new(GeneralStatistics::new());
+        statistics.add_sequence(&[3.0, 1.0, 2.0]);
+        assert_eq!(statistics.convergence_table(), &[(1, 3.0), (3, 2.0)]);
+        assert_eq!(statistics.statistics().percentile(0.5), 2.0);
+    }
+}
@@ -0,0 +1,335 @@
+use crate::math::statistics::sample_accumulator::SampleAccumulator;
+use crate::types::{Real, Size};
+
+/*
+Statistics over a stored set of weighted samples (QuantLib
+GeneralStatistics).
+
+Keeping the samples costs memory but gives percentiles and conditional
+expectations, which risk measures and historical simulations need:
+    - expectation_value(f, in_range) is the weighted average of f(x)
+      over the samples x in range, together with their number
+    - percentile(p) is the smallest sample x such that the samples up
+      to x carry at least a fraction p of the total weight;
+      top_percentile(p) is the same from the largest sample down
+Variance, skewness and excess kurtosis carry the usual small-sample
+corrections. Percentiles sort a copy of the samples unless sort() was
+called after the last addition.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct GeneralStatistics {
+    samples: Vec<(Real, Real)>,
+    sorted: bool,
+}
+impl GeneralStatistics {
+    // Constructor
+    pub fn new() -> GeneralStatistics {
+        GeneralStatistics {
+            samples: Vec::new(),
+            sorted: true,
+        }
+    }
+
+    // Modifiers
+    pub fn add(&mut self, value: Real, weight: Real) {
+        if weight < 0.0 {
+            panic!("negative weight ({weight}) not allowed");
+        }
+        self.samples.push((value, weight));
+        self.sorted = false;
+    }
+    pub fn add_sequence(&mut self, values: &[Real]) {
+        for value in values {
+            self.add(*value, 1.0);
+        }
+    }
+    pub fn reset(&mut self) {
+        *self = GeneralStatistics::new();
+    }
+    // Sorts the samples by value, so that percentiles need no copy
+    pub fn sort(&mut self) {
+        if !self.sorted {
+            self.samples.sort_by(|a, b| a.0.total_cmp(&b.0));
+            self.sorted = true;
+        }
+    }
+
+    // Inspectors
+    pub fn samples(&self) -> Size {
+        self.samples.len()
+    }
+    // (value, weight) pairs
+    pub fn data(&self) -> &[(Real, Real)] {
+        &self.samples
+    }
+    pub fn weight_sum(&self) -> Real {
+        self.samples.iter().map(|(_, weight)| weight).sum()
+    }
+    pub fn mean(&self) -> Real {
+        if self.samples.is_empty() {
+            panic!("empty sample set");
+        }
+        self.expectation_value(|x| x, |_| true).0
+    }
+    pub fn variance(&self) -> Real {
+        let n: Size = self.samples();
+        if n <= 1 {
+            panic!("sample number <= 1, insufficient");
+        }
+        let mean: Real = self.mean();
+        let x: Real = self
+            .expectation_value(|x| (x - mean) * (x - mean), |_| true)
+            .0;
+        x * n as Real / (n as Real - 1.0)
+    }
+    pub fn standard_deviation(&self) -> Real {
+        self.variance().sqrt()
+    }
+    // Standard error of the mean: sqrt(variance / samples)
+    pub fn error_estimate(&self) -> Real {
+        (self.variance() / self.samples() as Real).sqrt()
+    }
+    pub fn skewness(&self) -> Real {
+        let n: Size = self.samples();
+        if n <= 2 {
+            panic!("sample number <= 2, insufficient");
+        }
+        let n: Real = n as Real;
+        let mean: Real = self.mean();
+        let x: Real = self.expectation_value(|x| (x - mean).powi(3), |_| true).0;
+        let sigma: Real = self.standard_deviation();
+        x / (sigma * sigma * sigma) * (n / (n - 1.0)) * (n / (n - 2.0))
+    }
+    // Excess kurtosis
+    pub fn kurtosis(&self) -> Real {
+        let n: Size = self.samples();
+        if n <= 3 {
+            panic!("sample number <= 3, insufficient");
+        }
+        let n: Real = n as Real;
+        let mean: Real = self.mean();
+        let x: Real = self.expectation_value(|x| (x - mean).powi(4), |_| true).0;
+        let sigma2: Real = self.variance();
+        let c1: Real = (n / (n - 1.0)) * (n / (n - 2.0)) * ((n + 1.0) / (n - 3.0));
+        let c2: Real = 3.0 * ((n - 1.0) * (n - 1.0) / ((n - 2.0) * (n - 3.0)));
+        c1 * x / (sigma2 * sigma2) - c2
+    }
+    pub fn min(&self) -> Real {
+        if self.samples.is_empty() {
+            panic!("empty sample set");
+        }
+        self.samples
+            .iter()
+            .map(|(value, _)| *value)
+            .fold(Real::MAX, Real::min)
+    }
+    pub fn max(&self) -> Real {
+        if self.samples.is_empty() {
+            panic!("empty sample set");
+        }
+        self.samples
+            .iter()
+            .map(|(value, _)| *value)
+            .fold(Real::MIN, Real::max)
+    }
+
+    // (sum w f(x) / sum w, number of samples) over the samples in range
+    pub fn expectation_value<F, R>(&self, f: F, in_range: R) -> (Real, Size)
+    where
+        F: Fn(Real) -> Real,
+        R: Fn(Real) -> bool,
+    {
+        let (mut num, mut den, mut n): (Real, Real, Size) = (0.0, 0.0, 0);
+        for (value, weight) in self.samples.iter().filter(|(value, _)| in_range(*value)) {
+            num += weight * f(*value);
+            den += weight;
+            n += 1;
+        }
+        if n == 0 {
+            return (0.0, 0);
+        }
+        if den <= 0.0 {
+            panic!("sampleWeight = 0, insufficient");
+        }
+        (num / den, n)
+    }
+    pub fn percentile(&self, percent: Real) -> Real {
+        self.sorted_percentile(percent, false)
+    }
+    pub fn top_percentile(&self, percent: Real) -> Real {
+        self.sorted_percentile(percent, true)
+    }
+
+    fn sorted_percentile(&self, percent: Real, from_top: bool) -> Real {
+        if self.sorted {
+            return detail::percentile(&self.samples, percent, from_top);
+        }
+        let mut statistics: GeneralStatistics = self.clone();
+        statistics.sort();
+        detail::percentile(&statistics.samples, percent, from_top)
+    }
+}
+impl Default for GeneralStatistics {
+    fn default() -> Self {
+        GeneralStatistics::new()
+    }
+}
+impl SampleAccumulator for GeneralStatistics {
+    fn add(&mut self, value: Real, weight: Real) {
+        GeneralStatistics::add(self, value, weight)
+    }
+    fn reset(&mut self) {
+        GeneralStatistics::reset(self)
+    }
+    fn samples(&self) -> Size {
+        GeneralStatistics::samples(self)
+    }
+    fn weight_sum(&self) -> Real {
+        GeneralStatistics::weight_sum(self)
+    }
+    fn mean(&self) -> Real {
+        GeneralStatistics::mean(self)
+    }
+    fn variance(&self) -> Real {
+        GeneralStatistics::variance(self)
+    }
+    fn min(&self) -> Real {
+        GeneralStatistics::min(self)
+    }
+    fn max(&self) -> Real {
+        GeneralStatistics::max(self)
+    }
+}
+
+pub(crate) mod detail {
+    use super::*;
+
+    // First sorted sample, from the smallest or the largest one, at which
+    // the running weight reaches percent of the total
+    pub(crate) fn percentile(samples: &[(Real, Real)], percent: Real, from_top: bool) -> Real {
+        if percent <= 0.0 || percent > 1.0 {
+            panic!("percentile ({percent}) must be in (0.0, 1.0]");
+        }
+        if samples.is_empty() {
+            panic!("empty sample set");
+        }
+        let total: Real = samples.iter().map(|(_, weight)| weight).sum();
+        if total <= 0.0 {
+            panic!("empty sample set");
+        }
+        let target: Real = percent * total;
+        let mut integral: Real = 0.0;
+        let mut last: Real = 0.0;
+        let ordered: Box<dyn Iterator<Item = &(Real, Real)>> = if from_top {
+            Box::new(samples.iter().rev())
+        } else {
+            Box::new(samples.iter())
+        };
+        for (value, weight) in ordered {
+            integral += weight;
+            last = *value;
+            if integral >= target {
+                break;
+            }
+        }
+        last
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::statistics::incremental_statistics::IncrementalStatistics;
+    use std::panic;
+
+    const DATA: [Real; 10] = [3.0, 4.0, 5.0, 2.0, 3.0, 4.0, 5.0, 6.0, 4.0, 7.0];
+
+    #[test]
+    fn moments_match_the_reference_values() {
+        let mut statistics: GeneralStatistics = GeneralStatistics::new();
+        statistics.add_sequence(&DATA);
+
+        let cases: [(Real, Real, &str); 8] = [
+            (statistics.samples() as Real, 10.0, "samples"),
+            (statistics.weight_sum(), 10.0, "weight sum"),
+            (statistics.mean(), 4.3, "mean"),
+            (statistics.variance(), 2.2333333333333334, "variance"),
+            (statistics.skewness(), 0.3595430714067973, "skewness"),
+            (statistics.kurtosis(), -0.1517996372084148, "kurtosis"),
+            (statistics.min(), 2.0, "min"),
+            (statistics.max(), 7.0, "max"),
+        ];
+        for (value, expected, label) in cases {
+            assert!((value - expected).abs() < 1.0e-13, "{label} = {value}");
+        }
+    }
+
+    #[test]
+    fn weighted_samples_agree_with_incremental_statistics() {
+        let mut general: GeneralStatistics = GeneralStatistics::new();
+        let mut incremental: IncrementalStatistics = IncrementalStatistics::new();
+        for (i, value) in DATA.iter().enumerate() {
+            let weight: Real = 1.0 + (i % 3) as Real;
+            general.add(*value, weight);
+            incremental.add(*value, weight);
+        }
+        let cases: [(Real, Real, &str); 4] = [
+            (general.mean(), incremental.mean(), "mean"),
+            (general.variance(), incremental.variance(), "variance"),
+            (general.skewness(), incremental.skewness(), "skewness"),
+            (general.kurtosis(), incremental.kurtosis(), "kurtosis"),
+        ];
+        for (value, expected, label) in cases {
+            assert!(
+                (value - expected).abs() < 1.0e-13,
+                "{label}: {value} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn percentiles() {
+        let mut statistics: GeneralStatistics = GeneralStatistics::new();
+        statistics.add_sequence(&DATA);
+        // Sorted: 2 3 3 4 4 4 5 5 6 7
+        let cases: [(Real, Real, Real); 5] = [
+            (0.1, 2.0, 7.0),
+            (0.25, 3.0, 5.0),
+            (0.5, 4.0, 4.0),
+            (0.9, 6.0, 3.0),
+            (1.0, 7.0, 2.0),
+        ];
+        for (percent, expected, expected_top) in cases {
+            assert_eq!(statistics.percentile(percent), expected, "{percent}");
+            assert_eq!(
+                statistics.top_percentile(percent),
+                expected_top,
+                "{percent}"
+            );
+        }
+        statistics.sort();
+        assert_eq!(statistics.data()[0], (2.0, 1.0));
+        assert_eq!(statistics.percentile(0.5), 4.0);
+
+        let (value, n): (Real, Size) = statistics.expectation_value(|x| x, |x| x >= 5.0);
+        assert_eq!((value, n), (5.75, 4));
+    }
+
+    #[test]
+    fn invalid_inputs_panic() {
+        let mut statistics: GeneralStatistics = GeneralStatistics::default();
+        assert!(panic::catch_unwind(|| statistics.mean()).is_err());
+        assert!(panic::catch_unwind(|| statistics.percentile(0.5)).is_err());
+        statistics.add_sequence(&[1.0, 2.0]);
+        let cases: [(Real, &str); 2] = [(0.0, "null percentile"), (1.5, "percentile above one")];
+        for (percent, label) in cases {
+            let result = panic::catch_unwind(|| statistics.percentile(percent));
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+        assert!(panic::catch_unwind(|| statistics.skewness()).is_err());
+        assert!(panic::catch_unwind(|| statistics.clone().add(1.0, -1.0)).is_err());
+
+        statistics.reset();
+        assert_eq!(statistics.samples(), 0);
+    }
+}
@@ -0,0 +1,285 @@
+use crate::math::statistics::sample_accumulator::SampleAccumulator;
+use crate::types::{Real, Size};
+
+/*
+Statistics accumulated one sample at a time (QuantLib
+IncrementalStatistics).
+
+Samples are not stored: weighted mean and variance are updated with
+West's algorithm, which avoids the cancellation of the naive
+sum-of-squares formula:
+    W_n = W_{n-1} + w
+    mean_n = mean_{n-1} + w / W_n (x - mean_{n-1})
+    M2_n = M2_{n-1} + w (x - mean_{n-1}) (x - mean_n)
+The variance is M2 / W with the n / (n - 1) bias correction. The third
+and fourth central moments are updated in the same way (P. Pebay, 2008),
+and skewness and excess kurtosis carry the usual small-sample
+corrections, as in GeneralStatistics. The downside variance only uses
+the samples below zero.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct IncrementalStatistics {
+    sample_number: Size,
+    sample_weight: Real,
+    mean: Real,
+    m2: Real,
+    m3: Real,
+    m4: Real,
+    downside_sample_number: Size,
+    downside_sample_weight: Real,
+    downside_quadratic_sum: Real,
+    min: Real,
+    max: Real,
+}
+impl IncrementalStatistics {
+    // Constructor
+    pub fn new() -> IncrementalStatistics {
+        IncrementalStatistics {
+            sample_number: 0,
+            sample_weight: 0.0,
+            mean: 0.0,
+            m2: 0.0,
+            m3: 0.0,
+            m4: 0.0,
+            downside_sample_number: 0,
+            downside_sample_weight: 0.0,
+            downside_quadratic_sum: 0.0,
+            min: Real::MAX,
+            max: Real::MIN,
+        }
+    }
+
+    // Modifiers
+    pub fn add(&mut self, value: Real, weight: Real) {
+        if weight < 0.0 {
+            panic!("negative weight ({weight}) not allowed");
+        }
+        let previous_weight: Real = self.sample_weight;
+        self.sample_number += 1;
+        self.sample_weight += weight;
+        if self.sample_weight > 0.0 {
+            let total: Real = self.sample_weight;
+            let delta: Real = value - self.mean;
+            let delta2: Real = delta * delta;
+            // Higher moments first, as they use the previous lower ones
+            self.m4 += delta2
+                * delta2
+                * previous_weight
+                * weight
+                * (previous_weight * previous_weight - previous_weight * weight + weight * weight)
+                / (total * total * total)
+                + 6.0 * delta2 * weight * weight * self.m2 / (total * total)
+                - 4.0 * delta * weight * self.m3 / total;
+            self.m3 += delta2 * delta * previous_weight * weight * (previous_weight - weight)
+                / (total * total)
+                - 3.0 * delta * weight * self.m2 / total;
+            self.mean += weight / total * delta;
+            self.m2 += weight * delta * (value - self.mean);
+        }
+        if value < 0.0 {
+            self.downside_sample_number += 1;
+            self.downside_sample_weight += weight;
+            self.downside_quadratic_sum += weight * value * value;
+        }
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+    pub fn add_sequence(&mut self, values: &[Real]) {
+        for value in values {
+            self.add(*value, 1.0);
+        }
+    }
+    pub fn reset(&mut self) {
+        *self = IncrementalStatistics::new();
+    }
+
+    // Inspectors
+    pub fn samples(&self) -> Size {
+        self.sample_number
+    }
+    pub fn weight_sum(&self) -> Real {
+        self.sample_weight
+    }
+    pub fn mean(&self) -> Real {
+        if self.sample_weight <= 0.0 {
+            panic!("sampleWeight = 0, insufficient");
+        }
+        self.mean
+    }
+    pub fn variance(&self) -> Real {
+        if self.sample_weight <= 0.0 {
+            panic!("sampleWeight = 0, insufficient");
+        }
+        if self.sample_number <= 1 {
+            panic!("sample number <= 1, insufficient");
+        }
+        let n: Real = self.sample_number as Real;
+        (self.m2 / self.sample_weight * n / (n - 1.0)).max(0.0)
+    }
+    pub fn standard_deviation(&self) -> Real {
+        self.variance().sqrt()
+    }
+    // Standard error of the mean: sqrt(variance / samples)
+    pub fn error_estimate(&self) -> Real {
+        (self.variance() / self.sample_number as Real).sqrt()
+    }
+    pub fn skewness(&self) -> Real {
+        if self.sample_number <= 2 {
+            panic!("sample number <= 2, insufficient");
+        }
+        let n: Real = self.sample_number as Real;
+        let g1: Real = (self.m3 / self.sample_weight) / (self.m2 / self.sample_weight).powf(1.5);
+        g1 * (n * (n - 1.0)).sqrt() / (n - 2.0)
+    }
+    // Excess kurtosis
+    pub fn kurtosis(&self) -> Real {
+        if self.sample_number <= 3 {
+            panic!("sample number <= 3, insufficient");
+        }
+        let n: Real = self.sample_number as Real;
+        let m2: Real = self.m2 / self.sample_weight;
+        let g2: Real = self.m4 / self.sample_weight / (m2 * m2) - 3.0;
+        (n - 1.0) / ((n - 2.0) * (n - 3.0)) * ((n + 1.0) * g2 + 6.0)
+    }
+    // Variance of the samples below zero around zero; null if there are none
+    pub fn downside_variance(&self) -> Real {
+        if self.downside_sample_weight == 0.0 {
+            if self.sample_weight <= 0.0 {
+                panic!("sampleWeight = 0, insufficient");
+            }
+            return 0.0;
+        }
+        if self.downside_sample_number <= 1 {
+            panic!("downside sample number <= 1, insufficient");
+        }
+        let n: Real = self.downside_sample_number as Real;
+        n / (n - 1.0) * self.downside_quadratic_sum / self.downside_sample_weight
+    }
+    pub fn downside_deviation(&self) -> Real {
+        self.downside_variance().sqrt()
+    }
+    pub fn min(&self) -> Real {
+        if self.sample_number == 0 {
+            panic!("empty sample set");
+        }
+        self.min
+    }
+    pub fn max(&self) -> Real {
+        if self.sample_number == 0 {
+            panic!("empty sample set");
+        }
+        self.max
+    }
+}
+impl Default for IncrementalStatistics {
+    fn default() -> Self {
+        IncrementalStatistics::new()
+    }
+}
+impl SampleAccumulator for IncrementalStatistics {
+    fn add(&mut self, value: Real, weight: Real) {
+        IncrementalStatistics::add(self, value, weight)
+    }
+    fn reset(&mut self) {
+        IncrementalStatistics::reset(self)
+    }
+    fn samples(&self) -> Size {
+        IncrementalStatistics::samples(self)
+    }
+    fn weight_sum(&self) -> Real {
+        IncrementalStatistics::weight_sum(self)
+    }
+    fn mean(&self) -> Real {
+        IncrementalStatistics::mean(self)
+    }
+    fn variance(&self) -> Real {
+        IncrementalStatistics::variance(self)
+    }
+    fn min(&self) -> Real {
+        IncrementalStatistics::min(self)
+    }
+    fn max(&self) -> Real {
+        IncrementalStatistics::max(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    #[test]
+    fn unweighted_statistics() {
+        let mut statistics: IncrementalStatistics = IncrementalStatistics::new();
+        statistics.add_sequence(&[3.0, 4.0, 5.0, 2.0, 3.0, 4.0, 5.0, 6.0, 4.0, 7.0]);
+
+        let cases: [(Real, Real, &str); 6] = [
+            (statistics.samples() as Real, 10.0, "samples"),
+            (statistics.weight_sum(), 10.0, "weight sum"),
+            (statistics.mean(), 4.3, "mean"),
+            (statistics.variance(), 2.2333333333333333, "variance"),
+            (statistics.min(), 2.0, "min"),
+            (statistics.max(), 7.0, "max"),
+        ];
+        for (value, expected, label) in cases {
+            assert!((value - expected).abs() < 1.0e-14, "{label} = {value}");
+        }
+        assert!(
+            (statistics.error_estimate() - (2.2333333333333333_f64 / 10.0).sqrt()).abs() < 1.0e-14
+        );
+    }
+
+    #[test]
+    fn higher_moments() {
+        let mut statistics: IncrementalStatistics = IncrementalStatistics::new();
+        statistics.add_sequence(&[3.0, 4.0, 5.0, 2.0, 3.0, 4.0, 5.0, 6.0, 4.0, 7.0]);
+        assert!((statistics.skewness() - 0.3595430714067973).abs() < 1.0e-13);
+        assert!((statistics.kurtosis() + 0.1517996372084148).abs() < 1.0e-13);
+        assert_eq!(statistics.downside_variance(), 0.0);
+
+        // Integer weights give the moments of the repeated samples
+        let mut weighted: IncrementalStatistics = IncrementalStatistics::new();
+        let mut repeated: IncrementalStatistics = IncrementalStatistics::new();
+        for (value, weight) in [(-1.0, 2.0), (0.5, 1.0), (2.0, 3.0), (-3.0, 1.0)] {
+            weighted.add(value, weight);
+            for _ in 0..weight as Size {
+                repeated.add(value, 1.0);
+            }
+        }
+        let g1 =
+            |s: &IncrementalStatistics| s.m3 / s.sample_weight / (s.m2 / s.sample_weight).powf(1.5);
+        let g2 = |s: &IncrementalStatistics| s.m4 * s.sample_weight / (s.m2 * s.m2);
+        assert!((g1(&weighted) - g1(&repeated)).abs() < 1.0e-14);
+        assert!((g2(&weighted) - g2(&repeated)).abs() < 1.0e-14);
+        // Downside samples: -1 (weight 2) and -3 (weight 1)
+        assert!((weighted.downside_variance() - 2.0 * 11.0 / 3.0).abs() < 1.0e-14);
+    }
+
+    #[test]
+    fn weights_act_as_repeated_samples_for_the_mean() {
+        let mut weighted: IncrementalStatistics = IncrementalStatistics::new();
+        weighted.add(1.0, 1.0);
+        weighted.add(4.0, 3.0);
+        assert!((weighted.mean() - 3.25).abs() < 1.0e-15);
+        assert_eq!(weighted.weight_sum(), 4.0);
+    }
+
+    #[test]
+    fn large_offset_does_not_lose_the_variance() {
+        let mut statistics: IncrementalStatistics = IncrementalStatistics::new();
+        statistics.add_sequence(&[1.0e9 + 1.0, 1.0e9 + 2.0, 1.0e9 + 3.0]);
+        assert!((statistics.variance() - 1.0).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn insufficient_samples_panic() {
+        let mut statistics: IncrementalStatistics = IncrementalStatistics::default();
+        assert!(panic::catch_unwind(|| statistics.mean()).is_err());
+        statistics.add(1.0, 1.0);
+        assert!(panic::catch_unwind(|| statistics.variance()).is_err());
+        assert!(panic::catch_unwind(move || statistics.add(1.0, -1.0)).is_err());
+
+        statistics.reset();
+        assert_eq!(statistics.samples(), 0);
+    }
+}
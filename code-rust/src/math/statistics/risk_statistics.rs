@@ -0,0 +1,224 @@
+use crate::math::statistics::general_statistics::GeneralStatistics;
+use crate::math::statistics::sample_accumulator::SampleAccumulator;
+use crate::types::{Real, Size};
+
+/*
+Risk measures over a stored set of weighted samples (QuantLib
+RiskStatistics), read as profits and losses: negative values are
+losses.
+    - regret(target): variance below target, E[(x - target)^2 | x < target]
+      with the n / (n - 1) correction over the n samples below target;
+      semi-variance and downside variance are the regrets of the mean
+      and of zero
+    - value_at_risk(p) = -min(percentile(1 - p), 0), the loss not
+      exceeded with probability p
+    - expected_shortfall(p) = -min(E[x | x < -VaR(p)], 0)
+    - potential_upside(p) = max(percentile(p), 0)
+    - shortfall(target): weighted probability of ending below target
+    - average_shortfall(target) = E[target - x | x < target]
+Confidence levels p must lie in [0.9, 1). The underlying
+GeneralStatistics gives the moments and percentiles.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct RiskStatistics {
+    statistics: GeneralStatistics,
+}
+impl RiskStatistics {
+    // Constructor
+    pub fn new() -> RiskStatistics {
+        RiskStatistics {
+            statistics: GeneralStatistics::new(),
+        }
+    }
+
+    // Modifiers
+    pub fn add(&mut self, value: Real, weight: Real) {
+        self.statistics.add(value, weight);
+    }
+    pub fn add_sequence(&mut self, values: &[Real]) {
+        self.statistics.add_sequence(values);
+    }
+    pub fn reset(&mut self) {
+        self.statistics.reset();
+    }
+    pub fn sort(&mut self) {
+        self.statistics.sort();
+    }
+
+    // Inspectors
+    pub fn statistics(&self) -> &GeneralStatistics {
+        &self.statistics
+    }
+
+    pub fn regret(&self, target: Real) -> Real {
+        let (x, n): (Real, Size) = self
+            .statistics
+            .expectation_value(|x| (x - target) * (x - target), |x| x < target);
+        if n <= 1 {
+            panic!("samples under target <= 1, insufficient");
+        }
+        n as Real / (n as Real - 1.0) * x
+    }
+    pub fn semi_variance(&self) -> Real {
+        self.regret(self.statistics.mean())
+    }
+    pub fn semi_deviation(&self) -> Real {
+        self.semi_variance().sqrt()
+    }
+    pub fn downside_variance(&self) -> Real {
+        self.regret(0.0)
+    }
+    pub fn downside_deviation(&self) -> Real {
+        self.downside_variance().sqrt()
+    }
+    pub fn potential_upside(&self, percentile: Real) -> Real {
+        detail::check_confidence_level(percentile);
+        self.statistics.percentile(percentile).max(0.0)
+    }
+    pub fn value_at_risk(&self, percentile: Real) -> Real {
+        detail::check_confidence_level(percentile);
+        -self.statistics.percentile(1.0 - percentile).min(0.0)
+    }
+    pub fn expected_shortfall(&self, percentile: Real) -> Real {
+        let target: Real = -self.value_at_risk(percentile);
+        let (x, n): (Real, Size) = self.statistics.expectation_value(|x| x, |x| x < target);
+        if n == 0 {
+            panic!("no data below the target");
+        }
+        -x.min(0.0)
+    }
+    pub fn shortfall(&self, target: Real) -> Real {
+        if self.statistics.samples() == 0 {
+            panic!("empty sample set");
+        }
+        self.statistics
+            .expectation_value(|x| if x < target { 1.0 } else { 0.0 }, |_| true)
+            .0
+    }
+    pub fn average_shortfall(&self, target: Real) -> Real {
+        let (x, n): (Real, Size) = self
+            .statistics
+            .expectation_value(|x| target - x, |x| x < target);
+        if n == 0 {
+            panic!("no data below the target");
+        }
+        x
+    }
+}
+impl Default for RiskStatistics {
+    fn default() -> Self {
+        RiskStatistics::new()
+    }
+}
+impl SampleAccumulator for RiskStatistics {
+    fn add(&mut self, value: Real, weight: Real) {
+        RiskStatistics::add(self, value, weight)
+    }
+    fn reset(&mut self) {
+        RiskStatistics::reset(self)
+    }
+    fn samples(&self) -> Size {
+        self.statistics.samples()
+    }
+    fn weight_sum(&self) -> Real {
+        self.statistics.weight_sum()
+    }
+    fn mean(&self) -> Real {
+        self.statistics.mean()
+    }
+    fn variance(&self) -> Real {
+        self.statistics.variance()
+    }
+    fn min(&self) -> Real {
+        self.statistics.min()
+    }
+    fn max(&self) -> Real {
+        self.statistics.max()
+    }
+}
+
+pub(crate) mod detail {
+    use super::*;
+
+    pub(crate) fn check_confidence_level(percentile: Real) {
+        if !(0.9..1.0).contains(&percentile) {
+            panic!("percentile ({percentile}) out of range [0.9, 1.0)");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    // Profits and losses -50, -49, ..., 49
+    fn uniform_pnl() -> RiskStatistics {
+        let mut statistics: RiskStatistics = RiskStatistics::new();
+        for i in -50..50 {
+            statistics.add(i as Real, 1.0);
+        }
+        statistics.sort();
+        statistics
+    }
+
+    #[test]
+    fn risk_measures_of_a_uniform_sample() {
+        let statistics: RiskStatistics = uniform_pnl();
+        let cases: [(Real, Real, &str); 8] = [
+            (statistics.value_at_risk(0.975), 48.0, "value at risk"),
+            (
+                statistics.expected_shortfall(0.975),
+                49.5,
+                "expected shortfall",
+            ),
+            (statistics.potential_upside(0.975), 47.0, "potential upside"),
+            (statistics.shortfall(0.0), 0.5, "shortfall"),
+            (statistics.average_shortfall(0.0), 25.5, "average shortfall"),
+            (
+                statistics.downside_variance(),
+                42925.0 / 49.0,
+                "downside variance",
+            ),
+            (statistics.semi_variance(), 41662.5 / 49.0, "semi-variance"),
+            (statistics.statistics().mean(), -0.5, "mean"),
+        ];
+        for (value, expected, label) in cases {
+            assert!((value - expected).abs() < 1.0e-12, "{label} = {value}");
+        }
+        assert!((statistics.downside_deviation() - (42925.0_f64 / 49.0).sqrt()).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn weights_shift_the_value_at_risk() {
+        // A 10% chance of losing 100, otherwise a gain of 1
+        let mut statistics: RiskStatistics = RiskStatistics::new();
+        statistics.add(-100.0, 0.1);
+        statistics.add(1.0, 0.9);
+        assert_eq!(statistics.value_at_risk(0.95), 100.0);
+        assert_eq!(statistics.value_at_risk(0.9), 100.0);
+        assert!((statistics.shortfall(0.0) - 0.1).abs() < 1.0e-15);
+
+        // No losses: null value at risk
+        let mut gains: RiskStatistics = RiskStatistics::default();
+        gains.add_sequence(&[1.0, 2.0, 3.0]);
+        assert_eq!(gains.value_at_risk(0.99), 0.0);
+    }
+
+    #[test]
+    fn invalid_inputs_panic() {
+        let statistics: RiskStatistics = uniform_pnl();
+        let cases: [(Real, &str); 3] = [
+            (0.5, "confidence level below 0.9"),
+            (1.0, "confidence level of one"),
+            (-0.95, "negative confidence level"),
+        ];
+        for (percentile, label) in cases {
+            let result = panic::catch_unwind(|| statistics.value_at_risk(percentile));
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+        assert!(panic::catch_unwind(|| statistics.regret(-50.0)).is_err());
+        assert!(panic::catch_unwind(|| statistics.average_shortfall(-60.0)).is_err());
+        assert!(panic::catch_unwind(|| RiskStatistics::new().shortfall(0.0)).is_err());
+    }
+}
@@ -0,0 +1,34 @@
+use crate::types::{Real, Size};
+
+/*
+Common interface of the statistics accumulators, so that Monte Carlo
+models, sequence and convergence statistics can be fed by any of them
+(QuantLib uses the statistics class as a template parameter instead).
+
+IncrementalStatistics keeps running moments only; GeneralStatistics and
+RiskStatistics store the samples and also give percentiles and tail
+measures.
+*/
+pub trait SampleAccumulator: Default {
+    fn add(&mut self, value: Real, weight: Real);
+    fn reset(&mut self);
+    fn samples(&self) -> Size;
+    fn weight_sum(&self) -> Real;
+    fn mean(&self) -> Real;
+    fn variance(&self) -> Real;
+    fn min(&self) -> Real;
+    fn max(&self) -> Real;
+
+    fn add_sequence(&mut self, values: &[Real]) {
+        for value in values {
+            self.add(*value, 1.0);
+        }
+    }
+    fn standard_deviation(&self) -> Real {
+        self.variance().sqrt()
+    }
+    // Standard error of the mean: sqrt(variance / samples)
+    fn error_estimate(&self) -> Real {
+        (self.variance() / self.samples() as Real).sqrt()
+    }
+}
@@ -0,0 +1,232 @@
+use crate::math::matrix::Matrix;
+use crate::math::statistics::incremental_statistics::IncrementalStatistics;
+use crate::math::statistics::sample_accumulator::SampleAccumulator;
+use crate::types::{Real, Size};
+
+/*
+Statistics of vector samples (QuantLib SequenceStatistics): one
+accumulator per component, plus the covariance and correlation between
+components.
+
+The co-moments are updated with the same weighted West recursion as
+IncrementalStatistics, rather than from sums of products, so that large
+means do not wipe out the covariance:
+    mean_n = mean_{n-1} + w / W_n (x - mean_{n-1})
+    C_n = C_{n-1} + w (x - mean_{n-1}) (x - mean_n)^T
+The covariance is C / W with the n / (n - 1) bias correction. Any
+SampleAccumulator can be used per component, e.g. RiskStatistics for
+component-wise value at risk.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct SequenceStatistics<S: SampleAccumulator = IncrementalStatistics> {
+    statistics: Vec<S>,
+    sample_number: Size,
+    sample_weight: Real,
+    mean: Vec<Real>,
+    comoments: Matrix,
+}
+impl<S: SampleAccumulator> SequenceStatistics<S> {
+    // Constructor
+    pub fn new(dimension: Size) -> SequenceStatistics<S> {
+        if dimension == 0 {
+            panic!("null dimension");
+        }
+        SequenceStatistics {
+            statistics: (0..dimension).map(|_| S::default()).collect(),
+            sample_number: 0,
+            sample_weight: 0.0,
+            mean: vec![0.0; dimension],
+            comoments: Matrix::new(dimension, dimension, 0.0),
+        }
+    }
+
+    // Modifiers
+    pub fn add(&mut self, values: &[Real], weight: Real) {
+        if values.len() != self.size() {
+            panic!(
+                "sample size ({}) differs from the dimension ({})",
+                values.len(),
+                self.size()
+            );
+        }
+        for (statistics, value) in self.statistics.iter_mut().zip(values) {
+            statistics.add(*value, weight);
+        }
+        self.sample_number += 1;
+        self.sample_weight += weight;
+        if self.sample_weight > 0.0 {
+            let delta: Vec<Real> = values.iter().zip(&self.mean).map(|(x, m)| x - m).collect();
+            for (mean, d) in self.mean.iter_mut().zip(&delta) {
+                *mean += weight / self.sample_weight * d;
+            }
+            for (i, d) in delta.iter().enumerate() {
+                for (j, (x, m)) in values.iter().zip(&self.mean).enumerate() {
+                    self.comoments[i][j] += weight * d * (x - m);
+                }
+            }
+        }
+    }
+    pub fn reset(&mut self) {
+        *self = SequenceStatistics::new(self.size());
+    }
+
+    // Inspectors
+    pub fn size(&self) -> Size {
+        self.statistics.len()
+    }
+    // Accumulator of the i-th component
+    pub fn statistics(&self, i: Size) -> &S {
+        &self.statistics[i]
+    }
+    pub fn samples(&self) -> Size {
+        self.sample_number
+    }
+    pub fn weight_sum(&self) -> Real {
+        self.sample_weight
+    }
+    pub fn mean(&self) -> Vec<Real> {
+        self.statistics.iter().map(|s| s.mean()).collect()
+    }
+    pub fn variance(&self) -> Vec<Real> {
+        self.statistics.iter().map(|s| s.variance()).collect()
+    }
+    pub fn standard_deviation(&self) -> Vec<Real> {
+        self.statistics
+            .iter()
+            .map(|s| s.standard_deviation())
+            .collect()
+    }
+    pub fn error_estimate(&self) -> Vec<Real> {
+        self.statistics.iter().map(|s| s.error_estimate()).collect()
+    }
+    pub fn min(&self) -> Vec<Real> {
+        self.statistics.iter().map(|s| s.min()).collect()
+    }
+    pub fn max(&self) -> Vec<Real> {
+        self.statistics.iter().map(|s| s.max()).collect()
+    }
+    pub fn covariance(&self) -> Matrix {
+        if self.sample_weight <= 0.0 {
+            panic!("sampleWeight = 0, insufficient");
+        }
+        if self.sample_number <= 1 {
+            panic!("sample number <= 1, insufficient");
+        }
+        let n: Real = self.sample_number as Real;
+        &self.comoments * (n / (n - 1.0) / self.sample_weight)
+    }
+    pub fn correlation(&self) -> Matrix {
+        let mut correlation: Matrix = self.covariance();
+        let deviations: Vec<Real> = correlation.diagonal().iter().map(|v| v.sqrt()).collect();
+        for i in 0..self.size() {
+            for j in 0..self.size() {
+                correlation[i][j] = if i == j {
+                    1.0
+                } else if deviations[i] == 0.0 || deviations[j] == 0.0 {
+                    0.0
+                } else {
+                    correlation[i][j] / (deviations[i] * deviations[j])
+                };
+            }
+        }
+        correlation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::statistics::risk_statistics::RiskStatistics;
+    use std::panic;
+
+    #[test]
+    fn covariance_and_correlation() {
+        let mut statistics: SequenceStatistics = SequenceStatistics::new(3);
+        let samples: [[Real; 3]; 4] = [
+            [1.0, 2.0, 4.0],
+            [2.0, 4.0, 3.0],
+            [3.0, 6.0, 2.0],
+            [4.0, 8.0, 1.0],
+        ];
+        for sample in &samples {
+            statistics.add(sample, 1.0);
+        }
+        assert_eq!(statistics.samples(), 4);
+        assert_eq!(statistics.mean(), vec![2.5, 5.0, 2.5]);
+        assert_eq!(statistics.min(), vec![1.0, 2.0, 1.0]);
+
+        // The second component is twice the first, the third is 5 minus it
+        let unit: Real = 5.0 / 3.0;
+        let expected_covariance: Matrix = Matrix::from_rows(&[
+            vec![unit, 2.0 * unit, -unit],
+            vec![2.0 * unit, 4.0 * unit, -2.0 * unit],
+            vec![-unit, -2.0 * unit, unit],
+        ]);
+        assert!(
+            statistics
+                .covariance()
+                .max_abs_difference(&expected_covariance)
+                < 1.0e-14
+        );
+        let expected_correlation: Matrix = Matrix::from_rows(&[
+            vec![1.0, 1.0, -1.0],
+            vec![1.0, 1.0, -1.0],
+            vec![-1.0, -1.0, 1.0],
+        ]);
+        assert!(
+            statistics
+                .correlation()
+                .max_abs_difference(&expected_correlation)
+                < 1.0e-14
+        );
+        for (variance, i) in statistics.variance().iter().zip(0..3) {
+            assert!((variance - statistics.covariance()[i][i]).abs() < 1.0e-14);
+        }
+    }
+
+    #[test]
+    fn weighted_covariance_matches_repeated_samples() {
+        let mut weighted: SequenceStatistics = SequenceStatistics::new(2);
+        let mut repeated: SequenceStatistics = SequenceStatistics::new(2);
+        let samples: [([Real; 2], Size); 3] = [
+            ([1.0e6, 3.0], 2),
+            ([1.0e6 + 1.0, 1.0], 1),
+            ([1.0e6 + 3.0, 0.0], 3),
+        ];
+        for (sample, weight) in &samples {
+            weighted.add(sample, *weight as Real);
+            for _ in 0..*weight {
+                repeated.add(sample, 1.0);
+            }
+        }
+        // Same weighted second moments; the bias correction uses the sample count
+        let ratio: Real = (3.0 / 2.0) / (6.0 / 5.0);
+        let expected: Matrix = &repeated.covariance() * ratio;
+        assert!(weighted.covariance().max_abs_difference(&expected) < 1.0e-9);
+    }
+
+    #[test]
+    fn component_statistics_can_be_risk_statistics() {
+        let mut statistics: SequenceStatistics<RiskStatistics> = SequenceStatistics::new(2);
+        for i in -50..50 {
+            statistics.add(&[i as Real, 2.0 * i as Real], 1.0);
+        }
+        assert_eq!(statistics.statistics(0).value_at_risk(0.975), 48.0);
+        assert_eq!(statistics.statistics(1).value_at_risk(0.975), 96.0);
+
+        statistics.reset();
+        assert_eq!((statistics.samples(), statistics.size()), (0, 2));
+    }
+
+    #[test]
+    fn invalid_inputs_panic() {
+        let mut statistics: SequenceStatistics = SequenceStatistics::new(2);
+        assert!(panic::catch_unwind(|| statistics.covariance()).is_err());
+        statistics.add(&[1.0, 2.0], 1.0);
+        assert!(panic::catch_unwind(|| statistics.covariance()).is_err());
+        assert!(panic::catch_unwind(move || statistics.add(&[1.0], 1.0)).is_err());
+        assert!(
+            panic::catch_unwind(|| SequenceStatistics::<IncrementalStatistics>::new(0)).is_err()
+        );
+    }
+}
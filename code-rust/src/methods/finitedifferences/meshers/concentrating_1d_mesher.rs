@@ -0,0 +1,150 @@
+use crate::math::comparison::close;
+use crate::math::interpolations::linear_interpolation::LinearInterpolation;
+use crate::methods::finitedifferences::meshers::fdm1d_mesher::Fdm1dMesher;
+use crate::types::{Real, Size};
+
+/*
+Grid points concentrated around a critical point (QuantLib
+Concentrating1dMesher), e.g. the strike where the payoff has a kink.
+
+With c_point = (c, density) the points are
+    x_i = c + d sinh(c1 (1 - u_i) + c2 u_i),  u_i = i / (n - 1)
+where d = density * (end - start), c1 = asinh((start - c) / d) and
+c2 = asinh((end - c) / d): the smaller the density, the stronger the
+concentration. With require_c_point the u_i are remapped so that c is
+exactly one of the grid points.
+Without a critical point the grid is uniform.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct Concentrating1dMesher {
+    locations: Vec<Real>,
+}
+impl Concentrating1dMesher {
+    // Constructor
+    pub fn new(
+        start: Real,
+        end: Real,
+        size: Size,
+        c_point: Option<(Real, Real)>,
+        require_c_point: bool,
+    ) -> Concentrating1dMesher {
+        if end <= start {
+            panic!("end ({end}) must be larger than start ({start})");
+        }
+        if size < 2 {
+            panic!("at least two grid points are required ({size} given)");
+        }
+        let dx: Real = 1.0 / (size - 1) as Real;
+        let mut locations: Vec<Real> = vec![0.0; size];
+
+        match c_point {
+            Some((c_point, density)) => {
+                if c_point < start || c_point > end {
+                    panic!("cPoint ({c_point}) must be between start ({start}) and end ({end})");
+                }
+                if density <= 0.0 {
+                    panic!("density ({density}) must be positive");
+                }
+                let density: Real = density * (end - start);
+                let c1: Real = ((start - c_point) / density).asinh();
+                let c2: Real = ((end - c_point) / density).asinh();
+
+                let transform: Option<LinearInterpolation> = if require_c_point {
+                    let mut u: Vec<Real> = vec![0.0];
+                    let mut z: Vec<Real> = vec![0.0];
+                    if !close(c_point, start) && !close(c_point, end) {
+                        // Grid index closest to the critical point, kept inside
+                        let z0: Real = -c1 / (c2 - c1);
+                        let i0: Real = (z0 * (size - 1) as Real)
+                            .round()
+                            .clamp(1.0, (size - 2) as Real);
+                        u.push(i0 / (size - 1) as Real);
+                        z.push(z0);
+                    }
+                    u.push(1.0);
+                    z.push(1.0);
+                    Some(LinearInterpolation::new(&u, &z))
+                } else {
+                    None
+                };
+
+                for (i, x) in locations.iter_mut().enumerate().take(size - 1).skip(1) {
+                    let li: Real = match &transform {
+                        Some(transform) => transform.value(i as Real * dx),
+                        None => i as Real * dx,
+                    };
+                    *x = c_point + density * (c1 * (1.0 - li) + c2 * li).sinh();
+                }
+            }
+            None => {
+                for (i, x) in locations.iter_mut().enumerate().take(size - 1).skip(1) {
+                    *x = start + i as Real * dx * (end - start);
+                }
+            }
+        }
+        locations[0] = start;
+        locations[size - 1] = end;
+        Concentrating1dMesher { locations }
+    }
+}
+impl Fdm1dMesher for Concentrating1dMesher {
+    fn locations(&self) -> &[Real] {
+        &self.locations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    #[test]
+    fn points_concentrate_around_the_critical_point() {
+        let mesher: Concentrating1dMesher =
+            Concentrating1dMesher::new(0.0, 10.0, 51, Some((4.0, 0.05)), false);
+        let x: &[Real] = mesher.locations();
+
+        assert_eq!(x[0], 0.0);
+        assert_eq!(x[50], 10.0);
+        assert!(x.windows(2).all(|w| w[1] > w[0]));
+        // Spacing is smallest next to the critical point
+        let i: Size = x.partition_point(|xi| *xi < 4.0);
+        assert!(mesher.dplus(i) < 0.5 * mesher.dplus(0));
+        assert!(mesher.dplus(i) < 0.5 * mesher.dminus(50));
+    }
+
+    #[test]
+    fn required_critical_point_is_on_the_grid() {
+        for c in [1.234, 5.0, 8.9] {
+            let mesher: Concentrating1dMesher =
+                Concentrating1dMesher::new(0.0, 10.0, 41, Some((c, 0.1)), true);
+            let x: &[Real] = mesher.locations();
+            assert!(
+                x.iter().any(|xi| (xi - c).abs() < 1.0e-12),
+                "{c} not on the grid"
+            );
+            assert!(x.windows(2).all(|w| w[1] > w[0]));
+        }
+    }
+
+    #[test]
+    fn no_critical_point_gives_a_uniform_grid() {
+        let mesher: Concentrating1dMesher = Concentrating1dMesher::new(0.0, 1.0, 5, None, false);
+        assert_eq!(mesher.locations(), &[0.0, 0.25, 0.5, 0.75, 1.0]);
+    }
+
+    #[test]
+    #[allow(clippy::type_complexity)]
+    fn new_panics_on_invalid_inputs() {
+        let cases: [(Real, Real, Option<(Real, Real)>, &str); 3] = [
+            (1.0, 0.0, None, "end before start"),
+            (0.0, 1.0, Some((2.0, 0.1)), "critical point outside"),
+            (0.0, 1.0, Some((0.5, 0.0)), "zero density"),
+        ];
+        for (start, end, c_point, label) in cases {
+            let result =
+                panic::catch_unwind(|| Concentrating1dMesher::new(start, end, 10, c_point, true));
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::types::{Real, Size};
+
+/*
+One-dimensional mesher (QuantLib Fdm1dMesher): the sorted grid points
+x_0 < ... < x_{n-1} of a finite-difference scheme.
+
+dplus(i) = x_{i+1} - x_i and dminus(i) = x_i - x_{i-1} are the grid
+spacings the derivative operators are built from.
+*/
+pub trait Fdm1dMesher {
+    fn locations(&self) -> &[Real];
+
+    fn size(&self) -> Size {
+        self.locations().len()
+    }
+    fn location(&self, i: Size) -> Real {
+        self.locations()[i]
+    }
+    fn dplus(&self, i: Size) -> Real {
+        self.locations()[i + 1] - self.locations()[i]
+    }
+    fn dminus(&self, i: Size) -> Real {
+        self.locations()[i] - self.locations()[i - 1]
+    }
+}
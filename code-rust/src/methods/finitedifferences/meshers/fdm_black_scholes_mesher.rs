@@ -0,0 +1,97 @@
+use crate::math::distributions::normal_distribution::InverseCumulativeNormal;
+use crate::methods::finitedifferences::meshers::concentrating_1d_mesher::Concentrating1dMesher;
+use crate::methods::finitedifferences::meshers::fdm1d_mesher::Fdm1dMesher;
+use crate::methods::finitedifferences::meshers::uniform_1d_mesher::Uniform1dMesher;
+use crate::types::{Rate, Real, Size, Time, Volatility};
+
+/*
+Log-spot mesher for Black-Scholes problems (QuantLib
+FdmBlackScholesMesher), with constant rates and volatility.
+
+The grid covers x = ln(S) over
+    [ln(F_min) - k sigma sqrt(T),  ln(F_max) + k sigma sqrt(T)]
+where F_min and F_max are the smallest and largest forward over [0, T],
+and k = 1.5 * N^{-1}(1 - 1e-4): the probability mass outside the
+grid is negligible. With c_point = (S*, density) the points are
+concentrated around ln(S*) (usually the strike).
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct FdmBlackScholesMesher {
+    locations: Vec<Real>,
+}
+impl FdmBlackScholesMesher {
+    const EPS: Real = 0.0001;
+    const SCALE_FACTOR: Real = 1.5;
+
+    // Constructor
+    pub fn new(
+        size: Size,
+        spot: Real,
+        risk_free_rate: Rate,
+        dividend_yield: Rate,
+        volatility: Volatility,
+        maturity: Time,
+        c_point: Option<(Real, Real)>,
+    ) -> FdmBlackScholesMesher {
+        if spot <= 0.0 {
+            panic!("negative or null underlying given ({spot})");
+        }
+        if maturity <= 0.0 || volatility <= 0.0 {
+            panic!("positive maturity ({maturity}) and volatility ({volatility}) required");
+        }
+        let forward: Real = spot * ((risk_free_rate - dividend_yield) * maturity).exp();
+        let (minimum, maximum): (Real, Real) = (spot.min(forward), spot.max(forward));
+
+        let norm_inv_eps: Real =
+            InverseCumulativeNormal::default().value(1.0 - FdmBlackScholesMesher::EPS);
+        let sigma_sqrt_t: Real = volatility * maturity.sqrt();
+        let width: Real = sigma_sqrt_t * norm_inv_eps * FdmBlackScholesMesher::SCALE_FACTOR;
+        let x_min: Real = minimum.ln() - width;
+        let x_max: Real = maximum.ln() + width;
+
+        let locations: Vec<Real> = match c_point {
+            Some((c, density)) if c > 0.0 && (x_min..=x_max).contains(&c.ln()) => {
+                Concentrating1dMesher::new(x_min, x_max, size, Some((c.ln(), density)), false)
+                    .locations()
+                    .to_vec()
+            }
+            _ => Uniform1dMesher::new(x_min, x_max, size)
+                .locations()
+                .to_vec(),
+        };
+        FdmBlackScholesMesher { locations }
+    }
+}
+impl Fdm1dMesher for FdmBlackScholesMesher {
+    fn locations(&self) -> &[Real] {
+        &self.locations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_covers_the_spot_and_forward_distribution() {
+        let mesher: FdmBlackScholesMesher =
+            FdmBlackScholesMesher::new(100, 100.0, 0.05, 0.0, 0.2, 1.0, None);
+        let x: &[Real] = mesher.locations();
+        let width: Real = 0.2 * 3.719016485455709 * 1.5;
+
+        assert_eq!(mesher.size(), 100);
+        assert!((x[0] - (100.0_f64.ln() - width)).abs() < 1.0e-8);
+        assert!((x[99] - (100.0_f64.ln() + 0.05 + width)).abs() < 1.0e-8);
+        assert!((mesher.dplus(0) - mesher.dplus(50)).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn grid_concentrates_around_the_strike() {
+        let mesher: FdmBlackScholesMesher =
+            FdmBlackScholesMesher::new(100, 100.0, 0.05, 0.02, 0.2, 1.0, Some((110.0, 0.1)));
+        let x: &[Real] = mesher.locations();
+        let i: Size = x.partition_point(|xi| *xi < 110.0_f64.ln());
+        assert!(mesher.dplus(i) < mesher.dplus(0));
+        assert!(mesher.dplus(i) < mesher.dminus(99));
+    }
+}
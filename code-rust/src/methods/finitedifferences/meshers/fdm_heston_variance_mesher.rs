@@ -0,0 +1,84 @@
+use crate::methods::finitedifferences::meshers::concentrating_1d_mesher::Concentrating1dMesher;
+use crate::methods::finitedifferences::meshers::fdm1d_mesher::Fdm1dMesher;
+use crate::types::{Real, Size, Time, Volatility};
+
+/*
+Variance mesher for the Heston model (QuantLib FdmHestonVarianceMesher),
+with a simpler upper bound than QuantLib's, which integrates the
+transition density.
+
+The variance follows dv = kappa (theta - v) dt + sigma sqrt(v) dw, whose
+mean and variance at T are
+    m   = theta + (v0 - theta) e
+    s^2 = v0 sigma^2 e (1 - e) / kappa + theta sigma^2 (1 - e)^2 / (2 kappa)
+with e = exp(-kappa T). The grid covers [0, v_max] with
+    v_max = max(max(v0, m) + 5 s, 2 max(v0, theta))
+and is concentrated around v0, which is one of the grid points.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct FdmHestonVarianceMesher {
+    locations: Vec<Real>,
+}
+impl FdmHestonVarianceMesher {
+    const DENSITY: Real = 0.2;
+    const STD_DEVS: Real = 5.0;
+
+    // Constructor
+    pub fn new(
+        size: Size,
+        v0: Real,
+        kappa: Real,
+        theta: Real,
+        sigma: Volatility,
+        maturity: Time,
+    ) -> FdmHestonVarianceMesher {
+        if v0 <= 0.0 || theta <= 0.0 {
+            panic!("positive v0 ({v0}) and theta ({theta}) required");
+        }
+        if kappa <= 0.0 {
+            panic!("positive kappa ({kappa}) required");
+        }
+        if maturity <= 0.0 {
+            panic!("positive maturity ({maturity}) required");
+        }
+        let e: Real = (-kappa * maturity).exp();
+        let mean: Real = theta + (v0 - theta) * e;
+        let variance: Real = v0 * sigma * sigma * e * (1.0 - e) / kappa
+            + theta * sigma * sigma * (1.0 - e) * (1.0 - e) / (2.0 * kappa);
+        let v_max: Real = (v0.max(mean) + FdmHestonVarianceMesher::STD_DEVS * variance.sqrt())
+            .max(2.0 * v0.max(theta));
+
+        let locations: Vec<Real> = Concentrating1dMesher::new(
+            0.0,
+            v_max,
+            size,
+            Some((v0, FdmHestonVarianceMesher::DENSITY)),
+            true,
+        )
+        .locations()
+        .to_vec();
+        FdmHestonVarianceMesher { locations }
+    }
+}
+impl Fdm1dMesher for FdmHestonVarianceMesher {
+    fn locations(&self) -> &[Real] {
+        &self.locations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_starts_at_zero_and_contains_v0() {
+        let mesher: FdmHestonVarianceMesher =
+            FdmHestonVarianceMesher::new(51, 0.04, 1.5, 0.09, 0.6, 1.0);
+        let v: &[Real] = mesher.locations();
+
+        assert_eq!(v[0], 0.0);
+        assert!(v[50] >= 2.0 * 0.09);
+        assert!(v.iter().any(|v| (v - 0.04).abs() < 1.0e-12));
+        assert!(v.windows(2).all(|w| w[1] > w[0]));
+    }
+}
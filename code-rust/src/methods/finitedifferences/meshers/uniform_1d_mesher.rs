@@ -0,0 +1,50 @@
+use crate::methods::finitedifferences::meshers::fdm1d_mesher::Fdm1dMesher;
+use crate::types::{Real, Size};
+
+// Equally spaced grid points between start and end (QuantLib Uniform1dMesher)
+#[derive(Clone, PartialEq, Debug)]
+pub struct Uniform1dMesher {
+    locations: Vec<Real>,
+}
+impl Uniform1dMesher {
+    // Constructor
+    pub fn new(start: Real, end: Real, size: Size) -> Uniform1dMesher {
+        if end <= start {
+            panic!("end ({end}) must be larger than start ({start})");
+        }
+        if size < 2 {
+            panic!("at least two grid points are required ({size} given)");
+        }
+        let dx: Real = (end - start) / (size - 1) as Real;
+        let mut locations: Vec<Real> = (0..size).map(|i| start + i as Real * dx).collect();
+        locations[size - 1] = end;
+        Uniform1dMesher { locations }
+    }
+}
+impl Fdm1dMesher for Uniform1dMesher {
+    fn locations(&self) -> &[Real] {
+        &self.locations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    #[test]
+    fn grid_is_equally_spaced() {
+        let mesher: Uniform1dMesher = Uniform1dMesher::new(-1.0, 1.0, 5);
+        assert_eq!(mesher.locations(), &[-1.0, -0.5, 0.0, 0.5, 1.0]);
+        assert_eq!(mesher.size(), 5);
+        assert_eq!(mesher.location(3), 0.5);
+        assert_eq!(mesher.dplus(0), 0.5);
+        assert_eq!(mesher.dminus(4), 0.5);
+    }
+
+    #[test]
+    fn new_panics_on_invalid_grid() {
+        assert!(panic::catch_unwind(|| Uniform1dMesher::new(1.0, 1.0, 5)).is_err());
+        assert!(panic::catch_unwind(|| Uniform1dMesher::new(0.0, 1.0, 1)).is_err());
+    }
+}
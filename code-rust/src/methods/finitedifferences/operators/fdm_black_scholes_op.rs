@@ -0,0 +1,216 @@
+use crate::methods::finitedifferences::meshers::fdm1d_mesher::Fdm1dMesher;
+use crate::methods::finitedifferences::operators::fdm_linear_op_composite::FdmLinearOpComposite;
+use crate::methods::finitedifferences::operators::first_derivative_op::first_derivative_op;
+use crate::methods::finitedifferences::operators::second_derivative_op::second_derivative_op;
+use crate::methods::finitedifferences::operators::triple_band_linear_op::TripleBandLinearOp;
+use crate::processes::black_scholes_process::GeneralizedBlackScholesProcess;
+use crate::types::{Rate, Real, Size, Time, Volatility};
+
+/*
+Black-Scholes operator in log-spot x = ln(S) (QuantLib FdmBlackScholesOp):
+    L = (r - q - sigma^2 / 2) d/dx + sigma^2 / 2 d^2/dx^2 - r
+so that the option value solves dV/dt + L V = 0 backwards from the
+payoff. It has a single direction and no mixed term.
+
+The coefficients are either constant or those of the term structures
+of a Black-Scholes process: on each step [t1, t2], r and q are the
+forward rates of the curves and sigma^2 the Black forward variance at
+the strike, divided by t2 - t1.
+*/
+#[derive(Clone)]
+pub struct FdmBlackScholesOp {
+    risk_free_rate: Rate,
+    dividend_yield: Rate,
+    volatility: Volatility,
+    // Process and strike whose term structures give the coefficients, if any
+    term_structures: Option<(GeneralizedBlackScholesProcess, Real)>,
+    dx_map: TripleBandLinearOp,
+    dxx_map: TripleBandLinearOp,
+    map_t: TripleBandLinearOp,
+}
+impl FdmBlackScholesOp {
+    // Constructors
+    pub fn new(
+        mesher: &dyn Fdm1dMesher,
+        risk_free_rate: Rate,
+        dividend_yield: Rate,
+        volatility: Volatility,
+    ) -> FdmBlackScholesOp {
+        let dx_map: TripleBandLinearOp = first_derivative_op(mesher);
+        let dxx_map: TripleBandLinearOp = second_derivative_op(mesher);
+        let map_t: TripleBandLinearOp = dx_map.clone();
+        let mut op: FdmBlackScholesOp = FdmBlackScholesOp {
+            risk_free_rate,
+            dividend_yield,
+            volatility,
+            term_structures: None,
+            dx_map,
+            dxx_map,
+            map_t,
+        };
+        op.set_coefficients(risk_free_rate, dividend_yield, volatility * volatility);
+        op
+    }
+    // Operator on the term structures of process, at the forward variance of strike
+    pub fn from_process(
+        mesher: &dyn Fdm1dMesher,
+        process: &GeneralizedBlackScholesProcess,
+        strike: Real,
+    ) -> FdmBlackScholesOp {
+        let mut op: FdmBlackScholesOp = FdmBlackScholesOp::new(mesher, 0.0, 0.0, 0.0);
+        op.term_structures = Some((process.clone(), strike));
+        op.set_time(0.0, 1.0e-4);
+        op
+    }
+
+    // Inspectors
+    // Coefficients of the current step
+    pub fn risk_free_rate(&self) -> Rate {
+        self.risk_free_rate
+    }
+    pub fn dividend_yield(&self) -> Rate {
+        self.dividend_yield
+    }
+    pub fn volatility(&self) -> Volatility {
+        self.volatility
+    }
+
+    fn set_coefficients(&mut self, risk_free_rate: Rate, dividend_yield: Rate, variance: Real) {
+        let n: Size = self.dx_map.size();
+        let drift: Real = risk_free_rate - dividend_yield - 0.5 * variance;
+        self.risk_free_rate = risk_free_rate;
+        self.dividend_yield = dividend_yield;
+        self.volatility = variance.sqrt();
+        self.map_t = self
+            .dx_map
+            .mult(&vec![drift; n])
+            .add(&self.dxx_map.mult(&vec![0.5 * variance; n]))
+            .add_diagonal(&vec![-risk_free_rate; n]);
+    }
+}
+impl FdmLinearOpComposite for FdmBlackScholesOp {
+    fn size(&self) -> Size {
+        1
+    }
+    // Constant coefficients need no update
+    fn set_time(&mut self, t1: Time, t2: Time) {
+        if t2 <= t1 {
+            return;
+        }
+        if let Some((process, strike)) = &self.term_structures {
+            let risk_free_rate: Rate = process.risk_free_rate().forward_rate(t1, t2);
+            let dividend_yield: Rate = process.dividend_yield().forward_rate(t1, t2);
+            let variance: Real = process
+                .black_volatility()
+                .black_forward_variance(t1, t2, *strike)
+                / (t2 - t1);
+            self.set_coefficients(risk_free_rate, dividend_yield, variance);
+        }
+    }
+
+    fn apply(&self, r: &[Real]) -> Vec<Real> {
+        self.map_t.apply(r)
+    }
+    fn apply_mixed(&self, r: &[Real]) -> Vec<Real> {
+        vec![0.0; r.len()]
+    }
+    fn apply_direction(&self, direction: Size, r: &[Real]) -> Vec<Real> {
+        if direction == 0 {
+            self.map_t.apply(r)
+        } else {
+            vec![0.0; r.len()]
+        }
+    }
+    fn solve_splitting(&self, direction: Size, r: &[Real], a: Real) -> Vec<Real> {
+        if direction == 0 {
+            self.map_t.solve_splitting(r, a, 1.0)
+        } else {
+            r.to_vec()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::methods::finitedifferences::meshers::uniform_1d_mesher::Uniform1dMesher;
+    use crate::termstructures::volatility::equityfx::black_variance_curve::{
+        BlackVarianceCurve, VarianceInterpolation,
+    };
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::{Date, Month};
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use std::rc::Rc;
+
+    #[test]
+    fn forward_price_is_in_the_kernel() {
+        // V = S exp(-q (T - t)) solves the PDE, so L S = -q S
+        let mesher: Uniform1dMesher = Uniform1dMesher::new(3.0, 6.0, 101);
+        let (r, q): (Rate, Rate) = (0.05, 0.02);
+        let op: FdmBlackScholesOp = FdmBlackScholesOp::new(&mesher, r, q, 0.3);
+        let spot: Vec<Real> = mesher.locations().iter().map(|x| x.exp()).collect();
+        let l_spot: Vec<Real> = op.apply(&spot);
+
+        assert_eq!(op.size(), 1);
+        for i in 1..100 {
+            assert!(
+                (l_spot[i] / spot[i] + q).abs() < 1.0e-3,
+                "at S = {}: {}",
+                spot[i],
+                l_spot[i] / spot[i]
+            );
+        }
+        assert_eq!(op.apply_mixed(&spot), vec![0.0; 101]);
+        assert_eq!(op.apply_direction(0, &spot), l_spot);
+    }
+
+    #[test]
+    fn solve_splitting_inverts_the_implicit_step() {
+        let mesher: Uniform1dMesher = Uniform1dMesher::new(3.0, 6.0, 21);
+        let op: FdmBlackScholesOp = FdmBlackScholesOp::new(&mesher, 0.05, 0.0, 0.2);
+        let x: Vec<Real> = mesher
+            .locations()
+            .iter()
+            .map(|x| (x - 4.6).max(0.0))
+            .collect();
+        let dt: Real = 0.01;
+        let l_x: Vec<Real> = op.apply(&x);
+        let r: Vec<Real> = x.iter().zip(&l_x).map(|(x, l)| x - dt * l).collect();
+        let solution: Vec<Real> = op.solve_splitting(0, &r, -dt);
+        for (s, e) in solution.iter().zip(&x) {
+            assert!((s - e).abs() < 1.0e-12);
+        }
+    }
+
+    #[test]
+    fn coefficients_follow_the_term_structures() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let process: GeneralizedBlackScholesProcess = GeneralizedBlackScholesProcess::new(
+            100.0,
+            flat_rate(reference_date, 0.05),
+            flat_rate(reference_date, 0.02),
+            Rc::new(BlackVarianceCurve::new(
+                reference_date,
+                &[reference_date + 365, reference_date + 730],
+                &[0.2, 0.3],
+                Box::new(Actual365Fixed::new()),
+                VarianceInterpolation::Linear,
+            )),
+        );
+        let mesher: Uniform1dMesher = Uniform1dMesher::new(3.0, 6.0, 21);
+        let mut op: FdmBlackScholesOp = FdmBlackScholesOp::from_process(&mesher, &process, 100.0);
+        assert!((op.volatility() - 0.2).abs() < 1.0e-12);
+        assert!((op.risk_free_rate() - 0.05).abs() < 1.0e-12);
+        assert!((op.dividend_yield() - 0.02).abs() < 1.0e-12);
+
+        // Forward variance over the second year: 2 * 0.09 - 0.04
+        op.set_time(1.25, 1.5);
+        assert!((op.volatility() - 0.14_f64.sqrt()).abs() < 1.0e-12);
+        let constant: FdmBlackScholesOp =
+            FdmBlackScholesOp::new(&mesher, 0.05, 0.02, 0.14_f64.sqrt());
+        let x: Vec<Real> = mesher.locations().iter().map(|x| x * x).collect();
+        for (a, b) in op.apply(&x).iter().zip(constant.apply(&x)) {
+            assert!((a - b).abs() < 1.0e-10);
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use crate::methods::finitedifferences::meshers::fdm1d_mesher::Fdm1dMesher;
+use crate::methods::finitedifferences::operators::fdm_linear_op_composite::FdmLinearOpComposite;
+use crate::methods::finitedifferences::operators::first_derivative_op::first_derivative_op;
+use crate::methods::finitedifferences::operators::second_derivative_op::second_derivative_op;
+use crate::methods::finitedifferences::operators::triple_band_linear_op::TripleBandLinearOp;
+use crate::types::{Rate, Real, Size, Time, Volatility};
+
+/*
+Heston operator in (x, v) = (ln(S), variance) (QuantLib FdmHestonOp,
+constant parameters):
+    L = (r - q - v/2) d/dx + v/2 d^2/dx^2
+      + kappa (theta - v) d/dv + sigma^2 v/2 d^2/dv^2
+      + rho sigma v d^2/dxdv - r
+
+Values live on the product grid, x running fastest: the point (x_i, v_j)
+has index i + j * nx. Direction 0 is x, direction 1 is v, and the
+discount rate is split evenly between them as in QuantLib. The mixed
+derivative uses the product of the central first-derivative stencils
+and is zero on the boundary of the grid.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct FdmHestonOp {
+    x_size: Size,
+    v_size: Size,
+    // x-direction bands on the x-fastest layout
+    map_x: TripleBandLinearOp,
+    // v-direction bands on the v-fastest (transposed) layout
+    map_v: TripleBandLinearOp,
+    dx: TripleBandLinearOp,
+    dv: TripleBandLinearOp,
+    // rho sigma v_j
+    correlation_terms: Vec<Real>,
+}
+impl FdmHestonOp {
+    // Constructor
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        x_mesher: &dyn Fdm1dMesher,
+        v_mesher: &dyn Fdm1dMesher,
+        risk_free_rate: Rate,
+        dividend_yield: Rate,
+        kappa: Real,
+        theta: Real,
+        sigma: Volatility,
+        rho: Real,
+    ) -> FdmHestonOp {
+        let (nx, nv): (Size, Size) = (x_mesher.size(), v_mesher.size());
+        let v: &[Real] = v_mesher.locations();
+        let dx: TripleBandLinearOp = first_derivative_op(x_mesher);
+        let dxx: TripleBandLinearOp = second_derivative_op(x_mesher);
+        let dv: TripleBandLinearOp = first_derivative_op(v_mesher);
+        let dvv: TripleBandLinearOp = second_derivative_op(v_mesher);
+
+        let lines_x: Vec<TripleBandLinearOp> = v
+            .iter()
+            .map(|v| {
+                dx.mult(&vec![risk_free_rate - dividend_yield - 0.5 * v; nx])
+                    .add(&dxx.mult(&vec![0.5 * v; nx]))
+                    .add_diagonal(&vec![-0.5 * risk_free_rate; nx])
+            })
+            .collect();
+        let map_x: TripleBandLinearOp = detail::concatenate(&lines_x);
+
+        let drift: Vec<Real> = v.iter().map(|v| kappa * (theta - v)).collect();
+        let diffusion: Vec<Real> = v.iter().map(|v| 0.5 * sigma * sigma * v).collect();
+        let line_v: TripleBandLinearOp = dv
+            .mult(&drift)
+            .add(&dvv.mult(&diffusion))
+            .add_diagonal(&vec![-0.5 * risk_free_rate; nv]);
+        let map_v: TripleBandLinearOp = detail::concatenate(&vec![line_v; nx]);
+
+        FdmHestonOp {
+            x_size: nx,
+            v_size: nv,
+            map_x,
+            map_v,
+            dx,
+            dv,
+            correlation_terms: v.iter().map(|v| rho * sigma * v).collect(),
+        }
+    }
+
+    // x-fastest to v-fastest layout, and back with the sizes swapped
+    fn transpose(r: &[Real], fast: Size, slow: Size) -> Vec<Real> {
+        let mut result: Vec<Real> = vec![0.0; r.len()];
+        for j in 0..slow {
+            for i in 0..fast {
+                result[j + i * slow] = r[i + j * fast];
+            }
+        }
+        result
+    }
+    fn check_size(&self, size: Size) {
+        if size != self.x_size * self.v_size {
+            panic!(
+                "inconsistent sizes: {} given, {} required",
+                size,
+                self.x_size * self.v_size
+            );
+        }
+    }
+}
+impl FdmLinearOpComposite for FdmHestonOp {
+    fn size(&self) -> Size {
+        2
+    }
+    // Constant coefficients: nothing to update
+    fn set_time(&mut self, _t1: Time, _t2: Time) {}
+
+    fn apply(&self, r: &[Real]) -> Vec<Real> {
+        let l_x: Vec<Real> = self.apply_direction(0, r);
+        let l_v: Vec<Real> = self.apply_direction(1, r);
+        let l_mixed: Vec<Real> = self.apply_mixed(r);
+        (0..r.len()).map(|k| l_x[k] + l_v[k] + l_mixed[k]).collect()
+    }
+    fn apply_mixed(&self, r: &[Real]) -> Vec<Real> {
+        self.check_size(r.len());
+        let (nx, nv): (Size, Size) = (self.x_size, self.v_size);
+        let mut result: Vec<Real> = vec![0.0; r.len()];
+        for j in 1..nv - 1 {
+            let wv: [Real; 3] = [self.dv.lower()[j], self.dv.diag()[j], self.dv.upper()[j]];
+            for i in 1..nx - 1 {
+                let wx: [Real; 3] = [self.dx.lower()[i], self.dx.diag()[i], self.dx.upper()[i]];
+                let mut value: Real = 0.0;
+                for (b, wv) in wv.iter().enumerate() {
+                    for (a, wx) in wx.iter().enumerate() {
+                        value += wx * wv * r[(i + a - 1) + (j + b - 1) * nx];
+                    }
+                }
+                result[i + j * nx] = self.correlation_terms[j] * value;
+            }
+        }
+        result
+    }
+    fn apply_direction(&self, direction: Size, r: &[Real]) -> Vec<Real> {
+        self.check_size(r.len());
+        match direction {
+            0 => self.map_x.apply(r),
+            1 => {
+                let transposed: Vec<Real> = FdmHestonOp::transpose(r, self.x_size, self.v_size);
+                FdmHestonOp::transpose(&self.map_v.apply(&transposed), self.v_size, self.x_size)
+            }
+            _ => vec![0.0; r.len()],
+        }
+    }
+    fn solve_splitting(&self, direction: Size, r: &[Real], a: Real) -> Vec<Real> {
+        self.check_size(r.len());
+        match direction {
+            0 => self.map_x.solve_splitting(r, a, 1.0),
+            1 => {
+                let transposed: Vec<Real> = FdmHestonOp::transpose(r, self.x_size, self.v_size);
+                let solution: Vec<Real> = self.map_v.solve_splitting(&transposed, a, 1.0);
+                FdmHestonOp::transpose(&solution, self.v_size, self.x_size)
+            }
+            _ => r.to_vec(),
+        }
+    }
+}
+
+mod detail {
+    use crate::methods::finitedifferences::operators::triple_band_linear_op::TripleBandLinearOp;
+    use crate::types::Real;
+
+    /*
+    Block-diagonal operator made of independent lines. The derivative
+    operators have lower_0 = upper_{n-1} = 0, so the lines stay decoupled
+    both in apply and in solve_splitting.
+    */
+    pub(super) fn concatenate(lines: &[TripleBandLinearOp]) -> TripleBandLinearOp {
+        let band = |f: fn(&TripleBandLinearOp) -> &[Real]| -> Vec<Real> {
+            lines.iter().flat_map(|line| f(line).to_vec()).collect()
+        };
+        TripleBandLinearOp::new(
+            band(TripleBandLinearOp::lower),
+            band(TripleBandLinearOp::diag),
+            band(TripleBandLinearOp::upper),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::methods::finitedifferences::meshers::uniform_1d_mesher::Uniform1dMesher;
+
+    fn operator() -> (Uniform1dMesher, Uniform1dMesher, FdmHestonOp) {
+        let x_mesher: Uniform1dMesher = Uniform1dMesher::new(3.5, 5.5, 41);
+        let v_mesher: Uniform1dMesher = Uniform1dMesher::new(0.0, 0.5, 21);
+        let op: FdmHestonOp =
+            FdmHestonOp::new(&x_mesher, &v_mesher, 0.05, 0.02, 1.5, 0.04, 0.3, -0.7);
+        (x_mesher, v_mesher, op)
+    }
+
+    #[test]
+    fn forward_price_is_in_the_kernel() {
+        // V = S exp(-q (T - t)) solves the PDE for any variance: L S = -q S
+        let (x_mesher, v_mesher, op) = operator();
+        let spot: Vec<Real> = v_mesher
+            .locations()
+            .iter()
+            .flat_map(|_| x_mesher.locations().iter().map(|x| x.exp()))
+            .collect();
+        let l_spot: Vec<Real> = op.apply(&spot);
+
+        assert_eq!(op.size(), 2);
+        for j in 0..21 {
+            for i in 1..40 {
+                let k: Size = i + j * 41;
+                assert!((l_spot[k] / spot[k] + 0.02).abs() < 1.0e-3, "at ({i}, {j})");
+            }
+        }
+    }
+
+    #[test]
+    fn mixed_term_is_exact_for_products() {
+        // d^2/dxdv (x v) = 1
+        let (x_mesher, v_mesher, op) = operator();
+        let f: Vec<Real> = v_mesher
+            .locations()
+            .iter()
+            .flat_map(|v| x_mesher.locations().iter().map(move |x| x * v))
+            .collect();
+        let mixed: Vec<Real> = op.apply_mixed(&f);
+        for (j, v) in v_mesher.locations().iter().enumerate().take(20).skip(1) {
+            for i in 1..40 {
+                assert!((mixed[i + j * 41] - (-0.7 * 0.3 * v)).abs() < 1.0e-10);
+            }
+        }
+        assert_eq!(mixed[0], 0.0);
+    }
+
+    #[test]
+    fn solve_splitting_inverts_each_direction() {
+        let (x_mesher, v_mesher, op) = operator();
+        let f: Vec<Real> = v_mesher
+            .locations()
+            .iter()
+            .flat_map(|v| {
+                x_mesher
+                    .locations()
+                    .iter()
+                    .map(move |x| (x.exp() - 100.0).max(0.0) + v)
+            })
+            .collect();
+        let dt: Real = 0.01;
+        for direction in 0..2 {
+            let l_f: Vec<Real> = op.apply_direction(direction, &f);
+            let r: Vec<Real> = f.iter().zip(&l_f).map(|(f, l)| f - dt * l).collect();
+            let solution: Vec<Real> = op.solve_splitting(direction, &r, -dt);
+            for (s, e) in solution.iter().zip(&f) {
+                assert!((s - e).abs() < 1.0e-10, "direction {direction}");
+            }
+        }
+    }
+}
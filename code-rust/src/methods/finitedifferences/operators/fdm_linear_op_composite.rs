@@ -0,0 +1,22 @@
+use crate::types::{Real, Size, Time};
+
+/*
+Spatial operator of a finite-difference PDE (QuantLib
+FdmLinearOpComposite), split by direction for ADI schemes:
+    L = L_0 + ... + L_{d-1} + L_mixed
+    - apply: L r
+    - apply_direction(i, r): L_i r
+    - apply_mixed(r): the mixed-derivative part L_mixed r
+    - solve_splitting(i, r, a): x such that (I + a L_i) x = r
+set_time(t1, t2) freezes time-dependent coefficients over [t1, t2].
+*/
+pub trait FdmLinearOpComposite {
+    // Number of directions
+    fn size(&self) -> Size;
+    fn set_time(&mut self, t1: Time, t2: Time);
+
+    fn apply(&self, r: &[Real]) -> Vec<Real>;
+    fn apply_mixed(&self, r: &[Real]) -> Vec<Real>;
+    fn apply_direction(&self, direction: Size, r: &[Real]) -> Vec<Real>;
+    fn solve_splitting(&self, direction: Size, r: &[Real], a: Real) -> Vec<Real>;
+}
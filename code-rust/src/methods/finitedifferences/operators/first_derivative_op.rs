@@ -0,0 +1,62 @@
+use crate::methods::finitedifferences::meshers::fdm1d_mesher::Fdm1dMesher;
+use crate::methods::finitedifferences::operators::triple_band_linear_op::TripleBandLinearOp;
+use crate::types::{Real, Size};
+
+/*
+First derivative d/dx on a (possibly non-uniform) grid (QuantLib
+FirstDerivativeOp). Inner points use the second-order central
+difference with h- = dminus(i), h+ = dplus(i):
+    f'(x_i) ~ -h+ / (h- (h- + h+)) f_{i-1} + (h+ - h-) / (h- h+) f_i
+              + h- / (h+ (h- + h+)) f_{i+1}
+The boundaries use one-sided first-order differences.
+*/
+pub fn first_derivative_op(mesher: &dyn Fdm1dMesher) -> TripleBandLinearOp {
+    let n: Size = mesher.size();
+    let mut lower: Vec<Real> = vec![0.0; n];
+    let mut diag: Vec<Real> = vec![0.0; n];
+    let mut upper: Vec<Real> = vec![0.0; n];
+
+    // Upwinding at the lower boundary
+    upper[0] = 1.0 / mesher.dplus(0);
+    diag[0] = -upper[0];
+    for i in 1..n - 1 {
+        let hm: Real = mesher.dminus(i);
+        let hp: Real = mesher.dplus(i);
+        lower[i] = -hp / (hm * (hm + hp));
+        diag[i] = (hp - hm) / (hm * hp);
+        upper[i] = hm / (hp * (hm + hp));
+    }
+    // Downwinding at the upper boundary
+    diag[n - 1] = 1.0 / mesher.dminus(n - 1);
+    lower[n - 1] = -diag[n - 1];
+
+    TripleBandLinearOp::new(lower, diag, upper)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::methods::finitedifferences::meshers::concentrating_1d_mesher::Concentrating1dMesher;
+
+    #[test]
+    fn exact_for_quadratics_at_inner_points() {
+        let mesher: Concentrating1dMesher =
+            Concentrating1dMesher::new(-1.0, 2.0, 21, Some((0.3, 0.1)), false);
+        let x: &[Real] = mesher.locations();
+        let f: Vec<Real> = x.iter().map(|x| 3.0 * x * x - x + 2.0).collect();
+        let df: Vec<Real> = first_derivative_op(&mesher).apply(&f);
+
+        for i in 1..20 {
+            assert!(
+                (df[i] - (6.0 * x[i] - 1.0)).abs() < 1.0e-10,
+                "at x = {}",
+                x[i]
+            );
+        }
+        // One-sided differences are exact for straight lines
+        let line: Vec<Real> = x.iter().map(|x| 2.0 * x + 1.0).collect();
+        let d_line: Vec<Real> = first_derivative_op(&mesher).apply(&line);
+        assert!((d_line[0] - 2.0).abs() < 1.0e-12);
+        assert!((d_line[20] - 2.0).abs() < 1.0e-12);
+    }
+}
@@ -0,0 +1,48 @@
+use crate::methods::finitedifferences::meshers::fdm1d_mesher::Fdm1dMesher;
+use crate::methods::finitedifferences::operators::triple_band_linear_op::TripleBandLinearOp;
+use crate::types::{Real, Size};
+
+/*
+Second derivative d^2/dx^2 on a (possibly non-uniform) grid (QuantLib
+SecondDerivativeOp):
+    f''(x_i) ~ 2 / (h- (h- + h+)) f_{i-1} - 2 / (h- h+) f_i
+               + 2 / (h+ (h- + h+)) f_{i+1}
+The boundary rows are zero, i.e. the solution is assumed linear there.
+*/
+pub fn second_derivative_op(mesher: &dyn Fdm1dMesher) -> TripleBandLinearOp {
+    let n: Size = mesher.size();
+    let mut lower: Vec<Real> = vec![0.0; n];
+    let mut diag: Vec<Real> = vec![0.0; n];
+    let mut upper: Vec<Real> = vec![0.0; n];
+
+    for i in 1..n - 1 {
+        let hm: Real = mesher.dminus(i);
+        let hp: Real = mesher.dplus(i);
+        lower[i] = 2.0 / (hm * (hm + hp));
+        diag[i] = -2.0 / (hm * hp);
+        upper[i] = 2.0 / (hp * (hm + hp));
+    }
+
+    TripleBandLinearOp::new(lower, diag, upper)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::methods::finitedifferences::meshers::concentrating_1d_mesher::Concentrating1dMesher;
+
+    #[test]
+    fn exact_for_quadratics_at_inner_points() {
+        let mesher: Concentrating1dMesher =
+            Concentrating1dMesher::new(-1.0, 2.0, 21, Some((0.3, 0.1)), false);
+        let x: &[Real] = mesher.locations();
+        let f: Vec<Real> = x.iter().map(|x| 3.0 * x * x - x + 2.0).collect();
+        let d2f: Vec<Real> = second_derivative_op(&mesher).apply(&f);
+
+        assert_eq!(d2f[0], 0.0);
+        assert_eq!(d2f[20], 0.0);
+        for value in &d2f[1..20] {
+            assert!((value - 6.0).abs() < 1.0e-8, "{}", value);
+        }
+    }
+}
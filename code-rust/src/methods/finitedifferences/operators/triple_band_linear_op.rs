@@ -0,0 +1,174 @@
+use crate::types::{Real, Size};
+
+/*
+Tridiagonal linear operator on a 1-D grid (QuantLib TripleBandLinearOp):
+    (L r)_i = lower_i r_{i-1} + diag_i r_i + upper_i r_{i+1}
+lower_0 and upper_{n-1} are unused (the derivative operators set
+them to zero).
+
+solve_splitting(r, a, b) solves (b I + a L) x = r with the Thomas
+algorithm, which is what implicit time steps need.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct TripleBandLinearOp {
+    lower: Vec<Real>,
+    diag: Vec<Real>,
+    upper: Vec<Real>,
+}
+impl TripleBandLinearOp {
+    // Constructor
+    pub fn new(lower: Vec<Real>, diag: Vec<Real>, upper: Vec<Real>) -> TripleBandLinearOp {
+        if lower.len() != diag.len() || upper.len() != diag.len() {
+            panic!(
+                "band sizes differ: lower {}, diag {}, upper {}",
+                lower.len(),
+                diag.len(),
+                upper.len()
+            );
+        }
+        TripleBandLinearOp { lower, diag, upper }
+    }
+    pub fn zeros(size: Size) -> TripleBandLinearOp {
+        TripleBandLinearOp::new(vec![0.0; size], vec![0.0; size], vec![0.0; size])
+    }
+
+    // Inspectors
+    pub fn size(&self) -> Size {
+        self.diag.len()
+    }
+    pub fn lower(&self) -> &[Real] {
+        &self.lower
+    }
+    pub fn diag(&self) -> &[Real] {
+        &self.diag
+    }
+    pub fn upper(&self) -> &[Real] {
+        &self.upper
+    }
+
+    // Row i multiplied by u_i
+    pub fn mult(&self, u: &[Real]) -> TripleBandLinearOp {
+        self.check_size(u.len());
+        TripleBandLinearOp::new(
+            self.lower.iter().zip(u).map(|(l, u)| l * u).collect(),
+            self.diag.iter().zip(u).map(|(d, u)| d * u).collect(),
+            self.upper.iter().zip(u).map(|(v, u)| v * u).collect(),
+        )
+    }
+    pub fn add(&self, other: &TripleBandLinearOp) -> TripleBandLinearOp {
+        self.check_size(other.size());
+        let sum = |a: &[Real], b: &[Real]| a.iter().zip(b).map(|(x, y)| x + y).collect();
+        TripleBandLinearOp::new(
+            sum(&self.lower, &other.lower),
+            sum(&self.diag, &other.diag),
+            sum(&self.upper, &other.upper),
+        )
+    }
+    // L + diag(u)
+    pub fn add_diagonal(&self, u: &[Real]) -> TripleBandLinearOp {
+        self.check_size(u.len());
+        let mut result: TripleBandLinearOp = self.clone();
+        for (d, u) in result.diag.iter_mut().zip(u) {
+            *d += u;
+        }
+        result
+    }
+
+    pub fn apply(&self, r: &[Real]) -> Vec<Real> {
+        self.check_size(r.len());
+        let n: Size = self.size();
+        (0..n)
+            .map(|i| {
+                let mut value: Real = self.diag[i] * r[i];
+                if i > 0 {
+                    value += self.lower[i] * r[i - 1];
+                }
+                if i + 1 < n {
+                    value += self.upper[i] * r[i + 1];
+                }
+                value
+            })
+            .collect()
+    }
+
+    pub fn solve_splitting(&self, r: &[Real], a: Real, b: Real) -> Vec<Real> {
+        self.check_size(r.len());
+        let n: Size = self.size();
+        let mut x: Vec<Real> = vec![0.0; n];
+        let mut tmp: Vec<Real> = vec![0.0; n];
+
+        let mut bet: Real = 1.0 / (a * self.diag[0] + b);
+        x[0] = r[0] * bet;
+        for j in 1..n {
+            tmp[j] = a * self.upper[j - 1] * bet;
+            bet = 1.0 / (b + a * (self.diag[j] - tmp[j] * self.lower[j]));
+            x[j] = (r[j] - a * self.lower[j] * x[j - 1]) * bet;
+        }
+        for j in (0..n - 1).rev() {
+            x[j] -= tmp[j + 1] * x[j + 1];
+        }
+        x
+    }
+
+    fn check_size(&self, size: Size) {
+        if size != self.size() {
+            panic!(
+                "inconsistent sizes: {} given, {} required",
+                size,
+                self.size()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn operator() -> TripleBandLinearOp {
+        TripleBandLinearOp::new(
+            vec![0.0, 1.0, -2.0, 0.5],
+            vec![4.0, 5.0, 6.0, 3.0],
+            vec![1.0, 2.0, 1.5, 0.0],
+        )
+    }
+
+    #[test]
+    fn apply_multiplies_by_the_tridiagonal_matrix() {
+        let r: [Real; 4] = [1.0, 2.0, 3.0, 4.0];
+        assert_eq!(
+            operator().apply(&r),
+            vec![4.0 + 2.0, 1.0 + 10.0 + 6.0, -4.0 + 18.0 + 6.0, 1.5 + 12.0]
+        );
+    }
+
+    #[test]
+    fn solve_splitting_inverts_b_plus_a_l() {
+        let op: TripleBandLinearOp = operator();
+        let x: [Real; 4] = [0.5, -1.0, 2.0, 0.25];
+        for (a, b) in [(1.0, 0.0), (-0.3, 1.0), (0.7, 2.0)] {
+            let l_x: Vec<Real> = op.apply(&x);
+            let r: Vec<Real> = x.iter().zip(&l_x).map(|(x, l)| b * x + a * l).collect();
+            let solution: Vec<Real> = op.solve_splitting(&r, a, b);
+            for (s, e) in solution.iter().zip(x.iter()) {
+                assert!((s - e).abs() < 1.0e-14, "a = {a}, b = {b}: {s} vs {e}");
+            }
+        }
+    }
+
+    #[test]
+    fn algebra_acts_row_by_row() {
+        let op: TripleBandLinearOp = operator();
+        let scaled: TripleBandLinearOp = op.mult(&[1.0, 2.0, 0.0, -1.0]);
+        assert_eq!(scaled.diag(), &[4.0, 10.0, 0.0, -3.0]);
+        assert_eq!(scaled.lower(), &[0.0, 2.0, 0.0, -0.5]);
+
+        let sum: TripleBandLinearOp = op.add(&op).add_diagonal(&[1.0; 4]);
+        assert_eq!(sum.diag(), &[9.0, 11.0, 13.0, 7.0]);
+        assert_eq!(sum.upper(), &[2.0, 4.0, 3.0, 0.0]);
+        assert_eq!(
+            TripleBandLinearOp::zeros(3).apply(&[1.0, 2.0, 3.0]),
+            vec![0.0; 3]
+        );
+    }
+}
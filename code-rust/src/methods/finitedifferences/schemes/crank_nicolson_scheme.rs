@@ -0,0 +1,43 @@
+use crate::methods::finitedifferences::operators::fdm_linear_op_composite::FdmLinearOpComposite;
+use crate::methods::finitedifferences::schemes::explicit_euler_scheme::ExplicitEulerScheme;
+use crate::methods::finitedifferences::schemes::fdm_scheme::FdmScheme;
+use crate::methods::finitedifferences::schemes::implicit_euler_scheme::ImplicitEulerScheme;
+use crate::types::{Real, Time};
+
+/*
+Crank-Nicolson (theta) scheme (QuantLib CrankNicolsonScheme): an
+explicit step weighted by 1 - theta followed by an implicit one weighted
+by theta,
+    (I - theta dt L) a(t - dt) = (I + (1 - theta) dt L) a(t)
+theta = 0.5 is second order in time.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CrankNicolsonScheme {
+    theta: Real,
+    explicit: ExplicitEulerScheme,
+    implicit: ImplicitEulerScheme,
+}
+impl CrankNicolsonScheme {
+    // Constructor
+    pub fn new(theta: Real) -> CrankNicolsonScheme {
+        CrankNicolsonScheme {
+            theta,
+            explicit: ExplicitEulerScheme::new(),
+            implicit: ImplicitEulerScheme::new(),
+        }
+    }
+}
+impl FdmScheme for CrankNicolsonScheme {
+    fn set_step(&mut self, dt: Time) {
+        self.explicit.set_step(dt);
+        self.implicit.set_step(dt);
+    }
+    fn step(&self, map: &mut dyn FdmLinearOpComposite, a: &mut Vec<Real>, t: Time) {
+        if self.theta != 1.0 {
+            self.explicit.step_theta(map, a, t, 1.0 - self.theta);
+        }
+        if self.theta != 0.0 {
+            self.implicit.step_theta(map, a, t, self.theta);
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::methods::finitedifferences::operators::fdm_linear_op_composite::FdmLinearOpComposite;
+use crate::methods::finitedifferences::schemes::fdm_scheme::FdmScheme;
+use crate::methods::finitedifferences::schemes::fdm_scheme::detail;
+use crate::types::{Real, Time};
+
+/*
+Douglas ADI scheme (QuantLib DouglasScheme):
+    y_0 = a + dt L a
+    y_i = y_{i-1} + theta dt L_i (y_i - a),  i = 1..d
+    a(t - dt) = y_d
+Each y_i only needs a tridiagonal solve in direction i. In one
+dimension with theta = 0.5 this is Crank-Nicolson.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct DouglasScheme {
+    theta: Real,
+    dt: Time,
+}
+impl DouglasScheme {
+    // Constructor
+    pub fn new(theta: Real) -> DouglasScheme {
+        DouglasScheme { theta, dt: 0.0 }
+    }
+}
+impl FdmScheme for DouglasScheme {
+    fn set_step(&mut self, dt: Time) {
+        self.dt = dt;
+    }
+    fn step(&self, map: &mut dyn FdmLinearOpComposite, a: &mut Vec<Real>, t: Time) {
+        if t - self.dt < -1.0e-8 {
+            panic!("a step towards negative time given");
+        }
+        map.set_time((t - self.dt).max(0.0), t);
+
+        let mut y: Vec<Real> = detail::axpy(self.dt, &map.apply(a), a);
+        for i in 0..map.size() {
+            let rhs: Vec<Real> =
+                detail::axpy(-self.theta * self.dt, &map.apply_direction(i, a), &y);
+            y = map.solve_splitting(i, &rhs, -self.theta * self.dt);
+        }
+        *a = y;
+    }
+}
@@ -0,0 +1,50 @@
+use crate::methods::finitedifferences::operators::fdm_linear_op_composite::FdmLinearOpComposite;
+use crate::methods::finitedifferences::schemes::fdm_scheme::FdmScheme;
+use crate::types::{Real, Time};
+
+/*
+Explicit Euler scheme (QuantLib ExplicitEulerScheme):
+    a(t - dt) = a(t) + dt L a(t)
+Only conditionally stable: dt must be small compared to dx^2 / sigma^2.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ExplicitEulerScheme {
+    dt: Time,
+}
+impl ExplicitEulerScheme {
+    // Constructor
+    pub fn new() -> ExplicitEulerScheme {
+        ExplicitEulerScheme { dt: 0.0 }
+    }
+
+    // Step with the explicit part weighted by theta (used by Crank-Nicolson)
+    pub fn step_theta(
+        &self,
+        map: &mut dyn FdmLinearOpComposite,
+        a: &mut [Real],
+        t: Time,
+        theta: Real,
+    ) {
+        if t - self.dt < -1.0e-8 {
+            panic!("a step towards negative time given");
+        }
+        map.set_time((t - self.dt).max(0.0), t);
+        let l_a: Vec<Real> = map.apply(a);
+        for (x, l) in a.iter_mut().zip(l_a) {
+            *x += theta * self.dt * l;
+        }
+    }
+}
+impl Default for ExplicitEulerScheme {
+    fn default() -> Self {
+        ExplicitEulerScheme::new()
+    }
+}
+impl FdmScheme for ExplicitEulerScheme {
+    fn set_step(&mut self, dt: Time) {
+        self.dt = dt;
+    }
+    fn step(&self, map: &mut dyn FdmLinearOpComposite, a: &mut Vec<Real>, t: Time) {
+        self.step_theta(map, a, t, 1.0);
+    }
+}
@@ -0,0 +1,26 @@
+use crate::methods::finitedifferences::operators::fdm_linear_op_composite::FdmLinearOpComposite;
+use crate::types::{Real, Time};
+
+/*
+Time-stepping scheme for dV/dt + L V = 0, rolled back in time
+(QuantLib evolution schemes): step(map, a, t) takes the values a at
+time t to time t - dt.
+*/
+pub trait FdmScheme {
+    fn set_step(&mut self, dt: Time);
+    fn step(&self, map: &mut dyn FdmLinearOpComposite, a: &mut Vec<Real>, t: Time);
+}
+
+// Vector helpers shared by the ADI schemes
+pub(crate) mod detail {
+    use crate::types::Real;
+
+    // alpha x + y
+    pub(crate) fn axpy(alpha: Real, x: &[Real], y: &[Real]) -> Vec<Real> {
+        x.iter().zip(y).map(|(x, y)| alpha * x + y).collect()
+    }
+    // x - y
+    pub(crate) fn difference(x: &[Real], y: &[Real]) -> Vec<Real> {
+        x.iter().zip(y).map(|(x, y)| x - y).collect()
+    }
+}
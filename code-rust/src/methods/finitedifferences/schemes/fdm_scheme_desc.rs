@@ -0,0 +1,83 @@
+use crate::methods::finitedifferences::schemes::craig_sneyd_scheme::CraigSneydScheme;
+use crate::methods::finitedifferences::schemes::crank_nicolson_scheme::CrankNicolsonScheme;
+use crate::methods::finitedifferences::schemes::douglas_scheme::DouglasScheme;
+use crate::methods::finitedifferences::schemes::explicit_euler_scheme::ExplicitEulerScheme;
+use crate::methods::finitedifferences::schemes::fdm_scheme::FdmScheme;
+use crate::methods::finitedifferences::schemes::hundsdorfer_scheme::HundsdorferScheme;
+use crate::methods::finitedifferences::schemes::implicit_euler_scheme::ImplicitEulerScheme;
+use crate::methods::finitedifferences::schemes::modified_craig_sneyd_scheme::ModifiedCraigSneydScheme;
+use crate::types::Real;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum FdmSchemeType {
+    Hundsdorfer,
+    Douglas,
+    CraigSneyd,
+    ModifiedCraigSneyd,
+    ImplicitEuler,
+    ExplicitEuler,
+    CrankNicolson,
+}
+
+// Scheme choice and its parameters (QuantLib FdmSchemeDesc), with the usual presets
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct FdmSchemeDesc {
+    pub scheme_type: FdmSchemeType,
+    pub theta: Real,
+    pub mu: Real,
+}
+impl FdmSchemeDesc {
+    // Constructor
+    pub fn new(scheme_type: FdmSchemeType, theta: Real, mu: Real) -> FdmSchemeDesc {
+        FdmSchemeDesc {
+            scheme_type,
+            theta,
+            mu,
+        }
+    }
+
+    pub fn douglas() -> FdmSchemeDesc {
+        FdmSchemeDesc::new(FdmSchemeType::Douglas, 0.5, 0.0)
+    }
+    pub fn crank_nicolson() -> FdmSchemeDesc {
+        FdmSchemeDesc::new(FdmSchemeType::CrankNicolson, 0.5, 0.0)
+    }
+    pub fn implicit_euler() -> FdmSchemeDesc {
+        FdmSchemeDesc::new(FdmSchemeType::ImplicitEuler, 0.0, 0.0)
+    }
+    pub fn explicit_euler() -> FdmSchemeDesc {
+        FdmSchemeDesc::new(FdmSchemeType::ExplicitEuler, 0.0, 0.0)
+    }
+    pub fn craig_sneyd() -> FdmSchemeDesc {
+        FdmSchemeDesc::new(FdmSchemeType::CraigSneyd, 0.5, 0.5)
+    }
+    pub fn modified_craig_sneyd() -> FdmSchemeDesc {
+        FdmSchemeDesc::new(FdmSchemeType::ModifiedCraigSneyd, 1.0 / 3.0, 1.0 / 3.0)
+    }
+    pub fn hundsdorfer() -> FdmSchemeDesc {
+        FdmSchemeDesc::new(FdmSchemeType::Hundsdorfer, 0.5 + 3.0_f64.sqrt() / 6.0, 0.5)
+    }
+    pub fn modified_hundsdorfer() -> FdmSchemeDesc {
+        FdmSchemeDesc::new(FdmSchemeType::Hundsdorfer, 1.0 - 2.0_f64.sqrt() / 2.0, 0.5)
+    }
+
+    // The scheme described
+    pub fn scheme(&self) -> Box<dyn FdmScheme> {
+        match self.scheme_type {
+            FdmSchemeType::Hundsdorfer => Box::new(HundsdorferScheme::new(self.theta, self.mu)),
+            FdmSchemeType::Douglas => Box::new(DouglasScheme::new(self.theta)),
+            FdmSchemeType::CraigSneyd => Box::new(CraigSneydScheme::new(self.theta, self.mu)),
+            FdmSchemeType::ModifiedCraigSneyd => {
+                Box::new(ModifiedCraigSneydScheme::new(self.theta, self.mu))
+            }
+            FdmSchemeType::ImplicitEuler => Box::new(ImplicitEulerScheme::new()),
+            FdmSchemeType::ExplicitEuler => Box::new(ExplicitEulerScheme::new()),
+            FdmSchemeType::CrankNicolson => Box::new(CrankNicolsonScheme::new(self.theta)),
+        }
+    }
+}
+impl Default for FdmSchemeDesc {
+    fn default() -> Self {
+        FdmSchemeDesc::douglas()
+    }
+}
@@ -0,0 +1,55 @@
+use crate::methods::finitedifferences::operators::fdm_linear_op_composite::FdmLinearOpComposite;
+use crate::methods::finitedifferences::schemes::fdm_scheme::FdmScheme;
+use crate::types::{Real, Time};
+
+/*
+Implicit Euler scheme (QuantLib ImplicitEulerScheme):
+    (I - dt L) a(t - dt) = a(t)
+Unconditionally stable and strongly damping, but only first order in
+time; typically used for a few damping steps after a non-smooth payoff.
+Only one-directional operators are supported (a tridiagonal solve).
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ImplicitEulerScheme {
+    dt: Time,
+}
+impl ImplicitEulerScheme {
+    // Constructor
+    pub fn new() -> ImplicitEulerScheme {
+        ImplicitEulerScheme { dt: 0.0 }
+    }
+
+    // Step with the implicit part weighted by theta (used by Crank-Nicolson)
+    pub fn step_theta(
+        &self,
+        map: &mut dyn FdmLinearOpComposite,
+        a: &mut Vec<Real>,
+        t: Time,
+        theta: Real,
+    ) {
+        if t - self.dt < -1.0e-8 {
+            panic!("a step towards negative time given");
+        }
+        if map.size() != 1 {
+            panic!(
+                "implicit Euler scheme only supports one-dimensional operators ({} directions given)",
+                map.size()
+            );
+        }
+        map.set_time((t - self.dt).max(0.0), t);
+        *a = map.solve_splitting(0, a, -theta * self.dt);
+    }
+}
+impl Default for ImplicitEulerScheme {
+    fn default() -> Self {
+        ImplicitEulerScheme::new()
+    }
+}
+impl FdmScheme for ImplicitEulerScheme {
+    fn set_step(&mut self, dt: Time) {
+        self.dt = dt;
+    }
+    fn step(&self, map: &mut dyn FdmLinearOpComposite, a: &mut Vec<Real>, t: Time) {
+        self.step_theta(map, a, t, 1.0);
+    }
+}
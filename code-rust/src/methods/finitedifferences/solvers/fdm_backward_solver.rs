@@ -0,0 +1,232 @@
+use crate::math::comparison::close_enough;
+use crate::methods::finitedifferences::operators::fdm_linear_op_composite::FdmLinearOpComposite;
+use crate::methods::finitedifferences::schemes::douglas_scheme::DouglasScheme;
+use crate::methods::finitedifferences::schemes::fdm_scheme::FdmScheme;
+use crate::methods::finitedifferences::schemes::fdm_scheme_desc::FdmSchemeDesc;
+use crate::methods::finitedifferences::schemes::implicit_euler_scheme::ImplicitEulerScheme;
+use crate::methods::finitedifferences::stepconditions::fdm_step_condition::FdmStepCondition;
+use crate::methods::finitedifferences::stepconditions::fdm_step_condition_composite::FdmStepConditionComposite;
+use crate::types::{Real, Size, Time};
+
+/*
+Rolls the values back from `from` to `to` (QuantLib FdmBackwardSolver
+and FiniteDifferenceModel::rollback).
+
+The interval is cut into `steps` equal steps. The first damping_steps
+of them use implicit Euler to smooth the payoff kink (one-directional
+operators) or, for ADI operators, Douglas with theta = 1; the others use
+the described scheme. Steps are split at the stopping times of the
+condition so that dividends and Bermudan exercises happen at the right
+time, and the condition is applied after every step.
+*/
+pub struct FdmBackwardSolver {
+    map: Box<dyn FdmLinearOpComposite>,
+    condition: FdmStepConditionComposite,
+    scheme_desc: FdmSchemeDesc,
+}
+impl FdmBackwardSolver {
+    // Constructor
+    pub fn new(
+        map: Box<dyn FdmLinearOpComposite>,
+        condition: FdmStepConditionComposite,
+        scheme_desc: FdmSchemeDesc,
+    ) -> FdmBackwardSolver {
+        FdmBackwardSolver {
+            map,
+            condition,
+            scheme_desc,
+        }
+    }
+
+    pub fn rollback(
+        &mut self,
+        a: &mut Vec<Real>,
+        from: Time,
+        to: Time,
+        steps: Size,
+        damping_steps: Size,
+    ) {
+        if from < to {
+            panic!("rollback from {from} to a later time {to}");
+        }
+        if steps == 0 {
+            panic!("at least one time step is required");
+        }
+        let damping_steps: Size = damping_steps.min(steps);
+        let damping_to: Time = from - (from - to) * damping_steps as Real / steps as Real;
+
+        if damping_steps > 0 {
+            let mut damping: Box<dyn FdmScheme> = if self.map.size() == 1 {
+                Box::new(ImplicitEulerScheme::new())
+            } else {
+                Box::new(DouglasScheme::new(1.0))
+            };
+            self.rollback_impl(damping.as_mut(), a, from, damping_to, damping_steps);
+        }
+        if steps > damping_steps {
+            let mut scheme: Box<dyn FdmScheme> = self.scheme_desc.scheme();
+            self.rollback_impl(scheme.as_mut(), a, damping_to, to, steps - damping_steps);
+        }
+    }
+
+    fn rollback_impl(
+        &mut self,
+        scheme: &mut dyn FdmScheme,
+        a: &mut Vec<Real>,
+        from: Time,
+        to: Time,
+        steps: Size,
+    ) {
+        let dt: Time = (from - to) / steps as Real;
+        let mut t: Time = from;
+        scheme.set_step(dt);
+
+        for i in 0..steps {
+            let mut now: Time = t;
+            let next: Time = if i == steps - 1 { to } else { t - dt };
+
+            // Stopping times strictly inside (next, now), latest first; those
+            // on a grid time up to rounding are handled at that time
+            let mut hit: bool = false;
+            for stopping_time in self.condition.stopping_times().iter().rev() {
+                if next < *stopping_time
+                    && *stopping_time < now
+                    && !close_enough(*stopping_time, next)
+                    && !close_enough(*stopping_time, now)
+                {
+                    hit = true;
+                    scheme.set_step(now - stopping_time);
+                    scheme.step(self.map.as_mut(), a, now);
+                    self.condition.apply_to(a, *stopping_time);
+                    now = *stopping_time;
+                }
+            }
+            if hit {
+                if now - next > 1.0e-12 {
+                    scheme.set_step(now - next);
+                    scheme.step(self.map.as_mut(), a, now);
+                }
+                scheme.set_step(dt);
+            } else {
+                scheme.step(self.map.as_mut(), a, now);
+            }
+            self.condition.apply_to(a, next);
+            t = next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::methods::finitedifferences::meshers::fdm1d_mesher::Fdm1dMesher;
+    use crate::methods::finitedifferences::meshers::uniform_1d_mesher::Uniform1dMesher;
+    use crate::methods::finitedifferences::operators::fdm_black_scholes_op::FdmBlackScholesOp;
+    use std::cell::RefCell;
+    use std::panic;
+    use std::rc::Rc;
+
+    // Records the times the condition is applied at
+    struct Recorder {
+        times: Rc<RefCell<Vec<Time>>>,
+    }
+    impl FdmStepCondition for Recorder {
+        fn apply_to(&self, _a: &mut [Real], t: Time) {
+            self.times.borrow_mut().push(t);
+        }
+    }
+
+    #[test]
+    fn forward_is_rolled_back_with_every_scheme() {
+        // V = S exp(-q (T - t)) solves the PDE
+        let mesher: Uniform1dMesher = Uniform1dMesher::new(3.0, 6.0, 101);
+        let spot: Vec<Real> = mesher.locations().iter().map(|x| x.exp()).collect();
+        let schemes: [FdmSchemeDesc; 6] = [
+            FdmSchemeDesc::douglas(),
+            FdmSchemeDesc::crank_nicolson(),
+            FdmSchemeDesc::implicit_euler(),
+            FdmSchemeDesc::craig_sneyd(),
+            FdmSchemeDesc::modified_craig_sneyd(),
+            FdmSchemeDesc::hundsdorfer(),
+        ];
+        for desc in schemes {
+            let mut solver: FdmBackwardSolver = FdmBackwardSolver::new(
+                Box::new(FdmBlackScholesOp::new(&mesher, 0.05, 0.03, 0.2)),
+                FdmStepConditionComposite::new(&[], Vec::new()),
+                desc,
+            );
+            let mut a: Vec<Real> = spot.clone();
+            solver.rollback(&mut a, 1.0, 0.0, 50, 2);
+            let expected: Real = spot[50] * (-0.03_f64).exp();
+            assert!(
+                (a[50] - expected).abs() < 1.0e-3 * expected,
+                "{:?}: {} vs {}",
+                desc.scheme_type,
+                a[50],
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn rollback_stops_at_the_stopping_times() {
+        let mesher: Uniform1dMesher = Uniform1dMesher::new(3.0, 6.0, 11);
+        let times: Rc<RefCell<Vec<Time>>> = Rc::new(RefCell::new(Vec::new()));
+        let recorder: Recorder = Recorder {
+            times: times.clone(),
+        };
+        let mut solver: FdmBackwardSolver = FdmBackwardSolver::new(
+            Box::new(FdmBlackScholesOp::new(&mesher, 0.05, 0.0, 0.2)),
+            FdmStepConditionComposite::new(&[0.3, 0.5], vec![Box::new(recorder)]),
+            FdmSchemeDesc::douglas(),
+        );
+        let mut a: Vec<Real> = vec![1.0; 11];
+        solver.rollback(&mut a, 1.0, 0.0, 4, 0);
+
+        assert_eq!(*times.borrow(), vec![0.75, 0.5, 0.3, 0.25, 0.0]);
+    }
+
+    #[test]
+    fn stopping_times_on_the_grid_are_hit_once() {
+        // 1 - 3 * 0.1 is not exactly 0.7
+        let mesher: Uniform1dMesher = Uniform1dMesher::new(3.0, 6.0, 11);
+        let times: Rc<RefCell<Vec<Time>>> = Rc::new(RefCell::new(Vec::new()));
+        let recorder: Recorder = Recorder {
+            times: times.clone(),
+        };
+        let mut solver: FdmBackwardSolver = FdmBackwardSolver::new(
+            Box::new(FdmBlackScholesOp::new(&mesher, 0.05, 0.0, 0.2)),
+            FdmStepConditionComposite::new(&[0.7, 0.3], vec![Box::new(recorder)]),
+            FdmSchemeDesc::douglas(),
+        );
+        solver.rollback(&mut vec![1.0; 11], 1.0, 0.0, 10, 0);
+
+        let times: Vec<Time> = times.borrow().clone();
+        assert_eq!(times.len(), 10);
+        for stopping_time in [0.7, 0.3] {
+            let hits: usize = times
+                .iter()
+                .filter(|t| close_enough(**t, stopping_time))
+                .count();
+            assert_eq!(hits, 1, "{times:?}");
+        }
+    }
+
+    #[test]
+    fn rollback_panics_on_invalid_inputs() {
+        let cases: [(Time, Time, Size, &str); 2] =
+            [(0.0, 1.0, 4, "forward in time"), (1.0, 0.0, 0, "no steps")];
+        for (from, to, steps, label) in cases {
+            let result = panic::catch_unwind(|| {
+                let mesher: Uniform1dMesher = Uniform1dMesher::new(3.0, 6.0, 11);
+                let mut solver: FdmBackwardSolver = FdmBackwardSolver::new(
+                    Box::new(FdmBlackScholesOp::new(&mesher, 0.05, 0.0, 0.2)),
+                    FdmStepConditionComposite::new(&[], Vec::new()),
+                    FdmSchemeDesc::douglas(),
+                );
+                solver.rollback(&mut vec![1.0; 11], from, to, steps, 0);
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
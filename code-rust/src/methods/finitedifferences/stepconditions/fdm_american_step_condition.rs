@@ -0,0 +1,63 @@
+use crate::instruments::payoffs::Payoff;
+use crate::methods::finitedifferences::meshers::fdm1d_mesher::Fdm1dMesher;
+use crate::methods::finitedifferences::stepconditions::fdm_step_condition::FdmStepCondition;
+use crate::types::{Real, Time};
+
+/*
+American exercise (QuantLib FdmAmericanStepCondition): at every step
+the value cannot fall below the exercise value,
+    V_i = max(V_i, payoff(exp(x_i)))
+on a log-spot mesher.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct FdmAmericanStepCondition {
+    exercise_values: Vec<Real>,
+}
+impl FdmAmericanStepCondition {
+    // Constructor
+    pub fn new(mesher: &dyn Fdm1dMesher, payoff: &dyn Payoff) -> FdmAmericanStepCondition {
+        FdmAmericanStepCondition {
+            exercise_values: mesher
+                .locations()
+                .iter()
+                .map(|x| payoff.value(x.exp()))
+                .collect(),
+        }
+    }
+    // Exercise values given point by point, e.g. on a multi-dimensional grid
+    pub fn from_exercise_values(exercise_values: Vec<Real>) -> FdmAmericanStepCondition {
+        FdmAmericanStepCondition { exercise_values }
+    }
+}
+impl FdmStepCondition for FdmAmericanStepCondition {
+    fn apply_to(&self, a: &mut [Real], _t: Time) {
+        for (value, exercise) in a.iter_mut().zip(&self.exercise_values) {
+            *value = value.max(*exercise);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruments::payoffs::PlainVanillaPayoff;
+    use crate::methods::finitedifferences::meshers::uniform_1d_mesher::Uniform1dMesher;
+    use crate::option::OptionType;
+
+    #[test]
+    fn values_are_floored_at_the_exercise_value() {
+        let mesher: Uniform1dMesher = Uniform1dMesher::new(0.0, 2.0_f64.ln(), 2);
+        let payoff: PlainVanillaPayoff = PlainVanillaPayoff::new(OptionType::Put, 1.5);
+        let condition: FdmAmericanStepCondition = FdmAmericanStepCondition::new(&mesher, &payoff);
+
+        let mut a: Vec<Real> = vec![0.2, 0.1];
+        condition.apply_to(&mut a, 0.5);
+        assert_eq!(a, vec![0.5, 0.1]);
+
+        let condition: FdmAmericanStepCondition =
+            FdmAmericanStepCondition::from_exercise_values(vec![0.5, 0.0, 0.5, 0.0]);
+        let mut a: Vec<Real> = vec![0.2, 0.1, 0.7, 0.1];
+        condition.apply_to(&mut a, 0.5);
+        assert_eq!(a, vec![0.5, 0.1, 0.7, 0.1]);
+    }
+}
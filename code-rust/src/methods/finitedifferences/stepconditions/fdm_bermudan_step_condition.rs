@@ -0,0 +1,80 @@
+use crate::instruments::payoffs::Payoff;
+use crate::math::comparison::close_enough;
+use crate::methods::finitedifferences::meshers::fdm1d_mesher::Fdm1dMesher;
+use crate::methods::finitedifferences::stepconditions::fdm_step_condition::FdmStepCondition;
+use crate::types::{Real, Time};
+
+/*
+Bermudan exercise (QuantLib FdmBermudanStepCondition): as the American
+condition, but only at the exercise times, which must be stopping times
+of the rollback.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct FdmBermudanStepCondition {
+    exercise_times: Vec<Time>,
+    exercise_values: Vec<Real>,
+}
+impl FdmBermudanStepCondition {
+    // Constructor
+    pub fn new(
+        exercise_times: &[Time],
+        mesher: &dyn Fdm1dMesher,
+        payoff: &dyn Payoff,
+    ) -> FdmBermudanStepCondition {
+        FdmBermudanStepCondition {
+            exercise_times: exercise_times.to_vec(),
+            exercise_values: mesher
+                .locations()
+                .iter()
+                .map(|x| payoff.value(x.exp()))
+                .collect(),
+        }
+    }
+    // Exercise values given point by point, e.g. on a multi-dimensional grid
+    pub fn from_exercise_values(
+        exercise_times: &[Time],
+        exercise_values: Vec<Real>,
+    ) -> FdmBermudanStepCondition {
+        FdmBermudanStepCondition {
+            exercise_times: exercise_times.to_vec(),
+            exercise_values,
+        }
+    }
+
+    // Inspectors
+    pub fn exercise_times(&self) -> &[Time] {
+        &self.exercise_times
+    }
+}
+impl FdmStepCondition for FdmBermudanStepCondition {
+    fn apply_to(&self, a: &mut [Real], t: Time) {
+        if self.exercise_times.iter().any(|e| close_enough(*e, t)) {
+            for (value, exercise) in a.iter_mut().zip(&self.exercise_values) {
+                *value = value.max(*exercise);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruments::payoffs::PlainVanillaPayoff;
+    use crate::methods::finitedifferences::meshers::uniform_1d_mesher::Uniform1dMesher;
+    use crate::option::OptionType;
+
+    #[test]
+    fn exercise_only_at_exercise_times() {
+        let mesher: Uniform1dMesher = Uniform1dMesher::new(0.0, 2.0_f64.ln(), 2);
+        let payoff: PlainVanillaPayoff = PlainVanillaPayoff::new(OptionType::Call, 1.5);
+        let condition: FdmBermudanStepCondition =
+            FdmBermudanStepCondition::new(&[0.5, 1.0], &mesher, &payoff);
+
+        let mut a: Vec<Real> = vec![0.1, 0.1];
+        condition.apply_to(&mut a, 0.75);
+        assert_eq!(a, vec![0.1, 0.1]);
+        condition.apply_to(&mut a, 0.5);
+        assert_eq!(a, vec![0.1, 0.5]);
+        assert_eq!(condition.exercise_times(), &[0.5, 1.0]);
+    }
+}
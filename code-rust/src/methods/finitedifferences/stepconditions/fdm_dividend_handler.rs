@@ -0,0 +1,107 @@
+use crate::cashflows::dividend::Dividend;
+use crate::math::comparison::close_enough;
+use crate::math::interpolations::linear_interpolation::LinearInterpolation;
+use crate::methods::finitedifferences::meshers::fdm1d_mesher::Fdm1dMesher;
+use crate::methods::finitedifferences::stepconditions::fdm_step_condition::FdmStepCondition;
+use crate::types::{Real, Time};
+
+/*
+Discrete dividends (QuantLib FdmDividendHandler) on a log-spot mesher.
+The spot drops by the dividend D(S) when it goes ex-dividend (a cash
+amount, or a fraction of S), so just before the dividend time the
+value is
+    V(S) = V(S - D(S))   (after the dividend)
+read off the grid by interpolation in log-spot. Spots that would fall
+below the grid are floored at the lowest grid point.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct FdmDividendHandler {
+    dividend_times: Vec<Time>,
+    dividends: Vec<Dividend>,
+    x: Vec<Real>,
+}
+impl FdmDividendHandler {
+    // Constructor
+    pub fn new(
+        dividend_times: &[Time],
+        dividends: &[Dividend],
+        mesher: &dyn Fdm1dMesher,
+    ) -> FdmDividendHandler {
+        if dividend_times.len() != dividends.len() {
+            panic!(
+                "different number of dividend times ({}) and amounts ({})",
+                dividend_times.len(),
+                dividends.len()
+            );
+        }
+        FdmDividendHandler {
+            dividend_times: dividend_times.to_vec(),
+            dividends: dividends.to_vec(),
+            x: mesher.locations().to_vec(),
+        }
+    }
+
+    // Inspectors
+    pub fn dividend_times(&self) -> &[Time] {
+        &self.dividend_times
+    }
+    pub fn dividends(&self) -> &[Dividend] {
+        &self.dividends
+    }
+}
+impl FdmStepCondition for FdmDividendHandler {
+    fn apply_to(&self, a: &mut [Real], t: Time) {
+        for (time, dividend) in self.dividend_times.iter().zip(&self.dividends) {
+            if !close_enough(*time, t) {
+                continue;
+            }
+            let interpolation: LinearInterpolation = LinearInterpolation::new(&self.x, a);
+            let lowest_spot: Real = self.x[0].exp();
+            for (value, x) in a.iter_mut().zip(&self.x) {
+                let spot: Real = (x.exp() - dividend.amount(x.exp())).max(lowest_spot);
+                *value = interpolation.value(spot.ln());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::methods::finitedifferences::meshers::uniform_1d_mesher::Uniform1dMesher;
+    use crate::time::date::{Date, Month};
+
+    #[test]
+    fn values_are_shifted_by_the_dividend() {
+        let mesher: Uniform1dMesher = Uniform1dMesher::new(50.0_f64.ln(), 200.0_f64.ln(), 201);
+        let dividend: Dividend = Dividend::fixed(Date::new(1, Month::July, 2025), 2.0);
+        let handler: FdmDividendHandler = FdmDividendHandler::new(&[0.5], &[dividend], &mesher);
+        // V = ln(S) is linear on the grid, so interpolation is exact
+        let mut a: Vec<Real> = mesher.locations().to_vec();
+
+        handler.apply_to(&mut a, 0.25);
+        assert_eq!(a, mesher.locations().to_vec());
+
+        handler.apply_to(&mut a, 0.5);
+        for (value, x) in a.iter().zip(mesher.locations()) {
+            let expected: Real = (x.exp() - 2.0).max(50.0).ln();
+            assert!((value - expected).abs() < 1.0e-12, "at S = {}", x.exp());
+        }
+        assert_eq!(handler.dividend_times(), &[0.5]);
+        assert_eq!(handler.dividends(), &[dividend]);
+    }
+
+    #[test]
+    fn fractional_dividends_scale_the_spot() {
+        let mesher: Uniform1dMesher = Uniform1dMesher::new(50.0_f64.ln(), 200.0_f64.ln(), 201);
+        let dividend: Dividend = Dividend::fractional(Date::new(1, Month::July, 2025), 0.05);
+        let handler: FdmDividendHandler = FdmDividendHandler::new(&[0.5], &[dividend], &mesher);
+        let mut a: Vec<Real> = mesher.locations().to_vec();
+
+        handler.apply_to(&mut a, 0.5);
+        for (value, x) in a.iter().zip(mesher.locations()) {
+            let expected: Real = (0.95 * x.exp()).max(50.0).ln();
+            assert!((value - expected).abs() < 1.0e-12, "at S = {}", x.exp());
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use crate::types::{Real, Time};
+
+/*
+Condition applied to the values while rolling back (QuantLib
+StepCondition), e.g. early exercise or a dividend jump. apply_to is
+called after every time step, with the time reached.
+*/
+pub trait FdmStepCondition {
+    fn apply_to(&self, a: &mut [Real], t: Time);
+}
@@ -0,0 +1,147 @@
+use crate::cashflows::dividend::Dividend;
+use crate::exercise::{Exercise, ExerciseType};
+use crate::instruments::payoffs::Payoff;
+use crate::methods::finitedifferences::meshers::fdm1d_mesher::Fdm1dMesher;
+use crate::methods::finitedifferences::stepconditions::fdm_american_step_condition::FdmAmericanStepCondition;
+use crate::methods::finitedifferences::stepconditions::fdm_bermudan_step_condition::FdmBermudanStepCondition;
+use crate::methods::finitedifferences::stepconditions::fdm_dividend_handler::FdmDividendHandler;
+use crate::methods::finitedifferences::stepconditions::fdm_step_condition::FdmStepCondition;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Real, Time};
+
+/*
+Several step conditions applied in turn (QuantLib
+FdmStepConditionComposite), together with the stopping times the
+rollback must hit exactly (dividend and Bermudan exercise times).
+
+vanilla_composite builds the usual set for a vanilla option on a
+log-spot mesher: discrete dividends with their times, plus American or
+Bermudan exercise.
+*/
+pub struct FdmStepConditionComposite {
+    stopping_times: Vec<Time>,
+    conditions: Vec<Box<dyn FdmStepCondition>>,
+}
+impl FdmStepConditionComposite {
+    // Constructor
+    pub fn new(
+        stopping_times: &[Time],
+        conditions: Vec<Box<dyn FdmStepCondition>>,
+    ) -> FdmStepConditionComposite {
+        let mut stopping_times: Vec<Time> = stopping_times.to_vec();
+        stopping_times.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        stopping_times.dedup();
+        FdmStepConditionComposite {
+            stopping_times,
+            conditions,
+        }
+    }
+    pub fn vanilla_composite(
+        dividends: &[(Time, Dividend)],
+        exercise: &dyn Exercise,
+        reference_date: Date,
+        day_counter: &dyn DayCounter,
+        mesher: &dyn Fdm1dMesher,
+        payoff: &dyn Payoff,
+    ) -> FdmStepConditionComposite {
+        let mut stopping_times: Vec<Time> = Vec::new();
+        let mut conditions: Vec<Box<dyn FdmStepCondition>> = Vec::new();
+
+        if !dividends.is_empty() {
+            let times: Vec<Time> = dividends.iter().map(|(t, _)| *t).collect();
+            let amounts: Vec<Dividend> = dividends.iter().map(|(_, d)| *d).collect();
+            conditions.push(Box::new(FdmDividendHandler::new(&times, &amounts, mesher)));
+            stopping_times.extend(times);
+        }
+        match exercise.exercise_type() {
+            ExerciseType::American => {
+                conditions.push(Box::new(FdmAmericanStepCondition::new(mesher, payoff)));
+            }
+            ExerciseType::Bermudan => {
+                let times: Vec<Time> = exercise
+                    .dates()
+                    .iter()
+                    .map(|d| day_counter.year_fraction(reference_date, *d))
+                    .collect();
+                conditions.push(Box::new(FdmBermudanStepCondition::new(
+                    &times, mesher, payoff,
+                )));
+                stopping_times.extend(times);
+            }
+            ExerciseType::European => {}
+        }
+        FdmStepConditionComposite::new(&stopping_times, conditions)
+    }
+
+    // Inspectors
+    pub fn stopping_times(&self) -> &[Time] {
+        &self.stopping_times
+    }
+}
+impl FdmStepCondition for FdmStepConditionComposite {
+    fn apply_to(&self, a: &mut [Real], t: Time) {
+        for condition in &self.conditions {
+            condition.apply_to(a, t);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::{AmericanExercise, BermudanExercise, EuropeanExercise};
+    use crate::instruments::payoffs::PlainVanillaPayoff;
+    use crate::methods::finitedifferences::meshers::uniform_1d_mesher::Uniform1dMesher;
+    use crate::option::OptionType;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+
+    #[test]
+    fn vanilla_composite_collects_stopping_times() {
+        let reference_date: Date = Date::new(1, Month::January, 2025);
+        let mesher: Uniform1dMesher = Uniform1dMesher::new(4.0, 5.0, 11);
+        let payoff: PlainVanillaPayoff = PlainVanillaPayoff::new(OptionType::Put, 100.0);
+        let dividends: [(Time, Dividend); 2] = [
+            (0.5, Dividend::fixed(Date::new(2, Month::July, 2025), 1.0)),
+            (0.25, Dividend::fixed(Date::new(2, Month::April, 2025), 1.0)),
+        ];
+
+        let european: FdmStepConditionComposite = FdmStepConditionComposite::vanilla_composite(
+            &dividends,
+            &EuropeanExercise::new(Date::new(1, Month::January, 2026)),
+            reference_date,
+            &Actual365Fixed,
+            &mesher,
+            &payoff,
+        );
+        assert_eq!(european.stopping_times(), &[0.25, 0.5]);
+
+        let american: FdmStepConditionComposite = FdmStepConditionComposite::vanilla_composite(
+            &[],
+            &AmericanExercise::new(reference_date, Date::new(1, Month::January, 2026), false),
+            reference_date,
+            &Actual365Fixed,
+            &mesher,
+            &payoff,
+        );
+        assert!(american.stopping_times().is_empty());
+        let mut a: Vec<Real> = vec![0.0; 11];
+        american.apply_to(&mut a, 0.3);
+        assert_eq!(a[0], 100.0 - 4.0_f64.exp());
+
+        let bermudan_dates: [Date; 2] = [
+            Date::new(2, Month::July, 2025),
+            Date::new(1, Month::January, 2026),
+        ];
+        let bermudan: FdmStepConditionComposite = FdmStepConditionComposite::vanilla_composite(
+            &dividends,
+            &BermudanExercise::new(&bermudan_dates, false),
+            reference_date,
+            &Actual365Fixed,
+            &mesher,
+            &payoff,
+        );
+        assert_eq!(bermudan.stopping_times(), &[0.25, 182.0 / 365.0, 0.5, 1.0]);
+    }
+}
@@ -0,0 +1,410 @@
+use crate::math::distributions::binomial_distribution::peizer_pratt_method2_inversion;
+use crate::methods::lattices::tree::Tree;
+use crate::stochastic_process::StochasticProcess1D;
+use crate::types::{Real, Size, Time};
+
+/*
+Binomial trees for a log-normal underlying (QuantLib BinomialTree and
+its subclasses), built from a 1-D process whose drift and diffusion are
+those of ln(S), e.g. BlackScholesMertonProcess. With dt = end / steps:
+    - drift per step: mu dt, with mu = process.drift(0, x0)
+    - variance per step: process.variance(0, x0, dt)
+
+Node (i, j) has had j up moves out of i. The trees differ in how they
+choose the up and down moves and the probabilities:
+    - JarrowRudd: equal probabilities, moves sigma sqrt(dt) around the drift
+    - CoxRossRubinstein: equal jumps ln(u) = -ln(d) = sigma sqrt(dt)
+    - Tian: matches the first three moments of S
+    - LeisenReimer and Joshi4: probabilities from the Black-Scholes d2,
+      centred on the strike; steps are made odd
+*/
+pub trait BinomialTree: Tree {
+    fn new(process: &dyn StochasticProcess1D, end: Time, steps: Size, strike: Real) -> Self
+    where
+        Self: Sized;
+}
+
+// Jarrow-Rudd tree (QuantLib JarrowRudd): equal probabilities
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct JarrowRudd {
+    columns: Size,
+    x0: Real,
+    drift_per_step: Real,
+    up: Real,
+}
+impl BinomialTree for JarrowRudd {
+    fn new(process: &dyn StochasticProcess1D, end: Time, steps: Size, _strike: Real) -> JarrowRudd {
+        let (x0, dt): (Real, Time) = detail::check(process, end, steps);
+        JarrowRudd {
+            columns: steps + 1,
+            x0,
+            drift_per_step: process.drift(0.0, x0) * dt,
+            up: process.std_deviation(0.0, x0, dt),
+        }
+    }
+}
+impl Tree for JarrowRudd {
+    fn columns(&self) -> Size {
+        self.columns
+    }
+    fn size(&self, i: Size) -> Size {
+        i + 1
+    }
+    fn underlying(&self, i: Size, index: Size) -> Real {
+        let j: Real = 2.0 * index as Real - i as Real;
+        self.x0 * (i as Real * self.drift_per_step + j * self.up).exp()
+    }
+    fn descendant(&self, _i: Size, index: Size, branch: Size) -> Size {
+        index + branch
+    }
+    fn probability(&self, _i: Size, _index: Size, _branch: Size) -> Real {
+        0.5
+    }
+}
+
+// Cox-Ross-Rubinstein tree (QuantLib CoxRossRubinstein): equal jumps
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CoxRossRubinstein {
+    columns: Size,
+    x0: Real,
+    dx: Real,
+    pu: Real,
+}
+impl BinomialTree for CoxRossRubinstein {
+    fn new(
+        process: &dyn StochasticProcess1D,
+        end: Time,
+        steps: Size,
+        _strike: Real,
+    ) -> CoxRossRubinstein {
+        let (x0, dt): (Real, Time) = detail::check(process, end, steps);
+        let dx: Real = process.std_deviation(0.0, x0, dt);
+        let pu: Real = 0.5 + 0.5 * process.drift(0.0, x0) * dt / dx;
+        detail::check_probability(pu);
+        CoxRossRubinstein {
+            columns: steps + 1,
+            x0,
+            dx,
+            pu,
+        }
+    }
+}
+impl Tree for CoxRossRubinstein {
+    fn columns(&self) -> Size {
+        self.columns
+    }
+    fn size(&self, i: Size) -> Size {
+        i + 1
+    }
+    fn underlying(&self, i: Size, index: Size) -> Real {
+        let j: Real = 2.0 * index as Real - i as Real;
+        self.x0 * (j * self.dx).exp()
+    }
+    fn descendant(&self, _i: Size, index: Size, branch: Size) -> Size {
+        index + branch
+    }
+    fn probability(&self, _i: Size, _index: Size, branch: Size) -> Real {
+        if branch == 1 { self.pu } else { 1.0 - self.pu }
+    }
+}
+
+/*
+Tian tree (QuantLib Tian), with q = exp(variance per step) and
+r = exp(drift per step) sqrt(q):
+    u, d = r q / 2 (q + 1 +/- sqrt(q^2 + 2 q - 3)),  pu = (r - d) / (u - d)
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Tian {
+    moves: detail::Moves,
+}
+impl BinomialTree for Tian {
+    fn new(process: &dyn StochasticProcess1D, end: Time, steps: Size, _strike: Real) -> Tian {
+        let (x0, dt): (Real, Time) = detail::check(process, end, steps);
+        let q: Real = process.variance(0.0, x0, dt).exp();
+        let r: Real = (process.drift(0.0, x0) * dt).exp() * q.sqrt();
+        let root: Real = (q * q + 2.0 * q - 3.0).sqrt();
+        let up: Real = 0.5 * r * q * (q + 1.0 + root);
+        let down: Real = 0.5 * r * q * (q + 1.0 - root);
+        let pu: Real = (r - down) / (up - down);
+        detail::check_probability(pu);
+        Tian {
+            moves: detail::Moves {
+                columns: steps + 1,
+                x0,
+                up,
+                down,
+                pu,
+            },
+        }
+    }
+}
+impl Tree for Tian {
+    fn columns(&self) -> Size {
+        self.moves.columns
+    }
+    fn size(&self, i: Size) -> Size {
+        i + 1
+    }
+    fn underlying(&self, i: Size, index: Size) -> Real {
+        self.moves.underlying(i, index)
+    }
+    fn descendant(&self, _i: Size, index: Size, branch: Size) -> Size {
+        index + branch
+    }
+    fn probability(&self, _i: Size, _index: Size, branch: Size) -> Real {
+        self.moves.probability(branch)
+    }
+}
+
+/*
+Leisen-Reimer tree (QuantLib LeisenReimer). With n odd steps, v the
+variance up to maturity and d2 = (ln(S / K) + mu T) / sqrt(v):
+    pu = h(d2),  p' = h(d2 + sqrt(v)),  h = Peizer-Pratt inversion
+    u = e p' / pu,  d = (e - pu u) / (1 - pu)
+where e = exp(mu dt + v / (2 n)) is the growth of the forward per step.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct LeisenReimer {
+    moves: detail::Moves,
+}
+impl BinomialTree for LeisenReimer {
+    fn new(
+        process: &dyn StochasticProcess1D,
+        end: Time,
+        steps: Size,
+        strike: Real,
+    ) -> LeisenReimer {
+        let odd_steps: Size = detail::odd(steps);
+        LeisenReimer {
+            moves: detail::Moves::strike_centred(process, end, odd_steps, strike, |d| {
+                peizer_pratt_method2_inversion(d, odd_steps)
+            }),
+        }
+    }
+}
+impl Tree for LeisenReimer {
+    fn columns(&self) -> Size {
+        self.moves.columns
+    }
+    fn size(&self, i: Size) -> Size {
+        i + 1
+    }
+    fn underlying(&self, i: Size, index: Size) -> Real {
+        self.moves.underlying(i, index)
+    }
+    fn descendant(&self, _i: Size, index: Size, branch: Size) -> Size {
+        index + branch
+    }
+    fn probability(&self, _i: Size, _index: Size, branch: Size) -> Real {
+        self.moves.probability(branch)
+    }
+}
+
+/*
+Joshi's fourth-order tree (QuantLib Joshi4): as Leisen-Reimer, with
+h(d) the expansion in 1 / sqrt(k), k = (n - 1) / 2, of the probability
+that makes the binomial price exact up to fourth order.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Joshi4 {
+    moves: detail::Moves,
+}
+impl Joshi4 {
+    fn compute_up_prob(k: Real, dj: Real) -> Real {
+        let alpha: Real = dj / 8.0_f64.sqrt();
+        let alpha2: Real = alpha * alpha;
+        let alpha3: Real = alpha * alpha2;
+        let alpha5: Real = alpha3 * alpha2;
+        let alpha7: Real = alpha5 * alpha2;
+        let beta: Real = -0.375 * alpha - alpha3;
+        let gamma: Real = (5.0 / 6.0) * alpha5 + (13.0 / 12.0) * alpha3 + (25.0 / 128.0) * alpha;
+        let delta: Real = -0.1025 * alpha - 0.9285 * alpha3 - 1.43 * alpha5 - 0.5 * alpha7;
+        let root_k: Real = k.sqrt();
+        0.5 + alpha / root_k
+            + beta / (k * root_k)
+            + gamma / (k * k * root_k)
+            + delta / (k * k * k * root_k)
+    }
+}
+impl BinomialTree for Joshi4 {
+    fn new(process: &dyn StochasticProcess1D, end: Time, steps: Size, strike: Real) -> Joshi4 {
+        let odd_steps: Size = detail::odd(steps);
+        let k: Real = (odd_steps as Real - 1.0) / 2.0;
+        Joshi4 {
+            moves: detail::Moves::strike_centred(process, end, odd_steps, strike, |d| {
+                Joshi4::compute_up_prob(k, d)
+            }),
+        }
+    }
+}
+impl Tree for Joshi4 {
+    fn columns(&self) -> Size {
+        self.moves.columns
+    }
+    fn size(&self, i: Size) -> Size {
+        i + 1
+    }
+    fn underlying(&self, i: Size, index: Size) -> Real {
+        self.moves.underlying(i, index)
+    }
+    fn descendant(&self, _i: Size, index: Size, branch: Size) -> Size {
+        index + branch
+    }
+    fn probability(&self, _i: Size, _index: Size, branch: Size) -> Real {
+        self.moves.probability(branch)
+    }
+}
+
+mod detail {
+    use crate::stochastic_process::StochasticProcess1D;
+    use crate::types::{Real, Size, Time};
+
+    // Spot and step length, after checking the inputs
+    pub(super) fn check(process: &dyn StochasticProcess1D, end: Time, steps: Size) -> (Real, Time) {
+        if end <= 0.0 {
+            panic!("negative or null end time ({end}) not allowed");
+        }
+        if steps == 0 {
+            panic!("at least one step is required");
+        }
+        (process.x0(), end / steps as Real)
+    }
+    pub(super) fn check_probability(pu: Real) {
+        if !(0.0..=1.0).contains(&pu) {
+            panic!("up probability ({pu}) must be between 0 and 1");
+        }
+    }
+    pub(super) fn odd(steps: Size) -> Size {
+        if steps % 2 == 1 { steps } else { steps + 1 }
+    }
+
+    // S_ij = x0 d^(i - j) u^j, up with probability pu
+    #[derive(Copy, Clone, PartialEq, Debug)]
+    pub(super) struct Moves {
+        pub(super) columns: Size,
+        pub(super) x0: Real,
+        pub(super) up: Real,
+        pub(super) down: Real,
+        pub(super) pu: Real,
+    }
+    impl Moves {
+        // Leisen-Reimer style construction, with h the d -> probability map
+        pub(super) fn strike_centred(
+            process: &dyn StochasticProcess1D,
+            end: Time,
+            odd_steps: Size,
+            strike: Real,
+            h: impl Fn(Real) -> Real,
+        ) -> Moves {
+            let (x0, dt): (Real, Time) = check(process, end, odd_steps);
+            if strike <= 0.0 {
+                panic!("strike ({strike}) must be positive");
+            }
+            let n: Real = odd_steps as Real;
+            let drift_per_step: Real = process.drift(0.0, x0) * dt;
+            let variance: Real = process.variance(0.0, x0, end);
+            let ermqdt: Real = (drift_per_step + 0.5 * variance / n).exp();
+            let d2: Real = ((x0 / strike).ln() + drift_per_step * n) / variance.sqrt();
+            let pu: Real = h(d2);
+            let pdash: Real = h(d2 + variance.sqrt());
+            let up: Real = ermqdt * pdash / pu;
+            let down: Real = (ermqdt - pu * up) / (1.0 - pu);
+            Moves {
+                columns: odd_steps + 1,
+                x0,
+                up,
+                down,
+                pu,
+            }
+        }
+
+        pub(super) fn underlying(&self, i: Size, index: Size) -> Real {
+            self.x0 * self.down.powi((i - index) as i32) * self.up.powi(index as i32)
+        }
+        pub(super) fn probability(&self, branch: Size) -> Real {
+            if branch == 1 { self.pu } else { 1.0 - self.pu }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::processes::black_scholes_process::BlackScholesMertonProcess;
+
+    fn process() -> BlackScholesMertonProcess {
+        BlackScholesMertonProcess::new(100.0, 0.05, 0.02, 0.3)
+    }
+
+    // Expected spot one step ahead over the spot, for every node of the first columns
+    fn one_step_growth(tree: &dyn Tree) -> Vec<Real> {
+        let mut growth: Vec<Real> = Vec::new();
+        for i in 0..3 {
+            for j in 0..tree.size(i) {
+                let expected: Real = (0..2)
+                    .map(|b| {
+                        tree.probability(i, j, b) * tree.underlying(i + 1, tree.descendant(i, j, b))
+                    })
+                    .sum();
+                growth.push(expected / tree.underlying(i, j));
+            }
+        }
+        growth
+    }
+
+    #[test]
+    fn trees_start_at_the_spot_and_recombine() {
+        let trees: [(Box<dyn Tree>, Size); 5] = [
+            (Box::new(JarrowRudd::new(&process(), 1.0, 10, 100.0)), 11),
+            (
+                Box::new(CoxRossRubinstein::new(&process(), 1.0, 10, 100.0)),
+                11,
+            ),
+            (Box::new(Tian::new(&process(), 1.0, 10, 100.0)), 11),
+            (Box::new(LeisenReimer::new(&process(), 1.0, 10, 100.0)), 12),
+            (Box::new(Joshi4::new(&process(), 1.0, 10, 100.0)), 12),
+        ];
+        for (tree, columns) in trees {
+            assert_eq!(tree.columns(), columns);
+            assert!((tree.underlying(0, 0) - 100.0).abs() < 1.0e-12);
+            // Up then down = down then up
+            let up_down: Real = tree.underlying(2, tree.descendant(1, tree.descendant(0, 0, 1), 0));
+            let down_up: Real = tree.underlying(2, tree.descendant(1, tree.descendant(0, 0, 0), 1));
+            assert!((up_down - down_up).abs() < 1.0e-12);
+            assert!((tree.probability(0, 0, 0) + tree.probability(0, 0, 1) - 1.0).abs() < 1.0e-15);
+        }
+    }
+
+    #[test]
+    fn cox_ross_rubinstein_matches_the_log_drift() {
+        let tree: CoxRossRubinstein = CoxRossRubinstein::new(&process(), 1.0, 10, 100.0);
+        let dx: Real = 0.3 * 0.1_f64.sqrt();
+        assert!((tree.underlying(1, 1) / 100.0 - dx.exp()).abs() < 1.0e-14);
+        let mean_log_move: Real = (2.0 * tree.probability(0, 0, 1) - 1.0) * dx;
+        assert!((mean_log_move - (0.05 - 0.02 - 0.045) * 0.1).abs() < 1.0e-14);
+    }
+
+    #[test]
+    fn moment_matching_trees_grow_as_the_forward() {
+        let forward_growth: Real = (0.03_f64 * 0.1).exp();
+        let trees: [Box<dyn Tree>; 3] = [
+            Box::new(Tian::new(&process(), 1.0, 10, 100.0)),
+            Box::new(LeisenReimer::new(&process(), 1.1, 11, 110.0)),
+            Box::new(Joshi4::new(&process(), 1.1, 11, 90.0)),
+        ];
+        for tree in trees {
+            for growth in one_step_growth(tree.as_ref()) {
+                assert!((growth - forward_growth).abs() < 1.0e-14, "{}", growth);
+            }
+        }
+    }
+
+    #[test]
+    fn cox_ross_rubinstein_panics_on_negative_probabilities() {
+        // Drift much larger than the volatility over a single step
+        let process: BlackScholesMertonProcess =
+            BlackScholesMertonProcess::new(100.0, 0.5, 0.0, 0.01);
+        let result = std::panic::catch_unwind(|| CoxRossRubinstein::new(&process, 1.0, 1, 100.0));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,16 @@
+use crate::types::{Real, Size};
+
+/*
+Recombining tree (QuantLib Tree): column i holds size(i) nodes, and
+node (i, index) moves to node (i + 1, descendant(i, index, branch))
+with probability probability(i, index, branch). Binomial trees have two
+branches (0 = down, 1 = up), trinomial trees three.
+*/
+pub trait Tree {
+    // Number of columns, i.e. time steps + 1
+    fn columns(&self) -> Size;
+    fn size(&self, i: Size) -> Size;
+    fn underlying(&self, i: Size, index: Size) -> Real;
+    fn descendant(&self, i: Size, index: Size, branch: Size) -> Size;
+    fn probability(&self, i: Size, index: Size, branch: Size) -> Real;
+}
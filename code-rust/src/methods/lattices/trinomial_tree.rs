@@ -0,0 +1,194 @@
+use crate::methods::lattices::tree::Tree;
+use crate::stochastic_process::StochasticProcess1D;
+use crate::time_grid::TimeGrid;
+use crate::types::{Real, Size, Time};
+
+/*
+Recombining trinomial tree for a 1-D process, typically a short rate
+(QuantLib TrinomialTree, Hull-White construction).
+
+On step i, with v^2 the variance of the process over dt_i, the nodes of
+column i + 1 are x0 + j dx_{i+1} with dx_{i+1} = v sqrt(3). A node x
+branches to the three nodes around k dx_{i+1}, the one closest to the
+expectation m of the process; with e = m - (x0 + k dx_{i+1}):
+    p_down = 1/6 + e^2 / (6 v^2) - e / (2 sqrt(3) v)
+    p_mid  = 2/3 - e^2 / (3 v^2)
+    p_up   = 1/6 + e^2 / (6 v^2) + e / (2 sqrt(3) v)
+which match the mean and variance of the process. With is_positive the
+branches are shifted up until all descendants are positive.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct TrinomialTree {
+    x0: Real,
+    dx: Vec<Real>,
+    branchings: Vec<detail::Branching>,
+}
+impl TrinomialTree {
+    // Constructor
+    pub fn new(
+        process: &dyn StochasticProcess1D,
+        time_grid: &TimeGrid,
+        is_positive: bool,
+    ) -> TrinomialTree {
+        let x0: Real = process.x0();
+        let steps: Size = time_grid.len() - 1;
+        let mut dx: Vec<Real> = vec![0.0; steps + 1];
+        let mut branchings: Vec<detail::Branching> = Vec::with_capacity(steps);
+        let (mut j_min, mut j_max): (i64, i64) = (0, 0);
+
+        for i in 0..steps {
+            let t: Time = time_grid.times()[i];
+            let dt: Time = time_grid.dt(i);
+            let v2: Real = process.variance(t, 0.0, dt);
+            let v: Real = v2.sqrt();
+            dx[i + 1] = v * 3.0_f64.sqrt();
+
+            let mut branching: detail::Branching = detail::Branching::default();
+            for j in j_min..=j_max {
+                let x: Real = x0 + j as Real * dx[i];
+                let m: Real = process.expectation(t, x, dt);
+                let mut k: i64 = ((m - x0) / dx[i + 1] + 0.5).floor() as i64;
+                if is_positive {
+                    while x0 + (k - 1) as Real * dx[i + 1] <= 0.0 {
+                        k += 1;
+                    }
+                }
+                let e: Real = m - (x0 + k as Real * dx[i + 1]);
+                let e2: Real = e * e;
+                let e3: Real = e * 3.0_f64.sqrt();
+                branching.add(
+                    k,
+                    [
+                        (1.0 + e2 / v2 - e3 / v) / 6.0,
+                        (2.0 - e2 / v2) / 3.0,
+                        (1.0 + e2 / v2 + e3 / v) / 6.0,
+                    ],
+                );
+            }
+            j_min = branching.j_min();
+            j_max = branching.j_max();
+            branchings.push(branching);
+        }
+        TrinomialTree { x0, dx, branchings }
+    }
+
+    // Node spacing of column i
+    pub fn dx(&self, i: Size) -> Real {
+        self.dx[i]
+    }
+}
+impl Tree for TrinomialTree {
+    fn columns(&self) -> Size {
+        self.branchings.len() + 1
+    }
+    fn size(&self, i: Size) -> Size {
+        if i == 0 {
+            1
+        } else {
+            self.branchings[i - 1].size()
+        }
+    }
+    fn underlying(&self, i: Size, index: Size) -> Real {
+        if i == 0 {
+            self.x0
+        } else {
+            self.x0 + (self.branchings[i - 1].j_min() + index as i64) as Real * self.dx[i]
+        }
+    }
+    fn descendant(&self, i: Size, index: Size, branch: Size) -> Size {
+        self.branchings[i].descendant(index, branch)
+    }
+    fn probability(&self, i: Size, index: Size, branch: Size) -> Real {
+        self.branchings[i].probabilities[index][branch]
+    }
+}
+
+mod detail {
+    use crate::types::{Real, Size};
+
+    /*
+    Branching of one column (QuantLib TrinomialTree::Branching): node
+    index branches around the node k of the next column, whose nodes go
+    from j_min = min(k) - 1 to j_max = max(k) + 1.
+    */
+    #[derive(Clone, PartialEq, Debug, Default)]
+    pub(super) struct Branching {
+        k: Vec<i64>,
+        pub(super) probabilities: Vec<[Real; 3]>,
+    }
+    impl Branching {
+        pub(super) fn add(&mut self, k: i64, probabilities: [Real; 3]) {
+            self.k.push(k);
+            self.probabilities.push(probabilities);
+        }
+        pub(super) fn j_min(&self) -> i64 {
+            self.k.iter().min().unwrap() - 1
+        }
+        pub(super) fn j_max(&self) -> i64 {
+            self.k.iter().max().unwrap() + 1
+        }
+        pub(super) fn size(&self) -> Size {
+            (self.j_max() - self.j_min() + 1) as Size
+        }
+        pub(super) fn descendant(&self, index: Size, branch: Size) -> Size {
+            (self.k[index] - self.j_min()) as Size + branch - 1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::processes::ornstein_uhlenbeck_process::OrnsteinUhlenbeckProcess;
+
+    #[test]
+    fn branches_match_the_process_moments() {
+        let process: OrnsteinUhlenbeckProcess =
+            OrnsteinUhlenbeckProcess::new(0.1, 0.01, 0.03, 0.05);
+        let grid: TimeGrid = TimeGrid::new(2.0, 20);
+        let tree: TrinomialTree = TrinomialTree::new(&process, &grid, false);
+
+        assert_eq!(tree.columns(), 21);
+        assert_eq!(tree.size(0), 1);
+        assert_eq!(tree.underlying(0, 0), 0.03);
+        for i in 0..20 {
+            let dt: Time = grid.dt(i);
+            for index in 0..tree.size(i) {
+                let x: Real = tree.underlying(i, index);
+                let mut mean: Real = 0.0;
+                let mut second_moment: Real = 0.0;
+                let mut total: Real = 0.0;
+                for branch in 0..3 {
+                    let p: Real = tree.probability(i, index, branch);
+                    let y: Real = tree.underlying(i + 1, tree.descendant(i, index, branch));
+                    assert!(p >= 0.0, "negative probability at ({i}, {index})");
+                    total += p;
+                    mean += p * y;
+                    second_moment += p * y * y;
+                }
+                let expected_mean: Real = process.expectation(grid.times()[i], x, dt);
+                assert!((total - 1.0).abs() < 1.0e-14);
+                assert!((mean - expected_mean).abs() < 1.0e-14);
+                assert!(
+                    (second_moment - mean * mean - process.variance(0.0, 0.0, dt)).abs() < 1.0e-14
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn positive_trees_stay_positive() {
+        let process: OrnsteinUhlenbeckProcess =
+            OrnsteinUhlenbeckProcess::new(0.5, 0.02, 0.01, 0.01);
+        let grid: TimeGrid = TimeGrid::new(5.0, 50);
+        let tree: TrinomialTree = TrinomialTree::new(&process, &grid, true);
+        for i in 1..tree.columns() {
+            for index in 0..tree.size(i - 1) {
+                for branch in 0..3 {
+                    let descendant: Size = tree.descendant(i - 1, index, branch);
+                    assert!(tree.underlying(i, descendant) > 0.0);
+                }
+            }
+        }
+    }
+}
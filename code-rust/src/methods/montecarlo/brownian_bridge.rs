@@ -0,0 +1,223 @@
+use crate::time_grid::TimeGrid;
+use crate::types::{Real, Size, Time};
+
+/*
+Brownian bridge (QuantLib BrownianBridge).
+
+Turns n independent Gaussian draws z into the n normalized increments
+of a Brownian path on the grid t_1 < ... < t_n, building the path in
+bridge order instead of left to right:
+    - z_0 sets the terminal point W(t_n) = sqrt(t_n) z_0
+    - each following draw fills the midpoint l of an interval [j, k]
+      whose ends are already known, from the Brownian bridge law
+        W(t_l) = w_left W(t_{j-1}) + w_right W(t_k) + sigma_l z_i
+The first draws thus carry most of the variance of the path, which
+concentrates the effective dimension in the first (best distributed)
+coordinates of low-discrepancy sequences.
+The output increments are divided by sqrt(dt) so they can be fed to a
+process as standard Gaussian draws.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct BrownianBridge {
+    size: Size,
+    t: Vec<Time>,
+    sqrt_dt: Vec<Real>,
+    bridge_index: Vec<Size>,
+    left_index: Vec<Size>,
+    right_index: Vec<Size>,
+    left_weight: Vec<Real>,
+    right_weight: Vec<Real>,
+    std_dev: Vec<Real>,
+}
+impl BrownianBridge {
+    // Constructor
+    pub fn new(time_grid: &TimeGrid) -> BrownianBridge {
+        let times: &[Time] = time_grid.times();
+        if times.len() < 2 {
+            panic!("the time grid must have at least one step");
+        }
+        BrownianBridge::from_times(&times[1..])
+    }
+    // Times of the path points, t = 0 excluded
+    pub fn from_times(times: &[Time]) -> BrownianBridge {
+        let size: Size = times.len();
+        if size == 0 {
+            panic!("there must be at least one step");
+        }
+        let t: Vec<Time> = times.to_vec();
+        let mut sqrt_dt: Vec<Real> = vec![t[0].sqrt(); size];
+        for i in 1..size {
+            sqrt_dt[i] = (t[i] - t[i - 1]).sqrt();
+        }
+
+        let mut bridge_index: Vec<Size> = vec![0; size];
+        let mut left_index: Vec<Size> = vec![0; size];
+        let mut right_index: Vec<Size> = vec![0; size];
+        let mut left_weight: Vec<Real> = vec![0.0; size];
+        let mut right_weight: Vec<Real> = vec![0.0; size];
+        let mut std_dev: Vec<Real> = vec![0.0; size];
+
+        // map[i] != 0 once point i is constructed (by variate map[i] - 1)
+        let mut map: Vec<Size> = vec![0; size];
+        // The global step is constructed first
+        map[size - 1] = 1;
+        bridge_index[0] = size - 1;
+        std_dev[0] = t[size - 1].sqrt();
+
+        let mut j: Size = 0;
+        for i in 1..size {
+            // Next unpopulated entry
+            while map[j] != 0 {
+                j += 1;
+            }
+            // Next populated entry from there
+            let mut k: Size = j;
+            while map[k] == 0 {
+                k += 1;
+            }
+            // Point to be constructed next
+            let l: Size = j + ((k - 1 - j) >> 1);
+            map[l] = i;
+            bridge_index[i] = l;
+            left_index[i] = j;
+            right_index[i] = k;
+            if j != 0 {
+                left_weight[i] = (t[k] - t[l]) / (t[k] - t[j - 1]);
+                right_weight[i] = (t[l] - t[j - 1]) / (t[k] - t[j - 1]);
+                std_dev[i] = ((t[l] - t[j - 1]) * (t[k] - t[l]) / (t[k] - t[j - 1])).sqrt();
+            } else {
+                left_weight[i] = (t[k] - t[l]) / t[k];
+                right_weight[i] = t[l] / t[k];
+                std_dev[i] = (t[l] * (t[k] - t[l]) / t[k]).sqrt();
+            }
+            j = k + 1;
+            if j >= size {
+                // Wrap around
+                j = 0;
+            }
+        }
+
+        BrownianBridge {
+            size,
+            t,
+            sqrt_dt,
+            bridge_index,
+            left_index,
+            right_index,
+            left_weight,
+            right_weight,
+            std_dev,
+        }
+    }
+
+    // Inspectors
+    pub fn size(&self) -> Size {
+        self.size
+    }
+    pub fn times(&self) -> &[Time] {
+        &self.t
+    }
+
+    // Normalized path increments built from the Gaussian draws
+    pub fn transform(&self, input: &[Real], output: &mut [Real]) {
+        if input.len() != self.size || output.len() != self.size {
+            panic!(
+                "incompatible sequence sizes ({}, {}), {} required",
+                input.len(),
+                output.len(),
+                self.size
+            );
+        }
+        // Path values first...
+        output[self.size - 1] = self.std_dev[0] * input[0];
+        for (i, z) in input.iter().enumerate().skip(1) {
+            let j: Size = self.left_index[i];
+            let k: Size = self.right_index[i];
+            let l: Size = self.bridge_index[i];
+            output[l] = if j != 0 {
+                self.left_weight[i] * output[j - 1]
+                    + self.right_weight[i] * output[k]
+                    + self.std_dev[i] * z
+            } else {
+                self.right_weight[i] * output[k] + self.std_dev[i] * z
+            };
+        }
+        // ...then the increments, normalized to unit time
+        for i in (1..self.size).rev() {
+            output[i] -= output[i - 1];
+            output[i] /= self.sqrt_dt[i];
+        }
+        output[0] /= self.sqrt_dt[0];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    // Columns of the linear map z -> increments
+    fn transform_matrix(bridge: &BrownianBridge) -> Vec<Vec<Real>> {
+        let n: Size = bridge.size();
+        (0..n)
+            .map(|c| {
+                let mut input: Vec<Real> = vec![0.0; n];
+                input[c] = 1.0;
+                let mut output: Vec<Real> = vec![0.0; n];
+                bridge.transform(&input, &mut output);
+                output
+            })
+            .collect()
+    }
+
+    #[test]
+    fn increments_are_independent_standard_gaussians() {
+        // If z ~ N(0, I) the increments are A z, so A A^T must be I
+        let grids: [TimeGrid; 3] = [
+            TimeGrid::new(1.0, 1),
+            TimeGrid::new(2.0, 8),
+            TimeGrid::from_mandatory_times(&[0.1, 0.25, 0.7, 1.3, 2.0, 3.5, 5.0], 0),
+        ];
+        for grid in grids {
+            let bridge: BrownianBridge = BrownianBridge::new(&grid);
+            let columns: Vec<Vec<Real>> = transform_matrix(&bridge);
+            let n: Size = bridge.size();
+            for r in 0..n {
+                for s in 0..n {
+                    let product: Real = columns.iter().map(|c| c[r] * c[s]).sum();
+                    let expected: Real = if r == s { 1.0 } else { 0.0 };
+                    assert!(
+                        (product - expected).abs() < 1.0e-13,
+                        "(A A^T)[{r}][{s}] = {product} on {n} steps"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn first_draw_sets_the_terminal_point() {
+        // Only z_0 = 1: the path is a straight line to W(T) = sqrt(T)
+        let grid: TimeGrid = TimeGrid::new(4.0, 4);
+        let bridge: BrownianBridge = BrownianBridge::new(&grid);
+        let mut output: Vec<Real> = vec![0.0; 4];
+        bridge.transform(&[1.0, 0.0, 0.0, 0.0], &mut output);
+
+        // dt = 1, so the normalized increments add up to W(T)
+        let terminal: Real = output.iter().sum();
+        assert!((terminal - 2.0).abs() < 1.0e-14);
+        for dw in output {
+            assert!((dw - 0.5).abs() < 1.0e-14);
+        }
+    }
+
+    #[test]
+    fn transform_panics_on_wrong_sizes() {
+        let bridge: BrownianBridge = BrownianBridge::new(&TimeGrid::new(1.0, 4));
+        let result = panic::catch_unwind(|| {
+            let mut output: Vec<Real> = vec![0.0; 4];
+            bridge.transform(&[0.0; 3], &mut output);
+        });
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,15 @@
+use crate::methods::montecarlo::sample::Sample;
+
+/*
+Path generators as seen by the Monte Carlo model (QuantLib mctraits.hpp):
+single-variate generators produce a Path, multi-variate ones a MultiPath.
+
+next draws a new path; antithetic returns the mirror image of the last
+one, obtained by flipping the sign of the same Gaussian draws.
+*/
+pub trait SamplePathGenerator {
+    type PathType;
+
+    fn next(&mut self) -> &Sample<Self::PathType>;
+    fn antithetic(&mut self) -> &Sample<Self::PathType>;
+}
@@ -0,0 +1,240 @@
+use crate::math::statistics::incremental_statistics::IncrementalStatistics;
+use crate::math::statistics::sample_accumulator::SampleAccumulator;
+use crate::methods::montecarlo::mc_traits::SamplePathGenerator;
+use crate::methods::montecarlo::path_pricer::PathPricer;
+use crate::methods::montecarlo::sample::Sample;
+use crate::types::{Real, Size};
+
+/*
+General-purpose Monte Carlo model (QuantLib MonteCarloModel).
+
+Draws paths, prices them and accumulates the results. Two variance
+reduction techniques are available:
+    - antithetic variates: every path is priced together with its
+      antithetic path and the average of the two prices is accumulated
+    - control variates: a second pricer with a known expected value
+      (e.g. the analytic price of a similar instrument) corrects each
+      sample as price + (cv_value - cv_price). An optional separate path
+      generator can be given for the control variate; by default it is
+      priced on the same path.
+Prices are fed to any SampleAccumulator: IncrementalStatistics by
+default, RiskStatistics for percentiles of the simulated values, or
+ConvergenceStatistics to follow the convergence of the mean.
+*/
+pub struct MonteCarloModel<
+    G: SamplePathGenerator,
+    P: PathPricer<G::PathType>,
+    S: SampleAccumulator = IncrementalStatistics,
+> {
+    path_generator: G,
+    path_pricer: P,
+    sample_accumulator: S,
+    is_antithetic_variate: bool,
+    cv_path_pricer: Option<Box<dyn PathPricer<G::PathType>>>,
+    cv_option_value: Real,
+    cv_path_generator: Option<G>,
+}
+impl<G: SamplePathGenerator, P: PathPricer<G::PathType>, S: SampleAccumulator>
+    MonteCarloModel<G, P, S>
+{
+    // Constructor
+    pub fn new(
+        path_generator: G,
+        path_pricer: P,
+        sample_accumulator: S,
+        antithetic_variate: bool,
+        cv_path_pricer: Option<Box<dyn PathPricer<G::PathType>>>,
+        cv_option_value: Real,
+        cv_path_generator: Option<G>,
+    ) -> MonteCarloModel<G, P, S> {
+        MonteCarloModel {
+            path_generator,
+            path_pricer,
+            sample_accumulator,
+            is_antithetic_variate: antithetic_variate,
+            cv_path_pricer,
+            cv_option_value,
+            cv_path_generator,
+        }
+    }
+
+    pub fn add_samples(&mut self, samples: Size) {
+        for _ in 0..samples {
+            let path: &Sample<G::PathType> = self.path_generator.next();
+            let mut price: Real = self.path_pricer.value(&path.value);
+            let mut weight: Real = path.weight;
+            if let Some(cv_path_pricer) = &self.cv_path_pricer {
+                let cv_price: Real = match &mut self.cv_path_generator {
+                    None => cv_path_pricer.value(&path.value),
+                    Some(cv_path_generator) => {
+                        cv_path_pricer.value(&cv_path_generator.next().value)
+                    }
+                };
+                price += self.cv_option_value - cv_price;
+            }
+
+            if self.is_antithetic_variate {
+                let path: &Sample<G::PathType> = self.path_generator.antithetic();
+                let mut price2: Real = self.path_pricer.value(&path.value);
+                weight = path.weight;
+                if let Some(cv_path_pricer) = &self.cv_path_pricer {
+                    let cv_price: Real = match &mut self.cv_path_generator {
+                        None => cv_path_pricer.value(&path.value),
+                        Some(cv_path_generator) => {
+                            cv_path_pricer.value(&cv_path_generator.antithetic().value)
+                        }
+                    };
+                    price2 += self.cv_option_value - cv_price;
+                }
+                price = (price + price2) / 2.0;
+            }
+            self.sample_accumulator.add(price, weight);
+        }
+    }
+
+    // Inspectors
+    pub fn sample_accumulator(&self) -> &S {
+        &self.sample_accumulator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::randomnumbers::rng_traits::{PseudoRandom, PseudoRandomRsg};
+    use crate::math::statistics::convergence_statistics::ConvergenceStatistics;
+    use crate::math::statistics::risk_statistics::RiskStatistics;
+    use crate::methods::montecarlo::path::Path;
+    use crate::methods::montecarlo::path_generator::PathGenerator;
+    use crate::processes::geometric_brownian_process::GeometricBrownianMotionProcess;
+    use crate::time_grid::TimeGrid;
+
+    type Generator = PathGenerator<GeometricBrownianMotionProcess, PseudoRandomRsg>;
+
+    fn generator(steps: Size, seed: u64) -> Generator {
+        PathGenerator::new(
+            GeometricBrownianMotionProcess::new(100.0, 0.05, 0.2),
+            TimeGrid::new(1.0, steps),
+            PseudoRandom::make_sequence_generator(steps, seed),
+            false,
+        )
+    }
+
+    fn terminal_value(path: &Path) -> Real {
+        path.back()
+    }
+
+    #[test]
+    fn plain_monte_carlo_estimates_the_expectation() {
+        // Euler steps of GBM: E[S_T] = S_0 (1 + mu dt)^n
+        let mut model: MonteCarloModel<Generator, fn(&Path) -> Real> = MonteCarloModel::new(
+            generator(4, 42),
+            terminal_value,
+            IncrementalStatistics::new(),
+            false,
+            None,
+            0.0,
+            None,
+        );
+        model.add_samples(20_000);
+
+        let statistics: &IncrementalStatistics = model.sample_accumulator();
+        let expected: Real = 100.0 * (1.0 + 0.05 * 0.25_f64).powi(4);
+        assert_eq!(statistics.samples(), 20_000);
+        assert!((statistics.mean() - expected).abs() < 3.0 * statistics.error_estimate());
+        assert!(statistics.error_estimate() < 0.2);
+    }
+
+    #[test]
+    fn antithetic_variates_cancel_linear_payoffs() {
+        // With one Euler step S_T is linear in the draw: the average of a
+        // path and its antithetic path is exactly the expectation
+        let mut model: MonteCarloModel<Generator, fn(&Path) -> Real> = MonteCarloModel::new(
+            generator(1, 42),
+            terminal_value,
+            IncrementalStatistics::new(),
+            true,
+            None,
+            0.0,
+            None,
+        );
+        model.add_samples(100);
+
+        let statistics: &IncrementalStatistics = model.sample_accumulator();
+        assert!((statistics.mean() - 105.0).abs() < 1.0e-12);
+        assert!(statistics.standard_deviation() < 1.0e-12);
+    }
+
+    #[test]
+    fn control_variate_reduces_the_error() {
+        let call = |path: &Path| (path.back() - 100.0).max(0.0);
+        let cv_value: Real = 100.0 * (1.0 + 0.05 * 0.25_f64).powi(4);
+        let cv_path_pricer: Box<dyn PathPricer<Path>> = Box::new(terminal_value);
+
+        let mut plain: MonteCarloModel<Generator, _> = MonteCarloModel::new(
+            generator(4, 42),
+            call,
+            IncrementalStatistics::new(),
+            false,
+            None,
+            0.0,
+            None,
+        );
+        let mut controlled: MonteCarloModel<Generator, _> = MonteCarloModel::new(
+            generator(4, 42),
+            call,
+            IncrementalStatistics::new(),
+            false,
+            Some(cv_path_pricer),
+            cv_value,
+            None,
+        );
+        plain.add_samples(10_000);
+        controlled.add_samples(10_000);
+
+        // The forward is a good control for an ATM call
+        let plain_error: Real = plain.sample_accumulator().error_estimate();
+        let controlled_error: Real = controlled.sample_accumulator().error_estimate();
+        assert!(
+            controlled_error < 0.75 * plain_error,
+            "{controlled_error} vs {plain_error}"
+        );
+        assert!(
+            (controlled.sample_accumulator().mean() - plain.sample_accumulator().mean()).abs()
+                < 3.0 * plain_error
+        );
+    }
+
+    #[test]
+    fn any_sample_accumulator_can_be_used() {
+        let mut model: MonteCarloModel<
+            Generator,
+            fn(&Path) -> Real,
+            ConvergenceStatistics<RiskStatistics>,
+        > = MonteCarloModel::new(
+            generator(4, 42),
+            terminal_value,
+            ConvergenceStatistics::default(),
+            false,
+            None,
+            0.0,
+            None,
+        );
+        model.add_samples(1_000);
+
+        let convergence: &ConvergenceStatistics<RiskStatistics> = model.sample_accumulator();
+        let table: &[(Size, Real)] = convergence.convergence_table();
+        assert_eq!(table.len(), 9);
+        assert_eq!(table[8].0, 511);
+        // The terminal value is about 100 exp(0.05 +- 0.2 * 1.645) at the 5% and 95% levels
+        let statistics: &RiskStatistics = convergence.statistics();
+        let low: Real = statistics.statistics().percentile(0.05);
+        let high: Real = statistics.statistics().top_percentile(0.05);
+        assert!(low > 70.0 && low < 80.0, "{}", low);
+        assert!(high > 135.0 && high < 150.0, "{}", high);
+        assert_eq!(
+            statistics.potential_upside(0.95),
+            statistics.statistics().percentile(0.95)
+        );
+    }
+}
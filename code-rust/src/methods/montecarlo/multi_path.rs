@@ -0,0 +1,41 @@
+use crate::methods::montecarlo::path::Path;
+use crate::time_grid::TimeGrid;
+use crate::types::Size;
+use std::ops::{Index, IndexMut};
+
+// Correlated random walks of several assets on the same time grid (QuantLib MultiPath)
+#[derive(Clone, PartialEq, Debug)]
+pub struct MultiPath {
+    multi_path: Vec<Path>,
+}
+impl MultiPath {
+    // Constructor
+    pub fn new(n_asset: Size, time_grid: TimeGrid) -> MultiPath {
+        if n_asset == 0 {
+            panic!("number of asset must be positive");
+        }
+        MultiPath {
+            multi_path: vec![Path::new(time_grid); n_asset],
+        }
+    }
+
+    // Inspectors
+    pub fn asset_number(&self) -> Size {
+        self.multi_path.len()
+    }
+    pub fn path_size(&self) -> Size {
+        self.multi_path[0].len()
+    }
+}
+impl Index<Size> for MultiPath {
+    type Output = Path;
+
+    fn index(&self, j: Size) -> &Path {
+        &self.multi_path[j]
+    }
+}
+impl IndexMut<Size> for MultiPath {
+    fn index_mut(&mut self, j: Size) -> &mut Path {
+        &mut self.multi_path[j]
+    }
+}
@@ -0,0 +1,202 @@
+use crate::math::randomnumbers::rng_traits::SequenceGenerator;
+use crate::methods::montecarlo::mc_traits::SamplePathGenerator;
+use crate::methods::montecarlo::multi_path::MultiPath;
+use crate::methods::montecarlo::sample::Sample;
+use crate::stochastic_process::StochasticProcess;
+use crate::time_grid::TimeGrid;
+use crate::types::{Real, Size, Time};
+
+/*
+Generates correlated random paths of a multi-dimensional process
+(QuantLib MultiPathGenerator).
+
+The Gaussian sequence holds factors draws per time step, laid out
+step by step: draws [(i-1) * factors, i * factors) drive the step from
+t_{i-1} to t_i for all assets at once. Correlation comes from the
+process diffusion matrix. As in QuantLib, no Brownian bridge is
+available here.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct MultiPathGenerator<P: StochasticProcess, G: SequenceGenerator> {
+    process: P,
+    generator: G,
+    time_grid: TimeGrid,
+    next: Sample<MultiPath>,
+}
+impl<P: StochasticProcess, G: SequenceGenerator> MultiPathGenerator<P, G> {
+    // Constructor
+    pub fn new(process: P, time_grid: TimeGrid, generator: G) -> MultiPathGenerator<P, G> {
+        let factors: Size = process.factors();
+        let steps: Size = time_grid.len() - 1;
+        if generator.dimension() != factors * steps {
+            panic!(
+                "dimension ({}) is not equal to ({} * {}) the number of factors times the number of steps",
+                generator.dimension(),
+                factors,
+                steps
+            );
+        }
+        MultiPathGenerator {
+            next: Sample::new(MultiPath::new(process.size(), time_grid.clone()), 1.0),
+            process,
+            generator,
+            time_grid,
+        }
+    }
+
+    fn next_path(&mut self, antithetic: bool) -> &Sample<MultiPath> {
+        let sequence: &Sample<Vec<Real>> = if antithetic {
+            self.generator.last_sequence()
+        } else {
+            self.generator.next_sequence()
+        };
+        let factors: Size = self.process.factors();
+        let path: &mut MultiPath = &mut self.next.value;
+
+        let mut asset: Vec<Real> = self.process.initial_values();
+        for (j, x) in asset.iter().enumerate() {
+            path[j][0] = *x;
+        }
+        self.next.weight = sequence.weight;
+
+        let mut dw: Vec<Real> = vec![0.0; factors];
+        for i in 1..path.path_size() {
+            let offset: Size = (i - 1) * factors;
+            let t: Time = self.time_grid[i - 1];
+            let dt: Time = self.time_grid.dt(i - 1);
+            for (k, w) in dw.iter_mut().enumerate() {
+                let draw: Real = sequence.value[offset + k];
+                *w = if antithetic { -draw } else { draw };
+            }
+            asset = self.process.evolve(t, &asset, dt, &dw);
+            for (j, x) in asset.iter().enumerate() {
+                path[j][i] = *x;
+            }
+        }
+        &self.next
+    }
+}
+impl<P: StochasticProcess, G: SequenceGenerator> SamplePathGenerator for MultiPathGenerator<P, G> {
+    type PathType = MultiPath;
+
+    fn next(&mut self) -> &Sample<MultiPath> {
+        self.next_path(false)
+    }
+    fn antithetic(&mut self) -> &Sample<MultiPath> {
+        self.next_path(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::randomnumbers::rng_traits::{
+        LowDiscrepancy, LowDiscrepancyRsg, PseudoRandom, PseudoRandomRsg,
+    };
+    use std::panic;
+
+    // Two correlated arithmetic Brownian motions
+    #[derive(Copy, Clone, PartialEq, Debug)]
+    struct CorrelatedBrownianMotions {
+        rho: Real,
+    }
+    impl StochasticProcess for CorrelatedBrownianMotions {
+        fn size(&self) -> Size {
+            2
+        }
+        fn initial_values(&self) -> Vec<Real> {
+            vec![1.0, 2.0]
+        }
+        fn drift(&self, _t: Time, _x: &[Real]) -> Vec<Real> {
+            vec![0.1, -0.1]
+        }
+        fn diffusion(&self, _t: Time, _x: &[Real]) -> Vec<Vec<Real>> {
+            vec![
+                vec![0.2, 0.0],
+                vec![0.3 * self.rho, 0.3 * (1.0 - self.rho * self.rho).sqrt()],
+            ]
+        }
+    }
+
+    #[test]
+    fn paths_follow_the_process_evolution() {
+        let process: CorrelatedBrownianMotions = CorrelatedBrownianMotions { rho: 0.5 };
+        let grid: TimeGrid = TimeGrid::new(1.0, 3);
+        let mut generator: MultiPathGenerator<CorrelatedBrownianMotions, PseudoRandomRsg> =
+            MultiPathGenerator::new(
+                process,
+                grid.clone(),
+                PseudoRandom::make_sequence_generator(6, 42),
+            );
+        let mut draws: PseudoRandomRsg = PseudoRandom::make_sequence_generator(6, 42);
+
+        let dw: Vec<Real> = draws.next_sequence().value.clone();
+        let path: MultiPath = generator.next().value.clone();
+        let antithetic: MultiPath = generator.antithetic().value.clone();
+
+        assert_eq!(path.asset_number(), 2);
+        assert_eq!(path.path_size(), 4);
+        let mut x: Vec<Real> = vec![1.0, 2.0];
+        let mut y: Vec<Real> = vec![1.0, 2.0];
+        for i in 1..4 {
+            let w: [Real; 2] = [dw[2 * (i - 1)], dw[2 * (i - 1) + 1]];
+            x = process.evolve(grid[i - 1], &x, grid.dt(i - 1), &w);
+            y = process.evolve(grid[i - 1], &y, grid.dt(i - 1), &[-w[0], -w[1]]);
+            for j in 0..2 {
+                assert_eq!(path[j][i], x[j]);
+                assert_eq!(antithetic[j][i], y[j]);
+            }
+        }
+    }
+
+    #[test]
+    fn terminal_values_have_the_process_correlation() {
+        let rho: Real = 0.6;
+        let process: CorrelatedBrownianMotions = CorrelatedBrownianMotions { rho };
+        let mut generator: MultiPathGenerator<CorrelatedBrownianMotions, LowDiscrepancyRsg> =
+            MultiPathGenerator::new(
+                process,
+                TimeGrid::new(1.0, 4),
+                LowDiscrepancy::make_sequence_generator(8),
+            );
+        let n: usize = 8_191;
+        let (mut sum_x, mut sum_y, mut sum_xy, mut sum_xx, mut sum_yy): (
+            Real,
+            Real,
+            Real,
+            Real,
+            Real,
+        ) = (0.0, 0.0, 0.0, 0.0, 0.0);
+        for _ in 0..n {
+            let path: &MultiPath = &generator.next().value;
+            let (x, y): (Real, Real) = (path[0].back(), path[1].back());
+            sum_x += x;
+            sum_y += y;
+            sum_xy += x * y;
+            sum_xx += x * x;
+            sum_yy += y * y;
+        }
+        let n: Real = n as Real;
+        let covariance: Real = sum_xy / n - sum_x * sum_y / (n * n);
+        let variance_x: Real = sum_xx / n - sum_x * sum_x / (n * n);
+        let variance_y: Real = sum_yy / n - sum_y * sum_y / (n * n);
+
+        assert!((sum_x / n - 1.1).abs() < 1.0e-3);
+        assert!((sum_y / n - 1.9).abs() < 1.0e-3);
+        assert!((variance_x - 0.04).abs() < 1.0e-3);
+        assert!((variance_y - 0.09).abs() < 1.0e-3);
+        assert!((covariance / (variance_x * variance_y).sqrt() - rho).abs() < 1.0e-2);
+    }
+
+    #[test]
+    fn new_panics_on_dimension_mismatch() {
+        let result = panic::catch_unwind(|| {
+            MultiPathGenerator::new(
+                CorrelatedBrownianMotions { rho: 0.0 },
+                TimeGrid::new(1.0, 3),
+                PseudoRandom::make_sequence_generator(3, 42),
+            )
+        });
+        assert!(result.is_err());
+    }
+}
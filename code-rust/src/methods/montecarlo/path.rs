@@ -0,0 +1,66 @@
+use crate::time_grid::TimeGrid;
+use crate::types::{Real, Size, Time};
+use std::ops::{Index, IndexMut};
+
+/*
+Single-factor random walk (QuantLib Path): the values of the
+underlying at each point of a time grid. values[0] is the value at
+t = 0, so a path has as many values as the grid has times.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct Path {
+    time_grid: TimeGrid,
+    values: Vec<Real>,
+}
+impl Path {
+    // Constructor
+    pub fn new(time_grid: TimeGrid) -> Path {
+        let values: Vec<Real> = vec![0.0; time_grid.len()];
+        Path { time_grid, values }
+    }
+    pub fn with_values(time_grid: TimeGrid, values: Vec<Real>) -> Path {
+        if values.len() != time_grid.len() {
+            panic!(
+                "different number of times ({}) and values ({})",
+                time_grid.len(),
+                values.len()
+            );
+        }
+        Path { time_grid, values }
+    }
+
+    // Inspectors
+    pub fn len(&self) -> Size {
+        self.time_grid.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.time_grid.is_empty()
+    }
+    pub fn time(&self, i: Size) -> Time {
+        self.time_grid[i]
+    }
+    pub fn front(&self) -> Real {
+        self.values[0]
+    }
+    pub fn back(&self) -> Real {
+        self.values[self.values.len() - 1]
+    }
+    pub fn values(&self) -> &[Real] {
+        &self.values
+    }
+    pub fn time_grid(&self) -> &TimeGrid {
+        &self.time_grid
+    }
+}
+impl Index<Size> for Path {
+    type Output = Real;
+
+    fn index(&self, i: Size) -> &Real {
+        &self.values[i]
+    }
+}
+impl IndexMut<Size> for Path {
+    fn index_mut(&mut self, i: Size) -> &mut Real {
+        &mut self.values[i]
+    }
+}
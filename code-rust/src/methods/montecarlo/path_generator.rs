@@ -0,0 +1,221 @@
+use crate::math::randomnumbers::rng_traits::SequenceGenerator;
+use crate::methods::montecarlo::brownian_bridge::BrownianBridge;
+use crate::methods::montecarlo::mc_traits::SamplePathGenerator;
+use crate::methods::montecarlo::path::Path;
+use crate::methods::montecarlo::sample::Sample;
+use crate::stochastic_process::StochasticProcess1D;
+use crate::time_grid::TimeGrid;
+use crate::types::{Real, Size, Time};
+
+/*
+Generates random paths of a 1-D process (QuantLib PathGenerator).
+
+Each path consumes one Gaussian sequence with one draw per time step:
+    x_0 = process.x0()
+    x_i = process.evolve(t_{i-1}, x_{i-1}, dt_{i-1}, dw_{i-1})
+With brownian_bridge the draws are first reordered by a Brownian bridge,
+which pays off with low-discrepancy generators.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct PathGenerator<P: StochasticProcess1D, G: SequenceGenerator> {
+    brownian_bridge: bool,
+    generator: G,
+    dimension: Size,
+    time_grid: TimeGrid,
+    process: P,
+    next: Sample<Path>,
+    temp: Vec<Real>,
+    bb: BrownianBridge,
+}
+impl<P: StochasticProcess1D, G: SequenceGenerator> PathGenerator<P, G> {
+    // Constructor
+    pub fn new(
+        process: P,
+        time_grid: TimeGrid,
+        generator: G,
+        brownian_bridge: bool,
+    ) -> PathGenerator<P, G> {
+        let dimension: Size = generator.dimension();
+        if dimension != time_grid.len() - 1 {
+            panic!(
+                "sequence generator dimensionality ({}) != timeSteps ({})",
+                dimension,
+                time_grid.len() - 1
+            );
+        }
+        PathGenerator {
+            brownian_bridge,
+            generator,
+            dimension,
+            bb: BrownianBridge::new(&time_grid),
+            next: Sample::new(Path::new(time_grid.clone()), 1.0),
+            temp: vec![0.0; dimension],
+            time_grid,
+            process,
+        }
+    }
+
+    // Inspectors
+    pub fn size(&self) -> Size {
+        self.dimension
+    }
+    pub fn time_grid(&self) -> &TimeGrid {
+        &self.time_grid
+    }
+
+    fn next_path(&mut self, antithetic: bool) -> &Sample<Path> {
+        let sequence: &Sample<Vec<Real>> = if antithetic {
+            self.generator.last_sequence()
+        } else {
+            self.generator.next_sequence()
+        };
+        if self.brownian_bridge {
+            self.bb.transform(&sequence.value, &mut self.temp);
+        } else {
+            self.temp.copy_from_slice(&sequence.value);
+        }
+        self.next.weight = sequence.weight;
+
+        let path: &mut Path = &mut self.next.value;
+        path[0] = self.process.x0();
+        for i in 1..path.len() {
+            let t: Time = self.time_grid[i - 1];
+            let dt: Time = self.time_grid.dt(i - 1);
+            let dw: Real = if antithetic {
+                -self.temp[i - 1]
+            } else {
+                self.temp[i - 1]
+            };
+            path[i] = self.process.evolve(t, path[i - 1], dt, dw);
+        }
+        &self.next
+    }
+}
+impl<P: StochasticProcess1D, G: SequenceGenerator> SamplePathGenerator for PathGenerator<P, G> {
+    type PathType = Path;
+
+    fn next(&mut self) -> &Sample<Path> {
+        self.next_path(false)
+    }
+    fn antithetic(&mut self) -> &Sample<Path> {
+        self.next_path(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::randomnumbers::rng_traits::{
+        LowDiscrepancy, LowDiscrepancyRsg, PseudoRandom, PseudoRandomRsg,
+    };
+    use crate::processes::geometric_brownian_process::GeometricBrownianMotionProcess;
+    use crate::processes::ornstein_uhlenbeck_process::OrnsteinUhlenbeckProcess;
+    use std::panic;
+
+    #[test]
+    fn paths_follow_the_process_evolution() {
+        let process: GeometricBrownianMotionProcess =
+            GeometricBrownianMotionProcess::new(100.0, 0.05, 0.2);
+        let grid: TimeGrid = TimeGrid::new(1.0, 4);
+        let mut generator: PathGenerator<GeometricBrownianMotionProcess, PseudoRandomRsg> =
+            PathGenerator::new(
+                process,
+                grid.clone(),
+                PseudoRandom::make_sequence_generator(4, 42),
+                false,
+            );
+        let mut draws: PseudoRandomRsg = PseudoRandom::make_sequence_generator(4, 42);
+
+        for _ in 0..3 {
+            let dw: Vec<Real> = draws.next_sequence().value.clone();
+            let path: Path = generator.next().value.clone();
+            let antithetic: Path = generator.antithetic().value.clone();
+
+            assert_eq!(path.len(), 5);
+            assert_eq!(path.front(), 100.0);
+            assert_eq!(antithetic.front(), 100.0);
+            for i in 1..5 {
+                let expected: Real = process.evolve(grid[i - 1], path[i - 1], 0.25, dw[i - 1]);
+                assert_eq!(path[i], expected);
+                let expected: Real =
+                    process.evolve(grid[i - 1], antithetic[i - 1], 0.25, -dw[i - 1]);
+                assert_eq!(antithetic[i], expected);
+            }
+        }
+    }
+
+    #[test]
+    fn same_seed_same_paths() {
+        let process: OrnsteinUhlenbeckProcess = OrnsteinUhlenbeckProcess::new(0.5, 0.1, 0.03, 0.04);
+        let grid: TimeGrid = TimeGrid::new(2.0, 8);
+        let mut generator1: PathGenerator<OrnsteinUhlenbeckProcess, PseudoRandomRsg> =
+            PathGenerator::new(
+                process,
+                grid.clone(),
+                PseudoRandom::make_sequence_generator(8, 1),
+                true,
+            );
+        let mut generator2: PathGenerator<OrnsteinUhlenbeckProcess, PseudoRandomRsg> =
+            PathGenerator::new(
+                process,
+                grid,
+                PseudoRandom::make_sequence_generator(8, 1),
+                true,
+            );
+        for _ in 0..10 {
+            assert_eq!(generator1.next(), generator2.next());
+        }
+    }
+
+    #[test]
+    fn brownian_bridge_keeps_the_terminal_distribution() {
+        // OU has an exact transition law, so E[x_T] and Var[x_T] do not
+        // depend on the discretization or on the ordering of the draws
+        let (speed, volatility, x0, level): (Real, Real, Real, Real) = (0.5, 0.1, 0.03, 0.04);
+        let process: OrnsteinUhlenbeckProcess =
+            OrnsteinUhlenbeckProcess::new(speed, volatility, x0, level);
+        let grid: TimeGrid = TimeGrid::new(2.0, 8);
+        let expected_mean: Real = process.expectation(0.0, x0, 2.0);
+        let expected_variance: Real = process.variance(0.0, x0, 2.0);
+
+        for brownian_bridge in [false, true] {
+            let mut generator: PathGenerator<OrnsteinUhlenbeckProcess, LowDiscrepancyRsg> =
+                PathGenerator::new(
+                    process,
+                    grid.clone(),
+                    LowDiscrepancy::make_sequence_generator(8),
+                    brownian_bridge,
+                );
+            let n: usize = 4_095;
+            let (mut sum, mut sum_squares): (Real, Real) = (0.0, 0.0);
+            for _ in 0..n {
+                let x: Real = generator.next().value.back();
+                sum += x;
+                sum_squares += x * x;
+            }
+            let mean: Real = sum / n as Real;
+            let variance: Real = sum_squares / n as Real - mean * mean;
+            assert!(
+                (mean - expected_mean).abs() < 1.0e-4,
+                "bridge {brownian_bridge}: mean {mean}"
+            );
+            assert!(
+                (variance / expected_variance - 1.0).abs() < 3.0e-2,
+                "bridge {brownian_bridge}: variance {variance}"
+            );
+        }
+    }
+
+    #[test]
+    fn new_panics_on_dimension_mismatch() {
+        let result = panic::catch_unwind(|| {
+            PathGenerator::new(
+                GeometricBrownianMotionProcess::new(100.0, 0.05, 0.2),
+                TimeGrid::new(1.0, 4),
+                PseudoRandom::make_sequence_generator(3, 42),
+                false,
+            )
+        });
+        assert!(result.is_err());
+    }
+}
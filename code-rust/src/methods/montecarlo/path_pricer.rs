@@ -0,0 +1,17 @@
+use crate::types::Real;
+
+/*
+Path pricer (QuantLib PathPricer): the (discounted) payoff of an
+instrument along one simulated path. Monte Carlo prices are averages of
+path pricer values over many paths.
+
+Any Fn(&P) -> Real closure is a path pricer.
+*/
+pub trait PathPricer<P> {
+    fn value(&self, path: &P) -> Real;
+}
+impl<P, F: Fn(&P) -> Real> PathPricer<P> for F {
+    fn value(&self, path: &P) -> Real {
+        self(path)
+    }
+}
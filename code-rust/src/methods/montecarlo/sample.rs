@@ -0,0 +1,20 @@
+use crate::types::Real;
+
+/*
+Weighted sample (QuantLib Sample).
+
+Random number generators and path generators return their draws
+together with a weight: 1 for plain Monte Carlo, something else
+for importance sampling.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct Sample<T> {
+    pub value: T,
+    pub weight: Real,
+}
+impl<T> Sample<T> {
+    // Constructor
+    pub fn new(value: T, weight: Real) -> Sample<T> {
+        Sample { value, weight }
+    }
+}
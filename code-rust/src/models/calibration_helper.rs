@@ -0,0 +1,20 @@
+use crate::types::Real;
+
+/*
+Market instrument used to calibrate a model (QuantLib
+CalibrationHelper), e.g. a cap or a swaption quoted by its Black
+volatility.
+
+market_value is the price implied by the quote; model_value prices the
+same instrument with a model of type M. The calibration minimizes the
+sum of squared calibration errors, by default the relative price
+error (model - market) / market.
+*/
+pub trait CalibrationHelper<M: ?Sized> {
+    fn market_value(&self) -> Real;
+    fn model_value(&self, model: &M) -> Real;
+    fn calibration_error(&self, model: &M) -> Real {
+        let market_value: Real = self.market_value();
+        (self.model_value(model) - market_value) / market_value
+    }
+}
@@ -0,0 +1,148 @@
+use crate::math::optimization::constraint::Constraint;
+use crate::models::model::CalibratedModel;
+use crate::processes::heston_process::HestonProcess;
+use crate::types::{Real, Volatility};
+
+/*
+Heston stochastic-volatility model (QuantLib HestonModel), wrapping the
+HestonProcess whose variance parameters it calibrates:
+
+    dS = (r - q) S dt + sqrt(v) S dw_1
+    dv = kappa (theta - v) dt + sigma sqrt(v) dw_2,   dw_1 dw_2 = rho dt
+
+Parameters: [theta, kappa, sigma, rho, v0]. Spot, risk-free rate and
+dividend yield are market data and are not calibrated.
+*/
+#[derive(Clone)]
+pub struct HestonModel {
+    process: HestonProcess,
+}
+impl HestonModel {
+    // Constructor
+    pub fn new(process: HestonProcess) -> HestonModel {
+        let cases: [(Real, &str); 4] = [
+            (process.v0(), "v0"),
+            (process.kappa(), "kappa"),
+            (process.theta(), "theta"),
+            (process.sigma(), "sigma"),
+        ];
+        for (value, name) in cases {
+            if value <= 0.0 {
+                panic!("{name} ({value}) must be positive");
+            }
+        }
+        HestonModel { process }
+    }
+
+    // Inspectors
+    pub fn process(&self) -> &HestonProcess {
+        &self.process
+    }
+    pub fn theta(&self) -> Real {
+        self.process.theta()
+    }
+    pub fn kappa(&self) -> Real {
+        self.process.kappa()
+    }
+    pub fn sigma(&self) -> Volatility {
+        self.process.sigma()
+    }
+    pub fn rho(&self) -> Real {
+        self.process.rho()
+    }
+    pub fn v0(&self) -> Real {
+        self.process.v0()
+    }
+}
+impl CalibratedModel for HestonModel {
+    fn params(&self) -> Vec<Real> {
+        vec![
+            self.theta(),
+            self.kappa(),
+            self.sigma(),
+            self.rho(),
+            self.v0(),
+        ]
+    }
+    fn set_params(&mut self, params: &[Real]) {
+        self.process = self
+            .process
+            .with_variance_parameters(params[4], params[1], params[0], params[2], params[3]);
+    }
+    fn constraints(&self) -> Vec<Constraint> {
+        vec![
+            Constraint::Positive,
+            Constraint::Positive,
+            Constraint::Positive,
+            Constraint::Boundary {
+                low: -1.0,
+                high: 1.0,
+            },
+            Constraint::Positive,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::{Date, Month};
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    #[test]
+    fn params_round_trip_through_the_process() {
+        let mut model: HestonModel = HestonModel::new(HestonProcess::new(
+            100.0,
+            flat_rate(reference_date(), 0.05),
+            flat_rate(reference_date(), 0.02),
+            0.04,
+            1.5,
+            0.09,
+            0.3,
+            -0.6,
+        ));
+        assert_eq!(model.params(), vec![0.09, 1.5, 0.3, -0.6, 0.04]);
+        model.set_params(&[0.06, 2.0, 0.5, -0.3, 0.05]);
+        assert_eq!(
+            (
+                model.theta(),
+                model.kappa(),
+                model.sigma(),
+                model.rho(),
+                model.v0()
+            ),
+            (0.06, 2.0, 0.5, -0.3, 0.05)
+        );
+        assert_eq!(model.process().s0(), 100.0);
+        assert!((model.process().dividend_yield().zero_rate(1.0) - 0.02).abs() < 1.0e-15);
+    }
+
+    #[test]
+    fn new_panics_on_non_positive_parameters() {
+        let cases: [(Real, Real, Real, Real, &str); 4] = [
+            (0.0, 1.5, 0.09, 0.3, "v0"),
+            (0.04, -1.5, 0.09, 0.3, "kappa"),
+            (0.04, 1.5, 0.0, 0.3, "theta"),
+            (0.04, 1.5, 0.09, 0.0, "sigma"),
+        ];
+        for (v0, kappa, theta, sigma, label) in cases {
+            let result = std::panic::catch_unwind(|| {
+                HestonModel::new(HestonProcess::new(
+                    100.0,
+                    flat_rate(reference_date(), 0.05),
+                    flat_rate(reference_date(), 0.02),
+                    v0,
+                    kappa,
+                    theta,
+                    sigma,
+                    -0.6,
+                ))
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
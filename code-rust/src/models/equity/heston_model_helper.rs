@@ -0,0 +1,240 @@
+use crate::models::calibration_helper::CalibrationHelper;
+use crate::models::equity::heston_model::HestonModel;
+use crate::option::OptionType;
+use crate::pricingengines::black_formula::{black_formula, black_formula_implied_std_dev};
+use crate::pricingengines::vanilla::analytic_heston_engine::{
+    AnalyticHestonEngine, ComplexLogFormula, Integration,
+};
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::date::Date;
+use crate::time::period::Period;
+use crate::types::{DiscountFactor, Real, Time, Volatility};
+use std::rc::Rc;
+
+/*
+European option quoted by its Black volatility, for calibrating a
+Heston model to a volatility surface (QuantLib HestonModelHelper).
+
+The option expires at maturity after the reference date of the
+risk-free curve, whose day counter measures its time to maturity. As
+in QuantLib it is the out-of-the-money one: a call if the discounted
+strike is at least the dividend-discounted spot, a put otherwise. The
+market value is its Black price on the forward S P_q(T) / P_r(T); the
+model value comes from AnalyticHestonEngine (Gatheral's form,
+Gauss-Laguerre of order 128).
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct HestonModelHelper {
+    maturity: Time,
+    s0: Real,
+    strike: Real,
+    volatility: Volatility,
+    risk_free_discount: DiscountFactor,
+    dividend_discount: DiscountFactor,
+    option_type: OptionType,
+    integration: Integration,
+}
+impl HestonModelHelper {
+    // Constructor
+    pub fn new(
+        maturity: Period,
+        s0: Real,
+        strike: Real,
+        volatility: Volatility,
+        risk_free_rate: Rc<dyn YieldTermStructure>,
+        dividend_yield: Rc<dyn YieldTermStructure>,
+    ) -> HestonModelHelper {
+        if volatility <= 0.0 {
+            panic!("volatility ({volatility}) must be positive");
+        }
+        if s0 <= 0.0 || strike <= 0.0 {
+            panic!("spot ({s0}) and strike ({strike}) must be positive");
+        }
+        let maturity_date: Date = risk_free_rate.reference_date() + maturity;
+        let maturity: Time = risk_free_rate.time_from_reference(maturity_date);
+        if maturity <= 0.0 {
+            panic!("option maturity ({maturity}) must be positive");
+        }
+        let risk_free_discount: DiscountFactor = risk_free_rate.discount(maturity);
+        let dividend_discount: DiscountFactor = dividend_yield.discount_date(maturity_date);
+        let option_type: OptionType = if strike * risk_free_discount >= s0 * dividend_discount {
+            OptionType::Call
+        } else {
+            OptionType::Put
+        };
+        HestonModelHelper {
+            maturity,
+            s0,
+            strike,
+            volatility,
+            risk_free_discount,
+            dividend_discount,
+            option_type,
+            integration: Integration::gauss_laguerre(128),
+        }
+    }
+
+    // Inspectors
+    pub fn maturity(&self) -> Time {
+        self.maturity
+    }
+    pub fn strike(&self) -> Real {
+        self.strike
+    }
+    pub fn volatility(&self) -> Volatility {
+        self.volatility
+    }
+    pub fn option_type(&self) -> OptionType {
+        self.option_type
+    }
+
+    // Price of the option with the given Black volatility
+    pub fn black_price(&self, volatility: Volatility) -> Real {
+        black_formula(
+            self.option_type,
+            self.strike,
+            self.forward(),
+            volatility * self.maturity.sqrt(),
+            self.risk_free_discount,
+            0.0,
+        )
+    }
+    // Black volatility giving the target price
+    pub fn implied_volatility(&self, target_value: Real) -> Volatility {
+        black_formula_implied_std_dev(
+            self.option_type,
+            self.strike,
+            self.forward(),
+            target_value,
+            self.risk_free_discount,
+            0.0,
+            None,
+            1.0e-12,
+            100,
+        ) / self.maturity.sqrt()
+    }
+
+    fn forward(&self) -> Real {
+        self.s0 * self.dividend_discount / self.risk_free_discount
+    }
+}
+impl CalibrationHelper<HestonModel> for HestonModelHelper {
+    fn market_value(&self) -> Real {
+        self.black_price(self.volatility)
+    }
+    fn model_value(&self, model: &HestonModel) -> Real {
+        AnalyticHestonEngine::do_calculation(
+            model,
+            self.option_type,
+            self.strike,
+            self.maturity,
+            &self.integration,
+            ComplexLogFormula::Gatheral,
+        )
+        .value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::optimization::end_criteria::{EndCriteria, EndCriteriaType};
+    use crate::math::optimization::levenberg_marquardt::LevenbergMarquardt;
+    use crate::models::model::CalibratedModel;
+    use crate::processes::heston_process::HestonProcess;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::Month;
+    use crate::time::time_unit::TimeUnit;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn helper(months: i32, strike: Real, volatility: Volatility) -> HestonModelHelper {
+        HestonModelHelper::new(
+            Period::new(months, TimeUnit::Months),
+            100.0,
+            strike,
+            volatility,
+            flat_rate(reference_date(), 0.03),
+            flat_rate(reference_date(), 0.01),
+        )
+    }
+
+    fn model(v0: Real, kappa: Real, theta: Real, sigma: Volatility, rho: Real) -> HestonModel {
+        HestonModel::new(HestonProcess::new(
+            100.0,
+            flat_rate(reference_date(), 0.03),
+            flat_rate(reference_date(), 0.01),
+            v0,
+            kappa,
+            theta,
+            sigma,
+            rho,
+        ))
+    }
+
+    #[test]
+    fn helpers_quote_out_of_the_money_options() {
+        assert_eq!(helper(12, 90.0, 0.2).option_type(), OptionType::Put);
+        assert_eq!(helper(12, 110.0, 0.2).option_type(), OptionType::Call);
+        let quote: HestonModelHelper = helper(6, 95.0, 0.25);
+        let price: Real = quote.market_value();
+        assert!((quote.implied_volatility(price) - 0.25).abs() < 1.0e-10);
+    }
+
+    #[test]
+    fn calibration_recovers_heston_parameters() {
+        let target: HestonModel = model(0.04, 1.5, 0.06, 0.5, -0.6);
+        let mut quotes: Vec<HestonModelHelper> = Vec::new();
+        for months in [3, 6, 12, 24] {
+            for strike in [80.0, 90.0, 100.0, 110.0, 120.0] {
+                let quote: HestonModelHelper = helper(months, strike, 0.2);
+                let volatility: Volatility = quote.implied_volatility(quote.model_value(&target));
+                quotes.push(helper(months, strike, volatility));
+            }
+        }
+        let helpers: Vec<&dyn CalibrationHelper<HestonModel>> = quotes
+            .iter()
+            .map(|h| h as &dyn CalibrationHelper<HestonModel>)
+            .collect();
+
+        let mut calibrated: HestonModel = model(0.06, 1.0, 0.1, 0.3, -0.3);
+        let end: EndCriteriaType = calibrated.calibrate(
+            &helpers,
+            &LevenbergMarquardt::default(),
+            &EndCriteria::new(400, 1.0e-10, 1.0e-16, 1.0e-16),
+            &[],
+        );
+        assert_ne!(end, EndCriteriaType::MaxIterations);
+        for helper in &helpers {
+            assert!(helper.calibration_error(&calibrated).abs() < 1.0e-6);
+        }
+        let cases: [(Real, Real, &str); 5] = [
+            (calibrated.v0(), 0.04, "v0"),
+            (calibrated.kappa(), 1.5, "kappa"),
+            (calibrated.theta(), 0.06, "theta"),
+            (calibrated.sigma(), 0.5, "sigma"),
+            (calibrated.rho(), -0.6, "rho"),
+        ];
+        for (calculated, expected, name) in cases {
+            assert!(
+                (calculated - expected).abs() < 1.0e-4,
+                "{name}: {calculated} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn new_panics_on_invalid_input() {
+        let cases: [(i32, Real, Volatility, &str); 3] = [
+            (12, 100.0, 0.0, "null volatility"),
+            (12, -100.0, 0.2, "negative strike"),
+            (0, 100.0, 0.2, "null maturity"),
+        ];
+        for (months, strike, volatility, label) in cases {
+            let result = std::panic::catch_unwind(|| helper(months, strike, volatility));
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
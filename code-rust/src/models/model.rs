@@ -0,0 +1,260 @@
+use crate::math::optimization::constraint::Constraint;
+use crate::math::optimization::end_criteria::{EndCriteria, EndCriteriaType};
+use crate::math::optimization::levenberg_marquardt::{LevenbergMarquardt, OptimizationResult};
+use crate::models::calibration_helper::CalibrationHelper;
+use crate::option::OptionType;
+use crate::types::{DiscountFactor, Real, Size, Time};
+
+/*
+Model whose parameters can be calibrated to market instruments
+(QuantLib CalibratedModel).
+
+A model exposes its parameters as a flat vector, with one constraint
+per parameter. calibrate fits them to the helpers with the given
+optimizer; parameters flagged in fix_parameters (empty = none) keep
+their current value. Each trial point is priced on a copy of the
+model, so the model itself is only updated with the final result.
+*/
+pub trait CalibratedModel {
+    fn params(&self) -> Vec<Real>;
+    fn set_params(&mut self, params: &[Real]);
+    fn constraints(&self) -> Vec<Constraint>;
+
+    fn calibrate(
+        &mut self,
+        helpers: &[&dyn CalibrationHelper<Self>],
+        method: &LevenbergMarquardt,
+        end_criteria: &EndCriteria,
+        fix_parameters: &[bool],
+    ) -> EndCriteriaType
+    where
+        Self: Sized + Clone,
+    {
+        if helpers.is_empty() {
+            panic!("no calibration helpers given");
+        }
+        let params: Vec<Real> = self.params();
+        if !fix_parameters.is_empty() && fix_parameters.len() != params.len() {
+            panic!(
+                "fix_parameters has {} flags for {} parameters",
+                fix_parameters.len(),
+                params.len()
+            );
+        }
+        let free: Vec<Size> = (0..params.len())
+            .filter(|i| !fix_parameters.get(*i).copied().unwrap_or(false))
+            .collect();
+        let constraints: Vec<Constraint> = self.constraints();
+        let free_constraints: Vec<Constraint> = free.iter().map(|i| constraints[*i]).collect();
+        let initial: Vec<Real> = free.iter().map(|i| params[*i]).collect();
+
+        let all_params = |free_params: &[Real]| -> Vec<Real> {
+            let mut all: Vec<Real> = params.clone();
+            for (i, x) in free.iter().zip(free_params) {
+                all[*i] = *x;
+            }
+            all
+        };
+        let result: OptimizationResult = method.minimize(
+            |free_params: &[Real]| {
+                let mut model: Self = self.clone();
+                model.set_params(&all_params(free_params));
+                helpers
+                    .iter()
+                    .map(|helper| helper.calibration_error(&model))
+                    .collect()
+            },
+            &free_constraints,
+            &initial,
+            end_criteria,
+        );
+        self.set_params(&all_params(&result.parameters));
+        result.end_criteria
+    }
+}
+
+/*
+Model with analytic zero-coupon bonds and options on them (QuantLib
+AffineModel): discount(t) is the model price today of the zero-coupon
+bond paying 1 at t, and discount_bond_option the price of an option
+expiring at maturity on the zero-coupon bond maturing at bond_maturity.
+*/
+pub trait AffineModel: CalibratedModel {
+    fn discount(&self, t: Time) -> DiscountFactor;
+    fn discount_bond_option(
+        &self,
+        option_type: OptionType,
+        strike: Real,
+        maturity: Time,
+        bond_maturity: Time,
+    ) -> Real;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::shortrate::calibrationhelpers::cap_helper::CapHelper;
+    use crate::models::shortrate::calibrationhelpers::swaption_helper::SwaptionHelper;
+    use crate::models::shortrate::onefactormodels::hull_white::HullWhite;
+    use crate::models::shortrate::twofactormodels::g2::G2;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::{Date, Month};
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::period::Period;
+    use crate::time::time_unit::TimeUnit;
+    use crate::types::Volatility;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn end_criteria() -> EndCriteria {
+        EndCriteria::new(400, 1.0e-10, 1.0e-14, 1.0e-14)
+    }
+
+    // Coterminal swaptions quoted at the vols implied by the given model
+    fn swaption_helpers(model: &HullWhite) -> Vec<SwaptionHelper> {
+        (1..5)
+            .map(|maturity| {
+                let helper = |volatility: Volatility| -> SwaptionHelper {
+                    SwaptionHelper::new(
+                        &Actual365Fixed::new(),
+                        Period::new(maturity, TimeUnit::Years),
+                        Period::new(6 - maturity, TimeUnit::Years),
+                        Period::new(1, TimeUnit::Years),
+                        volatility,
+                        flat_rate(reference_date(), 0.04),
+                    )
+                };
+                let quote: SwaptionHelper = helper(0.2);
+                helper(quote.implied_volatility(quote.model_value(model)))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn calibration_recovers_hull_white_parameters() {
+        let helpers: Vec<SwaptionHelper> = swaption_helpers(&HullWhite::new(
+            flat_rate(reference_date(), 0.04),
+            0.1,
+            0.01,
+        ));
+        let helpers: Vec<&dyn CalibrationHelper<HullWhite>> = helpers
+            .iter()
+            .map(|h| h as &dyn CalibrationHelper<HullWhite>)
+            .collect();
+
+        let mut model: HullWhite = HullWhite::new(flat_rate(reference_date(), 0.04), 0.05, 0.02);
+        let end: EndCriteriaType = model.calibrate(
+            &helpers,
+            &LevenbergMarquardt::default(),
+            &end_criteria(),
+            &[],
+        );
+        assert_ne!(end, EndCriteriaType::MaxIterations);
+        assert!((model.a() - 0.1).abs() < 1.0e-5, "a = {}", model.a());
+        assert!(
+            (model.sigma() - 0.01).abs() < 1.0e-7,
+            "sigma = {}",
+            model.sigma()
+        );
+    }
+
+    #[test]
+    fn fixed_parameters_are_left_alone() {
+        let helpers: Vec<SwaptionHelper> = swaption_helpers(&HullWhite::new(
+            flat_rate(reference_date(), 0.04),
+            0.1,
+            0.01,
+        ));
+        let helpers: Vec<&dyn CalibrationHelper<HullWhite>> = helpers
+            .iter()
+            .map(|h| h as &dyn CalibrationHelper<HullWhite>)
+            .collect();
+
+        // With the right speed fixed, sigma alone is recovered
+        let mut model: HullWhite = HullWhite::new(flat_rate(reference_date(), 0.04), 0.1, 0.02);
+        model.calibrate(
+            &helpers,
+            &LevenbergMarquardt::default(),
+            &end_criteria(),
+            &[true, false],
+        );
+        assert_eq!(model.a(), 0.1);
+        assert!(
+            (model.sigma() - 0.01).abs() < 1.0e-7,
+            "sigma = {}",
+            model.sigma()
+        );
+    }
+
+    #[test]
+    fn g2_calibrates_to_caps() {
+        let target: G2 = G2::new(
+            flat_rate(reference_date(), 0.04),
+            0.3,
+            0.01,
+            0.05,
+            0.008,
+            -0.6,
+        );
+        let caps: Vec<CapHelper> = (2..=10)
+            .map(|years| {
+                let helper = |volatility: Volatility| -> CapHelper {
+                    CapHelper::new(
+                        &Actual365Fixed::new(),
+                        Period::new(years, TimeUnit::Years),
+                        Period::new(6, TimeUnit::Months),
+                        volatility,
+                        flat_rate(reference_date(), 0.04),
+                    )
+                };
+                let quote: CapHelper = helper(0.2);
+                helper(quote.implied_volatility(quote.model_value(&target)))
+            })
+            .collect();
+        let helpers: Vec<&dyn CalibrationHelper<G2>> = caps
+            .iter()
+            .map(|h| h as &dyn CalibrationHelper<G2>)
+            .collect();
+
+        // Mean reversions fixed, as usual for G2
+        let mut model: G2 = G2::new(
+            flat_rate(reference_date(), 0.04),
+            0.3,
+            0.015,
+            0.05,
+            0.004,
+            -0.2,
+        );
+        model.calibrate(
+            &helpers,
+            &LevenbergMarquardt::default(),
+            &end_criteria(),
+            &[true, false, true, false, false],
+        );
+        for helper in helpers {
+            assert!(helper.calibration_error(&model).abs() < 1.0e-8);
+        }
+        assert!(
+            (model.sigma() - 0.01).abs() < 1.0e-7,
+            "{:?}",
+            model.params()
+        );
+        assert!((model.eta() - 0.008).abs() < 1.0e-7, "{:?}", model.params());
+        assert!((model.rho() + 0.6).abs() < 1.0e-5, "{:?}", model.params());
+    }
+
+    #[test]
+    fn calibrate_panics_on_invalid_input() {
+        let result = std::panic::catch_unwind(|| {
+            HullWhite::new(flat_rate(reference_date(), 0.04), 0.1, 0.01).calibrate(
+                &[],
+                &LevenbergMarquardt::default(),
+                &end_criteria(),
+                &[],
+            )
+        });
+        assert!(result.is_err(), "expected panic for no helpers");
+    }
+}
@@ -0,0 +1,223 @@
+use crate::math::solvers1d::newton_safe::NewtonSafe;
+use crate::models::calibration_helper::CalibrationHelper;
+use crate::models::model::AffineModel;
+use crate::option::OptionType;
+use crate::pricingengines::black_formula::{black_formula, black_formula_vol_derivative};
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::time::period::Period;
+use crate::time::schedule::Schedule;
+use crate::types::{DiscountFactor, Rate, Real, Time, Volatility};
+use std::rc::Rc;
+
+/*
+At-the-money cap quoted by its flat Black volatility, for calibration
+(QuantLib CapHelper).
+
+The cap of the given length has one caplet per tenor starting at the
+reference date of the term structure, except the first one, whose rate
+is already fixed. The strike is the forward swap rate of the caplet
+periods on that term structure; times are measured by the term
+structure, accrual periods by the given day counter.
+
+    market value = sum_i P(0, t_{i+1}) tau_i Black(K, F_i, vol sqrt(t_i))
+    model value  = sum_i (1 + K tau_i) ZBP(t_i, t_{i+1}, 1 / (1 + K tau_i))
+
+since a caplet is a put on the zero bond over its period.
+*/
+#[derive(Clone)]
+pub struct CapHelper {
+    // Start time, end time and accrual period of each caplet
+    caplets: Vec<(Time, Time, Real)>,
+    strike: Rate,
+    volatility: Volatility,
+    term_structure: Rc<dyn YieldTermStructure>,
+}
+impl CapHelper {
+    // Constructor
+    pub fn new(
+        day_counter: &dyn DayCounter,
+        length: Period,
+        tenor: Period,
+        volatility: Volatility,
+        term_structure: Rc<dyn YieldTermStructure>,
+    ) -> CapHelper {
+        if volatility <= 0.0 {
+            panic!("volatility ({volatility}) must be positive");
+        }
+        let reference_date: Date = term_structure.reference_date();
+        let dates: Vec<Date> = Schedule::unadjusted(reference_date, reference_date + length, tenor)
+            .dates()
+            .to_vec();
+        if dates.len() < 3 {
+            panic!("cap of length {length} needs at least two {tenor} periods");
+        }
+        let caplets: Vec<(Time, Time, Real)> = dates[1..]
+            .windows(2)
+            .map(|period| {
+                (
+                    term_structure.time_from_reference(period[0]),
+                    term_structure.time_from_reference(period[1]),
+                    day_counter.year_fraction(period[0], period[1]),
+                )
+            })
+            .collect();
+        let discount = |t: Time| -> DiscountFactor { term_structure.discount(t) };
+        let annuity: Real = caplets
+            .iter()
+            .map(|(_, end, tau)| tau * discount(*end))
+            .sum();
+        let strike: Rate =
+            (discount(caplets[0].0) - discount(caplets[caplets.len() - 1].1)) / annuity;
+        CapHelper {
+            caplets,
+            strike,
+            volatility,
+            term_structure,
+        }
+    }
+
+    // Inspectors
+    pub fn strike(&self) -> Rate {
+        self.strike
+    }
+    pub fn volatility(&self) -> Volatility {
+        self.volatility
+    }
+
+    // Price of the cap with the given flat volatility
+    pub fn black_price(&self, volatility: Volatility) -> Real {
+        self.caplets
+            .iter()
+            .map(|(start, end, tau)| {
+                let forward: Rate = (self.discount(*start) / self.discount(*end) - 1.0) / tau;
+                self.discount(*end)
+                    * tau
+                    * black_formula(
+                        OptionType::Call,
+                        self.strike,
+                        forward,
+                        volatility * start.sqrt(),
+                        1.0,
+                        0.0,
+                    )
+            })
+            .sum()
+    }
+    // Flat volatility giving the target price
+    pub fn implied_volatility(&self, target_value: Real) -> Volatility {
+        let vega = |volatility: Volatility| -> Real {
+            self.caplets
+                .iter()
+                .map(|(start, end, tau)| {
+                    let forward: Rate = (self.discount(*start) / self.discount(*end) - 1.0) / tau;
+                    self.discount(*end)
+                        * tau
+                        * black_formula_vol_derivative(
+                            self.strike,
+                            forward,
+                            volatility * start.sqrt(),
+                            *start,
+                            1.0,
+                            0.0,
+                        )
+                })
+                .sum()
+        };
+        NewtonSafe::new().solve(
+            |volatility: Volatility| self.black_price(volatility) - target_value,
+            vega,
+            1.0e-12,
+            self.volatility.clamp(1.0e-4, 4.0),
+            1.0e-4,
+            4.0,
+        )
+    }
+
+    fn discount(&self, t: Time) -> DiscountFactor {
+        self.term_structure.discount(t)
+    }
+}
+impl<M: AffineModel> CalibrationHelper<M> for CapHelper {
+    fn market_value(&self) -> Real {
+        self.black_price(self.volatility)
+    }
+    fn model_value(&self, model: &M) -> Real {
+        self.caplets
+            .iter()
+            .map(|(start, end, tau)| {
+                let nominal: Real = 1.0 + self.strike * tau;
+                nominal * model.discount_bond_option(OptionType::Put, 1.0 / nominal, *start, *end)
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::shortrate::onefactormodels::hull_white::HullWhite;
+    use crate::models::shortrate::twofactormodels::g2::G2;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::time_unit::TimeUnit;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn helper(volatility: Volatility) -> CapHelper {
+        CapHelper::new(
+            &Actual365Fixed::new(),
+            Period::new(5, TimeUnit::Years),
+            Period::new(6, TimeUnit::Months),
+            volatility,
+            flat_rate(reference_date(), 0.04),
+        )
+    }
+
+    #[test]
+    fn strike_is_the_at_the_money_rate() {
+        // Simple rate equivalent to 4% continuous over half a year
+        let helper: CapHelper = helper(0.2);
+        assert_eq!(helper.caplets.len(), 9);
+        assert!(
+            (helper.strike() - 0.0404).abs() < 1.0e-3,
+            "{}",
+            helper.strike()
+        );
+    }
+
+    #[test]
+    fn implied_volatility_inverts_the_black_price() {
+        let helper: CapHelper = helper(0.2);
+        for volatility in [0.05, 0.2, 0.6] {
+            let price: Real = helper.black_price(volatility);
+            assert!((helper.implied_volatility(price) - volatility).abs() < 1.0e-10);
+        }
+    }
+
+    #[test]
+    fn model_values_imply_plausible_volatilities() {
+        // Gaussian models give Black caplet vols around sigma / rate at the money
+        let hull_white: HullWhite = HullWhite::new(flat_rate(reference_date(), 0.04), 0.05, 0.008);
+        let g2: G2 = G2::new(
+            flat_rate(reference_date(), 0.04),
+            0.05,
+            0.006,
+            0.5,
+            0.006,
+            -0.5,
+        );
+        for model_value in [
+            CalibrationHelper::<HullWhite>::model_value(&helper(0.2), &hull_white),
+            CalibrationHelper::<G2>::model_value(&helper(0.2), &g2),
+        ] {
+            let implied: Volatility = helper(0.2).implied_volatility(model_value);
+            assert!((helper(implied).black_price(implied) - model_value).abs() < 1.0e-12);
+            assert!(implied > 0.05 && implied < 0.5, "implied vol {implied}");
+        }
+    }
+}
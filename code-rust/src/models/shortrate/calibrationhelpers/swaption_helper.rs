@@ -0,0 +1,238 @@
+use crate::math::solvers1d::newton_safe::NewtonSafe;
+use crate::models::calibration_helper::CalibrationHelper;
+use crate::models::shortrate::one_factor_model::OneFactorAffineModel;
+use crate::option::OptionType;
+use crate::pricingengines::black_formula::{black_formula, black_formula_implied_std_dev};
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::time::period::Period;
+use crate::time::schedule::Schedule;
+use crate::types::{DiscountFactor, Rate, Real, Time, Volatility};
+use std::rc::Rc;
+
+/*
+At-the-money European payer swaption quoted by its Black volatility,
+for calibration (QuantLib SwaptionHelper).
+
+The underlying swap starts at maturity after the reference date of the
+term structure (the exercise date) and lasts length, with fixed
+payments every fixed_leg_tenor. Times are measured by the term
+structure, accrual periods by the given day counter. The floating leg
+is worth P(0, t_0) - P(0, t_n), so the strike is the forward swap rate
+    K = (P(0, t_0) - P(0, t_n)) / A,   A = sum_i tau_i P(0, t_i)
+and the market value is A Black(K, K, vol sqrt(t_0)).
+
+One-factor affine models price it with Jamshidian's decomposition: the
+payer swaption is a put on the coupon bond paying c_i = K tau_i (plus 1
+at t_n) struck at 1, which splits into puts on the zero bonds struck at
+X_i = P(t_0, t_i, r*), where r* solves sum_i c_i P(t_0, t_i, r*) = 1.
+*/
+#[derive(Clone)]
+pub struct SwaptionHelper {
+    fixed_dates: Vec<Date>,
+    exercise_time: Time,
+    payment_times: Vec<Time>,
+    accruals: Vec<Real>,
+    strike: Rate,
+    volatility: Volatility,
+    term_structure: Rc<dyn YieldTermStructure>,
+}
+impl SwaptionHelper {
+    // Constructor
+    pub fn new(
+        day_counter: &dyn DayCounter,
+        maturity: Period,
+        length: Period,
+        fixed_leg_tenor: Period,
+        volatility: Volatility,
+        term_structure: Rc<dyn YieldTermStructure>,
+    ) -> SwaptionHelper {
+        if volatility <= 0.0 {
+            panic!("volatility ({volatility}) must be positive");
+        }
+        let reference_date: Date = term_structure.reference_date();
+        let start: Date = reference_date + maturity;
+        if start <= reference_date {
+            panic!("swaption maturity ({maturity}) must be positive");
+        }
+        let fixed_dates: Vec<Date> = Schedule::unadjusted(start, start + length, fixed_leg_tenor)
+            .dates()
+            .to_vec();
+        let exercise_time: Time = term_structure.time_from_reference(start);
+        let payment_times: Vec<Time> = fixed_dates[1..]
+            .iter()
+            .map(|d| term_structure.time_from_reference(*d))
+            .collect();
+        let accruals: Vec<Real> = fixed_dates
+            .windows(2)
+            .map(|period| day_counter.year_fraction(period[0], period[1]))
+            .collect();
+        let mut helper: SwaptionHelper = SwaptionHelper {
+            fixed_dates,
+            exercise_time,
+            payment_times,
+            accruals,
+            strike: 0.0,
+            volatility,
+            term_structure,
+        };
+        helper.strike = (helper.discount(exercise_time)
+            - helper.discount(*helper.payment_times.last().unwrap()))
+            / helper.annuity();
+        helper
+    }
+
+    // Inspectors
+    // Start date of the swap followed by its fixed payment dates
+    pub fn fixed_dates(&self) -> &[Date] {
+        &self.fixed_dates
+    }
+    pub fn strike(&self) -> Rate {
+        self.strike
+    }
+    pub fn volatility(&self) -> Volatility {
+        self.volatility
+    }
+
+    // Price of the swaption with the given Black volatility
+    pub fn black_price(&self, volatility: Volatility) -> Real {
+        let std_dev: Real = volatility * self.exercise_time.sqrt();
+        black_formula(
+            OptionType::Call,
+            self.strike,
+            self.strike,
+            std_dev,
+            self.annuity(),
+            0.0,
+        )
+    }
+    // Black volatility giving the target price
+    pub fn implied_volatility(&self, target_value: Real) -> Volatility {
+        black_formula_implied_std_dev(
+            OptionType::Call,
+            self.strike,
+            self.strike,
+            target_value,
+            self.annuity(),
+            0.0,
+            None,
+            1.0e-12,
+            100,
+        ) / self.exercise_time.sqrt()
+    }
+
+    fn discount(&self, t: Time) -> DiscountFactor {
+        self.term_structure.discount(t)
+    }
+    fn annuity(&self) -> Real {
+        self.payment_times
+            .iter()
+            .zip(&self.accruals)
+            .map(|(t, tau)| tau * self.discount(*t))
+            .sum()
+    }
+}
+impl<M: OneFactorAffineModel> CalibrationHelper<M> for SwaptionHelper {
+    fn market_value(&self) -> Real {
+        self.black_price(self.volatility)
+    }
+    fn model_value(&self, model: &M) -> Real {
+        let t0: Time = self.exercise_time;
+        let mut coupons: Vec<Real> = self.accruals.iter().map(|tau| self.strike * tau).collect();
+        *coupons.last_mut().unwrap() += 1.0;
+
+        let bond = |r: Rate| -> Real {
+            self.payment_times
+                .iter()
+                .zip(&coupons)
+                .map(|(t, c)| c * model.discount_bond(t0, *t, r))
+                .sum::<Real>()
+                - 1.0
+        };
+        let bond_derivative = |r: Rate| -> Real {
+            -self
+                .payment_times
+                .iter()
+                .zip(&coupons)
+                .map(|(t, c)| c * model.affine_b(t0, *t) * model.discount_bond(t0, *t, r))
+                .sum::<Real>()
+        };
+        let guess: Rate = model.short_rate();
+        let (mut low, mut high): (Rate, Rate) = (guess - 0.1, guess + 0.1);
+        while bond(low) < 0.0 {
+            low -= high - low;
+        }
+        while bond(high) > 0.0 {
+            high += high - low;
+        }
+        let r_star: Rate =
+            NewtonSafe::new().solve(bond, bond_derivative, 1.0e-12, guess, low, high);
+
+        self.payment_times
+            .iter()
+            .zip(&coupons)
+            .map(|(t, c)| {
+                let strike: Real = model.discount_bond(t0, *t, r_star);
+                c * model.discount_bond_option(OptionType::Put, strike, t0, *t)
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::shortrate::onefactormodels::hull_white::HullWhite;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::time_unit::TimeUnit;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn helper(volatility: Volatility) -> SwaptionHelper {
+        SwaptionHelper::new(
+            &Actual365Fixed::new(),
+            Period::new(2, TimeUnit::Years),
+            Period::new(5, TimeUnit::Years),
+            Period::new(1, TimeUnit::Years),
+            volatility,
+            flat_rate(reference_date(), 0.04),
+        )
+    }
+
+    #[test]
+    fn swap_dates_and_strike() {
+        let helper: SwaptionHelper = helper(0.2);
+        assert_eq!(helper.fixed_dates().len(), 6);
+        assert_eq!(helper.fixed_dates()[0], Date::new(15, Month::January, 2027));
+        assert_eq!(helper.fixed_dates()[5], Date::new(15, Month::January, 2032));
+        // Annual rate equivalent to 4% continuous
+        assert!((helper.strike() - (0.04_f64.exp() - 1.0)).abs() < 2.0e-4);
+    }
+
+    #[test]
+    fn implied_volatility_inverts_the_black_price() {
+        let helper: SwaptionHelper = helper(0.2);
+        for volatility in [0.05, 0.2, 0.6] {
+            let price: Real = helper.black_price(volatility);
+            assert!((helper.implied_volatility(price) - volatility).abs() < 1.0e-10);
+        }
+    }
+
+    #[test]
+    fn jamshidian_price_implies_a_plausible_volatility() {
+        let model: HullWhite = HullWhite::new(flat_rate(reference_date(), 0.04), 0.1, 0.01);
+        let helper: SwaptionHelper = helper(0.2);
+        let value: Real = helper.model_value(&model);
+        let implied: Volatility = helper.implied_volatility(value);
+        assert!(value > 0.0);
+        assert!(implied > 0.1 && implied < 0.4, "implied vol {implied}");
+        // Higher model vol, higher price
+        let riskier: HullWhite = HullWhite::new(flat_rate(reference_date(), 0.04), 0.1, 0.015);
+        assert!(helper.model_value(&riskier) > value);
+    }
+}
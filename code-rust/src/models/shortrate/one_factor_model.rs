@@ -0,0 +1,180 @@
+use crate::math::solvers1d::newton_safe::NewtonSafe;
+use crate::methods::lattices::tree::Tree;
+use crate::methods::lattices::trinomial_tree::TrinomialTree;
+use crate::models::model::{AffineModel, CalibratedModel};
+use crate::time_grid::TimeGrid;
+use crate::types::{DiscountFactor, Rate, Real, Size, Time};
+
+/*
+Single-factor short-rate model that can be discretized on a trinomial
+tree (QuantLib OneFactorModel), e.g. to price Bermudan swaptions.
+*/
+pub trait OneFactorModel: CalibratedModel {
+    fn tree(&self, time_grid: &TimeGrid) -> ShortRateTree;
+}
+
+/*
+Single-factor affine model (QuantLib OneFactorAffineModel): the price
+at time now of the zero-coupon bond maturing at maturity, given the
+short rate r at that time, is
+    P(now, maturity, r) = A(now, maturity) exp(-B(now, maturity) r)
+*/
+pub trait OneFactorAffineModel: AffineModel {
+    // Short rate today, r(0)
+    fn short_rate(&self) -> Rate;
+    fn affine_a(&self, now: Time, maturity: Time) -> Real;
+    fn affine_b(&self, now: Time, maturity: Time) -> Real;
+
+    fn discount_bond(&self, now: Time, maturity: Time, rate: Rate) -> DiscountFactor {
+        self.affine_a(now, maturity) * (-self.affine_b(now, maturity) * rate).exp()
+    }
+}
+
+/*
+Trinomial tree of a short-rate model (QuantLib
+OneFactorModel::ShortRateTree). The underlying tree discretizes a state
+variable x; rate(i, index) is the short rate at each node, used to
+discount from column i to column i + 1, so it is only defined for the
+columns before the last one.
+
+new uses the state variable as the short rate. fitted is for models
+whose rate is r = phi(x + alpha(t)) with a time-dependent shift alpha
+chosen to reproduce a discount curve: column by column, alpha is
+solved from the Arrow-Debreu prices Q (value today of 1 paid at a
+node) so that
+    sum_j Q(i, j) exp(-phi(x_j + alpha_i) dt_i) = P(0, t_{i+1})
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct ShortRateTree {
+    tree: TrinomialTree,
+    time_grid: TimeGrid,
+    rates: Vec<Vec<Rate>>,
+}
+impl ShortRateTree {
+    // Constructors
+    pub fn new(tree: TrinomialTree, time_grid: TimeGrid) -> ShortRateTree {
+        let rates: Vec<Vec<Rate>> = (0..tree.columns() - 1)
+            .map(|i| (0..tree.size(i)).map(|j| tree.underlying(i, j)).collect())
+            .collect();
+        ShortRateTree {
+            tree,
+            time_grid,
+            rates,
+        }
+    }
+    pub fn fitted(
+        tree: TrinomialTree,
+        time_grid: TimeGrid,
+        discount: &dyn Fn(Time) -> DiscountFactor,
+        rate: &dyn Fn(Real) -> Rate,
+        rate_derivative: &dyn Fn(Real) -> Real,
+        guess: Real,
+    ) -> ShortRateTree {
+        let mut arrow_debreu: Vec<Real> = vec![1.0];
+        let mut rates: Vec<Vec<Rate>> = Vec::with_capacity(tree.columns() - 1);
+        let mut alpha: Real = guess;
+        for i in 0..tree.columns() - 1 {
+            let dt: Time = time_grid.dt(i);
+            let target: DiscountFactor = discount(time_grid.times()[i + 1]);
+            let x: Vec<Real> = (0..tree.size(i)).map(|j| tree.underlying(i, j)).collect();
+            let price = |a: Real| -> Real {
+                arrow_debreu
+                    .iter()
+                    .zip(&x)
+                    .map(|(q, xj)| q * (-rate(xj + a) * dt).exp())
+                    .sum::<Real>()
+                    - target
+            };
+            let price_derivative = |a: Real| -> Real {
+                -arrow_debreu
+                    .iter()
+                    .zip(&x)
+                    .map(|(q, xj)| q * rate_derivative(xj + a) * dt * (-rate(xj + a) * dt).exp())
+                    .sum::<Real>()
+            };
+            let (low, high): (Real, Real) = detail::bracket(&price, alpha);
+            alpha = NewtonSafe::new().solve(price, price_derivative, 1.0e-12, alpha, low, high);
+
+            let column_rates: Vec<Rate> = x.iter().map(|xj| rate(xj + alpha)).collect();
+            let mut next: Vec<Real> = vec![0.0; tree.size(i + 1)];
+            for (j, q) in arrow_debreu.iter().enumerate() {
+                let discounted: Real = q * (-column_rates[j] * dt).exp();
+                for branch in 0..3 {
+                    next[tree.descendant(i, j, branch)] +=
+                        discounted * tree.probability(i, j, branch);
+                }
+            }
+            rates.push(column_rates);
+            arrow_debreu = next;
+        }
+        ShortRateTree {
+            tree,
+            time_grid,
+            rates,
+        }
+    }
+
+    // Inspectors
+    pub fn time_grid(&self) -> &TimeGrid {
+        &self.time_grid
+    }
+    pub fn rate(&self, i: Size, index: Size) -> Rate {
+        self.rates[i][index]
+    }
+    // Discount factor from node (i, index) over the step to column i + 1
+    pub fn discount(&self, i: Size, index: Size) -> DiscountFactor {
+        (-self.rates[i][index] * self.time_grid.dt(i)).exp()
+    }
+
+    // Discounted expectation over one step, from column i + 1 to column i
+    pub fn rollback(&self, i: Size, values: &[Real]) -> Vec<Real> {
+        (0..self.size(i))
+            .map(|j| {
+                (0..3)
+                    .map(|b| self.probability(i, j, b) * values[self.descendant(i, j, b)])
+                    .sum::<Real>()
+                    * self.discount(i, j)
+            })
+            .collect()
+    }
+}
+impl Tree for ShortRateTree {
+    fn columns(&self) -> Size {
+        self.tree.columns()
+    }
+    fn size(&self, i: Size) -> Size {
+        self.tree.size(i)
+    }
+    fn underlying(&self, i: Size, index: Size) -> Real {
+        self.tree.underlying(i, index)
+    }
+    fn descendant(&self, i: Size, index: Size, branch: Size) -> Size {
+        self.tree.descendant(i, index, branch)
+    }
+    fn probability(&self, i: Size, index: Size, branch: Size) -> Real {
+        self.tree.probability(i, index, branch)
+    }
+}
+
+mod detail {
+    use crate::types::Real;
+
+    // Interval around the guess on which the decreasing price changes sign
+    pub(super) fn bracket(price: &dyn Fn(Real) -> Real, guess: Real) -> (Real, Real) {
+        let mut width: Real = 0.1;
+        let (mut low, mut high): (Real, Real) = (guess - width, guess + width);
+        for _ in 0..100 {
+            if price(low) > 0.0 && price(high) < 0.0 {
+                return (low, high);
+            }
+            width *= 2.0;
+            if price(low) <= 0.0 {
+                low -= width;
+            }
+            if price(high) >= 0.0 {
+                high += width;
+            }
+        }
+        panic!("unable to bracket the short-rate shift around {guess}");
+    }
+}
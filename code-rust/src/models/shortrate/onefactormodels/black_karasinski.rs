@@ -0,0 +1,142 @@
+use crate::math::optimization::constraint::Constraint;
+use crate::methods::lattices::trinomial_tree::TrinomialTree;
+use crate::models::model::CalibratedModel;
+use crate::models::shortrate::one_factor_model::{OneFactorModel, ShortRateTree};
+use crate::processes::ornstein_uhlenbeck_process::OrnsteinUhlenbeckProcess;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time_grid::TimeGrid;
+use crate::types::{Rate, Real, Time, Volatility};
+use std::rc::Rc;
+
+/*
+Black-Karasinski model (QuantLib BlackKarasinski).
+
+    d ln r = (theta(t) - a ln r) dt + sigma dw
+
+Rates stay positive but there is no closed form for bonds or options,
+so the model can only be used on a tree: ln r = x + alpha(t), with x an
+Ornstein-Uhlenbeck process starting at 0 and alpha fitted numerically
+to the initial term structure, whose short rate must be positive.
+
+Parameters: [a, sigma].
+*/
+#[derive(Clone)]
+pub struct BlackKarasinski {
+    term_structure: Rc<dyn YieldTermStructure>,
+    a: Real,
+    sigma: Volatility,
+}
+impl BlackKarasinski {
+    // Constructor
+    pub fn new(
+        term_structure: Rc<dyn YieldTermStructure>,
+        a: Real,
+        sigma: Volatility,
+    ) -> BlackKarasinski {
+        let rate: Rate = term_structure.instantaneous_forward(0.0);
+        if rate <= 0.0 {
+            panic!("positive short rate required ({rate})");
+        }
+        if a <= 0.0 {
+            panic!("mean reversion speed ({a}) must be positive");
+        }
+        if sigma <= 0.0 {
+            panic!("volatility ({sigma}) must be positive");
+        }
+        BlackKarasinski {
+            term_structure,
+            a,
+            sigma,
+        }
+    }
+
+    // Inspectors
+    pub fn term_structure(&self) -> &dyn YieldTermStructure {
+        self.term_structure.as_ref()
+    }
+    pub fn a(&self) -> Real {
+        self.a
+    }
+    pub fn sigma(&self) -> Volatility {
+        self.sigma
+    }
+}
+impl CalibratedModel for BlackKarasinski {
+    fn params(&self) -> Vec<Real> {
+        vec![self.a, self.sigma]
+    }
+    fn set_params(&mut self, params: &[Real]) {
+        self.a = params[0];
+        self.sigma = params[1];
+    }
+    fn constraints(&self) -> Vec<Constraint> {
+        vec![Constraint::Positive, Constraint::Positive]
+    }
+}
+impl OneFactorModel for BlackKarasinski {
+    fn tree(&self, time_grid: &TimeGrid) -> ShortRateTree {
+        let process: OrnsteinUhlenbeckProcess =
+            OrnsteinUhlenbeckProcess::new(self.a, self.sigma, 0.0, 0.0);
+        ShortRateTree::fitted(
+            TrinomialTree::new(&process, time_grid, false),
+            time_grid.clone(),
+            &|t: Time| self.term_structure.discount(t),
+            &|x: Real| x.exp(),
+            &|x: Real| x.exp(),
+            self.term_structure.instantaneous_forward(0.0).ln(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::methods::lattices::tree::Tree;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::{Date, Month};
+    use crate::types::Size;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    #[test]
+    fn fitted_tree_reproduces_the_curve_with_positive_rates() {
+        let model: BlackKarasinski =
+            BlackKarasinski::new(flat_rate(reference_date(), 0.03), 0.1, 0.25);
+        let steps: Size = 60;
+        let grid: TimeGrid = TimeGrid::new(10.0, steps);
+        let tree: ShortRateTree = model.tree(&grid);
+        let mut values: Vec<Real> = vec![1.0; tree.size(steps)];
+        for i in (0..steps).rev() {
+            for j in 0..tree.size(i) {
+                assert!(tree.rate(i, j) > 0.0);
+            }
+            values = (0..tree.size(i))
+                .map(|j| {
+                    (0..3)
+                        .map(|b| tree.probability(i, j, b) * values[tree.descendant(i, j, b)])
+                        .sum::<Real>()
+                        * tree.discount(i, j)
+                })
+                .collect();
+        }
+        assert!((values[0] - (-0.3_f64).exp()).abs() < 1.0e-12);
+        assert_eq!(model.params(), vec![0.1, 0.25]);
+    }
+
+    #[test]
+    fn new_panics_on_invalid_parameters() {
+        let cases: [(Real, Real, Real, &str); 3] = [
+            (0.0, 0.1, 0.2, "null short rate"),
+            (0.03, -0.1, 0.2, "negative speed"),
+            (0.03, 0.1, 0.0, "null vol"),
+        ];
+        for (rate, a, sigma, label) in cases {
+            let result = std::panic::catch_unwind(|| {
+                BlackKarasinski::new(flat_rate(reference_date(), rate), a, sigma)
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use crate::math::distributions::chi_square_distribution::NonCentralCumulativeChiSquareDistribution;
+use crate::math::optimization::constraint::Constraint;
+use crate::models::model::{AffineModel, CalibratedModel};
+use crate::models::shortrate::one_factor_model::OneFactorAffineModel;
+use crate::option::OptionType;
+use crate::types::{DiscountFactor, Rate, Real, Time, Volatility};
+
+/*
+Cox-Ingersoll-Ross model (QuantLib CoxIngersollRoss).
+
+    dr = k (theta - r) dt + sigma sqrt(r) dw
+
+Zero bonds are affine; with h = sqrt(k^2 + 2 sigma^2) and tau = T - t:
+    A(t, T) = (2 h exp((k + h) tau / 2) / (2 h + (k + h) (exp(h tau) - 1)))^(2 k theta / sigma^2)
+    B(t, T) = 2 (exp(h tau) - 1) / (2 h + (k + h) (exp(h tau) - 1))
+Options on zero bonds are priced with non-central chi-square
+distributions (Brigo-Mercurio 3.2.3). Like Vasicek, the model generates
+its own discount curve.
+
+Parameters: [theta, k, sigma, r0].
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CoxIngersollRoss {
+    theta: Real,
+    k: Real,
+    sigma: Volatility,
+    r0: Rate,
+}
+impl CoxIngersollRoss {
+    // Constructor
+    pub fn new(r0: Rate, theta: Real, k: Real, sigma: Volatility) -> CoxIngersollRoss {
+        let cases: [(Real, &str); 4] = [(r0, "r0"), (theta, "theta"), (k, "k"), (sigma, "sigma")];
+        for (value, name) in cases {
+            if value <= 0.0 {
+                panic!("{name} ({value}) must be positive");
+            }
+        }
+        CoxIngersollRoss {
+            theta,
+            k,
+            sigma,
+            r0,
+        }
+    }
+
+    // Inspectors
+    pub fn theta(&self) -> Real {
+        self.theta
+    }
+    pub fn k(&self) -> Real {
+        self.k
+    }
+    pub fn sigma(&self) -> Volatility {
+        self.sigma
+    }
+    pub fn r0(&self) -> Rate {
+        self.r0
+    }
+}
+impl CalibratedModel for CoxIngersollRoss {
+    fn params(&self) -> Vec<Real> {
+        vec![self.theta, self.k, self.sigma, self.r0]
+    }
+    fn set_params(&mut self, params: &[Real]) {
+        self.theta = params[0];
+        self.k = params[1];
+        self.sigma = params[2];
+        self.r0 = params[3];
+    }
+    fn constraints(&self) -> Vec<Constraint> {
+        vec![Constraint::Positive; 4]
+    }
+}
+impl AffineModel for CoxIngersollRoss {
+    fn discount(&self, t: Time) -> DiscountFactor {
+        self.discount_bond(0.0, t, self.r0)
+    }
+    fn discount_bond_option(
+        &self,
+        option_type: OptionType,
+        strike: Real,
+        maturity: Time,
+        bond_maturity: Time,
+    ) -> Real {
+        let discount_t: DiscountFactor = self.discount(maturity);
+        let discount_s: DiscountFactor = self.discount(bond_maturity);
+        if maturity < Real::EPSILON {
+            return (option_type.sign() * (discount_s - strike)).max(0.0);
+        }
+
+        let sigma2: Real = self.sigma * self.sigma;
+        let h: Real = (self.k * self.k + 2.0 * sigma2).sqrt();
+        let b: Real = self.affine_b(maturity, bond_maturity);
+        let rho: Real = 2.0 * h / (sigma2 * ((h * maturity).exp() - 1.0));
+        let psi: Real = (self.k + h) / sigma2;
+        let df: Real = 4.0 * self.k * self.theta / sigma2;
+        let ncp_s: Real = 2.0 * rho * rho * self.r0 * (h * maturity).exp() / (rho + psi + b);
+        let ncp_t: Real = 2.0 * rho * rho * self.r0 * (h * maturity).exp() / (rho + psi);
+        let chi_s: NonCentralCumulativeChiSquareDistribution =
+            NonCentralCumulativeChiSquareDistribution::new(df, ncp_s);
+        let chi_t: NonCentralCumulativeChiSquareDistribution =
+            NonCentralCumulativeChiSquareDistribution::new(df, ncp_t);
+
+        // Short rate below which the bond ends in the money
+        let z: Real = (self.affine_a(maturity, bond_maturity) / strike).ln() / b;
+        let call: Real = discount_s * chi_s.value(2.0 * z * (rho + psi + b))
+            - strike * discount_t * chi_t.value(2.0 * z * (rho + psi));
+        match option_type {
+            OptionType::Call => call,
+            OptionType::Put => call - discount_s + strike * discount_t,
+        }
+    }
+}
+impl OneFactorAffineModel for CoxIngersollRoss {
+    fn short_rate(&self) -> Rate {
+        self.r0
+    }
+    fn affine_a(&self, now: Time, maturity: Time) -> Real {
+        let sigma2: Real = self.sigma * self.sigma;
+        let h: Real = (self.k * self.k + 2.0 * sigma2).sqrt();
+        let tau: Time = maturity - now;
+        let numerator: Real = 2.0 * h * (0.5 * (self.k + h) * tau).exp();
+        let denominator: Real = 2.0 * h + (self.k + h) * ((tau * h).exp() - 1.0);
+        ((numerator / denominator).ln() * 2.0 * self.k * self.theta / sigma2).exp()
+    }
+    fn affine_b(&self, now: Time, maturity: Time) -> Real {
+        let h: Real = (self.k * self.k + 2.0 * self.sigma * self.sigma).sqrt();
+        let temp: Real = ((maturity - now) * h).exp() - 1.0;
+        2.0 * temp / (2.0 * h + (self.k + h) * temp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::distributions::normal_distribution::InverseCumulativeNormal;
+    use crate::math::randomnumbers::mt19937_uniform_rng::MersenneTwisterUniformRng;
+    use crate::math::statistics::incremental_statistics::IncrementalStatistics;
+    use crate::types::Size;
+
+    fn model() -> CoxIngersollRoss {
+        CoxIngersollRoss::new(0.04, 0.05, 0.5, 0.1)
+    }
+
+    #[test]
+    fn zero_volatility_limit_is_deterministic() {
+        // With sigma -> 0 the rate follows r(t) = theta + (r0 - theta) exp(-k t)
+        let model: CoxIngersollRoss = CoxIngersollRoss::new(0.04, 0.05, 0.5, 1.0e-3);
+        let t: Time = 3.0;
+        let integral: Real = 0.05 * t + (0.04 - 0.05) * (1.0 - (-0.5 * t).exp()) / 0.5;
+        assert!((model.discount(t) - (-integral).exp()).abs() < 1.0e-6);
+        assert_eq!(model.discount(0.0), 1.0);
+    }
+
+    #[test]
+    fn bond_option_matches_monte_carlo() {
+        let model: CoxIngersollRoss = model();
+        let (maturity, bond_maturity): (Time, Time) = (1.0, 3.0);
+        let strike: Real = model.discount(bond_maturity) / model.discount(maturity);
+        let call: Real =
+            model.discount_bond_option(OptionType::Call, strike, maturity, bond_maturity);
+
+        // Full-truncation Euler paths of r and of its integral
+        let steps: Size = 100;
+        let dt: Time = maturity / steps as Time;
+        let mut rng: MersenneTwisterUniformRng = MersenneTwisterUniformRng::new(42);
+        let inverse: InverseCumulativeNormal = InverseCumulativeNormal::default();
+        let mut statistics: IncrementalStatistics = IncrementalStatistics::new();
+        for _ in 0..20000 {
+            let (mut r, mut integral): (Real, Real) = (model.r0(), 0.0);
+            for _ in 0..steps {
+                let r_plus: Real = r.max(0.0);
+                integral += r_plus * dt;
+                r += model.k() * (model.theta() - r_plus) * dt
+                    + model.sigma() * (r_plus * dt).sqrt() * inverse.value(rng.next_real());
+            }
+            let bond: Real = model.discount_bond(maturity, bond_maturity, r.max(0.0));
+            statistics.add((-integral).exp() * (bond - strike).max(0.0), 1.0);
+        }
+        assert!(
+            (call - statistics.mean()).abs() < 3.0 * statistics.error_estimate(),
+            "{} vs {} +/- {}",
+            call,
+            statistics.mean(),
+            statistics.error_estimate()
+        );
+
+        let put: Real =
+            model.discount_bond_option(OptionType::Put, strike, maturity, bond_maturity);
+        assert!(put > 0.0);
+        assert!((call - put).abs() < 1.0e-14);
+    }
+
+    #[test]
+    fn new_panics_on_non_positive_parameters() {
+        let cases: [(Real, Real, Real, Real, &str); 4] = [
+            (0.0, 0.05, 0.5, 0.1, "r0"),
+            (0.04, 0.0, 0.5, 0.1, "theta"),
+            (0.04, 0.05, -0.5, 0.1, "k"),
+            (0.04, 0.05, 0.5, 0.0, "sigma"),
+        ];
+        for (r0, theta, k, sigma, label) in cases {
+            let result = std::panic::catch_unwind(|| CoxIngersollRoss::new(r0, theta, k, sigma));
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
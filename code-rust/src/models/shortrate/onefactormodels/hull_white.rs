@@ -0,0 +1,245 @@
+use crate::math::optimization::constraint::Constraint;
+use crate::methods::lattices::trinomial_tree::TrinomialTree;
+use crate::models::model::{AffineModel, CalibratedModel};
+use crate::models::shortrate::one_factor_model::{
+    OneFactorAffineModel, OneFactorModel, ShortRateTree,
+};
+use crate::models::shortrate::onefactormodels::vasicek::detail;
+use crate::option::OptionType;
+use crate::pricingengines::black_formula::black_formula;
+use crate::processes::ornstein_uhlenbeck_process::OrnsteinUhlenbeckProcess;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time_grid::TimeGrid;
+use crate::types::{DiscountFactor, Rate, Real, Time, Volatility};
+use std::rc::Rc;
+
+/*
+Hull-White (extended Vasicek) model (QuantLib HullWhite).
+
+    dr = (theta(t) - a r) dt + sigma dw
+
+theta(t) is chosen so that the model reproduces the initial term
+structure P(0, t), with instantaneous forward f(0, t). Zero bonds are affine with B(t, T) = (1 - exp(-a (T - t))) / a and
+    A(t, T) = P(0, T) / P(0, t)
+              exp(B f(0, t) - sigma^2 / (4 a) (1 - exp(-2 a t)) B^2)
+
+On a tree, r = x + alpha(t) where x is an Ornstein-Uhlenbeck process
+starting at 0 and alpha is fitted to the discount curve.
+
+Parameters: [a, sigma].
+*/
+#[derive(Clone)]
+pub struct HullWhite {
+    term_structure: Rc<dyn YieldTermStructure>,
+    a: Real,
+    sigma: Volatility,
+}
+impl HullWhite {
+    // Constructor
+    pub fn new(
+        term_structure: Rc<dyn YieldTermStructure>,
+        a: Real,
+        sigma: Volatility,
+    ) -> HullWhite {
+        if a <= 0.0 {
+            panic!("mean reversion speed ({a}) must be positive");
+        }
+        if sigma <= 0.0 {
+            panic!("volatility ({sigma}) must be positive");
+        }
+        HullWhite {
+            term_structure,
+            a,
+            sigma,
+        }
+    }
+
+    // Inspectors
+    pub fn term_structure(&self) -> &dyn YieldTermStructure {
+        self.term_structure.as_ref()
+    }
+    pub fn a(&self) -> Real {
+        self.a
+    }
+    pub fn sigma(&self) -> Volatility {
+        self.sigma
+    }
+}
+impl CalibratedModel for HullWhite {
+    fn params(&self) -> Vec<Real> {
+        vec![self.a, self.sigma]
+    }
+    fn set_params(&mut self, params: &[Real]) {
+        self.a = params[0];
+        self.sigma = params[1];
+    }
+    fn constraints(&self) -> Vec<Constraint> {
+        vec![Constraint::Positive, Constraint::Positive]
+    }
+}
+impl AffineModel for HullWhite {
+    fn discount(&self, t: Time) -> DiscountFactor {
+        self.term_structure.discount(t)
+    }
+    fn discount_bond_option(
+        &self,
+        option_type: OptionType,
+        strike: Real,
+        maturity: Time,
+        bond_maturity: Time,
+    ) -> Real {
+        let v: Real = detail::bond_option_std_dev(self.a, self.sigma, maturity, bond_maturity);
+        let f: DiscountFactor = self.discount(bond_maturity);
+        let k: Real = self.discount(maturity) * strike;
+        black_formula(option_type, k, f, v, 1.0, 0.0)
+    }
+}
+impl OneFactorAffineModel for HullWhite {
+    fn short_rate(&self) -> Rate {
+        self.term_structure.instantaneous_forward(0.0)
+    }
+    fn affine_a(&self, now: Time, maturity: Time) -> Real {
+        let bt: Real = self.affine_b(now, maturity);
+        let temp: Real = self.sigma * bt;
+        let forward: Rate = self.term_structure.instantaneous_forward(now);
+        let value: Real = bt * forward - 0.25 * temp * temp * detail::b(self.a, 2.0 * now);
+        value.exp() * self.discount(maturity) / self.discount(now)
+    }
+    fn affine_b(&self, now: Time, maturity: Time) -> Real {
+        detail::b(self.a, maturity - now)
+    }
+}
+impl OneFactorModel for HullWhite {
+    fn tree(&self, time_grid: &TimeGrid) -> ShortRateTree {
+        let process: OrnsteinUhlenbeckProcess =
+            OrnsteinUhlenbeckProcess::new(self.a, self.sigma, 0.0, 0.0);
+        ShortRateTree::fitted(
+            TrinomialTree::new(&process, time_grid, false),
+            time_grid.clone(),
+            &|t: Time| self.discount(t),
+            &|x: Real| x,
+            &|_x: Real| 1.0,
+            self.short_rate(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::methods::lattices::tree::Tree;
+    use crate::termstructures::yieldcurves::flat_forward::FlatForward;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::{Date, Month};
+    use crate::time::day_counter::DayCounter;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::types::Size;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    // Upward sloping curve, P(0, t) = exp(-(0.02 + 0.004 t) t)
+    struct SlopedCurve(FlatForward);
+    impl YieldTermStructure for SlopedCurve {
+        fn reference_date(&self) -> Date {
+            self.0.reference_date()
+        }
+        fn day_counter(&self) -> &dyn DayCounter {
+            self.0.day_counter()
+        }
+        fn discount(&self, t: Time) -> DiscountFactor {
+            (-(0.02 + 0.004 * t) * t).exp()
+        }
+    }
+    fn sloped_curve() -> Rc<dyn YieldTermStructure> {
+        Rc::new(SlopedCurve(FlatForward::new(
+            Date::new(15, Month::January, 2025),
+            0.0,
+            Box::new(Actual365Fixed::new()),
+        )))
+    }
+
+    #[test]
+    fn model_reproduces_the_initial_curve() {
+        let model: HullWhite = HullWhite::new(flat_rate(reference_date(), 0.04), 0.1, 0.01);
+        for t in [0.0_f64, 1.0, 7.5] {
+            let expected: DiscountFactor = (-0.04 * t).exp();
+            assert!((model.discount(t) - expected).abs() < 1.0e-15);
+            assert!((model.discount_bond(0.0, t, model.short_rate()) - expected).abs() < 1.0e-15);
+        }
+        let sloped: Rc<dyn YieldTermStructure> = sloped_curve();
+        let model: HullWhite = HullWhite::new(sloped.clone(), 0.1, 0.01);
+        for t in [1.0, 7.5] {
+            let expected: DiscountFactor = sloped.discount(t);
+            assert!((model.discount_bond(0.0, t, model.short_rate()) - expected).abs() < 1.0e-15);
+        }
+    }
+
+    #[test]
+    fn forward_bond_prices_are_martingales() {
+        // E[P(t, T, r_t)] discounted to today must give P(0, T): with a
+        // flat curve, check it on the tree at t = 2 for T = 5
+        let model: HullWhite = HullWhite::new(flat_rate(reference_date(), 0.04), 0.1, 0.01);
+        let steps: Size = 80;
+        let grid: TimeGrid = TimeGrid::new(2.0, steps);
+        let tree: ShortRateTree = model.tree(&grid);
+        let mut values: Vec<Real> = (0..tree.size(steps))
+            .map(|j| {
+                // short rate at the last column, from its neighbour's shift
+                let x: Real = tree.underlying(steps, j);
+                let alpha: Real = tree.rate(steps - 1, 0) - tree.underlying(steps - 1, 0);
+                model.discount_bond(2.0, 5.0, x + alpha)
+            })
+            .collect();
+        for i in (0..steps).rev() {
+            values = (0..tree.size(i))
+                .map(|j| {
+                    (0..3)
+                        .map(|b| tree.probability(i, j, b) * values[tree.descendant(i, j, b)])
+                        .sum::<Real>()
+                        * tree.discount(i, j)
+                })
+                .collect();
+        }
+        assert!(
+            (values[0] - model.discount(5.0)).abs() < 1.0e-4,
+            "{} vs {}",
+            values[0],
+            model.discount(5.0)
+        );
+    }
+
+    #[test]
+    fn fitted_tree_reproduces_discount_factors() {
+        for term_structure in [flat_rate(reference_date(), 0.04), sloped_curve()] {
+            let model: HullWhite = HullWhite::new(term_structure, 0.1, 0.01);
+            let steps: Size = 50;
+            let grid: TimeGrid = TimeGrid::new(5.0, steps);
+            let tree: ShortRateTree = model.tree(&grid);
+            let mut values: Vec<Real> = vec![1.0; tree.size(steps)];
+            for i in (0..steps).rev() {
+                values = (0..tree.size(i))
+                    .map(|j| {
+                        (0..3)
+                            .map(|b| tree.probability(i, j, b) * values[tree.descendant(i, j, b)])
+                            .sum::<Real>()
+                            * tree.discount(i, j)
+                    })
+                    .collect();
+            }
+            assert!((values[0] - model.discount(5.0)).abs() < 1.0e-12);
+        }
+    }
+
+    #[test]
+    fn new_panics_on_invalid_parameters() {
+        let cases: [(Real, Real, &str); 2] = [(0.0, 0.01, "null speed"), (0.1, 0.0, "null vol")];
+        for (a, sigma, label) in cases {
+            let result = std::panic::catch_unwind(|| {
+                HullWhite::new(flat_rate(reference_date(), 0.03), a, sigma)
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
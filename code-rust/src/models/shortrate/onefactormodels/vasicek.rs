@@ -0,0 +1,240 @@
+use crate::math::optimization::constraint::Constraint;
+use crate::methods::lattices::trinomial_tree::TrinomialTree;
+use crate::models::model::{AffineModel, CalibratedModel};
+use crate::models::shortrate::one_factor_model::{
+    OneFactorAffineModel, OneFactorModel, ShortRateTree,
+};
+use crate::option::OptionType;
+use crate::pricingengines::black_formula::black_formula;
+use crate::processes::ornstein_uhlenbeck_process::OrnsteinUhlenbeckProcess;
+use crate::time_grid::TimeGrid;
+use crate::types::{DiscountFactor, Rate, Real, Time, Volatility};
+
+/*
+Vasicek model (QuantLib Vasicek).
+
+    dr = a (b - r) dt + sigma dw
+
+under the real-world measure, with market price of risk lambda, so that
+the risk-neutral long-term level is b* = b + lambda sigma / a. Zero
+bonds are affine with
+    B(t, T) = (1 - exp(-a (T - t))) / a
+    A(t, T) = exp((b* - sigma^2 / (2 a^2)) (B - (T - t)) - sigma^2 B^2 / (4 a))
+and bond options follow Jamshidian's Black-like formula. The model
+generates its own discount curve, it is not fitted to a market one.
+
+Parameters: [a, b, sigma, lambda]; r0 is not calibrated.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Vasicek {
+    r0: Rate,
+    a: Real,
+    b: Real,
+    sigma: Volatility,
+    lambda: Real,
+}
+impl Vasicek {
+    // Constructor
+    pub fn new(r0: Rate, a: Real, b: Real, sigma: Volatility, lambda: Real) -> Vasicek {
+        if a <= 0.0 {
+            panic!("mean reversion speed ({a}) must be positive");
+        }
+        if sigma <= 0.0 {
+            panic!("volatility ({sigma}) must be positive");
+        }
+        Vasicek {
+            r0,
+            a,
+            b,
+            sigma,
+            lambda,
+        }
+    }
+
+    // Inspectors
+    pub fn r0(&self) -> Rate {
+        self.r0
+    }
+    pub fn a(&self) -> Real {
+        self.a
+    }
+    pub fn b(&self) -> Real {
+        self.b
+    }
+    pub fn sigma(&self) -> Volatility {
+        self.sigma
+    }
+    pub fn lambda(&self) -> Real {
+        self.lambda
+    }
+
+    // Risk-neutral long-term level
+    fn risk_neutral_level(&self) -> Real {
+        self.b + self.lambda * self.sigma / self.a
+    }
+}
+impl CalibratedModel for Vasicek {
+    fn params(&self) -> Vec<Real> {
+        vec![self.a, self.b, self.sigma, self.lambda]
+    }
+    fn set_params(&mut self, params: &[Real]) {
+        self.a = params[0];
+        self.b = params[1];
+        self.sigma = params[2];
+        self.lambda = params[3];
+    }
+    fn constraints(&self) -> Vec<Constraint> {
+        vec![
+            Constraint::Positive,
+            Constraint::NoConstraint,
+            Constraint::Positive,
+            Constraint::NoConstraint,
+        ]
+    }
+}
+impl AffineModel for Vasicek {
+    fn discount(&self, t: Time) -> DiscountFactor {
+        self.discount_bond(0.0, t, self.r0)
+    }
+    fn discount_bond_option(
+        &self,
+        option_type: OptionType,
+        strike: Real,
+        maturity: Time,
+        bond_maturity: Time,
+    ) -> Real {
+        let v: Real = detail::bond_option_std_dev(self.a, self.sigma, maturity, bond_maturity);
+        let f: DiscountFactor = self.discount(bond_maturity);
+        let k: Real = self.discount(maturity) * strike;
+        black_formula(option_type, k, f, v, 1.0, 0.0)
+    }
+}
+impl OneFactorAffineModel for Vasicek {
+    fn short_rate(&self) -> Rate {
+        self.r0
+    }
+    fn affine_a(&self, now: Time, maturity: Time) -> Real {
+        let sigma2: Real = self.sigma * self.sigma;
+        let bt: Real = self.affine_b(now, maturity);
+        let level: Real = self.risk_neutral_level() - 0.5 * sigma2 / (self.a * self.a);
+        (level * (bt - (maturity - now)) - 0.25 * sigma2 * bt * bt / self.a).exp()
+    }
+    fn affine_b(&self, now: Time, maturity: Time) -> Real {
+        detail::b(self.a, maturity - now)
+    }
+}
+impl OneFactorModel for Vasicek {
+    fn tree(&self, time_grid: &TimeGrid) -> ShortRateTree {
+        let process: OrnsteinUhlenbeckProcess =
+            OrnsteinUhlenbeckProcess::new(self.a, self.sigma, self.r0, self.risk_neutral_level());
+        ShortRateTree::new(
+            TrinomialTree::new(&process, time_grid, false),
+            time_grid.clone(),
+        )
+    }
+}
+
+pub(crate) mod detail {
+    use crate::types::{Real, Time, Volatility};
+
+    // B(t, t + tau) of the Gaussian short-rate models
+    pub(crate) fn b(a: Real, tau: Time) -> Real {
+        if a < Real::EPSILON.sqrt() {
+            tau
+        } else {
+            (1.0 - (-a * tau).exp()) / a
+        }
+    }
+
+    // Standard deviation of ln P(maturity, bond_maturity) at maturity
+    pub(crate) fn bond_option_std_dev(
+        a: Real,
+        sigma: Volatility,
+        maturity: Time,
+        bond_maturity: Time,
+    ) -> Real {
+        let variance_factor: Real = if a < Real::EPSILON.sqrt() {
+            maturity
+        } else {
+            0.5 * (1.0 - (-2.0 * a * maturity).exp()) / a
+        };
+        sigma * b(a, bond_maturity - maturity) * variance_factor.sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::methods::lattices::tree::Tree;
+    use crate::types::Size;
+
+    fn model() -> Vasicek {
+        Vasicek::new(0.03, 0.2, 0.05, 0.015, 0.0)
+    }
+
+    #[test]
+    fn discount_bonds_match_the_closed_form() {
+        // Long-term yield R = b - sigma^2 / (2 a^2)
+        let model: Vasicek = model();
+        let (a, b, sigma, r0): (Real, Real, Real, Real) = (0.2, 0.05, 0.015, 0.03);
+        let long_yield: Real = b - sigma * sigma / (2.0 * a * a);
+        for tau in [0.5, 2.0, 10.0] {
+            let bt: Real = (1.0 - (-a * tau).exp()) / a;
+            let expected: Real = (-long_yield * tau + (long_yield - r0) * bt
+                - sigma * sigma * bt * bt / (4.0 * a))
+                .exp();
+            assert!((model.discount(tau) - expected).abs() < 1.0e-14);
+            assert!((model.discount_bond(1.0, 1.0 + tau, r0) - expected).abs() < 1.0e-14);
+        }
+        assert_eq!(model.discount(0.0), 1.0);
+    }
+
+    #[test]
+    fn bond_options_satisfy_put_call_parity() {
+        let model: Vasicek = model();
+        let (maturity, bond_maturity, strike): (Time, Time, Real) = (1.0, 5.0, 0.85);
+        let call: Real =
+            model.discount_bond_option(OptionType::Call, strike, maturity, bond_maturity);
+        let put: Real =
+            model.discount_bond_option(OptionType::Put, strike, maturity, bond_maturity);
+        let forward: Real = model.discount(bond_maturity) - strike * model.discount(maturity);
+        assert!(call > 0.0 && put > 0.0);
+        assert!((call - put - forward).abs() < 1.0e-14);
+    }
+
+    #[test]
+    fn tree_prices_zero_bonds() {
+        // Rates are frozen over each step, hence an O(dt) error
+        let model: Vasicek = model();
+        let steps: Size = 200;
+        let grid: TimeGrid = TimeGrid::new(5.0, steps);
+        let tree: ShortRateTree = model.tree(&grid);
+        let mut values: Vec<Real> = vec![1.0; tree.size(steps)];
+        for i in (0..steps).rev() {
+            values = (0..tree.size(i))
+                .map(|j| {
+                    (0..3)
+                        .map(|b| tree.probability(i, j, b) * values[tree.descendant(i, j, b)])
+                        .sum::<Real>()
+                        * tree.discount(i, j)
+                })
+                .collect();
+        }
+        assert!(
+            (values[0] - model.discount(5.0)).abs() < 2.0e-4,
+            "{} vs {}",
+            values[0],
+            model.discount(5.0)
+        );
+    }
+
+    #[test]
+    fn new_panics_on_invalid_parameters() {
+        let cases: [(Real, Real, &str); 2] =
+            [(0.0, 0.01, "null speed"), (0.1, -0.01, "negative vol")];
+        for (a, sigma, label) in cases {
+            let result = std::panic::catch_unwind(|| Vasicek::new(0.03, a, 0.05, sigma, 0.0));
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use crate::math::optimization::constraint::Constraint;
+use crate::models::model::{AffineModel, CalibratedModel};
+use crate::option::OptionType;
+use crate::pricingengines::black_formula::black_formula;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::types::{DiscountFactor, Real, Time, Volatility};
+use std::rc::Rc;
+
+/*
+Two-additive-factor Gaussian model G2++ (QuantLib G2).
+
+    r(t) = x(t) + y(t) + phi(t)
+    dx = -a x dt + sigma dw1,   dy = -b y dt + eta dw2,   dw1 dw2 = rho dt
+
+phi(t) fits the initial term structure P(0, t). Zero bonds are (Brigo-Mercurio 4.2)
+    P(t, T) = P(0, T) / P(0, t) exp(0.5 (V(T - t) - V(T) + V(t))
+              - B(a, T - t) x - B(b, T - t) y)
+with B(z, tau) = (1 - exp(-z tau)) / z and V(tau) the variance of the
+integral of x + y over tau. Bond options are given by the Black formula
+with the volatility sigma_P of the forward bond price.
+
+Parameters: [a, sigma, b, eta, rho].
+*/
+#[derive(Clone)]
+pub struct G2 {
+    term_structure: Rc<dyn YieldTermStructure>,
+    a: Real,
+    sigma: Volatility,
+    b: Real,
+    eta: Volatility,
+    rho: Real,
+}
+impl G2 {
+    // Constructor
+    pub fn new(
+        term_structure: Rc<dyn YieldTermStructure>,
+        a: Real,
+        sigma: Volatility,
+        b: Real,
+        eta: Volatility,
+        rho: Real,
+    ) -> G2 {
+        let cases: [(Real, &str); 4] = [(a, "a"), (sigma, "sigma"), (b, "b"), (eta, "eta")];
+        for (value, name) in cases {
+            if value <= 0.0 {
+                panic!("{name} ({value}) must be positive");
+            }
+        }
+        if !(-1.0..=1.0).contains(&rho) {
+            panic!("correlation ({rho}) must be in [-1, 1]");
+        }
+        G2 {
+            term_structure,
+            a,
+            sigma,
+            b,
+            eta,
+            rho,
+        }
+    }
+
+    // Inspectors
+    pub fn term_structure(&self) -> &dyn YieldTermStructure {
+        self.term_structure.as_ref()
+    }
+    pub fn a(&self) -> Real {
+        self.a
+    }
+    pub fn sigma(&self) -> Volatility {
+        self.sigma
+    }
+    pub fn b(&self) -> Real {
+        self.b
+    }
+    pub fn eta(&self) -> Volatility {
+        self.eta
+    }
+    pub fn rho(&self) -> Real {
+        self.rho
+    }
+
+    // Price at time now of the zero bond maturing at maturity, given the factors
+    pub fn discount_bond(&self, now: Time, maturity: Time, x: Real, y: Real) -> DiscountFactor {
+        let tau: Time = maturity - now;
+        let a: Real = self.discount(maturity) / self.discount(now)
+            * (0.5 * (self.v(tau) - self.v(maturity) + self.v(now))).exp();
+        a * (-detail::b(self.a, tau) * x - detail::b(self.b, tau) * y).exp()
+    }
+
+    // Variance of the integral of x + y over [0, t]
+    fn v(&self, t: Time) -> Real {
+        let term = |z: Real, vol: Real| -> Real {
+            vol * vol / (z * z)
+                * (t + 2.0 / z * (-z * t).exp() - 0.5 / z * (-2.0 * z * t).exp() - 1.5 / z)
+        };
+        let cross: Real = 2.0 * self.rho * self.sigma * self.eta / (self.a * self.b)
+            * (t + ((-self.a * t).exp() - 1.0) / self.a + ((-self.b * t).exp() - 1.0) / self.b
+                - ((-(self.a + self.b) * t).exp() - 1.0) / (self.a + self.b));
+        term(self.a, self.sigma) + term(self.b, self.eta) + cross
+    }
+
+    // Volatility of the forward price of the bond maturing at s, up to t
+    fn sigma_p(&self, t: Time, s: Time) -> Real {
+        let temp: Real = 1.0 - (-(self.a + self.b) * t).exp();
+        let temp1: Real = 1.0 - (-self.a * (s - t)).exp();
+        let temp2: Real = 1.0 - (-self.b * (s - t)).exp();
+        let a3: Real = self.a * self.a * self.a;
+        let b3: Real = self.b * self.b * self.b;
+        let value: Real =
+            0.5 * self.sigma * self.sigma * temp1 * temp1 * (1.0 - (-2.0 * self.a * t).exp()) / a3
+                + 0.5 * self.eta * self.eta * temp2 * temp2 * (1.0 - (-2.0 * self.b * t).exp())
+                    / b3
+                + 2.0 * self.rho * self.sigma * self.eta / (self.a * self.b * (self.a + self.b))
+                    * temp1
+                    * temp2
+                    * temp;
+        value.sqrt()
+    }
+}
+impl CalibratedModel for G2 {
+    fn params(&self) -> Vec<Real> {
+        vec![self.a, self.sigma, self.b, self.eta, self.rho]
+    }
+    fn set_params(&mut self, params: &[Real]) {
+        self.a = params[0];
+        self.sigma = params[1];
+        self.b = params[2];
+        self.eta = params[3];
+        self.rho = params[4];
+    }
+    fn constraints(&self) -> Vec<Constraint> {
+        vec![
+            Constraint::Positive,
+            Constraint::Positive,
+            Constraint::Positive,
+            Constraint::Positive,
+            Constraint::Boundary {
+                low: -1.0,
+                high: 1.0,
+            },
+        ]
+    }
+}
+impl AffineModel for G2 {
+    fn discount(&self, t: Time) -> DiscountFactor {
+        self.term_structure.discount(t)
+    }
+    fn discount_bond_option(
+        &self,
+        option_type: OptionType,
+        strike: Real,
+        maturity: Time,
+        bond_maturity: Time,
+    ) -> Real {
+        let v: Real = self.sigma_p(maturity, bond_maturity);
+        let f: DiscountFactor = self.discount(bond_maturity);
+        let k: Real = self.discount(maturity) * strike;
+        black_formula(option_type, k, f, v, 1.0, 0.0)
+    }
+}
+
+mod detail {
+    use crate::types::{Real, Time};
+
+    pub(super) fn b(z: Real, tau: Time) -> Real {
+        (1.0 - (-z * tau).exp()) / z
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::shortrate::one_factor_model::OneFactorAffineModel;
+    use crate::models::shortrate::onefactormodels::hull_white::HullWhite;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::{Date, Month};
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    #[test]
+    fn one_vanishing_factor_gives_hull_white() {
+        let g2: G2 = G2::new(
+            flat_rate(reference_date(), 0.03),
+            0.1,
+            0.01,
+            0.5,
+            1.0e-12,
+            0.0,
+        );
+        let hull_white: HullWhite = HullWhite::new(flat_rate(reference_date(), 0.03), 0.1, 0.01);
+        for (maturity, bond_maturity, strike) in [(1.0, 5.0, 0.88), (2.0, 2.5, 0.985)] {
+            for option_type in [OptionType::Call, OptionType::Put] {
+                let expected: Real =
+                    hull_white.discount_bond_option(option_type, strike, maturity, bond_maturity);
+                let calculated: Real =
+                    g2.discount_bond_option(option_type, strike, maturity, bond_maturity);
+                assert!((calculated - expected).abs() < 1.0e-12);
+            }
+            let x: Real = 0.01;
+            let expected: DiscountFactor =
+                hull_white.discount_bond(maturity, bond_maturity, hull_white.short_rate() + x);
+            // Hull-White's r includes phi(t), G2's factors do not
+            let phi: Real = 0.03 + 0.5 * (0.01 * detail::b(0.1, maturity)).powi(2);
+            let calculated: DiscountFactor = g2.discount_bond(
+                maturity,
+                bond_maturity,
+                hull_white.short_rate() + x - phi,
+                0.0,
+            );
+            assert!(
+                (calculated - expected).abs() < 1.0e-12,
+                "{calculated} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn discount_bonds_today_match_the_curve() {
+        let g2: G2 = G2::new(
+            flat_rate(reference_date(), 0.03),
+            0.1,
+            0.01,
+            0.5,
+            0.008,
+            -0.7,
+        );
+        for t in [0.5, 3.0, 10.0] {
+            assert!((g2.discount_bond(0.0, t, 0.0, 0.0) - (-0.03 * t).exp()).abs() < 1.0e-15);
+        }
+    }
+
+    #[test]
+    fn new_panics_on_invalid_parameters() {
+        let cases: [(Real, Real, &str); 3] = [
+            (0.0, 0.5, "null a"),
+            (0.1, 1.5, "correlation above one"),
+            (0.1, -1.5, "correlation below minus one"),
+        ];
+        for (a, rho, label) in cases {
+            let result = std::panic::catch_unwind(|| {
+                G2::new(flat_rate(reference_date(), 0.03), a, 0.01, 0.5, 0.008, rho)
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
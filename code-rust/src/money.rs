@@ -0,0 +1,157 @@
+use crate::currency::Currency;
+use crate::exchange_rate::{ExchangeRate, ExchangeRateType};
+use crate::exchange_rate_manager::ExchangeRateManager;
+use crate::time::date::Date;
+use crate::types::{Real, Size};
+use std::fmt::{Display, Formatter, Result};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/*
+Amount of cash in a given currency (QuantLib Money).
+
+Arithmetic between amounts in different currencies is not allowed:
+QuantLib may convert them automatically through global settings, here
+amounts are converted explicitly with converted_to, which looks up the
+exchange rate in an ExchangeRateManager.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Money {
+    value: Real,
+    currency: Currency,
+}
+impl Money {
+    // Constructor
+    pub fn new(value: Real, currency: Currency) -> Money {
+        Money { value, currency }
+    }
+
+    // Inspectors
+    pub fn value(&self) -> Real {
+        self.value
+    }
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    // Amount rounded with the rounding of its currency
+    pub fn rounded(&self) -> Money {
+        Money::new(self.currency.rounding().apply(self.value), self.currency)
+    }
+    /*
+    Amount converted to the target currency at the exchange rate the
+    manager holds for the given date, direct or derived.
+    */
+    pub fn converted_to(
+        &self,
+        target: Currency,
+        manager: &ExchangeRateManager,
+        date: Date,
+    ) -> Money {
+        if self.currency == target {
+            return *self;
+        }
+        let rate: ExchangeRate =
+            manager.lookup(self.currency, target, date, ExchangeRateType::Derived);
+        rate.exchange(self).rounded()
+    }
+
+    fn check_currency(&self, other: &Money) {
+        if self.currency != other.currency {
+            panic!(
+                "currency mismatch ({} vs {}): convert the amounts first",
+                self.currency, other.currency
+            );
+        }
+    }
+}
+
+// Traits
+impl Neg for Money {
+    type Output = Money;
+    fn neg(self) -> Money {
+        Money::new(-self.value, self.currency)
+    }
+}
+impl Add for Money {
+    type Output = Money;
+    fn add(self, other: Money) -> Money {
+        self.check_currency(&other);
+        Money::new(self.value + other.value, self.currency)
+    }
+}
+impl Sub for Money {
+    type Output = Money;
+    fn sub(self, other: Money) -> Money {
+        self.check_currency(&other);
+        Money::new(self.value - other.value, self.currency)
+    }
+}
+impl Mul<Real> for Money {
+    type Output = Money;
+    fn mul(self, factor: Real) -> Money {
+        Money::new(self.value * factor, self.currency)
+    }
+}
+impl Mul<Currency> for Real {
+    type Output = Money;
+    fn mul(self, currency: Currency) -> Money {
+        Money::new(self, currency)
+    }
+}
+impl Div<Real> for Money {
+    type Output = Money;
+    fn div(self, divisor: Real) -> Money {
+        Money::new(self.value / divisor, self.currency)
+    }
+}
+impl Display for Money {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let decimals: Size = (self.currency.fractions_per_unit() as Real).log10().round() as Size;
+        write!(f, "{:.*} {}", decimals, self.rounded().value, self.currency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::currencies::america::USD;
+    use crate::currencies::europe::{EUR, GBP, ITL};
+    use crate::time::date::Month;
+
+    #[test]
+    fn arithmetic_in_the_same_currency() {
+        let m1: Money = 50000.0 * EUR;
+        let m2: Money = 100000.0 * EUR;
+        assert_eq!((m1 + m2 * 2.0 - m1 / 2.0).value(), 225000.0);
+        assert_eq!(-m1, -50000.0 * EUR);
+        assert_eq!((1.005 * EUR).rounded().value(), 1.01);
+        assert_eq!(format!("{}", 1234.5678 * EUR), "1234.57 EUR");
+        assert_eq!(format!("{}", 1234.0 * ITL), "1234 ITL");
+    }
+
+    #[test]
+    fn arithmetic_panics_on_currency_mismatch() {
+        let result = std::panic::catch_unwind(|| 1.0 * EUR + 1.0 * USD);
+        assert!(result.is_err(), "expected panic for EUR + USD");
+    }
+
+    #[test]
+    fn amounts_are_converted_through_the_manager() {
+        let mut manager: ExchangeRateManager = ExchangeRateManager::new();
+        let start: Date = Date::new(1, Month::January, 2025);
+        let end: Date = Date::new(31, Month::December, 2025);
+        manager.add(ExchangeRate::new(EUR, USD, 1.2042), start, end);
+        manager.add(ExchangeRate::new(EUR, GBP, 0.6612), start, end);
+        let date: Date = Date::new(15, Month::June, 2025);
+
+        let usd: Money = (50000.0 * EUR).converted_to(USD, &manager, date);
+        assert_eq!(usd.currency(), USD);
+        assert!((usd.value() - 60210.0).abs() < 1.0e-8);
+        let gbp: Money = (60210.0 * USD).converted_to(GBP, &manager, date);
+        assert!((gbp.value() - 33060.0).abs() < 1.0e-6, "{}", gbp);
+        let result = std::panic::catch_unwind(|| {
+            (1.0 * EUR).converted_to(USD, &ExchangeRateManager::new(), date)
+        });
+        assert!(result.is_err(), "expected panic for a missing rate");
+    }
+}
@@ -0,0 +1,59 @@
+use std::fmt::{Display, Formatter, Result};
+
+// In QuantLib this is Option::Type. In Rust `Option` is already taken
+// by the standard library, so the enum gets its own name.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum OptionType {
+    Put = -1,
+    Call = 1,
+}
+impl OptionType {
+    // +1 for a call and -1 for a put, handy to write payoffs as
+    // max(phi * (S - K), 0) without branching on the type.
+    pub fn sign(&self) -> f64 {
+        *self as i32 as f64
+    }
+}
+
+// Traits
+impl Display for OptionType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let option_type: &'static str = match self {
+            OptionType::Put => "Put",
+            OptionType::Call => "Call",
+        };
+        write!(f, "{option_type}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_option_type_outputs_correct_format() {
+        let cases: [(OptionType, &str); 2] = [(OptionType::Call, "Call"), (OptionType::Put, "Put")];
+
+        for (option_type, expected) in cases {
+            assert_eq!(
+                format!("{option_type}"),
+                expected,
+                "Failed for option type {:?}",
+                option_type
+            );
+        }
+    }
+
+    #[test]
+    fn sign_is_plus_one_for_call_and_minus_one_for_put() {
+        let cases: [(OptionType, f64); 2] = [(OptionType::Call, 1.0), (OptionType::Put, -1.0)];
+
+        for (option_type, expected) in cases {
+            assert_eq!(
+                option_type.sign(),
+                expected,
+                "Failed for option type {option_type:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,268 @@
+use crate::exercise::Exercise;
+use crate::instruments::asian_option::{AverageType, DiscreteAveragingAsianOption};
+use crate::instruments::payoffs::Payoff;
+use crate::math::randomnumbers::rng_traits::{PseudoRandom, PseudoRandomRsg};
+use crate::math::statistics::incremental_statistics::IncrementalStatistics;
+use crate::methods::montecarlo::monte_carlo_model::MonteCarloModel;
+use crate::methods::montecarlo::path::Path;
+use crate::methods::montecarlo::path_generator::PathGenerator;
+use crate::methods::montecarlo::path_pricer::PathPricer;
+use crate::pricingengines::asian::analytic_discrete_geometric_average_price_asian_engine;
+use crate::pricingengines::mc_results::McResults;
+use crate::pricingengines::mc_simulation::McSimulation;
+use crate::processes::black_scholes_process::BlackScholesMertonProcess;
+use crate::stochastic_process::StochasticProcess1D;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::time_grid::TimeGrid;
+use crate::types::{DiscountFactor, Real, Size, Time};
+
+type Generator = PathGenerator<BlackScholesMertonProcess, PseudoRandomRsg>;
+type Pricer = Box<dyn Fn(&Path) -> Real>;
+
+/*
+Monte Carlo engine for discrete arithmetic average-price Asian options
+(QuantLib MCDiscreteArithmeticAPEngine). Paths go through the future
+fixing times; each one is priced as the discounted payoff of the
+average of the past and simulated fixings.
+
+With the control variate, each sample is corrected by the geometric
+average-price option on the future fixings, whose value is known
+analytically. Geometric and arithmetic averages are highly correlated,
+so this removes most of the variance.
+*/
+pub struct McDiscreteArithmeticApEngine {
+    process: BlackScholesMertonProcess,
+    reference_date: Date,
+    day_counter: Box<dyn DayCounter>,
+    samples: Size,
+    seed: u64,
+    antithetic_variate: bool,
+    control_variate: bool,
+}
+impl McDiscreteArithmeticApEngine {
+    // Constructor
+    pub fn new(
+        process: BlackScholesMertonProcess,
+        reference_date: Date,
+        day_counter: Box<dyn DayCounter>,
+        samples: Size,
+        seed: u64,
+    ) -> McDiscreteArithmeticApEngine {
+        if samples == 0 {
+            panic!("at least one sample is required");
+        }
+        McDiscreteArithmeticApEngine {
+            process,
+            reference_date,
+            day_counter,
+            samples,
+            seed,
+            antithetic_variate: false,
+            control_variate: false,
+        }
+    }
+
+    // Modifiers
+    pub fn with_antithetic_variate(
+        mut self,
+        antithetic_variate: bool,
+    ) -> McDiscreteArithmeticApEngine {
+        self.antithetic_variate = antithetic_variate;
+        self
+    }
+    pub fn with_control_variate(mut self, control_variate: bool) -> McDiscreteArithmeticApEngine {
+        self.control_variate = control_variate;
+        self
+    }
+
+    pub fn calculate(&self, option: &DiscreteAveragingAsianOption) -> McResults {
+        if option.average_type() != AverageType::Arithmetic {
+            panic!("not an arithmetic average option");
+        }
+        let fixing_times: Vec<Time> =
+            analytic_discrete_geometric_average_price_asian_engine::detail::fixing_times(
+                option,
+                self.reference_date,
+                self.day_counter.as_ref(),
+            );
+        let maturity: Time = self
+            .day_counter
+            .year_fraction(self.reference_date, option.exercise().last_date());
+        if maturity <= 0.0 {
+            panic!("option expired: maturity {maturity} before the reference date");
+        }
+        if fixing_times.is_empty() || fixing_times[fixing_times.len() - 1] == 0.0 {
+            panic!("no future fixing to simulate");
+        }
+        let grid: TimeGrid = TimeGrid::from_mandatory_times(&fixing_times, fixing_times.len());
+        let indexes: Vec<Size> = fixing_times.iter().map(|t| grid.index_of(*t)).collect();
+        let discount: DiscountFactor = (-self.process.risk_free_rate() * maturity).exp();
+        let generator: Generator = PathGenerator::new(
+            self.process,
+            grid.clone(),
+            PseudoRandom::make_sequence_generator(grid.len() - 1, self.seed),
+            false,
+        );
+
+        let fixings: Real = (option.past_fixings() + fixing_times.len()) as Real;
+        let running_sum: Real = option.running_accumulator();
+        let payoff = *option.payoff();
+        let arithmetic_indexes: Vec<Size> = indexes.clone();
+        let pricer: Pricer = Box::new(move |path: &Path| {
+            let sum: Real = arithmetic_indexes
+                .iter()
+                .map(|i| path.values()[*i])
+                .sum::<Real>();
+            discount * payoff.value((running_sum + sum) / fixings)
+        });
+
+        let (cv_pricer, cv_value): (Option<Box<dyn PathPricer<Path>>>, Real) = if self
+            .control_variate
+        {
+            let geometric: DiscreteAveragingAsianOption =
+                DiscreteAveragingAsianOption::with_future_fixings(
+                    AverageType::Geometric,
+                    option.fixing_dates(),
+                    *option.payoff(),
+                    option.exercise().clone(),
+                );
+            let cv_value: Real =
+                analytic_discrete_geometric_average_price_asian_engine::detail::value(
+                    &geometric,
+                    &fixing_times,
+                    self.process.x0(),
+                    &self.process,
+                    maturity,
+                );
+            let future_fixings: Real = indexes.len() as Real;
+            let cv_pricer: Pricer = Box::new(move |path: &Path| {
+                let log_sum: Real = indexes.iter().map(|i| path.values()[*i].ln()).sum::<Real>();
+                discount * payoff.value((log_sum / future_fixings).exp())
+            });
+            (Some(Box::new(cv_pricer)), cv_value)
+        } else {
+            (None, 0.0)
+        };
+
+        let mut simulation: McSimulation<Generator, Pricer> =
+            McSimulation::new(MonteCarloModel::new(
+                generator,
+                pricer,
+                IncrementalStatistics::new(),
+                self.antithetic_variate,
+                cv_pricer,
+                cv_value,
+                None,
+            ));
+        let value: Real = simulation.value_with_samples(self.samples);
+        McResults {
+            value,
+            error_estimate: simulation.error_estimate(),
+            samples: simulation.sample_accumulator().samples(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::EuropeanExercise;
+    use crate::instruments::payoffs::PlainVanillaPayoff;
+    use crate::option::OptionType;
+    use crate::pricingengines::asian::analytic_discrete_geometric_average_price_asian_engine::AnalyticDiscreteGeometricAveragePriceAsianEngine;
+    use crate::pricingengines::black_formula::black_formula;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual360::Actual360;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn process() -> BlackScholesMertonProcess {
+        BlackScholesMertonProcess::new(100.0, 0.06, 0.03, 0.2)
+    }
+
+    fn engine(samples: Size) -> McDiscreteArithmeticApEngine {
+        McDiscreteArithmeticApEngine::new(
+            process(),
+            reference_date(),
+            Box::new(Actual360::new()),
+            samples,
+            42,
+        )
+    }
+
+    fn option(average_type: AverageType, fixing_dates: &[Date]) -> DiscreteAveragingAsianOption {
+        DiscreteAveragingAsianOption::with_future_fixings(
+            average_type,
+            fixing_dates,
+            PlainVanillaPayoff::new(OptionType::Call, 100.0),
+            EuropeanExercise::new(reference_date() + 360),
+        )
+    }
+
+    #[test]
+    fn single_fixing_at_maturity_is_european() {
+        let results: McResults = engine(20_000)
+            .with_antithetic_variate(true)
+            .calculate(&option(AverageType::Arithmetic, &[reference_date() + 360]));
+        let expected: Real = black_formula(
+            OptionType::Call,
+            100.0,
+            100.0 * (0.03_f64).exp(),
+            0.2,
+            (-0.06_f64).exp(),
+            0.0,
+        );
+        assert_eq!(results.samples, 20_000);
+        assert!(
+            (results.value - expected).abs() < 3.0 * results.error_estimate,
+            "{} vs {} ({})",
+            results.value,
+            expected,
+            results.error_estimate
+        );
+    }
+
+    #[test]
+    fn control_variate_reduces_the_error() {
+        let fixing_dates: Vec<Date> = (1..=10).map(|j| reference_date() + 36 * j).collect();
+        let arithmetic: DiscreteAveragingAsianOption =
+            option(AverageType::Arithmetic, &fixing_dates);
+        let plain: McResults = engine(10_000).calculate(&arithmetic);
+        let controlled: McResults = engine(10_000)
+            .with_control_variate(true)
+            .calculate(&arithmetic);
+        assert!(controlled.error_estimate < 0.1 * plain.error_estimate);
+        assert!((plain.value - controlled.value).abs() < 3.0 * plain.error_estimate);
+
+        // The arithmetic average is above the geometric one, and close to it
+        let geometric: Real = AnalyticDiscreteGeometricAveragePriceAsianEngine::new(
+            process(),
+            reference_date(),
+            Box::new(Actual360::new()),
+        )
+        .calculate(&option(AverageType::Geometric, &fixing_dates))
+        .value;
+        assert!(controlled.value > geometric && controlled.value < geometric + 0.3);
+    }
+
+    #[test]
+    fn calculate_panics_on_invalid_options() {
+        let cases: [(DiscreteAveragingAsianOption, &str); 2] = [
+            (
+                option(AverageType::Geometric, &[reference_date() + 360]),
+                "geometric average",
+            ),
+            (
+                option(AverageType::Arithmetic, &[reference_date()]),
+                "no future fixing",
+            ),
+        ];
+        for (option, label) in cases {
+            let result = std::panic::catch_unwind(|| engine(100).calculate(&option));
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
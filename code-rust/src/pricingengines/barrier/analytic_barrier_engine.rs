@@ -0,0 +1,348 @@
+use crate::exercise::Exercise;
+use crate::instruments::barrier_option::{BarrierOption, BarrierType};
+use crate::instruments::payoffs::StrikedTypePayoff;
+use crate::option::OptionType;
+use crate::pricingengines::greeks::spot_greeks;
+use crate::pricingengines::vanilla::vanilla_option_results::VanillaOptionResults;
+use crate::processes::black_scholes_process::BlackScholesMertonProcess;
+use crate::stochastic_process::StochasticProcess1D;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Real, Time};
+
+/*
+Analytic engine for single-barrier options (QuantLib
+AnalyticBarrierEngine), with the Reiner-Rubinstein formulas as given by
+Haug, "The Complete Guide to Option Pricing Formulas". Each case is a
+combination of the terms A to F:
+    A, B: vanilla-like terms at strike and barrier
+    C, D: their reflections through the barrier
+    E: rebate of knock-in options, paid at expiry
+    F: rebate of knock-out options, paid at the hit
+Delta and gamma come from central differences of the formula.
+*/
+pub struct AnalyticBarrierEngine {
+    process: BlackScholesMertonProcess,
+    reference_date: Date,
+    day_counter: Box<dyn DayCounter>,
+}
+impl AnalyticBarrierEngine {
+    // Constructor
+    pub fn new(
+        process: BlackScholesMertonProcess,
+        reference_date: Date,
+        day_counter: Box<dyn DayCounter>,
+    ) -> AnalyticBarrierEngine {
+        AnalyticBarrierEngine {
+            process,
+            reference_date,
+            day_counter,
+        }
+    }
+
+    pub fn calculate(&self, option: &BarrierOption) -> VanillaOptionResults {
+        let maturity: Time = self
+            .day_counter
+            .year_fraction(self.reference_date, option.exercise().last_date());
+        if maturity <= 0.0 {
+            panic!("option expired: maturity {maturity} before the reference date");
+        }
+        let spot: Real = self.process.x0();
+        if option.barrier_type().triggered(spot, option.barrier()) {
+            panic!(
+                "barrier touched: spot {}, barrier {}",
+                spot,
+                option.barrier()
+            );
+        }
+        spot_greeks(|s| detail::value(option, s, &self.process, maturity), spot)
+    }
+}
+
+pub(crate) mod detail {
+    use super::*;
+    use crate::math::distributions::normal_distribution::CumulativeNormalDistribution;
+
+    // The terms of the barrier formulas for one spot value
+    struct Terms {
+        spot: Real,
+        strike: Real,
+        barrier: Real,
+        rebate: Real,
+        std_dev: Real,
+        risk_free_discount: Real,
+        dividend_discount: Real,
+        mu: Real,
+        lambda: Real,
+        n: CumulativeNormalDistribution,
+    }
+    impl Terms {
+        fn mu_sigma(&self) -> Real {
+            (1.0 + self.mu) * self.std_dev
+        }
+        fn vanilla_like(&self, phi: Real, x: Real) -> Real {
+            phi * (self.spot * self.dividend_discount * self.n.value(phi * x)
+                - self.strike * self.risk_free_discount * self.n.value(phi * (x - self.std_dev)))
+        }
+        fn reflected(&self, eta: Real, phi: Real, y: Real) -> Real {
+            let hs: Real = self.barrier / self.spot;
+            phi * (self.spot
+                * self.dividend_discount
+                * hs.powf(2.0 * (self.mu + 1.0))
+                * self.n.value(eta * y)
+                - self.strike
+                    * self.risk_free_discount
+                    * hs.powf(2.0 * self.mu)
+                    * self.n.value(eta * (y - self.std_dev)))
+        }
+        fn a(&self, phi: Real) -> Real {
+            let x1: Real = (self.spot / self.strike).ln() / self.std_dev + self.mu_sigma();
+            self.vanilla_like(phi, x1)
+        }
+        fn b(&self, phi: Real) -> Real {
+            let x2: Real = (self.spot / self.barrier).ln() / self.std_dev + self.mu_sigma();
+            self.vanilla_like(phi, x2)
+        }
+        fn c(&self, eta: Real, phi: Real) -> Real {
+            let y1: Real = (self.barrier * self.barrier / (self.spot * self.strike)).ln()
+                / self.std_dev
+                + self.mu_sigma();
+            self.reflected(eta, phi, y1)
+        }
+        fn d(&self, eta: Real, phi: Real) -> Real {
+            let y2: Real = (self.barrier / self.spot).ln() / self.std_dev + self.mu_sigma();
+            self.reflected(eta, phi, y2)
+        }
+        fn e(&self, eta: Real) -> Real {
+            if self.rebate <= 0.0 {
+                return 0.0;
+            }
+            let x2: Real = (self.spot / self.barrier).ln() / self.std_dev + self.mu_sigma();
+            let y2: Real = (self.barrier / self.spot).ln() / self.std_dev + self.mu_sigma();
+            let hs: Real = self.barrier / self.spot;
+            self.rebate
+                * self.risk_free_discount
+                * (self.n.value(eta * (x2 - self.std_dev))
+                    - hs.powf(2.0 * self.mu) * self.n.value(eta * (y2 - self.std_dev)))
+        }
+        fn f(&self, eta: Real) -> Real {
+            if self.rebate <= 0.0 {
+                return 0.0;
+            }
+            let hs: Real = self.barrier / self.spot;
+            let z: Real = hs.ln() / self.std_dev + self.lambda * self.std_dev;
+            self.rebate
+                * (hs.powf(self.mu + self.lambda) * self.n.value(eta * z)
+                    + hs.powf(self.mu - self.lambda)
+                        * self.n.value(eta * (z - 2.0 * self.lambda * self.std_dev)))
+        }
+    }
+
+    // Price of the barrier option for the given spot
+    pub(crate) fn value(
+        option: &BarrierOption,
+        spot: Real,
+        process: &BlackScholesMertonProcess,
+        maturity: Time,
+    ) -> Real {
+        let (r, q, sigma): (Real, Real, Real) = (
+            process.risk_free_rate(),
+            process.dividend_yield(),
+            process.volatility(),
+        );
+        let variance: Real = sigma * sigma * maturity;
+        let mu: Real = (r - q) / (sigma * sigma) - 0.5;
+        let terms: Terms = Terms {
+            spot,
+            strike: option.payoff().strike(),
+            barrier: option.barrier(),
+            rebate: option.rebate(),
+            std_dev: variance.sqrt(),
+            risk_free_discount: (-r * maturity).exp(),
+            dividend_discount: (-q * maturity).exp(),
+            mu,
+            lambda: (mu * mu + 2.0 * r / (sigma * sigma)).sqrt(),
+            n: CumulativeNormalDistribution::new(0.0, 1.0),
+        };
+        let strike_above: bool = terms.strike >= terms.barrier;
+        let t: &Terms = &terms;
+        match (option.payoff().option_type(), option.barrier_type()) {
+            (OptionType::Call, BarrierType::DownIn) => {
+                if strike_above {
+                    t.c(1.0, 1.0) + t.e(1.0)
+                } else {
+                    t.a(1.0) - t.b(1.0) + t.d(1.0, 1.0) + t.e(1.0)
+                }
+            }
+            (OptionType::Call, BarrierType::UpIn) => {
+                if strike_above {
+                    t.a(1.0) + t.e(-1.0)
+                } else {
+                    t.b(1.0) - t.c(-1.0, 1.0) + t.d(-1.0, 1.0) + t.e(-1.0)
+                }
+            }
+            (OptionType::Call, BarrierType::DownOut) => {
+                if strike_above {
+                    t.a(1.0) - t.c(1.0, 1.0) + t.f(1.0)
+                } else {
+                    t.b(1.0) - t.d(1.0, 1.0) + t.f(1.0)
+                }
+            }
+            (OptionType::Call, BarrierType::UpOut) => {
+                if strike_above {
+                    t.f(-1.0)
+                } else {
+                    t.a(1.0) - t.b(1.0) + t.c(-1.0, 1.0) - t.d(-1.0, 1.0) + t.f(-1.0)
+                }
+            }
+            (OptionType::Put, BarrierType::DownIn) => {
+                if strike_above {
+                    t.b(-1.0) - t.c(1.0, -1.0) + t.d(1.0, -1.0) + t.e(1.0)
+                } else {
+                    t.a(-1.0) + t.e(1.0)
+                }
+            }
+            (OptionType::Put, BarrierType::UpIn) => {
+                if strike_above {
+                    t.a(-1.0) - t.b(-1.0) + t.d(-1.0, -1.0) + t.e(-1.0)
+                } else {
+                    t.c(-1.0, -1.0) + t.e(-1.0)
+                }
+            }
+            (OptionType::Put, BarrierType::DownOut) => {
+                if strike_above {
+                    t.a(-1.0) - t.b(-1.0) + t.c(1.0, -1.0) - t.d(1.0, -1.0) + t.f(1.0)
+                } else {
+                    t.f(1.0)
+                }
+            }
+            (OptionType::Put, BarrierType::UpOut) => {
+                if strike_above {
+                    t.b(-1.0) - t.d(-1.0, -1.0) + t.f(-1.0)
+                } else {
+                    t.a(-1.0) - t.c(-1.0, -1.0) + t.f(-1.0)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::EuropeanExercise;
+    use crate::instruments::payoffs::PlainVanillaPayoff;
+    use crate::pricingengines::black_formula::black_formula;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual360::Actual360;
+
+    fn engine(spot: Real, volatility: Real) -> AnalyticBarrierEngine {
+        AnalyticBarrierEngine::new(
+            BlackScholesMertonProcess::new(spot, 0.08, 0.04, volatility),
+            Date::new(15, Month::January, 2025),
+            Box::new(Actual360::new()),
+        )
+    }
+
+    fn option(
+        barrier_type: BarrierType,
+        barrier: Real,
+        option_type: OptionType,
+        strike: Real,
+    ) -> BarrierOption {
+        // Six months with Actual/360
+        let maturity: Date = Date::new(15, Month::January, 2025) + 180;
+        BarrierOption::new(
+            barrier_type,
+            barrier,
+            3.0,
+            PlainVanillaPayoff::new(option_type, strike),
+            EuropeanExercise::new(maturity),
+        )
+    }
+
+    #[test]
+    fn haug_values() {
+        // Haug, table 4-13: S = 100, r = 8%, q = 4%, T = 0.5, sigma = 25%, rebate 3
+        let cases: [(BarrierType, Real, OptionType, Real, Real); 25] = [
+            (BarrierType::DownOut, 95.0, OptionType::Call, 90.0, 9.0246),
+            (BarrierType::DownOut, 95.0, OptionType::Call, 100.0, 6.7924),
+            (BarrierType::DownOut, 95.0, OptionType::Call, 110.0, 4.8759),
+            (BarrierType::DownOut, 100.0, OptionType::Call, 90.0, 3.0000),
+            (BarrierType::UpOut, 105.0, OptionType::Call, 90.0, 2.6789),
+            (BarrierType::UpOut, 105.0, OptionType::Call, 100.0, 2.3580),
+            (BarrierType::UpOut, 105.0, OptionType::Call, 110.0, 2.3453),
+            (BarrierType::DownIn, 95.0, OptionType::Call, 90.0, 7.7627),
+            (BarrierType::DownIn, 95.0, OptionType::Call, 100.0, 4.0109),
+            (BarrierType::DownIn, 95.0, OptionType::Call, 110.0, 2.0576),
+            (BarrierType::UpIn, 105.0, OptionType::Call, 90.0, 14.1112),
+            (BarrierType::UpIn, 105.0, OptionType::Call, 100.0, 8.4482),
+            (BarrierType::UpIn, 105.0, OptionType::Call, 110.0, 4.5910),
+            (BarrierType::DownOut, 95.0, OptionType::Put, 90.0, 2.2798),
+            (BarrierType::DownOut, 95.0, OptionType::Put, 100.0, 2.2947),
+            (BarrierType::DownOut, 95.0, OptionType::Put, 110.0, 2.6252),
+            (BarrierType::UpOut, 105.0, OptionType::Put, 90.0, 3.7760),
+            (BarrierType::UpOut, 105.0, OptionType::Put, 100.0, 5.4932),
+            (BarrierType::UpOut, 105.0, OptionType::Put, 110.0, 7.5187),
+            (BarrierType::DownIn, 95.0, OptionType::Put, 90.0, 2.9586),
+            (BarrierType::DownIn, 95.0, OptionType::Put, 100.0, 6.5677),
+            (BarrierType::DownIn, 95.0, OptionType::Put, 110.0, 11.9752),
+            (BarrierType::UpIn, 105.0, OptionType::Put, 90.0, 1.4653),
+            (BarrierType::UpIn, 105.0, OptionType::Put, 100.0, 3.3721),
+            (BarrierType::UpIn, 105.0, OptionType::Put, 110.0, 7.0846),
+        ];
+        let engine: AnalyticBarrierEngine = engine(100.0, 0.25);
+        for (barrier_type, barrier, option_type, strike, expected) in cases {
+            let value: Real = engine
+                .calculate(&option(barrier_type, barrier, option_type, strike))
+                .value;
+            assert!(
+                (value - expected).abs() < 1.0e-4,
+                "{barrier_type} {option_type} {strike} with barrier {barrier}: {value} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn knock_in_plus_knock_out_is_vanilla() {
+        let engine: AnalyticBarrierEngine = engine(100.0, 0.3);
+        let vanilla: Real = black_formula(
+            OptionType::Call,
+            100.0,
+            100.0 * 0.02_f64.exp(),
+            0.3 * 0.5_f64.sqrt(),
+            (-0.04_f64).exp(),
+            0.0,
+        );
+        for (knock_in, knock_out, barrier) in [
+            (BarrierType::DownIn, BarrierType::DownOut, 90.0),
+            (BarrierType::UpIn, BarrierType::UpOut, 120.0),
+        ] {
+            let unrebated = |barrier_type: BarrierType| -> BarrierOption {
+                BarrierOption::new(
+                    barrier_type,
+                    barrier,
+                    0.0,
+                    PlainVanillaPayoff::new(OptionType::Call, 100.0),
+                    EuropeanExercise::new(Date::new(15, Month::January, 2025) + 180),
+                )
+            };
+            let knock_in: VanillaOptionResults = engine.calculate(&unrebated(knock_in));
+            let knock_out: VanillaOptionResults = engine.calculate(&unrebated(knock_out));
+            assert!((knock_in.value + knock_out.value - vanilla).abs() < 1.0e-10);
+        }
+    }
+
+    #[test]
+    fn calculate_panics_when_the_barrier_is_touched() {
+        let result = std::panic::catch_unwind(|| {
+            engine(94.0, 0.25).calculate(&option(
+                BarrierType::DownOut,
+                95.0,
+                OptionType::Call,
+                100.0,
+            ))
+        });
+        assert!(result.is_err(), "expected panic for a touched barrier");
+    }
+}
@@ -0,0 +1,326 @@
+use crate::exercise::Exercise;
+use crate::instruments::double_barrier_option::{DoubleBarrierOption, DoubleBarrierType};
+use crate::instruments::payoffs::StrikedTypePayoff;
+use crate::option::OptionType;
+use crate::pricingengines::black_formula::black_formula;
+use crate::pricingengines::greeks::spot_greeks;
+use crate::pricingengines::vanilla::vanilla_option_results::VanillaOptionResults;
+use crate::processes::black_scholes_process::BlackScholesMertonProcess;
+use crate::stochastic_process::StochasticProcess1D;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Integer, Real, Time};
+
+/*
+Analytic engine for double-barrier options (QuantLib
+AnalyticDoubleBarrierEngine), with the Ikeda-Kunitomo series for flat
+barriers as given by Haug:
+    c = S exp(-q T) sum_n [(U^n / L^n)^mu (N(d1) - N(d2))
+                           - (L^(n+1) / (U^n S))^mu (N(d3) - N(d4))]
+        - K exp(-r T) sum_n [the same with exponents mu - 2
+                             and d_i - sigma sqrt(T)]
+with mu = 2 (r - q) / sigma^2 + 1 and n from -series to series; the put
+has the same structure. Knock-in options are priced by in-out parity.
+Only KnockIn and KnockOut options without rebate are handled. Delta
+and gamma come from central differences of the formula.
+*/
+pub struct AnalyticDoubleBarrierEngine {
+    process: BlackScholesMertonProcess,
+    reference_date: Date,
+    day_counter: Box<dyn DayCounter>,
+    series: Integer,
+}
+impl AnalyticDoubleBarrierEngine {
+    // QuantLib's default, enough for all but the widest corridors
+    const DEFAULT_SERIES: Integer = 5;
+
+    // Constructor
+    pub fn new(
+        process: BlackScholesMertonProcess,
+        reference_date: Date,
+        day_counter: Box<dyn DayCounter>,
+    ) -> AnalyticDoubleBarrierEngine {
+        AnalyticDoubleBarrierEngine {
+            process,
+            reference_date,
+            day_counter,
+            series: AnalyticDoubleBarrierEngine::DEFAULT_SERIES,
+        }
+    }
+
+    // Modifiers
+    pub fn with_series(mut self, series: Integer) -> AnalyticDoubleBarrierEngine {
+        if series < 1 {
+            panic!("at least one series term required, {series} given");
+        }
+        self.series = series;
+        self
+    }
+
+    pub fn calculate(&self, option: &DoubleBarrierOption) -> VanillaOptionResults {
+        if !matches!(
+            option.barrier_type(),
+            DoubleBarrierType::KnockIn | DoubleBarrierType::KnockOut
+        ) {
+            panic!("{} double barriers not supported", option.barrier_type());
+        }
+        if option.rebate() != 0.0 {
+            panic!("rebates not supported ({} given)", option.rebate());
+        }
+        let maturity: Time = self
+            .day_counter
+            .year_fraction(self.reference_date, option.exercise().last_date());
+        if maturity <= 0.0 {
+            panic!("option expired: maturity {maturity} before the reference date");
+        }
+        let spot: Real = self.process.x0();
+        if option.triggered(spot) {
+            panic!(
+                "barrier touched: spot {} outside [{}, {}]",
+                spot,
+                option.barrier_low(),
+                option.barrier_high()
+            );
+        }
+        spot_greeks(
+            |s| detail::value(option, s, &self.process, maturity, self.series),
+            spot,
+        )
+    }
+}
+
+pub(crate) mod detail {
+    use super::*;
+    use crate::math::distributions::normal_distribution::CumulativeNormalDistribution;
+
+    pub(crate) fn value(
+        option: &DoubleBarrierOption,
+        spot: Real,
+        process: &BlackScholesMertonProcess,
+        maturity: Time,
+        series: Integer,
+    ) -> Real {
+        let knock_out: Real = knock_out_value(option, spot, process, maturity, series);
+        match option.barrier_type() {
+            DoubleBarrierType::KnockOut => knock_out,
+            _ => {
+                let (r, q, sigma): (Real, Real, Real) = (
+                    process.risk_free_rate(),
+                    process.dividend_yield(),
+                    process.volatility(),
+                );
+                let vanilla: Real = black_formula(
+                    option.payoff().option_type(),
+                    option.payoff().strike(),
+                    spot * ((r - q) * maturity).exp(),
+                    sigma * maturity.sqrt(),
+                    (-r * maturity).exp(),
+                    0.0,
+                );
+                (vanilla - knock_out).max(0.0)
+            }
+        }
+    }
+
+    fn knock_out_value(
+        option: &DoubleBarrierOption,
+        spot: Real,
+        process: &BlackScholesMertonProcess,
+        maturity: Time,
+        series: Integer,
+    ) -> Real {
+        let (r, q, sigma): (Real, Real, Real) = (
+            process.risk_free_rate(),
+            process.dividend_yield(),
+            process.volatility(),
+        );
+        let (low, high, strike): (Real, Real, Real) = (
+            option.barrier_low(),
+            option.barrier_high(),
+            option.payoff().strike(),
+        );
+        let std_dev: Real = sigma * maturity.sqrt();
+        let mu: Real = 2.0 * (r - q) / (sigma * sigma) + 1.0;
+        let drift: Real = (r - q + 0.5 * sigma * sigma) * maturity / std_dev;
+        let n = |x: Real| -> Real { CumulativeNormalDistribution::new(0.0, 1.0).value(x) };
+        let d = |numerator: Real, denominator: Real| -> Real {
+            (numerator / denominator).ln() / std_dev + drift
+        };
+
+        // For a call the corridor between strike and upper barrier pays off,
+        // for a put the one between lower barrier and strike
+        let (asset, cash): (Real, Real) = (-series..=series).fold((0.0, 0.0), |acc, k| {
+            let (u_n, l_n): (Real, Real) = (high.powi(k), low.powi(k));
+            let (d1, d2, d3, d4): (Real, Real, Real, Real) = match option.payoff().option_type() {
+                OptionType::Call => (
+                    d(spot * u_n * u_n, strike * l_n * l_n),
+                    d(spot * u_n * u_n, high * l_n * l_n),
+                    d(low.powi(2 * k + 2), strike * spot * u_n * u_n),
+                    d(low.powi(2 * k + 2), high * spot * u_n * u_n),
+                ),
+                OptionType::Put => (
+                    d(spot * u_n * u_n, low * l_n * l_n),
+                    d(spot * u_n * u_n, strike * l_n * l_n),
+                    d(low.powi(2 * k + 2), low * spot * u_n * u_n),
+                    d(low.powi(2 * k + 2), strike * spot * u_n * u_n),
+                ),
+            };
+            let direct: Real = u_n / l_n;
+            let reflected: Real = low * l_n / (u_n * spot);
+            (
+                acc.0 + direct.powf(mu) * (n(d1) - n(d2)) - reflected.powf(mu) * (n(d3) - n(d4)),
+                acc.1 + direct.powf(mu - 2.0) * (n(d1 - std_dev) - n(d2 - std_dev))
+                    - reflected.powf(mu - 2.0) * (n(d3 - std_dev) - n(d4 - std_dev)),
+            )
+        });
+        let value: Real =
+            spot * (-q * maturity).exp() * asset - strike * (-r * maturity).exp() * cash;
+        // The put sums come out with the opposite sign
+        (option.payoff().option_type().sign() * value).max(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::EuropeanExercise;
+    use crate::instruments::barrier_option::{BarrierOption, BarrierType};
+    use crate::instruments::payoffs::PlainVanillaPayoff;
+    use crate::pricingengines::barrier::analytic_barrier_engine::AnalyticBarrierEngine;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual360::Actual360;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn engine(r: Real, q: Real, volatility: Real) -> AnalyticDoubleBarrierEngine {
+        AnalyticDoubleBarrierEngine::new(
+            BlackScholesMertonProcess::new(100.0, r, q, volatility),
+            reference_date(),
+            Box::new(Actual360::new()),
+        )
+    }
+
+    fn option(
+        barrier_type: DoubleBarrierType,
+        low: Real,
+        high: Real,
+        option_type: OptionType,
+    ) -> DoubleBarrierOption {
+        DoubleBarrierOption::new(
+            barrier_type,
+            low,
+            high,
+            0.0,
+            PlainVanillaPayoff::new(option_type, 100.0),
+            // 0.25 years with Actual/360
+            EuropeanExercise::new(reference_date() + 90),
+        )
+    }
+
+    #[test]
+    fn haug_call_values() {
+        // Haug, table 4-14: S = K = 100, T = 0.25, r = b = 10%, flat barriers
+        let cases: [(Real, Real, [Real; 3]); 5] = [
+            (50.0, 150.0, [4.3515, 6.1644, 7.0373]),
+            (60.0, 140.0, [4.3505, 5.8500, 5.7726]),
+            (70.0, 130.0, [4.3139, 4.8293, 3.7765]),
+            (80.0, 120.0, [3.7516, 2.6387, 1.4903]),
+            (90.0, 110.0, [1.2055, 0.3098, 0.0477]),
+        ];
+        for (low, high, expected) in cases {
+            for (volatility, expected) in [0.15, 0.25, 0.35].into_iter().zip(expected) {
+                let value: Real = engine(0.1, 0.0, volatility)
+                    .calculate(&option(
+                        DoubleBarrierType::KnockOut,
+                        low,
+                        high,
+                        OptionType::Call,
+                    ))
+                    .value;
+                assert!(
+                    (value - expected).abs() < 1.0e-4,
+                    "[{low}, {high}] at {volatility}: {value} vs {expected}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn far_upper_barrier_gives_a_down_and_out_put() {
+        let single: Real = AnalyticBarrierEngine::new(
+            BlackScholesMertonProcess::new(100.0, 0.05, 0.02, 0.25),
+            reference_date(),
+            Box::new(Actual360::new()),
+        )
+        .calculate(&BarrierOption::new(
+            BarrierType::DownOut,
+            80.0,
+            0.0,
+            PlainVanillaPayoff::new(OptionType::Put, 100.0),
+            EuropeanExercise::new(reference_date() + 90),
+        ))
+        .value;
+        let double: Real = engine(0.05, 0.02, 0.25)
+            .calculate(&option(
+                DoubleBarrierType::KnockOut,
+                80.0,
+                1000.0,
+                OptionType::Put,
+            ))
+            .value;
+        assert!((double - single).abs() < 1.0e-8, "{double} vs {single}");
+    }
+
+    #[test]
+    fn knock_in_plus_knock_out_is_vanilla() {
+        let engine: AnalyticDoubleBarrierEngine = engine(0.05, 0.02, 0.25);
+        for option_type in [OptionType::Call, OptionType::Put] {
+            let knock_in: Real = engine
+                .calculate(&option(
+                    DoubleBarrierType::KnockIn,
+                    85.0,
+                    115.0,
+                    option_type,
+                ))
+                .value;
+            let knock_out: Real = engine
+                .calculate(&option(
+                    DoubleBarrierType::KnockOut,
+                    85.0,
+                    115.0,
+                    option_type,
+                ))
+                .value;
+            let vanilla: Real = black_formula(
+                option_type,
+                100.0,
+                100.0 * (0.03_f64 * 0.25).exp(),
+                0.25 * 0.5,
+                (-0.05_f64 * 0.25).exp(),
+                0.0,
+            );
+            assert!(knock_out > 0.0 && knock_in > knock_out, "{}", option_type);
+            assert!((knock_in + knock_out - vanilla).abs() < 1.0e-10);
+        }
+    }
+
+    #[test]
+    fn calculate_panics_on_unsupported_options() {
+        let cases: [(DoubleBarrierOption, &str); 2] = [
+            (
+                option(DoubleBarrierType::KIKO, 85.0, 115.0, OptionType::Call),
+                "KIKO barrier",
+            ),
+            (
+                option(DoubleBarrierType::KnockOut, 101.0, 115.0, OptionType::Call),
+                "touched barrier",
+            ),
+        ];
+        for (option, label) in cases {
+            let result = std::panic::catch_unwind(|| engine(0.05, 0.02, 0.25).calculate(&option));
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use crate::types::Real;
+
+// What the analytic basket engines compute: the value and the delta with respect to each spot
+#[derive(Clone, PartialEq, Debug)]
+pub struct BasketOptionResults {
+    pub value: Real,
+    pub deltas: Vec<Real>,
+}
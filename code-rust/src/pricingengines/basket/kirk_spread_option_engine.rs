@@ -0,0 +1,185 @@
+use crate::exercise::Exercise;
+use crate::instruments::payoffs::StrikedTypePayoff;
+use crate::instruments::spread_option::SpreadOption;
+use crate::pricingengines::basket::basket_option_results::BasketOptionResults;
+use crate::pricingengines::black_formula::black_formula;
+use crate::pricingengines::greeks::spot_deltas;
+use crate::processes::black_scholes_process::BlackScholesMertonProcess;
+use crate::stochastic_process::StochasticProcess1D;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{DiscountFactor, Real, Time};
+
+/*
+Kirk approximation for European spread options (QuantLib
+KirkSpreadOptionEngine). F_2 + K is treated as a lognormal asset, so
+that the option is a Black option on F = F_1 / (F_2 + K) struck at 1:
+    value = P (F_2 + K) Black(F, 1, sigma sqrt(T))
+    sigma^2 = sigma_1^2 + (sigma_2 w)^2 - 2 rho sigma_1 sigma_2 w,  w = F_2 / (F_2 + K)
+It is exact for K = 0 (Margrabe's exchange option) and accurate for
+strikes small compared to the forwards. Both processes must share the
+risk-free rate; deltas are obtained by central differences.
+*/
+pub struct KirkSpreadOptionEngine {
+    process1: BlackScholesMertonProcess,
+    process2: BlackScholesMertonProcess,
+    correlation: Real,
+    reference_date: Date,
+    day_counter: Box<dyn DayCounter>,
+}
+impl KirkSpreadOptionEngine {
+    // Constructor
+    pub fn new(
+        process1: BlackScholesMertonProcess,
+        process2: BlackScholesMertonProcess,
+        correlation: Real,
+        reference_date: Date,
+        day_counter: Box<dyn DayCounter>,
+    ) -> KirkSpreadOptionEngine {
+        if process1.risk_free_rate() != process2.risk_free_rate() {
+            panic!(
+                "processes with different risk-free rates ({} and {}) given",
+                process1.risk_free_rate(),
+                process2.risk_free_rate()
+            );
+        }
+        if !(-1.0..=1.0).contains(&correlation) {
+            panic!("correlation ({correlation}) must be in [-1, 1]");
+        }
+        KirkSpreadOptionEngine {
+            process1,
+            process2,
+            correlation,
+            reference_date,
+            day_counter,
+        }
+    }
+
+    // Inspectors
+    pub fn correlation(&self) -> Real {
+        self.correlation
+    }
+
+    pub fn calculate(&self, option: &SpreadOption) -> BasketOptionResults {
+        let maturity: Time = self
+            .day_counter
+            .year_fraction(self.reference_date, option.exercise().last_date());
+        if maturity <= 0.0 {
+            panic!("option expired: maturity {maturity} before the reference date");
+        }
+        let price = |spots: &[Real]| -> Real { self.value(spots[0], spots[1], option, maturity) };
+        let spots: [Real; 2] = [self.process1.x0(), self.process2.x0()];
+        BasketOptionResults {
+            value: price(&spots),
+            deltas: spot_deltas(price, &spots),
+        }
+    }
+
+    fn value(&self, spot1: Real, spot2: Real, option: &SpreadOption, maturity: Time) -> Real {
+        let strike: Real = option.payoff().strike();
+        let (sigma1, sigma2, rho): (Real, Real, Real) = (
+            self.process1.volatility(),
+            self.process2.volatility(),
+            self.correlation,
+        );
+        let discount: DiscountFactor = (-self.process1.risk_free_rate() * maturity).exp();
+        let forward1: Real = spot1
+            * ((self.process1.risk_free_rate() - self.process1.dividend_yield()) * maturity).exp();
+        let forward2: Real = spot2
+            * ((self.process2.risk_free_rate() - self.process2.dividend_yield()) * maturity).exp();
+        if forward2 + strike <= 0.0 {
+            panic!(
+                "Kirk approximation requires a positive F_2 + K ({} given)",
+                forward2 + strike
+            );
+        }
+
+        let w: Real = forward2 / (forward2 + strike);
+        let sigma: Real =
+            (sigma1 * sigma1 + sigma2 * sigma2 * w * w - 2.0 * rho * sigma1 * sigma2 * w).sqrt();
+        (forward2 + strike)
+            * black_formula(
+                option.payoff().option_type(),
+                1.0,
+                forward1 / (forward2 + strike),
+                sigma * maturity.sqrt(),
+                discount,
+                0.0,
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::EuropeanExercise;
+    use crate::instruments::payoffs::PlainVanillaPayoff;
+    use crate::option::OptionType;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual360::Actual360;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    // Options on futures: no drift, the dividend yield equals the rate
+    fn engine(
+        f1: Real,
+        f2: Real,
+        r: Real,
+        v1: Real,
+        v2: Real,
+        rho: Real,
+    ) -> KirkSpreadOptionEngine {
+        KirkSpreadOptionEngine::new(
+            BlackScholesMertonProcess::new(f1, r, r, v1),
+            BlackScholesMertonProcess::new(f2, r, r, v2),
+            rho,
+            reference_date(),
+            Box::new(Actual360::new()),
+        )
+    }
+
+    #[test]
+    #[allow(clippy::type_complexity)]
+    fn haug_values() {
+        // Haug, "The Complete Guide to Option Pricing Formulas", spread options on futures
+        let cases: [(Real, Real, Real, i32, Real, Real, Real, Real, Real); 2] = [
+            (28.0, 20.0, 7.0, 90, 0.05, 0.29, 0.36, 0.42, 2.1670),
+            (122.0, 120.0, 3.0, 36, 0.10, 0.2, 0.2, -0.5, 4.7530),
+        ];
+
+        for (f1, f2, strike, days, r, v1, v2, rho, expected) in cases {
+            let option: SpreadOption = SpreadOption::new(
+                PlainVanillaPayoff::new(OptionType::Call, strike),
+                EuropeanExercise::new(reference_date() + days),
+            );
+            let results: BasketOptionResults = engine(f1, f2, r, v1, v2, rho).calculate(&option);
+            assert!(
+                (results.value - expected).abs() < 1.0e-4,
+                "{} vs {}",
+                results.value,
+                expected
+            );
+            assert!(results.deltas[0] > 0.0 && results.deltas[1] < 0.0);
+        }
+    }
+
+    #[test]
+    fn put_call_parity() {
+        let engine: KirkSpreadOptionEngine = engine(122.0, 120.0, 0.1, 0.2, 0.2, -0.5);
+        let exercise: EuropeanExercise = EuropeanExercise::new(reference_date() + 36);
+        let value = |option_type: OptionType| -> Real {
+            engine
+                .calculate(&SpreadOption::new(
+                    PlainVanillaPayoff::new(option_type, 3.0),
+                    exercise.clone(),
+                ))
+                .value
+        };
+        let forward_spread: Real = (122.0 - 120.0 - 3.0) * (-0.01_f64).exp();
+        assert!(
+            (value(OptionType::Call) - value(OptionType::Put) - forward_spread).abs() < 1.0e-10
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use crate::exercise::Exercise;
+use crate::instruments::basket_option::{BasketOption, BasketPayoff};
+use crate::math::randomnumbers::rng_traits::{PseudoRandom, PseudoRandomRsg};
+use crate::math::statistics::incremental_statistics::IncrementalStatistics;
+use crate::methods::montecarlo::monte_carlo_model::MonteCarloModel;
+use crate::methods::montecarlo::multi_path::MultiPath;
+use crate::methods::montecarlo::multi_path_generator::MultiPathGenerator;
+use crate::pricingengines::mc_results::McResults;
+use crate::pricingengines::mc_simulation::McSimulation;
+use crate::processes::black_scholes_process::BlackScholesMertonProcess;
+use crate::processes::stochastic_process_array::StochasticProcessArray;
+use crate::stochastic_process::StochasticProcess;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::time_grid::TimeGrid;
+use crate::types::{DiscountFactor, Rate, Real, Size, Time};
+
+type Generator =
+    MultiPathGenerator<StochasticProcessArray<BlackScholesMertonProcess>, PseudoRandomRsg>;
+type Pricer = Box<dyn Fn(&MultiPath) -> Real>;
+
+/*
+Monte Carlo engine for European basket options (QuantLib
+MCEuropeanBasketEngine), spread options included once converted to
+baskets. The correlated Black-Scholes processes are evolved exactly to
+maturity in a single step, and each sample is the discounted basket
+payoff of the terminal prices. All the processes must share the
+risk-free rate.
+*/
+pub struct McEuropeanBasketEngine {
+    processes: StochasticProcessArray<BlackScholesMertonProcess>,
+    reference_date: Date,
+    day_counter: Box<dyn DayCounter>,
+    samples: Size,
+    seed: u64,
+    antithetic_variate: bool,
+}
+impl McEuropeanBasketEngine {
+    // Constructor
+    pub fn new(
+        processes: StochasticProcessArray<BlackScholesMertonProcess>,
+        reference_date: Date,
+        day_counter: Box<dyn DayCounter>,
+        samples: Size,
+        seed: u64,
+    ) -> McEuropeanBasketEngine {
+        if samples == 0 {
+            panic!("at least one sample is required");
+        }
+        let rate: Rate = processes.process(0).risk_free_rate();
+        if (1..processes.size()).any(|i| processes.process(i).risk_free_rate() != rate) {
+            panic!("processes with different risk-free rates given");
+        }
+        McEuropeanBasketEngine {
+            processes,
+            reference_date,
+            day_counter,
+            samples,
+            seed,
+            antithetic_variate: false,
+        }
+    }
+
+    // Modifiers
+    pub fn with_antithetic_variate(mut self, antithetic_variate: bool) -> McEuropeanBasketEngine {
+        self.antithetic_variate = antithetic_variate;
+        self
+    }
+
+    pub fn calculate(&self, option: &BasketOption) -> McResults {
+        let maturity: Time = self
+            .day_counter
+            .year_fraction(self.reference_date, option.exercise().last_date());
+        if maturity <= 0.0 {
+            panic!("option expired: maturity {maturity} before the reference date");
+        }
+        let grid: TimeGrid = TimeGrid::new(maturity, 1);
+        let generator: Generator = MultiPathGenerator::new(
+            self.processes.clone(),
+            grid,
+            PseudoRandom::make_sequence_generator(self.processes.factors(), self.seed),
+        );
+        let discount: DiscountFactor =
+            (-self.processes.process(0).risk_free_rate() * maturity).exp();
+        let payoff: BasketPayoff = option.payoff().clone();
+        // Checks the payoff against the number of assets before simulating
+        payoff.accumulate(&self.processes.initial_values());
+        let pricer: Pricer = Box::new(move |path: &MultiPath| {
+            let prices: Vec<Real> = (0..path.asset_number()).map(|j| path[j].back()).collect();
+            discount * payoff.value(&prices)
+        });
+
+        let mut simulation: McSimulation<Generator, Pricer> =
+            McSimulation::new(MonteCarloModel::new(
+                generator,
+                pricer,
+                IncrementalStatistics::new(),
+                self.antithetic_variate,
+                None,
+                0.0,
+                None,
+            ));
+        let value: Real = simulation.value_with_samples(self.samples);
+        McResults {
+            value,
+            error_estimate: simulation.error_estimate(),
+            samples: simulation.sample_accumulator().samples(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::EuropeanExercise;
+    use crate::instruments::payoffs::PlainVanillaPayoff;
+    use crate::instruments::spread_option::SpreadOption;
+    use crate::math::matrix::Matrix;
+    use crate::option::OptionType;
+    use crate::pricingengines::basket::kirk_spread_option_engine::KirkSpreadOptionEngine;
+    use crate::pricingengines::basket::stulz_engine::StulzEngine;
+    use crate::pricingengines::black_formula::black_formula;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual360::Actual360;
+    use std::panic;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn processes() -> [BlackScholesMertonProcess; 2] {
+        [
+            BlackScholesMertonProcess::new(100.0, 0.05, 0.02, 0.25),
+            BlackScholesMertonProcess::new(95.0, 0.05, 0.01, 0.35),
+        ]
+    }
+
+    fn engine(rho: Real) -> McEuropeanBasketEngine {
+        McEuropeanBasketEngine::new(
+            StochasticProcessArray::new(
+                processes().to_vec(),
+                Matrix::from_rows(&[vec![1.0, rho], vec![rho, 1.0]]),
+            ),
+            reference_date(),
+            Box::new(Actual360::new()),
+            20_000,
+            42,
+        )
+        .with_antithetic_variate(true)
+    }
+
+    fn assert_close(results: McResults, expected: Real) {
+        assert!(
+            (results.value - expected).abs() < 3.0 * results.error_estimate,
+            "{} vs {} ({})",
+            results.value,
+            expected,
+            results.error_estimate
+        );
+    }
+
+    #[test]
+    fn min_and_max_baskets_match_stulz() {
+        let stulz: StulzEngine = StulzEngine::new(
+            processes()[0],
+            processes()[1],
+            0.3,
+            reference_date(),
+            Box::new(Actual360::new()),
+        );
+        let exercise: EuropeanExercise = EuropeanExercise::new(reference_date() + 360);
+        let payoffs: [BasketPayoff; 2] = [
+            BasketPayoff::Min(PlainVanillaPayoff::new(OptionType::Call, 95.0)),
+            BasketPayoff::Max(PlainVanillaPayoff::new(OptionType::Put, 105.0)),
+        ];
+        for payoff in payoffs {
+            let option: BasketOption = BasketOption::new(payoff, exercise.clone());
+            assert_close(
+                engine(0.3).calculate(&option),
+                stulz.calculate(&option).value,
+            );
+        }
+    }
+
+    #[test]
+    fn spread_option_matches_kirk() {
+        let kirk: KirkSpreadOptionEngine = KirkSpreadOptionEngine::new(
+            processes()[0],
+            processes()[1],
+            0.6,
+            reference_date(),
+            Box::new(Actual360::new()),
+        );
+        let option: SpreadOption = SpreadOption::new(
+            PlainVanillaPayoff::new(OptionType::Call, 2.0),
+            EuropeanExercise::new(reference_date() + 180),
+        );
+        assert_close(
+            engine(0.6).calculate(&option.clone().into()),
+            kirk.calculate(&option).value,
+        );
+    }
+
+    #[test]
+    fn single_asset_basket_is_vanilla() {
+        let process: BlackScholesMertonProcess = processes()[0];
+        let option: BasketOption = BasketOption::new(
+            BasketPayoff::equally_weighted_average(
+                PlainVanillaPayoff::new(OptionType::Call, 100.0),
+                1,
+            ),
+            EuropeanExercise::new(reference_date() + 360),
+        );
+        let results: McResults = McEuropeanBasketEngine::new(
+            StochasticProcessArray::new(vec![process], Matrix::identity(1)),
+            reference_date(),
+            Box::new(Actual360::new()),
+            20_000,
+            7,
+        )
+        .calculate(&option);
+        let expected: Real = black_formula(
+            OptionType::Call,
+            100.0,
+            100.0 * (0.03_f64).exp(),
+            0.25,
+            (-0.05_f64).exp(),
+            0.0,
+        );
+        assert_eq!(results.samples, 20_000);
+        assert_close(results, expected);
+    }
+
+    #[test]
+    fn calculate_panics_on_mismatched_payoffs() {
+        let option: BasketOption = BasketOption::new(
+            BasketPayoff::Average(
+                PlainVanillaPayoff::new(OptionType::Call, 100.0),
+                vec![1.0; 3],
+            ),
+            EuropeanExercise::new(reference_date() + 360),
+        );
+        let result = panic::catch_unwind(|| engine(0.0).calculate(&option));
+        assert!(result.is_err(), "expected panic for 3 weights on 2 assets");
+    }
+}
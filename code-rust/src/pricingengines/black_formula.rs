@@ -0,0 +1,1142 @@
+use crate::math::distributions::normal_distribution::{
+    CumulativeNormalDistribution, NormalDistribution,
+};
+use crate::math::solvers1d::newton_safe::NewtonSafe;
+use crate::option::OptionType;
+use crate::types::{DiscountFactor, Real, Size, Time, Volatility};
+use std::f64::consts::PI;
+
+/*
+Black 1976 formula and friends (QuantLib blackformula.hpp).
+
+All the functions work on the forward price and on the standard deviation
+sigma * sqrt(T), so they can be reused for any underlying (stocks, caplets,
+swaptions). The displacement d shifts both forward and strike, giving the
+shifted-lognormal model: F + d follows a lognormal process.
+
+    d1 = ln((F + d) / (K + d)) / stdDev + stdDev / 2
+    d2 = d1 - stdDev
+    call = discount * [(F + d) N(d1) - (K + d) N(d2)]
+    put  = discount * [(K + d) N(-d2) - (F + d) N(-d1)]
+*/
+pub fn black_formula(
+    option_type: OptionType,
+    strike: Real,
+    forward: Real,
+    std_dev: Real,
+    discount: DiscountFactor,
+    displacement: Real,
+) -> Real {
+    detail::check_parameters(strike, forward, displacement);
+    if std_dev < 0.0 {
+        panic!("stdDev ({std_dev}) must be non-negative");
+    }
+    if discount <= 0.0 {
+        panic!("discount ({discount}) must be positive");
+    }
+
+    let sign: Real = option_type.sign();
+
+    // No optionality left: intrinsic value
+    if std_dev == 0.0 {
+        return (sign * (forward - strike)).max(0.0) * discount;
+    }
+
+    let forward: Real = forward + displacement;
+    let strike: Real = strike + displacement;
+
+    // Since displacement is non-negative, strike == 0 only if displacement == 0
+    if strike == 0.0 {
+        return match option_type {
+            OptionType::Call => forward * discount,
+            OptionType::Put => 0.0,
+        };
+    }
+
+    let d1: Real = (forward / strike).ln() / std_dev + 0.5 * std_dev;
+    let d2: Real = d1 - std_dev;
+    let phi: CumulativeNormalDistribution = CumulativeNormalDistribution::default();
+    let n_d1: Real = phi.value(sign * d1);
+    let n_d2: Real = phi.value(sign * d2);
+
+    // Rounding can give tiny negative values deep out of the money
+    (discount * sign * (forward * n_d1 - strike * n_d2)).max(0.0)
+}
+
+/*
+Approximated Black implied standard deviation.
+
+    - at the money: Brenner-Subrahmanyam (1988) / Feinstein (1988)
+        stdDev = price / discount * sqrt(2 pi) / F
+    - otherwise: Corrado-Miller (1996) extended moneyness approximation
+
+It is used as starting guess by black_formula_implied_std_dev.
+*/
+pub fn black_formula_implied_std_dev_approximation(
+    option_type: OptionType,
+    strike: Real,
+    forward: Real,
+    black_price: Real,
+    discount: DiscountFactor,
+    displacement: Real,
+) -> Real {
+    detail::check_parameters(strike, forward, displacement);
+    if black_price < 0.0 {
+        panic!("blackPrice ({black_price}) must be non-negative");
+    }
+    if discount <= 0.0 {
+        panic!("discount ({discount}) must be positive");
+    }
+
+    let forward: Real = forward + displacement;
+    let strike: Real = strike + displacement;
+
+    let std_dev: Real = if strike == forward {
+        black_price / discount * (2.0 * PI).sqrt() / forward
+    } else {
+        let moneyness_delta: Real = option_type.sign() * (forward - strike);
+        let moneyness_delta_half: Real = moneyness_delta / 2.0;
+        let temp: Real = black_price / discount - moneyness_delta_half;
+        let moneyness_delta_pi: Real = moneyness_delta * moneyness_delta / PI;
+        // When the approximation breaks down the square root term is zeroed
+        let temp2: Real = (temp * temp - moneyness_delta_pi).max(0.0).sqrt();
+        (temp + temp2) * (2.0 * PI).sqrt() / (forward + strike)
+    };
+
+    std_dev.max(0.0)
+}
+
+/*
+Approximated Black implied standard deviation (QuantLib
+blackFormulaImpliedStdDevApproximationRS): the explicit formula of
+D. Stefanica and R. Radoicic, "An explicit implied volatility formula"
+(2017), which replaces N by the Polya approximation
+    N(x) ~ (1 + sign(x) sqrt(1 - exp(-2 x^2 / pi))) / 2
+in the Black price and solves the resulting quadratic. It is accurate
+over a wide range of moneyness and is the starting guess of
+black_formula_implied_std_dev.
+*/
+pub fn black_formula_implied_std_dev_approximation_rs(
+    option_type: OptionType,
+    strike: Real,
+    forward: Real,
+    black_price: Real,
+    discount: DiscountFactor,
+    displacement: Real,
+) -> Real {
+    detail::check_parameters(strike, forward, displacement);
+    if black_price < 0.0 {
+        panic!("blackPrice ({black_price}) must be non-negative");
+    }
+    if discount <= 0.0 {
+        panic!("discount ({discount}) must be positive");
+    }
+
+    let forward: Real = forward + displacement;
+    let strike: Real = strike + displacement;
+
+    let ey: Real = forward / strike;
+    let ey2: Real = ey * ey;
+    let y: Real = ey.ln();
+    let alpha: Real = black_price / (strike * discount);
+    let r: Real = 2.0 * alpha - option_type.sign() * (ey - 1.0);
+    let r2: Real = r * r;
+
+    let a: Real = ((1.0 - 2.0 / PI) * y).exp();
+    let a_term: Real = (a - 1.0 / a) * (a - 1.0 / a);
+    let b: Real = (2.0 / PI * y).exp();
+    let b_term: Real = 4.0 * (b + 1.0 / b) - 2.0 / ey * (a + 1.0 / a) * (ey2 + 1.0 - r2);
+    let c_term: Real = (r2 - (ey - 1.0) * (ey - 1.0)) * ((ey + 1.0) * (ey + 1.0) - r2) / ey2;
+
+    let beta: Real = 2.0 * c_term / (b_term + (b_term * b_term + 4.0 * a_term * c_term).sqrt());
+    let gamma: Real = -PI / 2.0 * beta.ln();
+
+    // Price of the option at the boundary between the two roots
+    let m0: Real = strike
+        * discount
+        * if y >= 0.0 {
+            let x: Real = (2.0 * y).sqrt();
+            match option_type {
+                OptionType::Call => ey * detail::polya(x) - 0.5,
+                OptionType::Put => 0.5 - ey * detail::polya(-x),
+            }
+        } else {
+            let x: Real = (-2.0 * y).sqrt();
+            match option_type {
+                OptionType::Call => 0.5 * ey - detail::polya(-x),
+                OptionType::Put => detail::polya(x) - 0.5 * ey,
+            }
+        };
+    let std_dev: Real = if black_price > m0 {
+        (gamma + y).sqrt() + (gamma - y).sqrt()
+    } else if y >= 0.0 {
+        (gamma + y).sqrt() - (gamma - y).sqrt()
+    } else {
+        (gamma - y).sqrt() - (gamma + y).sqrt()
+    };
+
+    // No time value (or none left in double precision) gives NaN
+    std_dev.max(0.0)
+}
+
+/*
+Black implied standard deviation.
+
+The price is first mapped onto the out-of-the-money option through
+put-call parity (better conditioned), then the Stefanica-Radoicic
+approximation (or the given guess) is polished with a safe Newton
+solver on [0, 24] (24 = 300% vol over 60 years).
+*/
+#[allow(clippy::too_many_arguments)]
+pub fn black_formula_implied_std_dev(
+    option_type: OptionType,
+    strike: Real,
+    forward: Real,
+    black_price: Real,
+    discount: DiscountFactor,
+    displacement: Real,
+    guess: Option<Real>,
+    accuracy: Real,
+    max_iterations: Size,
+) -> Real {
+    detail::check_parameters(strike, forward, displacement);
+    if discount <= 0.0 {
+        panic!("discount ({discount}) must be positive");
+    }
+    if black_price < 0.0 {
+        panic!("option price ({black_price}) must be non-negative");
+    }
+
+    // Check the price of the "other" option implied by put-call parity
+    let other_option_price: Real = black_price - option_type.sign() * (forward - strike) * discount;
+    if other_option_price < 0.0 {
+        panic!(
+            "negative {} price ({}) implied by put-call parity. No solution exists for {} strike {}, forward {}, price {}, discount {}",
+            if option_type == OptionType::Call {
+                OptionType::Put
+            } else {
+                OptionType::Call
+            },
+            other_option_price,
+            option_type,
+            strike,
+            forward,
+            black_price,
+            discount
+        );
+    }
+
+    // Always work on the out-of-the-money option
+    let (option_type, black_price): (OptionType, Real) = match option_type {
+        OptionType::Put if strike > forward => (OptionType::Call, other_option_price),
+        OptionType::Call if strike < forward => (OptionType::Put, other_option_price),
+        _ => (option_type, black_price),
+    };
+
+    let guess: Real = match guess {
+        Some(guess) => {
+            if guess < 0.0 {
+                panic!("stdDev guess ({guess}) must be non-negative");
+            }
+            guess
+        }
+        None => black_formula_implied_std_dev_approximation_rs(
+            option_type,
+            strike,
+            forward,
+            black_price,
+            discount,
+            displacement,
+        ),
+    };
+
+    let forward: Real = forward + displacement;
+    let strike: Real = strike + displacement;
+    let undiscounted_price: Real = black_price / discount;
+
+    let sign: Real = option_type.sign();
+    let phi: CumulativeNormalDistribution = CumulativeNormalDistribution::default();
+    let f = |std_dev: Real| -> Real {
+        if std_dev == 0.0 {
+            return (sign * (forward - strike)).max(0.0) - undiscounted_price;
+        }
+        let d1: Real = (forward / strike).ln() / std_dev + 0.5 * std_dev;
+        let d2: Real = d1 - std_dev;
+        sign * (forward * phi.value(sign * d1) - strike * phi.value(sign * d2)) - undiscounted_price
+    };
+    let derivative = |std_dev: Real| -> Real {
+        if std_dev == 0.0 {
+            return 0.0;
+        }
+        let d1: Real = (forward / strike).ln() / std_dev + 0.5 * std_dev;
+        forward * phi.derivative(d1)
+    };
+
+    let min_std_dev: Real = 0.0;
+    let max_std_dev: Real = 24.0;
+    let mut solver: NewtonSafe = NewtonSafe::new();
+    solver.set_max_evaluations(max_iterations);
+    solver.solve(
+        f,
+        derivative,
+        accuracy,
+        guess.clamp(min_std_dev, max_std_dev),
+        min_std_dev,
+        max_std_dev,
+    )
+}
+
+// Black implied volatility: the implied standard deviation over sqrt(tte)
+#[allow(clippy::too_many_arguments)]
+pub fn black_formula_implied_vol(
+    option_type: OptionType,
+    strike: Real,
+    forward: Real,
+    tte: Time,
+    black_price: Real,
+    discount: DiscountFactor,
+    displacement: Real,
+    accuracy: Real,
+    max_iterations: Size,
+) -> Volatility {
+    if tte <= 0.0 {
+        panic!("tte ({tte}) must be positive");
+    }
+    black_formula_implied_std_dev(
+        option_type,
+        strike,
+        forward,
+        black_price,
+        discount,
+        displacement,
+        None,
+        accuracy,
+        max_iterations,
+    ) / tte.sqrt()
+}
+
+/*
+Probability of being in the money in the forward measure: N(d2) for a
+call, N(-d2) for a put. It is also the price of a cash-or-nothing
+digital (paying 1) divided by the discount.
+*/
+pub fn black_formula_cash_itm_probability(
+    option_type: OptionType,
+    strike: Real,
+    forward: Real,
+    std_dev: Real,
+    displacement: Real,
+) -> Real {
+    detail::check_parameters(strike, forward, displacement);
+    let sign: Real = option_type.sign();
+
+    if std_dev == 0.0 {
+        return if sign * forward > sign * strike {
+            1.0
+        } else {
+            0.0
+        };
+    }
+
+    let forward: Real = forward + displacement;
+    let strike: Real = strike + displacement;
+    if strike == 0.0 {
+        return match option_type {
+            OptionType::Call => 1.0,
+            OptionType::Put => 0.0,
+        };
+    }
+
+    let d1: Real = (forward / strike).ln() / std_dev + 0.5 * std_dev;
+    let d2: Real = d1 - std_dev;
+    CumulativeNormalDistribution::default().value(sign * d2)
+}
+
+/*
+Probability of being in the money in the asset measure: N(d1) for a
+call, N(-d1) for a put.
+*/
+pub fn black_formula_asset_itm_probability(
+    option_type: OptionType,
+    strike: Real,
+    forward: Real,
+    std_dev: Real,
+    displacement: Real,
+) -> Real {
+    detail::check_parameters(strike, forward, displacement);
+    let sign: Real = option_type.sign();
+
+    if std_dev == 0.0 {
+        return if sign * forward > sign * strike {
+            1.0
+        } else {
+            0.0
+        };
+    }
+
+    let forward: Real = forward + displacement;
+    let strike: Real = strike + displacement;
+    if strike == 0.0 {
+        return match option_type {
+            OptionType::Call => 1.0,
+            OptionType::Put => 0.0,
+        };
+    }
+
+    let d1: Real = (forward / strike).ln() / std_dev + 0.5 * std_dev;
+    CumulativeNormalDistribution::default().value(sign * d1)
+}
+
+// Black formula derivative with respect to stdDev: discount * F * n(d1)
+pub fn black_formula_std_dev_derivative(
+    strike: Real,
+    forward: Real,
+    std_dev: Real,
+    discount: DiscountFactor,
+    displacement: Real,
+) -> Real {
+    detail::check_parameters(strike, forward, displacement);
+    if std_dev < 0.0 {
+        panic!("stdDev ({std_dev}) must be non-negative");
+    }
+    if discount <= 0.0 {
+        panic!("discount ({discount}) must be positive");
+    }
+
+    let forward: Real = forward + displacement;
+    let strike: Real = strike + displacement;
+    if std_dev == 0.0 || strike == 0.0 {
+        return 0.0;
+    }
+
+    let d1: Real = (forward / strike).ln() / std_dev + 0.5 * std_dev;
+    discount * forward * CumulativeNormalDistribution::default().derivative(d1)
+}
+
+// Black formula derivative with respect to the volatility (vega)
+pub fn black_formula_vol_derivative(
+    strike: Real,
+    forward: Real,
+    std_dev: Real,
+    expiry: Time,
+    discount: DiscountFactor,
+    displacement: Real,
+) -> Real {
+    black_formula_std_dev_derivative(strike, forward, std_dev, discount, displacement)
+        * expiry.sqrt()
+}
+
+// Black formula second derivative with respect to stdDev
+pub fn black_formula_std_dev_second_derivative(
+    strike: Real,
+    forward: Real,
+    std_dev: Real,
+    discount: DiscountFactor,
+    displacement: Real,
+) -> Real {
+    detail::check_parameters(strike, forward, displacement);
+    if std_dev < 0.0 {
+        panic!("stdDev ({std_dev}) must be non-negative");
+    }
+    if discount <= 0.0 {
+        panic!("discount ({discount}) must be positive");
+    }
+
+    let forward: Real = forward + displacement;
+    let strike: Real = strike + displacement;
+    if std_dev == 0.0 || strike == 0.0 {
+        return 0.0;
+    }
+
+    let log_moneyness: Real = (forward / strike).ln();
+    let d1: Real = log_moneyness / std_dev + 0.5 * std_dev;
+    // d(d1)/d(stdDev)
+    let d1_prime: Real = -log_moneyness / (std_dev * std_dev) + 0.5;
+    // d/d(stdDev) [F n(d1)] = F n'(d1) d1'
+    discount * forward * NormalDistribution::default().derivative(d1) * d1_prime
+}
+
+/*
+Bachelier (normal) formula: the forward follows an arithmetic Brownian
+motion, stdDev is the absolute (basis point) standard deviation.
+
+    d = phi * (F - K) / stdDev
+    price = discount * [stdDev n(d) + phi (F - K) N(d)]
+*/
+pub fn bachelier_black_formula(
+    option_type: OptionType,
+    strike: Real,
+    forward: Real,
+    std_dev: Real,
+    discount: DiscountFactor,
+) -> Real {
+    if std_dev < 0.0 {
+        panic!("stdDev ({std_dev}) must be non-negative");
+    }
+    if discount <= 0.0 {
+        panic!("discount ({discount}) must be positive");
+    }
+
+    let d: Real = option_type.sign() * (forward - strike);
+    if std_dev == 0.0 {
+        return discount * d.max(0.0);
+    }
+
+    let h: Real = d / std_dev;
+    let phi: CumulativeNormalDistribution = CumulativeNormalDistribution::default();
+    (discount * (std_dev * phi.derivative(h) + d * phi.value(h))).max(0.0)
+}
+
+// Bachelier formula derivative with respect to stdDev: discount * n(d)
+pub fn bachelier_black_formula_std_dev_derivative(
+    strike: Real,
+    forward: Real,
+    std_dev: Real,
+    discount: DiscountFactor,
+) -> Real {
+    if std_dev < 0.0 {
+        panic!("stdDev ({std_dev}) must be non-negative");
+    }
+    if discount <= 0.0 {
+        panic!("discount ({discount}) must be positive");
+    }
+    if std_dev == 0.0 {
+        return 0.0;
+    }
+
+    let d: Real = (forward - strike) / std_dev;
+    discount * CumulativeNormalDistribution::default().derivative(d)
+}
+
+/*
+Bachelier implied (normal) volatility.
+
+P. Jäckel, "Implied Normal Volatility" (2017): a rational approximation
+of the inverse of the normalised out-of-the-money price, followed by one
+Householder step of third order. It is accurate to machine precision,
+a final Newton step on the price only cleans up rounding.
+*/
+pub fn bachelier_black_formula_implied_vol(
+    option_type: OptionType,
+    strike: Real,
+    forward: Real,
+    tte: Time,
+    bachelier_price: Real,
+    discount: DiscountFactor,
+) -> Real {
+    if tte <= 0.0 {
+        panic!("tte ({tte}) must be positive");
+    }
+    if discount <= 0.0 {
+        panic!("discount ({discount}) must be positive");
+    }
+
+    let forward_premium: Real = bachelier_price / discount;
+    let absolute_moneyness: Real = (forward - strike).abs();
+    let sqrt_tte: Real = tte.sqrt();
+
+    // At the money: price = stdDev / sqrt(2 pi)
+    if absolute_moneyness < Real::EPSILON.sqrt() {
+        return forward_premium * (2.0 * PI).sqrt() / sqrt_tte;
+    }
+
+    let intrinsic: Real = (option_type.sign() * (forward - strike)).max(0.0);
+    let time_value: Real = forward_premium - intrinsic;
+    if time_value < -Real::EPSILON * forward_premium.max(1.0) {
+        panic!(
+            "option price ({}) below intrinsic value ({})",
+            bachelier_price,
+            intrinsic * discount
+        );
+    }
+    if time_value <= 0.0 {
+        return 0.0;
+    }
+
+    let x_star: Real = detail::jaeckel_inverse_normalised_price(-time_value / absolute_moneyness);
+    let mut std_dev: Real = absolute_moneyness / x_star.abs();
+
+    // Newton polishing on the undiscounted price
+    let vega: Real = bachelier_black_formula_std_dev_derivative(strike, forward, std_dev, 1.0);
+    if vega > 0.0 {
+        let error: Real =
+            bachelier_black_formula(option_type, strike, forward, std_dev, 1.0) - forward_premium;
+        std_dev -= error / vega;
+    }
+
+    std_dev / sqrt_tte
+}
+
+// Private
+mod detail {
+    use crate::math::distributions::normal_distribution::CumulativeNormalDistribution;
+    use crate::types::Real;
+    use std::f64::consts::PI;
+
+    // Polya approximation of the cumulative normal
+    pub(crate) fn polya(x: Real) -> Real {
+        0.5 * (1.0 + x.signum() * (1.0 - (-2.0 / PI * x * x).exp()).sqrt())
+    }
+
+    pub(crate) fn check_parameters(strike: Real, forward: Real, displacement: Real) {
+        if displacement < 0.0 {
+            panic!("displacement ({displacement}) must be non-negative");
+        }
+        if strike + displacement < 0.0 {
+            panic!("strike + displacement ({strike} + {displacement}) must be non-negative");
+        }
+        if forward + displacement <= 0.0 {
+            panic!("forward + displacement ({forward} + {displacement}) must be positive");
+        }
+    }
+
+    /*
+    Given phi_tilde_star = -(OTM time value) / |F - K| (always negative),
+    returns x* < 0 such that Phi(x) + n(x) / x = phi_tilde_star.
+    The implied stdDev is then |F - K| / |x*|.
+    */
+    pub(crate) fn jaeckel_inverse_normalised_price(phi_tilde_star: Real) -> Real {
+        let x_bar: Real = if phi_tilde_star < -0.001882039271 {
+            let g: Real = 1.0 / (phi_tilde_star - 0.5);
+            let g2: Real = g * g;
+            let xi_bar: Real = (0.032114372355
+                - g2 * (0.016969777977 - g2 * (0.002620733246 - 0.000096066952 * g2)))
+                / (1.0 - g2 * (0.6635646938 - g2 * (0.14528712196 - 0.010472855461 * g2)));
+            g * (1.0 / (2.0 * PI).sqrt() + xi_bar * g2)
+        } else {
+            let h: Real = (-(-phi_tilde_star).ln()).sqrt();
+            (9.4883409779 - h * (9.6320903635 - h * (0.58556997323 + 2.1464093351 * h)))
+                / (1.0 - h * (0.65174820867 + h * (1.5120247828 + 0.000066437847132 * h)))
+        };
+
+        let phi: CumulativeNormalDistribution = CumulativeNormalDistribution::default();
+        let phi_tilde_bar: Real = phi.value(x_bar) + phi.derivative(x_bar) / x_bar;
+        let q: Real = (phi_tilde_bar - phi_tilde_star) / phi.derivative(x_bar);
+        let x2: Real = x_bar * x_bar;
+
+        // Householder step of third order
+        x_bar
+            + 3.0 * q * x2 * (2.0 - q * x_bar * (2.0 + x2))
+                / (6.0
+                    + q * x_bar
+                        * (-12.0 + x_bar * (6.0 * q + x_bar * (-6.0 + q * x_bar * (3.0 + x2)))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    fn assert_close(actual: Real, expected: Real, tolerance: Real, label: &str) {
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "{label}: got {actual}, expected {expected} (tolerance {tolerance})"
+        );
+    }
+
+    #[test]
+    fn black_formula_matches_reference_values() {
+        // (type, strike, forward, stdDev, discount, displacement, expected)
+        let cases: [(OptionType, Real, Real, Real, Real, Real, Real); 6] = [
+            (
+                OptionType::Call,
+                100.0,
+                100.0,
+                0.2,
+                1.0,
+                0.0,
+                7.965567455405796,
+            ),
+            (
+                OptionType::Put,
+                100.0,
+                100.0,
+                0.2,
+                1.0,
+                0.0,
+                7.965567455405796,
+            ),
+            (
+                OptionType::Call,
+                90.0,
+                100.0,
+                0.3,
+                0.95,
+                0.0,
+                16.16223590675723,
+            ),
+            (
+                OptionType::Put,
+                110.0,
+                100.0,
+                0.25,
+                0.9,
+                0.0,
+                14.57138377239151,
+            ),
+            (OptionType::Call, 0.01, 0.02, 0.0, 1.0, 0.0, 0.01),
+            (OptionType::Call, 0.0, 100.0, 0.2, 0.9, 0.0, 90.0),
+        ];
+
+        for (option_type, strike, forward, std_dev, discount, displacement, expected) in cases {
+            let price: Real = black_formula(
+                option_type,
+                strike,
+                forward,
+                std_dev,
+                discount,
+                displacement,
+            );
+            assert_close(
+                price,
+                expected,
+                1.0e-12,
+                &format!("{option_type} K={strike} F={forward} stdDev={std_dev}"),
+            );
+        }
+    }
+
+    #[test]
+    fn black_formula_satisfies_put_call_parity() {
+        let forward: Real = 0.03;
+        let discount: Real = 0.97;
+        for displacement in [0.0, 0.01] {
+            for strike in [0.005, 0.02, 0.03, 0.05] {
+                let call: Real = black_formula(
+                    OptionType::Call,
+                    strike,
+                    forward,
+                    0.35,
+                    discount,
+                    displacement,
+                );
+                let put: Real = black_formula(
+                    OptionType::Put,
+                    strike,
+                    forward,
+                    0.35,
+                    discount,
+                    displacement,
+                );
+                assert_close(
+                    call - put,
+                    discount * (forward - strike),
+                    1.0e-15,
+                    &format!("parity K={strike} d={displacement}"),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn black_formula_panics_on_invalid_parameters() {
+        let cases: [(Real, Real, Real, Real, Real, &str); 5] = [
+            (100.0, 100.0, -0.1, 1.0, 0.0, "negative stdDev"),
+            (100.0, 100.0, 0.2, 0.0, 0.0, "zero discount"),
+            (100.0, 100.0, 0.2, 1.0, -0.1, "negative displacement"),
+            (-1.0, 100.0, 0.2, 1.0, 0.0, "negative strike"),
+            (100.0, 0.0, 0.2, 1.0, 0.0, "zero forward"),
+        ];
+
+        for (strike, forward, std_dev, discount, displacement, label) in cases {
+            let result = panic::catch_unwind(|| {
+                black_formula(
+                    OptionType::Call,
+                    strike,
+                    forward,
+                    std_dev,
+                    discount,
+                    displacement,
+                )
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+
+    #[test]
+    fn implied_std_dev_round_trip() {
+        let forward: Real = 100.0;
+        let discount: Real = 0.95;
+        for option_type in [OptionType::Call, OptionType::Put] {
+            for strike in [50.0, 80.0, 100.0, 120.0, 200.0] {
+                for std_dev in [0.01, 0.1, 0.3, 1.0, 2.5] {
+                    for displacement in [0.0, 10.0] {
+                        let price: Real = black_formula(
+                            option_type,
+                            strike,
+                            forward,
+                            std_dev,
+                            discount,
+                            displacement,
+                        );
+                        // Skip prices with no time value left in double precision
+                        let intrinsic: Real =
+                            (option_type.sign() * (forward - strike)).max(0.0) * discount;
+                        if price - intrinsic < 1.0e-10 * forward {
+                            continue;
+                        }
+                        let implied: Real = black_formula_implied_std_dev(
+                            option_type,
+                            strike,
+                            forward,
+                            price,
+                            discount,
+                            displacement,
+                            None,
+                            1.0e-12,
+                            100,
+                        );
+                        assert_close(
+                            implied,
+                            std_dev,
+                            1.0e-8,
+                            &format!("{option_type} K={strike} stdDev={std_dev} d={displacement}"),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn implied_std_dev_uses_given_guess() {
+        let price: Real = black_formula(OptionType::Call, 105.0, 100.0, 0.25, 1.0, 0.0);
+        for guess in [0.01, 0.25, 5.0] {
+            let implied: Real = black_formula_implied_std_dev(
+                OptionType::Call,
+                105.0,
+                100.0,
+                price,
+                1.0,
+                0.0,
+                Some(guess),
+                1.0e-12,
+                100,
+            );
+            assert_close(implied, 0.25, 1.0e-10, &format!("guess {guess}"));
+        }
+    }
+
+    #[test]
+    fn implied_std_dev_panics_below_intrinsic() {
+        // Call worth less than its intrinsic value (10) has no implied stdDev
+        let result = panic::catch_unwind(|| {
+            black_formula_implied_std_dev(
+                OptionType::Call,
+                90.0,
+                100.0,
+                9.0,
+                1.0,
+                0.0,
+                None,
+                1.0e-12,
+                100,
+            )
+        });
+        assert!(result.is_err(), "expected panic below intrinsic");
+    }
+
+    #[test]
+    fn implied_std_dev_approximation_is_close() {
+        let forward: Real = 100.0;
+        for strike in [90.0, 100.0, 110.0] {
+            let price: Real = black_formula(OptionType::Call, strike, forward, 0.2, 1.0, 0.0);
+            let approximation: Real = black_formula_implied_std_dev_approximation(
+                OptionType::Call,
+                strike,
+                forward,
+                price,
+                1.0,
+                0.0,
+            );
+            assert_close(approximation, 0.2, 2.0e-3, &format!("K={strike}"));
+        }
+    }
+
+    #[test]
+    fn stefanica_radoicic_approximation_is_close() {
+        // Relative error of a few percent at most, across moneyness and maturities
+        let forward: Real = 100.0;
+        for option_type in [OptionType::Call, OptionType::Put] {
+            for strike in [50.0, 80.0, 100.0, 125.0, 200.0] {
+                for std_dev in [0.05, 0.2, 0.5, 1.0] {
+                    for displacement in [0.0, 10.0] {
+                        let price: Real =
+                            black_formula(option_type, strike, forward, std_dev, 0.9, displacement);
+                        let intrinsic: Real =
+                            (option_type.sign() * (forward - strike)).max(0.0) * 0.9;
+                        if price - intrinsic < 1.0e-6 * forward {
+                            continue;
+                        }
+                        let approximation: Real = black_formula_implied_std_dev_approximation_rs(
+                            option_type,
+                            strike,
+                            forward,
+                            price,
+                            0.9,
+                            displacement,
+                        );
+                        assert_close(
+                            approximation,
+                            std_dev,
+                            0.05 * std_dev,
+                            &format!("{option_type} K={strike} stdDev={std_dev} d={displacement}"),
+                        );
+                    }
+                }
+            }
+        }
+        // At the money only the Polya approximation of N is left
+        let price: Real = black_formula(OptionType::Call, 100.0, 100.0, 0.2, 1.0, 0.0);
+        let approximation: Real = black_formula_implied_std_dev_approximation_rs(
+            OptionType::Call,
+            100.0,
+            100.0,
+            price,
+            1.0,
+            0.0,
+        );
+        assert_close(approximation, 0.2, 1.0e-4, "at the money");
+    }
+
+    #[test]
+    fn implied_vol_scales_the_std_dev() {
+        let tte: Time = 2.0;
+        let price: Real = black_formula(OptionType::Put, 95.0, 100.0, 0.25 * tte.sqrt(), 0.9, 0.0);
+        let implied: Volatility = black_formula_implied_vol(
+            OptionType::Put,
+            95.0,
+            100.0,
+            tte,
+            price,
+            0.9,
+            0.0,
+            1.0e-12,
+            100,
+        );
+        assert_close(implied, 0.25, 1.0e-10, "implied vol");
+        let result = panic::catch_unwind(|| {
+            black_formula_implied_vol(
+                OptionType::Put,
+                95.0,
+                100.0,
+                0.0,
+                price,
+                0.9,
+                0.0,
+                1.0e-12,
+                100,
+            )
+        });
+        assert!(result.is_err(), "expected panic for a null tte");
+    }
+
+    #[test]
+    fn itm_probabilities() {
+        let (strike, forward, std_dev): (Real, Real, Real) = (95.0, 100.0, 0.2);
+        let d1: Real = (forward / strike).ln() / std_dev + 0.5 * std_dev;
+        let d2: Real = d1 - std_dev;
+        let phi: CumulativeNormalDistribution = CumulativeNormalDistribution::default();
+
+        let cases: [(Real, Real, &str); 4] = [
+            (
+                black_formula_cash_itm_probability(OptionType::Call, strike, forward, std_dev, 0.0),
+                phi.value(d2),
+                "cash call",
+            ),
+            (
+                black_formula_cash_itm_probability(OptionType::Put, strike, forward, std_dev, 0.0),
+                phi.value(-d2),
+                "cash put",
+            ),
+            (
+                black_formula_asset_itm_probability(
+                    OptionType::Call,
+                    strike,
+                    forward,
+                    std_dev,
+                    0.0,
+                ),
+                phi.value(d1),
+                "asset call",
+            ),
+            (
+                black_formula_asset_itm_probability(OptionType::Put, strike, forward, std_dev, 0.0),
+                phi.value(-d1),
+                "asset put",
+            ),
+        ];
+        for (actual, expected, label) in cases {
+            assert_close(actual, expected, 1.0e-15, label);
+        }
+
+        // Cash ITM probability is minus the strike derivative of the undiscounted call
+        let h: Real = 1.0e-4;
+        let numerical: Real =
+            -(black_formula(OptionType::Call, strike + h, forward, std_dev, 1.0, 0.0)
+                - black_formula(OptionType::Call, strike - h, forward, std_dev, 1.0, 0.0))
+                / (2.0 * h);
+        assert_close(
+            black_formula_cash_itm_probability(OptionType::Call, strike, forward, std_dev, 0.0),
+            numerical,
+            1.0e-8,
+            "strike derivative",
+        );
+
+        // No volatility: 0 or 1
+        assert_eq!(
+            black_formula_cash_itm_probability(OptionType::Call, strike, forward, 0.0, 0.0),
+            1.0
+        );
+        assert_eq!(
+            black_formula_cash_itm_probability(OptionType::Put, strike, forward, 0.0, 0.0),
+            0.0
+        );
+    }
+
+    #[test]
+    fn std_dev_derivatives_match_finite_differences() {
+        let h: Real = 1.0e-5;
+        for (strike, forward, std_dev, displacement) in [
+            (100.0, 100.0, 0.2, 0.0),
+            (0.02, 0.03, 0.4, 0.0),
+            (0.01, 0.005, 0.15, 0.02),
+        ] {
+            let price =
+                |s: Real| black_formula(OptionType::Call, strike, forward, s, 0.9, displacement);
+            let first: Real = (price(std_dev + h) - price(std_dev - h)) / (2.0 * h);
+            let second: Real =
+                (price(std_dev + h) - 2.0 * price(std_dev) + price(std_dev - h)) / (h * h);
+
+            let scale: Real = forward + displacement;
+            assert_close(
+                black_formula_std_dev_derivative(strike, forward, std_dev, 0.9, displacement),
+                first,
+                1.0e-8 * scale,
+                "first derivative",
+            );
+            assert_close(
+                black_formula_std_dev_second_derivative(
+                    strike,
+                    forward,
+                    std_dev,
+                    0.9,
+                    displacement,
+                ),
+                second,
+                1.0e-4 * scale,
+                "second derivative",
+            );
+            assert_close(
+                black_formula_vol_derivative(strike, forward, std_dev, 4.0, 0.9, displacement),
+                2.0 * black_formula_std_dev_derivative(strike, forward, std_dev, 0.9, displacement),
+                1.0e-15 * scale,
+                "vol derivative",
+            );
+        }
+    }
+
+    #[test]
+    fn bachelier_formula_matches_reference_values() {
+        // ATM: stdDev / sqrt(2 pi)
+        assert_close(
+            bachelier_black_formula(OptionType::Call, 0.02, 0.02, 0.01, 1.0),
+            0.01 / (2.0 * PI).sqrt(),
+            1.0e-16,
+            "ATM call",
+        );
+
+        // Put-call parity also holds in the normal model, even for negative rates
+        for strike in [-0.01, 0.0, 0.01, 0.03] {
+            let call: Real = bachelier_black_formula(OptionType::Call, strike, -0.005, 0.008, 0.98);
+            let put: Real = bachelier_black_formula(OptionType::Put, strike, -0.005, 0.008, 0.98);
+            assert_close(
+                call - put,
+                0.98 * (-0.005 - strike),
+                1.0e-16,
+                &format!("parity K={strike}"),
+            );
+        }
+
+        // No volatility: intrinsic value
+        assert_close(
+            bachelier_black_formula(OptionType::Put, 0.03, 0.01, 0.0, 0.5),
+            0.01,
+            1.0e-17,
+            "no volatility",
+        );
+    }
+
+    #[test]
+    fn bachelier_std_dev_derivative_matches_finite_differences() {
+        let h: Real = 1.0e-7;
+        let price = |s: Real| bachelier_black_formula(OptionType::Put, 0.01, 0.015, s, 0.9);
+        let numerical: Real = (price(0.01 + h) - price(0.01 - h)) / (2.0 * h);
+        assert_close(
+            bachelier_black_formula_std_dev_derivative(0.01, 0.015, 0.01, 0.9),
+            numerical,
+            1.0e-8,
+            "bachelier vega",
+        );
+    }
+
+    #[test]
+    fn bachelier_implied_vol_round_trip() {
+        let forward: Real = 0.01;
+        let tte: Time = 2.0;
+        let discount: Real = 0.96;
+        for option_type in [OptionType::Call, OptionType::Put] {
+            for strike in [-0.02, -0.005, 0.0, 0.01, 0.012, 0.03, 0.06] {
+                for vol in [0.0005, 0.005, 0.01, 0.02] {
+                    let std_dev: Real = vol * tte.sqrt();
+                    let price: Real =
+                        bachelier_black_formula(option_type, strike, forward, std_dev, discount);
+                    let implied: Real = bachelier_black_formula_implied_vol(
+                        option_type,
+                        strike,
+                        forward,
+                        tte,
+                        price,
+                        discount,
+                    );
+                    // Deep out of the money the time value underflows: only check
+                    // when there is something left to invert
+                    let intrinsic: Real =
+                        (option_type.sign() * (forward - strike)).max(0.0) * discount;
+                    if price - intrinsic < 1.0e-14 {
+                        continue;
+                    }
+                    assert_close(
+                        implied,
+                        vol,
+                        1.0e-10,
+                        &format!("{option_type} K={strike} vol={vol}"),
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bachelier_implied_vol_panics_on_invalid_inputs() {
+        let cases: [(Real, Real, Real, &str); 2] = [
+            (0.0, 0.001, 1.0, "zero time to expiry"),
+            (1.0, 0.001, 1.0, "price below intrinsic"),
+        ];
+
+        for (tte, price, discount, label) in cases {
+            let result = panic::catch_unwind(|| {
+                bachelier_black_formula_implied_vol(
+                    OptionType::Call,
+                    0.0,
+                    0.01,
+                    tte,
+                    price,
+                    discount,
+                )
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
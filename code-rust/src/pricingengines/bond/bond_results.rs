@@ -0,0 +1,12 @@
+use crate::time::date::Date;
+use crate::types::Real;
+
+// What the bond engine computes: values, prices per 100 of notional and the settlement date (QuantLib Bond::results)
+#[derive(Clone, PartialEq, Debug)]
+pub struct BondResults {
+    pub value: Real,
+    pub settlement_value: Real,
+    pub dirty_price: Real,
+    pub clean_price: Real,
+    pub settlement_date: Date,
+}
@@ -0,0 +1,203 @@
+use crate::instruments::bond::Bond;
+use crate::instruments::floating_rate_bond::FloatingRateBond;
+use crate::pricingengines::bond::bond_results::BondResults;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::date::Date;
+use crate::types::{DiscountFactor, Real};
+use std::rc::Rc;
+
+/*
+Discounting engine for bonds (QuantLib DiscountingBondEngine): the bond
+is traded on the reference date of the discount curve and every cash
+flow paid after its settlement date is discounted on the curve. The
+value is discounted to the reference date, the settlement value and
+the prices to the settlement date. Floating-rate bonds are forecast on
+the discount curve.
+*/
+pub struct DiscountingBondEngine {
+    discount_curve: Rc<dyn YieldTermStructure>,
+}
+impl DiscountingBondEngine {
+    // Constructor
+    pub fn new(discount_curve: Rc<dyn YieldTermStructure>) -> DiscountingBondEngine {
+        DiscountingBondEngine { discount_curve }
+    }
+
+    // Inspectors
+    pub fn discount_curve(&self) -> &dyn YieldTermStructure {
+        self.discount_curve.as_ref()
+    }
+
+    pub fn calculate(&self, bond: &Bond) -> BondResults {
+        let settlement_date: Date = bond.settlement_date(self.discount_curve.reference_date());
+        let notional: Real = bond.notional(settlement_date);
+        self.results(
+            &bond.cash_flows(),
+            settlement_date,
+            notional,
+            bond.accrued_amount(settlement_date),
+        )
+    }
+    pub fn calculate_floating_rate(&self, bond: &FloatingRateBond) -> BondResults {
+        let curve: &dyn YieldTermStructure = self.discount_curve.as_ref();
+        let settlement_date: Date = bond.settlement_date(curve.reference_date());
+        let notional: Real = bond.notional(settlement_date);
+        self.results(
+            &bond.cash_flows(curve),
+            settlement_date,
+            notional,
+            bond.accrued_amount(settlement_date, curve),
+        )
+    }
+
+    fn results(
+        &self,
+        cash_flows: &[(Date, Real)],
+        settlement_date: Date,
+        notional: Real,
+        accrued_amount: Real,
+    ) -> BondResults {
+        if notional == 0.0 {
+            panic!("bond redeemed by the settlement date ({settlement_date})");
+        }
+        let value: Real = cash_flows
+            .iter()
+            .filter(|(date, _)| *date > settlement_date)
+            .map(|(date, amount)| amount * self.discount_curve.discount_date(*date))
+            .sum();
+        let settlement_discount: DiscountFactor =
+            self.discount_curve.discount_date(settlement_date);
+        let settlement_value: Real = value / settlement_discount;
+        let dirty_price: Real = 100.0 * settlement_value / notional;
+        BondResults {
+            value,
+            settlement_value,
+            dirty_price,
+            clean_price: dirty_price - accrued_amount,
+            settlement_date,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexes::ibor_index::IborIndex;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::business_day_convention::BusinessDayConvention;
+    use crate::time::calendars::target::Target;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual360::Actual360;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::frequency::Frequency;
+    use crate::time::period::Period;
+    use crate::time::schedule::{DateGenerationRule, Schedule};
+    use crate::time::time_unit::TimeUnit;
+    use crate::types::Rate;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn engine(rate: Rate) -> DiscountingBondEngine {
+        DiscountingBondEngine::new(flat_rate(reference_date(), rate))
+    }
+
+    #[test]
+    fn prices_match_the_equivalent_yield() {
+        // Issued a year and a half ago: the first coupon is paid before settlement
+        let issue_date: Date = reference_date() - 547;
+        let schedule: Schedule = Schedule::unadjusted(
+            issue_date,
+            issue_date + 5 * 365,
+            Period::new(365, TimeUnit::Days),
+        );
+        let bond: Bond = Bond::fixed_rate(
+            2,
+            Box::new(Target::new()),
+            1.0e6,
+            &schedule,
+            0.05,
+            Box::new(Actual365Fixed::new()),
+        );
+        let results: BondResults = engine(0.03).calculate(&bond);
+        assert_eq!(results.settlement_date, reference_date() + 2);
+        // Continuous rate r and the yield (e^(r/f) - 1) f discount alike
+        for frequency in [Frequency::Annual, Frequency::Semiannual] {
+            let f: Real = frequency as i32 as Real;
+            let bond_yield: Rate = ((0.03 / f).exp() - 1.0) * f;
+            let clean: Real =
+                bond.clean_price_from_yield(bond_yield, frequency, results.settlement_date);
+            assert!((results.clean_price - clean).abs() < 1.0e-10);
+        }
+        let dirty: Real = results.clean_price + bond.accrued_amount(results.settlement_date);
+        assert!((results.dirty_price - dirty).abs() < 1.0e-10);
+        assert!((results.settlement_value - 1.0e4 * dirty).abs() < 1.0e-6);
+        let discount: DiscountFactor = (-0.03 * 2.0 / 365.0_f64).exp();
+        assert!((results.value - results.settlement_value * discount).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn zero_coupon_bond_is_worth_its_discounted_face_amount() {
+        let bond: Bond = Bond::zero_coupon(
+            0,
+            Box::new(Target::new()),
+            100.0,
+            reference_date() - 100,
+            reference_date() + 730,
+            Box::new(Actual365Fixed::new()),
+        );
+        let results: BondResults = engine(0.04).calculate(&bond);
+        let expected: Real = 100.0 * (-0.08_f64).exp();
+        assert!((results.value - expected).abs() < 1.0e-12);
+        assert!((results.clean_price - expected).abs() < 1.0e-12);
+        let result = std::panic::catch_unwind(|| {
+            let matured: Bond = Bond::zero_coupon(
+                0,
+                Box::new(Target::new()),
+                100.0,
+                reference_date() - 730,
+                reference_date(),
+                Box::new(Actual365Fixed::new()),
+            );
+            engine(0.04).calculate(&matured)
+        });
+        assert!(result.is_err(), "expected panic for a matured bond");
+    }
+
+    #[test]
+    fn floating_rate_bond_is_worth_its_current_coupon() {
+        // The forecast coupons and the redemption telescope to the face amount
+        // paid at the end of the current coupon, which fixed at 2.5%
+        let mut index: IborIndex = IborIndex::euribor(Period::new(6, TimeUnit::Months));
+        index.add_fixing(Date::new(13, Month::January, 2025), 0.025);
+        let schedule: Schedule = Schedule::new(
+            Date::new(15, Month::January, 2024),
+            Date::new(15, Month::January, 2027),
+            Period::new(6, TimeUnit::Months),
+            &Target::new(),
+            BusinessDayConvention::ModifiedFollowing,
+            DateGenerationRule::Backward,
+            false,
+        );
+        let bond: FloatingRateBond = FloatingRateBond::new(
+            2,
+            Box::new(Target::new()),
+            100.0,
+            &schedule,
+            Rc::new(index),
+            0.0,
+            Box::new(Actual360::new()),
+        );
+        let engine: DiscountingBondEngine = engine(0.03);
+        let results: BondResults = engine.calculate_floating_rate(&bond);
+        let coupon_end: Date = Date::new(15, Month::July, 2025);
+        let expected: Real = 100.0
+            * (1.0 + 0.025 * 181.0 / 360.0)
+            * engine.discount_curve().discount_date(coupon_end);
+        assert!((results.value - expected).abs() < 1.0e-10);
+        let accrued: Real =
+            0.025 * (results.settlement_date - Date::new(15, Month::January, 2025)) as Real / 3.6;
+        assert!((results.dirty_price - results.clean_price - accrued).abs() < 1.0e-12);
+    }
+}
@@ -0,0 +1,272 @@
+use crate::instruments::bond::Bond;
+use crate::instruments::callable_bond::{CallabilityType, CallableFixedRateBond};
+use crate::methods::lattices::tree::Tree;
+use crate::models::shortrate::one_factor_model::{OneFactorModel, ShortRateTree};
+use crate::pricingengines::bond::bond_results::BondResults;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::date::Date;
+use crate::time_grid::TimeGrid;
+use crate::types::{DiscountFactor, Real, Size, Time};
+use std::rc::Rc;
+
+/*
+Callable fixed-rate bond engine on the trinomial tree of a one-factor
+short-rate model (QuantLib TreeCallableFixedRateBondEngine).
+
+The bond is traded on the reference date of the discount curve, whose
+day counter measures the times on the tree. The cash flows paid after
+settlement are rolled back on the tree; on each callability date after
+settlement the issuer calls when the bond is worth more than the call
+price, and the holder puts when it is worth less than the put price,
+both prices being the clean price plus the accrued interest on the
+notional outstanding. Cash flows paid on a callability date are paid
+whether the bond is redeemed or not. The value and prices are given as
+by DiscountingBondEngine, the discount curve giving the discount factor
+to settlement.
+*/
+pub struct TreeCallableFixedRateBondEngine<M: OneFactorModel> {
+    model: M,
+    time_steps: Size,
+    discount_curve: Rc<dyn YieldTermStructure>,
+}
+impl<M: OneFactorModel> TreeCallableFixedRateBondEngine<M> {
+    // Constructor
+    pub fn new(
+        model: M,
+        time_steps: Size,
+        discount_curve: Rc<dyn YieldTermStructure>,
+    ) -> TreeCallableFixedRateBondEngine<M> {
+        if time_steps == 0 {
+            panic!("at least one time step required");
+        }
+        TreeCallableFixedRateBondEngine {
+            model,
+            time_steps,
+            discount_curve,
+        }
+    }
+
+    // Inspectors
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+    pub fn discount_curve(&self) -> &dyn YieldTermStructure {
+        self.discount_curve.as_ref()
+    }
+
+    pub fn calculate(&self, callable_bond: &CallableFixedRateBond) -> BondResults {
+        let bond: &Bond = callable_bond.bond();
+        let curve: &dyn YieldTermStructure = self.discount_curve.as_ref();
+        let settlement_date: Date = bond.settlement_date(curve.reference_date());
+        let notional: Real = bond.notional(settlement_date);
+        if notional == 0.0 {
+            panic!("bond redeemed by the settlement date ({settlement_date})");
+        }
+        let cash_flows: Vec<(Time, Real)> = bond
+            .cash_flows()
+            .iter()
+            .filter(|(date, _)| *date > settlement_date)
+            .map(|(date, amount)| (curve.time_from_reference(*date), *amount))
+            .collect();
+        // Redemption amount and sign of the exercise (+1 call, -1 put) on each callability
+        let callabilities: Vec<(Time, Real, Real)> = callable_bond
+            .callabilities()
+            .iter()
+            .filter(|callability| callability.date() > settlement_date)
+            .map(|callability| {
+                let date: Date = callability.date();
+                let amount: Real =
+                    bond.notional(date) * (callability.price() + bond.accrued_amount(date)) / 100.0;
+                let sign: Real = match callability.callability_type() {
+                    CallabilityType::Call => 1.0,
+                    CallabilityType::Put => -1.0,
+                };
+                (curve.time_from_reference(date), amount, sign)
+            })
+            .collect();
+
+        let times: Vec<Time> = cash_flows
+            .iter()
+            .map(|(t, _)| *t)
+            .chain(callabilities.iter().map(|(t, _, _)| *t))
+            .collect();
+        let grid: TimeGrid = TimeGrid::from_mandatory_times(&times, self.time_steps);
+        let mut cash_at: Vec<Real> = vec![0.0; grid.len()];
+        for (t, amount) in &cash_flows {
+            cash_at[grid.index_of(*t)] += amount;
+        }
+        let mut redemption_at: Vec<Option<(Real, Real)>> = vec![None; grid.len()];
+        for (t, amount, sign) in &callabilities {
+            redemption_at[grid.index_of(*t)] = Some((*amount, *sign));
+        }
+
+        let tree: ShortRateTree = self.model.tree(&grid);
+        let last: Size = grid.len() - 1;
+        let mut values: Vec<Real> = vec![cash_at[last]; tree.size(last)];
+        for i in (0..last).rev() {
+            values = tree.rollback(i, &values);
+            if let Some((amount, sign)) = redemption_at[i] {
+                // The issuer calls when sign (value - amount) > 0, the holder puts when < 0
+                for value in values.iter_mut() {
+                    if sign * (*value - amount) > 0.0 {
+                        *value = amount;
+                    }
+                }
+            }
+            for value in values.iter_mut() {
+                *value += cash_at[i];
+            }
+        }
+
+        let value: Real = values[0];
+        let settlement_discount: DiscountFactor = curve.discount_date(settlement_date);
+        let settlement_value: Real = value / settlement_discount;
+        let dirty_price: Real = 100.0 * settlement_value / notional;
+        BondResults {
+            value,
+            settlement_value,
+            dirty_price,
+            clean_price: dirty_price - bond.accrued_amount(settlement_date),
+            settlement_date,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruments::callable_bond::Callability;
+    use crate::math::solvers1d::brent::Brent;
+    use crate::models::model::AffineModel;
+    use crate::models::shortrate::one_factor_model::OneFactorAffineModel;
+    use crate::models::shortrate::onefactormodels::hull_white::HullWhite;
+    use crate::option::OptionType;
+    use crate::pricingengines::bond::discounting_bond_engine::DiscountingBondEngine;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::calendars::null_calendar::NullCalendar;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::period::Period;
+    use crate::time::schedule::Schedule;
+    use crate::time::time_unit::TimeUnit;
+    use crate::types::Rate;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    // Five-year 4.5% bond of 100 with 365-day periods, issued on the reference date
+    fn callable_bond(callabilities: &[Callability]) -> CallableFixedRateBond {
+        CallableFixedRateBond::new(
+            0,
+            Box::new(NullCalendar::new()),
+            100.0,
+            &Schedule::unadjusted(
+                reference_date(),
+                reference_date() + 5 * 365,
+                Period::new(365, TimeUnit::Days),
+            ),
+            0.045,
+            Box::new(Actual365Fixed::new()),
+            callabilities,
+        )
+    }
+
+    fn model() -> HullWhite {
+        HullWhite::new(flat_rate(reference_date(), 0.04), 0.1, 0.01)
+    }
+
+    fn engine() -> TreeCallableFixedRateBondEngine<HullWhite> {
+        TreeCallableFixedRateBondEngine::new(model(), 200, flat_rate(reference_date(), 0.04))
+    }
+
+    #[test]
+    fn european_call_matches_jamshidian() {
+        // Callable at par after two years: the straight bond less a call
+        // on the remaining coupons struck at par, i.e. a sum of zero-bond calls
+        let call_date: Date = reference_date() + 2 * 365;
+        let callable: CallableFixedRateBond =
+            callable_bond(&[Callability::new(CallabilityType::Call, 100.0, call_date)]);
+        let straight: Real = DiscountingBondEngine::new(flat_rate(reference_date(), 0.04))
+            .calculate(callable.bond())
+            .value;
+
+        let model: HullWhite = model();
+        let t0: Time = 2.0;
+        let payments: [(Time, Real); 3] = [(3.0, 4.5), (4.0, 4.5), (5.0, 104.5)];
+        let bond = |r: Rate| -> Real {
+            payments
+                .iter()
+                .map(|(t, c)| c * model.discount_bond(t0, *t, r))
+                .sum::<Real>()
+                - 100.0
+        };
+        let r_star: Rate = Brent::new().solve(bond, 1.0e-14, -0.5, 0.5);
+        let call: Real = payments
+            .iter()
+            .map(|(t, c)| {
+                let strike: Real = model.discount_bond(t0, *t, r_star);
+                c * model.discount_bond_option(OptionType::Call, strike, t0, *t)
+            })
+            .sum();
+
+        let results: BondResults = engine().calculate(&callable);
+        let expected: Real = straight - call;
+        assert!(call > 0.5, "{call}");
+        assert!(
+            (results.value - expected).abs() < 2.0e-3 * call,
+            "{} vs {expected}",
+            results.value
+        );
+        assert_eq!(results.settlement_date, reference_date());
+        assert!((results.clean_price - results.value).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn calls_cheapen_and_puts_enrich_the_bond() {
+        let dates: Vec<Date> = (1..5).map(|k| reference_date() + 365 * k).collect();
+        let straight: Real = engine().calculate(&callable_bond(&[])).value;
+        let exact: Real = DiscountingBondEngine::new(flat_rate(reference_date(), 0.04))
+            .calculate(callable_bond(&[]).bond())
+            .value;
+        assert!((straight - exact).abs() < 1.0e-8, "{straight} vs {exact}");
+
+        let bermudan = |callability_type: CallabilityType, price: Real| -> Real {
+            let callabilities: Vec<Callability> = dates
+                .iter()
+                .map(|date| Callability::new(callability_type, price, *date))
+                .collect();
+            engine().calculate(&callable_bond(&callabilities)).value
+        };
+        let european_call: Real = engine()
+            .calculate(&callable_bond(&[Callability::new(
+                CallabilityType::Call,
+                100.0,
+                dates[1],
+            )]))
+            .value;
+        assert!(bermudan(CallabilityType::Call, 100.0) < european_call);
+        assert!(european_call < straight);
+        assert!(bermudan(CallabilityType::Put, 100.0) > straight);
+        // Out-of-the-money rights are worth nothing
+        assert!((bermudan(CallabilityType::Call, 150.0) - straight).abs() < 1.0e-12);
+        assert!((bermudan(CallabilityType::Put, 50.0) - straight).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn calculate_panics_on_a_redeemed_bond() {
+        let result = std::panic::catch_unwind(|| {
+            TreeCallableFixedRateBondEngine::new(
+                model(),
+                50,
+                flat_rate(reference_date() + 6 * 365, 0.04),
+            )
+            .calculate(&callable_bond(&[]))
+        });
+        assert!(result.is_err(), "expected panic for a redeemed bond");
+        let result = std::panic::catch_unwind(|| {
+            TreeCallableFixedRateBondEngine::new(model(), 0, flat_rate(reference_date(), 0.04))
+        });
+        assert!(result.is_err(), "expected panic for no time steps");
+    }
+}
@@ -0,0 +1,146 @@
+use crate::instruments::cap_floor::CapFloor;
+use crate::pricingengines::capfloor::black_cap_floor_engine::detail::calculate;
+use crate::pricingengines::capfloor::cap_floor_results::CapFloorResults;
+use crate::termstructures::volatility::optionlet::optionlet_volatility_structure::OptionletVolatilityStructure;
+use crate::termstructures::volatility::volatility_type::VolatilityType;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use std::rc::Rc;
+
+/*
+Bachelier cap/floor engine (QuantLib BachelierCapFloorEngine): as
+BlackCapFloorEngine, with the normal (basis point) volatility of the
+optionlet structure in the Bachelier formula.
+*/
+pub struct BachelierCapFloorEngine {
+    discount_curve: Rc<dyn YieldTermStructure>,
+    volatility: Box<dyn OptionletVolatilityStructure>,
+}
+impl BachelierCapFloorEngine {
+    // Constructor
+    pub fn new(
+        discount_curve: Rc<dyn YieldTermStructure>,
+        volatility: Box<dyn OptionletVolatilityStructure>,
+    ) -> BachelierCapFloorEngine {
+        if volatility.volatility_type() != VolatilityType::Normal {
+            panic!("BachelierCapFloorEngine requires normal volatilities");
+        }
+        BachelierCapFloorEngine {
+            discount_curve,
+            volatility,
+        }
+    }
+
+    // Inspectors
+    pub fn discount_curve(&self) -> &dyn YieldTermStructure {
+        self.discount_curve.as_ref()
+    }
+    pub fn volatility(&self) -> &dyn OptionletVolatilityStructure {
+        self.volatility.as_ref()
+    }
+
+    pub fn calculate(&self, cap_floor: &CapFloor) -> CapFloorResults {
+        calculate(
+            cap_floor,
+            self.discount_curve.as_ref(),
+            self.volatility.reference_date(),
+            self.volatility.day_counter(),
+            &|t, strike| self.volatility.volatility(t, strike),
+            VolatilityType::Normal,
+            0.0,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cashflows::ibor_coupon::{IborCoupon, IborLeg};
+    use crate::pricingengines::capfloor::black_cap_floor_engine::BlackCapFloorEngine;
+    use crate::termstructures::volatility::optionlet::constant_optionlet_volatility::ConstantOptionletVolatility;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::{Date, Month};
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::types::{Real, Volatility};
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn leg(periods: i32) -> Vec<IborCoupon> {
+        let schedule: Vec<Date> = (1..=periods + 1)
+            .map(|k| reference_date() + 182 * k)
+            .collect();
+        IborLeg::new(&schedule)
+            .with_notionals(&[1.0e6])
+            .coupons(&Actual365Fixed::new())
+    }
+
+    fn volatility(
+        volatility: Volatility,
+        volatility_type: VolatilityType,
+    ) -> Box<dyn OptionletVolatilityStructure> {
+        Box::new(ConstantOptionletVolatility::new(
+            reference_date(),
+            Box::new(Actual365Fixed::new()),
+            volatility,
+            volatility_type,
+            0.0,
+        ))
+    }
+
+    #[test]
+    fn cap_floor_parity_with_negative_strikes() {
+        let engine: BachelierCapFloorEngine = BachelierCapFloorEngine::new(
+            flat_rate(reference_date(), 0.03),
+            volatility(0.01, VolatilityType::Normal),
+        );
+        for strike in [-0.01, 0.0, 0.03, 0.06] {
+            let cap: CapFloorResults = engine.calculate(&CapFloor::cap(leg(8), &[strike]));
+            let floor: CapFloorResults = engine.calculate(&CapFloor::floor(leg(8), &[strike]));
+            let collar: CapFloorResults =
+                engine.calculate(&CapFloor::collar(leg(8), &[strike], &[strike]));
+            let swap: Real = leg(8)
+                .iter()
+                .zip(&cap.forwards)
+                .map(|(coupon, forward)| {
+                    let t: Real = (coupon.date() - reference_date()) as Real / 365.0;
+                    1.0e6 * coupon.accrual_period() * (forward - strike) * (-0.03 * t).exp()
+                })
+                .sum();
+            assert!((cap.value - floor.value - swap).abs() < 1.0e-6);
+            assert!((collar.value - swap).abs() < 1.0e-6);
+        }
+    }
+
+    #[test]
+    fn matches_black_at_the_money_for_small_volatilities() {
+        // ATM: Black = Bachelier (1 - sigma^2 t / 24 + ...) with sigma_N = sigma F
+        let leg: Vec<IborCoupon> = leg(1);
+        let black: BlackCapFloorEngine = BlackCapFloorEngine::new(
+            flat_rate(reference_date(), 0.03),
+            volatility(0.02, VolatilityType::ShiftedLognormal),
+        );
+        let forward: Real = black
+            .calculate(&CapFloor::cap(leg.clone(), &[0.03]))
+            .forwards[0];
+        let cap: CapFloor = CapFloor::cap(leg, &[forward]);
+        let black_value: Real = black.calculate(&cap).value;
+        let bachelier: BachelierCapFloorEngine = BachelierCapFloorEngine::new(
+            flat_rate(reference_date(), 0.03),
+            volatility(0.02 * forward, VolatilityType::Normal),
+        );
+        let bachelier_value: Real = bachelier.calculate(&cap).value;
+        assert!((black_value / bachelier_value - 1.0).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn new_panics_on_lognormal_volatilities() {
+        let result = std::panic::catch_unwind(|| {
+            BachelierCapFloorEngine::new(
+                flat_rate(reference_date(), 0.03),
+                volatility(0.2, VolatilityType::ShiftedLognormal),
+            )
+        });
+        assert!(result.is_err(), "expected panic for lognormal volatilities");
+    }
+}
@@ -0,0 +1,369 @@
+use crate::instruments::cap_floor::CapFloor;
+use crate::pricingengines::capfloor::cap_floor_results::CapFloorResults;
+use crate::termstructures::volatility::optionlet::optionlet_volatility_structure::OptionletVolatilityStructure;
+use crate::termstructures::volatility::volatility_type::VolatilityType;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::types::Real;
+use std::rc::Rc;
+
+/*
+Black-formula cap/floor engine (QuantLib BlackCapFloorEngine): each
+optionlet is a call (caplet) or put (floorlet) on the forward rate of
+its coupon, with the shifted lognormal volatility of the optionlet
+structure at the fixing time and strike.
+
+Forwards and discount factors come from the discount curve, fixing
+times from the volatility structure. Coupons fixed at or before the
+reference date are valued at their intrinsic value on the fixing of
+the cap index, or on the forward when a fixing due on the reference
+date is not known yet. Coupons already paid are worth nothing.
+*/
+pub struct BlackCapFloorEngine {
+    discount_curve: Rc<dyn YieldTermStructure>,
+    volatility: Box<dyn OptionletVolatilityStructure>,
+}
+impl BlackCapFloorEngine {
+    // Constructor
+    pub fn new(
+        discount_curve: Rc<dyn YieldTermStructure>,
+        volatility: Box<dyn OptionletVolatilityStructure>,
+    ) -> BlackCapFloorEngine {
+        if volatility.volatility_type() != VolatilityType::ShiftedLognormal {
+            panic!("BlackCapFloorEngine requires shifted lognormal volatilities");
+        }
+        BlackCapFloorEngine {
+            discount_curve,
+            volatility,
+        }
+    }
+
+    // Inspectors
+    pub fn discount_curve(&self) -> &dyn YieldTermStructure {
+        self.discount_curve.as_ref()
+    }
+    pub fn volatility(&self) -> &dyn OptionletVolatilityStructure {
+        self.volatility.as_ref()
+    }
+    pub fn displacement(&self) -> Real {
+        self.volatility.displacement()
+    }
+
+    pub fn calculate(&self, cap_floor: &CapFloor) -> CapFloorResults {
+        detail::calculate(
+            cap_floor,
+            self.discount_curve.as_ref(),
+            self.volatility.reference_date(),
+            self.volatility.day_counter(),
+            &|t, strike| self.volatility.volatility(t, strike),
+            VolatilityType::ShiftedLognormal,
+            self.volatility.displacement(),
+        )
+    }
+}
+
+pub(crate) mod detail {
+    use crate::cashflows::ibor_coupon::IborCoupon;
+    use crate::indexes::ibor_index::IborIndex;
+    use crate::instruments::cap_floor::{CapFloor, CapFloorType};
+    use crate::option::OptionType;
+    use crate::pricingengines::black_formula::{
+        bachelier_black_formula, bachelier_black_formula_std_dev_derivative, black_formula,
+        black_formula_std_dev_derivative,
+    };
+    use crate::pricingengines::capfloor::cap_floor_results::CapFloorResults;
+    use crate::termstructures::volatility::volatility_type::VolatilityType;
+    use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+    use crate::time::date::Date;
+    use crate::time::day_counter::DayCounter;
+    use crate::types::{DiscountFactor, Rate, Real, Time, Volatility};
+
+    /*
+    Optionlet sum shared by the Black and Bachelier engines, the implied
+    volatility solver and the caplet stripper. volatility(t, strike) is
+    the optionlet volatility for a fixing at time t, measured from
+    reference_date with day_counter.
+    */
+    pub(crate) fn calculate(
+        cap_floor: &CapFloor,
+        discount_curve: &dyn YieldTermStructure,
+        reference_date: Date,
+        day_counter: &dyn DayCounter,
+        volatility: &dyn Fn(Time, Rate) -> Volatility,
+        volatility_type: VolatilityType,
+        displacement: Real,
+    ) -> CapFloorResults {
+        let cap_floor_type: CapFloorType = cap_floor.cap_floor_type();
+        let mut results: CapFloorResults = CapFloorResults {
+            value: 0.0,
+            vega: 0.0,
+            optionlet_values: Vec::new(),
+            forwards: Vec::new(),
+            std_devs: Vec::new(),
+        };
+        for (i, coupon) in cap_floor.floating_leg().iter().enumerate() {
+            if coupon.date() <= reference_date {
+                results.optionlet_values.push(0.0);
+                results.forwards.push(0.0);
+                results.std_devs.push(0.0);
+                continue;
+            }
+            let fixing_time: Time = day_counter.year_fraction(reference_date, coupon.fixing_date());
+            let forward: Rate = forward_rate(coupon, cap_floor.index(), discount_curve);
+            let discount: DiscountFactor = discount_curve.discount_date(coupon.date());
+            let accrual: Real = coupon.nominal() * coupon.accrual_period();
+
+            let mut value: Real = 0.0;
+            let mut std_dev: Real = 0.0;
+            if cap_floor_type != CapFloorType::Floor {
+                let strike: Rate = cap_floor.cap_rates()[i];
+                let (price, vega, cap_std_dev): (Real, Real, Real) = optionlet(
+                    OptionType::Call,
+                    strike,
+                    forward,
+                    fixing_time,
+                    volatility,
+                    discount,
+                    volatility_type,
+                    displacement,
+                );
+                value += accrual * price;
+                results.vega += accrual * vega;
+                std_dev = cap_std_dev;
+            }
+            if cap_floor_type != CapFloorType::Cap {
+                let strike: Rate = cap_floor.floor_rates()[i];
+                let (price, vega, floor_std_dev): (Real, Real, Real) = optionlet(
+                    OptionType::Put,
+                    strike,
+                    forward,
+                    fixing_time,
+                    volatility,
+                    discount,
+                    volatility_type,
+                    displacement,
+                );
+                // A collar is short the floor
+                let sign: Real = if cap_floor_type == CapFloorType::Collar {
+                    -1.0
+                } else {
+                    1.0
+                };
+                value += sign * accrual * price;
+                results.vega += sign * accrual * vega;
+                if cap_floor_type == CapFloorType::Floor {
+                    std_dev = floor_std_dev;
+                }
+            }
+            results.value += value;
+            results.optionlet_values.push(value);
+            results.forwards.push(forward);
+            results.std_devs.push(std_dev);
+        }
+        results
+    }
+
+    /*
+    Rate of the coupon: its fixing on the index once fixed, otherwise the
+    simply-compounded forward over the accrual period,
+        F = (P(0, t_start) / P(0, t_end) - 1) / tau.
+    Coupons fixed before the reference date require the index.
+    */
+    fn forward_rate(
+        coupon: &IborCoupon,
+        index: Option<&IborIndex>,
+        discount_curve: &dyn YieldTermStructure,
+    ) -> Rate {
+        match index {
+            Some(index) => coupon.rate(index, discount_curve),
+            None if coupon.fixing_date() < discount_curve.reference_date() => panic!(
+                "index required for the coupon fixed on {}",
+                coupon.fixing_date()
+            ),
+            None => coupon.forward_rate(discount_curve),
+        }
+    }
+
+    // Price, vega and std dev of one optionlet per unit of nominal times accrual
+    #[allow(clippy::too_many_arguments)]
+    fn optionlet(
+        option_type: OptionType,
+        strike: Rate,
+        forward: Rate,
+        fixing_time: Time,
+        volatility: &dyn Fn(Time, Rate) -> Volatility,
+        discount: DiscountFactor,
+        volatility_type: VolatilityType,
+        displacement: Real,
+    ) -> (Real, Real, Real) {
+        if fixing_time <= 0.0 {
+            // Already fixed: intrinsic value
+            let payoff: Real = (option_type.sign() * (forward - strike)).max(0.0);
+            return (discount * payoff, 0.0, 0.0);
+        }
+        let std_dev: Real = volatility(fixing_time, strike) * fixing_time.sqrt();
+        match volatility_type {
+            VolatilityType::ShiftedLognormal => (
+                black_formula(
+                    option_type,
+                    strike,
+                    forward,
+                    std_dev,
+                    discount,
+                    displacement,
+                ),
+                black_formula_std_dev_derivative(strike, forward, std_dev, discount, displacement)
+                    * fixing_time.sqrt(),
+                std_dev,
+            ),
+            VolatilityType::Normal => (
+                bachelier_black_formula(option_type, strike, forward, std_dev, discount),
+                bachelier_black_formula_std_dev_derivative(strike, forward, std_dev, discount)
+                    * fixing_time.sqrt(),
+                std_dev,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cashflows::ibor_coupon::{IborCoupon, IborLeg};
+    use crate::indexes::ibor_index::IborIndex;
+    use crate::option::OptionType;
+    use crate::pricingengines::black_formula::black_formula;
+    use crate::termstructures::volatility::optionlet::constant_optionlet_volatility::ConstantOptionletVolatility;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::{Date, Month};
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::period::Period;
+    use crate::time::time_unit::TimeUnit;
+    use crate::types::{DiscountFactor, Rate, Time, Volatility};
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn leg(first: i32) -> Vec<IborCoupon> {
+        let schedule: Vec<Date> = (first..=10).map(|k| reference_date() + 91 * k).collect();
+        IborLeg::new(&schedule)
+            .with_notionals(&[1.0e6])
+            .coupons(&Actual365Fixed::new())
+    }
+
+    fn engine(volatility: Volatility, displacement: Real) -> BlackCapFloorEngine {
+        BlackCapFloorEngine::new(
+            flat_rate(reference_date(), 0.03),
+            Box::new(ConstantOptionletVolatility::new(
+                reference_date(),
+                Box::new(Actual365Fixed::new()),
+                volatility,
+                VolatilityType::ShiftedLognormal,
+                displacement,
+            )),
+        )
+    }
+
+    #[test]
+    fn cap_is_the_sum_of_its_caplets() {
+        let cap: CapFloor = CapFloor::cap(leg(1), &[0.032]);
+        let results: CapFloorResults = engine(0.2, 0.0).calculate(&cap);
+        let mut expected: Real = 0.0;
+        for coupon in cap.floating_leg() {
+            let t_fixing: Time = (coupon.fixing_date() - reference_date()) as Time / 365.0;
+            let t_payment: Time = (coupon.date() - reference_date()) as Time / 365.0;
+            let forward: Rate =
+                ((0.03 * (t_payment - t_fixing)).exp() - 1.0) / coupon.accrual_period();
+            let discount: DiscountFactor = (-0.03 * t_payment).exp();
+            expected += 1.0e6
+                * coupon.accrual_period()
+                * black_formula(
+                    OptionType::Call,
+                    0.032,
+                    forward,
+                    0.2 * t_fixing.sqrt(),
+                    discount,
+                    0.0,
+                );
+        }
+        assert!((results.value - expected).abs() < 1.0e-8);
+        let sum: Real = results.optionlet_values.iter().sum();
+        assert!((results.value - sum).abs() < 1.0e-8);
+        assert_eq!(results.forwards.len(), 9);
+    }
+
+    #[test]
+    fn cap_floor_parity_and_collar() {
+        for (first, displacement) in [(1, 0.0), (0, 0.01)] {
+            let engine: BlackCapFloorEngine = engine(0.3, displacement);
+            let cap: CapFloorResults = engine.calculate(&CapFloor::cap(leg(first), &[0.025]));
+            let floor: CapFloorResults = engine.calculate(&CapFloor::floor(leg(first), &[0.025]));
+            let collar: CapFloorResults =
+                engine.calculate(&CapFloor::collar(leg(first), &[0.025], &[0.025]));
+
+            // cap - floor = swap paying the strike, sum of N tau (F - K) P(0, t)
+            let swap: Real = leg(first)
+                .iter()
+                .zip(&cap.forwards)
+                .map(|(coupon, forward)| {
+                    let t: Time = (coupon.date() - reference_date()) as Time / 365.0;
+                    1.0e6 * coupon.accrual_period() * (forward - 0.025) * (-0.03 * t).exp()
+                })
+                .sum();
+            assert!((cap.value - floor.value - swap).abs() < 1.0e-6);
+            assert!((collar.value - (cap.value - floor.value)).abs() < 1.0e-6);
+            assert!((collar.vega - (cap.vega - floor.vega)).abs() < 1.0e-6);
+        }
+    }
+
+    #[test]
+    fn fixed_caplets_pay_their_fixing() {
+        // The first coupon fixed 45 days before the reference date
+        let schedule: Vec<Date> = (0..=4).map(|k| reference_date() - 45 + 91 * k).collect();
+        let mut index: IborIndex = IborIndex::euribor(Period::new(3, TimeUnit::Months));
+        let leg: Vec<IborCoupon> = IborLeg::new(&schedule)
+            .with_notionals(&[1.0e6])
+            .with_index(&index)
+            .coupons(&Actual365Fixed::new());
+        index.add_fixing(leg[0].fixing_date(), 0.04);
+        let cap: CapFloor = CapFloor::cap(leg.clone(), &[0.032]).with_index(Rc::new(index));
+        let results: CapFloorResults = engine(0.2, 0.0).calculate(&cap);
+        let discount: DiscountFactor = (-0.03 * 46.0 / 365.0_f64).exp();
+        let expected: Real = 1.0e6 * leg[0].accrual_period() * (0.04 - 0.032) * discount;
+        assert_eq!(results.forwards[0], 0.04);
+        assert!((results.optionlet_values[0] - expected).abs() < 1.0e-8);
+        assert_eq!(results.std_devs[0], 0.0);
+
+        let result = std::panic::catch_unwind(|| {
+            engine(0.2, 0.0).calculate(&CapFloor::cap(leg.clone(), &[0.032]))
+        });
+        assert!(result.is_err(), "expected panic without the cap index");
+    }
+
+    #[test]
+    fn vega_matches_finite_differences() {
+        let cap: CapFloor = CapFloor::cap(leg(1), &[0.03]);
+        let h: Volatility = 1.0e-5;
+        let up: Real = engine(0.2 + h, 0.0).calculate(&cap).value;
+        let down: Real = engine(0.2 - h, 0.0).calculate(&cap).value;
+        let vega: Real = engine(0.2, 0.0).calculate(&cap).vega;
+        assert!((vega - (up - down) / (2.0 * h)).abs() < 1.0e-4 * vega);
+    }
+
+    #[test]
+    fn new_panics_on_normal_volatilities() {
+        let result = std::panic::catch_unwind(|| {
+            BlackCapFloorEngine::new(
+                flat_rate(reference_date(), 0.03),
+                Box::new(ConstantOptionletVolatility::new(
+                    reference_date(),
+                    Box::new(Actual365Fixed::new()),
+                    0.01,
+                    VolatilityType::Normal,
+                    0.0,
+                )),
+            )
+        });
+        assert!(result.is_err(), "expected panic for normal volatilities");
+    }
+}
@@ -0,0 +1,11 @@
+use crate::types::{Rate, Real};
+
+// What the cap/floor engines compute: value, vega and the optionlet breakdown (QuantLib CapFloor::results)
+#[derive(Clone, PartialEq, Debug)]
+pub struct CapFloorResults {
+    pub value: Real,
+    pub vega: Real,
+    pub optionlet_values: Vec<Real>,
+    pub forwards: Vec<Rate>,
+    pub std_devs: Vec<Real>,
+}
@@ -0,0 +1,21 @@
+use crate::types::{Rate, Real};
+
+/*
+What the CDS engines compute (QuantLib CreditDefaultSwap::results). Leg
+values are positive present values; value is the net present value for
+the side of the contract. The fair spread is the running spread making
+a contract without upfront worth nothing, the fair upfront the one
+making the contract worth nothing at its running spread.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CdsResults {
+    pub value: Real,
+    pub default_leg_value: Real,
+    pub coupon_leg_value: Real,
+    pub upfront_value: Real,
+    pub accrual_rebate_value: Real,
+    pub fair_spread: Rate,
+    pub fair_upfront: Rate,
+    // Coupon leg value, net of the rebate, per basis point of running spread
+    pub coupon_leg_bps: Real,
+}
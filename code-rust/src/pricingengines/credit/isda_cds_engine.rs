@@ -0,0 +1,291 @@
+use crate::instruments::credit_default_swap::CreditDefaultSwap;
+use crate::pricingengines::credit::cds_results::CdsResults;
+use crate::pricingengines::credit::mid_point_cds_engine::detail::check_recovery_rate;
+use crate::termstructures::credit::default_probability_term_structure::DefaultProbabilityTermStructure;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::types::Real;
+use std::rc::Rc;
+
+/*
+CDS engine of the ISDA standard model (QuantLib IsdaCdsEngine): hazard
+and interest rates are taken flat between the nodes of the curves, so
+that the protection leg and the premium accrued on default integrate
+exactly over each sub-interval. With h = hazard + rate on [t0, t1] of
+length dt and y = h dt, the protection leg gets
+    (1 - R) N hazard dt A(y) P(t0) S(t0),      A(y) = (1 - e^-y) / y
+and the accrued premium, running linearly from the period start ts,
+    N c hazard P(t0) S(t0) [(t0 - ts) dt A(y) + dt^2 B(y)],
+with B(y) = (A(y) - e^-y) / y replaced by its Taylor expansion for
+small y (the ISDA "Taylor" numerical fix) and c the premium accrual
+per unit of time.
+
+The interest rate on each sub-interval is the forward of the discount
+curve over it. Sub-intervals split at the nodes of the default curve
+and at period ends only, so the discount curve is taken flat in
+between, as FlatForward is. Both curves must share their reference
+date and day counter, which measures all times.
+*/
+pub struct IsdaCdsEngine {
+    probability: Box<dyn DefaultProbabilityTermStructure>,
+    recovery_rate: Real,
+    discount_curve: Rc<dyn YieldTermStructure>,
+}
+impl IsdaCdsEngine {
+    // Constructor
+    pub fn new(
+        probability: Box<dyn DefaultProbabilityTermStructure>,
+        recovery_rate: Real,
+        discount_curve: Rc<dyn YieldTermStructure>,
+    ) -> IsdaCdsEngine {
+        check_recovery_rate(recovery_rate);
+        detail::check_curves(probability.as_ref(), discount_curve.as_ref());
+        IsdaCdsEngine {
+            probability,
+            recovery_rate,
+            discount_curve,
+        }
+    }
+
+    // Inspectors
+    pub fn probability(&self) -> &dyn DefaultProbabilityTermStructure {
+        self.probability.as_ref()
+    }
+    pub fn recovery_rate(&self) -> Real {
+        self.recovery_rate
+    }
+    pub fn discount_curve(&self) -> &dyn YieldTermStructure {
+        self.discount_curve.as_ref()
+    }
+
+    pub fn calculate(&self, cds: &CreditDefaultSwap) -> CdsResults {
+        detail::calculate(
+            cds,
+            self.probability.as_ref(),
+            self.recovery_rate,
+            self.discount_curve.as_ref(),
+        )
+    }
+}
+
+pub(crate) mod detail {
+    use crate::instruments::credit_default_swap::CreditDefaultSwap;
+    use crate::pricingengines::credit::cds_results::CdsResults;
+    use crate::pricingengines::credit::mid_point_cds_engine::detail::results;
+    use crate::termstructures::credit::default_probability_term_structure::DefaultProbabilityTermStructure;
+    use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+    use crate::time::date::Date;
+    use crate::types::{DiscountFactor, Probability, Rate, Real, Time};
+
+    pub(crate) fn check_curves(
+        probability: &dyn DefaultProbabilityTermStructure,
+        discount_curve: &dyn YieldTermStructure,
+    ) {
+        if discount_curve.reference_date() != probability.reference_date()
+            || discount_curve.day_counter().name() != probability.day_counter().name()
+        {
+            panic!("discount and default curves must share reference date and day counter");
+        }
+    }
+
+    pub(crate) fn calculate(
+        cds: &CreditDefaultSwap,
+        probability: &dyn DefaultProbabilityTermStructure,
+        recovery_rate: Real,
+        discount_curve: &dyn YieldTermStructure,
+    ) -> CdsResults {
+        check_curves(probability, discount_curve);
+        let reference_date: Date = probability.reference_date();
+        let time = |date: Date| -> Time { probability.time_from_reference(date) };
+        let discount = |t: Time| -> DiscountFactor { discount_curve.discount(t) };
+        let nodes: Vec<Time> = probability.node_times();
+        let protection_start: Date = cds.protection_start().max(reference_date);
+        let loss: Real = cds.notional() * (1.0 - recovery_rate);
+
+        let mut default_leg: Real = 0.0;
+        // Coupon leg value per unit of running spread
+        let mut annuity: Real = 0.0;
+        for (i, period) in cds.schedule().windows(2).enumerate() {
+            let end: Date = period[1];
+            if end <= reference_date {
+                continue;
+            }
+            let t_end: Time = time(end);
+            annuity += cds.notional()
+                * cds.accruals()[i]
+                * probability.survival_probability(t_end)
+                * discount(t_end);
+
+            let start: Date = period[0].max(protection_start);
+            if start >= end {
+                continue;
+            }
+            let t_accrual_start: Time = time(period[0]);
+            let accrual_rate: Real = cds.accruals()[i] / (t_end - t_accrual_start);
+            let t_start: Time = time(start);
+            let mut t0: Time = t_start;
+            let mut survival0: Probability = probability.survival_probability(t0);
+            for t1 in nodes
+                .iter()
+                .copied()
+                .filter(|t| *t > t_start && *t < t_end)
+                .chain([t_end])
+            {
+                let survival1: Probability = probability.survival_probability(t1);
+                let dt: Time = t1 - t0;
+                let hazard_rate: Rate = (survival0 / survival1).ln() / dt;
+                let rate: Rate = discount_curve.forward_rate(t0, t1);
+                let y: Real = (hazard_rate + rate) * dt;
+                let weight: Real = hazard_rate * discount(t0) * survival0;
+                default_leg += loss * weight * dt * a(y);
+                annuity += cds.notional()
+                    * accrual_rate
+                    * weight
+                    * ((t0 - t_accrual_start) * dt * a(y) + dt * dt * b(y));
+                t0 = t1;
+                survival0 = survival1;
+            }
+        }
+        results(cds, probability, discount_curve, default_leg, annuity)
+    }
+
+    // (1 - e^-y) / y
+    pub(super) fn a(y: Real) -> Real {
+        if y == 0.0 { 1.0 } else { -(-y).exp_m1() / y }
+    }
+
+    // (A(y) - e^-y) / y, expanded for small y to avoid cancellations
+    pub(super) fn b(y: Real) -> Real {
+        if y.abs() < 1.0e-4 {
+            0.5 - y / 3.0 + y * y / 8.0 - y * y * y / 30.0
+        } else {
+            (a(y) - (-y).exp()) / y
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruments::credit_default_swap::ProtectionSide;
+    use crate::pricingengines::credit::mid_point_cds_engine::MidPointCdsEngine;
+    use crate::termstructures::credit::flat_hazard_rate::FlatHazardRate;
+    use crate::termstructures::credit::interpolated_hazard_rate_curve::InterpolatedHazardRateCurve;
+    use crate::termstructures::yieldcurves::flat_forward::FlatForward;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::{Date, Month};
+    use crate::time::day_counter::DayCounter;
+    use crate::time::daycounters::actual360::Actual360;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::period::Period;
+    use crate::time::time_unit::TimeUnit;
+    use crate::types::{Rate, Time};
+
+    fn trade_date() -> Date {
+        Date::new(15, Month::February, 2025)
+    }
+
+    fn cds(upfront: Rate, running_spread: Rate) -> CreditDefaultSwap {
+        CreditDefaultSwap::cds2015(
+            ProtectionSide::Buyer,
+            1.0e7,
+            upfront,
+            running_spread,
+            trade_date(),
+            Period::new(5, TimeUnit::Years),
+        )
+    }
+
+    fn flat_curve(hazard_rate: Rate) -> Box<FlatHazardRate> {
+        Box::new(FlatHazardRate::new(
+            trade_date(),
+            hazard_rate,
+            Box::new(Actual365Fixed::new()),
+        ))
+    }
+
+    #[test]
+    fn protection_leg_is_exact_on_flat_curves() {
+        let (hazard_rate, rate): (Rate, Rate) = (0.02, 0.03);
+        let cds: CreditDefaultSwap = cds(0.0, 0.01);
+        let results: CdsResults =
+            IsdaCdsEngine::new(flat_curve(hazard_rate), 0.4, flat_rate(trade_date(), rate))
+                .calculate(&cds);
+        let maturity: Time = Actual365Fixed::new().year_fraction(trade_date(), cds.maturity());
+        let h: Real = hazard_rate + rate;
+        let expected: Real = 1.0e7 * 0.6 * hazard_rate / h * (1.0 - (-h * maturity).exp());
+        assert!((results.default_leg_value - expected).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn isda_and_mid_point_engines_agree() {
+        let dates: [Date; 3] = [trade_date(), trade_date() + 730, trade_date() + 2000];
+        for hazard_rates in [[0.02, 0.02, 0.02], [0.0, 0.0, 0.05], [0.01, 0.01, 0.04]] {
+            let curve = || {
+                Box::new(InterpolatedHazardRateCurve::new(
+                    &dates,
+                    &hazard_rates,
+                    Box::new(Actual365Fixed::new()),
+                ))
+            };
+            let isda: CdsResults = IsdaCdsEngine::new(curve(), 0.4, flat_rate(trade_date(), 0.03))
+                .calculate(&cds(0.01, 0.01));
+            let mid_point: CdsResults =
+                MidPointCdsEngine::new(curve(), 0.4, flat_rate(trade_date(), 0.03))
+                    .calculate(&cds(0.01, 0.01));
+            assert!(
+                (isda.fair_spread - mid_point.fair_spread).abs() < 1.0e-5,
+                "{:?}: {} vs {}",
+                hazard_rates,
+                isda.fair_spread,
+                mid_point.fair_spread
+            );
+            assert!((isda.value - mid_point.value).abs() < 1.0e-4 * 1.0e7);
+            let at_fair_upfront: CdsResults =
+                IsdaCdsEngine::new(curve(), 0.4, flat_rate(trade_date(), 0.03))
+                    .calculate(&cds(isda.fair_upfront, 0.01));
+            assert!(at_fair_upfront.value.abs() < 1.0e-6);
+        }
+    }
+
+    #[test]
+    fn new_panics_on_mismatched_curves() {
+        let result = std::panic::catch_unwind(|| {
+            IsdaCdsEngine::new(
+                flat_curve(0.02),
+                0.4,
+                Rc::new(FlatForward::new(
+                    trade_date() + 1,
+                    0.03,
+                    Box::new(Actual365Fixed::new()),
+                )),
+            )
+        });
+        assert!(
+            result.is_err(),
+            "expected panic for different reference dates"
+        );
+        let result = std::panic::catch_unwind(|| {
+            IsdaCdsEngine::new(
+                flat_curve(0.02),
+                0.4,
+                Rc::new(FlatForward::new(
+                    trade_date(),
+                    0.03,
+                    Box::new(Actual360::new()),
+                )),
+            )
+        });
+        assert!(result.is_err(), "expected panic for different day counters");
+    }
+
+    #[test]
+    fn taylor_expansion_matches_the_closed_form() {
+        for y in [5.0e-5, 9.9e-5] {
+            let closed_form: Real = (detail::a(y) - (-y).exp()) / y;
+            assert!((detail::b(y) - closed_form).abs() < 1.0e-7);
+        }
+        assert!((detail::b(0.99999e-4) - detail::b(1.00001e-4)).abs() < 1.0e-8);
+        assert_eq!(detail::b(0.0), 0.5);
+        assert_eq!(detail::a(0.0), 1.0);
+    }
+}
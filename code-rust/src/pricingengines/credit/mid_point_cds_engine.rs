@@ -0,0 +1,252 @@
+use crate::instruments::credit_default_swap::CreditDefaultSwap;
+use crate::pricingengines::credit::cds_results::CdsResults;
+use crate::termstructures::credit::default_probability_term_structure::DefaultProbabilityTermStructure;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::types::Real;
+use std::rc::Rc;
+
+/*
+Mid-point CDS engine (QuantLib MidPointCdsEngine): defaults within each
+premium period are assumed to happen at its mid-point, where the
+protection payment and the accrued premium are paid.
+
+Payments are discounted on the discount curve by date, while default
+probabilities use the times of the default curve from its reference
+date. Periods ended by that reference date are ignored, as are the
+upfront and the rebate of contracts traded before it.
+*/
+pub struct MidPointCdsEngine {
+    probability: Box<dyn DefaultProbabilityTermStructure>,
+    recovery_rate: Real,
+    discount_curve: Rc<dyn YieldTermStructure>,
+}
+impl MidPointCdsEngine {
+    // Constructor
+    pub fn new(
+        probability: Box<dyn DefaultProbabilityTermStructure>,
+        recovery_rate: Real,
+        discount_curve: Rc<dyn YieldTermStructure>,
+    ) -> MidPointCdsEngine {
+        detail::check_recovery_rate(recovery_rate);
+        MidPointCdsEngine {
+            probability,
+            recovery_rate,
+            discount_curve,
+        }
+    }
+
+    // Inspectors
+    pub fn probability(&self) -> &dyn DefaultProbabilityTermStructure {
+        self.probability.as_ref()
+    }
+    pub fn recovery_rate(&self) -> Real {
+        self.recovery_rate
+    }
+    pub fn discount_curve(&self) -> &dyn YieldTermStructure {
+        self.discount_curve.as_ref()
+    }
+
+    pub fn calculate(&self, cds: &CreditDefaultSwap) -> CdsResults {
+        detail::calculate(
+            cds,
+            self.probability.as_ref(),
+            self.recovery_rate,
+            self.discount_curve.as_ref(),
+        )
+    }
+}
+
+pub(crate) mod detail {
+    use crate::instruments::credit_default_swap::CreditDefaultSwap;
+    use crate::pricingengines::credit::cds_results::CdsResults;
+    use crate::termstructures::credit::default_probability_term_structure::DefaultProbabilityTermStructure;
+    use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+    use crate::time::date::Date;
+    use crate::types::{DiscountFactor, Probability, Rate, Real, Time};
+
+    pub(crate) fn check_recovery_rate(recovery_rate: Real) {
+        if !(0.0..1.0).contains(&recovery_rate) {
+            panic!("recovery rate ({recovery_rate}) must be in [0, 1)");
+        }
+    }
+
+    pub(crate) fn calculate(
+        cds: &CreditDefaultSwap,
+        probability: &dyn DefaultProbabilityTermStructure,
+        recovery_rate: Real,
+        discount_curve: &dyn YieldTermStructure,
+    ) -> CdsResults {
+        let reference_date: Date = probability.reference_date();
+        let time = |date: Date| -> Time { probability.time_from_reference(date) };
+        let protection_start: Date = cds.protection_start().max(reference_date);
+        let loss: Real = cds.notional() * (1.0 - recovery_rate);
+
+        let mut default_leg: Real = 0.0;
+        // Coupon leg value per unit of running spread
+        let mut annuity: Real = 0.0;
+        for (i, period) in cds.schedule().windows(2).enumerate() {
+            let end: Date = period[1];
+            if end <= reference_date {
+                continue;
+            }
+            let t_end: Time = time(end);
+            let survival_end: Probability = probability.survival_probability(t_end);
+            annuity += cds.notional()
+                * cds.accruals()[i]
+                * survival_end
+                * discount_curve.discount_date(end);
+
+            let start: Date = period[0].max(protection_start);
+            if start >= end {
+                continue;
+            }
+            let t_start: Time = time(start);
+            let default_probability: Probability =
+                probability.survival_probability(t_start) - survival_end;
+            let mid_date: Date = start + (end - start) / 2;
+            let mid_discount: DiscountFactor = discount_curve.discount_date(mid_date);
+            default_leg += loss * default_probability * mid_discount;
+            annuity += cds.notional()
+                * cds.accrued_period(i, mid_date)
+                * default_probability
+                * mid_discount;
+        }
+        results(cds, probability, discount_curve, default_leg, annuity)
+    }
+
+    /*
+    Results from the default leg value and the coupon leg value per unit
+    of running spread; the upfront and the rebate are paid on the trade
+    date, if not before the reference date.
+    */
+    pub(crate) fn results(
+        cds: &CreditDefaultSwap,
+        probability: &dyn DefaultProbabilityTermStructure,
+        discount_curve: &dyn YieldTermStructure,
+        default_leg: Real,
+        annuity: Real,
+    ) -> CdsResults {
+        let (upfront_discount, rebate_annuity): (DiscountFactor, Real) =
+            if cds.trade_date() >= probability.reference_date() {
+                let discount: DiscountFactor = discount_curve.discount_date(cds.trade_date());
+                (
+                    discount,
+                    cds.notional() * cds.accrued_period(0, cds.protection_start()) * discount,
+                )
+            } else {
+                (0.0, 0.0)
+            };
+        let spread: Rate = cds.running_spread();
+        let net_annuity: Real = annuity - rebate_annuity;
+        let upfront_value: Real = cds.upfront() * cds.notional() * upfront_discount;
+        CdsResults {
+            value: cds.side().sign() * (default_leg - spread * net_annuity - upfront_value),
+            default_leg_value: default_leg,
+            coupon_leg_value: spread * annuity,
+            upfront_value,
+            accrual_rebate_value: spread * rebate_annuity,
+            fair_spread: default_leg / net_annuity,
+            fair_upfront: if upfront_discount > 0.0 {
+                (default_leg - spread * net_annuity) / (cds.notional() * upfront_discount)
+            } else {
+                0.0
+            },
+            coupon_leg_bps: net_annuity * 1.0e-4,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruments::credit_default_swap::ProtectionSide;
+    use crate::termstructures::credit::flat_hazard_rate::FlatHazardRate;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::{Date, Month};
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::period::Period;
+    use crate::time::time_unit::TimeUnit;
+    use crate::types::Rate;
+
+    fn trade_date() -> Date {
+        Date::new(15, Month::February, 2025)
+    }
+
+    fn engine(hazard_rate: Rate, rate: Rate) -> MidPointCdsEngine {
+        MidPointCdsEngine::new(
+            Box::new(FlatHazardRate::new(
+                trade_date(),
+                hazard_rate,
+                Box::new(Actual365Fixed::new()),
+            )),
+            0.4,
+            flat_rate(trade_date(), rate),
+        )
+    }
+
+    fn cds(side: ProtectionSide, upfront: Rate, running_spread: Rate) -> CreditDefaultSwap {
+        CreditDefaultSwap::cds2015(
+            side,
+            1.0e7,
+            upfront,
+            running_spread,
+            trade_date(),
+            Period::new(5, TimeUnit::Years),
+        )
+    }
+
+    #[test]
+    fn fair_spread_follows_the_credit_triangle() {
+        // Premiums accrue Actual/360 while hazard rates are per Actual/365 year. Without
+        // discounting, premiums paid in arrears are worth as much as a continuous premium.
+        for hazard_rate in [0.005, 0.02, 0.1] {
+            let results: CdsResults =
+                engine(hazard_rate, 0.0).calculate(&cds(ProtectionSide::Buyer, 0.0, 0.01));
+            let expected: Rate = hazard_rate * 0.6 * 360.0 / 365.0;
+            assert!(
+                (results.fair_spread - expected).abs() < 1.0e-3 * expected,
+                "{}: {} vs {}",
+                hazard_rate,
+                results.fair_spread,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn contracts_at_fair_terms_are_worth_nothing() {
+        let engine: MidPointCdsEngine = engine(0.02, 0.03);
+        let results: CdsResults = engine.calculate(&cds(ProtectionSide::Buyer, 0.0, 0.01));
+        assert!(results.value > 0.0);
+        assert!(results.accrual_rebate_value > 0.0);
+        let at_fair_spread: CdsResults =
+            engine.calculate(&cds(ProtectionSide::Buyer, 0.0, results.fair_spread));
+        assert!(at_fair_spread.value.abs() < 1.0e-6);
+        let at_fair_upfront: CdsResults =
+            engine.calculate(&cds(ProtectionSide::Seller, results.fair_upfront, 0.01));
+        assert!(at_fair_upfront.value.abs() < 1.0e-6);
+        // The value is linear in the running spread
+        let wider: CdsResults = engine.calculate(&cds(ProtectionSide::Buyer, 0.0, 0.0101));
+        assert!((results.value - wider.value - results.coupon_leg_bps).abs() < 1.0e-6);
+        let seller: CdsResults = engine.calculate(&cds(ProtectionSide::Seller, 0.0, 0.01));
+        assert_eq!(seller.value, -results.value);
+    }
+
+    #[test]
+    fn new_panics_on_invalid_recovery_rate() {
+        for recovery_rate in [-0.1, 1.0] {
+            let result = std::panic::catch_unwind(|| {
+                MidPointCdsEngine::new(
+                    Box::new(FlatHazardRate::new(
+                        trade_date(),
+                        0.02,
+                        Box::new(Actual365Fixed::new()),
+                    )),
+                    recovery_rate,
+                    flat_rate(trade_date(), 0.03),
+                )
+            });
+            assert!(result.is_err(), "expected panic for {recovery_rate}");
+        }
+    }
+}
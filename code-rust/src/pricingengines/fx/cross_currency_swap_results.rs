@@ -0,0 +1,18 @@
+use crate::money::Money;
+use crate::types::{Real, Spread};
+
+/*
+What the cross-currency swap engine computes: the value in the
+constant-leg currency, the value of receiving each leg (notional
+exchanges included) in its own currency, the resetting nominals and
+the spreads on either leg that make the swap worth nothing.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct CrossCurrencySwapResults {
+    pub value: Money,
+    pub constant_leg_value: Money,
+    pub resetting_leg_value: Money,
+    pub resetting_nominals: Vec<Real>,
+    pub fair_constant_spread: Spread,
+    pub fair_resetting_spread: Spread,
+}
@@ -0,0 +1,196 @@
+use crate::cashflows::ibor_coupon::IborCoupon;
+use crate::exchange_rate::ExchangeRate;
+use crate::instruments::mtm_cross_currency_basis_swap::MtMCrossCurrencyBasisSwap;
+use crate::money::Money;
+use crate::pricingengines::fx::cross_currency_swap_results::CrossCurrencySwapResults;
+use crate::pricingengines::fx::discounting_fx_forward_engine;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::date::Date;
+use crate::types::{DiscountFactor, Rate, Real, Time};
+use std::rc::Rc;
+
+/*
+Discounting engine for mark-to-market cross-currency basis swaps
+(QuantLib DiscountingSwapEngine on the legs of the swap, as in
+MtMCrossCurrencyBasisSwapRateHelper). Each leg is projected and
+discounted on the curve of its currency; the nominal of each resetting
+period is the constant nominal converted at the forward FX rate of the
+period start, and the resetting leg is converted at the spot rate.
+
+Both curves must share their reference date. The spot rate may be
+quoted in either direction. Without FX fixings the swap must not have
+started before the reference date.
+*/
+pub struct DiscountingCrossCurrencySwapEngine {
+    constant_discount_curve: Rc<dyn YieldTermStructure>,
+    resetting_discount_curve: Rc<dyn YieldTermStructure>,
+    spot: ExchangeRate,
+}
+impl DiscountingCrossCurrencySwapEngine {
+    // Constructor
+    pub fn new(
+        constant_discount_curve: Rc<dyn YieldTermStructure>,
+        resetting_discount_curve: Rc<dyn YieldTermStructure>,
+        spot: ExchangeRate,
+    ) -> DiscountingCrossCurrencySwapEngine {
+        discounting_fx_forward_engine::detail::check_reference_dates(
+            constant_discount_curve.as_ref(),
+            resetting_discount_curve.as_ref(),
+        );
+        DiscountingCrossCurrencySwapEngine {
+            constant_discount_curve,
+            resetting_discount_curve,
+            spot,
+        }
+    }
+
+    // Inspectors
+    pub fn constant_discount_curve(&self) -> &dyn YieldTermStructure {
+        self.constant_discount_curve.as_ref()
+    }
+    pub fn resetting_discount_curve(&self) -> &dyn YieldTermStructure {
+        self.resetting_discount_curve.as_ref()
+    }
+    pub fn spot(&self) -> &ExchangeRate {
+        &self.spot
+    }
+
+    pub fn calculate(&self, swap: &MtMCrossCurrencyBasisSwap) -> CrossCurrencySwapResults {
+        let reference_date: Date = self.constant_discount_curve.reference_date();
+        if swap.start_date() < reference_date {
+            panic!(
+                "swap started ({}) before the reference date ({}): no FX fixings available",
+                swap.start_date(),
+                reference_date
+            );
+        }
+        let spot: Real = discounting_fx_forward_engine::detail::spot_rate(
+            &self.spot,
+            swap.constant_currency(),
+            swap.resetting_currency(),
+        );
+        let constant_discount =
+            |date: Date| -> DiscountFactor { self.constant_discount_curve.discount_date(date) };
+        let resetting_discount =
+            |date: Date| -> DiscountFactor { self.resetting_discount_curve.discount_date(date) };
+        let nominal: Real = swap.constant_nominal().value();
+        let coupons: &[IborCoupon] = swap.constant_leg();
+
+        // Receiving the constant leg: pay the nominal at start, get it back at maturity
+        let mut constant_leg_value: Real = nominal
+            * (constant_discount(swap.maturity_date()) - constant_discount(swap.start_date()));
+        let mut constant_annuity: Real = 0.0;
+        let mut resetting_leg_value: Real = 0.0;
+        let mut resetting_annuity: Real = 0.0;
+        let mut resetting_nominals: Vec<Real> = Vec::with_capacity(coupons.len());
+        for coupon in coupons {
+            let (start, end): (Date, Date) = (coupon.accrual_start_date(), coupon.date());
+            let tau: Time = coupon.accrual_period();
+
+            let (p_start, p_end): (DiscountFactor, DiscountFactor) =
+                (constant_discount(start), constant_discount(end));
+            let forward: Rate = (p_start / p_end - 1.0) / tau;
+            constant_leg_value += nominal * (forward + swap.constant_spread()) * tau * p_end;
+            constant_annuity += nominal * tau * p_end;
+
+            let (q_start, q_end): (DiscountFactor, DiscountFactor) =
+                (resetting_discount(start), resetting_discount(end));
+            let resetting_nominal: Real = nominal * spot * p_start / q_start;
+            let forward: Rate = (q_start / q_end - 1.0) / tau;
+            // Nominal paid at the period start and returned with the coupon at its end
+            resetting_leg_value += resetting_nominal
+                * (q_end * (1.0 + (forward + swap.resetting_spread()) * tau) - q_start);
+            resetting_annuity += resetting_nominal * tau * q_end;
+            resetting_nominals.push(resetting_nominal);
+        }
+
+        let npv: Real = resetting_leg_value / spot - constant_leg_value;
+        CrossCurrencySwapResults {
+            value: Money::new(swap.sign() * npv, swap.constant_currency()),
+            constant_leg_value: Money::new(constant_leg_value, swap.constant_currency()),
+            resetting_leg_value: Money::new(resetting_leg_value, swap.resetting_currency()),
+            resetting_nominals,
+            fair_constant_spread: swap.constant_spread() + npv / constant_annuity,
+            fair_resetting_spread: swap.resetting_spread() - npv * spot / resetting_annuity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::currencies::america::USD;
+    use crate::currencies::europe::EUR;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual360::Actual360;
+    use crate::types::Spread;
+
+    fn engine() -> DiscountingCrossCurrencySwapEngine {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        DiscountingCrossCurrencySwapEngine::new(
+            flat_rate(reference_date, 0.045),
+            flat_rate(reference_date, 0.025),
+            ExchangeRate::new(EUR, USD, 1.1),
+        )
+    }
+
+    fn swap(constant_spread: Spread, resetting_spread: Spread) -> MtMCrossCurrencyBasisSwap {
+        let start: Date = Date::new(15, Month::January, 2025) + 2;
+        let schedule: Vec<Date> = (0..=8).map(|k| start + 91 * k).collect();
+        MtMCrossCurrencyBasisSwap::new(
+            1.0e6 * USD,
+            constant_spread,
+            EUR,
+            resetting_spread,
+            &schedule,
+            &Actual360::new(),
+            true,
+        )
+    }
+
+    #[test]
+    fn legs_without_spreads_are_worth_nothing() {
+        let results: CrossCurrencySwapResults = engine().calculate(&swap(0.0, 0.0));
+        assert!(results.value.value().abs() < 1.0e-8, "{}", results.value);
+        assert_eq!(results.resetting_leg_value.currency(), EUR);
+        // First nominal: USD 1M at the forward EUR/USD rate of the start date
+        let t: Time = 2.0 / 365.0;
+        let expected: Real = 1.0e6 / 1.1 * (-0.045 * t).exp() / (-0.025 * t).exp();
+        assert!((results.resetting_nominals[0] - expected).abs() < 1.0e-6);
+        assert!(results.resetting_nominals[7] < results.resetting_nominals[0]);
+    }
+
+    #[test]
+    fn fair_spreads_make_the_swap_worth_nothing() {
+        let results: CrossCurrencySwapResults = engine().calculate(&swap(0.001, -0.002));
+        assert!(results.value.value() < 0.0);
+        assert!(
+            (results.constant_leg_value.value() - 0.001 * 1.0e6 * 8.0 * 91.0 / 360.0).abs() < 100.0
+        );
+        let at_fair_constant: CrossCurrencySwapResults =
+            engine().calculate(&swap(results.fair_constant_spread, -0.002));
+        assert!(at_fair_constant.value.value().abs() < 1.0e-6);
+        let at_fair_resetting: CrossCurrencySwapResults =
+            engine().calculate(&swap(0.001, results.fair_resetting_spread));
+        assert!(at_fair_resetting.value.value().abs() < 1.0e-6);
+        // The legs have about the same annuity, so the fair spreads move by opposite amounts
+        assert!(
+            (results.fair_resetting_spread + results.fair_constant_spread + 0.001).abs() < 1.0e-4
+        );
+    }
+
+    #[test]
+    fn calculate_panics_on_seasoned_swaps() {
+        let result = std::panic::catch_unwind(|| {
+            let reference_date: Date = Date::new(15, Month::March, 2025);
+            DiscountingCrossCurrencySwapEngine::new(
+                flat_rate(reference_date, 0.045),
+                flat_rate(reference_date, 0.025),
+                ExchangeRate::new(EUR, USD, 1.1),
+            )
+            .calculate(&swap(0.0, 0.0))
+        });
+        assert!(result.is_err(), "expected panic for a seasoned swap");
+    }
+}
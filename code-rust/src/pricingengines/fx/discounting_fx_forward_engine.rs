@@ -0,0 +1,196 @@
+use crate::currency::Currency;
+use crate::exchange_rate::ExchangeRate;
+use crate::instruments::fx_forward::FxForward;
+use crate::money::Money;
+use crate::pricingengines::fx::fx_forward_results::FxForwardResults;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::types::{DiscountFactor, Real};
+use std::rc::Rc;
+
+/*
+Discounting engine for FX forwards (QuantLib DiscountingFxForwardEngine):
+each nominal is discounted on the curve of its currency and the source
+leg is converted at the spot rate.
+
+Both curves must share their reference date. The spot rate may be
+quoted in either direction. Forwards settled before the reference date
+are worth nothing, with the spot rate as fair forward rate.
+*/
+pub struct DiscountingFxForwardEngine {
+    source_discount_curve: Rc<dyn YieldTermStructure>,
+    target_discount_curve: Rc<dyn YieldTermStructure>,
+    spot: ExchangeRate,
+}
+impl DiscountingFxForwardEngine {
+    // Constructor
+    pub fn new(
+        source_discount_curve: Rc<dyn YieldTermStructure>,
+        target_discount_curve: Rc<dyn YieldTermStructure>,
+        spot: ExchangeRate,
+    ) -> DiscountingFxForwardEngine {
+        detail::check_reference_dates(
+            source_discount_curve.as_ref(),
+            target_discount_curve.as_ref(),
+        );
+        DiscountingFxForwardEngine {
+            source_discount_curve,
+            target_discount_curve,
+            spot,
+        }
+    }
+
+    // Inspectors
+    pub fn source_discount_curve(&self) -> &dyn YieldTermStructure {
+        self.source_discount_curve.as_ref()
+    }
+    pub fn target_discount_curve(&self) -> &dyn YieldTermStructure {
+        self.target_discount_curve.as_ref()
+    }
+    pub fn spot(&self) -> &ExchangeRate {
+        &self.spot
+    }
+
+    pub fn calculate(&self, forward: &FxForward) -> FxForwardResults {
+        let spot: Real = detail::spot_rate(
+            &self.spot,
+            forward.source_currency(),
+            forward.target_currency(),
+        );
+        let (source_discount, target_discount): (DiscountFactor, DiscountFactor) =
+            if forward.maturity_date() >= self.source_discount_curve.reference_date() {
+                (
+                    self.source_discount_curve
+                        .discount_date(forward.maturity_date()),
+                    self.target_discount_curve
+                        .discount_date(forward.maturity_date()),
+                )
+            } else {
+                (0.0, 0.0)
+            };
+        let fair_forward_rate: Real = if source_discount > 0.0 {
+            spot * source_discount / target_discount
+        } else {
+            spot
+        };
+        let source_leg_value: Money = forward.source_nominal() * source_discount;
+        let target_leg_value: Money = forward.target_nominal() * target_discount;
+        let sign: Real = if forward.pay_source_receive_target() {
+            1.0
+        } else {
+            -1.0
+        };
+        FxForwardResults {
+            value: Money::new(
+                sign * (target_leg_value.value() - spot * source_leg_value.value()),
+                forward.target_currency(),
+            ),
+            source_leg_value,
+            target_leg_value,
+            fair_forward_rate: ExchangeRate::new(
+                forward.source_currency(),
+                forward.target_currency(),
+                fair_forward_rate,
+            ),
+        }
+    }
+}
+
+pub(crate) mod detail {
+    use super::*;
+
+    pub(crate) fn check_reference_dates(
+        curve: &dyn YieldTermStructure,
+        other: &dyn YieldTermStructure,
+    ) {
+        if curve.reference_date() != other.reference_date() {
+            panic!(
+                "discount curves have different reference dates ({} and {})",
+                curve.reference_date(),
+                other.reference_date()
+            );
+        }
+    }
+
+    // Units of target currency per unit of source currency
+    pub(crate) fn spot_rate(spot: &ExchangeRate, source: Currency, target: Currency) -> Real {
+        let quoted_on_pair: bool = (spot.source() == source && spot.target() == target)
+            || (spot.source() == target && spot.target() == source);
+        if !quoted_on_pair {
+            panic!("spot rate {spot} does not quote {source}/{target}");
+        }
+        spot.exchange(&Money::new(1.0, source)).value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::currencies::america::USD;
+    use crate::currencies::asia::JPY;
+    use crate::currencies::europe::EUR;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::{Date, Month};
+
+    fn engine(spot: ExchangeRate) -> DiscountingFxForwardEngine {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        DiscountingFxForwardEngine::new(
+            flat_rate(reference_date, 0.02),
+            flat_rate(reference_date, 0.04),
+            spot,
+        )
+    }
+
+    #[test]
+    fn forward_at_the_fair_rate_is_worth_nothing() {
+        let maturity: Date = Date::new(15, Month::January, 2025) + 365;
+        // EUR/USD spot quoted either way round
+        for spot in [
+            ExchangeRate::new(EUR, USD, 1.1),
+            ExchangeRate::new(USD, EUR, 1.0 / 1.1),
+        ] {
+            let engine: DiscountingFxForwardEngine = engine(spot);
+            let at_spot: FxForward = FxForward::new(1.0e6 * EUR, 1.1e6 * USD, maturity, true);
+            let results: FxForwardResults = engine.calculate(&at_spot);
+            // Covered interest parity: F = S exp((r_target - r_source) T)
+            let fair: Real = 1.1 * 0.02_f64.exp();
+            assert!((results.fair_forward_rate.rate() - fair).abs() < 1.0e-12);
+            assert_eq!(results.value.currency(), USD);
+            let expected: Real = 1.1e6 * ((-0.04_f64).exp() - (-0.02_f64).exp());
+            assert!((results.value.value() - expected).abs() < 1.0e-6);
+
+            let at_fair: FxForward = FxForward::with_forward_rate(
+                1.0e6 * EUR,
+                &results.fair_forward_rate,
+                maturity,
+                false,
+            );
+            assert!(engine.calculate(&at_fair).value.value().abs() < 1.0e-8);
+            let receiver: FxForward = FxForward::new(1.0e6 * EUR, 1.1e6 * USD, maturity, false);
+            assert_eq!(engine.calculate(&receiver).value, -results.value);
+        }
+    }
+
+    #[test]
+    fn invalid_input_panics() {
+        let result = std::panic::catch_unwind(|| {
+            engine(ExchangeRate::new(EUR, JPY, 160.0)).calculate(&FxForward::new(
+                1.0e6 * EUR,
+                1.1e6 * USD,
+                Date::new(15, Month::January, 2026),
+                true,
+            ))
+        });
+        assert!(result.is_err(), "expected panic for an EUR/JPY spot");
+        let result = std::panic::catch_unwind(|| {
+            DiscountingFxForwardEngine::new(
+                flat_rate(Date::new(15, Month::January, 2025), 0.02),
+                flat_rate(Date::new(16, Month::January, 2025), 0.04),
+                ExchangeRate::new(EUR, USD, 1.1),
+            )
+        });
+        assert!(
+            result.is_err(),
+            "expected panic for different reference dates"
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use crate::exchange_rate::ExchangeRate;
+use crate::money::Money;
+
+/*
+What the FX forward engine computes (QuantLib FxForward::results): the
+value in the target currency, the discounted nominals in their own
+currencies and the fair forward rate, in target units per source unit.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct FxForwardResults {
+    pub value: Money,
+    pub source_leg_value: Money,
+    pub target_leg_value: Money,
+    pub fair_forward_rate: ExchangeRate,
+}
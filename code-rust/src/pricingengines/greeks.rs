@@ -0,0 +1,56 @@
+use crate::pricingengines::vanilla::vanilla_option_results::VanillaOptionResults;
+use crate::types::Real;
+
+const RELATIVE_BUMP: Real = 1.0e-4;
+
+/*
+Spot delta and gamma of a closed-form price by central differences, for
+the analytic engines whose formulas have no handy derivatives (QuantLib
+checks those engines in the same way): the price is revalued at
+spot (1 +- h), with a relative bump h of 1e-4.
+*/
+pub(crate) fn spot_greeks<F: Fn(Real) -> Real>(price: F, spot: Real) -> VanillaOptionResults {
+    let h: Real = spot * RELATIVE_BUMP;
+    let (p_down, p, p_up): (Real, Real, Real) = (price(spot - h), price(spot), price(spot + h));
+    VanillaOptionResults {
+        value: p,
+        delta: (p_up - p_down) / (2.0 * h),
+        gamma: (p_up - 2.0 * p + p_down) / (h * h),
+    }
+}
+
+// Deltas of a multi-asset price, bumping one spot at a time in the same way
+pub(crate) fn spot_deltas<F: Fn(&[Real]) -> Real>(price: F, spots: &[Real]) -> Vec<Real> {
+    let mut bumped: Vec<Real> = spots.to_vec();
+    (0..spots.len())
+        .map(|i| {
+            let h: Real = spots[i] * RELATIVE_BUMP;
+            bumped[i] = spots[i] + h;
+            let p_up: Real = price(&bumped);
+            bumped[i] = spots[i] - h;
+            let p_down: Real = price(&bumped);
+            bumped[i] = spots[i];
+            (p_up - p_down) / (2.0 * h)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn greeks_of_a_quadratic_are_exact() {
+        let results: VanillaOptionResults = spot_greeks(|s| 3.0 * s * s + 2.0 * s, 100.0);
+        assert_eq!(results.value, 30200.0);
+        assert!((results.delta - 602.0).abs() < 1.0e-6);
+        assert!((results.gamma - 6.0).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn deltas_of_a_product_are_exact() {
+        let deltas: Vec<Real> = spot_deltas(|s| s[0] * s[1] * s[1], &[2.0, 3.0]);
+        assert!((deltas[0] - 9.0).abs() < 1.0e-9);
+        assert!((deltas[1] - 12.0).abs() < 1.0e-9);
+    }
+}
@@ -0,0 +1,9 @@
+use crate::time::date::Date;
+use crate::types::Real;
+
+// What the CPI bond engine computes: value and projected cash flows (QuantLib Bond::results)
+#[derive(Clone, PartialEq, Debug)]
+pub struct CpiBondResults {
+    pub value: Real,
+    pub cash_flows: Vec<(Date, Real)>,
+}
@@ -0,0 +1,105 @@
+use crate::instruments::cpi_bond::CpiBond;
+use crate::pricingengines::inflation::cpi_bond_results::CpiBondResults;
+use crate::termstructures::inflation::zero_inflation_term_structure::ZeroInflationTermStructure;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::date::Date;
+use crate::types::Real;
+use std::rc::Rc;
+
+/*
+Discounting engine for CPI bonds (QuantLib DiscountingBondEngine on a
+CPIBond): CPI fixings not yet published are forecast on the zero
+inflation curve and every cash flow paid after the reference date of
+the discount curve is discounted on it.
+*/
+pub struct DiscountingCpiBondEngine {
+    discount_curve: Rc<dyn YieldTermStructure>,
+    inflation: Box<dyn ZeroInflationTermStructure>,
+}
+impl DiscountingCpiBondEngine {
+    // Constructor
+    pub fn new(
+        discount_curve: Rc<dyn YieldTermStructure>,
+        inflation: Box<dyn ZeroInflationTermStructure>,
+    ) -> DiscountingCpiBondEngine {
+        DiscountingCpiBondEngine {
+            discount_curve,
+            inflation,
+        }
+    }
+
+    // Inspectors
+    pub fn discount_curve(&self) -> &dyn YieldTermStructure {
+        self.discount_curve.as_ref()
+    }
+    pub fn inflation(&self) -> &dyn ZeroInflationTermStructure {
+        self.inflation.as_ref()
+    }
+
+    pub fn calculate(&self, bond: &CpiBond) -> CpiBondResults {
+        let reference_date: Date = self.discount_curve.reference_date();
+        let cash_flows: Vec<(Date, Real)> = bond
+            .cash_flows(Some(self.inflation.as_ref()))
+            .into_iter()
+            .filter(|(date, _)| *date > reference_date)
+            .collect();
+        let value: Real = cash_flows
+            .iter()
+            .map(|(date, amount)| amount * self.discount_curve.discount_date(*date))
+            .sum();
+        CpiBondResults { value, cash_flows }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexes::inflation_index::{CpiInterpolationType, ZeroInflationIndex};
+    use crate::termstructures::inflation::interpolated_zero_inflation_curve::InterpolatedZeroInflationCurve;
+    use crate::termstructures::yieldcurves::flat_forward::FlatForward;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::frequency::Frequency;
+    use crate::time::period::Period;
+    use crate::time::time_unit::TimeUnit;
+
+    #[test]
+    fn bond_paying_the_real_rate_is_worth_par() {
+        // With 2% inflation, a 2% real coupon discounted at the matching nominal rate
+        // is worth par, up to the leap day in the accruals
+        let base_date: Date = Date::new(1, Month::January, 2025);
+        let mut index: ZeroInflationIndex =
+            ZeroInflationIndex::new("CPI", Frequency::Annual, Period::new(1, TimeUnit::Months));
+        index.add_fixing(base_date, 100.0);
+        let schedule: Vec<Date> = (0..6)
+            .map(|k| Date::new(1, Month::January, 2025 + k))
+            .collect();
+        let bond: CpiBond = CpiBond::new(
+            100.0,
+            100.0,
+            Period::new(0, TimeUnit::Months),
+            index,
+            CpiInterpolationType::Flat,
+            &schedule,
+            0.02,
+            &Actual365Fixed::new(),
+        );
+        let engine: DiscountingCpiBondEngine = DiscountingCpiBondEngine::new(
+            Rc::new(FlatForward::new(
+                base_date,
+                (1.02_f64 * 1.02).ln(),
+                Box::new(Actual365Fixed::new()),
+            )),
+            Box::new(InterpolatedZeroInflationCurve::new(
+                base_date,
+                &[base_date, Date::new(1, Month::January, 2035)],
+                &[0.02, 0.02],
+                Frequency::Annual,
+                Box::new(Actual365Fixed::new()),
+            )),
+        );
+        let results: CpiBondResults = engine.calculate(&bond);
+        assert_eq!(results.cash_flows.len(), 6);
+        assert!((results.value - 100.0).abs() < 0.01, "{}", results.value);
+    }
+}
@@ -0,0 +1,10 @@
+use crate::types::{Rate, Real};
+
+// What the inflation swap engine computes (QuantLib ZeroCouponInflationSwap::results)
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct InflationSwapResults {
+    pub value: Real,
+    pub fixed_leg_value: Real,
+    pub inflation_leg_value: Real,
+    pub fair_rate: Rate,
+}
@@ -0,0 +1,132 @@
+use crate::instruments::zero_coupon_inflation_swap::ZeroCouponInflationSwap;
+use crate::pricingengines::inflation::inflation_swap_results::InflationSwapResults;
+use crate::termstructures::inflation::zero_inflation_term_structure::ZeroInflationTermStructure;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::types::{DiscountFactor, Rate, Real};
+use std::rc::Rc;
+
+/*
+Discounting engine for zero-coupon inflation swaps (QuantLib
+DiscountingSwapEngine on a ZeroCouponInflationSwap): CPI fixings not
+yet published are forecast on the zero inflation curve, both legs are
+discounted from maturity on the discount curve. Swaps paid by the
+reference date of the discount curve are worth nothing.
+*/
+pub struct ZeroCouponInflationSwapEngine {
+    discount_curve: Rc<dyn YieldTermStructure>,
+    inflation: Box<dyn ZeroInflationTermStructure>,
+}
+impl ZeroCouponInflationSwapEngine {
+    // Constructor
+    pub fn new(
+        discount_curve: Rc<dyn YieldTermStructure>,
+        inflation: Box<dyn ZeroInflationTermStructure>,
+    ) -> ZeroCouponInflationSwapEngine {
+        ZeroCouponInflationSwapEngine {
+            discount_curve,
+            inflation,
+        }
+    }
+
+    // Inspectors
+    pub fn discount_curve(&self) -> &dyn YieldTermStructure {
+        self.discount_curve.as_ref()
+    }
+    pub fn inflation(&self) -> &dyn ZeroInflationTermStructure {
+        self.inflation.as_ref()
+    }
+
+    pub fn calculate(&self, swap: &ZeroCouponInflationSwap) -> InflationSwapResults {
+        detail::calculate(swap, self.discount_curve.as_ref(), self.inflation.as_ref())
+    }
+}
+
+pub(crate) mod detail {
+    use super::*;
+
+    pub(crate) fn calculate(
+        swap: &ZeroCouponInflationSwap,
+        discount_curve: &dyn YieldTermStructure,
+        inflation: &dyn ZeroInflationTermStructure,
+    ) -> InflationSwapResults {
+        let fair_rate: Rate = swap.fair_rate(Some(inflation));
+        let discount: DiscountFactor = if swap.maturity_date() > discount_curve.reference_date() {
+            discount_curve.discount_date(swap.maturity_date())
+        } else {
+            0.0
+        };
+        let fixed_leg_value: Real = swap.fixed_leg_amount() * discount;
+        let inflation_leg_value: Real = swap.inflation_leg_amount(Some(inflation)) * discount;
+        InflationSwapResults {
+            value: swap.swap_type().sign() * (inflation_leg_value - fixed_leg_value),
+            fixed_leg_value,
+            inflation_leg_value,
+            fair_rate,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexes::inflation_index::{CpiInterpolationType, ZeroInflationIndex};
+    use crate::instruments::swap::SwapType;
+    use crate::termstructures::inflation::interpolated_zero_inflation_curve::InterpolatedZeroInflationCurve;
+    use crate::termstructures::yieldcurves::flat_forward::FlatForward;
+    use crate::time::date::{Date, Month};
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::frequency::Frequency;
+    use crate::time::period::Period;
+    use crate::time::time_unit::TimeUnit;
+
+    #[test]
+    fn legs_are_discounted_from_maturity() {
+        let mut index: ZeroInflationIndex =
+            ZeroInflationIndex::new("CPI", Frequency::Monthly, Period::new(1, TimeUnit::Months));
+        index.add_fixing(Date::new(1, Month::January, 2025), 100.0);
+        let reference_date: Date = Date::new(1, Month::April, 2025);
+        let engine: ZeroCouponInflationSwapEngine = ZeroCouponInflationSwapEngine::new(
+            Rc::new(FlatForward::new(
+                reference_date,
+                0.03,
+                Box::new(Actual365Fixed::new()),
+            )),
+            Box::new(InterpolatedZeroInflationCurve::new(
+                reference_date,
+                &[
+                    Date::new(1, Month::January, 2025),
+                    Date::new(1, Month::January, 2035),
+                ],
+                &[0.02, 0.03],
+                Frequency::Monthly,
+                Box::new(Actual365Fixed::new()),
+            )),
+        );
+        let swap = |swap_type: SwapType, fixed_rate: Rate| -> ZeroCouponInflationSwap {
+            ZeroCouponInflationSwap::new(
+                swap_type,
+                1.0e6,
+                reference_date,
+                Date::new(1, Month::April, 2032),
+                &Actual365Fixed::new(),
+                fixed_rate,
+                index.clone(),
+                Period::new(3, TimeUnit::Months),
+                CpiInterpolationType::Flat,
+            )
+        };
+        let payer: InflationSwapResults = engine.calculate(&swap(SwapType::Payer, 0.02));
+        let discount: DiscountFactor = (-0.03 * 2557.0 / 365.0_f64).exp();
+        assert!(
+            (payer.fixed_leg_value - discount * 1.0e6 * (1.02_f64.powf(2557.0 / 365.0) - 1.0))
+                .abs()
+                < 1.0e-6
+        );
+        assert!(payer.value > 0.0);
+        let receiver: InflationSwapResults = engine.calculate(&swap(SwapType::Receiver, 0.02));
+        assert_eq!(receiver.value, -payer.value);
+        let at_fair: InflationSwapResults =
+            engine.calculate(&swap(SwapType::Payer, payer.fair_rate));
+        assert!(at_fair.value.abs() < 1.0e-6);
+    }
+}
@@ -0,0 +1,211 @@
+use crate::exercise::Exercise;
+use crate::instruments::lookback_option::ContinuousFixedLookbackOption;
+use crate::instruments::payoffs::StrikedTypePayoff;
+use crate::option::OptionType;
+use crate::pricingengines::greeks::spot_greeks;
+use crate::pricingengines::vanilla::vanilla_option_results::VanillaOptionResults;
+use crate::processes::black_scholes_process::BlackScholesMertonProcess;
+use crate::stochastic_process::StochasticProcess1D;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Real, Time};
+
+/*
+Analytic engine for continuous fixed-strike lookback options (QuantLib
+AnalyticContinuousFixedLookbackEngine), with the Conze-Viswanathan
+formula as given by Haug. When the strike is beyond the running
+extremum (K > max for a call, K < min for a put) the option is priced
+with the strike as the reference level X; otherwise the extremum is
+the reference and its intrinsic value exp(-r T) |extremum - K| is
+added. With b = r - q and d1 = (ln(S / X) + (b + sigma^2 / 2) T) /
+(sigma sqrt(T)), a call is worth
+    S exp(-q T) N(d1) - X exp(-r T) N(d1 - sigma sqrt(T))
+    + S exp(-r T) sigma^2 / (2 b) [-(S / X)^(-2 b / sigma^2)
+        N(d1 - 2 b sqrt(T) / sigma) + exp(b T) N(d1)]
+on top of that intrinsic value. The formula needs a non-zero cost of
+carry b. Delta and gamma come from central differences of the formula.
+*/
+pub struct AnalyticContinuousFixedLookbackEngine {
+    process: BlackScholesMertonProcess,
+    reference_date: Date,
+    day_counter: Box<dyn DayCounter>,
+}
+impl AnalyticContinuousFixedLookbackEngine {
+    // Constructor
+    pub fn new(
+        process: BlackScholesMertonProcess,
+        reference_date: Date,
+        day_counter: Box<dyn DayCounter>,
+    ) -> AnalyticContinuousFixedLookbackEngine {
+        if process.risk_free_rate() == process.dividend_yield() {
+            panic!("null cost of carry not supported");
+        }
+        AnalyticContinuousFixedLookbackEngine {
+            process,
+            reference_date,
+            day_counter,
+        }
+    }
+
+    pub fn calculate(&self, option: &ContinuousFixedLookbackOption) -> VanillaOptionResults {
+        let maturity: Time = self
+            .day_counter
+            .year_fraction(self.reference_date, option.exercise().last_date());
+        if maturity <= 0.0 {
+            panic!("option expired: maturity {maturity} before the reference date");
+        }
+        let spot: Real = self.process.x0();
+        let option_type: OptionType = option.payoff().option_type();
+        if option_type.sign() * (option.minmax() - spot) < 0.0 {
+            panic!(
+                "running {} ({}) inconsistent with the spot ({})",
+                if option_type == OptionType::Call {
+                    "maximum"
+                } else {
+                    "minimum"
+                },
+                option.minmax(),
+                spot
+            );
+        }
+        spot_greeks(
+            |s| {
+                detail::value(
+                    option_type,
+                    option.payoff().strike(),
+                    option.minmax(),
+                    s,
+                    &self.process,
+                    maturity,
+                )
+            },
+            spot,
+        )
+    }
+}
+
+pub(crate) mod detail {
+    use super::*;
+    use crate::math::distributions::normal_distribution::CumulativeNormalDistribution;
+
+    pub(crate) fn value(
+        option_type: OptionType,
+        strike: Real,
+        minmax: Real,
+        spot: Real,
+        process: &BlackScholesMertonProcess,
+        maturity: Time,
+    ) -> Real {
+        let (r, q, sigma): (Real, Real, Real) = (
+            process.risk_free_rate(),
+            process.dividend_yield(),
+            process.volatility(),
+        );
+        let b: Real = r - q;
+        let std_dev: Real = sigma * maturity.sqrt();
+        let n = |x: Real| -> Real { CumulativeNormalDistribution::new(0.0, 1.0).value(x) };
+        let phi: Real = option_type.sign();
+        let discount: Real = (-r * maturity).exp();
+
+        // Strike beyond the extremum: no intrinsic value yet
+        let (level, intrinsic): (Real, Real) = if phi * (strike - minmax) > 0.0 {
+            (strike, 0.0)
+        } else {
+            (minmax, discount * phi * (minmax - strike))
+        };
+        let d1: Real = ((spot / level).ln() + (b + 0.5 * sigma * sigma) * maturity) / std_dev;
+        let d2: Real = d1 - std_dev;
+        let reflection: Real = -(spot / level).powf(-2.0 * b / (sigma * sigma))
+            * n(phi * (d1 - 2.0 * b * maturity.sqrt() / sigma))
+            + (b * maturity).exp() * n(phi * d1);
+        intrinsic
+            + phi * (spot * (-q * maturity).exp() * n(phi * d1) - level * discount * n(phi * d2))
+            + phi * spot * discount * sigma * sigma / (2.0 * b) * reflection
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::EuropeanExercise;
+    use crate::instruments::lookback_option::ContinuousFloatingLookbackOption;
+    use crate::instruments::payoffs::{FloatingTypePayoff, PlainVanillaPayoff};
+    use crate::pricingengines::black_formula::black_formula;
+    use crate::pricingengines::lookback::analytic_continuous_floating_lookback_engine::AnalyticContinuousFloatingLookbackEngine;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual360::Actual360;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn process() -> BlackScholesMertonProcess {
+        BlackScholesMertonProcess::new(100.0, 0.1, 0.04, 0.3)
+    }
+
+    fn fixed(option_type: OptionType, strike: Real, minmax: Real) -> Real {
+        AnalyticContinuousFixedLookbackEngine::new(
+            process(),
+            reference_date(),
+            Box::new(Actual360::new()),
+        )
+        .calculate(&ContinuousFixedLookbackOption::new(
+            minmax,
+            PlainVanillaPayoff::new(option_type, strike),
+            EuropeanExercise::new(reference_date() + 180),
+        ))
+        .value
+    }
+
+    #[test]
+    fn fixed_and_floating_lookbacks_are_at_parity() {
+        // With the strike inside the range, max(M_T, K) - K = (M_T - S_T) + S_T - K
+        let forward_value: Real = 100.0 * (-0.02_f64).exp();
+        let floating = |option_type: OptionType, minmax: Real| -> Real {
+            AnalyticContinuousFloatingLookbackEngine::new(
+                process(),
+                reference_date(),
+                Box::new(Actual360::new()),
+            )
+            .calculate(&ContinuousFloatingLookbackOption::new(
+                minmax,
+                FloatingTypePayoff::new(option_type),
+                EuropeanExercise::new(reference_date() + 180),
+            ))
+            .value
+        };
+        let cases: [(OptionType, Real, Real, OptionType); 2] = [
+            (OptionType::Call, 95.0, 110.0, OptionType::Put),
+            (OptionType::Put, 105.0, 90.0, OptionType::Call),
+        ];
+        for (option_type, strike, minmax, floating_type) in cases {
+            let expected: Real = floating(floating_type, minmax)
+                + option_type.sign() * (forward_value - strike * (-0.05_f64).exp());
+            let value: Real = fixed(option_type, strike, minmax);
+            assert!(
+                (value - expected).abs() < 1.0e-10,
+                "{option_type}: {value} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn fixed_lookback_is_worth_more_than_the_vanilla() {
+        for (option_type, strike) in [(OptionType::Call, 110.0), (OptionType::Put, 90.0)] {
+            let vanilla: Real = black_formula(
+                option_type,
+                strike,
+                100.0 * 0.03_f64.exp(),
+                0.3 * 0.5_f64.sqrt(),
+                (-0.05_f64).exp(),
+                0.0,
+            );
+            let value: Real = fixed(option_type, strike, 100.0);
+            assert!(value > vanilla, "{option_type}: {value} vs {vanilla}");
+            // Continuity at the extremum
+            let at_extremum: Real = fixed(option_type, 100.0, 100.0);
+            let beyond: Real = fixed(option_type, 100.0 + option_type.sign() * 1.0e-9, 100.0);
+            assert!((at_extremum - beyond).abs() < 1.0e-6);
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use crate::types::{Real, Size};
+
+// What the Monte Carlo engines compute: the simulated value and its standard error
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct McResults {
+    pub value: Real,
+    pub error_estimate: Real,
+    pub samples: Size,
+}
@@ -0,0 +1,153 @@
+use crate::math::statistics::incremental_statistics::IncrementalStatistics;
+use crate::math::statistics::sample_accumulator::SampleAccumulator;
+use crate::methods::montecarlo::mc_traits::SamplePathGenerator;
+use crate::methods::montecarlo::monte_carlo_model::MonteCarloModel;
+use crate::methods::montecarlo::path_pricer::PathPricer;
+use crate::types::{Real, Size};
+
+/*
+Monte Carlo simulation driver (QuantLib McSimulation).
+
+Runs a Monte Carlo model either for a fixed number of samples or until
+the error estimate of the mean falls below a tolerance:
+    - at least min_samples paths are drawn first
+    - since the error goes as 1 / sqrt(n), the number of samples needed
+      is estimated as n * (error / tolerance)^2, and 80% of the missing
+      samples are added per batch (at least min_samples)
+    - the simulation panics if max_samples is reached first
+*/
+pub struct McSimulation<
+    G: SamplePathGenerator,
+    P: PathPricer<G::PathType>,
+    S: SampleAccumulator = IncrementalStatistics,
+> {
+    mc_model: MonteCarloModel<G, P, S>,
+}
+impl<G: SamplePathGenerator, P: PathPricer<G::PathType>, S: SampleAccumulator>
+    McSimulation<G, P, S>
+{
+    const DEFAULT_MIN_SAMPLES: Size = 1023;
+
+    // Constructor
+    pub fn new(mc_model: MonteCarloModel<G, P, S>) -> McSimulation<G, P, S> {
+        McSimulation { mc_model }
+    }
+
+    // Simulated value with an error estimate below tolerance
+    pub fn value(&mut self, tolerance: Real, max_samples: Size, min_samples: Option<Size>) -> Real {
+        let min_samples: Size = min_samples.unwrap_or(McSimulation::<G, P, S>::DEFAULT_MIN_SAMPLES);
+        let mut sample_number: Size = self.sample_accumulator().samples();
+        if sample_number < min_samples {
+            self.mc_model.add_samples(min_samples - sample_number);
+            sample_number = self.sample_accumulator().samples();
+        }
+
+        let mut error: Real = self.error_estimate();
+        while error > tolerance {
+            if sample_number >= max_samples {
+                panic!(
+                    "max number of samples ({max_samples}) reached, while error ({error}) is still above tolerance ({tolerance})"
+                );
+            }
+            // Conservative estimate of how many samples are needed
+            let order: Real = error * error / tolerance / tolerance;
+            let next_batch: Real = (sample_number as Real * order * 0.8 - sample_number as Real)
+                .max(min_samples as Real);
+            // Do not exceed max_samples
+            let next_batch: Size = (next_batch as Size).min(max_samples - sample_number);
+            sample_number += next_batch;
+            self.mc_model.add_samples(next_batch);
+            error = self.error_estimate();
+        }
+        self.sample_accumulator().mean()
+    }
+
+    // Simulated value over exactly the given number of samples
+    pub fn value_with_samples(&mut self, samples: Size) -> Real {
+        let sample_number: Size = self.sample_accumulator().samples();
+        if samples < sample_number {
+            panic!(
+                "number of already simulated samples ({sample_number}) greater than requested samples ({samples})"
+            );
+        }
+        self.mc_model.add_samples(samples - sample_number);
+        self.sample_accumulator().mean()
+    }
+
+    // Inspectors
+    pub fn error_estimate(&self) -> Real {
+        self.sample_accumulator().error_estimate()
+    }
+    pub fn sample_accumulator(&self) -> &S {
+        self.mc_model.sample_accumulator()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::randomnumbers::rng_traits::{PseudoRandom, PseudoRandomRsg};
+    use crate::methods::montecarlo::path::Path;
+    use crate::methods::montecarlo::path_generator::PathGenerator;
+    use crate::processes::geometric_brownian_process::GeometricBrownianMotionProcess;
+    use crate::time_grid::TimeGrid;
+    use std::panic;
+
+    type Generator = PathGenerator<GeometricBrownianMotionProcess, PseudoRandomRsg>;
+
+    fn simulation(seed: u64) -> McSimulation<Generator, fn(&Path) -> Real> {
+        let generator: Generator = PathGenerator::new(
+            GeometricBrownianMotionProcess::new(100.0, 0.05, 0.2),
+            TimeGrid::new(1.0, 4),
+            PseudoRandom::make_sequence_generator(4, seed),
+            false,
+        );
+        let terminal_value: fn(&Path) -> Real = |path| path.back();
+        McSimulation::new(MonteCarloModel::new(
+            generator,
+            terminal_value,
+            IncrementalStatistics::new(),
+            false,
+            None,
+            0.0,
+            None,
+        ))
+    }
+
+    #[test]
+    fn value_runs_until_tolerance_is_met() {
+        let mut mc: McSimulation<Generator, fn(&Path) -> Real> = simulation(42);
+        let value: Real = mc.value(0.1, 1_000_000, None);
+        let expected: Real = 100.0 * (1.0 + 0.05 * 0.25_f64).powi(4);
+
+        assert!(mc.error_estimate() <= 0.1);
+        assert!(mc.sample_accumulator().samples() > 1023);
+        assert!((value - expected).abs() < 0.4);
+    }
+
+    #[test]
+    fn value_with_samples_is_deterministic() {
+        let mut mc1: McSimulation<Generator, fn(&Path) -> Real> = simulation(7);
+        let mut mc2: McSimulation<Generator, fn(&Path) -> Real> = simulation(7);
+        let value: Real = mc1.value_with_samples(5_000);
+        assert_eq!(value, mc2.value_with_samples(5_000));
+        assert_eq!(mc1.sample_accumulator().samples(), 5_000);
+
+        // Already simulated samples are kept
+        mc2.value_with_samples(8_000);
+        assert_eq!(mc2.sample_accumulator().samples(), 8_000);
+    }
+
+    #[test]
+    fn value_panics_when_max_samples_is_reached() {
+        let result = panic::catch_unwind(|| simulation(42).value(1.0e-6, 2_000, None));
+        assert!(result.is_err());
+
+        let result = panic::catch_unwind(|| {
+            let mut mc: McSimulation<Generator, fn(&Path) -> Real> = simulation(42);
+            mc.value_with_samples(100);
+            mc.value_with_samples(50)
+        });
+        assert!(result.is_err());
+    }
+}
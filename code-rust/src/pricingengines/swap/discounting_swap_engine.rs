@@ -0,0 +1,233 @@
+use crate::instruments::fixed_vs_floating_swap::FixedVsFloatingSwap;
+use crate::pricingengines::swap::swap_results::SwapResults;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::date::Date;
+use crate::types::{Rate, Real};
+use std::rc::Rc;
+
+/*
+Discounting engine for fixed-vs-floating swaps (QuantLib
+DiscountingSwapEngine): the coupons paid after the reference date of
+the discount curve are discounted on it, which also forecasts the
+floating rates F_j. Coupons already fixed pay the stored fixings of the
+swap index, and a missing one is an error. With A_fixed and A_floating
+the annuities N sum_i tau_i P(0, t_i) of the legs, a payer swap is worth
+    N sum_j tau_j (F_j + s) P(0, t_j) - K A_fixed,
+its fair rate is K minus value / A_fixed and its fair spread s minus
+value / A_floating.
+*/
+pub struct DiscountingSwapEngine {
+    discount_curve: Rc<dyn YieldTermStructure>,
+}
+impl DiscountingSwapEngine {
+    // Constructor
+    pub fn new(discount_curve: Rc<dyn YieldTermStructure>) -> DiscountingSwapEngine {
+        DiscountingSwapEngine { discount_curve }
+    }
+
+    // Inspectors
+    pub fn discount_curve(&self) -> &dyn YieldTermStructure {
+        self.discount_curve.as_ref()
+    }
+
+    pub fn calculate(&self, swap: &dyn FixedVsFloatingSwap) -> SwapResults {
+        let curve: &dyn YieldTermStructure = self.discount_curve.as_ref();
+        let reference_date: Date = curve.reference_date();
+        let fixed_annuity: Real = swap.nominal()
+            * swap.fixed_schedule()[1..]
+                .iter()
+                .zip(swap.fixed_accruals())
+                .filter(|(date, _)| **date > reference_date)
+                .map(|(date, tau)| tau * curve.discount_date(*date))
+                .sum::<Real>();
+        let (floating_annuity, forward_value): (Real, Real) = swap
+            .floating_periods()
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, date, _))| *date > reference_date)
+            .map(|(i, (_, date, tau))| {
+                let discount: Real = curve.discount_date(*date);
+                (
+                    tau * discount,
+                    tau * swap.floating_rate(i, curve) * discount,
+                )
+            })
+            .fold((0.0, 0.0), |(a, v), (da, dv)| (a + da, v + dv));
+        if fixed_annuity <= 0.0 || floating_annuity <= 0.0 {
+            panic!("swap matured on {}", swap.maturity_date());
+        }
+
+        let sign: Real = swap.swap_type().sign();
+        let fixed_leg_value: Real = -sign * swap.fixed_rate() * fixed_annuity;
+        let floating_leg_value: Real =
+            sign * swap.nominal() * (forward_value + swap.spread() * floating_annuity);
+        let value: Real = fixed_leg_value + floating_leg_value;
+        let fair_rate: Rate = swap.fixed_rate() + sign * value / fixed_annuity;
+        SwapResults {
+            value,
+            fixed_leg_value,
+            floating_leg_value,
+            fixed_leg_bps: -sign * fixed_annuity * 1.0e-4,
+            floating_leg_bps: sign * swap.nominal() * floating_annuity * 1.0e-4,
+            fair_rate,
+            fair_spread: swap.spread() - sign * value / (swap.nominal() * floating_annuity),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexes::ibor_index::{IborIndex, OvernightIndex};
+    use crate::instruments::overnight_indexed_swap::OvernightIndexedSwap;
+    use crate::instruments::swap::SwapType;
+    use crate::instruments::vanilla_swap::VanillaSwap;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual360::Actual360;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::period::Period;
+    use crate::time::time_unit::TimeUnit;
+    use crate::types::Spread;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn engine() -> DiscountingSwapEngine {
+        DiscountingSwapEngine::new(flat_rate(reference_date(), 0.03))
+    }
+
+    fn schedule(start: Date, step: i32, periods: i32) -> Vec<Date> {
+        (0..=periods).map(|k| start + step * k).collect()
+    }
+
+    fn vanilla_swap(swap_type: SwapType, fixed_rate: Rate, spread: Spread) -> VanillaSwap {
+        let start: Date = reference_date() + 2;
+        VanillaSwap::new(
+            swap_type,
+            1.0e6,
+            &schedule(start, 365, 5),
+            fixed_rate,
+            &Actual365Fixed::new(),
+            &schedule(start, 73, 25),
+            Rc::new(IborIndex::euribor(Period::new(3, TimeUnit::Months))),
+            &Actual360::new(),
+        )
+        .with_spread(spread)
+    }
+
+    #[test]
+    fn swaps_at_fair_terms_are_worth_nothing() {
+        let engine: DiscountingSwapEngine = engine();
+        let payer: SwapResults = engine.calculate(&vanilla_swap(SwapType::Payer, 0.04, 0.001));
+        // The floating leg is worth the discount factor difference of its ends plus the spread
+        let start: Real = (-0.03 * 2.0 / 365.0_f64).exp();
+        let end: Real = (-0.03 * 1827.0 / 365.0_f64).exp();
+        let floating: Real = 1.0e6 * (start - end) + 10.0 * payer.floating_leg_bps;
+        assert!((payer.floating_leg_value - floating).abs() < 1.0e-6);
+        assert!((payer.fixed_leg_value - 400.0 * payer.fixed_leg_bps).abs() < 1.0e-6);
+        assert!(payer.value < 0.0 && payer.fixed_leg_bps < 0.0);
+
+        let at_fair_rate: SwapResults =
+            engine.calculate(&vanilla_swap(SwapType::Payer, payer.fair_rate, 0.001));
+        assert!(at_fair_rate.value.abs() < 1.0e-6);
+        let at_fair_spread: SwapResults =
+            engine.calculate(&vanilla_swap(SwapType::Payer, 0.04, payer.fair_spread));
+        assert!(at_fair_spread.value.abs() < 1.0e-6);
+        let receiver: SwapResults =
+            engine.calculate(&vanilla_swap(SwapType::Receiver, 0.04, 0.001));
+        assert_eq!(receiver.value, -payer.value);
+        assert_eq!(receiver.fair_rate, payer.fair_rate);
+        assert!((receiver.fair_spread - payer.fair_spread).abs() < 1.0e-15);
+    }
+
+    #[test]
+    fn seasoned_swaps_pay_their_stored_fixings() {
+        // The current coupons accrue from Tuesday 14 January 2025, fixed before the reference date
+        let dates: Vec<Date> = schedule(Date::new(15, Month::January, 2024), 365, 3);
+        let mut euribor: IborIndex = IborIndex::euribor(Period::new(1, TimeUnit::Years));
+        euribor.add_fixing(Date::new(10, Month::January, 2025), 0.05);
+        let mut estr: OvernightIndex = OvernightIndex::estr();
+        estr.add_fixing(Date::new(14, Month::January, 2025), 0.05);
+        let vanilla: SwapResults = engine().calculate(&VanillaSwap::new(
+            SwapType::Payer,
+            1.0e6,
+            &dates,
+            0.03,
+            &Actual360::new(),
+            &dates,
+            Rc::new(euribor),
+            &Actual360::new(),
+        ));
+        let ois: SwapResults = engine().calculate(&OvernightIndexedSwap::new(
+            SwapType::Payer,
+            1.0e6,
+            &dates,
+            0.03,
+            &Actual360::new(),
+            &dates,
+            Rc::new(estr),
+            &Actual360::new(),
+        ));
+        // Coupons paid 364 and 729 days after the reference date
+        let (p1, p2): (Real, Real) = (
+            (-0.03 * 364.0 / 365.0_f64).exp(),
+            (-0.03 * 729.0 / 365.0_f64).exp(),
+        );
+        let expected: Real = 1.0e6 * (0.05 * 365.0 / 360.0 * p1 + p1 - p2);
+        assert!((vanilla.floating_leg_value - expected).abs() < 1.0e-6);
+        // One overnight fixing, then the curve from the reference date
+        let expected: Real = 1.0e6 * (1.0 + 0.05 / 360.0 - p2);
+        assert!((ois.floating_leg_value - expected).abs() < 1.0e-6);
+        assert_eq!(ois.fixed_leg_value, vanilla.fixed_leg_value);
+
+        let cases: [(bool, &str); 2] = [
+            (true, "missing Ibor fixing"),
+            (false, "missing overnight fixing"),
+        ];
+        for (ibor, label) in cases {
+            let result = std::panic::catch_unwind(|| {
+                let dates: Vec<Date> = schedule(Date::new(15, Month::January, 2024), 365, 3);
+                if ibor {
+                    engine().calculate(&VanillaSwap::new(
+                        SwapType::Payer,
+                        1.0e6,
+                        &dates,
+                        0.03,
+                        &Actual360::new(),
+                        &dates,
+                        Rc::new(IborIndex::euribor(Period::new(1, TimeUnit::Years))),
+                        &Actual360::new(),
+                    ))
+                } else {
+                    engine().calculate(&OvernightIndexedSwap::new(
+                        SwapType::Payer,
+                        1.0e6,
+                        &dates,
+                        0.03,
+                        &Actual360::new(),
+                        &dates,
+                        Rc::new(OvernightIndex::estr()),
+                        &Actual360::new(),
+                    ))
+                }
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+        let result = std::panic::catch_unwind(|| {
+            let dates: Vec<Date> = schedule(Date::new(15, Month::January, 2024), 365, 1);
+            engine().calculate(&OvernightIndexedSwap::new(
+                SwapType::Payer,
+                1.0e6,
+                &dates,
+                0.03,
+                &Actual360::new(),
+                &dates,
+                Rc::new(OvernightIndex::estr()),
+                &Actual360::new(),
+            ))
+        });
+        assert!(result.is_err(), "expected panic for a matured swap");
+    }
+}
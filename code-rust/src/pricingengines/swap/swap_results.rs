@@ -0,0 +1,17 @@
+use crate::types::{Rate, Real, Spread};
+
+/*
+What the swap engine computes (QuantLib FixedVsFloatingSwap::results):
+the value, the value and basis-point sensitivity of each leg, signed
+as received by the holder, and the fair fixed rate and floating spread.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct SwapResults {
+    pub value: Real,
+    pub fixed_leg_value: Real,
+    pub floating_leg_value: Real,
+    pub fixed_leg_bps: Real,
+    pub floating_leg_bps: Real,
+    pub fair_rate: Rate,
+    pub fair_spread: Spread,
+}
@@ -0,0 +1,156 @@
+use crate::instruments::swaption::Swaption;
+use crate::pricingengines::swaption::black_swaption_engine::detail::calculate;
+use crate::pricingengines::swaption::swaption_results::SwaptionResults;
+use crate::termstructures::volatility::swaption::swaption_volatility_structure::SwaptionVolatilityStructure;
+use crate::termstructures::volatility::volatility_type::VolatilityType;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use std::rc::Rc;
+
+/*
+Bachelier engine for European swaptions (QuantLib
+BachelierSwaptionEngine): as BlackSwaptionEngine, with the normal
+(basis point) volatility of the swaption volatility structure in the
+Bachelier formula, which allows negative forward swap rates and
+strikes.
+*/
+pub struct BachelierSwaptionEngine {
+    discount_curve: Rc<dyn YieldTermStructure>,
+    volatility: Box<dyn SwaptionVolatilityStructure>,
+}
+impl BachelierSwaptionEngine {
+    // Constructor
+    pub fn new(
+        discount_curve: Rc<dyn YieldTermStructure>,
+        volatility: Box<dyn SwaptionVolatilityStructure>,
+    ) -> BachelierSwaptionEngine {
+        if volatility.volatility_type() != VolatilityType::Normal {
+            panic!("BachelierSwaptionEngine requires normal volatilities");
+        }
+        BachelierSwaptionEngine {
+            discount_curve,
+            volatility,
+        }
+    }
+
+    // Inspectors
+    pub fn discount_curve(&self) -> &dyn YieldTermStructure {
+        self.discount_curve.as_ref()
+    }
+    pub fn volatility(&self) -> &dyn SwaptionVolatilityStructure {
+        self.volatility.as_ref()
+    }
+
+    pub fn calculate(&self, swaption: &Swaption) -> SwaptionResults {
+        calculate(
+            swaption,
+            self.discount_curve.as_ref(),
+            self.volatility.as_ref(),
+            VolatilityType::Normal,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::EuropeanExercise;
+    use crate::indexes::ibor_index::IborIndex;
+    use crate::instruments::swap::SwapType;
+    use crate::instruments::swaption::SettlementType;
+    use crate::instruments::vanilla_swap::VanillaSwap;
+    use crate::pricingengines::swaption::black_swaption_engine::BlackSwaptionEngine;
+    use crate::termstructures::volatility::swaption::constant_swaption_volatility::ConstantSwaptionVolatility;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::{Date, Month};
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::period::Period;
+    use crate::time::time_unit::TimeUnit;
+    use crate::types::{Rate, Real, Volatility};
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn volatility(
+        volatility: Volatility,
+        volatility_type: VolatilityType,
+    ) -> Box<dyn SwaptionVolatilityStructure> {
+        Box::new(ConstantSwaptionVolatility::new(
+            reference_date(),
+            Box::new(Actual365Fixed::new()),
+            volatility,
+            volatility_type,
+            0.0,
+        ))
+    }
+
+    // 1y into 3y annual swaption
+    fn swaption(swap_type: SwapType, strike: Rate) -> Swaption {
+        let start: Date = Date::advance(reference_date(), 1, TimeUnit::Years);
+        let dates: Vec<Date> = (0..=3)
+            .map(|k| Date::advance(start, k, TimeUnit::Years))
+            .collect();
+        let swap: VanillaSwap = VanillaSwap::new(
+            swap_type,
+            1.0e6,
+            &dates,
+            strike,
+            &Actual365Fixed::new(),
+            &dates,
+            Rc::new(IborIndex::euribor(Period::new(1, TimeUnit::Years))),
+            &Actual365Fixed::new(),
+        );
+        Swaption::new(
+            swap,
+            Box::new(EuropeanExercise::new(start)),
+            SettlementType::Physical,
+        )
+    }
+
+    #[test]
+    fn payer_receiver_parity_with_negative_rates() {
+        let engine: BachelierSwaptionEngine = BachelierSwaptionEngine::new(
+            flat_rate(reference_date(), -0.005),
+            volatility(0.006, VolatilityType::Normal),
+        );
+        for strike in [-0.01, -0.005, 0.0, 0.01] {
+            let payer: SwaptionResults = engine.calculate(&swaption(SwapType::Payer, strike));
+            let receiver: SwaptionResults = engine.calculate(&swaption(SwapType::Receiver, strike));
+            assert!(payer.atm_forward < 0.0);
+            assert!(payer.value > 0.0 && receiver.value > 0.0);
+            let forward_value: Real = payer.annuity * (payer.atm_forward - strike);
+            assert!((payer.value - receiver.value - forward_value).abs() < 1.0e-8);
+        }
+    }
+
+    #[test]
+    fn matches_black_at_the_money_for_small_volatilities() {
+        // ATM: Black = Bachelier (1 - sigma^2 t / 24 + ...) with sigma_N = sigma S
+        let black: BlackSwaptionEngine = BlackSwaptionEngine::new(
+            flat_rate(reference_date(), 0.03),
+            volatility(0.02, VolatilityType::ShiftedLognormal),
+        );
+        let forward: Rate = black
+            .calculate(&swaption(SwapType::Payer, 0.03))
+            .atm_forward;
+        let atm: Swaption = swaption(SwapType::Payer, forward);
+        let bachelier: BachelierSwaptionEngine = BachelierSwaptionEngine::new(
+            flat_rate(reference_date(), 0.03),
+            volatility(0.02 * forward, VolatilityType::Normal),
+        );
+        let black_value: Real = black.calculate(&atm).value;
+        let bachelier_value: Real = bachelier.calculate(&atm).value;
+        assert!((black_value / bachelier_value - 1.0).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn new_panics_on_lognormal_volatilities() {
+        let result = std::panic::catch_unwind(|| {
+            BachelierSwaptionEngine::new(
+                flat_rate(reference_date(), 0.03),
+                volatility(0.2, VolatilityType::ShiftedLognormal),
+            )
+        });
+        assert!(result.is_err(), "expected panic for lognormal volatilities");
+    }
+}
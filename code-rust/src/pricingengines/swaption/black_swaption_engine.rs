@@ -0,0 +1,402 @@
+use crate::instruments::swaption::Swaption;
+use crate::pricingengines::swaption::swaption_results::SwaptionResults;
+use crate::termstructures::volatility::swaption::swaption_volatility_structure::SwaptionVolatilityStructure;
+use crate::termstructures::volatility::volatility_type::VolatilityType;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use std::rc::Rc;
+
+/*
+Black-formula engine for European swaptions (QuantLib
+BlackSwaptionEngine): the swaption is a call (payer) or put (receiver)
+on the forward swap rate, struck at the fixed rate, times the annuity
+of the fixed leg. The shifted lognormal volatility and shift come from
+the swaption volatility structure at the exercise time and swap length.
+
+Forwards and discount factors come from the discount curve, the
+exercise time from the volatility structure. For cash settlement the
+annuity is the par-yield one,
+    P(0, t_start) sum_i tau_i prod_{k <= i} 1 / (1 + tau_k S)
+with S the forward swap rate. A spread s on the floating leg lowers the
+strike by s times the ratio of the floating and fixed annuities.
+*/
+pub struct BlackSwaptionEngine {
+    discount_curve: Rc<dyn YieldTermStructure>,
+    volatility: Box<dyn SwaptionVolatilityStructure>,
+}
+impl BlackSwaptionEngine {
+    // Constructor
+    pub fn new(
+        discount_curve: Rc<dyn YieldTermStructure>,
+        volatility: Box<dyn SwaptionVolatilityStructure>,
+    ) -> BlackSwaptionEngine {
+        if volatility.volatility_type() != VolatilityType::ShiftedLognormal {
+            panic!("BlackSwaptionEngine requires shifted lognormal volatilities");
+        }
+        BlackSwaptionEngine {
+            discount_curve,
+            volatility,
+        }
+    }
+
+    // Inspectors
+    pub fn discount_curve(&self) -> &dyn YieldTermStructure {
+        self.discount_curve.as_ref()
+    }
+    pub fn volatility(&self) -> &dyn SwaptionVolatilityStructure {
+        self.volatility.as_ref()
+    }
+
+    pub fn calculate(&self, swaption: &Swaption) -> SwaptionResults {
+        detail::calculate(
+            swaption,
+            self.discount_curve.as_ref(),
+            self.volatility.as_ref(),
+            VolatilityType::ShiftedLognormal,
+        )
+    }
+}
+
+pub(crate) mod detail {
+    use crate::exercise::ExerciseType;
+    use crate::instruments::fixed_vs_floating_swap::FixedVsFloatingSwap;
+    use crate::instruments::swap::SwapType;
+    use crate::instruments::swaption::{SettlementType, Swaption};
+    use crate::instruments::vanilla_swap::VanillaSwap;
+    use crate::option::OptionType;
+    use crate::pricingengines::black_formula::{
+        bachelier_black_formula, bachelier_black_formula_std_dev_derivative, black_formula,
+        black_formula_std_dev_derivative,
+    };
+    use crate::pricingengines::swaption::swaption_results::SwaptionResults;
+    use crate::termstructures::volatility::swaption::swaption_volatility_structure::SwaptionVolatilityStructure;
+    use crate::termstructures::volatility::volatility_type::VolatilityType;
+    use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+    use crate::time::date::Date;
+    use crate::time::day_counter::DayCounter;
+    use crate::types::{Rate, Real, Time, Volatility};
+
+    // Valuation shared by the Black and Bachelier engines
+    pub(crate) fn calculate(
+        swaption: &Swaption,
+        discount_curve: &dyn YieldTermStructure,
+        volatility: &dyn SwaptionVolatilityStructure,
+        volatility_type: VolatilityType,
+    ) -> SwaptionResults {
+        if swaption.exercise().exercise_type() != ExerciseType::European {
+            panic!("not a European swaption");
+        }
+        let reference_date: Date = volatility.reference_date();
+        let day_counter: &dyn DayCounter = volatility.day_counter();
+        let exercise_date: Date = swaption.exercise().last_date();
+        let exercise_time: Time = day_counter.year_fraction(reference_date, exercise_date);
+        if exercise_time < 0.0 {
+            panic!("swaption expired on {exercise_date}");
+        }
+        let swap: &VanillaSwap = swaption.swap();
+        let (forward, physical_annuity): (Rate, Real) = forward_and_annuity(swap, discount_curve);
+        let annuity: Real = swap.nominal()
+            * match swaption.settlement_type() {
+                SettlementType::Physical => physical_annuity,
+                SettlementType::Cash => {
+                    let mut compounding: Real = 1.0;
+                    let mut sum: Real = 0.0;
+                    for tau in swap.fixed_accruals() {
+                        compounding /= 1.0 + tau * forward;
+                        sum += tau * compounding;
+                    }
+                    sum * discount_curve.discount_date(swap.start_date())
+                }
+            };
+
+        let strike: Rate = swap.fixed_rate()
+            - swap.spread() * floating_annuity(swap, discount_curve) / physical_annuity;
+        let swap_length: Time = volatility.swap_length(swap.start_date(), swap.maturity_date());
+        let sigma: Volatility = volatility.volatility(exercise_time, swap_length, strike);
+        let std_dev: Real = sigma * exercise_time.sqrt();
+        let option_type: OptionType = match swap.swap_type() {
+            SwapType::Payer => OptionType::Call,
+            SwapType::Receiver => OptionType::Put,
+        };
+        let (value, std_dev_derivative): (Real, Real) = match volatility_type {
+            VolatilityType::ShiftedLognormal => {
+                let shift: Real = volatility.shift(exercise_time, swap_length);
+                (
+                    black_formula(option_type, strike, forward, std_dev, 1.0, shift),
+                    black_formula_std_dev_derivative(strike, forward, std_dev, 1.0, shift),
+                )
+            }
+            VolatilityType::Normal => (
+                bachelier_black_formula(option_type, strike, forward, std_dev, 1.0),
+                bachelier_black_formula_std_dev_derivative(strike, forward, std_dev, 1.0),
+            ),
+        };
+        SwaptionResults {
+            value: annuity * value,
+            vega: annuity * std_dev_derivative * exercise_time.sqrt(),
+            atm_forward: forward,
+            annuity,
+            std_dev,
+        }
+    }
+
+    /*
+    Forward swap rate and annuity per unit nominal on the discount curve:
+        A = sum_i tau_i P(0, t_i),   S = sum_j tau_j F_j P(0, t_j) / A
+    over the coupons not yet paid at the curve's reference date.
+    */
+    pub(crate) fn forward_and_annuity(
+        swap: &VanillaSwap,
+        discount_curve: &dyn YieldTermStructure,
+    ) -> (Rate, Real) {
+        let reference_date: Date = discount_curve.reference_date();
+        let annuity: Real = swap.fixed_schedule()[1..]
+            .iter()
+            .zip(swap.fixed_accruals())
+            .filter(|(date, _)| **date > reference_date)
+            .map(|(date, tau)| tau * discount_curve.discount_date(*date))
+            .sum();
+        if annuity <= 0.0 {
+            panic!("swap matured on {}", swap.maturity_date());
+        }
+        let floating: Real = swap
+            .floating_leg()
+            .iter()
+            .enumerate()
+            .filter(|(_, coupon)| coupon.date() > reference_date)
+            .map(|(i, coupon)| {
+                coupon.accrual_period()
+                    * swap.floating_rate(i, discount_curve)
+                    * discount_curve.discount_date(coupon.date())
+            })
+            .sum();
+        (floating / annuity, annuity)
+    }
+
+    // Floating annuity per unit nominal, sum_j tau_j P(0, t_j), over the coupons not yet paid
+    fn floating_annuity(swap: &VanillaSwap, discount_curve: &dyn YieldTermStructure) -> Real {
+        let reference_date: Date = discount_curve.reference_date();
+        swap.floating_leg()
+            .iter()
+            .filter(|coupon| coupon.date() > reference_date)
+            .map(|coupon| coupon.accrual_period() * discount_curve.discount_date(coupon.date()))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::{BermudanExercise, EuropeanExercise};
+    use crate::indexes::ibor_index::IborIndex;
+    use crate::instruments::fixed_vs_floating_swap::FixedVsFloatingSwap;
+    use crate::instruments::swap::SwapType;
+    use crate::instruments::swaption::SettlementType;
+    use crate::instruments::vanilla_swap::VanillaSwap;
+    use crate::models::shortrate::calibrationhelpers::swaption_helper::SwaptionHelper;
+    use crate::termstructures::volatility::swaption::constant_swaption_volatility::ConstantSwaptionVolatility;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::{Date, Month};
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::period::Period;
+    use crate::time::time_unit::TimeUnit;
+    use crate::types::{Rate, Real, Volatility};
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn engine(volatility: Volatility, shift: Real) -> BlackSwaptionEngine {
+        BlackSwaptionEngine::new(
+            flat_rate(reference_date(), 0.04),
+            Box::new(ConstantSwaptionVolatility::new(
+                reference_date(),
+                Box::new(Actual365Fixed::new()),
+                volatility,
+                VolatilityType::ShiftedLognormal,
+                shift,
+            )),
+        )
+    }
+
+    fn swaption(
+        swap_type: SwapType,
+        fixed_schedule: &[Date],
+        floating_schedule: &[Date],
+        strike: Rate,
+        settlement_type: SettlementType,
+    ) -> Swaption {
+        let swap: VanillaSwap = VanillaSwap::new(
+            swap_type,
+            1.0e6,
+            fixed_schedule,
+            strike,
+            &Actual365Fixed::new(),
+            floating_schedule,
+            Rc::new(IborIndex::euribor(Period::new(1, TimeUnit::Years))),
+            &Actual365Fixed::new(),
+        );
+        let exercise: Box<EuropeanExercise> = Box::new(EuropeanExercise::new(swap.start_date()));
+        Swaption::new(swap, exercise, settlement_type)
+    }
+
+    // 2y into 5y annual ATM swaption of the calibration helpers
+    fn helper() -> SwaptionHelper {
+        SwaptionHelper::new(
+            &Actual365Fixed::new(),
+            Period::new(2, TimeUnit::Years),
+            Period::new(5, TimeUnit::Years),
+            Period::new(1, TimeUnit::Years),
+            0.2,
+            flat_rate(reference_date(), 0.04),
+        )
+    }
+
+    #[test]
+    fn matches_the_swaption_helper_price() {
+        let helper: SwaptionHelper = helper();
+        let dates: &[Date] = helper.fixed_dates();
+        let floating: Vec<Date> = (0..=10)
+            .map(|k| Date::advance(dates[0], 6 * k, TimeUnit::Months))
+            .collect();
+        let results: SwaptionResults = engine(0.2, 0.0).calculate(&swaption(
+            SwapType::Payer,
+            dates,
+            &floating,
+            helper.strike(),
+            SettlementType::Physical,
+        ));
+        assert!((results.atm_forward - helper.strike()).abs() < 1.0e-14);
+        let expected: Real = 1.0e6 * helper.black_price(0.2);
+        assert!((results.value - expected).abs() < 1.0e-8 * expected);
+    }
+
+    #[test]
+    fn payer_receiver_parity() {
+        let dates: Vec<Date> = helper().fixed_dates().to_vec();
+        let engine: BlackSwaptionEngine = engine(0.3, 0.01);
+        for settlement_type in [SettlementType::Physical, SettlementType::Cash] {
+            for strike in [0.02, 0.04, 0.06] {
+                let payer: SwaptionResults = engine.calculate(&swaption(
+                    SwapType::Payer,
+                    &dates,
+                    &dates,
+                    strike,
+                    settlement_type,
+                ));
+                let receiver: SwaptionResults = engine.calculate(&swaption(
+                    SwapType::Receiver,
+                    &dates,
+                    &dates,
+                    strike,
+                    settlement_type,
+                ));
+                let forward_value: Real = payer.annuity * (payer.atm_forward - strike);
+                assert!((payer.value - receiver.value - forward_value).abs() < 1.0e-8);
+                assert!((payer.vega - receiver.vega).abs() < 1.0e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn cash_and_physical_annuities_agree_on_a_single_period() {
+        // tau P(0, t_1) = P(0, t_0) tau / (1 + tau S) for one period
+        let start: Date = reference_date() + 365;
+        let dates: [Date; 2] = [start, start + 182];
+        let results: [SwaptionResults; 2] =
+            [SettlementType::Physical, SettlementType::Cash].map(|settlement_type| {
+                engine(0.25, 0.0).calculate(&swaption(
+                    SwapType::Payer,
+                    &dates,
+                    &dates,
+                    0.04,
+                    settlement_type,
+                ))
+            });
+        assert!((results[0].annuity - results[1].annuity).abs() < 1.0e-9);
+        assert!((results[0].value - results[1].value).abs() < 1.0e-9);
+        // Longer swaps: cash annuities discount on the swap rate
+        let dates: Vec<Date> = helper().fixed_dates().to_vec();
+        let physical: SwaptionResults = engine(0.25, 0.0).calculate(&swaption(
+            SwapType::Payer,
+            &dates,
+            &dates,
+            0.04,
+            SettlementType::Physical,
+        ));
+        let cash: SwaptionResults = engine(0.25, 0.0).calculate(&swaption(
+            SwapType::Payer,
+            &dates,
+            &dates,
+            0.04,
+            SettlementType::Cash,
+        ));
+        assert!((cash.annuity / physical.annuity - 1.0).abs() < 1.0e-3);
+    }
+
+    #[test]
+    fn floating_spread_shifts_the_strike() {
+        // On matching legs a spread s over the fixed rate K prices as a strike of K - s
+        let dates: Vec<Date> = helper().fixed_dates().to_vec();
+        let with_spread: Swaption = {
+            let swap: VanillaSwap = VanillaSwap::new(
+                SwapType::Payer,
+                1.0e6,
+                &dates,
+                0.045,
+                &Actual365Fixed::new(),
+                &dates,
+                Rc::new(IborIndex::euribor(Period::new(1, TimeUnit::Years))),
+                &Actual365Fixed::new(),
+            )
+            .with_spread(0.005);
+            let exercise: Box<EuropeanExercise> =
+                Box::new(EuropeanExercise::new(swap.start_date()));
+            Swaption::new(swap, exercise, SettlementType::Physical)
+        };
+        let engine: BlackSwaptionEngine = engine(0.2, 0.0);
+        let expected: SwaptionResults = engine.calculate(&swaption(
+            SwapType::Payer,
+            &dates,
+            &dates,
+            0.04,
+            SettlementType::Physical,
+        ));
+        let results: SwaptionResults = engine.calculate(&with_spread);
+        assert!((results.value - expected.value).abs() < 1.0e-8);
+        assert!((results.vega - expected.vega).abs() < 1.0e-8);
+    }
+
+    #[test]
+    fn calculate_panics_on_bermudan_exercise_and_normal_volatilities() {
+        let dates: Vec<Date> = helper().fixed_dates().to_vec();
+        let result = std::panic::catch_unwind(|| {
+            let swap: VanillaSwap = VanillaSwap::new(
+                SwapType::Payer,
+                1.0,
+                &dates,
+                0.04,
+                &Actual365Fixed::new(),
+                &dates,
+                Rc::new(IborIndex::euribor(Period::new(1, TimeUnit::Years))),
+                &Actual365Fixed::new(),
+            );
+            let exercise: Box<BermudanExercise> =
+                Box::new(BermudanExercise::new(&dates[..2], false));
+            engine(0.2, 0.0).calculate(&Swaption::new(swap, exercise, SettlementType::Physical))
+        });
+        assert!(result.is_err(), "expected panic for Bermudan exercise");
+        let result = std::panic::catch_unwind(|| {
+            BlackSwaptionEngine::new(
+                flat_rate(reference_date(), 0.04),
+                Box::new(ConstantSwaptionVolatility::new(
+                    reference_date(),
+                    Box::new(Actual365Fixed::new()),
+                    0.01,
+                    VolatilityType::Normal,
+                    0.0,
+                )),
+            )
+        });
+        assert!(result.is_err(), "expected panic for normal volatilities");
+    }
+}
@@ -0,0 +1,11 @@
+use crate::types::{Rate, Real};
+
+// What the analytic swaption engines compute (QuantLib Swaption::results)
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SwaptionResults {
+    pub value: Real,
+    pub vega: Real,
+    pub atm_forward: Rate,
+    pub annuity: Real,
+    pub std_dev: Real,
+}
@@ -0,0 +1,316 @@
+use crate::exercise::{Exercise, ExerciseType};
+use crate::instruments::fixed_vs_floating_swap::FixedVsFloatingSwap;
+use crate::instruments::swap::SwapType;
+use crate::instruments::swaption::{SettlementType, Swaption};
+use crate::instruments::vanilla_swap::VanillaSwap;
+use crate::methods::lattices::tree::Tree;
+use crate::models::shortrate::one_factor_model::{OneFactorModel, ShortRateTree};
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::time_grid::TimeGrid;
+use crate::types::{Rate, Real, Size, Time};
+
+/*
+Swaption engine on the trinomial tree of a one-factor short-rate model
+(QuantLib TreeSwaptionEngine), for European and Bermudan exercise.
+
+Swaptions are physically settled, and their swap is valued on its
+fixed rate and fixed schedule [start, payment_1, ..., payment_n], with
+accrual periods measured by the engine's day counter. As the floating
+leg is taken at par at each reset, whatever its own schedule, exercising
+at reset t_k into the rest of the swap is worth, for a payer, 1 - B_k
+per unit of nominal, where B_k is the value of the remaining fixed
+coupons plus the notional at t_n; floating spreads are therefore not
+supported. B is rolled back on the tree along with the option; exercise
+dates must be reset dates (fixed_schedule[0..n]). Past exercise dates
+are ignored.
+*/
+pub struct TreeSwaptionEngine<M: OneFactorModel> {
+    model: M,
+    reference_date: Date,
+    day_counter: Box<dyn DayCounter>,
+    time_steps: Size,
+}
+impl<M: OneFactorModel> TreeSwaptionEngine<M> {
+    // Constructor
+    pub fn new(
+        model: M,
+        reference_date: Date,
+        day_counter: Box<dyn DayCounter>,
+        time_steps: Size,
+    ) -> TreeSwaptionEngine<M> {
+        if time_steps == 0 {
+            panic!("at least one time step required");
+        }
+        TreeSwaptionEngine {
+            model,
+            reference_date,
+            day_counter,
+            time_steps,
+        }
+    }
+
+    pub fn calculate(&self, swaption: &Swaption) -> Real {
+        if swaption.settlement_type() != SettlementType::Physical {
+            panic!("cash-settled swaptions not supported");
+        }
+        let swap: &VanillaSwap = swaption.swap();
+        if swap.spread() != 0.0 {
+            panic!("floating spread not supported");
+        }
+        swap.nominal()
+            * self.unit_value(
+                swap.swap_type(),
+                swap.fixed_rate(),
+                swap.fixed_schedule(),
+                swaption.exercise(),
+            )
+    }
+
+    // Value per unit of nominal of the swaption into the swap paying fixed_rate on fixed_dates
+    fn unit_value(
+        &self,
+        swap_type: SwapType,
+        fixed_rate: Rate,
+        fixed_dates: &[Date],
+        exercise: &dyn Exercise,
+    ) -> Real {
+        if fixed_dates.len() < 2 {
+            panic!("at least a start and a payment date required");
+        }
+        if exercise.exercise_type() == ExerciseType::American {
+            panic!("American exercise not supported");
+        }
+        let times: Vec<Time> = fixed_dates
+            .iter()
+            .map(|d| self.day_counter.year_fraction(self.reference_date, *d))
+            .collect();
+        if times[0] < 0.0 {
+            panic!("swap start ({}) before the reference date", fixed_dates[0]);
+        }
+        let coupons: Vec<Real> = fixed_dates
+            .windows(2)
+            .map(|period| fixed_rate * self.day_counter.year_fraction(period[0], period[1]))
+            .collect();
+        let n: Size = coupons.len();
+
+        let grid: TimeGrid = TimeGrid::from_mandatory_times(&times, self.time_steps);
+        let mut exercisable: Vec<bool> = vec![false; grid.len()];
+        for date in exercise.dates() {
+            if *date < self.reference_date {
+                continue;
+            }
+            let k: Size = fixed_dates[..n]
+                .iter()
+                .position(|d| d == date)
+                .unwrap_or_else(|| panic!("exercise date {date} is not a reset date"));
+            exercisable[grid.index_of(times[k])] = true;
+        }
+        let mut coupon_at: Vec<Real> = vec![0.0; grid.len()];
+        for k in 1..n {
+            coupon_at[grid.index_of(times[k])] = coupons[k - 1];
+        }
+
+        let tree: ShortRateTree = self.model.tree(&grid);
+        let last: Size = grid.len() - 1;
+        let mut bond: Vec<Real> = vec![1.0 + coupons[n - 1]; tree.size(last)];
+        let mut option: Vec<Real> = vec![0.0; tree.size(last)];
+        for i in (0..last).rev() {
+            bond = tree.rollback(i, &bond);
+            option = tree.rollback(i, &option);
+            if exercisable[i] {
+                for (value, b) in option.iter_mut().zip(&bond) {
+                    *value = value.max(swap_type.sign() * (1.0 - b));
+                }
+            }
+            // Coupons paid at t_i are not part of a swap entered at t_i
+            for b in bond.iter_mut() {
+                *b += coupon_at[i];
+            }
+        }
+        option[0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::{BermudanExercise, EuropeanExercise};
+    use crate::indexes::ibor_index::IborIndex;
+    use crate::models::calibration_helper::CalibrationHelper;
+    use crate::models::shortrate::calibrationhelpers::swaption_helper::SwaptionHelper;
+    use crate::models::shortrate::onefactormodels::black_karasinski::BlackKarasinski;
+    use crate::models::shortrate::onefactormodels::hull_white::HullWhite;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::period::Period;
+    use crate::time::time_unit::TimeUnit;
+    use std::rc::Rc;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    // 2y into 5y annual ATM swaption
+    fn helper() -> SwaptionHelper {
+        SwaptionHelper::new(
+            &Actual365Fixed::new(),
+            Period::new(2, TimeUnit::Years),
+            Period::new(5, TimeUnit::Years),
+            Period::new(1, TimeUnit::Years),
+            0.2,
+            flat_rate(reference_date(), 0.04),
+        )
+    }
+
+    fn engine<M: OneFactorModel>(model: M) -> TreeSwaptionEngine<M> {
+        TreeSwaptionEngine::new(
+            model,
+            reference_date(),
+            Box::new(Actual365Fixed::new()),
+            200,
+        )
+    }
+
+    // Swaption into the swap of the helper at its strike
+    fn swaption(
+        swap_type: SwapType,
+        nominal: Real,
+        exercise: impl Exercise + 'static,
+        settlement_type: SettlementType,
+    ) -> Swaption {
+        let helper: SwaptionHelper = helper();
+        let dates: &[Date] = helper.fixed_dates();
+        let swap: VanillaSwap = VanillaSwap::new(
+            swap_type,
+            nominal,
+            dates,
+            helper.strike(),
+            &Actual365Fixed::new(),
+            dates,
+            Rc::new(IborIndex::euribor(Period::new(1, TimeUnit::Years))),
+            &Actual365Fixed::new(),
+        );
+        Swaption::new(swap, Box::new(exercise), settlement_type)
+    }
+
+    #[test]
+    fn european_swaption_matches_jamshidian() {
+        let model: HullWhite = HullWhite::new(flat_rate(reference_date(), 0.04), 0.1, 0.01);
+        let helper: SwaptionHelper = helper();
+        let expected: Real = helper.model_value(&model);
+        let calculated: Real = engine(model).calculate(&swaption(
+            SwapType::Payer,
+            1.0,
+            EuropeanExercise::new(helper.fixed_dates()[0]),
+            SettlementType::Physical,
+        ));
+        assert!(
+            (calculated - expected).abs() < 1.0e-2 * expected,
+            "{calculated} vs {expected}"
+        );
+    }
+
+    #[test]
+    fn bermudan_swaptions_are_worth_more_than_european_ones() {
+        let helper: SwaptionHelper = helper();
+        let dates: &[Date] = helper.fixed_dates();
+        let european = |swap_type: SwapType| {
+            swaption(
+                swap_type,
+                1.0,
+                EuropeanExercise::new(dates[0]),
+                SettlementType::Physical,
+            )
+        };
+        let bermudan = |swap_type: SwapType| {
+            swaption(
+                swap_type,
+                1.0,
+                BermudanExercise::new(&dates[..dates.len() - 1], false),
+                SettlementType::Physical,
+            )
+        };
+
+        for swap_type in [SwapType::Payer, SwapType::Receiver] {
+            let hull_white: TreeSwaptionEngine<HullWhite> =
+                engine(HullWhite::new(flat_rate(reference_date(), 0.04), 0.1, 0.01));
+            let black_karasinski: TreeSwaptionEngine<BlackKarasinski> = engine(
+                BlackKarasinski::new(flat_rate(reference_date(), 0.04), 0.1, 0.25),
+            );
+            let values: [(Real, Real); 2] = [
+                (
+                    hull_white.calculate(&european(swap_type)),
+                    hull_white.calculate(&bermudan(swap_type)),
+                ),
+                (
+                    black_karasinski.calculate(&european(swap_type)),
+                    black_karasinski.calculate(&bermudan(swap_type)),
+                ),
+            ];
+            for (european_value, bermudan_value) in values {
+                assert!(european_value > 0.0);
+                assert!(
+                    bermudan_value > european_value,
+                    "{swap_type}: {bermudan_value} vs {european_value}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn swaptions_are_valued_per_unit_of_nominal() {
+        let dates: Vec<Date> = helper().fixed_dates().to_vec();
+        let engine: TreeSwaptionEngine<HullWhite> =
+            engine(HullWhite::new(flat_rate(reference_date(), 0.04), 0.1, 0.01));
+        let bermudan: BermudanExercise = BermudanExercise::new(&dates[..dates.len() - 1], false);
+        let unit: Real = engine.calculate(&swaption(
+            SwapType::Receiver,
+            1.0,
+            bermudan.clone(),
+            SettlementType::Physical,
+        ));
+        let value: Real = engine.calculate(&swaption(
+            SwapType::Receiver,
+            1.0e6,
+            bermudan.clone(),
+            SettlementType::Physical,
+        ));
+        assert!((value - 1.0e6 * unit).abs() < 1.0e-8, "{value} vs {unit}");
+        let result = std::panic::catch_unwind(|| {
+            TreeSwaptionEngine::new(
+                HullWhite::new(flat_rate(reference_date(), 0.04), 0.1, 0.01),
+                reference_date(),
+                Box::new(Actual365Fixed::new()),
+                10,
+            )
+            .calculate(&swaption(
+                SwapType::Receiver,
+                1.0e6,
+                bermudan,
+                SettlementType::Cash,
+            ))
+        });
+        assert!(result.is_err(), "expected panic for cash settlement");
+    }
+
+    #[test]
+    fn calculate_panics_on_invalid_exercise() {
+        let dates: Vec<Date> = helper().fixed_dates().to_vec();
+        let result = std::panic::catch_unwind(|| {
+            engine(HullWhite::new(flat_rate(reference_date(), 0.04), 0.1, 0.01)).calculate(
+                &swaption(
+                    SwapType::Payer,
+                    1.0,
+                    EuropeanExercise::new(dates[0] + 1),
+                    SettlementType::Physical,
+                ),
+            )
+        });
+        assert!(
+            result.is_err(),
+            "expected panic for exercise off a reset date"
+        );
+    }
+}
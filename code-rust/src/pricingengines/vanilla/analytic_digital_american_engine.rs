@@ -0,0 +1,317 @@
+use crate::exercise::{EarlyExercise, ExerciseType};
+use crate::instruments::vanilla_option::{VanillaOption, VanillaPayoff};
+use crate::pricingengines::greeks::spot_greeks;
+use crate::pricingengines::vanilla::vanilla_option_results::VanillaOptionResults;
+use crate::processes::black_scholes_process::{
+    BlackScholesMertonProcess, GeneralizedBlackScholesProcess,
+};
+use crate::stochastic_process::StochasticProcess1D;
+use crate::time::date::Date;
+use crate::types::{Real, Time};
+
+/*
+Analytic engine for American digital options (QuantLib
+AnalyticDigitalAmericanEngine, with the AmericanPayoffAtHit and
+AmericanPayoffAtExpiry formulas of Reiner-Rubinstein): the option pays
+as soon as the underlying touches the strike (one-touch), from above
+for puts and from below for calls. With mu = (r - q) / sigma^2 - 1/2
+and lambda = sqrt(mu^2 + 2 r / sigma^2):
+    - paid at hit, the payoff is discounted from the first hitting
+      time: R [(K / S)^(mu + lambda) N(eta z)
+               + (K / S)^(mu - lambda) N(eta (z - 2 lambda sigma sqrt(T)))]
+      with z = ln(K / S) / (sigma sqrt(T)) + lambda sigma sqrt(T)
+    - paid at expiry, it is weighted by the probability of a hit
+eta is +1 for puts and -1 for calls. Cash-or-nothing options pay their
+cash amount, asset-or-nothing ones the asset, i.e. the strike when paid
+at hit. Options already in the money pay at once. sigma is the Black
+volatility of the process at the expiry, for the strike as barrier.
+The option is traded on the reference date of the risk-free curve, on
+which its maturity is measured. Delta and gamma come from central
+differences of the formula.
+*/
+pub struct AnalyticDigitalAmericanEngine {
+    process: GeneralizedBlackScholesProcess,
+}
+impl AnalyticDigitalAmericanEngine {
+    // Constructor
+    pub fn new(process: GeneralizedBlackScholesProcess) -> AnalyticDigitalAmericanEngine {
+        AnalyticDigitalAmericanEngine { process }
+    }
+
+    // Inspectors
+    pub fn process(&self) -> &GeneralizedBlackScholesProcess {
+        &self.process
+    }
+
+    pub fn calculate(&self, option: &VanillaOption) -> VanillaOptionResults {
+        let exercise: &dyn EarlyExercise = match option.exercise().as_early_exercise() {
+            Some(exercise) if exercise.exercise_type() == ExerciseType::American => exercise,
+            _ => panic!("not an American option"),
+        };
+        let payoff: &VanillaPayoff = option.payoff();
+        if let VanillaPayoff::PlainVanilla(_) = payoff {
+            panic!("non-digital payoff given");
+        }
+        let reference_date: Date = self.process.risk_free_rate().reference_date();
+        if exercise.date(0) > reference_date {
+            panic!(
+                "exercise from {} not supported: it must start by the reference date ({})",
+                exercise.date(0),
+                reference_date
+            );
+        }
+        let maturity: Time = self.process.time(exercise.last_date());
+        if maturity <= 0.0 {
+            panic!("option expired: maturity {maturity} before the reference date");
+        }
+        let process: BlackScholesMertonProcess = self
+            .process
+            .constant_volatility_process(exercise.last_date(), payoff.strike());
+        let at_expiry: bool = exercise.payoff_at_expiry();
+        spot_greeks(
+            |s| detail::value(payoff, at_expiry, s, &process, maturity),
+            process.x0(),
+        )
+    }
+}
+
+pub(crate) mod detail {
+    use super::*;
+    use crate::math::distributions::normal_distribution::CumulativeNormalDistribution;
+
+    pub(crate) fn value(
+        payoff: &VanillaPayoff,
+        at_expiry: bool,
+        spot: Real,
+        process: &BlackScholesMertonProcess,
+        maturity: Time,
+    ) -> Real {
+        let (r, q, sigma): (Real, Real, Real) = (
+            process.risk_free_rate(),
+            process.dividend_yield(),
+            process.volatility(),
+        );
+        let strike: Real = payoff.strike();
+        let (discount, dividend_discount): (Real, Real) =
+            ((-r * maturity).exp(), (-q * maturity).exp());
+        let in_the_money: bool = payoff.option_type().sign() * (spot - strike) >= 0.0;
+        let n = |x: Real| -> Real { CumulativeNormalDistribution::new(0.0, 1.0).value(x) };
+
+        let std_dev: Real = sigma * maturity.sqrt();
+        let mu: Real = (r - q) / (sigma * sigma) - 0.5;
+        let ks: Real = strike / spot;
+        // eta: +1 when the strike is hit from above (puts), -1 from below (calls)
+        let eta: Real = -payoff.option_type().sign();
+        match (payoff, at_expiry) {
+            (VanillaPayoff::PlainVanilla(_), _) => unreachable!("non-digital payoff"),
+            (VanillaPayoff::CashOrNothing(cash), false) => {
+                if in_the_money {
+                    return cash.cash_payoff();
+                }
+                cash.cash_payoff() * hit_discount(ks, mu, r, sigma, std_dev, eta, &n)
+            }
+            (VanillaPayoff::AssetOrNothing(_), false) => {
+                if in_the_money {
+                    return spot;
+                }
+                strike * hit_discount(ks, mu, r, sigma, std_dev, eta, &n)
+            }
+            (VanillaPayoff::CashOrNothing(cash), true) => {
+                if in_the_money {
+                    return cash.cash_payoff() * discount;
+                }
+                let x2: Real = -ks.ln() / std_dev + (1.0 + mu) * std_dev;
+                let y2: Real = ks.ln() / std_dev + (1.0 + mu) * std_dev;
+                cash.cash_payoff()
+                    * discount
+                    * (n(-eta * (x2 - std_dev)) + ks.powf(2.0 * mu) * n(eta * (y2 - std_dev)))
+            }
+            (VanillaPayoff::AssetOrNothing(_), true) => {
+                if in_the_money {
+                    return spot * dividend_discount;
+                }
+                let x2: Real = -ks.ln() / std_dev + (1.0 + mu) * std_dev;
+                let y2: Real = ks.ln() / std_dev + (1.0 + mu) * std_dev;
+                spot * dividend_discount * (n(-eta * x2) + ks.powf(2.0 * (mu + 1.0)) * n(eta * y2))
+            }
+        }
+    }
+
+    // Expected discount factor at the first hit of the strike, zero if after maturity
+    fn hit_discount(
+        ks: Real,
+        mu: Real,
+        r: Real,
+        sigma: Real,
+        std_dev: Real,
+        eta: Real,
+        n: &dyn Fn(Real) -> Real,
+    ) -> Real {
+        let lambda: Real = (mu * mu + 2.0 * r / (sigma * sigma)).sqrt();
+        let z: Real = ks.ln() / std_dev + lambda * std_dev;
+        ks.powf(mu + lambda) * n(eta * z)
+            + ks.powf(mu - lambda) * n(eta * (z - 2.0 * lambda * std_dev))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::{AmericanExercise, EuropeanExercise};
+    use crate::instruments::payoffs::{
+        AssetOrNothingPayoff, CashOrNothingPayoff, PlainVanillaPayoff,
+    };
+    use crate::option::OptionType;
+    use crate::termstructures::volatility::equityfx::black_constant_vol::BlackConstantVol;
+    use crate::termstructures::yieldcurves::flat_forward::FlatForward;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual360::Actual360;
+    use std::rc::Rc;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn engine(spot: Real, q: Real, r: Real) -> AnalyticDigitalAmericanEngine {
+        let curve = |rate: Real| {
+            Rc::new(FlatForward::new(
+                reference_date(),
+                rate,
+                Box::new(Actual360::new()),
+            ))
+        };
+        AnalyticDigitalAmericanEngine::new(GeneralizedBlackScholesProcess::new(
+            spot,
+            curve(r),
+            curve(q),
+            Rc::new(BlackConstantVol::new(
+                reference_date(),
+                0.2,
+                Box::new(Actual360::new()),
+            )),
+        ))
+    }
+
+    fn option(payoff: impl Into<VanillaPayoff>, payoff_at_expiry: bool) -> VanillaOption {
+        // Six months with Actual/360
+        VanillaOption::new(
+            payoff,
+            Box::new(AmericanExercise::new(
+                reference_date(),
+                reference_date() + 180,
+                payoff_at_expiry,
+            )),
+        )
+    }
+
+    #[test]
+    fn cash_at_hit_values() {
+        // QuantLib test-suite/digitaloption.cpp: K = 100, r = 10%, T = 0.5, sigma = 20%, cash 15
+        let cases: [(OptionType, Real, Real, Real); 6] = [
+            (OptionType::Put, 105.0, 0.0, 9.7264),
+            (OptionType::Call, 95.0, 0.0, 11.6553),
+            // In the money: immediate payoff
+            (OptionType::Call, 105.0, 0.0, 15.0),
+            (OptionType::Put, 95.0, 0.0, 15.0),
+            // Non-null dividend yield
+            (OptionType::Put, 105.0, 0.2, 12.2715),
+            (OptionType::Call, 95.0, 0.2, 8.9109),
+        ];
+        for (option_type, spot, q, expected) in cases {
+            let value: Real = engine(spot, q, 0.1)
+                .calculate(&option(
+                    CashOrNothingPayoff::new(option_type, 100.0, 15.0),
+                    false,
+                ))
+                .value;
+            assert!(
+                (value - expected).abs() < 1.0e-4,
+                "{option_type} at {spot}: {value} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn asset_at_hit_pays_the_strike() {
+        let cash: Real = engine(105.0, 0.0, 0.1)
+            .calculate(&option(
+                CashOrNothingPayoff::new(OptionType::Put, 100.0, 100.0),
+                false,
+            ))
+            .value;
+        let asset: Real = engine(105.0, 0.0, 0.1)
+            .calculate(&option(
+                AssetOrNothingPayoff::new(OptionType::Put, 100.0),
+                false,
+            ))
+            .value;
+        assert!((asset - cash).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn without_rates_paying_at_hit_or_at_expiry_is_the_same() {
+        for (option_type, spot) in [(OptionType::Put, 105.0), (OptionType::Call, 95.0)] {
+            let payoff: CashOrNothingPayoff = CashOrNothingPayoff::new(option_type, 100.0, 15.0);
+            let at_hit: Real = engine(spot, 0.0, 0.0)
+                .calculate(&option(payoff, false))
+                .value;
+            let at_expiry: Real = engine(spot, 0.0, 0.0)
+                .calculate(&option(payoff, true))
+                .value;
+            assert!((at_hit - at_expiry).abs() < 1.0e-12, "{}", option_type);
+            // With positive rates, waiting for expiry costs discounting
+            let at_hit: Real = engine(spot, 0.0, 0.1)
+                .calculate(&option(payoff, false))
+                .value;
+            let at_expiry: Real = engine(spot, 0.0, 0.1)
+                .calculate(&option(payoff, true))
+                .value;
+            assert!(at_expiry < at_hit && at_expiry > at_hit * (-0.05_f64).exp());
+        }
+        // Asset paid at expiry if touched: almost the spot for a strike next to it
+        let asset: Real = engine(100.5, 0.0, 0.1)
+            .calculate(&option(
+                AssetOrNothingPayoff::new(OptionType::Put, 100.0),
+                true,
+            ))
+            .value;
+        assert!(asset < 100.5 && asset > 90.0, "{}", asset);
+    }
+
+    #[test]
+    #[allow(clippy::type_complexity)]
+    fn calculate_panics_on_unsupported_options() {
+        let cases: [(fn() -> VanillaOption, &str); 3] = [
+            (
+                || {
+                    VanillaOption::new(
+                        CashOrNothingPayoff::new(OptionType::Put, 100.0, 15.0),
+                        Box::new(AmericanExercise::new(
+                            reference_date() + 30,
+                            reference_date() + 180,
+                            false,
+                        )),
+                    )
+                },
+                "a forward-starting exercise",
+            ),
+            (
+                || {
+                    VanillaOption::new(
+                        CashOrNothingPayoff::new(OptionType::Put, 100.0, 15.0),
+                        Box::new(EuropeanExercise::new(reference_date() + 180)),
+                    )
+                },
+                "a European exercise",
+            ),
+            (
+                || option(PlainVanillaPayoff::new(OptionType::Put, 100.0), false),
+                "a plain vanilla payoff",
+            ),
+        ];
+        for (option, label) in cases {
+            let result = std::panic::catch_unwind(|| engine(105.0, 0.0, 0.1).calculate(&option()));
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use crate::cashflows::dividend::{Dividend, DividendSchedule};
+use crate::exercise::{Exercise, ExerciseType};
+use crate::instruments::payoffs::StrikedTypePayoff;
+use crate::instruments::vanilla_option::VanillaOption;
+use crate::pricingengines::black_formula::black_formula;
+use crate::pricingengines::greeks::spot_greeks;
+use crate::pricingengines::vanilla::vanilla_option_results::VanillaOptionResults;
+use crate::processes::black_scholes_process::{
+    BlackScholesMertonProcess, GeneralizedBlackScholesProcess,
+};
+use crate::stochastic_process::StochasticProcess1D;
+use crate::time::date::Date;
+use crate::types::{Real, Time};
+
+/*
+Analytic engine for European options on a stock paying discrete
+dividends, under the escrowed dividend model (QuantLib
+AnalyticDividendEuropeanEngine). The dividends going ex before expiry
+are taken out of the spot, and the remaining risky part follows the
+Black-Scholes process:
+    S* = (S - sum_i D_i exp(-(r - q) t_i)) prod_j (1 - f_j)
+for cash dividends D_i and fractional dividends f_j, so the option is
+the Black option on the forward S* exp((r - q) T), with the Black
+volatility of the process at the expiry and strike. The option is
+traded on the reference date of the risk-free curve, on which the times
+of the dividends and expiry are measured. Delta and gamma, with respect
+to the full spot, come from central differences.
+*/
+pub struct AnalyticDividendEuropeanEngine {
+    process: GeneralizedBlackScholesProcess,
+    dividends: DividendSchedule,
+}
+impl AnalyticDividendEuropeanEngine {
+    // Constructor
+    pub fn new(
+        process: GeneralizedBlackScholesProcess,
+        dividends: DividendSchedule,
+    ) -> AnalyticDividendEuropeanEngine {
+        AnalyticDividendEuropeanEngine { process, dividends }
+    }
+
+    // Inspectors
+    pub fn process(&self) -> &GeneralizedBlackScholesProcess {
+        &self.process
+    }
+    pub fn dividends(&self) -> &DividendSchedule {
+        &self.dividends
+    }
+
+    pub fn calculate(&self, option: &VanillaOption) -> VanillaOptionResults {
+        let exercise: &dyn Exercise = option.exercise();
+        if exercise.exercise_type() != ExerciseType::European {
+            panic!("not an European option");
+        }
+        let payoff: &dyn StrikedTypePayoff = option.payoff().payoff();
+        let maturity: Time = self.process.time(exercise.last_date());
+        if maturity <= 0.0 {
+            panic!("option expired: maturity {maturity} before the reference date");
+        }
+        let process: BlackScholesMertonProcess = self
+            .process
+            .constant_volatility_process(exercise.last_date(), payoff.strike());
+        let carry: Real = process.risk_free_rate() - process.dividend_yield();
+        let mut riskless: Real = 0.0;
+        let mut retained: Real = 1.0;
+        let reference_date: Date = self.process.risk_free_rate().reference_date();
+        for dividend in self.dividends.between(reference_date, exercise.last_date()) {
+            match dividend {
+                Dividend::Fixed { date, amount } => {
+                    let t: Time = self.process.time(*date);
+                    riskless += amount * (-carry * t).exp();
+                }
+                Dividend::Fractional { rate, .. } => retained *= 1.0 - rate,
+            }
+        }
+
+        let std_dev: Real = process.volatility() * maturity.sqrt();
+        let discount: Real = (-process.risk_free_rate() * maturity).exp();
+        spot_greeks(
+            |spot| {
+                let risky: Real = (spot - riskless) * retained;
+                if risky <= 0.0 {
+                    panic!("dividends ({riskless}) exceed the spot ({spot}) in the escrowed model");
+                }
+                black_formula(
+                    payoff.option_type(),
+                    payoff.strike(),
+                    risky * (carry * maturity).exp(),
+                    std_dev,
+                    discount,
+                    0.0,
+                )
+            },
+            process.x0(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::EuropeanExercise;
+    use crate::instruments::payoffs::PlainVanillaPayoff;
+    use crate::option::OptionType;
+    use crate::termstructures::volatility::equityfx::black_constant_vol::BlackConstantVol;
+    use crate::termstructures::yieldcurves::flat_forward::FlatForward;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual360::Actual360;
+    use std::panic;
+    use std::rc::Rc;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    // Engine on the constant parameters of process, with structures on Actual/360
+    fn engine(
+        process: BlackScholesMertonProcess,
+        dividends: DividendSchedule,
+    ) -> AnalyticDividendEuropeanEngine {
+        let curve = |rate: Real| {
+            Rc::new(FlatForward::new(
+                reference_date(),
+                rate,
+                Box::new(Actual360::new()),
+            ))
+        };
+        let volatility: Rc<BlackConstantVol> = Rc::new(BlackConstantVol::new(
+            reference_date(),
+            process.volatility(),
+            Box::new(Actual360::new()),
+        ));
+        AnalyticDividendEuropeanEngine::new(
+            GeneralizedBlackScholesProcess::new(
+                process.x0(),
+                curve(process.risk_free_rate()),
+                curve(process.dividend_yield()),
+                volatility,
+            ),
+            dividends,
+        )
+    }
+
+    fn option(payoff: PlainVanillaPayoff, days: i32) -> VanillaOption {
+        VanillaOption::new(
+            payoff,
+            Box::new(EuropeanExercise::new(reference_date() + days)),
+        )
+    }
+
+    #[test]
+    fn hull_example() {
+        // Hull, "Options, Futures and Other Derivatives": dividends of 0.5 in
+        // two and five months on a six-month call
+        let dividends: DividendSchedule = DividendSchedule::from_cash_amounts(
+            &[reference_date() + 60, reference_date() + 150],
+            &[0.5, 0.5],
+        );
+        let results: VanillaOptionResults = engine(
+            BlackScholesMertonProcess::new(40.0, 0.09, 0.0, 0.3),
+            dividends,
+        )
+        .calculate(&option(
+            PlainVanillaPayoff::new(OptionType::Call, 40.0),
+            180,
+        ));
+        assert!((results.value - 3.67).abs() < 5.0e-3, "{}", results.value);
+        assert!(results.delta > 0.0 && results.gamma > 0.0);
+    }
+
+    #[test]
+    fn fractional_dividends_scale_the_spot() {
+        let process: BlackScholesMertonProcess =
+            BlackScholesMertonProcess::new(100.0, 0.05, 0.01, 0.2);
+        let payoff: PlainVanillaPayoff = PlainVanillaPayoff::new(OptionType::Put, 95.0);
+        let dividends: DividendSchedule = DividendSchedule::new(&[
+            Dividend::fractional(reference_date() + 90, 0.02),
+            Dividend::fractional(reference_date() + 270, 0.03),
+            // Paid after expiry: ignored
+            Dividend::fixed(reference_date() + 400, 10.0),
+        ]);
+        let value: Real = engine(process, dividends)
+            .calculate(&option(payoff, 360))
+            .value;
+        let expected: Real = black_formula(
+            OptionType::Put,
+            95.0,
+            100.0 * 0.98 * 0.97 * (0.04_f64).exp(),
+            0.2,
+            (-0.05_f64).exp(),
+            0.0,
+        );
+        assert!((value - expected).abs() < 1.0e-12, "{value} vs {expected}");
+    }
+
+    #[test]
+    fn calculate_panics_when_dividends_exceed_the_spot() {
+        let result = panic::catch_unwind(|| {
+            engine(
+                BlackScholesMertonProcess::new(10.0, 0.05, 0.0, 0.2),
+                DividendSchedule::from_cash_amounts(&[reference_date() + 30], &[12.0]),
+            )
+            .calculate(&option(
+                PlainVanillaPayoff::new(OptionType::Call, 10.0),
+                360,
+            ))
+        });
+        assert!(
+            result.is_err(),
+            "expected panic for dividends above the spot"
+        );
+    }
+}
@@ -0,0 +1,508 @@
+use crate::exercise::ExerciseType;
+use crate::instruments::vanilla_option::{VanillaOption, VanillaPayoff};
+use crate::math::distributions::normal_distribution::NormalDistribution;
+use crate::option::OptionType;
+use crate::pricingengines::black_formula::{
+    black_formula, black_formula_asset_itm_probability, black_formula_cash_itm_probability,
+};
+use crate::pricingengines::vanilla::european_option_results::EuropeanOptionResults;
+use crate::processes::black_scholes_process::GeneralizedBlackScholesProcess;
+use crate::stochastic_process::StochasticProcess1D;
+use crate::time::date::Date;
+use crate::types::{DiscountFactor, Rate, Real, Time};
+
+/*
+Black-Scholes-Merton engine for European options (QuantLib
+AnalyticEuropeanEngine, through its BlackCalculator). The option is the
+Black option on the forward F = S P_q(T) / P_r(T), discounted at
+P_r(T), and its value is written as
+    discount * (F alpha + x beta)
+with, for d1 and d2 of the Black formula and phi +1 for calls and -1
+for puts:
+    - plain vanilla: alpha = phi N(phi d1), beta = -phi N(phi d2), x = K
+    - cash-or-nothing: alpha = 0, beta = N(phi d2), x the cash amount
+    - asset-or-nothing: alpha = N(phi d1), beta = 0
+so all the greeks follow from the derivatives of alpha and beta with
+respect to d1 and d2. Theta comes from the Black-Scholes equation. The
+variance is the Black variance of the process at the expiry and strike
+of the option. Each structure measures the time to expiry with its own
+reference date and day counter: the volatility structure for vega, the
+risk-free curve for rho and theta, the dividend curve for the dividend
+rho.
+*/
+pub struct AnalyticEuropeanEngine {
+    process: GeneralizedBlackScholesProcess,
+}
+impl AnalyticEuropeanEngine {
+    // Constructor
+    pub fn new(process: GeneralizedBlackScholesProcess) -> AnalyticEuropeanEngine {
+        AnalyticEuropeanEngine { process }
+    }
+
+    // Inspectors
+    pub fn process(&self) -> &GeneralizedBlackScholesProcess {
+        &self.process
+    }
+
+    pub fn calculate(&self, option: &VanillaOption) -> EuropeanOptionResults {
+        if option.exercise().exercise_type() != ExerciseType::European {
+            panic!("not an European option");
+        }
+        let expiry: Date = option.exercise().last_date();
+        let maturity: Time = self.process.time(expiry);
+        if maturity <= 0.0 {
+            panic!("option expired: maturity {maturity} before the reference date");
+        }
+        let payoff: &VanillaPayoff = option.payoff();
+        let volatility_time: Time = self.process.black_volatility().time_from_reference(expiry);
+        let dividend_time: Time = self.process.dividend_yield().time_from_reference(expiry);
+        let variance: Real = self
+            .process
+            .black_volatility()
+            .black_variance(volatility_time, payoff.strike());
+        if variance <= 0.0 {
+            panic!("variance ({variance}) must be positive");
+        }
+
+        let spot: Real = self.process.x0();
+        let discount: DiscountFactor = self.process.risk_free_rate().discount_date(expiry);
+        let dividend_discount: DiscountFactor = self.process.dividend_yield().discount_date(expiry);
+        let forward: Real = spot * dividend_discount / discount;
+        let std_dev: Real = variance.sqrt();
+
+        let option_type: OptionType = payoff.option_type();
+        let strike: Real = payoff.strike();
+        let sign: Real = option_type.sign();
+        let d1: Real = (forward / strike).ln() / std_dev + 0.5 * std_dev;
+        let d2: Real = d1 - std_dev;
+        let n_d1: Real = NormalDistribution::default().value(d1);
+        let n_d2: Real = NormalDistribution::default().value(d2);
+        let asset_itm: Real =
+            black_formula_asset_itm_probability(option_type, strike, forward, std_dev, 0.0);
+        let cash_itm: Real =
+            black_formula_cash_itm_probability(option_type, strike, forward, std_dev, 0.0);
+
+        // alpha, x, d alpha / d d1 and d beta / d d2 (beta only enters the value)
+        let (value, alpha, x, d_alpha, d_beta): (Real, Real, Real, Real, Real) = match payoff {
+            VanillaPayoff::PlainVanilla(_) => (
+                black_formula(option_type, strike, forward, std_dev, discount, 0.0),
+                sign * asset_itm,
+                strike,
+                n_d1,
+                -n_d2,
+            ),
+            VanillaPayoff::CashOrNothing(payoff) => (
+                discount * payoff.cash_payoff() * cash_itm,
+                0.0,
+                payoff.cash_payoff(),
+                0.0,
+                sign * n_d2,
+            ),
+            VanillaPayoff::AssetOrNothing(_) => (
+                discount * forward * asset_itm,
+                asset_itm,
+                0.0,
+                sign * n_d1,
+                0.0,
+            ),
+        };
+
+        let d_alpha_d_spot: Real = d_alpha / (std_dev * spot);
+        let d_beta_d_spot: Real = d_beta / (std_dev * spot);
+        let delta: Real =
+            discount * (forward / spot * alpha + forward * d_alpha_d_spot + x * d_beta_d_spot);
+        let d2_alpha_d_spot2: Real = -d_alpha_d_spot / spot * (1.0 + d1 / std_dev);
+        let d2_beta_d_spot2: Real = -d_beta_d_spot / spot * (1.0 + d2 / std_dev);
+        let gamma: Real = discount
+            * (forward * d2_alpha_d_spot2
+                + x * d2_beta_d_spot2
+                + 2.0 * forward / spot * d_alpha_d_spot);
+        let log_moneyness: Real = (strike / forward).ln() / variance;
+        let vega: Real = discount
+            * volatility_time.sqrt()
+            * (forward * d_alpha * (log_moneyness + 0.5) + x * d_beta * (log_moneyness - 0.5));
+        let rho: Real = maturity
+            * (discount * (forward * (d_alpha / std_dev + alpha) + x * d_beta / std_dev) - value);
+        let dividend_rho: Real = -dividend_time
+            * discount
+            * (forward * (d_alpha / std_dev + alpha) + x * d_beta / std_dev);
+        // Black-Scholes equation: theta + (r - q) S delta + sigma^2 S^2 gamma / 2 = r value
+        let r: Rate = self.process.risk_free_rate().zero_rate(maturity);
+        let q: Rate = self.process.dividend_yield().zero_rate(dividend_time);
+        let theta: Real = r * value
+            - (r - q) * spot * delta
+            - 0.5 * variance / volatility_time * spot * spot * gamma;
+        EuropeanOptionResults {
+            value,
+            delta,
+            gamma,
+            vega,
+            theta,
+            rho,
+            dividend_rho,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::{AmericanExercise, EuropeanExercise};
+    use crate::instruments::payoffs::{
+        AssetOrNothingPayoff, CashOrNothingPayoff, PlainVanillaPayoff,
+    };
+    use crate::termstructures::volatility::equityfx::black_constant_vol::BlackConstantVol;
+    use crate::termstructures::volatility::equityfx::black_variance_curve::{
+        BlackVarianceCurve, VarianceInterpolation,
+    };
+    use crate::termstructures::yieldcurves::flat_forward::FlatForward;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+    use crate::time::date::Month;
+    use crate::time::day_counter::DayCounter;
+    use crate::time::daycounters::actual360::Actual360;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use std::panic;
+    use std::rc::Rc;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn curve(rate: Real) -> Rc<dyn YieldTermStructure> {
+        Rc::new(FlatForward::new(
+            reference_date(),
+            rate,
+            Box::new(Actual360::new()),
+        ))
+    }
+
+    // Structures on Actual/360, so that expiries of whole days give Haug's times
+    fn engine(spot: Real, q: Real, r: Real, volatility: Real) -> AnalyticEuropeanEngine {
+        AnalyticEuropeanEngine::new(GeneralizedBlackScholesProcess::new(
+            spot,
+            curve(r),
+            curve(q),
+            Rc::new(BlackConstantVol::new(
+                reference_date(),
+                volatility,
+                Box::new(Actual360::new()),
+            )),
+        ))
+    }
+
+    fn option(payoff: impl Into<VanillaPayoff>, days: i32) -> VanillaOption {
+        VanillaOption::new(
+            payoff,
+            Box::new(EuropeanExercise::new(reference_date() + days)),
+        )
+    }
+
+    #[test]
+    #[allow(clippy::type_complexity)]
+    fn haug_values_and_greeks() {
+        // Haug, "Option pricing formulas", pp. 2-18, as in QuantLib's European option tests:
+        // type, strike, spot, q, r, days, vol, greek, expected
+        type Greek = fn(&EuropeanOptionResults) -> Real;
+        let cases: [(OptionType, Real, Real, Real, Real, i32, Real, Greek, Real); 11] = [
+            (
+                OptionType::Call,
+                65.0,
+                60.0,
+                0.0,
+                0.08,
+                90,
+                0.30,
+                |g| g.value,
+                2.1334,
+            ),
+            (
+                OptionType::Put,
+                95.0,
+                100.0,
+                0.05,
+                0.10,
+                180,
+                0.20,
+                |g| g.value,
+                2.4648,
+            ),
+            (
+                OptionType::Call,
+                100.0,
+                105.0,
+                0.10,
+                0.10,
+                180,
+                0.36,
+                |g| g.delta,
+                0.5946,
+            ),
+            (
+                OptionType::Put,
+                100.0,
+                105.0,
+                0.10,
+                0.10,
+                180,
+                0.36,
+                |g| g.delta,
+                -0.3566,
+            ),
+            (
+                OptionType::Call,
+                60.0,
+                55.0,
+                0.0,
+                0.10,
+                270,
+                0.30,
+                |g| g.gamma,
+                0.0278,
+            ),
+            (
+                OptionType::Put,
+                60.0,
+                55.0,
+                0.0,
+                0.10,
+                270,
+                0.30,
+                |g| g.gamma,
+                0.0278,
+            ),
+            (
+                OptionType::Call,
+                60.0,
+                55.0,
+                0.0,
+                0.10,
+                270,
+                0.30,
+                |g| g.vega,
+                18.9358,
+            ),
+            (
+                OptionType::Put,
+                60.0,
+                55.0,
+                0.0,
+                0.10,
+                270,
+                0.30,
+                |g| g.vega,
+                18.9358,
+            ),
+            (
+                OptionType::Put,
+                405.0,
+                430.0,
+                0.05,
+                0.07,
+                30,
+                0.20,
+                |g| g.theta,
+                -31.1924,
+            ),
+            (
+                OptionType::Call,
+                75.0,
+                72.0,
+                0.0,
+                0.09,
+                360,
+                0.19,
+                |g| g.rho,
+                38.7325,
+            ),
+            (
+                OptionType::Put,
+                490.0,
+                500.0,
+                0.05,
+                0.08,
+                90,
+                0.15,
+                |g| g.dividend_rho,
+                42.2254,
+            ),
+        ];
+        for (option_type, strike, spot, q, r, days, vol, greek, expected) in cases {
+            let results: EuropeanOptionResults = engine(spot, q, r, vol)
+                .calculate(&option(PlainVanillaPayoff::new(option_type, strike), days));
+            let calculated: Real = greek(&results);
+            assert!(
+                (calculated - expected).abs() < 1.0e-4,
+                "{option_type} {strike}: {calculated} instead of {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn haug_digital_values() {
+        // Haug, "Option pricing formulas", p. 88 and p. 90
+        let cash: EuropeanOptionResults = engine(100.0, 0.06, 0.06, 0.35).calculate(&option(
+            CashOrNothingPayoff::new(OptionType::Put, 80.0, 10.0),
+            270,
+        ));
+        assert!((cash.value - 2.6710).abs() < 1.0e-4, "{}", cash.value);
+        let asset: EuropeanOptionResults = engine(70.0, 0.05, 0.07, 0.27).calculate(&option(
+            AssetOrNothingPayoff::new(OptionType::Put, 65.0),
+            180,
+        ));
+        assert!((asset.value - 20.2069).abs() < 1.0e-4, "{}", asset.value);
+    }
+
+    #[test]
+    fn greeks_match_finite_differences() {
+        let (spot, q, r, vol, days): (Real, Real, Real, Real, i32) = (100.0, 0.03, 0.05, 0.25, 270);
+        let payoffs: [VanillaPayoff; 6] = [
+            PlainVanillaPayoff::new(OptionType::Call, 105.0).into(),
+            PlainVanillaPayoff::new(OptionType::Put, 95.0).into(),
+            CashOrNothingPayoff::new(OptionType::Call, 105.0, 10.0).into(),
+            CashOrNothingPayoff::new(OptionType::Put, 95.0, 10.0).into(),
+            AssetOrNothingPayoff::new(OptionType::Call, 105.0).into(),
+            AssetOrNothingPayoff::new(OptionType::Put, 95.0).into(),
+        ];
+        let h: Real = 1.0e-4;
+        for payoff in payoffs {
+            let value = |spot: Real, q: Real, r: Real, vol: Real, days: i32| -> Real {
+                engine(spot, q, r, vol)
+                    .calculate(&option(payoff, days))
+                    .value
+            };
+            let results: EuropeanOptionResults =
+                engine(spot, q, r, vol).calculate(&option(payoff, days));
+            let ds: Real = spot * h;
+            let expected: [(Real, Real); 6] = [
+                (
+                    results.delta,
+                    (value(spot + ds, q, r, vol, days) - value(spot - ds, q, r, vol, days))
+                        / (2.0 * ds),
+                ),
+                (
+                    results.gamma,
+                    (value(spot + ds, q, r, vol, days) - 2.0 * results.value
+                        + value(spot - ds, q, r, vol, days))
+                        / (ds * ds),
+                ),
+                (
+                    results.vega,
+                    (value(spot, q, r, vol + h, days) - value(spot, q, r, vol - h, days))
+                        / (2.0 * h),
+                ),
+                (
+                    results.rho,
+                    (value(spot, q, r + h, vol, days) - value(spot, q, r - h, vol, days))
+                        / (2.0 * h),
+                ),
+                (
+                    results.dividend_rho,
+                    (value(spot, q + h, r, vol, days) - value(spot, q - h, r, vol, days))
+                        / (2.0 * h),
+                ),
+                // One day less to expiry, a year of 360 days
+                (
+                    results.theta,
+                    (value(spot, q, r, vol, days - 1) - value(spot, q, r, vol, days + 1)) * 180.0,
+                ),
+            ];
+            for (calculated, bumped) in expected {
+                assert!(
+                    (calculated - bumped).abs() < 1.0e-3 * (1.0 + bumped.abs()),
+                    "{}: {} instead of {}",
+                    payoff.payoff().description(),
+                    calculated,
+                    bumped
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn put_call_parity() {
+        let engine: AnalyticEuropeanEngine = engine(100.0, 0.02, 0.05, 0.3);
+        let call: EuropeanOptionResults = engine.calculate(&option(
+            PlainVanillaPayoff::new(OptionType::Call, 110.0),
+            360,
+        ));
+        let put: EuropeanOptionResults = engine.calculate(&option(
+            PlainVanillaPayoff::new(OptionType::Put, 110.0),
+            360,
+        ));
+        let forward_value: Real = 100.0 * (-0.02_f64).exp() - 110.0 * (-0.05_f64).exp();
+        assert!((call.value - put.value - forward_value).abs() < 1.0e-12);
+        assert!((call.delta - put.delta - (-0.02_f64).exp()).abs() < 1.0e-12);
+        assert!((call.gamma - put.gamma).abs() < 1.0e-12);
+        assert!((call.vega - put.vega).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn calculate_panics_on_invalid_options() {
+        let payoff: PlainVanillaPayoff = PlainVanillaPayoff::new(OptionType::Call, 100.0);
+        let result = panic::catch_unwind(|| {
+            let exercise: AmericanExercise =
+                AmericanExercise::new(reference_date(), reference_date() + 90, false);
+            engine(100.0, 0.0, 0.05, 0.2).calculate(&VanillaOption::new(payoff, Box::new(exercise)))
+        });
+        assert!(result.is_err(), "expected panic for American exercise");
+        let cases: [(&str, Real, i32); 2] =
+            [("expired option", 0.2, 0), ("null volatility", 0.0, 90)];
+        for (case, volatility, days) in cases {
+            let result = panic::catch_unwind(|| {
+                engine(100.0, 0.0, 0.05, volatility).calculate(&option(payoff, days))
+            });
+            assert!(result.is_err(), "expected panic for {case}");
+        }
+    }
+
+    #[test]
+    fn volatility_is_read_at_the_expiry() {
+        let curve: Rc<BlackVarianceCurve> = Rc::new(BlackVarianceCurve::new(
+            reference_date(),
+            &[reference_date() + 180, reference_date() + 360],
+            &[0.2, 0.3],
+            Box::new(Actual360::new()),
+            VarianceInterpolation::Linear,
+        ));
+        let engine: AnalyticEuropeanEngine = AnalyticEuropeanEngine::new(
+            GeneralizedBlackScholesProcess::new(100.0, self::curve(0.05), self::curve(0.0), curve),
+        );
+        for (days, volatility) in [(180, 0.2), (360, 0.3)] {
+            let payoff: PlainVanillaPayoff = PlainVanillaPayoff::new(OptionType::Call, 100.0);
+            let expected: EuropeanOptionResults =
+                self::engine(100.0, 0.0, 0.05, volatility).calculate(&option(payoff, days));
+            let results: EuropeanOptionResults = engine.calculate(&option(payoff, days));
+            assert!((results.value - expected.value).abs() < 1.0e-12);
+            assert!((results.vega - expected.vega).abs() < 1.0e-12);
+        }
+    }
+
+    #[test]
+    fn each_structure_measures_its_own_time() {
+        // Rates on Actual/365 (Fixed), volatility on Actual/360
+        let engine: AnalyticEuropeanEngine =
+            AnalyticEuropeanEngine::new(GeneralizedBlackScholesProcess::new(
+                100.0,
+                flat_rate(reference_date(), 0.05),
+                flat_rate(reference_date(), 0.02),
+                Rc::new(BlackConstantVol::new(
+                    reference_date(),
+                    0.2,
+                    Box::new(Actual360::new()),
+                )),
+            ));
+        let payoff: PlainVanillaPayoff = PlainVanillaPayoff::new(OptionType::Call, 100.0);
+        let results: EuropeanOptionResults = engine.calculate(&option(payoff, 180));
+        let discount: DiscountFactor = (-0.05 * 180.0 / 365.0_f64).exp();
+        let forward: Real = 100.0 * (0.03 * 180.0 / 365.0_f64).exp();
+        let std_dev: Real = 0.2 * 0.5_f64.sqrt();
+        let expected: Real =
+            black_formula(OptionType::Call, 100.0, forward, std_dev, discount, 0.0);
+        assert!((results.value - expected).abs() < 1.0e-12);
+        assert_eq!(
+            engine.process().time(reference_date() + 180),
+            Actual365Fixed::new().year_fraction(reference_date(), reference_date() + 180)
+        );
+    }
+}
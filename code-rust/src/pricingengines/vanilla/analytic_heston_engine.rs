@@ -0,0 +1,530 @@
+use crate::exercise::{Exercise, ExerciseType};
+use crate::instruments::payoffs::{PlainVanillaPayoff, StrikedTypePayoff};
+use crate::instruments::vanilla_option::{VanillaOption, VanillaPayoff};
+use crate::math::complex::Complex;
+use crate::math::integrals::gauss_lobatto_integral::GaussLobattoIntegral;
+use crate::math::integrals::gaussian_quadratures::GaussLaguerreIntegration;
+use crate::models::equity::heston_model::HestonModel;
+use crate::option::OptionType;
+use crate::pricingengines::vanilla::vanilla_option_results::VanillaOptionResults;
+use crate::types::{DiscountFactor, Real, Size, Time};
+use std::cell::Cell;
+use std::f64::consts::PI;
+
+// Form of the complex logarithm in the characteristic function (QuantLib AnalyticHestonEngine::ComplexLogFormula)
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ComplexLogFormula {
+    // Gatheral's form, continuous with the principal logarithm (Lord-Kahl)
+    Gatheral,
+    // Heston's original form with the Kahl-Jackel rotation count
+    BranchCorrection,
+}
+
+/*
+Integration scheme over [0, inf) for the Heston probabilities (QuantLib
+AnalyticHestonEngine::Integration).
+
+Gauss-Laguerre integrates f(u) = exp(-u) (exp(u) f(u)) on fixed nodes.
+Gauss-Lobatto is adaptive: [0, inf) is mapped onto [-1, 1] by
+    u = -ln((1 - x) / 2) / c_inf
+where c_inf is the asymptotic decay rate of the integrand.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub enum Integration {
+    GaussLaguerre(GaussLaguerreIntegration),
+    GaussLobatto(GaussLobattoIntegral),
+}
+impl Integration {
+    pub fn gauss_laguerre(order: Size) -> Integration {
+        Integration::GaussLaguerre(GaussLaguerreIntegration::new(order, 0.0))
+    }
+    pub fn gauss_lobatto(
+        rel_tolerance: Real,
+        abs_tolerance: Real,
+        max_evaluations: Size,
+    ) -> Integration {
+        Integration::GaussLobatto(GaussLobattoIntegral::new(
+            max_evaluations,
+            abs_tolerance,
+            Some(rel_tolerance),
+            false,
+        ))
+    }
+
+    // Integral of f over [0, inf)
+    pub fn calculate<F>(&self, c_inf: Real, f: F) -> Real
+    where
+        F: Fn(Real) -> Real,
+    {
+        match self {
+            Integration::GaussLaguerre(quadrature) => quadrature
+                .x()
+                .iter()
+                .zip(quadrature.weights())
+                // exp(ln w + u) since w underflows at the far nodes
+                .map(|(u, w)| (w.ln() + u).exp() * f(*u))
+                .sum(),
+            Integration::GaussLobatto(integrator) => integrator.integrate(
+                |x: Real| {
+                    if x >= 1.0 {
+                        return 0.0;
+                    }
+                    let u: Real = -(0.5 - 0.5 * x).ln() / c_inf;
+                    f(u) / (c_inf * (1.0 - x))
+                },
+                -1.0,
+                1.0,
+            ),
+        }
+    }
+}
+
+/*
+Analytic engine for European options under Heston (QuantLib
+AnalyticHestonEngine).
+
+With F the forward, x = ln(F / K) and phi_j the characteristic function
+of ln(S_T / F) under the share (j = 1) and risk-neutral (j = 0)
+measures,
+    P_j = 1/2 + 1/pi int_0^inf Im(exp(i u x) phi_j(u)) / u du
+    call = D(T) (F P_1 - K P_0)
+and puts follow from put-call parity. Following Gatheral, with
+    alpha = -u^2/2 - i u/2 + i j u,   beta = kappa - rho sigma j - i rho sigma u
+    d = sqrt(beta^2 - 2 alpha sigma^2),  r- = (beta - d) / sigma^2,  g = (beta - d) / (beta + d)
+    D = r- (1 - exp(-d T)) / (1 - g exp(-d T))
+    C = kappa (r- T - 2 / sigma^2 ln((1 - g exp(-d T)) / (1 - g)))
+ln phi_j = C theta + D v0. Heston's original form uses 1/g and exp(+d T)
+instead; its logarithm crosses the branch cut and is corrected by
+counting the rotations between successive points, hence it requires the
+increasing nodes of Gauss-Laguerre. Delta is exp(-q T) P_1 (minus
+exp(-q T) for puts) and gamma exp(-q T) / S dP_1/dx. The maturity is
+measured on the risk-free curve of the process.
+*/
+pub struct AnalyticHestonEngine {
+    model: HestonModel,
+    integration: Integration,
+    formula: ComplexLogFormula,
+}
+impl AnalyticHestonEngine {
+    // Constructor
+    pub fn new(
+        model: HestonModel,
+        integration: Integration,
+        formula: ComplexLogFormula,
+    ) -> AnalyticHestonEngine {
+        detail::check_formula(&integration, formula);
+        AnalyticHestonEngine {
+            model,
+            integration,
+            formula,
+        }
+    }
+
+    // Inspectors
+    pub fn model(&self) -> &HestonModel {
+        &self.model
+    }
+
+    pub fn calculate(&self, option: &VanillaOption) -> VanillaOptionResults {
+        let exercise: &dyn Exercise = option.exercise();
+        if exercise.exercise_type() != ExerciseType::European {
+            panic!("not an European option");
+        }
+        let payoff: &PlainVanillaPayoff = match option.payoff() {
+            VanillaPayoff::PlainVanilla(payoff) => payoff,
+            _ => panic!("non plain-vanilla payoff given"),
+        };
+        let maturity: Time = self.model.process().time(exercise.last_date());
+        if maturity <= 0.0 {
+            panic!("option expired: maturity {maturity} before the reference date");
+        }
+        AnalyticHestonEngine::do_calculation(
+            &self.model,
+            payoff.option_type(),
+            payoff.strike(),
+            maturity,
+            &self.integration,
+            self.formula,
+        )
+    }
+
+    // Price of the option expiring at maturity (QuantLib doCalculation)
+    pub fn do_calculation(
+        model: &HestonModel,
+        option_type: OptionType,
+        strike: Real,
+        maturity: Time,
+        integration: &Integration,
+        formula: ComplexLogFormula,
+    ) -> VanillaOptionResults {
+        detail::check_formula(integration, formula);
+        let spot: Real = model.process().s0();
+        let risk_free_discount: DiscountFactor =
+            model.process().risk_free_rate().discount(maturity);
+        let dividend_discount: DiscountFactor = model.process().dividend_yield().discount(maturity);
+        let forward: Real = spot * dividend_discount / risk_free_discount;
+        let x: Real = (forward / strike).ln();
+        let c_inf: Real = (1.0 - model.rho() * model.rho()).sqrt() / model.sigma();
+        let c_inf: Real =
+            c_inf.clamp(1.0e-4, 0.2) * (model.v0() + model.kappa() * model.theta() * maturity);
+
+        let integral = |j: Real, part: fn(Complex, Real) -> Real| -> Real {
+            // Phase of the logarithm and its rotation count, for the branch correction
+            let branch: Cell<(Real, Real)> = Cell::new((0.0, 0.0));
+            integration.calculate(c_inf, |u: Real| {
+                // The integrands have finite limits at u = 0
+                let u: Real = u.max(Real::EPSILON.sqrt());
+                let exponent: Complex = match formula {
+                    ComplexLogFormula::Gatheral => detail::gatheral_exponent(model, u, j, maturity),
+                    ComplexLogFormula::BranchCorrection => {
+                        detail::heston_exponent(model, u, j, maturity, &branch)
+                    }
+                };
+                part((exponent + Complex::new(0.0, u * x)).exp(), u)
+            }) / PI
+        };
+        let p1: Real = 0.5 + integral(1.0, |z, u| z.im / u);
+        let p0: Real = 0.5 + integral(0.0, |z, u| z.im / u);
+        let p1_density: Real = integral(1.0, |z, _| z.re);
+
+        let call: Real = risk_free_discount * (forward * p1 - strike * p0);
+        let (value, delta): (Real, Real) = match option_type {
+            OptionType::Call => (call, dividend_discount * p1),
+            OptionType::Put => (
+                call - risk_free_discount * (forward - strike),
+                dividend_discount * (p1 - 1.0),
+            ),
+        };
+        VanillaOptionResults {
+            value,
+            delta,
+            gamma: dividend_discount * p1_density / spot,
+        }
+    }
+}
+
+pub(crate) mod detail {
+    use super::{ComplexLogFormula, Integration};
+    use crate::math::complex::Complex;
+    use crate::models::equity::heston_model::HestonModel;
+    use crate::types::{Real, Time};
+    use std::cell::Cell;
+    use std::f64::consts::PI;
+
+    pub(super) fn check_formula(integration: &Integration, formula: ComplexLogFormula) {
+        if let Integration::GaussLobatto(_) = integration
+            && formula == ComplexLogFormula::BranchCorrection
+        {
+            panic!("branch correction requires the increasing nodes of Gauss-Laguerre");
+        }
+    }
+
+    // d and beta of the characteristic function
+    fn d_beta(model: &HestonModel, u: Real, j: Real) -> (Complex, Complex, Complex) {
+        let (sigma, rho): (Real, Real) = (model.sigma(), model.rho());
+        let alpha: Complex = Complex::new(-0.5 * u * u, u * (j - 0.5));
+        let beta: Complex = Complex::new(model.kappa() - rho * sigma * j, -rho * sigma * u);
+        let d: Complex = (beta * beta - alpha * (2.0 * sigma * sigma)).sqrt();
+        (alpha, beta, d)
+    }
+
+    // ln phi_j(u) in Gatheral's form
+    pub(crate) fn gatheral_exponent(model: &HestonModel, u: Real, j: Real, t: Time) -> Complex {
+        let sigma2: Real = model.sigma() * model.sigma();
+        let (alpha, beta, d): (Complex, Complex, Complex) = d_beta(model, u, j);
+        // (beta - d) / sigma^2 without the cancellation, as beta^2 - d^2 = 2 alpha sigma^2
+        let r_minus: Complex = 2.0 * alpha / (beta + d);
+        let g: Complex = r_minus * sigma2 / (beta + d);
+        let e: Complex = (-d * t).exp();
+        let big_d: Complex = r_minus * (1.0 - e) / (1.0 - g * e);
+        let big_c: Complex =
+            model.kappa() * (r_minus * t - 2.0 / sigma2 * ((1.0 - g * e) / (1.0 - g)).ln());
+        big_c * model.theta() + big_d * model.v0()
+    }
+
+    /*
+    ln phi_j(u) in Heston's form, ln((1 - G exp(d T)) / (1 - G)) with
+    G = (beta + d) / (beta - d). Its principal value, computed without
+    overflowing exp(d T), jumps by 2 pi across the branch cut; branch
+    holds the previous phase and the rotation count making it continuous
+    along increasing u.
+    */
+    pub(super) fn heston_exponent(
+        model: &HestonModel,
+        u: Real,
+        j: Real,
+        t: Time,
+        branch: &Cell<(Real, Real)>,
+    ) -> Complex {
+        let sigma2: Real = model.sigma() * model.sigma();
+        let (alpha, beta, d): (Complex, Complex, Complex) = d_beta(model, u, j);
+        let r_plus: Complex = (beta + d) / sigma2;
+        let big_g: Complex = (beta + d) * (beta + d) / (alpha * (2.0 * sigma2));
+        let e: Complex = (-d * t).exp();
+        let big_d: Complex = r_plus * (e - 1.0) / (e - big_g);
+
+        let log: Complex = d * t + (e - big_g).ln() - (1.0 - big_g).ln();
+        let phase: Real = log.im - 2.0 * PI * (log.im / (2.0 * PI)).round();
+        let (previous, mut rotations): (Real, Real) = branch.get();
+        if phase - previous > PI {
+            rotations -= 1.0;
+        } else if previous - phase > PI {
+            rotations += 1.0;
+        }
+        branch.set((phase, rotations));
+        let log: Complex = Complex::new(log.re, phase + 2.0 * PI * rotations);
+
+        let big_c: Complex = model.kappa() / sigma2 * (r_plus * sigma2 * t - 2.0 * log);
+        big_c * model.theta() + big_d * model.v0()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::AmericanExercise;
+    use crate::exercise::EuropeanExercise;
+    use crate::instruments::payoffs::CashOrNothingPayoff;
+    use crate::pricingengines::black_formula::black_formula;
+    use crate::processes::heston_process::HestonProcess;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::{Date, Month};
+
+    fn reference_date() -> Date {
+        Date::new(1, Month::January, 2025)
+    }
+
+    fn option(option_type: OptionType, strike: Real, expiry: Date) -> VanillaOption {
+        VanillaOption::new(
+            PlainVanillaPayoff::new(option_type, strike),
+            Box::new(EuropeanExercise::new(expiry)),
+        )
+    }
+
+    fn engine(
+        process: HestonProcess,
+        integration: Integration,
+        formula: ComplexLogFormula,
+    ) -> AnalyticHestonEngine {
+        AnalyticHestonEngine::new(HestonModel::new(process), integration, formula)
+    }
+
+    #[test]
+    fn european_options_match_lewis_reference_prices() {
+        // Lewis (2000): S = 100, T = 1, r = 1%, q = 2%, v0 = 0.04, kappa = 4,
+        // theta = 0.25, sigma = 1, rho = -0.5
+        let process: HestonProcess = HestonProcess::new(
+            100.0,
+            flat_rate(reference_date(), 0.01),
+            flat_rate(reference_date(), 0.02),
+            0.04,
+            4.0,
+            0.25,
+            1.0,
+            -0.5,
+        );
+        let expiry: Date = Date::new(1, Month::January, 2026);
+        let cases: [(OptionType, Real, Real); 4] = [
+            (OptionType::Call, 90.0, 20.93334900059671),
+            (OptionType::Call, 100.0, 16.07015491702883),
+            (OptionType::Call, 110.0, 12.13221151670983),
+            (OptionType::Put, 100.0, 17.05527096127011),
+        ];
+        let engines: [(AnalyticHestonEngine, &str); 3] = [
+            (
+                engine(
+                    process.clone(),
+                    Integration::gauss_laguerre(128),
+                    ComplexLogFormula::Gatheral,
+                ),
+                "Gauss-Laguerre",
+            ),
+            (
+                engine(
+                    process.clone(),
+                    Integration::gauss_laguerre(128),
+                    ComplexLogFormula::BranchCorrection,
+                ),
+                "branch correction",
+            ),
+            (
+                engine(
+                    process.clone(),
+                    Integration::gauss_lobatto(1.0e-12, 1.0e-12, 100000),
+                    ComplexLogFormula::Gatheral,
+                ),
+                "Gauss-Lobatto",
+            ),
+        ];
+        for (engine, label) in &engines {
+            for (option_type, strike, expected) in cases {
+                let value: Real = engine.calculate(&option(option_type, strike, expiry)).value;
+                assert!(
+                    (value - expected).abs() < 1.0e-8,
+                    "{label} {option_type} {strike}: {value} vs {expected}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn branch_correction_agrees_with_gatheral_at_long_maturities() {
+        // High vol of vol and long maturity: the uncorrected logarithm jumps
+        let process: HestonProcess = HestonProcess::new(
+            100.0,
+            flat_rate(reference_date(), 0.03),
+            flat_rate(reference_date(), 0.0),
+            0.09,
+            0.5,
+            0.09,
+            1.5,
+            -0.9,
+        );
+        for strike in [50.0, 100.0, 200.0] {
+            let option: VanillaOption =
+                option(OptionType::Call, strike, Date::new(1, Month::January, 2035));
+            let gatheral: Real = engine(
+                process.clone(),
+                Integration::gauss_laguerre(128),
+                ComplexLogFormula::Gatheral,
+            )
+            .calculate(&option)
+            .value;
+            let corrected: Real = engine(
+                process.clone(),
+                Integration::gauss_laguerre(128),
+                ComplexLogFormula::BranchCorrection,
+            )
+            .calculate(&option)
+            .value;
+            assert!(
+                (gatheral - corrected).abs() < 1.0e-8,
+                "{strike}: {gatheral} vs {corrected}"
+            );
+        }
+    }
+
+    #[test]
+    fn small_vol_of_vol_gives_black_scholes() {
+        let process: HestonProcess = HestonProcess::new(
+            100.0,
+            flat_rate(reference_date(), 0.05),
+            flat_rate(reference_date(), 0.02),
+            0.04,
+            2.0,
+            0.04,
+            1.0e-3,
+            0.0,
+        );
+        let results: VanillaOptionResults = engine(
+            process.clone(),
+            Integration::gauss_laguerre(128),
+            ComplexLogFormula::Gatheral,
+        )
+        .calculate(&option(
+            OptionType::Call,
+            105.0,
+            Date::new(1, Month::January, 2026),
+        ));
+        let expected: Real = black_formula(
+            OptionType::Call,
+            105.0,
+            100.0 * 0.03_f64.exp(),
+            0.2,
+            (-0.05_f64).exp(),
+            0.0,
+        );
+        assert!(
+            (results.value - expected).abs() < 1.0e-4,
+            "{} vs {}",
+            results.value,
+            expected
+        );
+    }
+
+    #[test]
+    fn greeks_match_finite_differences() {
+        let value = |spot: Real, option_type: OptionType| -> VanillaOptionResults {
+            engine(
+                HestonProcess::new(
+                    spot,
+                    flat_rate(reference_date(), 0.03),
+                    flat_rate(reference_date(), 0.01),
+                    0.05,
+                    1.5,
+                    0.06,
+                    0.6,
+                    -0.7,
+                ),
+                Integration::gauss_laguerre(128),
+                ComplexLogFormula::Gatheral,
+            )
+            .calculate(&option(option_type, 95.0, Date::new(1, Month::July, 2026)))
+        };
+        let h: Real = 1.0e-2;
+        for option_type in [OptionType::Call, OptionType::Put] {
+            let results: VanillaOptionResults = value(100.0, option_type);
+            let up: Real = value(100.0 + h, option_type).value;
+            let down: Real = value(100.0 - h, option_type).value;
+            let delta: Real = (up - down) / (2.0 * h);
+            let gamma: Real = (up - 2.0 * results.value + down) / (h * h);
+            assert!((results.delta - delta).abs() < 1.0e-6, "{results:?}");
+            assert!((results.gamma - gamma).abs() < 1.0e-5, "{results:?}");
+        }
+    }
+
+    #[test]
+    fn calculate_panics_on_invalid_input() {
+        let process = || -> HestonProcess {
+            HestonProcess::new(
+                100.0,
+                flat_rate(reference_date(), 0.05),
+                flat_rate(reference_date(), 0.02),
+                0.04,
+                2.0,
+                0.04,
+                0.3,
+                -0.5,
+            )
+        };
+        let result = std::panic::catch_unwind(|| {
+            engine(
+                process(),
+                Integration::gauss_laguerre(64),
+                ComplexLogFormula::Gatheral,
+            )
+            .calculate(&VanillaOption::new(
+                PlainVanillaPayoff::new(OptionType::Put, 100.0),
+                Box::new(AmericanExercise::new(
+                    Date::new(1, Month::January, 2025),
+                    Date::new(1, Month::January, 2026),
+                    false,
+                )),
+            ))
+        });
+        assert!(result.is_err(), "expected panic for American exercise");
+        let result = std::panic::catch_unwind(|| {
+            engine(
+                process(),
+                Integration::gauss_laguerre(64),
+                ComplexLogFormula::Gatheral,
+            )
+            .calculate(&VanillaOption::new(
+                CashOrNothingPayoff::new(OptionType::Put, 100.0, 10.0),
+                Box::new(EuropeanExercise::new(Date::new(1, Month::January, 2026))),
+            ))
+        });
+        assert!(result.is_err(), "expected panic for a digital payoff");
+        let result = std::panic::catch_unwind(|| {
+            engine(
+                process(),
+                Integration::gauss_lobatto(1.0e-8, 1.0e-8, 1000),
+                ComplexLogFormula::BranchCorrection,
+            )
+        });
+        assert!(
+            result.is_err(),
+            "expected panic for branch correction with Gauss-Lobatto"
+        );
+    }
+}
@@ -0,0 +1,405 @@
+use crate::exercise::{Exercise, ExerciseType};
+use crate::instruments::payoffs::StrikedTypePayoff;
+use crate::instruments::vanilla_option::VanillaOption;
+use crate::math::solvers1d::brent::Brent;
+use crate::methods::lattices::binomial_tree::BinomialTree;
+use crate::pricingengines::vanilla::vanilla_option_results::VanillaOptionResults;
+use crate::processes::black_scholes_process::{
+    BlackScholesMertonProcess, GeneralizedBlackScholesProcess,
+};
+use crate::termstructures::volatility::equityfx::black_vol_term_structure::BlackVolTermStructure;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::types::{Real, Size, Time};
+use std::marker::PhantomData;
+
+/*
+Binomial-tree engine for vanilla options (QuantLib BinomialVanillaEngine),
+generic over the tree (CoxRossRubinstein, JarrowRudd, Tian,
+LeisenReimer, Joshi4).
+
+The tree is built on the zero rates and Black volatility of the expiry
+and strike, and its steps are laid out in variance time: step i of n
+stands for the time t_i at which the Black variance at the strike is
+i/n of its value at expiry, so that each step carries the forward
+variance of the term structure. The nodes of step i are rescaled to
+the forward S Dq(t_i) / Dr(t_i), and the option values are rolled back
+discounted on the risk-free curve:
+    V(i, j) = Dr(t_{i+1}) / Dr(t_i) (p_d V(i + 1, j) + p_u V(i + 1, j + 1))
+European options are thus priced on the Black variance of their
+expiry, while American options can be exercised at every t_i from the
+earliest exercise date on and Bermudan ones at the t_i closest to
+their dates. Times are measured on the risk-free curve, from its
+reference date, and the volatility structure should share its day
+counter. Delta and gamma come from the nodes of steps 1 and 2
+(Odegaard).
+*/
+pub struct BinomialVanillaEngine<T: BinomialTree> {
+    process: GeneralizedBlackScholesProcess,
+    time_steps: Size,
+    tree: PhantomData<T>,
+}
+impl<T: BinomialTree> BinomialVanillaEngine<T> {
+    // Constructor
+    pub fn new(
+        process: GeneralizedBlackScholesProcess,
+        time_steps: Size,
+    ) -> BinomialVanillaEngine<T> {
+        if time_steps < 2 {
+            panic!("at least 2 time steps required, {time_steps} provided");
+        }
+        BinomialVanillaEngine {
+            process,
+            time_steps,
+            tree: PhantomData,
+        }
+    }
+
+    // Inspectors
+    pub fn process(&self) -> &GeneralizedBlackScholesProcess {
+        &self.process
+    }
+
+    pub fn calculate(&self, option: &VanillaOption) -> VanillaOptionResults {
+        let payoff: &dyn StrikedTypePayoff = option.payoff().payoff();
+        let exercise: &dyn Exercise = option.exercise();
+        let maturity: Time = self.process.time(exercise.last_date());
+        if maturity <= 0.0 {
+            panic!("option expired: maturity {maturity} before the reference date");
+        }
+        let process: BlackScholesMertonProcess = self
+            .process
+            .constant_volatility_process(exercise.last_date(), payoff.strike());
+        let tree: T = T::new(&process, maturity, self.time_steps, payoff.strike());
+        let steps: Size = tree.columns() - 1;
+        let times: Vec<Time> = self.variance_times(maturity, steps, payoff.strike());
+        let exercisable: Vec<bool> = self.exercisable_steps(exercise, &times);
+
+        // Forward of each step over the one of the tree
+        let risk_free: &dyn YieldTermStructure = self.process.risk_free_rate();
+        let dividend: &dyn YieldTermStructure = self.process.dividend_yield();
+        let carry: Real = process.risk_free_rate() - process.dividend_yield();
+        let scale: Vec<Real> = times
+            .iter()
+            .enumerate()
+            .map(|(i, t)| {
+                let tree_time: Time = maturity * i as Real / steps as Real;
+                dividend.discount(*t) / risk_free.discount(*t) / (carry * tree_time).exp()
+            })
+            .collect();
+        let underlying = |i: Size, j: Size| -> Real { scale[i] * tree.underlying(i, j) };
+
+        let mut values: Vec<Real> = (0..tree.size(steps))
+            .map(|j| payoff.value(underlying(steps, j)))
+            .collect();
+        let mut stored: [Vec<Real>; 2] = [Vec::new(), Vec::new()];
+        for i in (0..steps).rev() {
+            let discount: Real = risk_free.discount(times[i + 1]) / risk_free.discount(times[i]);
+            values = (0..tree.size(i))
+                .map(|j| {
+                    let continuation: Real = (0..2)
+                        .map(|b| tree.probability(i, j, b) * values[tree.descendant(i, j, b)])
+                        .sum::<Real>()
+                        * discount;
+                    if exercisable[i] {
+                        continuation.max(payoff.value(underlying(i, j)))
+                    } else {
+                        continuation
+                    }
+                })
+                .collect();
+            if (1..=2).contains(&i) {
+                stored[i - 1] = values.clone();
+            }
+        }
+
+        // Partial derivatives from the nodes of steps 1 and 2
+        let (p2, p1): (&[Real], &[Real]) = (&stored[1], &stored[0]);
+        let s2: [Real; 3] = [underlying(2, 0), underlying(2, 1), underlying(2, 2)];
+        let delta2u: Real = (p2[2] - p2[1]) / (s2[2] - s2[1]);
+        let delta2d: Real = (p2[1] - p2[0]) / (s2[1] - s2[0]);
+        let (s1u, s1d): (Real, Real) = (underlying(1, 1), underlying(1, 0));
+        VanillaOptionResults {
+            value: values[0],
+            delta: (p1[1] - p1[0]) / (s1u - s1d),
+            gamma: (delta2u - delta2d) / ((s2[2] - s2[0]) / 2.0),
+        }
+    }
+
+    // Times t_i at which the Black variance at strike is i / steps of the one at maturity
+    fn variance_times(&self, maturity: Time, steps: Size, strike: Real) -> Vec<Time> {
+        let volatility: &dyn BlackVolTermStructure = self.process.black_volatility();
+        let variance: Real = volatility.black_variance(maturity, strike);
+        let mut times: Vec<Time> = vec![0.0];
+        for i in 1..steps {
+            let target: Real = variance * i as Real / steps as Real;
+            let t: Time = if variance > 0.0 {
+                Brent::new().solve(
+                    |t: Time| volatility.black_variance(t, strike) - target,
+                    1.0e-12,
+                    times[i - 1],
+                    maturity,
+                )
+            } else {
+                maturity * i as Real / steps as Real
+            };
+            times.push(t);
+        }
+        times.push(maturity);
+        times
+    }
+
+    // Whether early exercise is possible at each step
+    fn exercisable_steps(&self, exercise: &dyn Exercise, times: &[Time]) -> Vec<bool> {
+        let mut exercisable: Vec<bool> = vec![false; times.len()];
+        match exercise.exercise_type() {
+            ExerciseType::American => {
+                let earliest: Time = self.process.time(exercise.date(0)).max(0.0);
+                for (i, t) in times.iter().enumerate() {
+                    exercisable[i] = *t >= earliest;
+                }
+            }
+            ExerciseType::Bermudan => {
+                for date in exercise.dates() {
+                    let t: Time = self.process.time(*date);
+                    if t >= 0.0 {
+                        // Closest step, the earlier one on ties
+                        let closest: Size = (0..times.len())
+                            .min_by(|a, b| (times[*a] - t).abs().total_cmp(&(times[*b] - t).abs()))
+                            .unwrap();
+                        exercisable[closest] = true;
+                    }
+                }
+            }
+            ExerciseType::European => {}
+        }
+        exercisable
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cashflows::dividend::DividendSchedule;
+    use crate::exercise::{AmericanExercise, BermudanExercise, EuropeanExercise};
+    use crate::instruments::payoffs::PlainVanillaPayoff;
+    use crate::methods::finitedifferences::schemes::fdm_scheme_desc::FdmSchemeDesc;
+    use crate::methods::lattices::binomial_tree::{
+        CoxRossRubinstein, JarrowRudd, Joshi4, LeisenReimer, Tian,
+    };
+    use crate::option::OptionType;
+    use crate::pricingengines::black_formula::black_formula;
+    use crate::pricingengines::vanilla::fd_black_scholes_vanilla_engine::FdBlackScholesVanillaEngine;
+    use crate::stochastic_process::StochasticProcess1D;
+    use crate::termstructures::volatility::equityfx::black_constant_vol::BlackConstantVol;
+    use crate::termstructures::volatility::equityfx::black_variance_curve::{
+        BlackVarianceCurve, VarianceInterpolation,
+    };
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::{Date, Month};
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use std::rc::Rc;
+
+    // Engine on the constant volatility of process
+    fn engine<T: BinomialTree>(
+        process: BlackScholesMertonProcess,
+        steps: Size,
+    ) -> BinomialVanillaEngine<T> {
+        let reference_date: Date = Date::new(1, Month::January, 2025);
+        let volatility: Rc<BlackConstantVol> = Rc::new(BlackConstantVol::new(
+            reference_date,
+            process.volatility(),
+            Box::new(Actual365Fixed::new()),
+        ));
+        BinomialVanillaEngine::<T>::new(
+            GeneralizedBlackScholesProcess::new(
+                process.x0(),
+                flat_rate(reference_date, process.risk_free_rate()),
+                flat_rate(reference_date, process.dividend_yield()),
+                volatility,
+            ),
+            steps,
+        )
+    }
+
+    fn results<T: BinomialTree>(
+        process: BlackScholesMertonProcess,
+        steps: Size,
+        payoff: PlainVanillaPayoff,
+        exercise: impl Exercise + 'static,
+    ) -> VanillaOptionResults {
+        engine::<T>(process, steps).calculate(&VanillaOption::new(payoff, Box::new(exercise)))
+    }
+
+    #[test]
+    fn european_options_converge_to_black_scholes() {
+        let process: BlackScholesMertonProcess =
+            BlackScholesMertonProcess::new(100.0, 0.05, 0.02, 0.25);
+        let exercise: EuropeanExercise = EuropeanExercise::new(Date::new(1, Month::January, 2026));
+        let payoff: PlainVanillaPayoff = PlainVanillaPayoff::new(OptionType::Call, 105.0);
+        let expected: Real = black_formula(
+            OptionType::Call,
+            105.0,
+            100.0 * 0.03_f64.exp(),
+            0.25,
+            (-0.05_f64).exp(),
+            0.0,
+        );
+
+        let cases: [(Real, Real, &str); 5] = [
+            (
+                results::<JarrowRudd>(process, 801, payoff, exercise.clone()).value,
+                2.0e-2,
+                "JarrowRudd",
+            ),
+            (
+                results::<CoxRossRubinstein>(process, 801, payoff, exercise.clone()).value,
+                2.0e-2,
+                "CRR",
+            ),
+            (
+                results::<Tian>(process, 801, payoff, exercise.clone()).value,
+                2.0e-2,
+                "Tian",
+            ),
+            (
+                results::<LeisenReimer>(process, 101, payoff, exercise.clone()).value,
+                1.0e-3,
+                "LeisenReimer",
+            ),
+            (
+                results::<Joshi4>(process, 101, payoff, exercise.clone()).value,
+                1.0e-4,
+                "Joshi4",
+            ),
+        ];
+        for (value, tolerance, label) in cases {
+            assert!(
+                (value - expected).abs() < tolerance,
+                "{label}: {value} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn greeks_are_close_to_black_scholes() {
+        // d1 = (ln(100 / 100) + 0.05 + 0.02) / 0.2 = 0.35
+        let process: BlackScholesMertonProcess =
+            BlackScholesMertonProcess::new(100.0, 0.05, 0.0, 0.2);
+        let exercise: EuropeanExercise = EuropeanExercise::new(Date::new(1, Month::January, 2026));
+        let payoff: PlainVanillaPayoff = PlainVanillaPayoff::new(OptionType::Call, 100.0);
+        let results: VanillaOptionResults =
+            results::<LeisenReimer>(process, 201, payoff, exercise.clone());
+
+        assert!(
+            (results.delta - 0.636830651175619).abs() < 1.0e-3,
+            "delta {}",
+            results.delta
+        );
+        assert!(
+            (results.gamma - 0.018762017345847).abs() < 1.0e-4,
+            "gamma {}",
+            results.gamma
+        );
+    }
+
+    #[test]
+    fn early_exercise_adds_value() {
+        // Hull's example: S = K = 50, r = 10%, sigma = 40%, 5 months
+        let reference_date: Date = Date::new(1, Month::January, 2025);
+        let expiry: Date = reference_date + 152;
+        let process: BlackScholesMertonProcess =
+            BlackScholesMertonProcess::new(50.0, 0.1, 0.0, 0.4);
+        let payoff: PlainVanillaPayoff = PlainVanillaPayoff::new(OptionType::Put, 50.0);
+
+        let european: Real =
+            results::<CoxRossRubinstein>(process, 500, payoff, EuropeanExercise::new(expiry)).value;
+        let bermudan: Real = results::<CoxRossRubinstein>(
+            process,
+            500,
+            payoff,
+            BermudanExercise::new(&[reference_date + 61, reference_date + 122, expiry], false),
+        )
+        .value;
+        let american: Real = results::<CoxRossRubinstein>(
+            process,
+            500,
+            payoff,
+            AmericanExercise::new(reference_date, expiry, false),
+        )
+        .value;
+
+        assert!((american - 4.28).abs() < 1.0e-2, "american {american}");
+        assert!(european < bermudan && bermudan < american);
+    }
+
+    #[test]
+    fn steps_follow_the_forward_variance() {
+        // 10% volatility over the first year and 40% over the second one
+        let reference_date: Date = Date::new(1, Month::January, 2025);
+        let expiry: Date = reference_date + 730;
+        let process = |volatility: Rc<dyn BlackVolTermStructure>| {
+            GeneralizedBlackScholesProcess::new(
+                100.0,
+                flat_rate(reference_date, 0.05),
+                flat_rate(reference_date, 0.0),
+                volatility,
+            )
+        };
+        let curve: Rc<dyn BlackVolTermStructure> = Rc::new(BlackVarianceCurve::new(
+            reference_date,
+            &[reference_date + 365, expiry],
+            &[0.1, 0.085_f64.sqrt()],
+            Box::new(Actual365Fixed::new()),
+            VarianceInterpolation::Linear,
+        ));
+        let flat: Rc<dyn BlackVolTermStructure> = Rc::new(BlackConstantVol::new(
+            reference_date,
+            0.085_f64.sqrt(),
+            Box::new(Actual365Fixed::new()),
+        ));
+        let payoff: PlainVanillaPayoff = PlainVanillaPayoff::new(OptionType::Put, 100.0);
+        let european = || VanillaOption::new(payoff, Box::new(EuropeanExercise::new(expiry)));
+        let american = || {
+            VanillaOption::new(
+                payoff,
+                Box::new(AmericanExercise::new(reference_date, expiry, false)),
+            )
+        };
+        let binomial = |volatility: Rc<dyn BlackVolTermStructure>, option: VanillaOption| {
+            BinomialVanillaEngine::<LeisenReimer>::new(process(volatility), 401)
+                .calculate(&option)
+                .value
+        };
+
+        // Europeans only see the total variance
+        let expected: Real = binomial(flat.clone(), european());
+        let on_curve: Real = binomial(curve.clone(), european());
+        assert!(
+            (on_curve - expected).abs() < 1.0e-10,
+            "{on_curve} vs {expected}"
+        );
+        // Americans agree with the finite-difference operator on the forward variance
+        let on_curve: Real = binomial(curve.clone(), american());
+        let on_flat: Real = binomial(flat, american());
+        let fd: Real = FdBlackScholesVanillaEngine::new(
+            process(curve),
+            DividendSchedule::default(),
+            400,
+            400,
+            2,
+            FdmSchemeDesc::douglas(),
+        )
+        .calculate(&american())
+        .value;
+        assert!((on_curve - fd).abs() < 1.0e-2, "{on_curve} vs {fd}");
+        assert!((on_curve - on_flat).abs() > 0.1, "{on_curve} vs {on_flat}");
+    }
+
+    #[test]
+    fn new_panics_with_less_than_two_steps() {
+        let result = std::panic::catch_unwind(|| {
+            engine::<CoxRossRubinstein>(BlackScholesMertonProcess::new(100.0, 0.05, 0.0, 0.2), 1)
+        });
+        assert!(result.is_err());
+    }
+}
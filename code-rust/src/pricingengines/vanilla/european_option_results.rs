@@ -0,0 +1,18 @@
+use crate::types::Real;
+
+/*
+What the analytic European engine computes: value and the greeks of
+QuantLib's Greeks results. Theta is per year; vega, rho and dividend rho
+are per unit (not per percent) change of the volatility, risk-free rate
+and dividend yield.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct EuropeanOptionResults {
+    pub value: Real,
+    pub delta: Real,
+    pub gamma: Real,
+    pub vega: Real,
+    pub theta: Real,
+    pub rho: Real,
+    pub dividend_rho: Real,
+}
@@ -0,0 +1,404 @@
+use crate::cashflows::dividend::{Dividend, DividendSchedule};
+use crate::exercise::Exercise;
+use crate::instruments::payoffs::StrikedTypePayoff;
+use crate::instruments::vanilla_option::VanillaOption;
+use crate::math::interpolations::cubic_interpolation::CubicNaturalSpline;
+use crate::methods::finitedifferences::meshers::fdm_black_scholes_mesher::FdmBlackScholesMesher;
+use crate::methods::finitedifferences::meshers::fdm1d_mesher::Fdm1dMesher;
+use crate::methods::finitedifferences::operators::fdm_black_scholes_op::FdmBlackScholesOp;
+use crate::methods::finitedifferences::schemes::fdm_scheme_desc::FdmSchemeDesc;
+use crate::methods::finitedifferences::solvers::fdm_backward_solver::FdmBackwardSolver;
+use crate::methods::finitedifferences::stepconditions::fdm_step_condition_composite::FdmStepConditionComposite;
+use crate::pricingengines::vanilla::vanilla_option_results::VanillaOptionResults;
+use crate::processes::black_scholes_process::{
+    BlackScholesMertonProcess, GeneralizedBlackScholesProcess,
+};
+use crate::stochastic_process::StochasticProcess1D;
+use crate::types::{Real, Size, Time};
+
+/*
+Finite-difference engine for vanilla options under Black-Scholes
+(QuantLib FdBlackScholesVanillaEngine).
+
+The PDE is solved in log-spot on an FdmBlackScholesMesher with x_grid
+points concentrated around the strike, rolled back over t_grid steps
+from maturity to the reference date of the risk-free curve, on which
+times are measured. European, American and Bermudan exercises are
+supported, as well as the discrete dividends of a dividend schedule:
+the spot drops by each dividend on its ex-date. On each step the
+operator takes the forward rates of the curves and the forward Black
+variance at the strike (see FdmBlackScholesOp), which is exact for
+strike-independent volatilities; the mesher is set on the Black
+volatility of the expiry and strike.
+Value, delta and gamma are read off a cubic spline through the grid
+values at the spot:
+    delta = V_x / S,  gamma = (V_xx - V_x) / S^2
+*/
+pub struct FdBlackScholesVanillaEngine {
+    process: GeneralizedBlackScholesProcess,
+    dividends: DividendSchedule,
+    t_grid: Size,
+    x_grid: Size,
+    damping_steps: Size,
+    scheme_desc: FdmSchemeDesc,
+}
+impl FdBlackScholesVanillaEngine {
+    // Constructor
+    pub fn new(
+        process: GeneralizedBlackScholesProcess,
+        dividends: DividendSchedule,
+        t_grid: Size,
+        x_grid: Size,
+        damping_steps: Size,
+        scheme_desc: FdmSchemeDesc,
+    ) -> FdBlackScholesVanillaEngine {
+        FdBlackScholesVanillaEngine {
+            process,
+            dividends,
+            t_grid,
+            x_grid,
+            damping_steps,
+            scheme_desc,
+        }
+    }
+
+    // Inspectors
+    pub fn process(&self) -> &GeneralizedBlackScholesProcess {
+        &self.process
+    }
+    pub fn dividends(&self) -> &DividendSchedule {
+        &self.dividends
+    }
+
+    pub fn calculate(&self, option: &VanillaOption) -> VanillaOptionResults {
+        let payoff: &dyn StrikedTypePayoff = option.payoff().payoff();
+        let exercise: &dyn Exercise = option.exercise();
+        let maturity: Time = self.process.time(exercise.last_date());
+        if maturity <= 0.0 {
+            panic!("option expired: maturity {maturity} before the reference date");
+        }
+        let process: BlackScholesMertonProcess = self
+            .process
+            .constant_volatility_process(exercise.last_date(), payoff.strike());
+        let spot: Real = process.x0();
+
+        let mesher: FdmBlackScholesMesher = FdmBlackScholesMesher::new(
+            self.x_grid,
+            spot,
+            process.risk_free_rate(),
+            process.dividend_yield(),
+            process.volatility(),
+            maturity,
+            Some((payoff.strike(), 0.1)),
+        );
+        let dividends: Vec<(Time, Dividend)> = self
+            .dividends
+            .dividends()
+            .iter()
+            .map(|dividend| (self.process.time(dividend.date()), *dividend))
+            .filter(|(t, _)| *t > 0.0 && *t < maturity)
+            .collect();
+        let conditions: FdmStepConditionComposite = FdmStepConditionComposite::vanilla_composite(
+            &dividends,
+            exercise,
+            self.process.risk_free_rate().reference_date(),
+            self.process.risk_free_rate().day_counter(),
+            &mesher,
+            payoff,
+        );
+        let map: FdmBlackScholesOp =
+            FdmBlackScholesOp::from_process(&mesher, &self.process, payoff.strike());
+
+        let mut values: Vec<Real> = mesher
+            .locations()
+            .iter()
+            .map(|x| payoff.value(x.exp()))
+            .collect();
+        FdmBackwardSolver::new(Box::new(map), conditions, self.scheme_desc).rollback(
+            &mut values,
+            maturity,
+            0.0,
+            self.t_grid,
+            self.damping_steps,
+        );
+
+        let spline: CubicNaturalSpline = CubicNaturalSpline::new(mesher.locations(), &values);
+        let x: Real = spot.ln();
+        let (v_x, v_xx): (Real, Real) = (spline.derivative(x), spline.second_derivative(x));
+        VanillaOptionResults {
+            value: spline.value(x),
+            delta: v_x / spot,
+            gamma: (v_xx - v_x) / (spot * spot),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::{AmericanExercise, EuropeanExercise};
+    use crate::instruments::payoffs::PlainVanillaPayoff;
+    use crate::math::distributions::normal_distribution::{
+        CumulativeNormalDistribution, NormalDistribution,
+    };
+    use crate::option::OptionType;
+    use crate::pricingengines::black_formula::black_formula;
+    use crate::termstructures::volatility::equityfx::black_constant_vol::BlackConstantVol;
+    use crate::termstructures::volatility::equityfx::black_variance_curve::{
+        BlackVarianceCurve, VarianceInterpolation,
+    };
+    use crate::termstructures::volatility::equityfx::black_vol_term_structure::BlackVolTermStructure;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::{Date, Month};
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use std::rc::Rc;
+
+    // Engine on the constant volatility of process
+    fn engine(
+        process: BlackScholesMertonProcess,
+        dividends: DividendSchedule,
+        scheme: FdmSchemeDesc,
+    ) -> FdBlackScholesVanillaEngine {
+        let reference_date: Date = Date::new(1, Month::January, 2025);
+        let volatility: Rc<BlackConstantVol> = Rc::new(BlackConstantVol::new(
+            reference_date,
+            process.volatility(),
+            Box::new(Actual365Fixed::new()),
+        ));
+        FdBlackScholesVanillaEngine::new(
+            GeneralizedBlackScholesProcess::new(
+                process.x0(),
+                flat_rate(reference_date, process.risk_free_rate()),
+                flat_rate(reference_date, process.dividend_yield()),
+                volatility,
+            ),
+            dividends,
+            100,
+            200,
+            2,
+            scheme,
+        )
+    }
+
+    #[test]
+    fn european_options_match_black_scholes() {
+        let (s, r, q, sigma): (Real, Real, Real, Real) = (100.0, 0.05, 0.02, 0.2);
+        let expiry: Date = Date::new(1, Month::January, 2026);
+        let t: Time = 1.0;
+        let forward: Real = s * ((r - q) * t).exp();
+        let std_dev: Real = sigma * t.sqrt();
+        let schemes: [FdmSchemeDesc; 3] = [
+            FdmSchemeDesc::douglas(),
+            FdmSchemeDesc::crank_nicolson(),
+            FdmSchemeDesc::hundsdorfer(),
+        ];
+
+        for scheme in schemes {
+            let engine = engine(
+                BlackScholesMertonProcess::new(s, r, q, sigma),
+                DividendSchedule::default(),
+                scheme,
+            );
+            for (option_type, strike) in [
+                (OptionType::Call, 90.0),
+                (OptionType::Call, 110.0),
+                (OptionType::Put, 100.0),
+            ] {
+                let results: VanillaOptionResults = engine.calculate(&VanillaOption::new(
+                    PlainVanillaPayoff::new(option_type, strike),
+                    Box::new(EuropeanExercise::new(expiry)),
+                ));
+                let expected: Real =
+                    black_formula(option_type, strike, forward, std_dev, (-r * t).exp(), 0.0);
+                let d1: Real = (forward / strike).ln() / std_dev + 0.5 * std_dev;
+                let expected_delta: Real = (-q * t).exp()
+                    * match option_type {
+                        OptionType::Call => CumulativeNormalDistribution::default().value(d1),
+                        OptionType::Put => CumulativeNormalDistribution::default().value(d1) - 1.0,
+                    };
+                let expected_gamma: Real =
+                    (-q * t).exp() * NormalDistribution::default().value(d1) / (s * std_dev);
+
+                let label: String = format!("{:?} {} {}", scheme.scheme_type, option_type, strike);
+                assert!(
+                    (results.value - expected).abs() < 1.0e-2,
+                    "{}: value {} vs {}",
+                    label,
+                    results.value,
+                    expected
+                );
+                assert!(
+                    (results.delta - expected_delta).abs() < 1.0e-3,
+                    "{label}: delta"
+                );
+                assert!(
+                    (results.gamma - expected_gamma).abs() < 1.0e-4,
+                    "{label}: gamma"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn american_put_is_worth_more_than_european() {
+        // Hull's example: S = K = 50, r = 10%, sigma = 40%, 5 months
+        let reference_date: Date = Date::new(1, Month::January, 2025);
+        let expiry: Date = reference_date + 152;
+        let engine = engine(
+            BlackScholesMertonProcess::new(50.0, 0.1, 0.0, 0.4),
+            DividendSchedule::default(),
+            FdmSchemeDesc::douglas(),
+        );
+        let payoff: PlainVanillaPayoff = PlainVanillaPayoff::new(OptionType::Put, 50.0);
+
+        let american: Real = engine
+            .calculate(&VanillaOption::new(
+                payoff,
+                Box::new(AmericanExercise::new(reference_date, expiry, false)),
+            ))
+            .value;
+        let european: Real = engine
+            .calculate(&VanillaOption::new(
+                payoff,
+                Box::new(EuropeanExercise::new(expiry)),
+            ))
+            .value;
+        assert!((american - 4.28).abs() < 1.0e-2, "american {american}");
+        assert!(american > european + 0.1);
+    }
+
+    #[test]
+    fn cash_dividend_lowers_the_spot() {
+        // Paid the next day, the dividend just lowers the spot: the call is
+        // close to the Black-Scholes call on S - D
+        let reference_date: Date = Date::new(1, Month::January, 2025);
+        let option: VanillaOption = VanillaOption::new(
+            PlainVanillaPayoff::new(OptionType::Call, 100.0),
+            Box::new(EuropeanExercise::new(Date::new(1, Month::January, 2026))),
+        );
+        let engine = |dividends: DividendSchedule| {
+            engine(
+                BlackScholesMertonProcess::new(100.0, 0.05, 0.0, 0.2),
+                dividends,
+                FdmSchemeDesc::douglas(),
+            )
+        };
+
+        let without: Real = engine(DividendSchedule::default()).calculate(&option).value;
+        let with: Real = engine(DividendSchedule::new(&[Dividend::fixed(
+            reference_date + 1,
+            5.0,
+        )]))
+        .calculate(&option)
+        .value;
+        let expected: Real = black_formula(
+            OptionType::Call,
+            100.0,
+            95.0 * 0.05_f64.exp(),
+            0.2,
+            (-0.05_f64).exp(),
+            0.0,
+        );
+        assert!(with < without - 2.0);
+        assert!((with - expected).abs() < 2.0e-2, "{with} vs {expected}");
+    }
+
+    #[test]
+    fn american_call_is_exercised_before_a_large_dividend() {
+        let reference_date: Date = Date::new(1, Month::January, 2025);
+        let expiry: Date = reference_date + 180;
+        let engine = |dividends: DividendSchedule| {
+            engine(
+                BlackScholesMertonProcess::new(100.0, 0.05, 0.0, 0.2),
+                dividends,
+                FdmSchemeDesc::douglas(),
+            )
+        };
+        let payoff: PlainVanillaPayoff = PlainVanillaPayoff::new(OptionType::Call, 90.0);
+        let dividends: DividendSchedule = DividendSchedule::new(&[
+            Dividend::fixed(reference_date + 170, 8.0),
+            Dividend::fractional(reference_date + 60, 0.01),
+        ]);
+        let american: VanillaOption = VanillaOption::new(
+            payoff,
+            Box::new(AmericanExercise::new(reference_date, expiry, false)),
+        );
+
+        let no_dividends: Real = engine(DividendSchedule::default())
+            .calculate(&american)
+            .value;
+        let engine: FdBlackScholesVanillaEngine = engine(dividends);
+        let european: Real = engine
+            .calculate(&VanillaOption::new(
+                payoff,
+                Box::new(EuropeanExercise::new(expiry)),
+            ))
+            .value;
+        let american: Real = engine.calculate(&american).value;
+        // Exercising just before the dividend is worth about S e^{-0.01} - K e^{-r t}
+        assert!(american > european + 1.0, "{american} vs {european}");
+        assert!(american > 99.0 - 90.0 * (-0.05_f64 * 170.0 / 365.0).exp());
+        assert!(american < no_dividends);
+    }
+
+    #[test]
+    fn operator_follows_the_forward_variance() {
+        // 10% volatility over the first year and 40% over the second one
+        let reference_date: Date = Date::new(1, Month::January, 2025);
+        let expiry: Date = reference_date + 730;
+        let engine = |volatility: Rc<dyn BlackVolTermStructure>| {
+            FdBlackScholesVanillaEngine::new(
+                GeneralizedBlackScholesProcess::new(
+                    100.0,
+                    flat_rate(reference_date, 0.05),
+                    flat_rate(reference_date, 0.0),
+                    volatility,
+                ),
+                DividendSchedule::default(),
+                200,
+                200,
+                2,
+                FdmSchemeDesc::douglas(),
+            )
+        };
+        let curve: Rc<dyn BlackVolTermStructure> = Rc::new(BlackVarianceCurve::new(
+            reference_date,
+            &[reference_date + 365, expiry],
+            &[0.1, 0.085_f64.sqrt()],
+            Box::new(Actual365Fixed::new()),
+            VarianceInterpolation::Linear,
+        ));
+        let flat: Rc<dyn BlackVolTermStructure> = Rc::new(BlackConstantVol::new(
+            reference_date,
+            0.085_f64.sqrt(),
+            Box::new(Actual365Fixed::new()),
+        ));
+        let payoff: PlainVanillaPayoff = PlainVanillaPayoff::new(OptionType::Put, 100.0);
+        let european: VanillaOption =
+            VanillaOption::new(payoff, Box::new(EuropeanExercise::new(expiry)));
+        let american: VanillaOption = VanillaOption::new(
+            payoff,
+            Box::new(AmericanExercise::new(reference_date, expiry, false)),
+        );
+
+        // Europeans only see the total variance
+        let expected: Real = black_formula(
+            OptionType::Put,
+            100.0,
+            100.0 * 0.1_f64.exp(),
+            0.17_f64.sqrt(),
+            (-0.1_f64).exp(),
+            0.0,
+        );
+        let european: Real = engine(curve.clone()).calculate(&european).value;
+        assert!(
+            (european - expected).abs() < 1.0e-2,
+            "{european} vs {expected}"
+        );
+        // Americans are exercised early, when the volatility is still low
+        let on_curve: Real = engine(curve).calculate(&american).value;
+        let on_flat: Real = engine(flat).calculate(&american).value;
+        assert!(on_curve > european + 0.2, "{on_curve} vs {european}");
+        assert!((on_curve - on_flat).abs() > 0.1, "{on_curve} vs {on_flat}");
+    }
+}
@@ -0,0 +1,292 @@
+use crate::exercise::{Exercise, ExerciseType};
+use crate::instruments::payoffs::StrikedTypePayoff;
+use crate::instruments::vanilla_option::VanillaOption;
+use crate::math::interpolations::cubic_interpolation::CubicNaturalSpline;
+use crate::methods::finitedifferences::meshers::fdm_black_scholes_mesher::FdmBlackScholesMesher;
+use crate::methods::finitedifferences::meshers::fdm_heston_variance_mesher::FdmHestonVarianceMesher;
+use crate::methods::finitedifferences::meshers::fdm1d_mesher::Fdm1dMesher;
+use crate::methods::finitedifferences::operators::fdm_heston_op::FdmHestonOp;
+use crate::methods::finitedifferences::schemes::fdm_scheme_desc::FdmSchemeDesc;
+use crate::methods::finitedifferences::solvers::fdm_backward_solver::FdmBackwardSolver;
+use crate::methods::finitedifferences::stepconditions::fdm_american_step_condition::FdmAmericanStepCondition;
+use crate::methods::finitedifferences::stepconditions::fdm_bermudan_step_condition::FdmBermudanStepCondition;
+use crate::methods::finitedifferences::stepconditions::fdm_step_condition::FdmStepCondition;
+use crate::methods::finitedifferences::stepconditions::fdm_step_condition_composite::FdmStepConditionComposite;
+use crate::pricingengines::vanilla::vanilla_option_results::VanillaOptionResults;
+use crate::processes::heston_process::HestonProcess;
+use crate::types::{Rate, Real, Size, Time};
+
+/*
+Finite-difference engine for vanilla options under Heston (QuantLib
+FdHestonVanillaEngine), without dividends.
+
+The PDE is solved on the product of a log-spot mesher (x_grid points,
+sized with the volatility sqrt(max(v0, theta)) and concentrated around
+the strike) and an FdmHestonVarianceMesher (v_grid points), rolled back
+with an ADI scheme (Hundsdorfer by default in QuantLib), with the
+zero rates of the risk-free and dividend curves to the expiry. Times
+are measured on the risk-free curve, from its reference date. European,
+American and Bermudan exercises are supported. The results are read off
+cubic splines: along x on every variance line, then along v at v0.
+*/
+pub struct FdHestonVanillaEngine {
+    process: HestonProcess,
+    t_grid: Size,
+    x_grid: Size,
+    v_grid: Size,
+    damping_steps: Size,
+    scheme_desc: FdmSchemeDesc,
+}
+impl FdHestonVanillaEngine {
+    // Constructor
+    pub fn new(
+        process: HestonProcess,
+        t_grid: Size,
+        x_grid: Size,
+        v_grid: Size,
+        damping_steps: Size,
+        scheme_desc: FdmSchemeDesc,
+    ) -> FdHestonVanillaEngine {
+        FdHestonVanillaEngine {
+            process,
+            t_grid,
+            x_grid,
+            v_grid,
+            damping_steps,
+            scheme_desc,
+        }
+    }
+
+    // Inspectors
+    pub fn process(&self) -> &HestonProcess {
+        &self.process
+    }
+
+    pub fn calculate(&self, option: &VanillaOption) -> VanillaOptionResults {
+        let payoff: &dyn StrikedTypePayoff = option.payoff().payoff();
+        let exercise: &dyn Exercise = option.exercise();
+        let process: &HestonProcess = &self.process;
+        let maturity: Time = process.time(exercise.last_date());
+        if maturity <= 0.0 {
+            panic!("option expired: maturity {maturity} before the reference date");
+        }
+        let spot: Real = process.s0();
+        // Zero rates to the expiry
+        let r: Rate = process.risk_free_rate().zero_rate(maturity);
+        let q: Rate = process.dividend_yield().zero_rate(
+            process
+                .dividend_yield()
+                .time_from_reference(exercise.last_date()),
+        );
+
+        let x_mesher: FdmBlackScholesMesher = FdmBlackScholesMesher::new(
+            self.x_grid,
+            spot,
+            r,
+            q,
+            process.v0().max(process.theta()).sqrt(),
+            maturity,
+            Some((payoff.strike(), 0.1)),
+        );
+        let v_mesher: FdmHestonVarianceMesher = FdmHestonVarianceMesher::new(
+            self.v_grid,
+            process.v0(),
+            process.kappa(),
+            process.theta(),
+            process.sigma(),
+            maturity,
+        );
+        let map: FdmHestonOp = FdmHestonOp::new(
+            &x_mesher,
+            &v_mesher,
+            r,
+            q,
+            process.kappa(),
+            process.theta(),
+            process.sigma(),
+            process.rho(),
+        );
+
+        // Payoff repeated on every variance line (x runs fastest)
+        let exercise_values: Vec<Real> = v_mesher
+            .locations()
+            .iter()
+            .flat_map(|_| x_mesher.locations().iter().map(|x| payoff.value(x.exp())))
+            .collect();
+        let conditions: FdmStepConditionComposite =
+            self.step_conditions(exercise, exercise_values.clone());
+        let mut values: Vec<Real> = exercise_values;
+        FdmBackwardSolver::new(Box::new(map), conditions, self.scheme_desc).rollback(
+            &mut values,
+            maturity,
+            0.0,
+            self.t_grid,
+            self.damping_steps,
+        );
+
+        // Along x on every variance line, then along v
+        let x: Real = spot.ln();
+        let mut lines: [Vec<Real>; 3] = [Vec::new(), Vec::new(), Vec::new()];
+        for line in values.chunks(self.x_grid) {
+            let spline: CubicNaturalSpline = CubicNaturalSpline::new(x_mesher.locations(), line);
+            lines[0].push(spline.value(x));
+            lines[1].push(spline.derivative(x));
+            lines[2].push(spline.second_derivative(x));
+        }
+        let [value, v_x, v_xx]: [Real; 3] = lines
+            .map(|line| CubicNaturalSpline::new(v_mesher.locations(), &line).value(process.v0()));
+        VanillaOptionResults {
+            value,
+            delta: v_x / spot,
+            gamma: (v_xx - v_x) / (spot * spot),
+        }
+    }
+
+    fn step_conditions(
+        &self,
+        exercise: &dyn Exercise,
+        exercise_values: Vec<Real>,
+    ) -> FdmStepConditionComposite {
+        match exercise.exercise_type() {
+            ExerciseType::American => {
+                let condition: Box<dyn FdmStepCondition> = Box::new(
+                    FdmAmericanStepCondition::from_exercise_values(exercise_values),
+                );
+                FdmStepConditionComposite::new(&[], vec![condition])
+            }
+            ExerciseType::Bermudan => {
+                let times: Vec<Time> = exercise
+                    .dates()
+                    .iter()
+                    .map(|d| self.process.time(*d))
+                    .collect();
+                let condition: Box<dyn FdmStepCondition> = Box::new(
+                    FdmBermudanStepCondition::from_exercise_values(&times, exercise_values),
+                );
+                FdmStepConditionComposite::new(&times, vec![condition])
+            }
+            ExerciseType::European => FdmStepConditionComposite::new(&[], Vec::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exercise::{AmericanExercise, EuropeanExercise};
+    use crate::instruments::payoffs::PlainVanillaPayoff;
+    use crate::option::OptionType;
+    use crate::pricingengines::black_formula::black_formula;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::{Date, Month};
+
+    fn reference_date() -> Date {
+        Date::new(1, Month::January, 2025)
+    }
+
+    fn option(payoff: PlainVanillaPayoff, exercise: impl Exercise + 'static) -> VanillaOption {
+        VanillaOption::new(payoff, Box::new(exercise))
+    }
+
+    fn engine(process: HestonProcess) -> FdHestonVanillaEngine {
+        FdHestonVanillaEngine::new(process, 50, 100, 41, 0, FdmSchemeDesc::hundsdorfer())
+    }
+
+    #[test]
+    fn european_options_match_lewis_reference_prices() {
+        // Lewis (2000): S = 100, T = 1, r = 1%, q = 2%, v0 = 0.04, kappa = 4,
+        // theta = 0.25, sigma = 1, rho = -0.5
+        let process: HestonProcess = HestonProcess::new(
+            100.0,
+            flat_rate(reference_date(), 0.01),
+            flat_rate(reference_date(), 0.02),
+            0.04,
+            4.0,
+            0.25,
+            1.0,
+            -0.5,
+        );
+        let exercise: EuropeanExercise = EuropeanExercise::new(Date::new(1, Month::January, 2026));
+        let cases: [(OptionType, Real, Real); 4] = [
+            (OptionType::Call, 90.0, 20.93334900059671),
+            (OptionType::Call, 100.0, 16.07015491702883),
+            (OptionType::Call, 110.0, 12.13221151670983),
+            (OptionType::Put, 100.0, 17.05527096127011),
+        ];
+        for (option_type, strike, expected) in cases {
+            let value: Real = engine(process.clone())
+                .calculate(&option(
+                    PlainVanillaPayoff::new(option_type, strike),
+                    exercise.clone(),
+                ))
+                .value;
+            assert!(
+                (value - expected).abs() < 5.0e-2,
+                "{option_type} {strike}: {value} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn small_vol_of_vol_gives_black_scholes() {
+        let process: HestonProcess = HestonProcess::new(
+            100.0,
+            flat_rate(reference_date(), 0.05),
+            flat_rate(reference_date(), 0.02),
+            0.04,
+            2.0,
+            0.04,
+            1.0e-3,
+            0.0,
+        );
+        let results: VanillaOptionResults = engine(process.clone()).calculate(&option(
+            PlainVanillaPayoff::new(OptionType::Call, 105.0),
+            EuropeanExercise::new(Date::new(1, Month::January, 2026)),
+        ));
+        let expected: Real = black_formula(
+            OptionType::Call,
+            105.0,
+            100.0 * 0.03_f64.exp(),
+            0.2,
+            (-0.05_f64).exp(),
+            0.0,
+        );
+        assert!(
+            (results.value - expected).abs() < 2.0e-2,
+            "{} vs {}",
+            results.value,
+            expected
+        );
+        assert!(results.delta > 0.0 && results.delta < 1.0);
+        assert!(results.gamma > 0.0);
+    }
+
+    #[test]
+    fn american_put_is_worth_more_than_european() {
+        let process: HestonProcess = HestonProcess::new(
+            100.0,
+            flat_rate(reference_date(), 0.05),
+            flat_rate(reference_date(), 0.0),
+            0.04,
+            2.0,
+            0.04,
+            0.3,
+            -0.5,
+        );
+        let reference_date: Date = Date::new(1, Month::January, 2025);
+        let expiry: Date = Date::new(1, Month::January, 2026);
+        let payoff: PlainVanillaPayoff = PlainVanillaPayoff::new(OptionType::Put, 110.0);
+
+        let american: Real = engine(process.clone())
+            .calculate(&option(
+                payoff,
+                AmericanExercise::new(reference_date, expiry, false),
+            ))
+            .value;
+        let european: Real = engine(process.clone())
+            .calculate(&option(payoff, EuropeanExercise::new(expiry)))
+            .value;
+        assert!(american > european + 0.1, "{american} vs {european}");
+        assert!(american >= 10.0);
+    }
+}
@@ -0,0 +1,9 @@
+use crate::types::Real;
+
+// What the vanilla engines compute: value and spot sensitivities (QuantLib OneAssetOption results)
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct VanillaOptionResults {
+    pub value: Real,
+    pub delta: Real,
+    pub gamma: Real,
+}
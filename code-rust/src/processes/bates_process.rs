@@ -0,0 +1,191 @@
+use crate::math::distributions::normal_distribution::CumulativeNormalDistribution;
+use crate::math::distributions::poisson_distribution::InverseCumulativePoisson;
+use crate::processes::heston_process::HestonProcess;
+use crate::stochastic_process::StochasticProcess;
+use crate::types::{Real, Size, Time};
+
+/*
+Bates process (QuantLib BatesProcess): the Heston process with
+log-normal jumps in the spot, arriving with intensity lambda,
+
+    dS = (r - q - lambda m) S dt + sqrt(v) S dw_1 + (J - 1) S dN
+    ln J ~ N(nu, delta^2),  m = E[J - 1] = exp(nu + delta^2 / 2) - 1
+
+Besides the two Brownian factors of Heston, evolve takes two more
+Gaussian draws: the first is mapped to a uniform that gives the number
+of jumps n over dt by inverse Poisson sampling, the second to the jump
+size, so that the spot is multiplied by
+    exp(-lambda m dt + nu n + delta sqrt(n) dw_4)
+on top of the Heston step.
+*/
+#[derive(Clone)]
+pub struct BatesProcess {
+    heston: HestonProcess,
+    lambda: Real,
+    nu: Real,
+    delta: Real,
+}
+impl BatesProcess {
+    // Constructor
+    pub fn new(heston: HestonProcess, lambda: Real, nu: Real, delta: Real) -> BatesProcess {
+        if lambda < 0.0 {
+            panic!("negative jump intensity ({lambda}) given");
+        }
+        if delta < 0.0 {
+            panic!("negative jump volatility ({delta}) given");
+        }
+        BatesProcess {
+            heston,
+            lambda,
+            nu,
+            delta,
+        }
+    }
+
+    // Inspectors
+    pub fn heston(&self) -> &HestonProcess {
+        &self.heston
+    }
+    pub fn lambda(&self) -> Real {
+        self.lambda
+    }
+    pub fn nu(&self) -> Real {
+        self.nu
+    }
+    pub fn delta(&self) -> Real {
+        self.delta
+    }
+
+    // Mean relative jump size m = E[J - 1]
+    pub fn m(&self) -> Real {
+        (self.nu + 0.5 * self.delta * self.delta).exp() - 1.0
+    }
+}
+impl StochasticProcess for BatesProcess {
+    fn size(&self) -> Size {
+        2
+    }
+    fn factors(&self) -> Size {
+        4
+    }
+    fn initial_values(&self) -> Vec<Real> {
+        self.heston.initial_values()
+    }
+    fn drift(&self, t: Time, x: &[Real]) -> Vec<Real> {
+        let mut drift: Vec<Real> = self.heston.drift(t, x);
+        drift[0] -= self.lambda * self.m();
+        drift
+    }
+    fn diffusion(&self, t: Time, x: &[Real]) -> Vec<Vec<Real>> {
+        // The jump factors do not diffuse
+        self.heston
+            .diffusion(t, x)
+            .into_iter()
+            .map(|mut row| {
+                row.extend([0.0, 0.0]);
+                row
+            })
+            .collect()
+    }
+    fn evolve(&self, t0: Time, x0: &[Real], dt: Time, dw: &[Real]) -> Vec<Real> {
+        let mut evolved: Vec<Real> = self.heston.evolve(t0, x0, dt, &dw[..2]);
+        let jumps: Real = if self.lambda > 0.0 {
+            let p: Real = CumulativeNormalDistribution::default()
+                .value(dw[2])
+                .min(1.0 - Real::EPSILON);
+            InverseCumulativePoisson::new(self.lambda * dt).value(p)
+        } else {
+            0.0
+        };
+        evolved[0] *=
+            (-self.lambda * self.m() * dt + self.nu * jumps + self.delta * jumps.sqrt() * dw[3])
+                .exp();
+        evolved
+    }
+    fn apply(&self, x0: &[Real], dx: &[Real]) -> Vec<Real> {
+        self.heston.apply(x0, dx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::{Date, Month};
+    use std::panic;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn heston() -> HestonProcess {
+        HestonProcess::new(
+            100.0,
+            flat_rate(reference_date(), 0.05),
+            flat_rate(reference_date(), 0.02),
+            0.04,
+            1.5,
+            0.09,
+            0.3,
+            -0.6,
+        )
+    }
+
+    #[test]
+    fn drift_is_compensated_for_the_jumps() {
+        let process: BatesProcess = BatesProcess::new(heston(), 0.5, -0.1, 0.2);
+        let m: Real = (-0.1_f64 + 0.02).exp() - 1.0;
+        assert!((process.m() - m).abs() < 1.0e-15);
+        assert_eq!(process.factors(), 4);
+        let x: [Real; 2] = [100.0, 0.04];
+        let drift: Vec<Real> = process.drift(0.0, &x);
+        let heston_drift: Vec<Real> = heston().drift(0.0, &x);
+        assert!((drift[0] - (heston_drift[0] - 0.5 * m)).abs() < 1.0e-15);
+        assert_eq!(drift[1], heston_drift[1]);
+        let diffusion: Vec<Vec<Real>> = process.diffusion(0.0, &x);
+        assert_eq!(diffusion[0][2..], [0.0, 0.0]);
+        assert_eq!(diffusion[1].len(), 4);
+    }
+
+    #[test]
+    fn evolve_adds_jumps_to_the_heston_step() {
+        let process: BatesProcess = BatesProcess::new(heston(), 0.5, -0.1, 0.2);
+        let x0: Vec<Real> = process.initial_values();
+        let dt: Time = 0.1;
+        let heston_step: Vec<Real> = heston().evolve(0.0, &x0, dt, &[0.5, -1.0]);
+        let compensator: Real = (-0.5 * process.m() * dt).exp();
+        // A very low uniform draw: no jump
+        let evolved: Vec<Real> = process.evolve(0.0, &x0, dt, &[0.5, -1.0, -10.0, 1.0]);
+        assert!((evolved[0] - heston_step[0] * compensator).abs() < 1.0e-12);
+        assert_eq!(evolved[1], heston_step[1]);
+        // P(N <= 1) = 0.99879 for lambda dt = 0.05: N(3.1) = 0.99903 gives two jumps
+        let evolved: Vec<Real> = process.evolve(0.0, &x0, dt, &[0.5, -1.0, 3.1, 1.0]);
+        let jumps: Real = 2.0;
+        let expected: Real =
+            heston_step[0] * compensator * (-0.1 * jumps + 0.2 * jumps.sqrt() * 1.0).exp();
+        assert!((evolved[0] - expected).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn without_jumps_it_is_the_heston_process() {
+        let process: BatesProcess = BatesProcess::new(heston(), 0.0, -0.1, 0.2);
+        let x0: Vec<Real> = process.initial_values();
+        let dw: [Real; 4] = [0.3, 0.7, 2.0, -1.0];
+        assert_eq!(
+            process.evolve(0.0, &x0, 0.25, &dw),
+            heston().evolve(0.0, &x0, 0.25, &dw[..2])
+        );
+    }
+
+    #[test]
+    fn new_panics_on_invalid_jumps() {
+        let cases: [(&str, Real, Real); 2] = [
+            ("negative intensity", -0.5, 0.2),
+            ("negative jump volatility", 0.5, -0.2),
+        ];
+        for (case, lambda, delta) in cases {
+            let result = panic::catch_unwind(|| BatesProcess::new(heston(), lambda, -0.1, delta));
+            assert!(result.is_err(), "expected panic for {case}");
+        }
+    }
+}
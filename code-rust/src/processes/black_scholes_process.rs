@@ -0,0 +1,263 @@
+use crate::stochastic_process::StochasticProcess1D;
+use crate::termstructures::volatility::equityfx::black_vol_term_structure::BlackVolTermStructure;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::date::Date;
+use crate::types::{Rate, Real, Time, Volatility};
+use std::rc::Rc;
+
+/*
+Black-Scholes-Merton process (QuantLib BlackScholesMertonProcess) with
+constant risk-free rate r, dividend yield q and volatility sigma:
+
+    dS = (r - q) S dt + sigma S dw
+
+Like QuantLib it works on ln(S): drift and diffusion are those of the
+logarithm, r - q - sigma^2 / 2 and sigma, and apply(S, dx) = S exp(dx),
+so that evolve is exact.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct BlackScholesMertonProcess {
+    x0: Real,
+    risk_free_rate: Rate,
+    dividend_yield: Rate,
+    volatility: Volatility,
+}
+impl BlackScholesMertonProcess {
+    // Constructor
+    pub fn new(
+        x0: Real,
+        risk_free_rate: Rate,
+        dividend_yield: Rate,
+        volatility: Volatility,
+    ) -> BlackScholesMertonProcess {
+        if x0 <= 0.0 {
+            panic!("negative or null underlying given ({x0})");
+        }
+        BlackScholesMertonProcess {
+            x0,
+            risk_free_rate,
+            dividend_yield,
+            volatility,
+        }
+    }
+
+    // Inspectors
+    pub fn risk_free_rate(&self) -> Rate {
+        self.risk_free_rate
+    }
+    pub fn dividend_yield(&self) -> Rate {
+        self.dividend_yield
+    }
+    pub fn volatility(&self) -> Volatility {
+        self.volatility
+    }
+}
+impl StochasticProcess1D for BlackScholesMertonProcess {
+    fn x0(&self) -> Real {
+        self.x0
+    }
+    fn drift(&self, _t: Time, _x: Real) -> Real {
+        self.risk_free_rate - self.dividend_yield - 0.5 * self.volatility * self.volatility
+    }
+    fn diffusion(&self, _t: Time, _x: Real) -> Real {
+        self.volatility
+    }
+    fn apply(&self, x0: Real, dx: Real) -> Real {
+        x0 * dx.exp()
+    }
+}
+
+/*
+Black-Scholes process whose volatility comes from a Black volatility
+structure and whose drift comes from risk-free and dividend yield
+curves (QuantLib GeneralizedBlackScholesProcess):
+
+    dS = (r(t) - q(t)) S dt + sigma(t, S) S dw
+
+It also works on ln(S). Over [t, t + dt] the log-spot moves by the
+forward carry ln(P_q(t + dt) P_r(t) / (P_q(t) P_r(t + dt))) - v / 2
+plus a Gaussian of variance v, the forward variance of the structure at
+the spot, so evolve is exact for structures that do not depend on the
+strike; the drift and diffusion use the forward rates and volatility
+over the next 1e-4 years. Times are measured on the curves and the
+volatility structure, which should share their reference date and day
+counter.
+*/
+#[derive(Clone)]
+pub struct GeneralizedBlackScholesProcess {
+    x0: Real,
+    risk_free_rate: Rc<dyn YieldTermStructure>,
+    dividend_yield: Rc<dyn YieldTermStructure>,
+    black_volatility: Rc<dyn BlackVolTermStructure>,
+}
+impl GeneralizedBlackScholesProcess {
+    // Constructor
+    pub fn new(
+        x0: Real,
+        risk_free_rate: Rc<dyn YieldTermStructure>,
+        dividend_yield: Rc<dyn YieldTermStructure>,
+        black_volatility: Rc<dyn BlackVolTermStructure>,
+    ) -> GeneralizedBlackScholesProcess {
+        if x0 <= 0.0 {
+            panic!("negative or null underlying given ({x0})");
+        }
+        GeneralizedBlackScholesProcess {
+            x0,
+            risk_free_rate,
+            dividend_yield,
+            black_volatility,
+        }
+    }
+
+    // Inspectors
+    pub fn risk_free_rate(&self) -> &dyn YieldTermStructure {
+        self.risk_free_rate.as_ref()
+    }
+    pub fn dividend_yield(&self) -> &dyn YieldTermStructure {
+        self.dividend_yield.as_ref()
+    }
+    pub fn black_volatility(&self) -> &dyn BlackVolTermStructure {
+        self.black_volatility.as_ref()
+    }
+
+    // Time to date on the risk-free curve, on which engines measure maturities (QuantLib time)
+    pub fn time(&self, date: Date) -> Time {
+        self.risk_free_rate.time_from_reference(date)
+    }
+
+    /*
+    Process with the zero rates and Black volatility of the given expiry
+    and strike, on which engines for a single option work (QuantLib
+    builds the same flat process for its lattice engines).
+    */
+    pub fn constant_volatility_process(
+        &self,
+        expiry: Date,
+        strike: Real,
+    ) -> BlackScholesMertonProcess {
+        let t: Time = self.black_volatility.time_from_reference(expiry);
+        BlackScholesMertonProcess::new(
+            self.x0,
+            self.risk_free_rate
+                .zero_rate(self.risk_free_rate.time_from_reference(expiry)),
+            self.dividend_yield
+                .zero_rate(self.dividend_yield.time_from_reference(expiry)),
+            self.black_volatility.black_vol(t, strike),
+        )
+    }
+
+    // Continuously-compounded forward of the carry r - q over [t, t + dt], times dt
+    fn carry(&self, t: Time, dt: Time) -> Real {
+        (self.risk_free_rate.forward_rate(t, t + dt) - self.dividend_yield.forward_rate(t, t + dt))
+            * dt
+    }
+}
+impl StochasticProcess1D for GeneralizedBlackScholesProcess {
+    fn x0(&self) -> Real {
+        self.x0
+    }
+    fn drift(&self, t: Time, x: Real) -> Real {
+        let volatility: Volatility = self.diffusion(t, x);
+        self.risk_free_rate.instantaneous_forward(t)
+            - self.dividend_yield.instantaneous_forward(t)
+            - 0.5 * volatility * volatility
+    }
+    fn diffusion(&self, t: Time, x: Real) -> Real {
+        self.black_volatility.black_forward_vol(t, t + 1.0e-4, x)
+    }
+    fn expectation(&self, t0: Time, x0: Real, dt: Time) -> Real {
+        let variance: Real = self.variance(t0, x0, dt);
+        self.apply(x0, self.carry(t0, dt) - 0.5 * variance)
+    }
+    fn std_deviation(&self, t0: Time, x0: Real, dt: Time) -> Real {
+        self.variance(t0, x0, dt).sqrt()
+    }
+    fn variance(&self, t0: Time, x0: Real, dt: Time) -> Real {
+        self.black_volatility
+            .black_forward_variance(t0, t0 + dt, x0)
+    }
+    fn apply(&self, x0: Real, dx: Real) -> Real {
+        x0 * dx.exp()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::termstructures::volatility::equityfx::black_constant_vol::BlackConstantVol;
+    use crate::termstructures::volatility::equityfx::black_variance_curve::{
+        BlackVarianceCurve, VarianceInterpolation,
+    };
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+
+    #[test]
+    fn evolve_is_lognormal() {
+        let process: BlackScholesMertonProcess =
+            BlackScholesMertonProcess::new(100.0, 0.05, 0.02, 0.2);
+        let (dt, dw): (Time, Real) = (0.25, 1.5);
+        let expected: Real = 100.0 * ((0.05 - 0.02 - 0.02) * dt + 0.2 * dt.sqrt() * dw).exp();
+
+        assert_eq!(process.x0(), 100.0);
+        assert_eq!(process.risk_free_rate(), 0.05);
+        assert_eq!(process.dividend_yield(), 0.02);
+        assert_eq!(process.volatility(), 0.2);
+        assert!((process.evolve(0.0, 100.0, dt, dw) - expected).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn generalized_process_on_a_constant_volatility() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let process: GeneralizedBlackScholesProcess = GeneralizedBlackScholesProcess::new(
+            100.0,
+            flat_rate(reference_date, 0.05),
+            flat_rate(reference_date, 0.02),
+            Rc::new(BlackConstantVol::new(
+                reference_date,
+                0.2,
+                Box::new(Actual365Fixed::new()),
+            )),
+        );
+        let constant: BlackScholesMertonProcess =
+            process.constant_volatility_process(reference_date + 365, 90.0);
+        assert!((constant.risk_free_rate() - 0.05).abs() < 1.0e-15);
+        assert!((constant.dividend_yield() - 0.02).abs() < 1.0e-15);
+        assert_eq!(constant.volatility(), 0.2);
+        let (dt, dw): (Time, Real) = (0.25, 1.5);
+        assert!((process.diffusion(0.5, 100.0) - 0.2).abs() < 1.0e-9);
+        assert!((process.drift(0.5, 100.0) - constant.drift(0.5, 100.0)).abs() < 1.0e-9);
+        assert!(
+            (process.evolve(0.5, 100.0, dt, dw) - constant.evolve(0.5, 100.0, dt, dw)).abs()
+                < 1.0e-12
+        );
+    }
+
+    #[test]
+    fn generalized_process_follows_the_forward_variance() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let process: GeneralizedBlackScholesProcess = GeneralizedBlackScholesProcess::new(
+            100.0,
+            flat_rate(reference_date, 0.05),
+            flat_rate(reference_date, 0.0),
+            Rc::new(BlackVarianceCurve::new(
+                reference_date,
+                &[reference_date + 365, reference_date + 730],
+                &[0.2, 0.3],
+                Box::new(Actual365Fixed::new()),
+                VarianceInterpolation::Linear,
+            )),
+        );
+        // Forward variance over the second year: 2 * 0.09 - 0.04
+        let variance: Real = 0.14;
+        assert!((process.variance(1.0, 100.0, 1.0) - variance).abs() < 1.0e-12);
+        assert!((process.diffusion(1.5, 100.0) - variance.sqrt()).abs() < 1.0e-9);
+        let expected: Real = 100.0 * (0.05 - 0.5 * variance + variance.sqrt() * 0.7).exp();
+        assert!((process.evolve(1.0, 100.0, 1.0, 0.7) - expected).abs() < 1.0e-10);
+        // The constant process uses the Black volatility of the expiry
+        let constant: BlackScholesMertonProcess =
+            process.constant_volatility_process(reference_date + 730, 100.0);
+        assert!((constant.volatility() - 0.3).abs() < 1.0e-15);
+    }
+}
@@ -0,0 +1,78 @@
+use crate::stochastic_process::StochasticProcess1D;
+use crate::types::{Real, Time, Volatility};
+
+/*
+Geometric Brownian motion (QuantLib GeometricBrownianMotionProcess).
+
+    dS = mu S dt + sigma S dw
+
+Drift and volatility are constant. Evolution uses the Euler
+discretization on S itself.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct GeometricBrownianMotionProcess {
+    initial_value: Real,
+    mue: Real,
+    sigma: Volatility,
+}
+impl GeometricBrownianMotionProcess {
+    // Constructor
+    pub fn new(
+        initial_value: Real,
+        mue: Real,
+        sigma: Volatility,
+    ) -> GeometricBrownianMotionProcess {
+        GeometricBrownianMotionProcess {
+            initial_value,
+            mue,
+            sigma,
+        }
+    }
+}
+impl StochasticProcess1D for GeometricBrownianMotionProcess {
+    fn x0(&self) -> Real {
+        self.initial_value
+    }
+    fn drift(&self, _t: Time, x: Real) -> Real {
+        self.mue * x
+    }
+    fn diffusion(&self, _t: Time, x: Real) -> Real {
+        self.sigma * x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drift_and_diffusion_are_proportional_to_level() {
+        let process: GeometricBrownianMotionProcess =
+            GeometricBrownianMotionProcess::new(100.0, 0.05, 0.2);
+        assert_eq!(process.x0(), 100.0);
+
+        let cases: [(Real, Real, Real); 3] =
+            [(50.0, 2.5, 10.0), (100.0, 5.0, 20.0), (200.0, 10.0, 40.0)];
+        for (x, drift, diffusion) in cases {
+            assert!(
+                (process.drift(0.0, x) - drift).abs() < 1.0e-12,
+                "drift at {x}"
+            );
+            assert!(
+                (process.diffusion(0.0, x) - diffusion).abs() < 1.0e-12,
+                "diffusion at {x}"
+            );
+        }
+    }
+
+    #[test]
+    fn evolve_uses_euler_step() {
+        let process: GeometricBrownianMotionProcess =
+            GeometricBrownianMotionProcess::new(100.0, 0.05, 0.2);
+        let dt: Time = 0.25;
+        // 100 + 0.05 * 100 * 0.25 + 0.2 * 100 * 0.5 * 1.0
+        assert!((process.evolve(0.0, 100.0, dt, 1.0) - 111.25).abs() < 1.0e-12);
+        assert!((process.expectation(0.0, 100.0, dt) - 101.25).abs() < 1.0e-12);
+        assert!((process.std_deviation(0.0, 100.0, dt) - 10.0).abs() < 1.0e-12);
+    }
+}
@@ -0,0 +1,179 @@
+use crate::stochastic_process::StochasticProcess;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::date::Date;
+use crate::types::{Real, Size, Time, Volatility};
+use std::rc::Rc;
+
+/*
+Heston process (QuantLib HestonProcess) whose drift comes from
+risk-free and dividend yield curves, r(t) and q(t) being their
+instantaneous forwards:
+
+    dS = (r(t) - q(t)) S dt + sqrt(v) S dw_1
+    dv = kappa (theta - v) dt + sigma sqrt(v) dw_2
+    dw_1 dw_2 = rho dt
+
+As in QuantLib the spot component works on ln(S), so apply multiplies
+the spot by exp(dx). Evolution is the Euler scheme with full
+truncation: negative variances are floored at zero in the drift and
+diffusion.
+*/
+#[derive(Clone)]
+pub struct HestonProcess {
+    s0: Real,
+    risk_free_rate: Rc<dyn YieldTermStructure>,
+    dividend_yield: Rc<dyn YieldTermStructure>,
+    v0: Real,
+    kappa: Real,
+    theta: Real,
+    sigma: Volatility,
+    rho: Real,
+}
+impl HestonProcess {
+    // Constructor
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        s0: Real,
+        risk_free_rate: Rc<dyn YieldTermStructure>,
+        dividend_yield: Rc<dyn YieldTermStructure>,
+        v0: Real,
+        kappa: Real,
+        theta: Real,
+        sigma: Volatility,
+        rho: Real,
+    ) -> HestonProcess {
+        if s0 <= 0.0 {
+            panic!("negative or null underlying given ({s0})");
+        }
+        if !(-1.0..=1.0).contains(&rho) {
+            panic!("correlation ({rho}) must be between -1 and 1");
+        }
+        HestonProcess {
+            s0,
+            risk_free_rate,
+            dividend_yield,
+            v0,
+            kappa,
+            theta,
+            sigma,
+            rho,
+        }
+    }
+
+    // Inspectors
+    pub fn s0(&self) -> Real {
+        self.s0
+    }
+    pub fn risk_free_rate(&self) -> &dyn YieldTermStructure {
+        self.risk_free_rate.as_ref()
+    }
+    pub fn dividend_yield(&self) -> &dyn YieldTermStructure {
+        self.dividend_yield.as_ref()
+    }
+    pub fn v0(&self) -> Real {
+        self.v0
+    }
+    pub fn kappa(&self) -> Real {
+        self.kappa
+    }
+    pub fn theta(&self) -> Real {
+        self.theta
+    }
+    pub fn sigma(&self) -> Volatility {
+        self.sigma
+    }
+    pub fn rho(&self) -> Real {
+        self.rho
+    }
+
+    // Time to date on the risk-free curve, on which engines measure maturities (QuantLib time)
+    pub fn time(&self, date: Date) -> Time {
+        self.risk_free_rate.time_from_reference(date)
+    }
+
+    // Process on the same spot and curves with other variance parameters
+    pub fn with_variance_parameters(
+        &self,
+        v0: Real,
+        kappa: Real,
+        theta: Real,
+        sigma: Volatility,
+        rho: Real,
+    ) -> HestonProcess {
+        HestonProcess::new(
+            self.s0,
+            self.risk_free_rate.clone(),
+            self.dividend_yield.clone(),
+            v0,
+            kappa,
+            theta,
+            sigma,
+            rho,
+        )
+    }
+}
+impl StochasticProcess for HestonProcess {
+    fn size(&self) -> Size {
+        2
+    }
+    fn initial_values(&self) -> Vec<Real> {
+        vec![self.s0, self.v0]
+    }
+    fn drift(&self, t: Time, x: &[Real]) -> Vec<Real> {
+        let v: Real = x[1].max(0.0);
+        vec![
+            self.risk_free_rate.instantaneous_forward(t)
+                - self.dividend_yield.instantaneous_forward(t)
+                - 0.5 * v,
+            self.kappa * (self.theta - v),
+        ]
+    }
+    fn diffusion(&self, _t: Time, x: &[Real]) -> Vec<Vec<Real>> {
+        let vol: Real = x[1].max(0.0).sqrt();
+        let sigma2: Real = self.sigma * vol;
+        vec![
+            vec![vol, 0.0],
+            vec![
+                self.rho * sigma2,
+                (1.0 - self.rho * self.rho).sqrt() * sigma2,
+            ],
+        ]
+    }
+    fn apply(&self, x0: &[Real], dx: &[Real]) -> Vec<Real> {
+        vec![x0[0] * dx[0].exp(), x0[1] + dx[1]]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::{Date, Month};
+
+    #[test]
+    fn evolve_uses_full_truncation_euler() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let process: HestonProcess = HestonProcess::new(
+            100.0,
+            flat_rate(reference_date, 0.05),
+            flat_rate(reference_date, 0.02),
+            0.04,
+            1.5,
+            0.09,
+            0.3,
+            -0.6,
+        );
+        let (dt, dw): (Time, [Real; 2]) = (0.01, [0.5, -1.0]);
+        let evolved: Vec<Real> = process.evolve(0.0, &process.initial_values(), dt, &dw);
+
+        let sqrt_dt: Real = dt.sqrt();
+        let dx: Real = (0.05 - 0.02 - 0.02) * dt + 0.2 * sqrt_dt * 0.5;
+        let dv: Real = 1.5 * 0.05 * dt + 0.3 * 0.2 * sqrt_dt * (-0.6 * 0.5 - 0.8 * 1.0);
+        assert!((evolved[0] - 100.0 * dx.exp()).abs() < 1.0e-12);
+        assert!((evolved[1] - (0.04 + dv)).abs() < 1.0e-12);
+
+        // Negative variances do not diffuse
+        let truncated: Vec<Real> = process.evolve(0.0, &[100.0, -0.01], dt, &dw);
+        assert!((truncated[1] - (-0.01 + 1.5 * 0.09 * dt)).abs() < 1.0e-14);
+    }
+}
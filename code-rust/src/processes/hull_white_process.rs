@@ -0,0 +1,162 @@
+use crate::processes::ornstein_uhlenbeck_process::OrnsteinUhlenbeckProcess;
+use crate::stochastic_process::StochasticProcess1D;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::types::{Real, Time, Volatility};
+use std::rc::Rc;
+
+/*
+Short-rate process of the Hull-White model (QuantLib HullWhiteProcess).
+
+    dr = (theta(t) - a r) dt + sigma dw
+
+with theta(t) fitted to the term structure. As in QuantLib, r = x + alpha(t)
+where x is an Ornstein-Uhlenbeck process with level 0 and
+    alpha(t) = f(0, t) + sigma^2 / (2 a^2) (1 - exp(-a t))^2
+f(0, t) being the instantaneous forward. The transition law is Gaussian
+and exact:
+    E[r_{t+dt}] = alpha(t + dt) + (r_t - alpha(t)) exp(-a dt)
+with the variance of x. The process starts at f(0, 0).
+*/
+#[derive(Clone)]
+pub struct HullWhiteProcess {
+    term_structure: Rc<dyn YieldTermStructure>,
+    process: OrnsteinUhlenbeckProcess,
+    a: Real,
+    sigma: Volatility,
+}
+impl HullWhiteProcess {
+    // Constructor
+    pub fn new(
+        term_structure: Rc<dyn YieldTermStructure>,
+        a: Real,
+        sigma: Volatility,
+    ) -> HullWhiteProcess {
+        let r0: Real = term_structure.instantaneous_forward(0.0);
+        HullWhiteProcess {
+            term_structure,
+            process: OrnsteinUhlenbeckProcess::new(a, sigma, r0, 0.0),
+            a,
+            sigma,
+        }
+    }
+
+    // Inspectors
+    pub fn term_structure(&self) -> &dyn YieldTermStructure {
+        self.term_structure.as_ref()
+    }
+    pub fn a(&self) -> Real {
+        self.a
+    }
+    pub fn sigma(&self) -> Volatility {
+        self.sigma
+    }
+
+    // Deterministic shift between the short rate and the Ornstein-Uhlenbeck process
+    pub fn alpha(&self, t: Time) -> Real {
+        let convexity: Real = if self.a > Real::EPSILON {
+            let b: Real = self.sigma * (1.0 - (-self.a * t).exp()) / self.a;
+            0.5 * b * b
+        } else {
+            0.5 * self.sigma * self.sigma * t * t
+        };
+        self.term_structure.instantaneous_forward(t) + convexity
+    }
+}
+impl StochasticProcess1D for HullWhiteProcess {
+    fn x0(&self) -> Real {
+        self.process.x0()
+    }
+    fn drift(&self, t: Time, x: Real) -> Real {
+        // theta(t) = f'(0, t) + a f(0, t) + sigma^2 / (2 a) (1 - exp(-2 a t))
+        let shift: Time = 1.0e-4;
+        let f: Real = self.term_structure.instantaneous_forward(t);
+        let f_up: Real = self.term_structure.instantaneous_forward(t + shift);
+        let convexity: Real = if self.a > Real::EPSILON {
+            self.sigma * self.sigma / (2.0 * self.a) * (1.0 - (-2.0 * self.a * t).exp())
+        } else {
+            self.sigma * self.sigma * t
+        };
+        self.process.drift(t, x) + convexity + self.a * f + (f_up - f) / shift
+    }
+    fn diffusion(&self, t: Time, x: Real) -> Real {
+        self.process.diffusion(t, x)
+    }
+    fn expectation(&self, t0: Time, x0: Real, dt: Time) -> Real {
+        self.process.expectation(t0, x0, dt) + self.alpha(t0 + dt)
+            - self.alpha(t0) * (-self.a * dt).exp()
+    }
+    fn std_deviation(&self, t0: Time, x0: Real, dt: Time) -> Real {
+        self.process.std_deviation(t0, x0, dt)
+    }
+    fn variance(&self, t0: Time, x0: Real, dt: Time) -> Real {
+        self.process.variance(t0, x0, dt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::termstructures::yieldcurves::flat_forward::FlatForward;
+    use crate::time::date::{Date, Month};
+    use crate::time::day_counter::DayCounter;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::types::DiscountFactor;
+
+    // Curve with forwards rising from 2% to 4%: P(0, t) = exp(-0.02 t - 0.002 t^2)
+    struct SlopedCurve(FlatForward);
+    impl YieldTermStructure for SlopedCurve {
+        fn reference_date(&self) -> Date {
+            self.0.reference_date()
+        }
+        fn day_counter(&self) -> &dyn DayCounter {
+            self.0.day_counter()
+        }
+        fn discount(&self, t: Time) -> DiscountFactor {
+            (-0.02 * t - 0.002 * t * t).exp()
+        }
+    }
+
+    fn process(a: Real) -> HullWhiteProcess {
+        HullWhiteProcess::new(
+            Rc::new(SlopedCurve(FlatForward::new(
+                Date::new(15, Month::January, 2025),
+                0.0,
+                Box::new(Actual365Fixed::new()),
+            ))),
+            a,
+            0.01,
+        )
+    }
+
+    #[test]
+    fn starts_at_the_short_forward_and_follows_alpha() {
+        let process: HullWhiteProcess = process(0.1);
+        assert!((process.x0() - 0.02).abs() < 1.0e-6);
+        assert!((process.alpha(0.0) - process.x0()).abs() < 1.0e-12);
+        // From r_0 = alpha(0) the expected rate is alpha(t)
+        for t in [0.5, 2.0, 10.0] {
+            let b: Real = 0.01 * (1.0 - (-0.1 * t as Real).exp()) / 0.1;
+            let expected: Real = 0.02 + 0.004 * t + 0.5 * b * b;
+            assert!((process.alpha(t) - expected).abs() < 1.0e-6, "{}", t);
+            assert!((process.expectation(0.0, process.x0(), t) - process.alpha(t)).abs() < 1.0e-12);
+        }
+        let variance: Real = 0.0001 / 0.2 * (1.0 - (-0.2_f64).exp());
+        assert!((process.variance(1.0, 0.03, 1.0) - variance).abs() < 1.0e-15);
+        assert_eq!(process.diffusion(1.0, 0.03), 0.01);
+    }
+
+    #[test]
+    fn drift_is_the_slope_of_the_expectation() {
+        for a in [0.1, 0.0] {
+            let process: HullWhiteProcess = process(a);
+            let (t, x, dt): (Time, Real, Time) = (3.0, 0.05, 1.0e-6);
+            let slope: Real = (process.expectation(t, x, dt) - x) / dt;
+            assert!(
+                (process.drift(t, x) - slope).abs() < 1.0e-5,
+                "{} vs {}",
+                process.drift(t, x),
+                slope
+            );
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use crate::stochastic_process::StochasticProcess1D;
+use crate::types::{Real, Time, Volatility};
+
+/*
+Ornstein-Uhlenbeck process (QuantLib OrnsteinUhlenbeckProcess).
+
+    dx = a (r - x) dt + sigma dw
+
+a is the speed of mean reversion, r the level and sigma the volatility.
+The transition law is Gaussian and known exactly, so expectation and
+variance are overridden instead of using the Euler discretization:
+
+    E[x_{t+dt}]   = r + (x_t - r) exp(-a dt)
+    Var[x_{t+dt}] = sigma^2 / (2a) * (1 - exp(-2a dt))
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct OrnsteinUhlenbeckProcess {
+    x0: Real,
+    speed: Real,
+    level: Real,
+    volatility: Volatility,
+}
+impl OrnsteinUhlenbeckProcess {
+    // Constructor
+    pub fn new(
+        speed: Real,
+        volatility: Volatility,
+        x0: Real,
+        level: Real,
+    ) -> OrnsteinUhlenbeckProcess {
+        if volatility < 0.0 {
+            panic!("negative volatility given ({volatility})");
+        }
+        OrnsteinUhlenbeckProcess {
+            x0,
+            speed,
+            level,
+            volatility,
+        }
+    }
+
+    // Inspectors
+    pub fn speed(&self) -> Real {
+        self.speed
+    }
+    pub fn volatility(&self) -> Volatility {
+        self.volatility
+    }
+    pub fn level(&self) -> Real {
+        self.level
+    }
+}
+impl StochasticProcess1D for OrnsteinUhlenbeckProcess {
+    fn x0(&self) -> Real {
+        self.x0
+    }
+    fn drift(&self, _t: Time, x: Real) -> Real {
+        self.speed * (self.level - x)
+    }
+    fn diffusion(&self, _t: Time, _x: Real) -> Real {
+        self.volatility
+    }
+    fn expectation(&self, _t0: Time, x0: Real, dt: Time) -> Real {
+        self.level + (x0 - self.level) * (-self.speed * dt).exp()
+    }
+    fn std_deviation(&self, t0: Time, x0: Real, dt: Time) -> Real {
+        self.variance(t0, x0, dt).sqrt()
+    }
+    fn variance(&self, _t0: Time, _x0: Real, dt: Time) -> Real {
+        if self.speed < Real::EPSILON.sqrt() {
+            // Algebraic limit for small speed: Brownian motion
+            self.volatility * self.volatility * dt
+        } else {
+            0.5 * self.volatility * self.volatility / self.speed
+                * (1.0 - (-2.0 * self.speed * dt).exp())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    #[test]
+    fn inspectors_return_constructor_values() {
+        let process: OrnsteinUhlenbeckProcess = OrnsteinUhlenbeckProcess::new(0.5, 0.1, 0.03, 0.04);
+        assert_eq!(process.speed(), 0.5);
+        assert_eq!(process.volatility(), 0.1);
+        assert_eq!(process.x0(), 0.03);
+        assert_eq!(process.level(), 0.04);
+        assert_eq!(process.drift(0.0, 0.02), 0.5 * (0.04 - 0.02));
+        assert_eq!(process.diffusion(0.0, 0.02), 0.1);
+    }
+
+    #[test]
+    fn exact_expectation_and_variance() {
+        let process: OrnsteinUhlenbeckProcess = OrnsteinUhlenbeckProcess::new(0.5, 0.1, 0.03, 0.04);
+        let dt: Time = 2.0;
+
+        let expected_mean: Real = 0.04 + (0.03 - 0.04) * (-1.0_f64).exp();
+        let expected_variance: Real = 0.5 * 0.01 / 0.5 * (1.0 - (-2.0_f64).exp());
+
+        assert!((process.expectation(0.0, 0.03, dt) - expected_mean).abs() < 1.0e-15);
+        assert!((process.variance(0.0, 0.03, dt) - expected_variance).abs() < 1.0e-15);
+        assert!((process.std_deviation(0.0, 0.03, dt) - expected_variance.sqrt()).abs() < 1.0e-15);
+        assert!(
+            (process.evolve(0.0, 0.03, dt, 1.5) - (expected_mean + 1.5 * expected_variance.sqrt()))
+                .abs()
+                < 1.0e-15
+        );
+    }
+
+    #[test]
+    fn zero_speed_is_brownian_motion() {
+        let process: OrnsteinUhlenbeckProcess = OrnsteinUhlenbeckProcess::new(0.0, 0.2, 1.0, 0.0);
+        assert_eq!(process.expectation(0.0, 1.0, 3.0), 1.0);
+        assert!((process.variance(0.0, 1.0, 3.0) - 0.12).abs() < 1.0e-15);
+    }
+
+    #[test]
+    fn variance_converges_to_stationary_value() {
+        // For large dt the variance tends to sigma^2 / (2a)
+        let process: OrnsteinUhlenbeckProcess = OrnsteinUhlenbeckProcess::new(2.0, 0.3, 0.0, 0.0);
+        assert!((process.variance(0.0, 0.0, 100.0) - 0.09 / 4.0).abs() < 1.0e-15);
+    }
+
+    #[test]
+    fn new_panics_for_negative_volatility() {
+        let result = panic::catch_unwind(|| OrnsteinUhlenbeckProcess::new(0.5, -0.1, 0.0, 0.0));
+        assert!(result.is_err(), "expected panic for negative volatility");
+    }
+}
@@ -0,0 +1,76 @@
+use crate::stochastic_process::StochasticProcess1D;
+use crate::types::{Real, Time, Volatility};
+
+/*
+Square-root (CIR) process (QuantLib SquareRootProcess).
+
+    dx = a (b - x) dt + sigma sqrt(x) dw
+
+a is the speed of mean reversion, b the mean level and sigma the
+volatility. Evolution uses the Euler discretization.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SquareRootProcess {
+    x0: Real,
+    mean: Real,
+    speed: Real,
+    volatility: Volatility,
+}
+impl SquareRootProcess {
+    // Constructor
+    pub fn new(mean: Real, speed: Real, volatility: Volatility, x0: Real) -> SquareRootProcess {
+        SquareRootProcess {
+            x0,
+            mean,
+            speed,
+            volatility,
+        }
+    }
+
+    // Inspectors
+    pub fn mean(&self) -> Real {
+        self.mean
+    }
+    pub fn speed(&self) -> Real {
+        self.speed
+    }
+    pub fn volatility(&self) -> Volatility {
+        self.volatility
+    }
+}
+impl StochasticProcess1D for SquareRootProcess {
+    fn x0(&self) -> Real {
+        self.x0
+    }
+    fn drift(&self, _t: Time, x: Real) -> Real {
+        self.speed * (self.mean - x)
+    }
+    fn diffusion(&self, _t: Time, x: Real) -> Real {
+        self.volatility * x.sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drift_and_diffusion() {
+        let process: SquareRootProcess = SquareRootProcess::new(0.04, 1.5, 0.3, 0.09);
+        assert_eq!(process.x0(), 0.09);
+        assert_eq!(process.mean(), 0.04);
+        assert_eq!(process.speed(), 1.5);
+        assert_eq!(process.volatility(), 0.3);
+        assert!((process.drift(0.0, 0.09) - 1.5 * (0.04 - 0.09)).abs() < 1.0e-16);
+        assert!((process.diffusion(0.0, 0.09) - 0.09).abs() < 1.0e-16);
+    }
+
+    #[test]
+    fn evolve_uses_euler_step() {
+        let process: SquareRootProcess = SquareRootProcess::new(0.04, 1.5, 0.3, 0.09);
+        let dt: Time = 0.01;
+        let dw: Real = -0.5;
+        let expected: Real = 0.09 + 1.5 * (0.04 - 0.09) * dt + 0.3 * 0.3 * dt.sqrt() * dw;
+        assert!((process.evolve(0.0, 0.09, dt, dw) - expected).abs() < 1.0e-16);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::math::matrix::Matrix;
+use crate::math::matrixutilities::cholesky_decomposition::cholesky_decomposition;
+use crate::stochastic_process::{StochasticProcess, StochasticProcess1D};
+use crate::types::{Real, Size, Time};
+
+/*
+Array of correlated 1-D processes (QuantLib StochasticProcessArray),
+seen as a single multi-dimensional process.
+
+Each component follows its own 1-D dynamics; their Brownian motions
+have the given correlation matrix, which must be symmetric with unit
+diagonal and positive definite. Independent draws are correlated with
+the Cholesky factor L of the matrix (L L^T = correlation):
+    - diffusion row i = sigma_i * L[i]
+    - evolve correlates the draws first, then lets each component
+      evolve with its own (possibly exact) discretization
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct StochasticProcessArray<P: StochasticProcess1D> {
+    processes: Vec<P>,
+    correlation: Matrix,
+    sqrt_correlation: Matrix,
+}
+impl<P: StochasticProcess1D> StochasticProcessArray<P> {
+    // Constructor
+    pub fn new(processes: Vec<P>, correlation: Matrix) -> StochasticProcessArray<P> {
+        if processes.is_empty() {
+            panic!("no processes given");
+        }
+        if correlation.rows() != processes.len() || correlation.columns() != processes.len() {
+            panic!(
+                "correlation matrix must be {0} x {0} like the number of processes",
+                processes.len()
+            );
+        }
+        for i in 0..correlation.rows() {
+            if correlation[i][i] != 1.0 {
+                panic!(
+                    "non unit diagonal element ({}) in correlation matrix",
+                    correlation[i][i]
+                );
+            }
+            for j in 0..i {
+                if correlation[i][j] != correlation[j][i] {
+                    panic!("correlation matrix not symmetric at ({i}, {j})");
+                }
+            }
+        }
+        let sqrt_correlation: Matrix = cholesky_decomposition(&correlation, false);
+        StochasticProcessArray {
+            processes,
+            correlation,
+            sqrt_correlation,
+        }
+    }
+
+    // Inspectors
+    pub fn process(&self, i: Size) -> &P {
+        &self.processes[i]
+    }
+    pub fn correlation(&self) -> &Matrix {
+        &self.correlation
+    }
+
+    // Independent draws turned into correlated ones: L * dw
+    fn correlated(&self, dw: &[Real]) -> Vec<Real> {
+        (0..self.sqrt_correlation.rows())
+            .map(|i| {
+                let row: &[Real] = &self.sqrt_correlation[i];
+                row.iter().zip(dw.iter()).map(|(l, w)| l * w).sum()
+            })
+            .collect()
+    }
+}
+// Traits
+impl<P: StochasticProcess1D> StochasticProcess for StochasticProcessArray<P> {
+    fn size(&self) -> Size {
+        self.processes.len()
+    }
+    fn initial_values(&self) -> Vec<Real> {
+        self.processes.iter().map(|p| p.x0()).collect()
+    }
+    fn drift(&self, t: Time, x: &[Real]) -> Vec<Real> {
+        self.processes
+            .iter()
+            .zip(x.iter())
+            .map(|(p, x)| p.drift(t, *x))
+            .collect()
+    }
+    fn diffusion(&self, t: Time, x: &[Real]) -> Vec<Vec<Real>> {
+        self.processes
+            .iter()
+            .zip(x.iter())
+            .enumerate()
+            .map(|(i, (p, x))| {
+                let row: &[Real] = &self.sqrt_correlation[i];
+                let sigma: Real = p.diffusion(t, *x);
+                row.iter().map(|l| sigma * l).collect()
+            })
+            .collect()
+    }
+    fn expectation(&self, t0: Time, x0: &[Real], dt: Time) -> Vec<Real> {
+        self.processes
+            .iter()
+            .zip(x0.iter())
+            .map(|(p, x)| p.expectation(t0, *x, dt))
+            .collect()
+    }
+    fn std_deviation(&self, t0: Time, x0: &[Real], dt: Time) -> Vec<Vec<Real>> {
+        self.processes
+            .iter()
+            .zip(x0.iter())
+            .enumerate()
+            .map(|(i, (p, x))| {
+                let row: &[Real] = &self.sqrt_correlation[i];
+                let std_deviation: Real = p.std_deviation(t0, *x, dt);
+                row.iter().map(|l| std_deviation * l).collect()
+            })
+            .collect()
+    }
+    fn evolve(&self, t0: Time, x0: &[Real], dt: Time, dw: &[Real]) -> Vec<Real> {
+        self.processes
+            .iter()
+            .zip(x0.iter())
+            .zip(self.correlated(dw))
+            .map(|((p, x), w)| p.evolve(t0, *x, dt, w))
+            .collect()
+    }
+    fn apply(&self, x0: &[Real], dx: &[Real]) -> Vec<Real> {
+        self.processes
+            .iter()
+            .zip(x0.iter().zip(dx.iter()))
+            .map(|(p, (x, d))| p.apply(*x, *d))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::processes::black_scholes_process::BlackScholesMertonProcess;
+    use std::panic;
+
+    fn processes() -> Vec<BlackScholesMertonProcess> {
+        vec![
+            BlackScholesMertonProcess::new(100.0, 0.05, 0.02, 0.2),
+            BlackScholesMertonProcess::new(50.0, 0.05, 0.0, 0.3),
+        ]
+    }
+
+    #[test]
+    fn diffusion_gives_the_correlated_covariance() {
+        let array: StochasticProcessArray<BlackScholesMertonProcess> = StochasticProcessArray::new(
+            processes(),
+            Matrix::from_rows(&[vec![1.0, 0.4], vec![0.4, 1.0]]),
+        );
+        let x0: Vec<Real> = array.initial_values();
+        let covariance: Vec<Vec<Real>> = array.covariance(0.0, &x0, 2.0);
+        let expected: [[Real; 2]; 2] = [[0.08, 0.048], [0.048, 0.18]];
+
+        assert_eq!(array.size(), 2);
+        assert_eq!(x0, vec![100.0, 50.0]);
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!((covariance[i][j] - expected[i][j]).abs() < 1.0e-15);
+            }
+        }
+    }
+
+    #[test]
+    fn evolve_uses_the_component_dynamics() {
+        let array: StochasticProcessArray<BlackScholesMertonProcess> = StochasticProcessArray::new(
+            processes(),
+            Matrix::from_rows(&[vec![1.0, -0.6], vec![-0.6, 1.0]]),
+        );
+        let (dt, dw): (Time, [Real; 2]) = (0.5, [1.0, 0.5]);
+        let evolved: Vec<Real> = array.evolve(0.0, &[100.0, 50.0], dt, &dw);
+        // Second draw: -0.6 * 1.0 + 0.8 * 0.5
+        let expected: [Real; 2] = [
+            processes()[0].evolve(0.0, 100.0, dt, 1.0),
+            processes()[1].evolve(0.0, 50.0, dt, -0.2),
+        ];
+
+        for i in 0..2 {
+            assert!((evolved[i] - expected[i]).abs() < 1.0e-12);
+        }
+    }
+
+    #[test]
+    fn new_panics_on_invalid_correlations() {
+        let cases: [(Vec<Vec<Real>>, &str); 4] = [
+            (vec![vec![1.0, 0.5]], "wrong size"),
+            (vec![vec![1.0, 0.5], vec![0.5, 0.9]], "non unit diagonal"),
+            (vec![vec![1.0, 0.5], vec![0.4, 1.0]], "non symmetric"),
+            (
+                vec![
+                    vec![1.0, 0.9, -0.9],
+                    vec![0.9, 1.0, 0.9],
+                    vec![-0.9, 0.9, 1.0],
+                ],
+                "not positive definite",
+            ),
+        ];
+        for (correlation, label) in cases {
+            let n: Size = correlation.len();
+            let result = panic::catch_unwind(|| {
+                StochasticProcessArray::new(
+                    vec![BlackScholesMertonProcess::new(100.0, 0.05, 0.0, 0.2); n.max(2)],
+                    Matrix::from_rows(&correlation),
+                )
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use crate::types::{Real, Size, Time};
+
+/*
+Multi-dimensional stochastic process (QuantLib StochasticProcess).
+
+    dx_t = mu(t, x_t) dt + sigma(t, x_t) dw_t
+
+mu is the drift (vector) and sigma the diffusion (matrix, size x factors).
+Only drift and diffusion are mandatory: the default expectation,
+std_deviation, covariance and evolve use the Euler discretization,
+i.e. drift and diffusion are frozen over [t0, t0 + dt].
+Processes with an exact transition law override them.
+
+Vectors are Vec<Real> and matrices are rows of Vec<Real>.
+*/
+pub trait StochasticProcess {
+    // Number of dimensions of the process
+    fn size(&self) -> Size;
+    // Number of independent Brownian factors driving the process
+    fn factors(&self) -> Size {
+        self.size()
+    }
+    fn initial_values(&self) -> Vec<Real>;
+    fn drift(&self, t: Time, x: &[Real]) -> Vec<Real>;
+    fn diffusion(&self, t: Time, x: &[Real]) -> Vec<Vec<Real>>;
+
+    // E[x_{t0 + dt} | x_{t0} = x0]
+    fn expectation(&self, t0: Time, x0: &[Real], dt: Time) -> Vec<Real> {
+        let drift: Vec<Real> = self.drift(t0, x0);
+        let dx: Vec<Real> = drift.iter().map(|mu| mu * dt).collect();
+        self.apply(x0, &dx)
+    }
+    // Matrix S such that S * S^T is the covariance over dt
+    fn std_deviation(&self, t0: Time, x0: &[Real], dt: Time) -> Vec<Vec<Real>> {
+        let sqrt_dt: Real = dt.sqrt();
+        self.diffusion(t0, x0)
+            .iter()
+            .map(|row| row.iter().map(|sigma| sigma * sqrt_dt).collect())
+            .collect()
+    }
+    // Cov[x_{t0 + dt} | x_{t0} = x0] = sigma * sigma^T * dt
+    fn covariance(&self, t0: Time, x0: &[Real], dt: Time) -> Vec<Vec<Real>> {
+        let sigma: Vec<Vec<Real>> = self.diffusion(t0, x0);
+        sigma
+            .iter()
+            .map(|row_i| {
+                sigma
+                    .iter()
+                    .map(|row_j| {
+                        row_i
+                            .iter()
+                            .zip(row_j.iter())
+                            .map(|(a, b)| a * b)
+                            .sum::<Real>()
+                            * dt
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+    /*
+    Asset value after a time step dt, given the Gaussian increments dw
+    (one per factor):
+        x = E[x] + S * dw
+    */
+    fn evolve(&self, t0: Time, x0: &[Real], dt: Time, dw: &[Real]) -> Vec<Real> {
+        let std_deviation: Vec<Vec<Real>> = self.std_deviation(t0, x0, dt);
+        let dx: Vec<Real> = std_deviation
+            .iter()
+            .map(|row| row.iter().zip(dw.iter()).map(|(s, w)| s * w).sum())
+            .collect();
+        self.apply(&self.expectation(t0, x0, dt), &dx)
+    }
+    // Applies a change to the asset value (e.g. x * exp(dx) for log-processes)
+    fn apply(&self, x0: &[Real], dx: &[Real]) -> Vec<Real> {
+        x0.iter().zip(dx.iter()).map(|(x, d)| x + d).collect()
+    }
+}
+
+/*
+1-D stochastic process (QuantLib StochasticProcess1D).
+
+    dx_t = mu(t, x_t) dt + sigma(t, x_t) dw_t
+
+Same idea as above with scalars instead of vectors and matrices:
+the default methods are the Euler discretization.
+*/
+pub trait StochasticProcess1D {
+    fn x0(&self) -> Real;
+    fn drift(&self, t: Time, x: Real) -> Real;
+    fn diffusion(&self, t: Time, x: Real) -> Real;
+
+    // E[x_{t0 + dt} | x_{t0} = x0]
+    fn expectation(&self, t0: Time, x0: Real, dt: Time) -> Real {
+        self.apply(x0, self.drift(t0, x0) * dt)
+    }
+    // Std[x_{t0 + dt} | x_{t0} = x0]
+    fn std_deviation(&self, t0: Time, x0: Real, dt: Time) -> Real {
+        self.diffusion(t0, x0) * dt.sqrt()
+    }
+    // Var[x_{t0 + dt} | x_{t0} = x0]
+    fn variance(&self, t0: Time, x0: Real, dt: Time) -> Real {
+        let std_deviation: Real = self.std_deviation(t0, x0, dt);
+        std_deviation * std_deviation
+    }
+    // x = E[x] + Std[x] * dw, with dw a standard Gaussian draw
+    fn evolve(&self, t0: Time, x0: Real, dt: Time, dw: Real) -> Real {
+        self.apply(
+            self.expectation(t0, x0, dt),
+            self.std_deviation(t0, x0, dt) * dw,
+        )
+    }
+    fn apply(&self, x0: Real, dx: Real) -> Real {
+        x0 + dx
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // dx = a dt + b dw, i.e. Brownian motion with constant drift
+    struct ArithmeticBrownianMotion {
+        a: Real,
+        b: Real,
+    }
+    impl StochasticProcess1D for ArithmeticBrownianMotion {
+        fn x0(&self) -> Real {
+            1.0
+        }
+        fn drift(&self, _t: Time, _x: Real) -> Real {
+            self.a
+        }
+        fn diffusion(&self, _t: Time, _x: Real) -> Real {
+            self.b
+        }
+    }
+
+    // Two correlated Brownian motions driven by two factors
+    struct CorrelatedBrownianMotions {
+        rho: Real,
+    }
+    impl StochasticProcess for CorrelatedBrownianMotions {
+        fn size(&self) -> Size {
+            2
+        }
+        fn initial_values(&self) -> Vec<Real> {
+            vec![0.0, 0.0]
+        }
+        fn drift(&self, _t: Time, _x: &[Real]) -> Vec<Real> {
+            vec![0.1, -0.2]
+        }
+        fn diffusion(&self, _t: Time, _x: &[Real]) -> Vec<Vec<Real>> {
+            // Cholesky factor of [[1, rho], [rho, 1]]
+            vec![
+                vec![1.0, 0.0],
+                vec![self.rho, (1.0 - self.rho * self.rho).sqrt()],
+            ]
+        }
+    }
+
+    #[test]
+    fn default_1d_methods_use_euler_discretization() {
+        let process: ArithmeticBrownianMotion = ArithmeticBrownianMotion { a: 0.5, b: 0.2 };
+        let dt: Time = 0.25;
+
+        assert_eq!(process.expectation(0.0, 1.0, dt), 1.125);
+        assert_eq!(process.std_deviation(0.0, 1.0, dt), 0.1);
+        assert!((process.variance(0.0, 1.0, dt) - 0.01).abs() < 1.0e-16);
+        assert_eq!(process.evolve(0.0, 1.0, dt, 2.0), 1.325);
+    }
+
+    #[test]
+    fn default_multi_dimensional_methods_use_euler_discretization() {
+        let process: CorrelatedBrownianMotions = CorrelatedBrownianMotions { rho: 0.6 };
+        let x0: Vec<Real> = process.initial_values();
+        let dt: Time = 4.0;
+
+        assert_eq!(process.factors(), 2);
+        assert_eq!(process.expectation(0.0, &x0, dt), vec![0.4, -0.8]);
+        assert_eq!(
+            process.std_deviation(0.0, &x0, dt),
+            vec![vec![2.0, 0.0], vec![1.2, 1.6]]
+        );
+
+        let covariance: Vec<Vec<Real>> = process.covariance(0.0, &x0, dt);
+        let expected: [[Real; 2]; 2] = [[4.0, 2.4], [2.4, 4.0]];
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!(
+                    (covariance[i][j] - expected[i][j]).abs() < 1.0e-14,
+                    "covariance[{}][{}] = {}",
+                    i,
+                    j,
+                    covariance[i][j]
+                );
+            }
+        }
+
+        let evolved: Vec<Real> = process.evolve(0.0, &x0, dt, &[1.0, -1.0]);
+        assert!((evolved[0] - 2.4).abs() < 1.0e-14);
+        assert!((evolved[1] - (-0.8 + 1.2 - 1.6)).abs() < 1.0e-14);
+    }
+}
@@ -0,0 +1,274 @@
+use crate::instruments::credit_default_swap::{CreditDefaultSwap, ProtectionSide};
+use crate::pricingengines::credit::cds_results::CdsResults;
+use crate::pricingengines::credit::{isda_cds_engine, mid_point_cds_engine};
+use crate::termstructures::credit::default_probability_term_structure::DefaultProbabilityTermStructure;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::date::Date;
+use crate::time::period::Period;
+use crate::types::{Rate, Real};
+use std::fmt::{Display, Formatter, Result};
+use std::rc::Rc;
+
+// Engine used to price the quoted contracts (QuantLib CdsHelper::PricingModel)
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum CdsPricingModel {
+    MidPoint,
+    Isda,
+}
+
+// Traits
+impl Display for CdsPricingModel {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let model: &'static str = match self {
+            CdsPricingModel::MidPoint => "MidPoint",
+            CdsPricingModel::Isda => "ISDA",
+        };
+        write!(f, "{model}")
+    }
+}
+
+/*
+Market quote a default curve is bootstrapped on (QuantLib
+DefaultProbabilityHelper): the curve must reproduce quote, which only
+depends on the curve up to the pillar date.
+*/
+pub trait DefaultProbabilityHelper {
+    fn quote(&self) -> Real;
+    fn pillar_date(&self) -> Date;
+    fn implied_quote(&self, probability: &dyn DefaultProbabilityTermStructure) -> Real;
+}
+
+/*
+Running spread quote of a standard CDS without upfront (QuantLib
+SpreadCdsHelper). The contract is traded on trade_date with the CDS2015
+schedule for tenor and discounted on discount_curve.
+*/
+#[derive(Clone)]
+pub struct SpreadCdsHelper {
+    cds: CreditDefaultSwap,
+    running_spread: Rate,
+    recovery_rate: Real,
+    discount_curve: Rc<dyn YieldTermStructure>,
+    model: CdsPricingModel,
+}
+impl SpreadCdsHelper {
+    // Constructor
+    pub fn new(
+        running_spread: Rate,
+        tenor: Period,
+        trade_date: Date,
+        recovery_rate: Real,
+        discount_curve: Rc<dyn YieldTermStructure>,
+        model: CdsPricingModel,
+    ) -> SpreadCdsHelper {
+        SpreadCdsHelper {
+            cds: CreditDefaultSwap::cds2015(
+                ProtectionSide::Buyer,
+                1.0,
+                0.0,
+                running_spread,
+                trade_date,
+                tenor,
+            ),
+            running_spread,
+            recovery_rate,
+            discount_curve,
+            model,
+        }
+    }
+
+    // Inspectors
+    pub fn cds(&self) -> &CreditDefaultSwap {
+        &self.cds
+    }
+    pub fn discount_curve(&self) -> &dyn YieldTermStructure {
+        self.discount_curve.as_ref()
+    }
+}
+impl DefaultProbabilityHelper for SpreadCdsHelper {
+    fn quote(&self) -> Real {
+        self.running_spread
+    }
+    fn pillar_date(&self) -> Date {
+        self.cds.maturity()
+    }
+    fn implied_quote(&self, probability: &dyn DefaultProbabilityTermStructure) -> Real {
+        detail::calculate(
+            &self.cds,
+            probability,
+            self.recovery_rate,
+            self.discount_curve.as_ref(),
+            self.model,
+        )
+        .fair_spread
+    }
+}
+
+/*
+Upfront quote, as a fraction of the notional, of a standard CDS paying
+running_spread (QuantLib UpfrontCdsHelper). The contract is traded on
+trade_date with the CDS2015 schedule for tenor and discounted on
+discount_curve.
+*/
+#[derive(Clone)]
+pub struct UpfrontCdsHelper {
+    cds: CreditDefaultSwap,
+    upfront: Rate,
+    recovery_rate: Real,
+    discount_curve: Rc<dyn YieldTermStructure>,
+    model: CdsPricingModel,
+}
+impl UpfrontCdsHelper {
+    // Constructor
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        upfront: Rate,
+        running_spread: Rate,
+        tenor: Period,
+        trade_date: Date,
+        recovery_rate: Real,
+        discount_curve: Rc<dyn YieldTermStructure>,
+        model: CdsPricingModel,
+    ) -> UpfrontCdsHelper {
+        UpfrontCdsHelper {
+            cds: CreditDefaultSwap::cds2015(
+                ProtectionSide::Buyer,
+                1.0,
+                upfront,
+                running_spread,
+                trade_date,
+                tenor,
+            ),
+            upfront,
+            recovery_rate,
+            discount_curve,
+            model,
+        }
+    }
+
+    // Inspectors
+    pub fn cds(&self) -> &CreditDefaultSwap {
+        &self.cds
+    }
+    pub fn discount_curve(&self) -> &dyn YieldTermStructure {
+        self.discount_curve.as_ref()
+    }
+}
+impl DefaultProbabilityHelper for UpfrontCdsHelper {
+    fn quote(&self) -> Real {
+        self.upfront
+    }
+    fn pillar_date(&self) -> Date {
+        self.cds.maturity()
+    }
+    fn implied_quote(&self, probability: &dyn DefaultProbabilityTermStructure) -> Real {
+        detail::calculate(
+            &self.cds,
+            probability,
+            self.recovery_rate,
+            self.discount_curve.as_ref(),
+            self.model,
+        )
+        .fair_upfront
+    }
+}
+
+mod detail {
+    use super::*;
+
+    pub(super) fn calculate(
+        cds: &CreditDefaultSwap,
+        probability: &dyn DefaultProbabilityTermStructure,
+        recovery_rate: Real,
+        discount_curve: &dyn YieldTermStructure,
+        model: CdsPricingModel,
+    ) -> CdsResults {
+        mid_point_cds_engine::detail::check_recovery_rate(recovery_rate);
+        match model {
+            CdsPricingModel::MidPoint => mid_point_cds_engine::detail::calculate(
+                cds,
+                probability,
+                recovery_rate,
+                discount_curve,
+            ),
+            CdsPricingModel::Isda => {
+                isda_cds_engine::detail::calculate(cds, probability, recovery_rate, discount_curve)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::termstructures::credit::flat_hazard_rate::FlatHazardRate;
+    use crate::termstructures::yieldcurves::flat_forward::FlatForward;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::time_unit::TimeUnit;
+
+    #[test]
+    fn display_pricing_models() {
+        assert_eq!(format!("{}", CdsPricingModel::MidPoint), "MidPoint");
+        assert_eq!(format!("{}", CdsPricingModel::Isda), "ISDA");
+    }
+
+    #[test]
+    fn implied_quotes_grow_with_the_hazard_rate() {
+        let trade_date: Date = Date::new(15, Month::February, 2025);
+        let tenor: Period = Period::new(3, TimeUnit::Years);
+        let discount_curve: Rc<dyn YieldTermStructure> = Rc::new(FlatForward::new(
+            trade_date,
+            0.03,
+            Box::new(Actual365Fixed::new()),
+        ));
+        let spread: SpreadCdsHelper = SpreadCdsHelper::new(
+            0.01,
+            tenor,
+            trade_date,
+            0.4,
+            discount_curve.clone(),
+            CdsPricingModel::Isda,
+        );
+        let upfront: UpfrontCdsHelper = UpfrontCdsHelper::new(
+            0.02,
+            0.01,
+            tenor,
+            trade_date,
+            0.4,
+            discount_curve.clone(),
+            CdsPricingModel::MidPoint,
+        );
+        assert_eq!(spread.pillar_date(), Date::new(20, Month::December, 2027));
+        assert_eq!(upfront.pillar_date(), spread.pillar_date());
+        assert_eq!((spread.quote(), upfront.quote()), (0.01, 0.02));
+        let curve = |hazard_rate: Rate| -> FlatHazardRate {
+            FlatHazardRate::new(trade_date, hazard_rate, Box::new(Actual365Fixed::new()))
+        };
+        assert!(spread.implied_quote(&curve(0.03)) > spread.implied_quote(&curve(0.02)));
+        assert!(upfront.implied_quote(&curve(0.03)) > upfront.implied_quote(&curve(0.02)));
+        // At a hazard rate giving a fair spread of 1%, there is no upfront on a 1% coupon
+        let parity: UpfrontCdsHelper = UpfrontCdsHelper::new(
+            0.0,
+            0.01,
+            tenor,
+            trade_date,
+            0.4,
+            discount_curve.clone(),
+            CdsPricingModel::Isda,
+        );
+        let flat: FlatHazardRate = curve(0.02);
+        let fair_spread: Rate = spread.implied_quote(&flat);
+        let at_fair: UpfrontCdsHelper = UpfrontCdsHelper::new(
+            0.0,
+            fair_spread,
+            tenor,
+            trade_date,
+            0.4,
+            discount_curve.clone(),
+            CdsPricingModel::Isda,
+        );
+        assert!(at_fair.implied_quote(&flat).abs() < 1.0e-12);
+        assert!(parity.implied_quote(&flat) > 0.0);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Probability, Rate, Real, Time};
+
+/*
+Default probability term structure (QuantLib
+DefaultProbabilityTermStructure): survival probability S(t), default
+density p(t) = -dS/dt and hazard rate h(t) = p(t) / S(t) of a single
+name, with times measured by the structure's own day counter from its
+reference date.
+
+QuantLib derives one class per quantity being interpolated (hazard
+rates, survival probabilities or default densities); here every curve
+gives its survival probability and default density, and the hazard
+rate follows. node_times lists the times at which the hazard rate may
+jump, used by engines integrating piecewise between them.
+*/
+pub trait DefaultProbabilityTermStructure {
+    fn reference_date(&self) -> Date;
+    fn day_counter(&self) -> &dyn DayCounter;
+    fn survival_probability(&self, t: Time) -> Probability;
+    fn default_density(&self, t: Time) -> Real;
+
+    fn hazard_rate(&self, t: Time) -> Rate {
+        let survival: Probability = self.survival_probability(t);
+        if survival == 0.0 {
+            0.0
+        } else {
+            self.default_density(t) / survival
+        }
+    }
+    fn default_probability(&self, t: Time) -> Probability {
+        1.0 - self.survival_probability(t)
+    }
+    // Probability of a default between t1 and t2
+    fn default_probability_between(&self, t1: Time, t2: Time) -> Probability {
+        if t1 > t2 {
+            panic!("initial time ({t1}) later than final time ({t2})");
+        }
+        self.survival_probability(t1) - self.survival_probability(t2)
+    }
+    fn time_from_reference(&self, date: Date) -> Time {
+        self.day_counter()
+            .year_fraction(self.reference_date(), date)
+    }
+    fn node_times(&self) -> Vec<Time> {
+        Vec::new()
+    }
+}
+
+pub(crate) mod detail {
+    use crate::time::date::Date;
+    use crate::time::day_counter::DayCounter;
+    use crate::types::{Real, Size, Time};
+
+    pub(crate) fn check_time(t: Time) {
+        if t < 0.0 {
+            panic!("negative time ({t}) given");
+        }
+    }
+
+    /*
+    Times of the nodes of an interpolated curve, checking that the first
+    date is the reference date, that dates increase and that there is one
+    value per date.
+    */
+    pub(crate) fn curve_times(
+        reference_date: Date,
+        dates: &[Date],
+        values: &[Real],
+        day_counter: &dyn DayCounter,
+    ) -> Vec<Time> {
+        if dates.len() < 2 {
+            panic!("at least two dates required");
+        }
+        if dates.len() != values.len() {
+            panic!("{} dates given for {} values", dates.len(), values.len());
+        }
+        if dates[0] != reference_date {
+            panic!(
+                "first date ({}) must be the reference date ({})",
+                dates[0], reference_date
+            );
+        }
+        if dates.windows(2).any(|pair| pair[1] <= pair[0]) {
+            panic!("dates must be sorted in increasing order");
+        }
+        dates
+            .iter()
+            .map(|d| day_counter.year_fraction(reference_date, *d))
+            .collect()
+    }
+
+    // Index i of the segment (times[i - 1], times[i]] containing t, the last one beyond the curve
+    pub(crate) fn segment(times: &[Time], t: Time) -> Size {
+        times[1..]
+            .iter()
+            .position(|node| t <= *node)
+            .map_or(times.len() - 1, |i| i + 1)
+    }
+}
@@ -0,0 +1,96 @@
+use crate::termstructures::credit::default_probability_term_structure::{
+    DefaultProbabilityTermStructure, detail::check_time,
+};
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Probability, Rate, Real, Time};
+
+// Constant hazard rate h, so that S(t) = exp(-h t) (QuantLib FlatHazardRate)
+pub struct FlatHazardRate {
+    reference_date: Date,
+    hazard_rate: Rate,
+    day_counter: Box<dyn DayCounter>,
+}
+impl FlatHazardRate {
+    // Constructor
+    pub fn new(
+        reference_date: Date,
+        hazard_rate: Rate,
+        day_counter: Box<dyn DayCounter>,
+    ) -> FlatHazardRate {
+        if hazard_rate < 0.0 {
+            panic!("negative hazard rate ({hazard_rate}) not allowed");
+        }
+        FlatHazardRate {
+            reference_date,
+            hazard_rate,
+            day_counter,
+        }
+    }
+}
+impl DefaultProbabilityTermStructure for FlatHazardRate {
+    fn reference_date(&self) -> Date {
+        self.reference_date
+    }
+    fn day_counter(&self) -> &dyn DayCounter {
+        self.day_counter.as_ref()
+    }
+    fn survival_probability(&self, t: Time) -> Probability {
+        check_time(t);
+        (-self.hazard_rate * t).exp()
+    }
+    fn default_density(&self, t: Time) -> Real {
+        self.hazard_rate * self.survival_probability(t)
+    }
+    fn hazard_rate(&self, t: Time) -> Rate {
+        check_time(t);
+        self.hazard_rate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+
+    #[test]
+    fn probabilities_of_a_flat_hazard_rate() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let curve: FlatHazardRate =
+            FlatHazardRate::new(reference_date, 0.02, Box::new(Actual365Fixed::new()));
+        let t: Time = curve.time_from_reference(reference_date + 730);
+        assert_eq!(t, 2.0);
+        assert_eq!(curve.survival_probability(t), (-0.04_f64).exp());
+        assert!((curve.default_probability(t) - (1.0 - (-0.04_f64).exp())).abs() < 1.0e-15);
+        assert!((curve.default_density(t) - 0.02 * (-0.04_f64).exp()).abs() < 1.0e-15);
+        assert_eq!(curve.hazard_rate(t), 0.02);
+        assert!(
+            (curve.default_probability_between(1.0, 2.0) - ((-0.02_f64).exp() - (-0.04_f64).exp()))
+                .abs()
+                < 1.0e-15
+        );
+    }
+
+    #[test]
+    fn invalid_input_panics() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let negative_hazard = std::panic::catch_unwind(|| {
+            FlatHazardRate::new(reference_date, -0.01, Box::new(Actual365Fixed::new()))
+        });
+        assert!(
+            negative_hazard.is_err(),
+            "expected panic for negative hazard"
+        );
+        let negative_time = std::panic::catch_unwind(|| {
+            FlatHazardRate::new(reference_date, 0.01, Box::new(Actual365Fixed::new()))
+                .survival_probability(-1.0)
+        });
+        assert!(negative_time.is_err(), "expected panic for negative time");
+        let swapped_times = std::panic::catch_unwind(|| {
+            FlatHazardRate::new(reference_date, 0.01, Box::new(Actual365Fixed::new()))
+                .default_probability_between(2.0, 1.0)
+        });
+        assert!(swapped_times.is_err(), "expected panic for swapped times");
+    }
+}
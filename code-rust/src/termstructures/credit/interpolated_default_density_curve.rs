@@ -0,0 +1,148 @@
+use crate::termstructures::credit::default_probability_term_structure::{
+    DefaultProbabilityTermStructure,
+    detail::{check_time, curve_times, segment},
+};
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Probability, Real, Size, Time};
+
+/*
+Default density curve interpolated linearly (QuantLib
+InterpolatedDefaultDensityCurve<Linear>), flat beyond the last date.
+The survival probability is 1 minus the integrated density, floored at
+zero; the densities must not integrate above 1 over the curve dates.
+*/
+pub struct InterpolatedDefaultDensityCurve {
+    reference_date: Date,
+    day_counter: Box<dyn DayCounter>,
+    dates: Vec<Date>,
+    times: Vec<Time>,
+    densities: Vec<Real>,
+    // Default probability up to each node
+    cumulated: Vec<Probability>,
+}
+impl InterpolatedDefaultDensityCurve {
+    // Constructor
+    pub fn new(
+        dates: &[Date],
+        densities: &[Real],
+        day_counter: Box<dyn DayCounter>,
+    ) -> InterpolatedDefaultDensityCurve {
+        let times: Vec<Time> = curve_times(dates[0], dates, densities, day_counter.as_ref());
+        if densities.iter().any(|p| *p < 0.0) {
+            panic!("negative default densities not allowed");
+        }
+        let mut cumulated: Vec<Probability> = vec![0.0; times.len()];
+        for i in 1..times.len() {
+            cumulated[i] = cumulated[i - 1]
+                + 0.5 * (densities[i - 1] + densities[i]) * (times[i] - times[i - 1]);
+        }
+        if cumulated[times.len() - 1] > 1.0 {
+            panic!(
+                "default densities integrate to {} > 1",
+                cumulated[times.len() - 1]
+            );
+        }
+        InterpolatedDefaultDensityCurve {
+            reference_date: dates[0],
+            day_counter,
+            dates: dates.to_vec(),
+            times,
+            densities: densities.to_vec(),
+            cumulated,
+        }
+    }
+
+    // Inspectors
+    pub fn dates(&self) -> &[Date] {
+        &self.dates
+    }
+    pub fn times(&self) -> &[Time] {
+        &self.times
+    }
+    pub fn default_densities(&self) -> &[Real] {
+        &self.densities
+    }
+}
+impl DefaultProbabilityTermStructure for InterpolatedDefaultDensityCurve {
+    fn reference_date(&self) -> Date {
+        self.reference_date
+    }
+    fn day_counter(&self) -> &dyn DayCounter {
+        self.day_counter.as_ref()
+    }
+    fn survival_probability(&self, t: Time) -> Probability {
+        check_time(t);
+        if t == 0.0 {
+            return 1.0;
+        }
+        let i: Size = segment(&self.times, t);
+        let dt: Time = t - self.times[i - 1];
+        let density: Real = self.default_density(t);
+        let default_probability: Probability =
+            self.cumulated[i - 1] + 0.5 * (self.densities[i - 1] + density) * dt;
+        (1.0 - default_probability).max(0.0)
+    }
+    fn default_density(&self, t: Time) -> Real {
+        check_time(t);
+        let last: Size = self.times.len() - 1;
+        if t >= self.times[last] {
+            return self.densities[last];
+        }
+        let i: Size = segment(&self.times, t);
+        let weight: Real = (t - self.times[i - 1]) / (self.times[i] - self.times[i - 1]);
+        self.densities[i - 1] + weight * (self.densities[i] - self.densities[i - 1])
+    }
+    fn node_times(&self) -> Vec<Time> {
+        self.times[1..].to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+
+    #[test]
+    fn densities_are_linear_and_integrate_to_default_probabilities() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let curve: InterpolatedDefaultDensityCurve = InterpolatedDefaultDensityCurve::new(
+            &[reference_date, reference_date + 365, reference_date + 1095],
+            &[0.01, 0.02, 0.02],
+            Box::new(Actual365Fixed::new()),
+        );
+        let cases: [(Time, Real, Probability); 4] = [
+            (0.5, 0.015, 0.00625),
+            (1.0, 0.02, 0.015),
+            (2.0, 0.02, 0.035),
+            (5.0, 0.02, 0.095),
+        ];
+        for (t, density, default_probability) in cases {
+            assert!((curve.default_density(t) - density).abs() < 1.0e-15);
+            assert!((curve.default_probability(t) - default_probability).abs() < 1.0e-15);
+            assert!((curve.hazard_rate(t) - density / (1.0 - default_probability)).abs() < 1.0e-15);
+        }
+        assert_eq!(curve.default_density(0.0), 0.01);
+    }
+
+    #[test]
+    fn new_panics_on_invalid_densities() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let dates: [Date; 2] = [reference_date, reference_date + 365];
+        let cases: [([Real; 2], &str); 2] = [
+            ([0.01, -0.01], "negative density"),
+            ([1.5, 1.5], "default probability above 1"),
+        ];
+        for (densities, label) in cases {
+            let result = std::panic::catch_unwind(|| {
+                InterpolatedDefaultDensityCurve::new(
+                    &dates,
+                    &densities,
+                    Box::new(Actual365Fixed::new()),
+                )
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
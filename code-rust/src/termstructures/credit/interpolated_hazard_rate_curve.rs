@@ -0,0 +1,172 @@
+use crate::termstructures::credit::default_probability_term_structure::{
+    DefaultProbabilityTermStructure,
+    detail::{check_time, curve_times, segment},
+};
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Probability, Rate, Real, Size, Time};
+
+/*
+Hazard rate curve interpolated backward-flat (QuantLib
+InterpolatedHazardRateCurve<BackwardFlat>): hazard_rates[i] applies on
+(t[i - 1], t[i]], the last one beyond the last date, and
+S(t) = exp(-integral of the hazard rate from 0 to t). The first date is
+the reference date; its hazard rate only matters at t = 0.
+*/
+pub struct InterpolatedHazardRateCurve {
+    reference_date: Date,
+    day_counter: Box<dyn DayCounter>,
+    dates: Vec<Date>,
+    times: Vec<Time>,
+    hazard_rates: Vec<Rate>,
+    // Integral of the hazard rate up to each node
+    cumulated: Vec<Real>,
+}
+impl InterpolatedHazardRateCurve {
+    // Constructor
+    pub fn new(
+        dates: &[Date],
+        hazard_rates: &[Rate],
+        day_counter: Box<dyn DayCounter>,
+    ) -> InterpolatedHazardRateCurve {
+        let times: Vec<Time> = curve_times(dates[0], dates, hazard_rates, day_counter.as_ref());
+        if hazard_rates.iter().any(|h| *h < 0.0) {
+            panic!("negative hazard rates not allowed");
+        }
+        let mut cumulated: Vec<Real> = vec![0.0; times.len()];
+        for i in 1..times.len() {
+            cumulated[i] = cumulated[i - 1] + hazard_rates[i] * (times[i] - times[i - 1]);
+        }
+        InterpolatedHazardRateCurve {
+            reference_date: dates[0],
+            day_counter,
+            dates: dates.to_vec(),
+            times,
+            hazard_rates: hazard_rates.to_vec(),
+            cumulated,
+        }
+    }
+
+    // Inspectors
+    pub fn dates(&self) -> &[Date] {
+        &self.dates
+    }
+    pub fn times(&self) -> &[Time] {
+        &self.times
+    }
+    pub fn hazard_rates(&self) -> &[Rate] {
+        &self.hazard_rates
+    }
+
+    // Sets the hazard rates from node i on (node 0 following node 1), for bootstrapping
+    pub(crate) fn set_hazard_rates_from(&mut self, i: Size, hazard_rate: Rate) {
+        for h in &mut self.hazard_rates[i..] {
+            *h = hazard_rate;
+        }
+        if i <= 1 {
+            self.hazard_rates[0] = hazard_rate;
+        }
+        for k in i.max(1)..self.times.len() {
+            self.cumulated[k] =
+                self.cumulated[k - 1] + self.hazard_rates[k] * (self.times[k] - self.times[k - 1]);
+        }
+    }
+}
+impl DefaultProbabilityTermStructure for InterpolatedHazardRateCurve {
+    fn reference_date(&self) -> Date {
+        self.reference_date
+    }
+    fn day_counter(&self) -> &dyn DayCounter {
+        self.day_counter.as_ref()
+    }
+    fn survival_probability(&self, t: Time) -> Probability {
+        check_time(t);
+        if t == 0.0 {
+            return 1.0;
+        }
+        let i: Size = segment(&self.times, t);
+        let integral: Real = self.cumulated[i - 1] + self.hazard_rates[i] * (t - self.times[i - 1]);
+        (-integral).exp()
+    }
+    fn default_density(&self, t: Time) -> Real {
+        self.hazard_rate(t) * self.survival_probability(t)
+    }
+    fn hazard_rate(&self, t: Time) -> Rate {
+        check_time(t);
+        if t == 0.0 {
+            return self.hazard_rates[0];
+        }
+        self.hazard_rates[segment(&self.times, t)]
+    }
+    fn node_times(&self) -> Vec<Time> {
+        self.times[1..].to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+
+    fn curve() -> InterpolatedHazardRateCurve {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        InterpolatedHazardRateCurve::new(
+            &[reference_date, reference_date + 365, reference_date + 1095],
+            &[0.01, 0.01, 0.03],
+            Box::new(Actual365Fixed::new()),
+        )
+    }
+
+    #[test]
+    fn hazard_rates_are_backward_flat() {
+        let curve: InterpolatedHazardRateCurve = curve();
+        let cases: [(Time, Rate, Real); 5] = [
+            (0.5, 0.01, 0.005),
+            (1.0, 0.01, 0.01),
+            (2.0, 0.03, 0.04),
+            (3.0, 0.03, 0.07),
+            (4.0, 0.03, 0.1),
+        ];
+        for (t, hazard_rate, integral) in cases {
+            assert_eq!(curve.hazard_rate(t), hazard_rate);
+            assert!((curve.survival_probability(t) - (-integral).exp()).abs() < 1.0e-15);
+            assert!((curve.default_density(t) - hazard_rate * (-integral).exp()).abs() < 1.0e-15);
+        }
+        assert_eq!(curve.survival_probability(0.0), 1.0);
+        assert_eq!(curve.node_times(), vec![1.0, 3.0]);
+    }
+
+    #[test]
+    fn new_panics_on_invalid_nodes() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let cases: [(Vec<Date>, Vec<Rate>, &str); 4] = [
+            (vec![reference_date], vec![0.01], "single date"),
+            (
+                vec![reference_date, reference_date + 365],
+                vec![0.01],
+                "missing hazard rate",
+            ),
+            (
+                vec![reference_date, reference_date + 365, reference_date + 200],
+                vec![0.01; 3],
+                "unsorted dates",
+            ),
+            (
+                vec![reference_date, reference_date + 365],
+                vec![0.01, -0.01],
+                "negative hazard rate",
+            ),
+        ];
+        for (dates, hazard_rates, label) in cases {
+            let result = std::panic::catch_unwind(|| {
+                InterpolatedHazardRateCurve::new(
+                    &dates,
+                    &hazard_rates,
+                    Box::new(Actual365Fixed::new()),
+                )
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use crate::termstructures::credit::default_probability_term_structure::{
+    DefaultProbabilityTermStructure,
+    detail::{check_time, curve_times, segment},
+};
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Probability, Rate, Real, Size, Time};
+
+/*
+Survival probability curve interpolated log-linearly (QuantLib
+InterpolatedSurvivalProbabilityCurve<LogLinear>), so that the hazard
+rate is flat between nodes; beyond the last date the hazard rate of the
+last segment is kept. The first date is the reference date, with
+probability 1.
+*/
+pub struct InterpolatedSurvivalProbabilityCurve {
+    reference_date: Date,
+    day_counter: Box<dyn DayCounter>,
+    dates: Vec<Date>,
+    times: Vec<Time>,
+    probabilities: Vec<Probability>,
+    // Hazard rate on (t[i - 1], t[i]], repeating the first segment at index 0
+    hazard_rates: Vec<Rate>,
+}
+impl InterpolatedSurvivalProbabilityCurve {
+    // Constructor
+    pub fn new(
+        dates: &[Date],
+        probabilities: &[Probability],
+        day_counter: Box<dyn DayCounter>,
+    ) -> InterpolatedSurvivalProbabilityCurve {
+        let times: Vec<Time> = curve_times(dates[0], dates, probabilities, day_counter.as_ref());
+        if probabilities[0] != 1.0 {
+            panic!(
+                "initial survival probability ({}) must be 1",
+                probabilities[0]
+            );
+        }
+        if probabilities.windows(2).any(|pair| pair[1] > pair[0]) {
+            panic!("survival probabilities must not increase");
+        }
+        if probabilities.iter().any(|p| *p <= 0.0) {
+            panic!("survival probabilities must be positive");
+        }
+        let mut hazard_rates: Vec<Rate> = vec![0.0; times.len()];
+        for i in 1..times.len() {
+            hazard_rates[i] =
+                (probabilities[i - 1] / probabilities[i]).ln() / (times[i] - times[i - 1]);
+        }
+        hazard_rates[0] = hazard_rates[1];
+        InterpolatedSurvivalProbabilityCurve {
+            reference_date: dates[0],
+            day_counter,
+            dates: dates.to_vec(),
+            times,
+            probabilities: probabilities.to_vec(),
+            hazard_rates,
+        }
+    }
+
+    // Inspectors
+    pub fn dates(&self) -> &[Date] {
+        &self.dates
+    }
+    pub fn times(&self) -> &[Time] {
+        &self.times
+    }
+    pub fn survival_probabilities(&self) -> &[Probability] {
+        &self.probabilities
+    }
+}
+impl DefaultProbabilityTermStructure for InterpolatedSurvivalProbabilityCurve {
+    fn reference_date(&self) -> Date {
+        self.reference_date
+    }
+    fn day_counter(&self) -> &dyn DayCounter {
+        self.day_counter.as_ref()
+    }
+    fn survival_probability(&self, t: Time) -> Probability {
+        check_time(t);
+        if t == 0.0 {
+            return 1.0;
+        }
+        let i: Size = segment(&self.times, t);
+        self.probabilities[i - 1] * (-self.hazard_rates[i] * (t - self.times[i - 1])).exp()
+    }
+    fn default_density(&self, t: Time) -> Real {
+        self.hazard_rate(t) * self.survival_probability(t)
+    }
+    fn hazard_rate(&self, t: Time) -> Rate {
+        check_time(t);
+        if t == 0.0 {
+            return self.hazard_rates[0];
+        }
+        self.hazard_rates[segment(&self.times, t)]
+    }
+    fn node_times(&self) -> Vec<Time> {
+        self.times[1..].to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+
+    #[test]
+    fn survival_probabilities_are_log_linear() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let (s1, s3): (Probability, Probability) = ((-0.01_f64).exp(), (-0.07_f64).exp());
+        let curve: InterpolatedSurvivalProbabilityCurve = InterpolatedSurvivalProbabilityCurve::new(
+            &[reference_date, reference_date + 365, reference_date + 1095],
+            &[1.0, s1, s3],
+            Box::new(Actual365Fixed::new()),
+        );
+        let cases: [(Time, Rate, Real); 4] = [
+            (0.5, 0.01, 0.005),
+            (1.0, 0.01, 0.01),
+            (2.0, 0.03, 0.04),
+            (4.0, 0.03, 0.1),
+        ];
+        for (t, hazard_rate, integral) in cases {
+            assert!((curve.hazard_rate(t) - hazard_rate).abs() < 1.0e-14);
+            assert!((curve.survival_probability(t) - (-integral).exp()).abs() < 1.0e-15);
+        }
+        assert_eq!(curve.survival_probability(3.0), s3);
+        assert_eq!(curve.node_times(), vec![1.0, 3.0]);
+    }
+
+    #[test]
+    fn new_panics_on_invalid_probabilities() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let dates: [Date; 3] = [reference_date, reference_date + 365, reference_date + 730];
+        let cases: [([Probability; 3], &str); 3] = [
+            ([0.99, 0.98, 0.97], "initial probability below 1"),
+            ([1.0, 0.97, 0.98], "increasing probabilities"),
+            ([1.0, 0.5, 0.0], "null probability"),
+        ];
+        for (probabilities, label) in cases {
+            let result = std::panic::catch_unwind(|| {
+                InterpolatedSurvivalProbabilityCurve::new(
+                    &dates,
+                    &probabilities,
+                    Box::new(Actual365Fixed::new()),
+                )
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use crate::math::solvers1d::brent::Brent;
+use crate::termstructures::credit::default_probability_helpers::DefaultProbabilityHelper;
+use crate::termstructures::credit::default_probability_term_structure::DefaultProbabilityTermStructure;
+use crate::termstructures::credit::interpolated_hazard_rate_curve::InterpolatedHazardRateCurve;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Probability, Rate, Real, Time};
+use std::cell::RefCell;
+
+/*
+Default curve bootstrapped on market quotes (QuantLib
+PiecewiseDefaultCurve<HazardRate, BackwardFlat>): one node per helper
+pillar, with a flat hazard rate up to each pillar solved, in order of
+pillars, so that the helper reproduces its quote. Hazard rates are
+searched in [0, max_hazard_rate] with the Brent solver.
+*/
+pub struct PiecewiseDefaultCurve {
+    curve: InterpolatedHazardRateCurve,
+}
+impl PiecewiseDefaultCurve {
+    const MAX_HAZARD_RATE: Rate = 10.0;
+
+    // Constructor
+    pub fn new(
+        reference_date: Date,
+        helpers: &[&dyn DefaultProbabilityHelper],
+        day_counter: Box<dyn DayCounter>,
+        accuracy: Real,
+    ) -> PiecewiseDefaultCurve {
+        if helpers.is_empty() {
+            panic!("no helpers given");
+        }
+        let mut helpers: Vec<&dyn DefaultProbabilityHelper> = helpers.to_vec();
+        helpers.sort_by_key(|helper| helper.pillar_date());
+        let dates: Vec<Date> = [reference_date]
+            .into_iter()
+            .chain(helpers.iter().map(|helper| helper.pillar_date()))
+            .collect();
+        let curve: RefCell<InterpolatedHazardRateCurve> = RefCell::new(
+            InterpolatedHazardRateCurve::new(&dates, &vec![0.0; dates.len()], day_counter),
+        );
+
+        for (i, helper) in helpers.iter().enumerate() {
+            let error = |hazard_rate: Rate| -> Real {
+                curve.borrow_mut().set_hazard_rates_from(i + 1, hazard_rate);
+                helper.implied_quote(&*curve.borrow()) - helper.quote()
+            };
+            let hazard_rate: Rate =
+                Brent::new().solve(error, accuracy, 0.0, PiecewiseDefaultCurve::MAX_HAZARD_RATE);
+            curve.borrow_mut().set_hazard_rates_from(i + 1, hazard_rate);
+        }
+        PiecewiseDefaultCurve {
+            curve: curve.into_inner(),
+        }
+    }
+
+    // Inspectors
+    pub fn dates(&self) -> &[Date] {
+        self.curve.dates()
+    }
+    pub fn hazard_rates(&self) -> &[Rate] {
+        self.curve.hazard_rates()
+    }
+    pub fn curve(&self) -> &InterpolatedHazardRateCurve {
+        &self.curve
+    }
+}
+impl DefaultProbabilityTermStructure for PiecewiseDefaultCurve {
+    fn reference_date(&self) -> Date {
+        self.curve.reference_date()
+    }
+    fn day_counter(&self) -> &dyn DayCounter {
+        self.curve.day_counter()
+    }
+    fn survival_probability(&self, t: Time) -> Probability {
+        self.curve.survival_probability(t)
+    }
+    fn default_density(&self, t: Time) -> Real {
+        self.curve.default_density(t)
+    }
+    fn hazard_rate(&self, t: Time) -> Rate {
+        self.curve.hazard_rate(t)
+    }
+    fn node_times(&self) -> Vec<Time> {
+        self.curve.node_times()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::termstructures::credit::default_probability_helpers::{
+        CdsPricingModel, SpreadCdsHelper, UpfrontCdsHelper,
+    };
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::period::Period;
+    use crate::time::time_unit::TimeUnit;
+
+    fn trade_date() -> Date {
+        Date::new(15, Month::February, 2025)
+    }
+
+    #[test]
+    fn bootstrapped_curve_reprices_the_quotes() {
+        for model in [CdsPricingModel::MidPoint, CdsPricingModel::Isda] {
+            let spread_helpers: Vec<SpreadCdsHelper> = [(1, 0.005), (3, 0.008), (5, 0.012)]
+                .iter()
+                .map(|(years, spread)| {
+                    SpreadCdsHelper::new(
+                        *spread,
+                        Period::new(*years, TimeUnit::Years),
+                        trade_date(),
+                        0.4,
+                        flat_rate(trade_date(), 0.03),
+                        model,
+                    )
+                })
+                .collect();
+            let upfront_helper: UpfrontCdsHelper = UpfrontCdsHelper::new(
+                0.03,
+                0.01,
+                Period::new(7, TimeUnit::Years),
+                trade_date(),
+                0.4,
+                flat_rate(trade_date(), 0.03),
+                model,
+            );
+            // Helpers need not be sorted
+            let helpers: Vec<&dyn DefaultProbabilityHelper> = vec![
+                &upfront_helper,
+                &spread_helpers[2],
+                &spread_helpers[0],
+                &spread_helpers[1],
+            ];
+            let curve: PiecewiseDefaultCurve = PiecewiseDefaultCurve::new(
+                trade_date(),
+                &helpers,
+                Box::new(Actual365Fixed::new()),
+                1.0e-12,
+            );
+            assert_eq!(curve.dates().len(), 5);
+            assert_eq!(curve.dates()[4], upfront_helper.pillar_date());
+            for helper in helpers {
+                assert!(
+                    (helper.implied_quote(&curve) - helper.quote()).abs() < 1.0e-10,
+                    "{}: {} vs {}",
+                    model,
+                    helper.implied_quote(&curve),
+                    helper.quote()
+                );
+            }
+            // Steep spreads give increasing hazard rates
+            let hazard_rates: &[Rate] = curve.hazard_rates();
+            assert!(
+                hazard_rates
+                    .windows(2)
+                    .skip(1)
+                    .all(|pair| pair[1] > pair[0])
+            );
+            assert_eq!(hazard_rates[0], hazard_rates[1]);
+        }
+    }
+
+    #[test]
+    fn bootstrap_panics_on_invalid_helpers() {
+        let result = std::panic::catch_unwind(|| {
+            PiecewiseDefaultCurve::new(trade_date(), &[], Box::new(Actual365Fixed::new()), 1.0e-12)
+        });
+        assert!(result.is_err(), "expected panic for no helpers");
+        let result = std::panic::catch_unwind(|| {
+            let helper: SpreadCdsHelper = SpreadCdsHelper::new(
+                0.01,
+                Period::new(5, TimeUnit::Years),
+                trade_date(),
+                0.4,
+                flat_rate(trade_date(), 0.03),
+                CdsPricingModel::Isda,
+            );
+            PiecewiseDefaultCurve::new(
+                trade_date(),
+                &[&helper, &helper],
+                Box::new(Actual365Fixed::new()),
+                1.0e-12,
+            )
+        });
+        assert!(result.is_err(), "expected panic for duplicated pillars");
+    }
+}
@@ -0,0 +1,79 @@
+use crate::indexes::inflation_index::{
+    CpiInterpolationType, ZeroInflationIndex, cpi_last_fixing_date,
+};
+use crate::instruments::swap::SwapType;
+use crate::instruments::zero_coupon_inflation_swap::ZeroCouponInflationSwap;
+use crate::termstructures::inflation::zero_inflation_term_structure::ZeroInflationTermStructure;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::time::period::Period;
+use crate::types::{Rate, Real};
+
+/*
+Market quote a zero inflation curve is bootstrapped on (QuantLib
+BootstrapHelper<ZeroInflationTermStructure>): the curve must reproduce
+quote, which only depends on the curve up to the pillar date.
+*/
+pub trait ZeroInflationHelper {
+    fn quote(&self) -> Real;
+    fn pillar_date(&self) -> Date;
+    fn implied_quote(&self, inflation: &dyn ZeroInflationTermStructure) -> Real;
+}
+
+/*
+Fixed rate quote of a zero-coupon inflation swap from start_date to
+maturity (QuantLib ZeroCouponInflationSwapHelper). The pillar is the
+last CPI fixing date the swap observes.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct ZeroCouponInflationSwapHelper {
+    swap: ZeroCouponInflationSwap,
+    quote: Rate,
+    pillar_date: Date,
+}
+impl ZeroCouponInflationSwapHelper {
+    // Constructor
+    pub fn new(
+        quote: Rate,
+        observation_lag: Period,
+        start_date: Date,
+        maturity: Date,
+        day_counter: &dyn DayCounter,
+        index: ZeroInflationIndex,
+        interpolation: CpiInterpolationType,
+    ) -> ZeroCouponInflationSwapHelper {
+        let pillar_date: Date =
+            cpi_last_fixing_date(maturity, observation_lag, interpolation, index.frequency());
+        ZeroCouponInflationSwapHelper {
+            swap: ZeroCouponInflationSwap::new(
+                SwapType::Payer,
+                1.0,
+                start_date,
+                maturity,
+                day_counter,
+                quote,
+                index,
+                observation_lag,
+                interpolation,
+            ),
+            quote,
+            pillar_date,
+        }
+    }
+
+    // Inspectors
+    pub fn swap(&self) -> &ZeroCouponInflationSwap {
+        &self.swap
+    }
+}
+impl ZeroInflationHelper for ZeroCouponInflationSwapHelper {
+    fn quote(&self) -> Real {
+        self.quote
+    }
+    fn pillar_date(&self) -> Date {
+        self.pillar_date
+    }
+    fn implied_quote(&self, inflation: &dyn ZeroInflationTermStructure) -> Real {
+        self.swap.fair_rate(Some(inflation))
+    }
+}
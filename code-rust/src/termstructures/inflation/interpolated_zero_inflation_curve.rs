@@ -0,0 +1,197 @@
+use crate::termstructures::inflation::seasonality::MultiplicativePriceSeasonality;
+use crate::termstructures::inflation::zero_inflation_term_structure::ZeroInflationTermStructure;
+use crate::termstructures::volatility::capfloor::cap_floor_term_vol_surface::detail::interpolate;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::time::frequency::Frequency;
+use crate::types::{Rate, Size, Time};
+
+/*
+Zero inflation curve interpolated linearly in the zero rate (QuantLib
+InterpolatedZeroInflationCurve<Linear>), flat beyond the last date. The
+first date is the base date, the other ones are fixing dates of the
+index; the rate at the base date only matters at t = 0.
+*/
+pub struct InterpolatedZeroInflationCurve {
+    reference_date: Date,
+    day_counter: Box<dyn DayCounter>,
+    frequency: Frequency,
+    dates: Vec<Date>,
+    times: Vec<Time>,
+    rates: Vec<Rate>,
+    seasonality: Option<MultiplicativePriceSeasonality>,
+}
+impl InterpolatedZeroInflationCurve {
+    // Constructor
+    pub fn new(
+        reference_date: Date,
+        dates: &[Date],
+        rates: &[Rate],
+        frequency: Frequency,
+        day_counter: Box<dyn DayCounter>,
+    ) -> InterpolatedZeroInflationCurve {
+        if dates.len() < 2 {
+            panic!("at least two dates required");
+        }
+        if dates.len() != rates.len() {
+            panic!("{} dates given for {} rates", dates.len(), rates.len());
+        }
+        if dates.windows(2).any(|pair| pair[1] <= pair[0]) {
+            panic!("dates must be sorted in increasing order");
+        }
+        if dates[0] > reference_date {
+            panic!(
+                "base date ({}) after the reference date ({})",
+                dates[0], reference_date
+            );
+        }
+        let times: Vec<Time> = dates
+            .iter()
+            .map(|d| day_counter.year_fraction(dates[0], *d))
+            .collect();
+        InterpolatedZeroInflationCurve {
+            reference_date,
+            day_counter,
+            frequency,
+            dates: dates.to_vec(),
+            times,
+            rates: rates.to_vec(),
+            seasonality: None,
+        }
+    }
+
+    // Modifiers
+    pub fn with_seasonality(
+        mut self,
+        seasonality: MultiplicativePriceSeasonality,
+    ) -> InterpolatedZeroInflationCurve {
+        self.seasonality = Some(seasonality);
+        self
+    }
+    // Sets the rates from node i on (node 0 following node 1), for bootstrapping
+    pub(crate) fn set_rates_from(&mut self, i: Size, rate: Rate) {
+        for r in &mut self.rates[i..] {
+            *r = rate;
+        }
+        if i <= 1 {
+            self.rates[0] = rate;
+        }
+    }
+
+    // Inspectors
+    pub fn dates(&self) -> &[Date] {
+        &self.dates
+    }
+    pub fn times(&self) -> &[Time] {
+        &self.times
+    }
+    pub fn rates(&self) -> &[Rate] {
+        &self.rates
+    }
+}
+impl ZeroInflationTermStructure for InterpolatedZeroInflationCurve {
+    fn reference_date(&self) -> Date {
+        self.reference_date
+    }
+    fn base_date(&self) -> Date {
+        self.dates[0]
+    }
+    fn day_counter(&self) -> &dyn DayCounter {
+        self.day_counter.as_ref()
+    }
+    fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+    fn unseasoned_zero_rate(&self, t: Time) -> Rate {
+        interpolate(&self.times, &self.rates, t)
+    }
+    fn seasonality(&self) -> Option<&MultiplicativePriceSeasonality> {
+        self.seasonality.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+
+    fn curve() -> InterpolatedZeroInflationCurve {
+        InterpolatedZeroInflationCurve::new(
+            Date::new(15, Month::March, 2025),
+            &[
+                Date::new(1, Month::January, 2025),
+                Date::new(1, Month::January, 2026),
+                Date::new(1, Month::January, 2030),
+            ],
+            &[0.02, 0.02, 0.03],
+            Frequency::Monthly,
+            Box::new(Actual365Fixed::new()),
+        )
+    }
+
+    #[test]
+    fn zero_rates_are_linear_on_period_starts() {
+        let curve: InterpolatedZeroInflationCurve = curve();
+        assert_eq!(curve.base_date(), Date::new(1, Month::January, 2025));
+        assert_eq!(curve.zero_rate(Date::new(20, Month::January, 2026)), 0.02);
+        let t: Time = curve.times()[2];
+        let mid: Date = Date::new(1, Month::January, 2028);
+        let expected: Rate = 0.02 + 0.01 * (curve.time_from_base(mid) - 1.0) / (t - 1.0);
+        assert!((curve.zero_rate(mid + 10) - expected).abs() < 1.0e-15);
+        assert_eq!(curve.zero_rate(Date::new(1, Month::January, 2040)), 0.03);
+    }
+
+    #[test]
+    fn seasonality_corrects_the_index_growth() {
+        let factors: Vec<f64> = (0..12).map(|m| 1.0 + 0.001 * m as f64).collect();
+        let seasonality: MultiplicativePriceSeasonality = MultiplicativePriceSeasonality::new(
+            Date::new(1, Month::January, 2020),
+            Frequency::Monthly,
+            &factors,
+        );
+        let plain: InterpolatedZeroInflationCurve = curve();
+        let seasonal: InterpolatedZeroInflationCurve = curve().with_seasonality(seasonality);
+        for (date, factor) in [
+            (Date::new(1, Month::January, 2027), 1.0),
+            (Date::new(1, Month::June, 2027), 1.005),
+        ] {
+            let t: Time = plain.time_from_base(date);
+            let growth: f64 = (1.0 + seasonal.zero_rate(date)).powf(t);
+            let expected: f64 = (1.0 + plain.zero_rate(date)).powf(t) * factor;
+            assert!((growth - expected).abs() < 1.0e-14, "{}", date);
+        }
+    }
+
+    #[test]
+    fn new_panics_on_invalid_nodes() {
+        let base_date: Date = Date::new(1, Month::January, 2025);
+        let cases: [(Date, Vec<Date>, Vec<Rate>, &str); 3] = [
+            (base_date, vec![base_date], vec![0.02], "single date"),
+            (
+                base_date,
+                vec![base_date, base_date + 365],
+                vec![0.02],
+                "missing rate",
+            ),
+            (
+                base_date - 1,
+                vec![base_date, base_date + 365],
+                vec![0.02, 0.02],
+                "base date after the reference date",
+            ),
+        ];
+        for (reference_date, dates, rates, label) in cases {
+            let result = std::panic::catch_unwind(|| {
+                InterpolatedZeroInflationCurve::new(
+                    reference_date,
+                    &dates,
+                    &rates,
+                    Frequency::Monthly,
+                    Box::new(Actual365Fixed::new()),
+                )
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use crate::math::solvers1d::brent::Brent;
+use crate::termstructures::inflation::inflation_helpers::ZeroInflationHelper;
+use crate::termstructures::inflation::interpolated_zero_inflation_curve::InterpolatedZeroInflationCurve;
+use crate::termstructures::inflation::seasonality::MultiplicativePriceSeasonality;
+use crate::termstructures::inflation::zero_inflation_term_structure::ZeroInflationTermStructure;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::time::frequency::Frequency;
+use crate::types::{Rate, Real, Time};
+use std::cell::RefCell;
+
+/*
+Zero inflation curve bootstrapped on zero-coupon inflation swap quotes
+(QuantLib PiecewiseZeroInflationCurve<Linear>): one node per helper
+pillar after the base date, each zero rate solved in order of pillars
+so that the helper reproduces its quote. Rates are searched in
+[MIN_RATE, MAX_RATE] with the Brent solver.
+*/
+pub struct PiecewiseZeroInflationCurve {
+    curve: InterpolatedZeroInflationCurve,
+}
+impl PiecewiseZeroInflationCurve {
+    const MIN_RATE: Rate = -0.5;
+    const MAX_RATE: Rate = 1.0;
+
+    // Constructor
+    pub fn new(
+        reference_date: Date,
+        base_date: Date,
+        frequency: Frequency,
+        day_counter: Box<dyn DayCounter>,
+        helpers: &[&dyn ZeroInflationHelper],
+        accuracy: Real,
+    ) -> PiecewiseZeroInflationCurve {
+        if helpers.is_empty() {
+            panic!("no helpers given");
+        }
+        let mut helpers: Vec<&dyn ZeroInflationHelper> = helpers.to_vec();
+        helpers.sort_by_key(|helper| helper.pillar_date());
+        let dates: Vec<Date> = [base_date]
+            .into_iter()
+            .chain(helpers.iter().map(|helper| helper.pillar_date()))
+            .collect();
+        let curve: RefCell<InterpolatedZeroInflationCurve> =
+            RefCell::new(InterpolatedZeroInflationCurve::new(
+                reference_date,
+                &dates,
+                &vec![0.0; dates.len()],
+                frequency,
+                day_counter,
+            ));
+
+        for (i, helper) in helpers.iter().enumerate() {
+            let error = |rate: Rate| -> Real {
+                curve.borrow_mut().set_rates_from(i + 1, rate);
+                helper.implied_quote(&*curve.borrow()) - helper.quote()
+            };
+            let rate: Rate = Brent::new().solve(
+                error,
+                accuracy,
+                PiecewiseZeroInflationCurve::MIN_RATE,
+                PiecewiseZeroInflationCurve::MAX_RATE,
+            );
+            curve.borrow_mut().set_rates_from(i + 1, rate);
+        }
+        PiecewiseZeroInflationCurve {
+            curve: curve.into_inner(),
+        }
+    }
+
+    // Modifiers
+    pub fn with_seasonality(
+        self,
+        seasonality: MultiplicativePriceSeasonality,
+    ) -> PiecewiseZeroInflationCurve {
+        PiecewiseZeroInflationCurve {
+            curve: self.curve.with_seasonality(seasonality),
+        }
+    }
+
+    // Inspectors
+    pub fn dates(&self) -> &[Date] {
+        self.curve.dates()
+    }
+    pub fn rates(&self) -> &[Rate] {
+        self.curve.rates()
+    }
+    pub fn curve(&self) -> &InterpolatedZeroInflationCurve {
+        &self.curve
+    }
+}
+impl ZeroInflationTermStructure for PiecewiseZeroInflationCurve {
+    fn reference_date(&self) -> Date {
+        self.curve.reference_date()
+    }
+    fn base_date(&self) -> Date {
+        self.curve.base_date()
+    }
+    fn day_counter(&self) -> &dyn DayCounter {
+        self.curve.day_counter()
+    }
+    fn frequency(&self) -> Frequency {
+        self.curve.frequency()
+    }
+    fn unseasoned_zero_rate(&self, t: Time) -> Rate {
+        self.curve.unseasoned_zero_rate(t)
+    }
+    fn seasonality(&self) -> Option<&MultiplicativePriceSeasonality> {
+        self.curve.seasonality()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexes::inflation_index::{CpiInterpolationType, ZeroInflationIndex};
+    use crate::termstructures::inflation::inflation_helpers::ZeroCouponInflationSwapHelper;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::period::Period;
+    use crate::time::time_unit::TimeUnit;
+
+    #[test]
+    fn bootstrapped_curve_reprices_the_swaps() {
+        let reference_date: Date = Date::new(15, Month::April, 2025);
+        let lag: Period = Period::new(3, TimeUnit::Months);
+        let mut index: ZeroInflationIndex =
+            ZeroInflationIndex::new("CPI", Frequency::Monthly, Period::new(1, TimeUnit::Months));
+        index.add_fixing(Date::new(1, Month::January, 2025), 100.0);
+        index.add_fixing(Date::new(1, Month::February, 2025), 100.3);
+        for interpolation in [CpiInterpolationType::Flat, CpiInterpolationType::Linear] {
+            let swap_helpers: Vec<ZeroCouponInflationSwapHelper> =
+                [(1, 0.025), (3, 0.022), (5, 0.021), (10, 0.0225)]
+                    .iter()
+                    .map(|(years, quote)| {
+                        ZeroCouponInflationSwapHelper::new(
+                            *quote,
+                            lag,
+                            reference_date,
+                            Date::advance(reference_date, *years, TimeUnit::Years),
+                            &Actual365Fixed::new(),
+                            index.clone(),
+                            interpolation,
+                        )
+                    })
+                    .collect();
+            let helpers: Vec<&dyn ZeroInflationHelper> = swap_helpers
+                .iter()
+                .rev()
+                .map(|helper| helper as &dyn ZeroInflationHelper)
+                .collect();
+            let curve: PiecewiseZeroInflationCurve = PiecewiseZeroInflationCurve::new(
+                reference_date,
+                Date::new(1, Month::January, 2025),
+                Frequency::Monthly,
+                Box::new(Actual365Fixed::new()),
+                &helpers,
+                1.0e-12,
+            );
+            assert_eq!(curve.dates().len(), 5);
+            for helper in helpers {
+                assert!(
+                    (helper.implied_quote(&curve) - helper.quote()).abs() < 1.0e-10,
+                    "{}: {} vs {}",
+                    interpolation,
+                    helper.implied_quote(&curve),
+                    helper.quote()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn bootstrap_panics_without_helpers() {
+        let result = std::panic::catch_unwind(|| {
+            PiecewiseZeroInflationCurve::new(
+                Date::new(15, Month::April, 2025),
+                Date::new(1, Month::January, 2025),
+                Frequency::Monthly,
+                Box::new(Actual365Fixed::new()),
+                &[],
+                1.0e-12,
+            )
+        });
+        assert!(result.is_err(), "expected panic for no helpers");
+    }
+}
@@ -0,0 +1,110 @@
+use crate::time::date::Date;
+use crate::time::frequency::Frequency;
+use crate::types::{Real, Size};
+
+/*
+Multiplicative seasonality of an inflation index (QuantLib
+MultiplicativePriceSeasonality): the index at date d is scaled by the
+factor of the period containing d, counted in periods of the given
+frequency from the seasonality base date, modulo the number of
+factors. The number of factors must cover whole years.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct MultiplicativePriceSeasonality {
+    base_date: Date,
+    frequency: Frequency,
+    factors: Vec<Real>,
+}
+impl MultiplicativePriceSeasonality {
+    // Constructor
+    pub fn new(
+        base_date: Date,
+        frequency: Frequency,
+        factors: &[Real],
+    ) -> MultiplicativePriceSeasonality {
+        let periods_per_year: Size = match frequency {
+            Frequency::Monthly => 12,
+            Frequency::Quarterly => 4,
+            Frequency::Semiannual => 2,
+            Frequency::Annual => 1,
+            _ => panic!("{frequency} frequency not allowed for seasonality"),
+        };
+        if factors.is_empty() || factors.len() % periods_per_year != 0 {
+            panic!(
+                "{} factors given, a multiple of {} required for a {} seasonality",
+                factors.len(),
+                periods_per_year,
+                frequency
+            );
+        }
+        if factors.iter().any(|f| *f <= 0.0) {
+            panic!("seasonality factors must be positive");
+        }
+        MultiplicativePriceSeasonality {
+            base_date,
+            frequency,
+            factors: factors.to_vec(),
+        }
+    }
+
+    // Inspectors
+    pub fn base_date(&self) -> Date {
+        self.base_date
+    }
+    pub fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+    pub fn factors(&self) -> &[Real] {
+        &self.factors
+    }
+
+    // Factor of the period containing date
+    pub fn factor(&self, date: Date) -> Real {
+        let months_per_period: i32 = 12 / self.frequency as i32;
+        let months: i32 = 12 * (date.year() - self.base_date.year()) + date.month() as i32
+            - self.base_date.month() as i32;
+        let periods: i32 = months.div_euclid(months_per_period);
+        self.factors[periods.rem_euclid(self.factors.len() as i32) as Size]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+
+    #[test]
+    fn factors_repeat_every_year() {
+        let seasonality: MultiplicativePriceSeasonality = MultiplicativePriceSeasonality::new(
+            Date::new(1, Month::January, 2020),
+            Frequency::Quarterly,
+            &[0.99, 1.0, 1.02, 0.99],
+        );
+        let cases: [(Date, Real); 5] = [
+            (Date::new(15, Month::February, 2020), 0.99),
+            (Date::new(1, Month::April, 2025), 1.0),
+            (Date::new(30, Month::September, 2031), 1.02),
+            (Date::new(1, Month::December, 2019), 0.99),
+            (Date::new(1, Month::August, 2019), 1.02),
+        ];
+        for (date, factor) in cases {
+            assert_eq!(seasonality.factor(date), factor, "{date}");
+        }
+    }
+
+    #[test]
+    fn new_panics_on_invalid_factors() {
+        let base_date: Date = Date::new(1, Month::January, 2020);
+        let cases: [(Frequency, Vec<Real>, &str); 3] = [
+            (Frequency::Monthly, vec![1.0; 6], "half a year of factors"),
+            (Frequency::Weekly, vec![1.0; 52], "weekly frequency"),
+            (Frequency::Annual, vec![0.0], "null factor"),
+        ];
+        for (frequency, factors, label) in cases {
+            let result = std::panic::catch_unwind(|| {
+                MultiplicativePriceSeasonality::new(base_date, frequency, &factors)
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use crate::termstructures::inflation::seasonality::MultiplicativePriceSeasonality;
+use crate::time::date::{Date, Month};
+use crate::time::day_counter::DayCounter;
+use crate::time::frequency::Frequency;
+use crate::types::{Rate, Real, Size, Time};
+
+/*
+Zero-coupon inflation term structure (QuantLib
+ZeroInflationTermStructure): the annual zero rate z(d) at which the
+index grows from its fixing at the base date, so that
+    I(d) = I(base) (1 + z(d))^t,   t = year fraction from base to d,
+with d taken at the start of its inflation period. Discounting times
+are measured from the reference date, growth times from the base date,
+both with the structure's own day counter.
+
+Curves give the zero rate without seasonality; when a seasonality is
+set, zero_rate corrects the index growth by the ratio of the seasonal
+factors at d and at the base date.
+*/
+pub trait ZeroInflationTermStructure {
+    fn reference_date(&self) -> Date;
+    fn base_date(&self) -> Date;
+    fn day_counter(&self) -> &dyn DayCounter;
+    fn frequency(&self) -> Frequency;
+    // Zero rate without seasonality, t years after the base date
+    fn unseasoned_zero_rate(&self, t: Time) -> Rate;
+
+    fn seasonality(&self) -> Option<&MultiplicativePriceSeasonality> {
+        None
+    }
+    fn zero_rate(&self, date: Date) -> Rate {
+        let (start, _): (Date, Date) = inflation_period(date, self.frequency());
+        let t: Time = self.time_from_base(start);
+        let rate: Rate = self.unseasoned_zero_rate(t);
+        match self.seasonality() {
+            Some(seasonality) if t > 0.0 => {
+                let correction: Real =
+                    seasonality.factor(start) / seasonality.factor(self.base_date());
+                ((1.0 + rate).powf(t) * correction).powf(1.0 / t) - 1.0
+            }
+            _ => rate,
+        }
+    }
+    fn time_from_reference(&self, date: Date) -> Time {
+        self.day_counter()
+            .year_fraction(self.reference_date(), date)
+    }
+    fn time_from_base(&self, date: Date) -> Time {
+        self.day_counter().year_fraction(self.base_date(), date)
+    }
+}
+
+/*
+First and last day of the inflation period containing date (QuantLib
+inflationPeriod), for monthly, quarterly, semiannual or annual indexes.
+*/
+pub fn inflation_period(date: Date, frequency: Frequency) -> (Date, Date) {
+    let months: Size = match frequency {
+        Frequency::Monthly => 1,
+        Frequency::Quarterly => 3,
+        Frequency::Semiannual => 6,
+        Frequency::Annual => 12,
+        _ => panic!("{frequency} frequency not allowed for inflation periods"),
+    };
+    let month: Size = date.month() as Size;
+    let first_month: Size = months * ((month - 1) / months) + 1;
+    let start: Date = Date::new(1, Month::from_index(first_month), date.year());
+    let end: Date = Date::new(1, Month::from_index(first_month + months - 1), date.year());
+    (start, end.end_of_month())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inflation_periods_contain_the_date() {
+        let date: Date = Date::new(17, Month::August, 2025);
+        let cases: [(Frequency, Date, Date); 4] = [
+            (
+                Frequency::Monthly,
+                Date::new(1, Month::August, 2025),
+                Date::new(31, Month::August, 2025),
+            ),
+            (
+                Frequency::Quarterly,
+                Date::new(1, Month::July, 2025),
+                Date::new(30, Month::September, 2025),
+            ),
+            (
+                Frequency::Semiannual,
+                Date::new(1, Month::July, 2025),
+                Date::new(31, Month::December, 2025),
+            ),
+            (
+                Frequency::Annual,
+                Date::new(1, Month::January, 2025),
+                Date::new(31, Month::December, 2025),
+            ),
+        ];
+        for (frequency, start, end) in cases {
+            assert_eq!(
+                inflation_period(date, frequency),
+                (start, end),
+                "{frequency}"
+            );
+        }
+        let result = std::panic::catch_unwind(|| inflation_period(date, Frequency::Weekly));
+        assert!(result.is_err(), "expected panic for a weekly frequency");
+    }
+}
@@ -0,0 +1,181 @@
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::time::period::Period;
+use crate::types::{Rate, Time, Volatility};
+
+/*
+Cap/floor flat (term) volatilities quoted by cap maturity and strike
+(QuantLib CapFloorTermVolSurface): the single volatility that prices
+all the optionlets of a cap expiring at reference date + tenor.
+
+volatilities[i][j] is the quote for option_tenors[i] and strikes[j].
+Volatilities are interpolated linearly in strike, then linearly in
+time; they are flat outside the quoted tenors and strikes.
+*/
+pub struct CapFloorTermVolSurface {
+    reference_date: Date,
+    day_counter: Box<dyn DayCounter>,
+    option_tenors: Vec<Period>,
+    option_dates: Vec<Date>,
+    option_times: Vec<Time>,
+    strikes: Vec<Rate>,
+    volatilities: Vec<Vec<Volatility>>,
+}
+impl CapFloorTermVolSurface {
+    // Constructor
+    pub fn new(
+        reference_date: Date,
+        day_counter: Box<dyn DayCounter>,
+        option_tenors: &[Period],
+        strikes: &[Rate],
+        volatilities: &[Vec<Volatility>],
+    ) -> CapFloorTermVolSurface {
+        if option_tenors.is_empty() || strikes.is_empty() {
+            panic!("at least one option tenor and one strike required");
+        }
+        if volatilities.len() != option_tenors.len()
+            || volatilities.iter().any(|row| row.len() != strikes.len())
+        {
+            panic!(
+                "volatility matrix must be {} x {}",
+                option_tenors.len(),
+                strikes.len()
+            );
+        }
+        if volatilities.iter().flatten().any(|vol| *vol < 0.0) {
+            panic!("negative volatilities not allowed");
+        }
+        if strikes.windows(2).any(|pair| pair[1] <= pair[0]) {
+            panic!("strikes must be sorted in increasing order");
+        }
+        let option_dates: Vec<Date> = option_tenors
+            .iter()
+            .map(|tenor| reference_date + *tenor)
+            .collect();
+        let option_times: Vec<Time> = option_dates
+            .iter()
+            .map(|date| day_counter.year_fraction(reference_date, *date))
+            .collect();
+        if option_times[0] <= 0.0 || option_times.windows(2).any(|pair| pair[1] <= pair[0]) {
+            panic!("option tenors must be positive and sorted in increasing order");
+        }
+        CapFloorTermVolSurface {
+            reference_date,
+            day_counter,
+            option_tenors: option_tenors.to_vec(),
+            option_dates,
+            option_times,
+            strikes: strikes.to_vec(),
+            volatilities: volatilities.to_vec(),
+        }
+    }
+
+    // Inspectors
+    pub fn reference_date(&self) -> Date {
+        self.reference_date
+    }
+    pub fn day_counter(&self) -> &dyn DayCounter {
+        self.day_counter.as_ref()
+    }
+    pub fn option_tenors(&self) -> &[Period] {
+        &self.option_tenors
+    }
+    pub fn option_dates(&self) -> &[Date] {
+        &self.option_dates
+    }
+    pub fn option_times(&self) -> &[Time] {
+        &self.option_times
+    }
+    pub fn strikes(&self) -> &[Rate] {
+        &self.strikes
+    }
+    pub fn max_date(&self) -> Date {
+        self.option_dates[self.option_dates.len() - 1]
+    }
+
+    // Flat volatility of the cap expiring at time t
+    pub fn volatility(&self, t: Time, strike: Rate) -> Volatility {
+        let smiles: Vec<Volatility> = self
+            .volatilities
+            .iter()
+            .map(|row| detail::interpolate(&self.strikes, row, strike))
+            .collect();
+        detail::interpolate(&self.option_times, &smiles, t)
+    }
+}
+
+pub(crate) mod detail {
+    use crate::math::interpolations::linear_interpolation::LinearInterpolation;
+    use crate::types::Real;
+
+    // Linear interpolation, flat outside [x_0, x_n]; a single point gives a constant
+    pub(crate) fn interpolate(x: &[Real], y: &[Real], value: Real) -> Real {
+        if x.len() == 1 {
+            return y[0];
+        }
+        LinearInterpolation::new(x, y).value(value.clamp(x[0], x[x.len() - 1]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::time_unit::TimeUnit;
+
+    fn surface(tenors: &[Period], volatilities: &[Vec<Volatility>]) -> CapFloorTermVolSurface {
+        CapFloorTermVolSurface::new(
+            Date::new(15, Month::January, 2025),
+            Box::new(Actual365Fixed::new()),
+            tenors,
+            &[0.02, 0.04],
+            volatilities,
+        )
+    }
+
+    #[test]
+    fn volatilities_are_interpolated_bilinearly_and_flat_outside() {
+        let surface: CapFloorTermVolSurface = surface(
+            &[
+                Period::new(1, TimeUnit::Years),
+                Period::new(3, TimeUnit::Years),
+            ],
+            &[vec![0.30, 0.20], vec![0.26, 0.18]],
+        );
+        let t1: Time = surface.option_times()[0];
+        let t3: Time = surface.option_times()[1];
+        let cases: [(Time, Rate, Volatility); 5] = [
+            (t1, 0.02, 0.30),
+            (t3, 0.03, 0.22),
+            (0.5 * (t1 + t3), 0.03, 0.235),
+            (0.1, 0.01, 0.30),
+            (10.0, 0.05, 0.18),
+        ];
+        for (t, strike, expected) in cases {
+            let calculated: Volatility = surface.volatility(t, strike);
+            assert!(
+                (calculated - expected).abs() < 1.0e-14,
+                "t = {t}, strike = {strike}: {calculated} vs {expected}"
+            );
+        }
+        assert_eq!(surface.max_date(), Date::new(15, Month::January, 2028));
+    }
+
+    #[test]
+    #[allow(clippy::type_complexity)]
+    fn new_panics_on_invalid_quotes() {
+        let one: Period = Period::new(1, TimeUnit::Years);
+        let two: Period = Period::new(2, TimeUnit::Years);
+        let cases: [(Vec<Period>, Vec<Vec<Volatility>>, &str); 4] = [
+            (vec![one, two], vec![vec![0.2, 0.2]], "missing row"),
+            (vec![one], vec![vec![0.2]], "missing column"),
+            (vec![two, one], vec![vec![0.2, 0.2]; 2], "unsorted tenors"),
+            (vec![one], vec![vec![0.2, -0.1]], "negative volatility"),
+        ];
+        for (tenors, volatilities, label) in cases {
+            let result = std::panic::catch_unwind(|| surface(&tenors, &volatilities));
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
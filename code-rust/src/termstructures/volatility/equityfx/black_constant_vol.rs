@@ -0,0 +1,90 @@
+use crate::termstructures::volatility::equityfx::black_vol_term_structure::{
+    BlackVolTermStructure, detail::check_time,
+};
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Real, Time, Volatility};
+
+// Volatility constant in time and strike, so that the variance is sigma^2 t (QuantLib BlackConstantVol)
+pub struct BlackConstantVol {
+    reference_date: Date,
+    volatility: Volatility,
+    day_counter: Box<dyn DayCounter>,
+}
+impl BlackConstantVol {
+    // Constructor
+    pub fn new(
+        reference_date: Date,
+        volatility: Volatility,
+        day_counter: Box<dyn DayCounter>,
+    ) -> BlackConstantVol {
+        if volatility < 0.0 {
+            panic!("negative volatility ({volatility}) given");
+        }
+        BlackConstantVol {
+            reference_date,
+            volatility,
+            day_counter,
+        }
+    }
+
+    // Inspectors
+    pub fn volatility(&self) -> Volatility {
+        self.volatility
+    }
+}
+impl BlackVolTermStructure for BlackConstantVol {
+    fn reference_date(&self) -> Date {
+        self.reference_date
+    }
+    fn day_counter(&self) -> &dyn DayCounter {
+        self.day_counter.as_ref()
+    }
+    fn black_variance(&self, t: Time, _strike: Real) -> Real {
+        check_time(t);
+        self.volatility * self.volatility * t
+    }
+    fn black_vol(&self, t: Time, _strike: Real) -> Volatility {
+        check_time(t);
+        self.volatility
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use std::panic;
+
+    #[test]
+    fn volatilities_and_variances_are_flat() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let surface: BlackConstantVol =
+            BlackConstantVol::new(reference_date, 0.2, Box::new(Actual365Fixed::new()));
+        assert_eq!(surface.volatility(), 0.2);
+        assert_eq!(surface.time_from_reference(reference_date + 730), 2.0);
+        for (t, strike) in [(0.0, 100.0), (0.5, 80.0), (2.0, 120.0)] {
+            assert_eq!(surface.black_vol(t, strike), 0.2);
+            assert!((surface.black_variance(t, strike) - 0.04 * t).abs() < 1.0e-15);
+        }
+        assert!((surface.black_forward_variance(0.5, 2.0, 100.0) - 0.06).abs() < 1.0e-15);
+        assert!((surface.black_forward_vol(0.5, 2.0, 100.0) - 0.2).abs() < 1.0e-15);
+        assert!((surface.black_forward_vol(1.0, 1.0, 100.0) - 0.2).abs() < 1.0e-9);
+
+        let result = panic::catch_unwind(|| {
+            BlackConstantVol::new(reference_date, -0.1, Box::new(Actual365Fixed::new()))
+        });
+        assert!(result.is_err(), "expected panic for a negative volatility");
+        let result = panic::catch_unwind(|| {
+            BlackConstantVol::new(reference_date, 0.2, Box::new(Actual365Fixed::new()))
+                .black_variance(-0.1, 100.0)
+        });
+        assert!(result.is_err(), "expected panic for a negative time");
+        let result = panic::catch_unwind(|| {
+            BlackConstantVol::new(reference_date, 0.2, Box::new(Actual365Fixed::new()))
+                .black_forward_variance(2.0, 1.0, 100.0)
+        });
+        assert!(result.is_err(), "expected panic for decreasing times");
+    }
+}
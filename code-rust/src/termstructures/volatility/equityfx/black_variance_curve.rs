@@ -0,0 +1,236 @@
+use crate::math::interpolations::cubic_interpolation::CubicNaturalSpline;
+use crate::math::interpolations::linear_interpolation::LinearInterpolation;
+use crate::termstructures::volatility::equityfx::black_vol_term_structure::{
+    BlackVolTermStructure, detail::check_time,
+};
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Real, Time, Volatility};
+
+// How the variances of a BlackVarianceCurve are interpolated in time
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum VarianceInterpolation {
+    Linear,
+    Cubic,
+}
+
+/*
+Black volatility curve, independent of the strike, interpolating the
+total variances sigma_i^2 t_i of the given volatilities (QuantLib
+BlackVarianceCurve). The variance is 0 at the reference date and is
+interpolated linearly or with a natural cubic spline up to the last
+date; beyond it the last volatility is kept. Variances must be
+non-decreasing so that forward variances are non-negative at the nodes;
+a cubic spline may still overshoot between them.
+*/
+pub struct BlackVarianceCurve {
+    reference_date: Date,
+    day_counter: Box<dyn DayCounter>,
+    dates: Vec<Date>,
+    times: Vec<Time>,
+    variances: Vec<Real>,
+    interpolation: detail::Interpolator,
+}
+impl BlackVarianceCurve {
+    // Constructor
+    pub fn new(
+        reference_date: Date,
+        dates: &[Date],
+        volatilities: &[Volatility],
+        day_counter: Box<dyn DayCounter>,
+        interpolation: VarianceInterpolation,
+    ) -> BlackVarianceCurve {
+        if dates.len() != volatilities.len() {
+            panic!(
+                "mismatch between date vector ({}) and black vol vector ({})",
+                dates.len(),
+                volatilities.len()
+            );
+        }
+        if dates.is_empty() || dates[0] <= reference_date {
+            panic!("at least one date after the reference date ({reference_date}) required");
+        }
+        let mut times: Vec<Time> = vec![0.0];
+        let mut variances: Vec<Real> = vec![0.0];
+        for (date, volatility) in dates.iter().zip(volatilities) {
+            let t: Time = day_counter.year_fraction(reference_date, *date);
+            if t <= times[times.len() - 1] {
+                panic!("dates must be sorted and unique");
+            }
+            let variance: Real = volatility * volatility * t;
+            if variance < variances[variances.len() - 1] {
+                panic!("variance must be non-decreasing (at {date})");
+            }
+            times.push(t);
+            variances.push(variance);
+        }
+        let interpolation: detail::Interpolator = match interpolation {
+            VarianceInterpolation::Linear => {
+                detail::Interpolator::Linear(LinearInterpolation::new(&times, &variances))
+            }
+            VarianceInterpolation::Cubic => {
+                detail::Interpolator::Cubic(CubicNaturalSpline::new(&times, &variances))
+            }
+        };
+        BlackVarianceCurve {
+            reference_date,
+            day_counter,
+            dates: dates.to_vec(),
+            times,
+            variances,
+            interpolation,
+        }
+    }
+
+    // Inspectors
+    pub fn dates(&self) -> &[Date] {
+        &self.dates
+    }
+    // Times and variances of the nodes, starting with 0 at the reference date
+    pub fn times(&self) -> &[Time] {
+        &self.times
+    }
+    pub fn variances(&self) -> &[Real] {
+        &self.variances
+    }
+}
+impl BlackVolTermStructure for BlackVarianceCurve {
+    fn reference_date(&self) -> Date {
+        self.reference_date
+    }
+    fn day_counter(&self) -> &dyn DayCounter {
+        self.day_counter.as_ref()
+    }
+    fn black_variance(&self, t: Time, _strike: Real) -> Real {
+        check_time(t);
+        let last: usize = self.times.len() - 1;
+        if t <= self.times[last] {
+            self.interpolation.value(t)
+        } else {
+            // Flat volatility extrapolation
+            self.variances[last] * t / self.times[last]
+        }
+    }
+}
+
+mod detail {
+    use crate::math::interpolations::cubic_interpolation::CubicNaturalSpline;
+    use crate::math::interpolations::linear_interpolation::LinearInterpolation;
+    use crate::types::Real;
+
+    pub(super) enum Interpolator {
+        Linear(LinearInterpolation),
+        Cubic(CubicNaturalSpline),
+    }
+    impl Interpolator {
+        pub(super) fn value(&self, x: Real) -> Real {
+            match self {
+                Interpolator::Linear(interpolation) => interpolation.value(x),
+                Interpolator::Cubic(interpolation) => interpolation.value(x),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use std::panic;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn curve(interpolation: VarianceInterpolation) -> BlackVarianceCurve {
+        BlackVarianceCurve::new(
+            reference_date(),
+            &[
+                reference_date() + 365,
+                reference_date() + 730,
+                reference_date() + 1095,
+            ],
+            &[0.20, 0.22, 0.21],
+            Box::new(Actual365Fixed::new()),
+            interpolation,
+        )
+    }
+
+    #[test]
+    fn volatilities_are_recovered_at_the_nodes() {
+        for interpolation in [VarianceInterpolation::Linear, VarianceInterpolation::Cubic] {
+            let curve: BlackVarianceCurve = curve(interpolation);
+            assert_eq!(curve.dates().len(), 3);
+            assert_eq!(curve.times(), &[0.0, 1.0, 2.0, 3.0]);
+            for (t, expected) in [(1.0, 0.20), (2.0, 0.22), (3.0, 0.21)] {
+                assert!((curve.black_vol(t, 100.0) - expected).abs() < 1.0e-15);
+            }
+            // Flat volatility beyond the last date
+            assert!((curve.black_vol(5.0, 100.0) - 0.21).abs() < 1.0e-15);
+            assert!(curve.black_variance(0.0, 100.0).abs() < 1.0e-15);
+        }
+    }
+
+    #[test]
+    fn linear_interpolation_of_the_variance() {
+        let curve: BlackVarianceCurve = curve(VarianceInterpolation::Linear);
+        // Forward variance between the nodes: 2 * 0.0484 - 0.04 per year
+        let forward_variance: Real = 2.0 * 0.0484 - 0.04;
+        let variance: Real = 0.04 + 0.5 * forward_variance;
+        assert!((curve.black_variance(1.5, 100.0) - variance).abs() < 1.0e-15);
+        assert!((curve.black_vol(1.5, 100.0) - (variance / 1.5).sqrt()).abs() < 1.0e-15);
+        assert!(
+            (curve.black_forward_vol(1.0, 2.0, 100.0) - forward_variance.sqrt()).abs() < 1.0e-12
+        );
+        // Flat volatility from the reference date to the first date
+        assert!((curve.black_vol(0.5, 100.0) - 0.2).abs() < 1.0e-15);
+    }
+
+    #[test]
+    fn cubic_interpolation_is_smooth_at_the_nodes() {
+        let curve: BlackVarianceCurve = curve(VarianceInterpolation::Cubic);
+        let h: Time = 1.0e-6;
+        let slope = |t: Time| -> Real {
+            (curve.black_variance(t + h, 100.0) - curve.black_variance(t - h, 100.0)) / (2.0 * h)
+        };
+        let left: Real =
+            (curve.black_variance(2.0, 100.0) - curve.black_variance(2.0 - h, 100.0)) / h;
+        let right: Real =
+            (curve.black_variance(2.0 + h, 100.0) - curve.black_variance(2.0, 100.0)) / h;
+        assert!((left - right).abs() < 1.0e-5);
+        assert!(slope(1.5) > 0.0);
+        assert!((curve.black_variance(1.5, 100.0) - curve.black_variance(1.5, 50.0)).abs() == 0.0);
+    }
+
+    #[test]
+    fn new_panics_on_invalid_input() {
+        let d: Date = reference_date();
+        let cases: [(&str, Vec<Date>, Vec<Volatility>); 4] = [
+            ("mismatched sizes", vec![d + 365, d + 730], vec![0.2]),
+            (
+                "date on the reference date",
+                vec![d, d + 365],
+                vec![0.2, 0.2],
+            ),
+            ("unsorted dates", vec![d + 730, d + 365], vec![0.2, 0.2]),
+            (
+                "decreasing variance",
+                vec![d + 365, d + 730],
+                vec![0.3, 0.2],
+            ),
+        ];
+        for (case, dates, volatilities) in cases {
+            let result = panic::catch_unwind(|| {
+                BlackVarianceCurve::new(
+                    d,
+                    &dates,
+                    &volatilities,
+                    Box::new(Actual365Fixed::new()),
+                    VarianceInterpolation::Linear,
+                )
+            });
+            assert!(result.is_err(), "expected panic for {case}");
+        }
+    }
+}
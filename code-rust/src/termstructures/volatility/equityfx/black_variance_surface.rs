@@ -0,0 +1,348 @@
+use crate::math::matrix::Matrix;
+use crate::termstructures::volatility::equityfx::black_vol_term_structure::{
+    BlackVolTermStructure, detail::check_time,
+};
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Real, Size, Time};
+
+// How the variances of a BlackVarianceSurface are interpolated in time and strike
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SurfaceInterpolation {
+    Bilinear,
+    Bicubic,
+}
+
+// QuantLib BlackVarianceSurface::Extrapolation: below the lowest or above the highest strike
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum StrikeExtrapolation {
+    // The variance at the edge strike is kept
+    Constant,
+    // The interpolation is extended
+    InterpolatorDefault,
+}
+
+/*
+Black volatility surface on a grid of strikes and dates, interpolating
+the total variances sigma_ij^2 t_j of the given volatilities (QuantLib
+BlackVarianceSurface). The volatility matrix has a row per strike and a
+column per date. The variance is 0 at the reference date and is
+interpolated bilinearly (by default) or with a bicubic spline in time
+and strike up to the last date; beyond it the last volatility of each
+strike is kept. Outside the strikes the variance is the edge one
+(by default) or is extrapolated by the interpolation, each side being
+set separately. Variances must be non-decreasing in time for each
+strike.
+*/
+pub struct BlackVarianceSurface {
+    reference_date: Date,
+    day_counter: Box<dyn DayCounter>,
+    dates: Vec<Date>,
+    times: Vec<Time>,
+    strikes: Vec<Real>,
+    variances: Matrix,
+    interpolation: detail::Interpolator,
+    lower_extrapolation: StrikeExtrapolation,
+    upper_extrapolation: StrikeExtrapolation,
+}
+impl BlackVarianceSurface {
+    // Constructor
+    pub fn new(
+        reference_date: Date,
+        dates: &[Date],
+        strikes: &[Real],
+        black_vols: &Matrix,
+        day_counter: Box<dyn DayCounter>,
+    ) -> BlackVarianceSurface {
+        if black_vols.columns() != dates.len() {
+            panic!(
+                "mismatch between date vector ({}) and black vol matrix columns ({})",
+                dates.len(),
+                black_vols.columns()
+            );
+        }
+        if black_vols.rows() != strikes.len() {
+            panic!(
+                "mismatch between strike vector ({}) and black vol matrix rows ({})",
+                strikes.len(),
+                black_vols.rows()
+            );
+        }
+        if dates.is_empty() || dates[0] <= reference_date {
+            panic!("at least one date after the reference date ({reference_date}) required");
+        }
+        if strikes.len() < 2 {
+            panic!("at least 2 strikes required, {} provided", strikes.len());
+        }
+        if strikes.windows(2).any(|w| w[1] <= w[0]) {
+            panic!("strikes must be sorted and unique");
+        }
+        let mut times: Vec<Time> = vec![0.0];
+        let mut variances: Matrix = Matrix::new(strikes.len(), dates.len() + 1, 0.0);
+        for (j, date) in dates.iter().enumerate() {
+            let t: Time = day_counter.year_fraction(reference_date, *date);
+            if t <= times[j] {
+                panic!("dates must be sorted and unique");
+            }
+            times.push(t);
+            for i in 0..strikes.len() {
+                let volatility: Real = black_vols[i][j];
+                variances[i][j + 1] = volatility * volatility * t;
+                if variances[i][j + 1] < variances[i][j] {
+                    panic!(
+                        "variance must be non-decreasing (at {date} for strike {})",
+                        strikes[i]
+                    );
+                }
+            }
+        }
+        let interpolation: detail::Interpolator =
+            detail::Interpolator::new(SurfaceInterpolation::Bilinear, &times, strikes, &variances);
+        BlackVarianceSurface {
+            reference_date,
+            day_counter,
+            dates: dates.to_vec(),
+            times,
+            strikes: strikes.to_vec(),
+            variances,
+            interpolation,
+            lower_extrapolation: StrikeExtrapolation::Constant,
+            upper_extrapolation: StrikeExtrapolation::Constant,
+        }
+    }
+    pub fn with_interpolation(mut self, interpolation: SurfaceInterpolation) -> Self {
+        self.interpolation =
+            detail::Interpolator::new(interpolation, &self.times, &self.strikes, &self.variances);
+        self
+    }
+    pub fn with_extrapolation(
+        mut self,
+        lower_extrapolation: StrikeExtrapolation,
+        upper_extrapolation: StrikeExtrapolation,
+    ) -> Self {
+        self.lower_extrapolation = lower_extrapolation;
+        self.upper_extrapolation = upper_extrapolation;
+        self
+    }
+
+    // Inspectors
+    pub fn dates(&self) -> &[Date] {
+        &self.dates
+    }
+    // Times of the columns of the variances, starting with 0 at the reference date
+    pub fn times(&self) -> &[Time] {
+        &self.times
+    }
+    pub fn strikes(&self) -> &[Real] {
+        &self.strikes
+    }
+    // Variances with a row per strike and a column per time
+    pub fn variances(&self) -> &Matrix {
+        &self.variances
+    }
+    pub fn min_strike(&self) -> Real {
+        self.strikes[0]
+    }
+    pub fn max_strike(&self) -> Real {
+        self.strikes[self.strikes.len() - 1]
+    }
+}
+impl BlackVolTermStructure for BlackVarianceSurface {
+    fn reference_date(&self) -> Date {
+        self.reference_date
+    }
+    fn day_counter(&self) -> &dyn DayCounter {
+        self.day_counter.as_ref()
+    }
+    fn black_variance(&self, t: Time, strike: Real) -> Real {
+        check_time(t);
+        if t == 0.0 {
+            return 0.0;
+        }
+        let strike: Real = if strike < self.min_strike()
+            && self.lower_extrapolation == StrikeExtrapolation::Constant
+        {
+            self.min_strike()
+        } else if strike > self.max_strike()
+            && self.upper_extrapolation == StrikeExtrapolation::Constant
+        {
+            self.max_strike()
+        } else {
+            strike
+        };
+        let last: Size = self.times.len() - 1;
+        if t <= self.times[last] {
+            self.interpolation.value(t, strike)
+        } else {
+            // Flat volatility extrapolation
+            self.interpolation.value(self.times[last], strike) * t / self.times[last]
+        }
+    }
+}
+
+mod detail {
+    use super::SurfaceInterpolation;
+    use crate::math::interpolations::bicubic_spline_interpolation::BicubicSpline;
+    use crate::math::interpolations::bilinear_interpolation::BilinearInterpolation;
+    use crate::math::matrix::Matrix;
+    use crate::types::{Real, Time};
+
+    pub(super) enum Interpolator {
+        Bilinear(BilinearInterpolation),
+        Bicubic(BicubicSpline),
+    }
+    impl Interpolator {
+        pub(super) fn new(
+            interpolation: SurfaceInterpolation,
+            times: &[Time],
+            strikes: &[Real],
+            variances: &Matrix,
+        ) -> Interpolator {
+            match interpolation {
+                SurfaceInterpolation::Bilinear => {
+                    Interpolator::Bilinear(BilinearInterpolation::new(times, strikes, variances))
+                }
+                SurfaceInterpolation::Bicubic => {
+                    Interpolator::Bicubic(BicubicSpline::new(times, strikes, variances))
+                }
+            }
+        }
+        pub(super) fn value(&self, t: Time, strike: Real) -> Real {
+            match self {
+                Interpolator::Bilinear(interpolation) => interpolation.value(t, strike),
+                Interpolator::Bicubic(interpolation) => interpolation.value(t, strike),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use std::panic;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    // Smile at 90, 100 and 110 for one and two years
+    fn surface() -> BlackVarianceSurface {
+        BlackVarianceSurface::new(
+            reference_date(),
+            &[reference_date() + 365, reference_date() + 730],
+            &[90.0, 100.0, 110.0],
+            &Matrix::from_rows(&[vec![0.25, 0.24], vec![0.20, 0.21], vec![0.22, 0.22]]),
+            Box::new(Actual365Fixed::new()),
+        )
+    }
+
+    #[test]
+    fn volatilities_are_recovered_at_the_nodes() {
+        for interpolation in [
+            SurfaceInterpolation::Bilinear,
+            SurfaceInterpolation::Bicubic,
+        ] {
+            let surface: BlackVarianceSurface = surface().with_interpolation(interpolation);
+            assert_eq!(surface.times(), &[0.0, 1.0, 2.0]);
+            assert_eq!(surface.variances()[0][0], 0.0);
+            for (t, strike, expected) in [
+                (1.0, 90.0, 0.25),
+                (1.0, 100.0, 0.20),
+                (2.0, 100.0, 0.21),
+                (2.0, 110.0, 0.22),
+            ] {
+                assert!((surface.black_vol(t, strike) - expected).abs() < 1.0e-14);
+            }
+            // Flat volatility beyond the last date
+            assert!((surface.black_vol(3.0, 90.0) - 0.24).abs() < 1.0e-14);
+            assert_eq!(surface.black_variance(0.0, 100.0), 0.0);
+        }
+    }
+
+    #[test]
+    fn bilinear_interpolation_of_the_variance() {
+        let surface: BlackVarianceSurface = surface();
+        // Halfway in time and strike between the four variances
+        let variance: Real = 0.25 * (0.25 * 0.25 + 0.20 * 0.20 + 2.0 * (0.24 * 0.24 + 0.21 * 0.21));
+        assert!((surface.black_variance(1.5, 95.0) - variance).abs() < 1.0e-15);
+        // Variance linear in time from the reference date
+        assert!((surface.black_vol(0.5, 100.0) - 0.2).abs() < 1.0e-15);
+    }
+
+    #[test]
+    fn strikes_are_extrapolated_as_chosen() {
+        let constant: BlackVarianceSurface = surface();
+        assert_eq!(
+            constant.black_variance(1.0, 50.0),
+            constant.black_variance(1.0, 90.0)
+        );
+        assert_eq!(
+            constant.black_variance(1.0, 150.0),
+            constant.black_variance(1.0, 110.0)
+        );
+
+        let extrapolated: BlackVarianceSurface = surface().with_extrapolation(
+            StrikeExtrapolation::InterpolatorDefault,
+            StrikeExtrapolation::Constant,
+        );
+        // Linear in strike below 90, along the slope between 90 and 100
+        let slope: Real = (0.20 * 0.20 - 0.25 * 0.25) / 10.0;
+        let expected: Real = 0.25 * 0.25 - 5.0 * slope;
+        assert!((extrapolated.black_variance(1.0, 85.0) - expected).abs() < 1.0e-15);
+        assert_eq!(
+            extrapolated.black_variance(1.0, 150.0),
+            extrapolated.black_variance(1.0, 110.0)
+        );
+    }
+
+    #[test]
+    fn new_panics_on_invalid_input() {
+        let d: Date = reference_date();
+        let cases: [(&str, Vec<Date>, Vec<Real>, Matrix); 5] = [
+            (
+                "mismatched dates",
+                vec![d + 365],
+                vec![90.0, 100.0],
+                Matrix::new(2, 2, 0.2),
+            ),
+            (
+                "mismatched strikes",
+                vec![d + 365, d + 730],
+                vec![90.0, 100.0, 110.0],
+                Matrix::new(2, 2, 0.2),
+            ),
+            (
+                "unsorted strikes",
+                vec![d + 365, d + 730],
+                vec![100.0, 90.0],
+                Matrix::new(2, 2, 0.2),
+            ),
+            (
+                "unsorted dates",
+                vec![d + 730, d + 365],
+                vec![90.0, 100.0],
+                Matrix::new(2, 2, 0.2),
+            ),
+            (
+                "decreasing variance",
+                vec![d + 365, d + 730],
+                vec![90.0, 100.0],
+                Matrix::from_rows(&[vec![0.2, 0.2], vec![0.3, 0.2]]),
+            ),
+        ];
+        for (case, dates, strikes, black_vols) in cases {
+            let result = panic::catch_unwind(|| {
+                BlackVarianceSurface::new(
+                    d,
+                    &dates,
+                    &strikes,
+                    &black_vols,
+                    Box::new(Actual365Fixed::new()),
+                )
+            });
+            assert!(result.is_err(), "expected panic for {case}");
+        }
+    }
+}
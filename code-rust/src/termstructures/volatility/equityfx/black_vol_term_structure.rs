@@ -0,0 +1,52 @@
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Real, Time, Volatility};
+
+/*
+Black volatility of equity and FX options (QuantLib
+BlackVolTermStructure), as a function of the time to expiry and of the
+strike. Times are measured with the structure's own day counter from
+its reference date, and the structure is defined by its total variance
+    sigma(t, K)^2 t
+from which the volatility and the forward variance between two times
+follow. At t = 0 the volatility is taken over the first 1e-5 years, as
+in QuantLib. Structures are shared by the processes and engines using
+them as Rc<dyn BlackVolTermStructure>.
+*/
+pub trait BlackVolTermStructure {
+    fn reference_date(&self) -> Date;
+    fn day_counter(&self) -> &dyn DayCounter;
+    fn black_variance(&self, t: Time, strike: Real) -> Real;
+
+    fn black_vol(&self, t: Time, strike: Real) -> Volatility {
+        let t: Time = if t == 0.0 { detail::EPSILON } else { t };
+        (self.black_variance(t, strike) / t).sqrt()
+    }
+    fn black_forward_variance(&self, t1: Time, t2: Time, strike: Real) -> Real {
+        if t2 < t1 {
+            panic!("initial time ({t1}) must be before final time ({t2})");
+        }
+        self.black_variance(t2, strike) - self.black_variance(t1, strike)
+    }
+    fn black_forward_vol(&self, t1: Time, t2: Time, strike: Real) -> Volatility {
+        let t2: Time = if t2 == t1 { t1 + detail::EPSILON } else { t2 };
+        (self.black_forward_variance(t1, t2, strike) / (t2 - t1)).sqrt()
+    }
+    fn time_from_reference(&self, date: Date) -> Time {
+        self.day_counter()
+            .year_fraction(self.reference_date(), date)
+    }
+}
+
+pub(crate) mod detail {
+    use crate::types::Time;
+
+    // Shortest time over which volatilities are taken
+    pub(crate) const EPSILON: Time = 1.0e-5;
+
+    pub(crate) fn check_time(t: Time) {
+        if t < 0.0 {
+            panic!("negative time ({t}) given");
+        }
+    }
+}
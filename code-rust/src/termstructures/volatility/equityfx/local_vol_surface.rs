@@ -0,0 +1,249 @@
+use crate::termstructures::volatility::equityfx::black_vol_term_structure::{
+    BlackVolTermStructure, detail::check_time,
+};
+use crate::termstructures::volatility::equityfx::local_vol_term_structure::LocalVolTermStructure;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{DiscountFactor, Real, Time, Volatility};
+use std::rc::Rc;
+
+/*
+Local volatility implied by a Black volatility surface through Dupire's
+formula (QuantLib LocalVolSurface). With the total variance w(t, y) as
+a function of the log-moneyness y = ln(K / F(t)), F(t) = S Dq(t) / Dr(t),
+the local variance at K is
+    dw/dt / (1 - y/w dw/dy + 1/4 (-1/4 - 1/w + y^2/w^2) (dw/dy)^2
+             + 1/2 d2w/dy2)
+with the derivatives taken by finite differences: in y by bumping the
+strike by dy = 1e-4 y (1e-6 near the money), in t over dt = min(1e-4, t/2)
+at a constant y, i.e. at the strikes moving with the forward. The
+surface must be smooth enough for the local variance to be positive.
+Times are measured on the Black surface, which should share its
+reference date and day counter with the curves.
+*/
+pub struct LocalVolSurface {
+    black_ts: Rc<dyn BlackVolTermStructure>,
+    risk_free_ts: Rc<dyn YieldTermStructure>,
+    dividend_ts: Rc<dyn YieldTermStructure>,
+    underlying: Real,
+}
+impl LocalVolSurface {
+    // Constructor
+    pub fn new(
+        black_ts: Rc<dyn BlackVolTermStructure>,
+        risk_free_ts: Rc<dyn YieldTermStructure>,
+        dividend_ts: Rc<dyn YieldTermStructure>,
+        underlying: Real,
+    ) -> LocalVolSurface {
+        if underlying <= 0.0 {
+            panic!("negative or null underlying given ({underlying})");
+        }
+        LocalVolSurface {
+            black_ts,
+            risk_free_ts,
+            dividend_ts,
+            underlying,
+        }
+    }
+
+    // Inspectors
+    pub fn black_ts(&self) -> &dyn BlackVolTermStructure {
+        self.black_ts.as_ref()
+    }
+    pub fn underlying(&self) -> Real {
+        self.underlying
+    }
+
+    // Strike at t2 with the log-moneyness of strike at t1
+    fn moving_strike(&self, strike: Real, t1: Time, t2: Time) -> Real {
+        strike * self.risk_free_ts.discount(t1) * self.dividend_ts.discount(t2)
+            / (self.risk_free_ts.discount(t2) * self.dividend_ts.discount(t1))
+    }
+}
+impl LocalVolTermStructure for LocalVolSurface {
+    fn reference_date(&self) -> Date {
+        self.black_ts.reference_date()
+    }
+    fn day_counter(&self) -> &dyn DayCounter {
+        self.black_ts.day_counter()
+    }
+    fn local_vol(&self, t: Time, underlying: Real) -> Volatility {
+        check_time(t);
+        let dr: DiscountFactor = self.risk_free_ts.discount(t);
+        let dq: DiscountFactor = self.dividend_ts.discount(t);
+        let forward: Real = self.underlying * dq / dr;
+
+        // Strike derivatives
+        let strike: Real = underlying;
+        let y: Real = (strike / forward).ln();
+        let dy: Real = if y.abs() > 0.001 {
+            y * 0.0001
+        } else {
+            0.000001
+        };
+        let w: Real = self.black_ts.black_variance(t, strike);
+        let wp: Real = self.black_ts.black_variance(t, strike * dy.exp());
+        let wm: Real = self.black_ts.black_variance(t, strike / dy.exp());
+        let dwdy: Real = (wp - wm) / (2.0 * dy);
+        let d2wdy2: Real = (wp - 2.0 * w + wm) / (dy * dy);
+
+        // Time derivative, forward at t = 0
+        let dwdt: Real = if t == 0.0 {
+            let dt: Time = 0.0001;
+            let wpt: Real = self
+                .black_ts
+                .black_variance(t + dt, self.moving_strike(strike, t, t + dt));
+            (wpt - w) / dt
+        } else {
+            let dt: Time = (t / 2.0).min(0.0001);
+            let wpt: Real = self
+                .black_ts
+                .black_variance(t + dt, self.moving_strike(strike, t, t + dt));
+            let wmt: Real = self
+                .black_ts
+                .black_variance(t - dt, self.moving_strike(strike, t, t - dt));
+            (wpt - wmt) / (2.0 * dt)
+        };
+
+        let local_variance: Real = if dwdy == 0.0 && d2wdy2 == 0.0 {
+            // No smile: avoid dividing by w, which may be 0
+            dwdt
+        } else {
+            let den1: Real = 1.0 - y / w * dwdy;
+            let den2: Real = 0.25 * (-0.25 - 1.0 / w + y * y / w / w) * dwdy * dwdy;
+            let den3: Real = 0.5 * d2wdy2;
+            dwdt / (den1 + den2 + den3)
+        };
+        if local_variance < 0.0 {
+            panic!(
+                "negative local vol^2 at strike {strike} and time {t}; the black vol surface is not smooth enough"
+            );
+        }
+        local_variance.sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::matrix::Matrix;
+    use crate::termstructures::volatility::equityfx::black_constant_vol::BlackConstantVol;
+    use crate::termstructures::volatility::equityfx::black_variance_surface::{
+        BlackVarianceSurface, SurfaceInterpolation,
+    };
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn local_vol(black_ts: Rc<dyn BlackVolTermStructure>) -> LocalVolSurface {
+        LocalVolSurface::new(
+            black_ts,
+            flat_rate(reference_date(), 0.05),
+            flat_rate(reference_date(), 0.02),
+            100.0,
+        )
+    }
+
+    #[test]
+    fn constant_black_volatility_is_the_local_volatility() {
+        let surface: LocalVolSurface = local_vol(Rc::new(BlackConstantVol::new(
+            reference_date(),
+            0.2,
+            Box::new(Actual365Fixed::new()),
+        )));
+        for (t, underlying) in [(0.0, 100.0), (0.5, 80.0), (1.0, 100.0), (3.0, 140.0)] {
+            assert!(
+                (surface.local_vol(t, underlying) - 0.2).abs() < 1.0e-8,
+                "local vol at ({t}, {underlying})"
+            );
+        }
+        assert_eq!(surface.reference_date(), reference_date());
+    }
+
+    #[test]
+    fn term_structure_without_smile_gives_the_forward_volatility() {
+        let d: Date = reference_date();
+        let surface: LocalVolSurface = local_vol(Rc::new(BlackVarianceSurface::new(
+            d,
+            &[d + 365, d + 730],
+            &[50.0, 200.0],
+            &Matrix::from_rows(&[vec![0.2, 0.3], vec![0.2, 0.3]]),
+            Box::new(Actual365Fixed::new()),
+        )));
+        // Forward variance over the second year: 2 * 0.09 - 0.04
+        assert!((surface.local_vol(0.5, 100.0) - 0.2).abs() < 1.0e-8);
+        assert!((surface.local_vol(1.5, 120.0) - 0.14_f64.sqrt()).abs() < 1.0e-8);
+    }
+
+    #[test]
+    fn smile_raises_the_local_volatility_away_from_the_money() {
+        // Black variance w = (0.04 + 0.1 y^2) t in the log-moneyness y: the local
+        // volatility at the money is close to the Black one and rises with the smile
+        let d: Date = reference_date();
+        let strikes: Vec<Real> = (0..=60).map(|i| 70.0 + i as Real).collect();
+        let forward = |t: Time| -> Real { 100.0 * (0.03 * t).exp() };
+        let dates: Vec<Date> = (1..=8).map(|k| d + 45 * k).collect();
+        let black_vols: Vec<Vec<Real>> = strikes
+            .iter()
+            .map(|strike| {
+                dates
+                    .iter()
+                    .map(|date| {
+                        let t: Time = (*date - d) as Time / 365.0;
+                        let y: Real = (strike / forward(t)).ln();
+                        (0.04 + 0.1 * y * y).sqrt()
+                    })
+                    .collect()
+            })
+            .collect();
+        let surface: LocalVolSurface = local_vol(Rc::new(
+            BlackVarianceSurface::new(
+                d,
+                &dates,
+                &strikes,
+                &Matrix::from_rows(&black_vols),
+                Box::new(Actual365Fixed::new()),
+            )
+            .with_interpolation(SurfaceInterpolation::Bicubic),
+        ));
+        let at_the_money: Volatility = surface.local_vol(0.5, forward(0.5));
+        let away: Volatility = surface.local_vol(0.5, 0.8 * forward(0.5));
+        assert!((at_the_money - 0.2).abs() < 5.0e-3, "{at_the_money}");
+        assert!(away > at_the_money + 0.01, "{away} vs {at_the_money}");
+    }
+
+    #[test]
+    fn local_vol_panics_on_a_concave_smile() {
+        let result = std::panic::catch_unwind(|| {
+            let d: Date = reference_date();
+            let surface: LocalVolSurface = LocalVolSurface::new(
+                Rc::new(BlackVarianceSurface::new(
+                    d,
+                    &[d + 365],
+                    &[90.0, 100.0, 110.0],
+                    &Matrix::from_rows(&[vec![0.2], vec![0.4], vec![0.2]]),
+                    Box::new(Actual365Fixed::new()),
+                )),
+                flat_rate(d, 0.0),
+                flat_rate(d, 0.0),
+                100.0,
+            );
+            surface.local_vol(0.5, 100.0)
+        });
+        assert!(result.is_err(), "expected panic for a concave smile");
+        let result = std::panic::catch_unwind(|| {
+            local_vol(Rc::new(BlackConstantVol::new(
+                reference_date(),
+                0.2,
+                Box::new(Actual365Fixed::new()),
+            )))
+            .local_vol(-1.0, 100.0)
+        });
+        assert!(result.is_err(), "expected panic for a negative time");
+    }
+}
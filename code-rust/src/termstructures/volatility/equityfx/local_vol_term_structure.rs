@@ -0,0 +1,20 @@
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Real, Time, Volatility};
+
+/*
+Local volatility of equity and FX underlyings (QuantLib
+LocalVolTermStructure): the instantaneous volatility sigma(t, S) of the
+underlying at level S, t years after the reference date as measured by
+the structure's own day counter.
+*/
+pub trait LocalVolTermStructure {
+    fn reference_date(&self) -> Date;
+    fn day_counter(&self) -> &dyn DayCounter;
+    fn local_vol(&self, t: Time, underlying: Real) -> Volatility;
+
+    fn time_from_reference(&self, date: Date) -> Time {
+        self.day_counter()
+            .year_fraction(self.reference_date(), date)
+    }
+}
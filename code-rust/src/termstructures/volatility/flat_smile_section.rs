@@ -0,0 +1,128 @@
+use crate::termstructures::volatility::smile_section::SmileSection;
+use crate::termstructures::volatility::volatility_type::VolatilityType;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Rate, Real, Time, Volatility};
+
+// Same volatility for all strikes (QuantLib FlatSmileSection)
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct FlatSmileSection {
+    exercise_date: Date,
+    exercise_time: Time,
+    volatility: Volatility,
+    atm_level: Option<Rate>,
+    volatility_type: VolatilityType,
+    shift: Real,
+}
+impl FlatSmileSection {
+    // Constructor
+    pub fn new(
+        exercise_date: Date,
+        reference_date: Date,
+        day_counter: &dyn DayCounter,
+        volatility: Volatility,
+        atm_level: Option<Rate>,
+        volatility_type: VolatilityType,
+        shift: Real,
+    ) -> FlatSmileSection {
+        if volatility < 0.0 {
+            panic!("negative volatility ({volatility}) not allowed");
+        }
+        FlatSmileSection {
+            exercise_date,
+            exercise_time: detail::exercise_time(exercise_date, reference_date, day_counter),
+            volatility,
+            atm_level,
+            volatility_type,
+            shift,
+        }
+    }
+}
+impl SmileSection for FlatSmileSection {
+    fn exercise_date(&self) -> Date {
+        self.exercise_date
+    }
+    fn exercise_time(&self) -> Time {
+        self.exercise_time
+    }
+    fn volatility(&self, _strike: Rate) -> Volatility {
+        self.volatility
+    }
+    fn atm_level(&self) -> Option<Rate> {
+        self.atm_level
+    }
+    fn volatility_type(&self) -> VolatilityType {
+        self.volatility_type
+    }
+    fn shift(&self) -> Real {
+        self.shift
+    }
+}
+
+pub(crate) mod detail {
+    use crate::time::date::Date;
+    use crate::time::day_counter::DayCounter;
+    use crate::types::Time;
+
+    // Time from the reference date to the exercise, which must not be in the past
+    pub(crate) fn exercise_time(
+        exercise_date: Date,
+        reference_date: Date,
+        day_counter: &dyn DayCounter,
+    ) -> Time {
+        let exercise_time: Time = day_counter.year_fraction(reference_date, exercise_date);
+        if exercise_time < 0.0 {
+            panic!("exercise date ({exercise_date}) before the reference date ({reference_date})");
+        }
+        exercise_time
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+
+    #[test]
+    fn volatility_is_flat() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let section: FlatSmileSection = FlatSmileSection::new(
+            reference_date + 730,
+            reference_date,
+            &Actual365Fixed::new(),
+            0.01,
+            Some(-0.002),
+            VolatilityType::Normal,
+            0.0,
+        );
+        assert_eq!(section.exercise_time(), 2.0);
+        assert_eq!(section.volatility(-0.01), 0.01);
+        assert!((section.variance(0.05) - 2.0e-4).abs() < 1.0e-18);
+        assert_eq!(section.atm_level(), Some(-0.002));
+        assert_eq!(section.volatility_type(), VolatilityType::Normal);
+    }
+
+    #[test]
+    fn new_panics_on_invalid_input() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let cases: [(Date, Volatility, &str); 2] = [
+            (reference_date - 1, 0.2, "past exercise"),
+            (reference_date + 365, -0.2, "negative volatility"),
+        ];
+        for (exercise_date, volatility, label) in cases {
+            let result = std::panic::catch_unwind(|| {
+                FlatSmileSection::new(
+                    exercise_date,
+                    reference_date,
+                    &Actual365Fixed::new(),
+                    volatility,
+                    None,
+                    VolatilityType::ShiftedLognormal,
+                    0.0,
+                )
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use crate::termstructures::volatility::capfloor::cap_floor_term_vol_surface::detail::interpolate;
+use crate::termstructures::volatility::flat_smile_section::detail::exercise_time;
+use crate::termstructures::volatility::smile_section::SmileSection;
+use crate::termstructures::volatility::volatility_type::VolatilityType;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Rate, Real, Time, Volatility};
+
+/*
+Smile given by volatility quotes at a set of strikes (QuantLib
+InterpolatedSmileSection), linearly interpolated in strike and flat
+outside the quoted strikes.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct InterpolatedSmileSection {
+    exercise_date: Date,
+    exercise_time: Time,
+    strikes: Vec<Rate>,
+    volatilities: Vec<Volatility>,
+    atm_level: Option<Rate>,
+    volatility_type: VolatilityType,
+    shift: Real,
+}
+impl InterpolatedSmileSection {
+    // Constructor
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        exercise_date: Date,
+        reference_date: Date,
+        day_counter: &dyn DayCounter,
+        strikes: &[Rate],
+        volatilities: &[Volatility],
+        atm_level: Option<Rate>,
+        volatility_type: VolatilityType,
+        shift: Real,
+    ) -> InterpolatedSmileSection {
+        if strikes.is_empty() || strikes.len() != volatilities.len() {
+            panic!(
+                "{} strikes given for {} volatilities",
+                strikes.len(),
+                volatilities.len()
+            );
+        }
+        if strikes.windows(2).any(|pair| pair[1] <= pair[0]) {
+            panic!("strikes must be sorted in increasing order");
+        }
+        if volatilities.iter().any(|vol| *vol < 0.0) {
+            panic!("negative volatilities not allowed");
+        }
+        InterpolatedSmileSection {
+            exercise_date,
+            exercise_time: exercise_time(exercise_date, reference_date, day_counter),
+            strikes: strikes.to_vec(),
+            volatilities: volatilities.to_vec(),
+            atm_level,
+            volatility_type,
+            shift,
+        }
+    }
+
+    // Inspectors
+    pub fn strikes(&self) -> &[Rate] {
+        &self.strikes
+    }
+    pub fn volatilities(&self) -> &[Volatility] {
+        &self.volatilities
+    }
+}
+impl SmileSection for InterpolatedSmileSection {
+    fn exercise_date(&self) -> Date {
+        self.exercise_date
+    }
+    fn exercise_time(&self) -> Time {
+        self.exercise_time
+    }
+    fn volatility(&self, strike: Rate) -> Volatility {
+        interpolate(&self.strikes, &self.volatilities, strike)
+    }
+    fn atm_level(&self) -> Option<Rate> {
+        self.atm_level
+    }
+    fn volatility_type(&self) -> VolatilityType {
+        self.volatility_type
+    }
+    fn shift(&self) -> Real {
+        self.shift
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+
+    fn section(strikes: &[Rate], volatilities: &[Volatility]) -> InterpolatedSmileSection {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        InterpolatedSmileSection::new(
+            reference_date + 365,
+            reference_date,
+            &Actual365Fixed::new(),
+            strikes,
+            volatilities,
+            Some(0.03),
+            VolatilityType::ShiftedLognormal,
+            0.01,
+        )
+    }
+
+    #[test]
+    fn volatilities_are_interpolated_linearly_and_flat_outside() {
+        let section: InterpolatedSmileSection = section(&[0.02, 0.03, 0.05], &[0.3, 0.25, 0.23]);
+        let cases: [(Rate, Volatility); 5] = [
+            (0.0, 0.3),
+            (0.025, 0.275),
+            (0.03, 0.25),
+            (0.04, 0.24),
+            (0.08, 0.23),
+        ];
+        for (strike, expected) in cases {
+            assert!((section.volatility(strike) - expected).abs() < 1.0e-15);
+        }
+        assert_eq!(section.shift(), 0.01);
+        assert_eq!(section.strikes().len(), 3);
+    }
+
+    #[test]
+    fn new_panics_on_invalid_quotes() {
+        let cases: [(Vec<Rate>, Vec<Volatility>, &str); 4] = [
+            (vec![], vec![], "no quotes"),
+            (vec![0.02, 0.03], vec![0.2], "missing volatility"),
+            (vec![0.03, 0.02], vec![0.2, 0.2], "unsorted strikes"),
+            (vec![0.02, 0.03], vec![0.2, -0.2], "negative volatility"),
+        ];
+        for (strikes, volatilities, label) in cases {
+            let result = std::panic::catch_unwind(|| section(&strikes, &volatilities));
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
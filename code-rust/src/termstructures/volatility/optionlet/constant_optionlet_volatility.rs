@@ -0,0 +1,90 @@
+use crate::termstructures::volatility::optionlet::optionlet_volatility_structure::OptionletVolatilityStructure;
+use crate::termstructures::volatility::volatility_type::VolatilityType;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Rate, Real, Time, Volatility};
+
+// Same volatility for all fixing times and strikes (QuantLib ConstantOptionletVolatility)
+pub struct ConstantOptionletVolatility {
+    reference_date: Date,
+    day_counter: Box<dyn DayCounter>,
+    volatility: Volatility,
+    volatility_type: VolatilityType,
+    displacement: Real,
+}
+impl ConstantOptionletVolatility {
+    // Constructor
+    pub fn new(
+        reference_date: Date,
+        day_counter: Box<dyn DayCounter>,
+        volatility: Volatility,
+        volatility_type: VolatilityType,
+        displacement: Real,
+    ) -> ConstantOptionletVolatility {
+        if volatility < 0.0 {
+            panic!("negative volatility ({volatility}) not allowed");
+        }
+        ConstantOptionletVolatility {
+            reference_date,
+            day_counter,
+            volatility,
+            volatility_type,
+            displacement,
+        }
+    }
+}
+impl OptionletVolatilityStructure for ConstantOptionletVolatility {
+    fn reference_date(&self) -> Date {
+        self.reference_date
+    }
+    fn day_counter(&self) -> &dyn DayCounter {
+        self.day_counter.as_ref()
+    }
+    fn volatility(&self, _option_time: Time, _strike: Rate) -> Volatility {
+        self.volatility
+    }
+    fn volatility_type(&self) -> VolatilityType {
+        self.volatility_type
+    }
+    fn displacement(&self) -> Real {
+        self.displacement
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+
+    #[test]
+    fn volatility_is_flat() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let volatility: ConstantOptionletVolatility = ConstantOptionletVolatility::new(
+            reference_date,
+            Box::new(Actual365Fixed::new()),
+            0.2,
+            VolatilityType::ShiftedLognormal,
+            0.01,
+        );
+        assert_eq!(volatility.volatility(0.5, 0.01), 0.2);
+        assert_eq!(volatility.volatility(5.0, 0.08), 0.2);
+        assert!((volatility.black_variance(2.0, 0.03) - 0.08).abs() < 1.0e-15);
+        assert_eq!(volatility.time_from_reference(reference_date + 365), 1.0);
+        assert_eq!(volatility.displacement(), 0.01);
+    }
+
+    #[test]
+    fn new_panics_on_negative_volatility() {
+        let result = std::panic::catch_unwind(|| {
+            ConstantOptionletVolatility::new(
+                Date::new(15, Month::January, 2025),
+                Box::new(Actual365Fixed::new()),
+                -0.01,
+                VolatilityType::Normal,
+                0.0,
+            )
+        });
+        assert!(result.is_err(), "expected panic for negative volatility");
+    }
+}
@@ -0,0 +1,335 @@
+use crate::cashflows::ibor_coupon::IborCoupon;
+use crate::instruments::cap_floor::CapFloor;
+use crate::option::OptionType;
+use crate::pricingengines::black_formula::{
+    bachelier_black_formula_implied_vol, black_formula_implied_std_dev,
+};
+use crate::pricingengines::capfloor::black_cap_floor_engine::detail::calculate;
+use crate::pricingengines::capfloor::cap_floor_results::CapFloorResults;
+use crate::termstructures::volatility::capfloor::cap_floor_term_vol_surface::CapFloorTermVolSurface;
+use crate::termstructures::volatility::volatility_type::VolatilityType;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::date::Date;
+use crate::time::period::Period;
+use crate::time::schedule::Schedule;
+use crate::types::{DiscountFactor, Rate, Real, Size, Time, Volatility};
+
+/*
+Caplet volatilities stripped from cap/floor flat volatilities (QuantLib
+OptionletStripper1).
+
+The optionlets are the index_tenor periods from the reference date up
+to the last quoted cap maturity; the first one, fixing today, is left
+out as in market caps. For each strike the cap (or floor, below the
+switch strike) made of the first i + 1 optionlets is priced with the
+flat volatility quoted at its maturity; the difference between two
+consecutive prices is the price of the i-th optionlet, whose implied
+volatility is the stripped one. Flat volatilities at maturities between
+quoted tenors are interpolated by the surface.
+
+Quotes and stripped volatilities are of the given volatility type and
+displacement. Forwards and discount factors come from the discount
+curve, fixing times from the surface's day counter.
+*/
+pub struct OptionletStripper1 {
+    term_vol_surface: CapFloorTermVolSurface,
+    index_tenor: Period,
+    switch_strike: Option<Rate>,
+    volatility_type: VolatilityType,
+    displacement: Real,
+    optionlet_fixing_dates: Vec<Date>,
+    optionlet_payment_dates: Vec<Date>,
+    optionlet_fixing_times: Vec<Time>,
+    atm_optionlet_rates: Vec<Rate>,
+    cap_floor_prices: Vec<Vec<Real>>,
+    cap_floor_volatilities: Vec<Vec<Volatility>>,
+    optionlet_prices: Vec<Vec<Real>>,
+    optionlet_volatilities: Vec<Vec<Volatility>>,
+}
+impl OptionletStripper1 {
+    // Constructor
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        term_vol_surface: CapFloorTermVolSurface,
+        index_tenor: Period,
+        discount_curve: &dyn YieldTermStructure,
+        switch_strike: Option<Rate>,
+        accuracy: Real,
+        max_iterations: Size,
+        volatility_type: VolatilityType,
+        displacement: Real,
+    ) -> OptionletStripper1 {
+        let reference_date: Date = term_vol_surface.reference_date();
+        let schedule: Vec<Date> =
+            Schedule::unadjusted(reference_date, term_vol_surface.max_date(), index_tenor)
+                .dates()
+                .to_vec();
+        if schedule.len() < 3 {
+            panic!("index tenor ({index_tenor}) too long for the quoted cap maturities");
+        }
+        let coupons: Vec<IborCoupon> = schedule[1..]
+            .windows(2)
+            .map(|period| {
+                IborCoupon::new(1.0, period[0], period[1], term_vol_surface.day_counter())
+            })
+            .collect();
+        let optionlet_fixing_times: Vec<Time> = coupons
+            .iter()
+            .map(|coupon| {
+                term_vol_surface
+                    .day_counter()
+                    .year_fraction(reference_date, coupon.fixing_date())
+            })
+            .collect();
+        let payment_times: Vec<Time> = coupons
+            .iter()
+            .map(|coupon| {
+                term_vol_surface
+                    .day_counter()
+                    .year_fraction(reference_date, coupon.date())
+            })
+            .collect();
+
+        let n: Size = coupons.len();
+        let strikes: &[Rate] = term_vol_surface.strikes();
+        let mut atm_optionlet_rates: Vec<Rate> = Vec::new();
+        let mut cap_floor_prices: Vec<Vec<Real>> = vec![Vec::new(); n];
+        let mut cap_floor_volatilities: Vec<Vec<Volatility>> = vec![Vec::new(); n];
+        let mut optionlet_prices: Vec<Vec<Real>> = vec![Vec::new(); n];
+        let mut optionlet_volatilities: Vec<Vec<Volatility>> = vec![Vec::new(); n];
+        for (j, strike) in strikes.iter().enumerate() {
+            let option_type: OptionType = match switch_strike {
+                Some(switch_strike) if *strike < switch_strike => OptionType::Put,
+                _ => OptionType::Call,
+            };
+            let mut previous_price: Real = 0.0;
+            for i in 0..n {
+                let volatility: Volatility = term_vol_surface.volatility(payment_times[i], *strike);
+                let leg: Vec<IborCoupon> = coupons[..=i].to_vec();
+                let cap_floor: CapFloor = match option_type {
+                    OptionType::Call => CapFloor::cap(leg, &[*strike]),
+                    OptionType::Put => CapFloor::floor(leg, &[*strike]),
+                };
+                let results: CapFloorResults = calculate(
+                    &cap_floor,
+                    discount_curve,
+                    reference_date,
+                    term_vol_surface.day_counter(),
+                    &|_, _| volatility,
+                    volatility_type,
+                    displacement,
+                );
+                if j == 0 {
+                    atm_optionlet_rates.push(results.forwards[i]);
+                }
+                let price: Real = results.value - previous_price;
+                previous_price = results.value;
+
+                let forward: Rate = results.forwards[i];
+                let discount: DiscountFactor = discount_curve.discount_date(coupons[i].date());
+                let accrual: Real = coupons[i].accrual_period();
+                let fixing_time: Time = optionlet_fixing_times[i];
+                let optionlet_volatility: Volatility = match volatility_type {
+                    VolatilityType::ShiftedLognormal => {
+                        black_formula_implied_std_dev(
+                            option_type,
+                            *strike,
+                            forward,
+                            price / accrual,
+                            discount,
+                            displacement,
+                            None,
+                            accuracy,
+                            max_iterations,
+                        ) / fixing_time.sqrt()
+                    }
+                    VolatilityType::Normal => bachelier_black_formula_implied_vol(
+                        option_type,
+                        *strike,
+                        forward,
+                        fixing_time,
+                        price / accrual,
+                        discount,
+                    ),
+                };
+                cap_floor_prices[i].push(results.value);
+                cap_floor_volatilities[i].push(volatility);
+                optionlet_prices[i].push(price);
+                optionlet_volatilities[i].push(optionlet_volatility);
+            }
+        }
+        OptionletStripper1 {
+            optionlet_fixing_dates: coupons.iter().map(|c| c.fixing_date()).collect(),
+            optionlet_payment_dates: coupons.iter().map(|c| c.date()).collect(),
+            term_vol_surface,
+            index_tenor,
+            switch_strike,
+            volatility_type,
+            displacement,
+            optionlet_fixing_times,
+            atm_optionlet_rates,
+            cap_floor_prices,
+            cap_floor_volatilities,
+            optionlet_prices,
+            optionlet_volatilities,
+        }
+    }
+
+    // Inspectors
+    pub fn term_vol_surface(&self) -> &CapFloorTermVolSurface {
+        &self.term_vol_surface
+    }
+    pub fn index_tenor(&self) -> Period {
+        self.index_tenor
+    }
+    pub fn switch_strike(&self) -> Option<Rate> {
+        self.switch_strike
+    }
+    pub fn volatility_type(&self) -> VolatilityType {
+        self.volatility_type
+    }
+    pub fn displacement(&self) -> Real {
+        self.displacement
+    }
+    pub fn optionlet_fixing_dates(&self) -> &[Date] {
+        &self.optionlet_fixing_dates
+    }
+    pub fn optionlet_payment_dates(&self) -> &[Date] {
+        &self.optionlet_payment_dates
+    }
+    pub fn optionlet_fixing_times(&self) -> &[Time] {
+        &self.optionlet_fixing_times
+    }
+    pub fn atm_optionlet_rates(&self) -> &[Rate] {
+        &self.atm_optionlet_rates
+    }
+    pub fn optionlet_strikes(&self) -> &[Rate] {
+        self.term_vol_surface.strikes()
+    }
+    // [i][j]: cap/floor up to optionlet i at strike j
+    pub fn cap_floor_prices(&self) -> &[Vec<Real>] {
+        &self.cap_floor_prices
+    }
+    pub fn cap_floor_volatilities(&self) -> &[Vec<Volatility>] {
+        &self.cap_floor_volatilities
+    }
+    // [i][j]: optionlet i at strike j
+    pub fn optionlet_prices(&self) -> &[Vec<Real>] {
+        &self.optionlet_prices
+    }
+    pub fn optionlet_volatilities(&self) -> &[Vec<Volatility>] {
+        &self.optionlet_volatilities
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::termstructures::yieldcurves::flat_forward::FlatForward;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::time_unit::TimeUnit;
+
+    fn discount_curve() -> FlatForward {
+        FlatForward::new(
+            Date::new(15, Month::January, 2025),
+            0.03,
+            Box::new(Actual365Fixed::new()),
+        )
+    }
+
+    fn surface(volatilities: &[Vec<Volatility>]) -> CapFloorTermVolSurface {
+        CapFloorTermVolSurface::new(
+            Date::new(15, Month::January, 2025),
+            Box::new(Actual365Fixed::new()),
+            &[
+                Period::new(1, TimeUnit::Years),
+                Period::new(2, TimeUnit::Years),
+                Period::new(5, TimeUnit::Years),
+            ],
+            &[0.02, 0.03, 0.04],
+            volatilities,
+        )
+    }
+
+    #[test]
+    fn flat_quotes_give_flat_optionlet_volatilities() {
+        let cases: [(VolatilityType, Volatility); 2] = [
+            (VolatilityType::ShiftedLognormal, 0.25),
+            (VolatilityType::Normal, 0.008),
+        ];
+        for (volatility_type, volatility) in cases {
+            let stripper: OptionletStripper1 = OptionletStripper1::new(
+                surface(&[
+                    vec![volatility; 3],
+                    vec![volatility; 3],
+                    vec![volatility; 3],
+                ]),
+                Period::new(6, TimeUnit::Months),
+                &discount_curve(),
+                None,
+                1.0e-12,
+                100,
+                volatility_type,
+                0.0,
+            );
+            assert_eq!(stripper.optionlet_fixing_times().len(), 9);
+            for calculated in stripper.optionlet_volatilities().iter().flatten() {
+                assert!(
+                    (calculated - volatility).abs() < 1.0e-9,
+                    "{volatility_type}: {calculated} vs {volatility}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn switch_strike_does_not_change_the_stripped_volatilities() {
+        let quotes: [Vec<Volatility>; 3] = [
+            vec![0.32, 0.30, 0.29],
+            vec![0.30, 0.28, 0.27],
+            vec![0.27, 0.25, 0.24],
+        ];
+        let strip = |switch_strike: Option<Rate>| -> OptionletStripper1 {
+            OptionletStripper1::new(
+                surface(&quotes),
+                Period::new(6, TimeUnit::Months),
+                &discount_curve(),
+                switch_strike,
+                1.0e-12,
+                100,
+                VolatilityType::ShiftedLognormal,
+                0.0,
+            )
+        };
+        let caps: OptionletStripper1 = strip(None);
+        let mixed: OptionletStripper1 = strip(Some(0.03));
+        for (cap, floor) in caps
+            .optionlet_volatilities()
+            .iter()
+            .flatten()
+            .zip(mixed.optionlet_volatilities().iter().flatten())
+        {
+            assert!((cap - floor).abs() < 1.0e-8, "{cap} vs {floor}");
+        }
+        // The first cap is a single caplet: same flat and optionlet volatility
+        assert!((caps.optionlet_volatilities()[0][1] - 0.30).abs() < 1.0e-10);
+    }
+
+    #[test]
+    fn new_panics_on_too_long_index_tenor() {
+        let result = std::panic::catch_unwind(|| {
+            OptionletStripper1::new(
+                surface(&[vec![0.2; 3], vec![0.2; 3], vec![0.2; 3]]),
+                Period::new(5, TimeUnit::Years),
+                &discount_curve(),
+                None,
+                1.0e-12,
+                100,
+                VolatilityType::ShiftedLognormal,
+                0.0,
+            )
+        });
+        assert!(result.is_err(), "expected panic for a 5Y index tenor");
+    }
+}
@@ -0,0 +1,34 @@
+use crate::termstructures::volatility::volatility_type::VolatilityType;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Rate, Real, Time, Volatility};
+
+/*
+Volatility of single caplets and floorlets (QuantLib
+OptionletVolatilityStructure), as a function of the time to the fixing
+and of the strike. Times are measured with the structure's own day
+counter from its reference date.
+
+Volatilities are Black volatilities of the rate shifted by the
+displacement, or normal volatilities, depending on volatility_type.
+*/
+pub trait OptionletVolatilityStructure {
+    fn reference_date(&self) -> Date;
+    fn day_counter(&self) -> &dyn DayCounter;
+    fn volatility(&self, option_time: Time, strike: Rate) -> Volatility;
+
+    fn volatility_type(&self) -> VolatilityType {
+        VolatilityType::ShiftedLognormal
+    }
+    fn displacement(&self) -> Real {
+        0.0
+    }
+    fn time_from_reference(&self, date: Date) -> Time {
+        self.day_counter()
+            .year_fraction(self.reference_date(), date)
+    }
+    fn black_variance(&self, option_time: Time, strike: Rate) -> Real {
+        let volatility: Volatility = self.volatility(option_time, strike);
+        volatility * volatility * option_time
+    }
+}
@@ -0,0 +1,184 @@
+use crate::termstructures::volatility::capfloor::cap_floor_term_vol_surface::detail::interpolate;
+use crate::termstructures::volatility::optionlet::optionlet_stripper1::OptionletStripper1;
+use crate::termstructures::volatility::optionlet::optionlet_volatility_structure::OptionletVolatilityStructure;
+use crate::termstructures::volatility::volatility_type::VolatilityType;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Rate, Real, Time, Volatility};
+
+/*
+Optionlet volatility structure on stripped caplet volatilities (QuantLib
+StrippedOptionletAdapter): linear interpolation in strike at each
+optionlet fixing time, then linear interpolation in time. Volatilities
+are flat outside the stripped strikes and fixing times.
+*/
+pub struct StrippedOptionletAdapter {
+    stripper: OptionletStripper1,
+}
+impl StrippedOptionletAdapter {
+    // Constructor
+    pub fn new(stripper: OptionletStripper1) -> StrippedOptionletAdapter {
+        StrippedOptionletAdapter { stripper }
+    }
+
+    // Inspectors
+    pub fn stripper(&self) -> &OptionletStripper1 {
+        &self.stripper
+    }
+}
+impl OptionletVolatilityStructure for StrippedOptionletAdapter {
+    fn reference_date(&self) -> Date {
+        self.stripper.term_vol_surface().reference_date()
+    }
+    fn day_counter(&self) -> &dyn DayCounter {
+        self.stripper.term_vol_surface().day_counter()
+    }
+    fn volatility(&self, option_time: Time, strike: Rate) -> Volatility {
+        let strikes: &[Rate] = self.stripper.optionlet_strikes();
+        let smiles: Vec<Volatility> = self
+            .stripper
+            .optionlet_volatilities()
+            .iter()
+            .map(|row| interpolate(strikes, row, strike))
+            .collect();
+        interpolate(self.stripper.optionlet_fixing_times(), &smiles, option_time)
+    }
+    fn volatility_type(&self) -> VolatilityType {
+        self.stripper.volatility_type()
+    }
+    fn displacement(&self) -> Real {
+        self.stripper.displacement()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cashflows::ibor_coupon::{IborCoupon, IborLeg};
+    use crate::instruments::cap_floor::CapFloor;
+    use crate::pricingengines::capfloor::bachelier_cap_floor_engine::BachelierCapFloorEngine;
+    use crate::pricingengines::capfloor::black_cap_floor_engine::BlackCapFloorEngine;
+    use crate::pricingengines::capfloor::black_cap_floor_engine::detail::calculate;
+    use crate::termstructures::volatility::capfloor::cap_floor_term_vol_surface::CapFloorTermVolSurface;
+    use crate::termstructures::yieldcurves::flat_forward::testing::flat_rate;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::period::Period;
+    use crate::time::schedule::Schedule;
+    use crate::time::time_unit::TimeUnit;
+    use crate::types::Size;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn stripper(
+        volatilities: &[Vec<Volatility>],
+        volatility_type: VolatilityType,
+    ) -> OptionletStripper1 {
+        let surface: CapFloorTermVolSurface = CapFloorTermVolSurface::new(
+            Date::new(15, Month::January, 2025),
+            Box::new(Actual365Fixed::new()),
+            &[
+                Period::new(1, TimeUnit::Years),
+                Period::new(2, TimeUnit::Years),
+                Period::new(3, TimeUnit::Years),
+                Period::new(5, TimeUnit::Years),
+            ],
+            &[0.02, 0.03, 0.04, 0.05],
+            volatilities,
+        );
+        OptionletStripper1::new(
+            surface,
+            Period::new(6, TimeUnit::Months),
+            flat_rate(reference_date(), 0.03).as_ref(),
+            Some(0.03),
+            1.0e-12,
+            100,
+            volatility_type,
+            0.0,
+        )
+    }
+
+    // Caps on the stripped optionlets, priced on the adapter and on the flat quote
+    fn check_repricing(adapter: StrippedOptionletAdapter, price: &dyn Fn(&CapFloor) -> Real) {
+        let surface: &CapFloorTermVolSurface = adapter.stripper().term_vol_surface();
+        let reference_date: Date = surface.reference_date();
+        for (k, tenor) in surface.option_tenors().iter().enumerate() {
+            let schedule: Vec<Date> = Schedule::unadjusted(
+                reference_date,
+                reference_date + *tenor,
+                Period::new(6, TimeUnit::Months),
+            )
+            .dates()
+            .to_vec();
+            let leg: Vec<IborCoupon> = IborLeg::new(&schedule[1..])
+                .with_notionals(&[1.0e6])
+                .coupons(&Actual365Fixed::new());
+            for (j, strike) in surface.strikes().iter().enumerate() {
+                let cap: CapFloor = CapFloor::cap(leg.clone(), &[*strike]);
+                let flat: Volatility = surface.volatility(surface.option_times()[k], *strike);
+                let expected: Real = calculate(
+                    &cap,
+                    flat_rate(reference_date, 0.03).as_ref(),
+                    reference_date,
+                    surface.day_counter(),
+                    &|_, _| flat,
+                    adapter.volatility_type(),
+                    0.0,
+                )
+                .value;
+                let calculated: Real = price(&cap);
+                assert!(
+                    (calculated - expected).abs() < 1.0e-6,
+                    "tenor {k}, strike {j}: {calculated} vs {expected}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn stripped_volatilities_reprice_the_cap_quotes() {
+        let quotes: [Vec<Volatility>; 4] = [
+            vec![0.34, 0.30, 0.29, 0.29],
+            vec![0.33, 0.29, 0.27, 0.27],
+            vec![0.31, 0.27, 0.25, 0.25],
+            vec![0.28, 0.25, 0.23, 0.22],
+        ];
+        let adapter: StrippedOptionletAdapter =
+            StrippedOptionletAdapter::new(stripper(&quotes, VolatilityType::ShiftedLognormal));
+        let n: Size = adapter.stripper().optionlet_fixing_times().len();
+        assert_eq!(n, 9);
+        let t: Time = adapter.stripper().optionlet_fixing_times()[3];
+        let expected: Volatility = adapter.stripper().optionlet_volatilities()[3][1];
+        assert!((adapter.volatility(t, 0.03) - expected).abs() < 1.0e-15);
+        let engine: BlackCapFloorEngine = BlackCapFloorEngine::new(
+            flat_rate(reference_date(), 0.03),
+            Box::new(StrippedOptionletAdapter::new(stripper(
+                &quotes,
+                VolatilityType::ShiftedLognormal,
+            ))),
+        );
+        check_repricing(adapter, &|cap| engine.calculate(cap).value);
+    }
+
+    #[test]
+    fn stripped_normal_volatilities_reprice_the_cap_quotes() {
+        let quotes: [Vec<Volatility>; 4] = [
+            vec![0.0095, 0.0090, 0.0088, 0.0088],
+            vec![0.0094, 0.0089, 0.0086, 0.0086],
+            vec![0.0092, 0.0087, 0.0084, 0.0083],
+            vec![0.0090, 0.0085, 0.0082, 0.0080],
+        ];
+        let adapter: StrippedOptionletAdapter =
+            StrippedOptionletAdapter::new(stripper(&quotes, VolatilityType::Normal));
+        let engine: BachelierCapFloorEngine = BachelierCapFloorEngine::new(
+            flat_rate(reference_date(), 0.03),
+            Box::new(StrippedOptionletAdapter::new(stripper(
+                &quotes,
+                VolatilityType::Normal,
+            ))),
+        );
+        check_repricing(adapter, &|cap| engine.calculate(cap).value);
+    }
+}
@@ -0,0 +1,470 @@
+use crate::termstructures::volatility::volatility_type::VolatilityType;
+use crate::types::{Rate, Real, Time, Volatility};
+use std::fmt::{Display, Formatter, Result};
+
+// Expansion used for the SABR lognormal volatility (QuantLib SabrApproximationModel)
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SabrApproximationModel {
+    Hagan2002,
+    Obloj2008,
+}
+
+/*
+SABR implied Black volatility (QuantLib sabrVolatility), Hagan et al.,
+"Managing Smile Risk" (2002):
+
+    vol = alpha / D * z / x(z) * d
+    D = (F K)^((1 - beta) / 2) (1 + (1 - beta)^2 ln^2(F/K) / 24 + (1 - beta)^4 ln^4(F/K) / 1920)
+    z = nu / alpha (F K)^((1 - beta) / 2) ln(F/K)
+    x(z) = ln((sqrt(1 - 2 rho z + z^2) + z - rho) / (1 - rho))
+    d = 1 + T ((1 - beta)^2 alpha^2 / (24 (F K)^(1 - beta))
+             + rho beta nu alpha / (4 (F K)^((1 - beta) / 2)) + (2 - 3 rho^2) nu^2 / 24)
+
+Near the money ln(F/K) and z / x(z) are replaced by their expansions.
+Forward and strike must be positive.
+*/
+pub fn sabr_volatility(
+    strike: Rate,
+    forward: Rate,
+    expiry: Time,
+    alpha: Real,
+    beta: Real,
+    nu: Real,
+    rho: Real,
+) -> Volatility {
+    detail::check_inputs(strike, forward, expiry);
+    validate_sabr_parameters(alpha, beta, nu, rho);
+    detail::unsafe_sabr_volatility(strike, forward, expiry, alpha, beta, nu, rho)
+}
+
+/*
+SABR implied Black volatility with the leading term of J. Obloj,
+"Fine-tune your smile: Correction to Hagan et al." (2008):
+
+    vol = nu ln(F/K) / x(z) * d
+    z = nu (F^(1 - beta) - K^(1 - beta)) / (alpha (1 - beta))   (z = nu ln(F/K) / alpha if beta = 1)
+
+with x(z) and d as in Hagan's formula, which it matches at the money.
+It is more accurate away from the money, in particular for beta < 1.
+*/
+pub fn obloj_sabr_volatility(
+    strike: Rate,
+    forward: Rate,
+    expiry: Time,
+    alpha: Real,
+    beta: Real,
+    nu: Real,
+    rho: Real,
+) -> Volatility {
+    detail::check_inputs(strike, forward, expiry);
+    validate_sabr_parameters(alpha, beta, nu, rho);
+    detail::unsafe_obloj_volatility(strike, forward, expiry, alpha, beta, nu, rho)
+}
+
+/*
+SABR implied normal (Bachelier) volatility (QuantLib
+sabrNormalVolatility), Hagan et al. (2002):
+
+    vol_N = alpha (F K)^(beta / 2) E z / x(z) d
+    E = (1 + ln^2(F/K) / 24 + ln^4(F/K) / 1920)
+        / (1 + (1 - beta)^2 ln^2(F/K) / 24 + (1 - beta)^4 ln^4(F/K) / 1920)
+    d = 1 + T (-beta (2 - beta) alpha^2 / (24 (F K)^(1 - beta))
+             + rho beta nu alpha / (4 (F K)^((1 - beta) / 2)) + (2 - 3 rho^2) nu^2 / 24)
+*/
+pub fn sabr_normal_volatility(
+    strike: Rate,
+    forward: Rate,
+    expiry: Time,
+    alpha: Real,
+    beta: Real,
+    nu: Real,
+    rho: Real,
+) -> Volatility {
+    detail::check_inputs(strike, forward, expiry);
+    validate_sabr_parameters(alpha, beta, nu, rho);
+    detail::unsafe_sabr_normal_volatility(strike, forward, expiry, alpha, beta, nu, rho)
+}
+
+// SABR volatility of the displaced forward and strike (QuantLib shiftedSabrVolatility)
+#[allow(clippy::too_many_arguments)]
+pub fn shifted_sabr_volatility(
+    strike: Rate,
+    forward: Rate,
+    expiry: Time,
+    alpha: Real,
+    beta: Real,
+    nu: Real,
+    rho: Real,
+    shift: Real,
+) -> Volatility {
+    sabr_volatility(
+        strike + shift,
+        forward + shift,
+        expiry,
+        alpha,
+        beta,
+        nu,
+        rho,
+    )
+}
+
+// SABR normal volatility of the displaced forward and strike (QuantLib shiftedSabrNormalVolatility)
+#[allow(clippy::too_many_arguments)]
+pub fn shifted_sabr_normal_volatility(
+    strike: Rate,
+    forward: Rate,
+    expiry: Time,
+    alpha: Real,
+    beta: Real,
+    nu: Real,
+    rho: Real,
+    shift: Real,
+) -> Volatility {
+    sabr_normal_volatility(
+        strike + shift,
+        forward + shift,
+        expiry,
+        alpha,
+        beta,
+        nu,
+        rho,
+    )
+}
+
+/*
+Volatility of the given type from the SABR parameters [alpha, beta,
+nu, rho] on the displaced forward and strike: the chosen lognormal
+expansion for shifted lognormal volatilities, Hagan's normal formula
+for normal ones.
+*/
+pub fn sabr_smile_volatility(
+    strike: Rate,
+    forward: Rate,
+    expiry: Time,
+    parameters: &[Real; 4],
+    shift: Real,
+    volatility_type: VolatilityType,
+    model: SabrApproximationModel,
+) -> Volatility {
+    let [alpha, beta, nu, rho]: [Real; 4] = *parameters;
+    let (strike, forward): (Rate, Rate) = (strike + shift, forward + shift);
+    match (volatility_type, model) {
+        (VolatilityType::Normal, _) => {
+            sabr_normal_volatility(strike, forward, expiry, alpha, beta, nu, rho)
+        }
+        (VolatilityType::ShiftedLognormal, SabrApproximationModel::Hagan2002) => {
+            sabr_volatility(strike, forward, expiry, alpha, beta, nu, rho)
+        }
+        (VolatilityType::ShiftedLognormal, SabrApproximationModel::Obloj2008) => {
+            obloj_sabr_volatility(strike, forward, expiry, alpha, beta, nu, rho)
+        }
+    }
+}
+
+// alpha > 0, 0 <= beta <= 1, nu >= 0, -1 < rho < 1
+pub fn validate_sabr_parameters(alpha: Real, beta: Real, nu: Real, rho: Real) {
+    if alpha <= 0.0 {
+        panic!("alpha ({alpha}) must be positive");
+    }
+    if !(0.0..=1.0).contains(&beta) {
+        panic!("beta ({beta}) must be in [0, 1]");
+    }
+    if nu < 0.0 {
+        panic!("nu ({nu}) must be non-negative");
+    }
+    if rho * rho >= 1.0 {
+        panic!("rho ({rho}) must be in (-1, 1)");
+    }
+}
+
+mod detail {
+    use crate::types::{Rate, Real, Time, Volatility};
+
+    pub(super) fn check_inputs(strike: Rate, forward: Rate, expiry: Time) {
+        if strike <= 0.0 {
+            panic!("strike ({strike}) must be positive");
+        }
+        if forward <= 0.0 {
+            panic!("forward ({forward}) must be positive");
+        }
+        if expiry < 0.0 {
+            panic!("expiry time ({expiry}) must be non-negative");
+        }
+    }
+
+    // ln(F/K), expanded when forward and strike are too close
+    fn log_moneyness(strike: Rate, forward: Rate) -> Real {
+        let epsilon: Real = (forward - strike) / strike;
+        if epsilon.abs() > 1.0e-12 {
+            (forward / strike).ln()
+        } else {
+            epsilon - 0.5 * epsilon * epsilon
+        }
+    }
+
+    // z / x(z), expanded for small z
+    fn z_over_x(z: Real, rho: Real) -> Real {
+        if z * z > 10.0 * Real::EPSILON {
+            let b: Real = 1.0 - 2.0 * rho * z + z * z;
+            z / ((b.sqrt() + z - rho) / (1.0 - rho)).ln()
+        } else {
+            1.0 - 0.5 * rho * z - (3.0 * rho * rho - 2.0) * z * z / 12.0
+        }
+    }
+
+    // Time correction d, with the first term scaled by c1 = (1 - beta)^2 or -beta (2 - beta)
+    #[allow(clippy::too_many_arguments)]
+    fn correction(
+        expiry: Time,
+        a: Real,
+        c1: Real,
+        alpha: Real,
+        beta: Real,
+        nu: Real,
+        rho: Real,
+    ) -> Real {
+        1.0 + expiry
+            * (c1 * alpha * alpha / (24.0 * a)
+                + 0.25 * rho * beta * nu * alpha / a.sqrt()
+                + (2.0 - 3.0 * rho * rho) * nu * nu / 24.0)
+    }
+
+    pub(super) fn unsafe_sabr_volatility(
+        strike: Rate,
+        forward: Rate,
+        expiry: Time,
+        alpha: Real,
+        beta: Real,
+        nu: Real,
+        rho: Real,
+    ) -> Volatility {
+        let one_minus_beta: Real = 1.0 - beta;
+        let a: Real = (forward * strike).powf(one_minus_beta);
+        let log_m: Real = log_moneyness(strike, forward);
+        let z: Real = (nu / alpha) * a.sqrt() * log_m;
+        let c: Real = one_minus_beta * one_minus_beta * log_m * log_m;
+        let d: Real = a.sqrt() * (1.0 + c / 24.0 + c * c / 1920.0);
+        let c1: Real = one_minus_beta * one_minus_beta;
+        alpha / d * z_over_x(z, rho) * correction(expiry, a, c1, alpha, beta, nu, rho)
+    }
+
+    pub(super) fn unsafe_obloj_volatility(
+        strike: Rate,
+        forward: Rate,
+        expiry: Time,
+        alpha: Real,
+        beta: Real,
+        nu: Real,
+        rho: Real,
+    ) -> Volatility {
+        let one_minus_beta: Real = 1.0 - beta;
+        let a: Real = (forward * strike).powf(one_minus_beta);
+        let log_m: Real = log_moneyness(strike, forward);
+        // nu ln(F/K) / z, which tends to alpha / (F K)^((1 - beta) / 2) at the money;
+        // F^(1 - beta) - K^(1 - beta) = K^(1 - beta) (exp((1 - beta) ln(F/K)) - 1) avoids cancellation
+        let (z, leading): (Real, Real) = if log_m == 0.0 {
+            (0.0, alpha / a.sqrt())
+        } else if one_minus_beta > 0.0 {
+            let difference: Real = strike.powf(one_minus_beta) * (one_minus_beta * log_m).exp_m1();
+            (
+                nu * difference / (alpha * one_minus_beta),
+                alpha * one_minus_beta * log_m / difference,
+            )
+        } else {
+            (nu * log_m / alpha, alpha)
+        };
+        let c1: Real = one_minus_beta * one_minus_beta;
+        leading * z_over_x(z, rho) * correction(expiry, a, c1, alpha, beta, nu, rho)
+    }
+
+    pub(super) fn unsafe_sabr_normal_volatility(
+        strike: Rate,
+        forward: Rate,
+        expiry: Time,
+        alpha: Real,
+        beta: Real,
+        nu: Real,
+        rho: Real,
+    ) -> Volatility {
+        let one_minus_beta: Real = 1.0 - beta;
+        let a: Real = (forward * strike).powf(one_minus_beta);
+        let log_m: Real = log_moneyness(strike, forward);
+        let z: Real = (nu / alpha) * a.sqrt() * log_m;
+        let c: Real = one_minus_beta * one_minus_beta * log_m * log_m;
+        let d: Real = log_m * log_m;
+        let e: Real = (1.0 + d / 24.0 + d * d / 1920.0) / (1.0 + c / 24.0 + c * c / 1920.0);
+        let c1: Real = -beta * (2.0 - beta);
+        alpha
+            * (forward * strike).powf(0.5 * beta)
+            * e
+            * z_over_x(z, rho)
+            * correction(expiry, a, c1, alpha, beta, nu, rho)
+    }
+}
+
+// Traits
+impl Display for SabrApproximationModel {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let model: &'static str = match self {
+            SabrApproximationModel::Hagan2002 => "Hagan2002",
+            SabrApproximationModel::Obloj2008 => "Obloj2008",
+        };
+        write!(f, "{model}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lognormal_limit_gives_alpha() {
+        // beta = 1, nu = 0: geometric Brownian motion with volatility alpha
+        for strike in [0.01, 0.03, 0.1] {
+            let volatility: Volatility = sabr_volatility(strike, 0.03, 5.0, 0.2, 1.0, 0.0, -0.3);
+            assert!((volatility - 0.2).abs() < 1.0e-15, "{}", volatility);
+        }
+    }
+
+    #[test]
+    fn at_the_money_volatility_is_continuous() {
+        let (forward, expiry, alpha, beta, nu, rho): (Rate, Time, Real, Real, Real, Real) =
+            (0.04, 2.0, 0.03, 0.5, 0.4, -0.25);
+        // vol_ATM = alpha / F^(1 - beta) (1 + T (...))
+        let atm: Volatility = alpha / forward.powf(1.0 - beta)
+            * (1.0
+                + expiry
+                    * (0.25 * alpha * alpha / (24.0 * forward)
+                        + 0.25 * rho * beta * nu * alpha / forward.sqrt()
+                        + (2.0 - 3.0 * rho * rho) * nu * nu / 24.0));
+        let at: Volatility = sabr_volatility(forward, forward, expiry, alpha, beta, nu, rho);
+        assert!((at - atm).abs() < 1.0e-15);
+        for bump in [1.0e-13, 1.0e-9, 1.0e-6] {
+            let near: Volatility = sabr_volatility(
+                forward * (1.0 + bump),
+                forward,
+                expiry,
+                alpha,
+                beta,
+                nu,
+                rho,
+            );
+            assert!((near - atm).abs() < 0.1 * bump + 1.0e-15, "{}", bump);
+        }
+    }
+
+    #[test]
+    fn shift_displaces_forward_and_strike() {
+        let shifted: Volatility =
+            shifted_sabr_volatility(-0.002, -0.001, 3.0, 0.05, 0.5, 0.3, 0.1, 0.02);
+        let displaced: Volatility = sabr_volatility(0.018, 0.019, 3.0, 0.05, 0.5, 0.3, 0.1);
+        assert!((shifted - displaced).abs() < 1.0e-12);
+        // Negative skew: low strikes get higher volatilities
+        let low: Volatility = sabr_volatility(0.02, 0.03, 1.0, 0.04, 0.5, 0.3, -0.4);
+        let high: Volatility = sabr_volatility(0.04, 0.03, 1.0, 0.04, 0.5, 0.3, -0.4);
+        assert!(low > high);
+    }
+
+    #[test]
+    fn normal_volatility_limits() {
+        // beta = 0, nu = 0: arithmetic Brownian motion with volatility alpha
+        for strike in [0.01, 0.03, 0.1] {
+            let volatility: Volatility =
+                sabr_normal_volatility(strike, 0.03, 5.0, 0.008, 0.0, 0.0, 0.2);
+            assert!((volatility - 0.008).abs() < 1.0e-15, "{}", volatility);
+        }
+        // Lognormal and normal volatilities are consistent at the money
+        let lognormal: Volatility = sabr_volatility(0.03, 0.03, 1.0, 0.03, 0.5, 0.3, -0.2);
+        let normal: Volatility = sabr_normal_volatility(0.03, 0.03, 1.0, 0.03, 0.5, 0.3, -0.2);
+        // vol_N ~ F vol_B (1 - vol_B^2 T / 24) at the money
+        let expected: Volatility = 0.03 * lognormal * (1.0 - lognormal * lognormal / 24.0);
+        assert!((normal - expected).abs() < 1.0e-3 * normal);
+    }
+
+    #[test]
+    fn obloj_matches_hagan_at_the_money() {
+        let parameters: [(Real, Real, Real, Real); 3] = [
+            (0.03, 0.5, 0.4, -0.25),
+            (0.2, 1.0, 0.5, 0.3),
+            (0.006, 0.0, 0.3, 0.1),
+        ];
+        for (alpha, beta, nu, rho) in parameters {
+            let hagan: Volatility = sabr_volatility(0.04, 0.04, 2.0, alpha, beta, nu, rho);
+            let obloj: Volatility = obloj_sabr_volatility(0.04, 0.04, 2.0, alpha, beta, nu, rho);
+            assert!((hagan - obloj).abs() < 1.0e-15);
+            // Away from the money the two expansions differ, but only slightly
+            let hagan: Volatility = sabr_volatility(0.05, 0.04, 2.0, alpha, beta, nu, rho);
+            let obloj: Volatility = obloj_sabr_volatility(0.05, 0.04, 2.0, alpha, beta, nu, rho);
+            assert!((hagan / obloj - 1.0).abs() < 1.0e-2, "{hagan} vs {obloj}");
+            let near: Volatility =
+                obloj_sabr_volatility(0.04 * (1.0 + 1.0e-10), 0.04, 2.0, alpha, beta, nu, rho);
+            assert!((near - sabr_volatility(0.04, 0.04, 2.0, alpha, beta, nu, rho)).abs() < 1.0e-9);
+        }
+        // nu = 0, beta < 1: exact CEV leading term alpha (1 - beta) ln(F/K) / (F^(1 - beta) - K^(1 - beta))
+        let obloj: Volatility = obloj_sabr_volatility(0.02, 0.04, 0.0, 0.03, 0.5, 0.0, 0.0);
+        let expected: Volatility = 0.03 * 0.5 * 2.0_f64.ln() / (0.04_f64.sqrt() - 0.02_f64.sqrt());
+        assert!((obloj - expected).abs() < 1.0e-15);
+    }
+
+    #[test]
+    fn smile_volatility_dispatches_on_type_and_model() {
+        let parameters: [Real; 4] = [0.03, 0.5, 0.4, -0.2];
+        let cases: [(VolatilityType, SabrApproximationModel, Volatility); 3] = [
+            (
+                VolatilityType::ShiftedLognormal,
+                SabrApproximationModel::Hagan2002,
+                sabr_volatility(0.025, 0.03, 1.0, 0.03, 0.5, 0.4, -0.2),
+            ),
+            (
+                VolatilityType::ShiftedLognormal,
+                SabrApproximationModel::Obloj2008,
+                obloj_sabr_volatility(0.025, 0.03, 1.0, 0.03, 0.5, 0.4, -0.2),
+            ),
+            (
+                VolatilityType::Normal,
+                SabrApproximationModel::Hagan2002,
+                shifted_sabr_normal_volatility(0.015, 0.02, 1.0, 0.03, 0.5, 0.4, -0.2, 0.01),
+            ),
+        ];
+        for (volatility_type, model, expected) in cases {
+            let shift: Real = if volatility_type == VolatilityType::Normal {
+                0.01
+            } else {
+                0.0
+            };
+            let strike: Rate = 0.025 - shift;
+            let forward: Rate = 0.03 - shift;
+            let calculated: Volatility = sabr_smile_volatility(
+                strike,
+                forward,
+                1.0,
+                &parameters,
+                shift,
+                volatility_type,
+                model,
+            );
+            assert!(
+                (calculated - expected).abs() < 1.0e-15,
+                "{volatility_type} {model}"
+            );
+        }
+    }
+
+    #[test]
+    fn panics_on_invalid_parameters() {
+        let cases: [(Rate, Real, Real, Real, Real, &str); 6] = [
+            (-0.01, 0.03, 0.5, 0.3, 0.0, "negative strike"),
+            (0.03, 0.0, 0.5, 0.3, 0.0, "null alpha"),
+            (0.03, 0.03, 1.5, 0.3, 0.0, "beta above 1"),
+            (0.03, 0.03, 0.5, -0.3, 0.0, "negative nu"),
+            (0.03, 0.03, 0.5, 0.3, 1.0, "rho = 1"),
+            (0.03, 0.03, 0.5, 0.3, -1.5, "rho below -1"),
+        ];
+        for (strike, alpha, beta, nu, rho, label) in cases {
+            let result = std::panic::catch_unwind(|| {
+                sabr_volatility(strike, 0.03, 1.0, alpha, beta, nu, rho)
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
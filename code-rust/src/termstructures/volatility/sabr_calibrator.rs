@@ -0,0 +1,274 @@
+use crate::math::optimization::constraint::Constraint;
+use crate::math::optimization::end_criteria::{EndCriteria, EndCriteriaType};
+use crate::math::optimization::levenberg_marquardt::{LevenbergMarquardt, OptimizationResult};
+use crate::termstructures::volatility::sabr::{
+    SabrApproximationModel, sabr_smile_volatility, validate_sabr_parameters,
+};
+use crate::termstructures::volatility::volatility_type::VolatilityType;
+use crate::types::{Rate, Real, Size, Time, Volatility};
+
+// Outcome of a SABR calibration: parameters [alpha, beta, nu, rho] and fit errors
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SabrCalibrationResult {
+    pub parameters: [Real; 4],
+    pub rms_error: Volatility,
+    pub max_error: Volatility,
+    pub end_criteria: EndCriteriaType,
+}
+
+/*
+Least-squares fit of a SABR smile to volatility quotes for one expiry
+(QuantLib SABRInterpolation).
+
+Parameters are ordered [alpha, beta, nu, rho]; fixed ones keep their
+guess and Levenberg-Marquardt runs on the free ones, with alpha and nu
+kept positive, beta in [0, 1] and rho in (-1, 1). Quotes are of the
+given volatility type on the forward and strikes displaced by shift.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SabrCalibrator {
+    guess: [Real; 4],
+    fixed: [bool; 4],
+    shift: Real,
+    volatility_type: VolatilityType,
+    model: SabrApproximationModel,
+}
+impl SabrCalibrator {
+    // Constructor
+    pub fn new(
+        guess: [Real; 4],
+        fixed: [bool; 4],
+        shift: Real,
+        volatility_type: VolatilityType,
+        model: SabrApproximationModel,
+    ) -> SabrCalibrator {
+        let [alpha, beta, nu, rho]: [Real; 4] = guess;
+        validate_sabr_parameters(alpha, beta, nu, rho);
+        if fixed.iter().all(|f| *f) {
+            panic!("all SABR parameters fixed: nothing to calibrate");
+        }
+        SabrCalibrator {
+            guess,
+            fixed,
+            shift,
+            volatility_type,
+            model,
+        }
+    }
+
+    pub fn calibrate(
+        &self,
+        strikes: &[Rate],
+        volatilities: &[Volatility],
+        forward: Rate,
+        expiry: Time,
+    ) -> SabrCalibrationResult {
+        if strikes.len() != volatilities.len() {
+            panic!(
+                "{} strikes given for {} volatilities",
+                strikes.len(),
+                volatilities.len()
+            );
+        }
+        let free: Vec<Size> = (0..4).filter(|i| !self.fixed[*i]).collect();
+        if strikes.len() < free.len() {
+            panic!(
+                "{} quotes given for {} free parameters",
+                strikes.len(),
+                free.len()
+            );
+        }
+        if let Some(strike) = strikes.iter().find(|k| **k + self.shift <= 0.0) {
+            panic!("strike ({}) below -shift ({})", strike, -self.shift);
+        }
+        if forward + self.shift <= 0.0 {
+            panic!("forward ({}) below -shift ({})", forward, -self.shift);
+        }
+
+        let errors = |x: &[Real]| -> Vec<Real> {
+            let parameters: [Real; 4] = self.parameters(x, &free);
+            strikes
+                .iter()
+                .zip(volatilities)
+                .map(|(strike, volatility)| {
+                    sabr_smile_volatility(
+                        *strike,
+                        forward,
+                        expiry,
+                        &parameters,
+                        self.shift,
+                        self.volatility_type,
+                        self.model,
+                    ) - volatility
+                })
+                .collect()
+        };
+        let constraints: Vec<Constraint> = free.iter().map(|i| detail::constraint(*i)).collect();
+        let initial: Vec<Real> = free.iter().map(|i| self.guess[*i]).collect();
+        let result: OptimizationResult = LevenbergMarquardt::default().minimize(
+            errors,
+            &constraints,
+            &initial,
+            &EndCriteria::new(1000, 1.0e-14, 1.0e-16, 1.0e-16),
+        );
+
+        let residuals: Vec<Real> = errors(&result.parameters);
+        SabrCalibrationResult {
+            parameters: self.parameters(&result.parameters, &free),
+            rms_error: (result.value / strikes.len() as Real).sqrt(),
+            max_error: residuals.iter().fold(0.0, |m: Real, e| m.max(e.abs())),
+            end_criteria: result.end_criteria,
+        }
+    }
+
+    // Full parameter set from the free ones
+    fn parameters(&self, x: &[Real], free: &[Size]) -> [Real; 4] {
+        let mut parameters: [Real; 4] = self.guess;
+        for (value, i) in x.iter().zip(free) {
+            parameters[*i] = *value;
+        }
+        parameters
+    }
+}
+
+mod detail {
+    use crate::math::optimization::constraint::Constraint;
+    use crate::types::Size;
+
+    // Constraint on the i-th of [alpha, beta, nu, rho]
+    pub(super) fn constraint(i: Size) -> Constraint {
+        match i {
+            1 => Constraint::Boundary {
+                low: 0.0,
+                high: 1.0,
+            },
+            3 => Constraint::Boundary {
+                low: -0.9999,
+                high: 0.9999,
+            },
+            _ => Constraint::Positive,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FORWARD: Rate = 0.03;
+    const EXPIRY: Time = 2.0;
+    const PARAMETERS: [Real; 4] = [0.02, 0.5, 0.4, -0.3];
+
+    fn strikes() -> Vec<Rate> {
+        (0..9).map(|k| 0.01 + 0.005 * k as Real).collect()
+    }
+
+    fn quotes(volatility_type: VolatilityType, model: SabrApproximationModel) -> Vec<Volatility> {
+        strikes()
+            .iter()
+            .map(|k| {
+                sabr_smile_volatility(
+                    *k,
+                    FORWARD,
+                    EXPIRY,
+                    &PARAMETERS,
+                    0.0,
+                    volatility_type,
+                    model,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn calibration_recovers_the_parameters() {
+        let cases: [(VolatilityType, SabrApproximationModel); 3] = [
+            (
+                VolatilityType::ShiftedLognormal,
+                SabrApproximationModel::Hagan2002,
+            ),
+            (
+                VolatilityType::ShiftedLognormal,
+                SabrApproximationModel::Obloj2008,
+            ),
+            (VolatilityType::Normal, SabrApproximationModel::Hagan2002),
+        ];
+        for (volatility_type, model) in cases {
+            let calibrator: SabrCalibrator = SabrCalibrator::new(
+                [0.03, 0.5, 0.3, 0.0],
+                [false, true, false, false],
+                0.0,
+                volatility_type,
+                model,
+            );
+            let result: SabrCalibrationResult =
+                calibrator.calibrate(&strikes(), &quotes(volatility_type, model), FORWARD, EXPIRY);
+            assert!(result.rms_error < 1.0e-8, "{volatility_type} {model}");
+            assert!(result.max_error < 1.0e-8, "{volatility_type} {model}");
+            for (calculated, expected) in result.parameters.iter().zip(PARAMETERS) {
+                assert!(
+                    (calculated - expected).abs() < 1.0e-5,
+                    "{volatility_type} {model}: {calculated} vs {expected}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn fixed_parameters_keep_their_guess() {
+        let calibrator: SabrCalibrator = SabrCalibrator::new(
+            [0.03, 0.7, 0.3, 0.0],
+            [false, true, false, true],
+            0.0,
+            VolatilityType::ShiftedLognormal,
+            SabrApproximationModel::Hagan2002,
+        );
+        let result: SabrCalibrationResult = calibrator.calibrate(
+            &strikes(),
+            &quotes(
+                VolatilityType::ShiftedLognormal,
+                SabrApproximationModel::Hagan2002,
+            ),
+            FORWARD,
+            EXPIRY,
+        );
+        assert_eq!(result.parameters[1], 0.7);
+        assert_eq!(result.parameters[3], 0.0);
+        // The wrong beta and rho leave a residual error
+        assert!(result.rms_error > 1.0e-6);
+        assert!(result.max_error >= result.rms_error);
+    }
+
+    #[test]
+    fn calibration_panics_on_invalid_input() {
+        let all_fixed = std::panic::catch_unwind(|| {
+            SabrCalibrator::new(
+                PARAMETERS,
+                [true; 4],
+                0.0,
+                VolatilityType::Normal,
+                SabrApproximationModel::Hagan2002,
+            )
+        });
+        assert!(all_fixed.is_err(), "expected panic for all fixed");
+
+        let cases: [(Vec<Rate>, Vec<Volatility>, &str); 3] = [
+            (vec![0.02, 0.03], vec![0.2], "missing volatility"),
+            (vec![0.02, 0.03], vec![0.2, 0.2], "too few quotes"),
+            (vec![-0.01, 0.02, 0.03], vec![0.2; 3], "negative strike"),
+        ];
+        for (strikes, volatilities, label) in cases {
+            let result = std::panic::catch_unwind(|| {
+                SabrCalibrator::new(
+                    [0.03, 0.5, 0.3, 0.0],
+                    [false, true, false, false],
+                    0.0,
+                    VolatilityType::ShiftedLognormal,
+                    SabrApproximationModel::Hagan2002,
+                )
+                .calibrate(&strikes, &volatilities, FORWARD, EXPIRY)
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
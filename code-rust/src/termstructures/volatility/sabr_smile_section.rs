@@ -0,0 +1,152 @@
+use crate::termstructures::volatility::flat_smile_section::detail::exercise_time;
+use crate::termstructures::volatility::sabr::{
+    SabrApproximationModel, sabr_smile_volatility, validate_sabr_parameters,
+};
+use crate::termstructures::volatility::smile_section::SmileSection;
+use crate::termstructures::volatility::volatility_type::VolatilityType;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Rate, Real, Time, Volatility};
+
+/*
+SABR smile for a single expiry (QuantLib SabrSmileSection) with
+parameters [alpha, beta, nu, rho] on the forward displaced by shift.
+Shifted lognormal volatilities use the chosen expansion, normal ones
+Hagan's normal formula. The ATM level is the forward.
+*/
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SabrSmileSection {
+    exercise_date: Date,
+    exercise_time: Time,
+    forward: Rate,
+    parameters: [Real; 4],
+    shift: Real,
+    volatility_type: VolatilityType,
+    model: SabrApproximationModel,
+}
+impl SabrSmileSection {
+    // Constructor
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        exercise_date: Date,
+        reference_date: Date,
+        day_counter: &dyn DayCounter,
+        forward: Rate,
+        parameters: [Real; 4],
+        shift: Real,
+        volatility_type: VolatilityType,
+        model: SabrApproximationModel,
+    ) -> SabrSmileSection {
+        let [alpha, beta, nu, rho]: [Real; 4] = parameters;
+        validate_sabr_parameters(alpha, beta, nu, rho);
+        if forward + shift <= 0.0 {
+            panic!("forward ({}) must be above -shift ({})", forward, -shift);
+        }
+        SabrSmileSection {
+            exercise_date,
+            exercise_time: exercise_time(exercise_date, reference_date, day_counter),
+            forward,
+            parameters,
+            shift,
+            volatility_type,
+            model,
+        }
+    }
+
+    // Inspectors
+    pub fn alpha(&self) -> Real {
+        self.parameters[0]
+    }
+    pub fn beta(&self) -> Real {
+        self.parameters[1]
+    }
+    pub fn nu(&self) -> Real {
+        self.parameters[2]
+    }
+    pub fn rho(&self) -> Real {
+        self.parameters[3]
+    }
+    pub fn model(&self) -> SabrApproximationModel {
+        self.model
+    }
+}
+impl SmileSection for SabrSmileSection {
+    fn exercise_date(&self) -> Date {
+        self.exercise_date
+    }
+    fn exercise_time(&self) -> Time {
+        self.exercise_time
+    }
+    fn volatility(&self, strike: Rate) -> Volatility {
+        sabr_smile_volatility(
+            strike,
+            self.forward,
+            self.exercise_time,
+            &self.parameters,
+            self.shift,
+            self.volatility_type,
+            self.model,
+        )
+    }
+    fn atm_level(&self) -> Option<Rate> {
+        Some(self.forward)
+    }
+    fn volatility_type(&self) -> VolatilityType {
+        self.volatility_type
+    }
+    fn shift(&self) -> Real {
+        self.shift
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::termstructures::volatility::sabr::shifted_sabr_volatility;
+    use crate::termstructures::volatility::smile_section::butterfly_arbitrage_strikes;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+
+    fn section(forward: Rate, parameters: [Real; 4], shift: Real) -> SabrSmileSection {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        SabrSmileSection::new(
+            reference_date + 730,
+            reference_date,
+            &Actual365Fixed::new(),
+            forward,
+            parameters,
+            shift,
+            VolatilityType::ShiftedLognormal,
+            SabrApproximationModel::Hagan2002,
+        )
+    }
+
+    #[test]
+    fn volatilities_follow_the_sabr_formula() {
+        let section: SabrSmileSection = section(-0.002, [0.02, 0.5, 0.3, -0.2], 0.02);
+        for strike in [-0.01, -0.002, 0.01] {
+            let expected: Volatility =
+                shifted_sabr_volatility(strike, -0.002, 2.0, 0.02, 0.5, 0.3, -0.2, 0.02);
+            assert_eq!(section.volatility(strike), expected);
+        }
+        assert_eq!(section.atm_level(), Some(-0.002));
+        assert_eq!((section.alpha(), section.beta()), (0.02, 0.5));
+        assert_eq!((section.nu(), section.rho()), (0.3, -0.2));
+        // Moderate smiles are free of butterfly arbitrage
+        let strikes: Vec<Rate> = (0..30).map(|k| -0.012 + 0.001 * k as Real).collect();
+        assert!(butterfly_arbitrage_strikes(&section, &strikes).is_empty());
+    }
+
+    #[test]
+    fn new_panics_on_invalid_input() {
+        let cases: [(Rate, [Real; 4], &str); 3] = [
+            (0.03, [0.0, 0.5, 0.3, 0.0], "null alpha"),
+            (0.03, [0.03, 0.5, 0.3, 1.0], "rho = 1"),
+            (-0.03, [0.03, 0.5, 0.3, 0.0], "forward below -shift"),
+        ];
+        for (forward, parameters, label) in cases {
+            let result = std::panic::catch_unwind(|| section(forward, parameters, 0.02));
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use crate::option::OptionType;
+use crate::pricingengines::black_formula::{bachelier_black_formula, black_formula};
+use crate::termstructures::volatility::volatility_type::VolatilityType;
+use crate::time::date::Date;
+use crate::types::{DiscountFactor, Rate, Real, Time, Volatility};
+
+/*
+Volatility smile for a single expiry (QuantLib SmileSection): the
+volatility as a function of the strike for options exercised on
+exercise_date, exercise_time years after the reference date.
+
+Option prices and densities need the ATM level (the forward) of the
+section; they are undiscounted unless a discount factor is given.
+*/
+pub trait SmileSection {
+    fn exercise_date(&self) -> Date;
+    fn exercise_time(&self) -> Time;
+    fn volatility(&self, strike: Rate) -> Volatility;
+
+    fn atm_level(&self) -> Option<Rate> {
+        None
+    }
+    fn volatility_type(&self) -> VolatilityType {
+        VolatilityType::ShiftedLognormal
+    }
+    fn shift(&self) -> Real {
+        0.0
+    }
+    fn variance(&self, strike: Rate) -> Real {
+        let volatility: Volatility = self.volatility(strike);
+        volatility * volatility * self.exercise_time()
+    }
+    fn option_price(
+        &self,
+        strike: Rate,
+        option_type: OptionType,
+        discount: DiscountFactor,
+    ) -> Real {
+        let forward: Rate = self
+            .atm_level()
+            .unwrap_or_else(|| panic!("smile section has no ATM level"));
+        let std_dev: Real = self.variance(strike).sqrt();
+        match self.volatility_type() {
+            VolatilityType::ShiftedLognormal => black_formula(
+                option_type,
+                strike,
+                forward,
+                std_dev,
+                discount,
+                self.shift(),
+            ),
+            VolatilityType::Normal => {
+                bachelier_black_formula(option_type, strike, forward, std_dev, discount)
+            }
+        }
+    }
+    // Risk-neutral density of the underlying at strike: second strike derivative of the call price
+    fn density(&self, strike: Rate, discount: DiscountFactor, gap: Real) -> Real {
+        let call = |k: Rate| -> Real { self.option_price(k, OptionType::Call, discount) };
+        (call(strike + gap) - 2.0 * call(strike) + call(strike - gap)) / (gap * gap * discount)
+    }
+}
+
+/*
+Strikes where the smile allows a butterfly arbitrage: for consecutive
+given strikes K_{i-1} < K_i < K_{i+1} the undiscounted call prices must
+be convex,
+    C(K_{i-1}) (K_{i+1} - K_i) + C(K_{i+1}) (K_i - K_{i-1}) >= C(K_i) (K_{i+1} - K_{i-1})
+and K_i is reported when they are not.
+*/
+pub fn butterfly_arbitrage_strikes(section: &dyn SmileSection, strikes: &[Rate]) -> Vec<Rate> {
+    if strikes.windows(2).any(|pair| pair[1] <= pair[0]) {
+        panic!("strikes must be sorted in increasing order");
+    }
+    let calls: Vec<Real> = strikes
+        .iter()
+        .map(|strike| section.option_price(*strike, OptionType::Call, 1.0))
+        .collect();
+    (1..strikes.len().saturating_sub(1))
+        .filter(|&i| {
+            let (low, mid, high): (Rate, Rate, Rate) = (strikes[i - 1], strikes[i], strikes[i + 1]);
+            let butterfly: Real =
+                calls[i - 1] * (high - mid) + calls[i + 1] * (mid - low) - calls[i] * (high - low);
+            butterfly < -detail::TOLERANCE * (high - low)
+        })
+        .map(|i| strikes[i])
+        .collect()
+}
+
+/*
+Strikes where two smiles allow a calendar arbitrage: the total variance
+of the later expiry must not be below that of the earlier one at the
+same strike. Both sections must quote the same type of volatility.
+*/
+pub fn calendar_arbitrage_strikes(
+    earlier: &dyn SmileSection,
+    later: &dyn SmileSection,
+    strikes: &[Rate],
+) -> Vec<Rate> {
+    if earlier.volatility_type() != later.volatility_type() {
+        panic!("smile sections quote different volatility types");
+    }
+    if later.exercise_time() < earlier.exercise_time() {
+        panic!(
+            "later exercise time ({}) before the earlier one ({})",
+            later.exercise_time(),
+            earlier.exercise_time()
+        );
+    }
+    strikes
+        .iter()
+        .filter(|strike| later.variance(**strike) < earlier.variance(**strike) - detail::TOLERANCE)
+        .copied()
+        .collect()
+}
+
+mod detail {
+    use crate::types::Real;
+
+    // Price and variance differences below this are rounding noise
+    pub(super) const TOLERANCE: Real = 1.0e-12;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::distributions::normal_distribution::NormalDistribution;
+    use crate::termstructures::volatility::flat_smile_section::FlatSmileSection;
+    use crate::termstructures::volatility::interpolated_smile_section::InterpolatedSmileSection;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+
+    fn reference_date() -> Date {
+        Date::new(15, Month::January, 2025)
+    }
+
+    fn flat(days: i32, volatility: Volatility) -> FlatSmileSection {
+        FlatSmileSection::new(
+            reference_date() + days,
+            reference_date(),
+            &Actual365Fixed::new(),
+            volatility,
+            Some(0.03),
+            VolatilityType::ShiftedLognormal,
+            0.0,
+        )
+    }
+
+    #[test]
+    fn density_of_a_flat_smile_is_lognormal() {
+        let section: FlatSmileSection = flat(730, 0.25);
+        let std_dev: Real = 0.25 * 2.0_f64.sqrt();
+        for strike in [0.02, 0.03, 0.045] {
+            let d2: Real = (0.03_f64 / strike).ln() / std_dev - 0.5 * std_dev;
+            let expected: Real = NormalDistribution::default().value(d2) / (strike * std_dev);
+            let calculated: Real = section.density(strike, 0.9, 1.0e-5);
+            assert!(
+                (calculated / expected - 1.0).abs() < 1.0e-4,
+                "{strike}: {calculated} vs {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn butterfly_arbitrage_is_detected() {
+        let strikes: [Rate; 5] = [0.01, 0.02, 0.03, 0.04, 0.05];
+        assert!(butterfly_arbitrage_strikes(&flat(365, 0.2), &strikes).is_empty());
+        let spiked: InterpolatedSmileSection = InterpolatedSmileSection::new(
+            reference_date() + 365,
+            reference_date(),
+            &Actual365Fixed::new(),
+            &strikes,
+            &[0.2, 0.2, 0.6, 0.2, 0.2],
+            Some(0.03),
+            VolatilityType::ShiftedLognormal,
+            0.0,
+        );
+        assert_eq!(butterfly_arbitrage_strikes(&spiked, &strikes), vec![0.03]);
+    }
+
+    #[test]
+    fn calendar_arbitrage_is_detected() {
+        let strikes: [Rate; 3] = [0.02, 0.03, 0.04];
+        assert!(calendar_arbitrage_strikes(&flat(365, 0.2), &flat(730, 0.15), &strikes).is_empty());
+        assert_eq!(
+            calendar_arbitrage_strikes(&flat(365, 0.2), &flat(730, 0.14), &strikes),
+            strikes.to_vec()
+        );
+        let result = std::panic::catch_unwind(|| {
+            calendar_arbitrage_strikes(&flat(730, 0.2), &flat(365, 0.2), &strikes)
+        });
+        assert!(result.is_err(), "expected panic for swapped expiries");
+    }
+
+    #[test]
+    fn option_price_panics_without_atm_level() {
+        let result = std::panic::catch_unwind(|| {
+            FlatSmileSection::new(
+                reference_date() + 365,
+                reference_date(),
+                &Actual365Fixed::new(),
+                0.2,
+                None,
+                VolatilityType::ShiftedLognormal,
+                0.0,
+            )
+            .option_price(0.03, OptionType::Call, 1.0)
+        });
+        assert!(result.is_err(), "expected panic without ATM level");
+    }
+}
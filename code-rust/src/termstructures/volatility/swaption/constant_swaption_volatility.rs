@@ -0,0 +1,92 @@
+use crate::termstructures::volatility::swaption::swaption_volatility_structure::SwaptionVolatilityStructure;
+use crate::termstructures::volatility::volatility_type::VolatilityType;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Rate, Real, Time, Volatility};
+
+// Same volatility for all exercise times, swap lengths and strikes (QuantLib ConstantSwaptionVolatility)
+pub struct ConstantSwaptionVolatility {
+    reference_date: Date,
+    day_counter: Box<dyn DayCounter>,
+    volatility: Volatility,
+    volatility_type: VolatilityType,
+    shift: Real,
+}
+impl ConstantSwaptionVolatility {
+    // Constructor
+    pub fn new(
+        reference_date: Date,
+        day_counter: Box<dyn DayCounter>,
+        volatility: Volatility,
+        volatility_type: VolatilityType,
+        shift: Real,
+    ) -> ConstantSwaptionVolatility {
+        if volatility < 0.0 {
+            panic!("negative volatility ({volatility}) not allowed");
+        }
+        ConstantSwaptionVolatility {
+            reference_date,
+            day_counter,
+            volatility,
+            volatility_type,
+            shift,
+        }
+    }
+}
+impl SwaptionVolatilityStructure for ConstantSwaptionVolatility {
+    fn reference_date(&self) -> Date {
+        self.reference_date
+    }
+    fn day_counter(&self) -> &dyn DayCounter {
+        self.day_counter.as_ref()
+    }
+    fn volatility(&self, _option_time: Time, _swap_length: Time, _strike: Rate) -> Volatility {
+        self.volatility
+    }
+    fn volatility_type(&self) -> VolatilityType {
+        self.volatility_type
+    }
+    fn shift(&self, _option_time: Time, _swap_length: Time) -> Real {
+        self.shift
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+
+    #[test]
+    fn volatility_is_flat() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let volatility: ConstantSwaptionVolatility = ConstantSwaptionVolatility::new(
+            reference_date,
+            Box::new(Actual365Fixed::new()),
+            0.2,
+            VolatilityType::ShiftedLognormal,
+            0.01,
+        );
+        assert_eq!(volatility.volatility(1.0, 5.0, 0.03), 0.2);
+        assert!((volatility.black_variance(2.0, 10.0, 0.05) - 0.08).abs() < 1.0e-15);
+        assert_eq!(volatility.shift(1.0, 5.0), 0.01);
+        let start: Date = Date::new(15, Month::January, 2026);
+        let end: Date = Date::new(15, Month::January, 2031);
+        assert_eq!(volatility.swap_length(start, end), 5.0);
+        assert_eq!(volatility.swap_length(start, start + 182), 0.5);
+    }
+
+    #[test]
+    fn new_panics_on_negative_volatility() {
+        let result = std::panic::catch_unwind(|| {
+            ConstantSwaptionVolatility::new(
+                Date::new(15, Month::January, 2025),
+                Box::new(Actual365Fixed::new()),
+                -0.2,
+                VolatilityType::ShiftedLognormal,
+                0.0,
+            )
+        });
+        assert!(result.is_err(), "expected panic for negative volatility");
+    }
+}
@@ -0,0 +1,411 @@
+use crate::indexes::ibor_index::IborIndex;
+use crate::instruments::swap::SwapType;
+use crate::instruments::vanilla_swap::VanillaSwap;
+use crate::pricingengines::swaption::black_swaption_engine::detail::forward_and_annuity;
+use crate::termstructures::volatility::sabr::{SabrApproximationModel, shifted_sabr_volatility};
+use crate::termstructures::volatility::sabr_calibrator::{SabrCalibrationResult, SabrCalibrator};
+use crate::termstructures::volatility::swaption::swaption_volatility_matrix::SwaptionVolatilityMatrix;
+use crate::termstructures::volatility::swaption::swaption_volatility_matrix::detail::bilinear;
+use crate::termstructures::volatility::swaption::swaption_volatility_structure::SwaptionVolatilityStructure;
+use crate::termstructures::volatility::volatility_type::VolatilityType;
+use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::time::period::Period;
+use crate::time::schedule::Schedule;
+use crate::types::{Rate, Real, Size, Spread, Time, Volatility};
+use std::rc::Rc;
+
+/*
+Swaption volatility cube with SABR smiles (QuantLib SwaptionVolCube1).
+
+On each (option tenor, swap tenor) node of the ATM matrix the smile is
+quoted as volatility spreads over the ATM volatility at strikes
+forward + strike_spreads[k]: vol_spreads[i][j][k]. A shifted SABR smile
+with fixed beta is calibrated to each node by Levenberg-Marquardt on
+(alpha, nu, rho), using the shift of the ATM matrix, which allows
+negative forwards and strikes down to -shift.
+
+Node forwards are the forward swap rates on the discount curve, with
+fixed payments every fixed_leg_tenor against coupons on index. Between nodes the SABR parameters, forwards and shifts
+are interpolated bilinearly in option time and swap length, flat
+outside the grid.
+*/
+pub struct SabrSwaptionVolatilityCube {
+    atm_volatility: SwaptionVolatilityMatrix,
+    strike_spreads: Vec<Spread>,
+    beta: Real,
+    forwards: Vec<Vec<Rate>>,
+    alphas: Vec<Vec<Real>>,
+    nus: Vec<Vec<Real>>,
+    rhos: Vec<Vec<Real>>,
+    calibration_errors: Vec<Vec<Volatility>>,
+}
+impl SabrSwaptionVolatilityCube {
+    // Constructor
+    pub fn new(
+        atm_volatility: SwaptionVolatilityMatrix,
+        strike_spreads: &[Spread],
+        vol_spreads: &[Vec<Vec<Volatility>>],
+        discount_curve: &dyn YieldTermStructure,
+        index: Rc<IborIndex>,
+        fixed_leg_tenor: Period,
+        beta: Real,
+    ) -> SabrSwaptionVolatilityCube {
+        if atm_volatility.volatility_type() != VolatilityType::ShiftedLognormal {
+            panic!("SABR cube requires shifted lognormal ATM volatilities");
+        }
+        if strike_spreads.len() < 3 {
+            panic!("at least three strike spreads required to calibrate alpha, nu and rho");
+        }
+        if strike_spreads.windows(2).any(|pair| pair[1] <= pair[0]) {
+            panic!("strike spreads must be sorted in increasing order");
+        }
+        if !(0.0..=1.0).contains(&beta) {
+            panic!("beta ({beta}) must be in [0, 1]");
+        }
+        let (n_options, n_swaps): (Size, Size) = (
+            atm_volatility.option_tenors().len(),
+            atm_volatility.swap_tenors().len(),
+        );
+        if vol_spreads.len() != n_options
+            || vol_spreads.iter().any(|row| {
+                row.len() != n_swaps
+                    || row
+                        .iter()
+                        .any(|spreads| spreads.len() != strike_spreads.len())
+            })
+        {
+            panic!(
+                "vol spreads must be {} x {} x {}",
+                n_options,
+                n_swaps,
+                strike_spreads.len()
+            );
+        }
+
+        let reference_date: Date = atm_volatility.reference_date();
+        let day_counter: &dyn DayCounter = atm_volatility.day_counter();
+        let mut forwards: Vec<Vec<Rate>> = vec![vec![0.0; n_swaps]; n_options];
+        let mut alphas: Vec<Vec<Real>> = vec![vec![0.0; n_swaps]; n_options];
+        let mut nus: Vec<Vec<Real>> = vec![vec![0.0; n_swaps]; n_options];
+        let mut rhos: Vec<Vec<Real>> = vec![vec![0.0; n_swaps]; n_options];
+        let mut calibration_errors: Vec<Vec<Volatility>> = vec![vec![0.0; n_swaps]; n_options];
+        for i in 0..n_options {
+            let start: Date = reference_date + atm_volatility.option_tenors()[i];
+            let expiry: Time = atm_volatility.option_times()[i];
+            for j in 0..n_swaps {
+                let schedule: Vec<Date> = Schedule::unadjusted(
+                    start,
+                    start + atm_volatility.swap_tenors()[j],
+                    fixed_leg_tenor,
+                )
+                .dates()
+                .to_vec();
+                let swap: VanillaSwap = VanillaSwap::new(
+                    SwapType::Payer,
+                    1.0,
+                    &schedule,
+                    0.0,
+                    day_counter,
+                    &schedule,
+                    index.clone(),
+                    day_counter,
+                );
+                let forward: Rate = forward_and_annuity(&swap, discount_curve).0;
+                let shift: Real = atm_volatility.shifts()[i][j];
+                let atm: Volatility = atm_volatility.volatilities()[i][j];
+                let quotes: Vec<(Rate, Volatility)> = strike_spreads
+                    .iter()
+                    .zip(&vol_spreads[i][j])
+                    .map(|(spread, vol_spread)| (forward + spread, atm + vol_spread))
+                    .collect();
+                if quotes[0].0 + shift <= 0.0 {
+                    panic!(
+                        "strike ({}) below -shift ({}) at node ({}, {})",
+                        quotes[0].0, -shift, i, j
+                    );
+                }
+
+                let (strikes, volatilities): (Vec<Rate>, Vec<Volatility>) =
+                    quotes.into_iter().unzip();
+                let result: SabrCalibrationResult = SabrCalibrator::new(
+                    [atm * (forward + shift).powf(1.0 - beta), beta, 0.4, 0.0],
+                    [false, true, false, false],
+                    shift,
+                    VolatilityType::ShiftedLognormal,
+                    SabrApproximationModel::Hagan2002,
+                )
+                .calibrate(&strikes, &volatilities, forward, expiry);
+                forwards[i][j] = forward;
+                alphas[i][j] = result.parameters[0];
+                nus[i][j] = result.parameters[2];
+                rhos[i][j] = result.parameters[3];
+                calibration_errors[i][j] = result.rms_error;
+            }
+        }
+        SabrSwaptionVolatilityCube {
+            atm_volatility,
+            strike_spreads: strike_spreads.to_vec(),
+            beta,
+            forwards,
+            alphas,
+            nus,
+            rhos,
+            calibration_errors,
+        }
+    }
+
+    // Inspectors
+    pub fn atm_volatility(&self) -> &SwaptionVolatilityMatrix {
+        &self.atm_volatility
+    }
+    pub fn strike_spreads(&self) -> &[Spread] {
+        &self.strike_spreads
+    }
+    pub fn beta(&self) -> Real {
+        self.beta
+    }
+    // Forward swap rate at node (i, j)
+    pub fn forwards(&self) -> &[Vec<Rate>] {
+        &self.forwards
+    }
+    // [alpha, beta, nu, rho] calibrated at node (i, j)
+    pub fn sabr_parameters(&self, i: Size, j: Size) -> [Real; 4] {
+        [
+            self.alphas[i][j],
+            self.beta,
+            self.nus[i][j],
+            self.rhos[i][j],
+        ]
+    }
+    // Root mean square volatility error of the calibration at node (i, j)
+    pub fn calibration_errors(&self) -> &[Vec<Volatility>] {
+        &self.calibration_errors
+    }
+
+    fn interpolate(&self, values: &[Vec<Real>], option_time: Time, swap_length: Time) -> Real {
+        bilinear(
+            self.atm_volatility.option_times(),
+            self.atm_volatility.swap_lengths(),
+            values,
+            option_time,
+            swap_length,
+        )
+    }
+}
+impl SwaptionVolatilityStructure for SabrSwaptionVolatilityCube {
+    fn reference_date(&self) -> Date {
+        self.atm_volatility.reference_date()
+    }
+    fn day_counter(&self) -> &dyn DayCounter {
+        self.atm_volatility.day_counter()
+    }
+    fn volatility(&self, option_time: Time, swap_length: Time, strike: Rate) -> Volatility {
+        shifted_sabr_volatility(
+            strike,
+            self.interpolate(&self.forwards, option_time, swap_length),
+            option_time,
+            self.interpolate(&self.alphas, option_time, swap_length),
+            self.beta,
+            self.interpolate(&self.nus, option_time, swap_length),
+            self.interpolate(&self.rhos, option_time, swap_length),
+            self.shift(option_time, swap_length),
+        )
+    }
+    fn shift(&self, option_time: Time, swap_length: Time) -> Real {
+        self.atm_volatility.shift(option_time, swap_length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::termstructures::yieldcurves::flat_forward::FlatForward;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::time_unit::TimeUnit;
+
+    const SPREADS: [Spread; 5] = [-0.01, -0.005, 0.0, 0.005, 0.01];
+
+    fn discount_curve(rate: Rate) -> FlatForward {
+        FlatForward::new(
+            Date::new(15, Month::January, 2025),
+            rate,
+            Box::new(Actual365Fixed::new()),
+        )
+    }
+
+    fn index() -> Rc<IborIndex> {
+        Rc::new(IborIndex::euribor(Period::new(1, TimeUnit::Years)))
+    }
+
+    fn tenors() -> ([Period; 2], [Period; 2]) {
+        (
+            [
+                Period::new(1, TimeUnit::Years),
+                Period::new(5, TimeUnit::Years),
+            ],
+            [
+                Period::new(2, TimeUnit::Years),
+                Period::new(10, TimeUnit::Years),
+            ],
+        )
+    }
+
+    fn matrix(volatilities: &[Vec<Volatility>], shift: Real) -> SwaptionVolatilityMatrix {
+        let (options, swaps): ([Period; 2], [Period; 2]) = tenors();
+        SwaptionVolatilityMatrix::new(
+            Date::new(15, Month::January, 2025),
+            Box::new(Actual365Fixed::new()),
+            &options,
+            &swaps,
+            volatilities,
+            VolatilityType::ShiftedLognormal,
+            &[vec![shift; 2], vec![shift; 2]],
+        )
+    }
+
+    // Cube quoted from known SABR smiles [alpha, nu, rho] at each node
+    fn cube(rate: Rate, shift: Real, smiles: &[[[Real; 3]; 2]; 2]) -> SabrSwaptionVolatilityCube {
+        let flat: SwaptionVolatilityMatrix = matrix(&[vec![0.2; 2], vec![0.2; 2]], shift);
+        let reference_date: Date = flat.reference_date();
+        let option_times: &[Time] = flat.option_times();
+        let forward_at = |i: Size, j: Size| -> Rate {
+            let start: Date = reference_date + flat.option_tenors()[i];
+            let schedule: Vec<Date> = Schedule::unadjusted(
+                start,
+                start + flat.swap_tenors()[j],
+                Period::new(1, TimeUnit::Years),
+            )
+            .dates()
+            .to_vec();
+            let swap: VanillaSwap = VanillaSwap::new(
+                SwapType::Payer,
+                1.0,
+                &schedule,
+                0.0,
+                &Actual365Fixed::new(),
+                &schedule,
+                index(),
+                &Actual365Fixed::new(),
+            );
+            forward_and_annuity(&swap, &discount_curve(rate)).0
+        };
+        let mut atm: Vec<Vec<Volatility>> = vec![vec![0.0; 2]; 2];
+        let mut vol_spreads: Vec<Vec<Vec<Volatility>>> = vec![vec![Vec::new(); 2]; 2];
+        for i in 0..2 {
+            for j in 0..2 {
+                let forward: Rate = forward_at(i, j);
+                let [alpha, nu, rho]: [Real; 3] = smiles[i][j];
+                let vol = |strike: Rate| -> Volatility {
+                    shifted_sabr_volatility(
+                        strike,
+                        forward,
+                        option_times[i],
+                        alpha,
+                        0.5,
+                        nu,
+                        rho,
+                        shift,
+                    )
+                };
+                atm[i][j] = vol(forward);
+                vol_spreads[i][j] = SPREADS
+                    .iter()
+                    .map(|spread| vol(forward + spread) - atm[i][j])
+                    .collect();
+            }
+        }
+        SabrSwaptionVolatilityCube::new(
+            matrix(&atm, shift),
+            &SPREADS,
+            &vol_spreads,
+            &discount_curve(rate),
+            index(),
+            Period::new(1, TimeUnit::Years),
+            0.5,
+        )
+    }
+
+    #[test]
+    fn calibration_recovers_sabr_smiles() {
+        let smiles: [[[Real; 3]; 2]; 2] = [
+            [[0.04, 0.5, -0.3], [0.035, 0.4, -0.2]],
+            [[0.03, 0.3, -0.1], [0.03, 0.25, 0.1]],
+        ];
+        let cube: SabrSwaptionVolatilityCube = cube(0.03, 0.0, &smiles);
+        for (i, row) in smiles.iter().enumerate() {
+            for (j, smile) in row.iter().enumerate() {
+                let [alpha, beta, nu, rho]: [Real; 4] = cube.sabr_parameters(i, j);
+                assert_eq!(beta, 0.5);
+                for (calculated, expected) in [alpha, nu, rho].iter().zip(smile) {
+                    assert!(
+                        (calculated - expected).abs() < 1.0e-5,
+                        "node ({i}, {j}): {calculated} vs {expected}"
+                    );
+                }
+                assert!(cube.calibration_errors()[i][j] < 1.0e-8);
+            }
+        }
+        // Off-quote strike at a node, and flat extrapolation in time
+        let option_time: Time = cube.atm_volatility().option_times()[1];
+        let forward: Rate = cube.forwards()[1][0];
+        let expected: Volatility = shifted_sabr_volatility(
+            forward + 0.0075,
+            forward,
+            option_time,
+            0.03,
+            0.5,
+            0.3,
+            -0.1,
+            0.0,
+        );
+        assert!((cube.volatility(option_time, 2.0, forward + 0.0075) - expected).abs() < 1.0e-7);
+        let beyond: Volatility = cube.volatility(30.0, 2.0, forward);
+        assert!(beyond > 0.0);
+    }
+
+    #[test]
+    fn shifted_smiles_handle_negative_rates() {
+        let smiles: [[[Real; 3]; 2]; 2] = [[[0.02, 0.4, 0.1]; 2]; 2];
+        let cube: SabrSwaptionVolatilityCube = cube(-0.004, 0.02, &smiles);
+        assert!(cube.forwards()[0][0] < 0.0);
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!(cube.calibration_errors()[i][j] < 1.0e-8);
+                let [alpha, _, nu, rho]: [Real; 4] = cube.sabr_parameters(i, j);
+                assert!((alpha - 0.02).abs() < 1.0e-5);
+                assert!((nu - 0.4).abs() < 1.0e-4);
+                assert!((rho - 0.1).abs() < 1.0e-4);
+            }
+        }
+        let option_time: Time = cube.atm_volatility().option_times()[0];
+        assert_eq!(cube.shift(option_time, 5.0), 0.02);
+        assert!(cube.volatility(option_time, 5.0, -0.01) > 0.0);
+    }
+
+    #[test]
+    fn new_panics_on_invalid_quotes() {
+        let atm: Vec<Vec<Volatility>> = vec![vec![0.2; 2]; 2];
+        let cases: [(Vec<Spread>, Real, Rate, &str); 3] = [
+            (vec![-0.01, 0.01], 0.0, 0.03, "two strike spreads"),
+            (vec![-0.01, 0.0, 0.01], 0.0, 0.005, "strikes below -shift"),
+            (vec![0.01, 0.0, -0.01], 0.0, 0.03, "unsorted spreads"),
+        ];
+        for (spreads, shift, rate, label) in cases {
+            let result = std::panic::catch_unwind(|| {
+                SabrSwaptionVolatilityCube::new(
+                    matrix(&atm, shift),
+                    &spreads,
+                    &vec![vec![vec![0.0; spreads.len()]; 2]; 2],
+                    &discount_curve(rate),
+                    index(),
+                    Period::new(1, TimeUnit::Years),
+                    0.5,
+                )
+            });
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
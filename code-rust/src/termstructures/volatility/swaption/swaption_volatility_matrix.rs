@@ -0,0 +1,214 @@
+use crate::termstructures::volatility::swaption::swaption_volatility_structure::SwaptionVolatilityStructure;
+use crate::termstructures::volatility::volatility_type::VolatilityType;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::time::period::Period;
+use crate::types::{Rate, Real, Time, Volatility};
+
+/*
+At-the-money swaption volatilities quoted by option tenor and swap
+tenor (QuantLib SwaptionVolatilityMatrix). volatilities[i][j] is the
+quote for option_tenors[i] and swap_tenors[j]; shifts, for shifted
+lognormal quotes, have the same layout and default to zero when empty.
+
+Volatilities and shifts are interpolated bilinearly in option time and
+swap length, flat outside the quoted grid; the strike is ignored.
+*/
+pub struct SwaptionVolatilityMatrix {
+    reference_date: Date,
+    day_counter: Box<dyn DayCounter>,
+    option_tenors: Vec<Period>,
+    option_times: Vec<Time>,
+    swap_tenors: Vec<Period>,
+    swap_lengths: Vec<Time>,
+    volatilities: Vec<Vec<Volatility>>,
+    shifts: Vec<Vec<Real>>,
+    volatility_type: VolatilityType,
+}
+impl SwaptionVolatilityMatrix {
+    // Constructor
+    pub fn new(
+        reference_date: Date,
+        day_counter: Box<dyn DayCounter>,
+        option_tenors: &[Period],
+        swap_tenors: &[Period],
+        volatilities: &[Vec<Volatility>],
+        volatility_type: VolatilityType,
+        shifts: &[Vec<Real>],
+    ) -> SwaptionVolatilityMatrix {
+        if option_tenors.is_empty() || swap_tenors.is_empty() {
+            panic!("at least one option tenor and one swap tenor required");
+        }
+        let shifts: Vec<Vec<Real>> = if shifts.is_empty() {
+            vec![vec![0.0; swap_tenors.len()]; option_tenors.len()]
+        } else {
+            shifts.to_vec()
+        };
+        for (matrix, name) in [(volatilities, "volatility"), (&shifts[..], "shift")] {
+            if matrix.len() != option_tenors.len()
+                || matrix.iter().any(|row| row.len() != swap_tenors.len())
+            {
+                panic!(
+                    "{} matrix must be {} x {}",
+                    name,
+                    option_tenors.len(),
+                    swap_tenors.len()
+                );
+            }
+        }
+        if volatilities.iter().flatten().any(|vol| *vol < 0.0) {
+            panic!("negative volatilities not allowed");
+        }
+        let option_times: Vec<Time> = option_tenors
+            .iter()
+            .map(|tenor| day_counter.year_fraction(reference_date, reference_date + *tenor))
+            .collect();
+        let swap_lengths: Vec<Time> = swap_tenors.iter().map(|tenor| tenor.years()).collect();
+        for (times, name) in [(&option_times, "option"), (&swap_lengths, "swap")] {
+            if times[0] <= 0.0 || times.windows(2).any(|pair| pair[1] <= pair[0]) {
+                panic!("{name} tenors must be positive and sorted in increasing order");
+            }
+        }
+        SwaptionVolatilityMatrix {
+            reference_date,
+            day_counter,
+            option_tenors: option_tenors.to_vec(),
+            option_times,
+            swap_tenors: swap_tenors.to_vec(),
+            swap_lengths,
+            volatilities: volatilities.to_vec(),
+            shifts,
+            volatility_type,
+        }
+    }
+
+    // Inspectors
+    pub fn option_tenors(&self) -> &[Period] {
+        &self.option_tenors
+    }
+    pub fn option_times(&self) -> &[Time] {
+        &self.option_times
+    }
+    pub fn swap_tenors(&self) -> &[Period] {
+        &self.swap_tenors
+    }
+    pub fn swap_lengths(&self) -> &[Time] {
+        &self.swap_lengths
+    }
+    pub fn volatilities(&self) -> &[Vec<Volatility>] {
+        &self.volatilities
+    }
+    pub fn shifts(&self) -> &[Vec<Real>] {
+        &self.shifts
+    }
+}
+impl SwaptionVolatilityStructure for SwaptionVolatilityMatrix {
+    fn reference_date(&self) -> Date {
+        self.reference_date
+    }
+    fn day_counter(&self) -> &dyn DayCounter {
+        self.day_counter.as_ref()
+    }
+    fn volatility(&self, option_time: Time, swap_length: Time, _strike: Rate) -> Volatility {
+        detail::bilinear(
+            &self.option_times,
+            &self.swap_lengths,
+            &self.volatilities,
+            option_time,
+            swap_length,
+        )
+    }
+    fn volatility_type(&self) -> VolatilityType {
+        self.volatility_type
+    }
+    fn shift(&self, option_time: Time, swap_length: Time) -> Real {
+        detail::bilinear(
+            &self.option_times,
+            &self.swap_lengths,
+            &self.shifts,
+            option_time,
+            swap_length,
+        )
+    }
+}
+
+pub(crate) mod detail {
+    use crate::termstructures::volatility::capfloor::cap_floor_term_vol_surface::detail::interpolate;
+    use crate::types::Real;
+
+    // Bilinear interpolation of z[i][j] given on x[i] x y[j], flat outside the grid
+    pub(crate) fn bilinear(x: &[Real], y: &[Real], z: &[Vec<Real>], xv: Real, yv: Real) -> Real {
+        let column: Vec<Real> = z.iter().map(|row| interpolate(y, row, yv)).collect();
+        interpolate(x, &column, xv)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::time_unit::TimeUnit;
+
+    fn matrix(volatilities: &[Vec<Volatility>], shifts: &[Vec<Real>]) -> SwaptionVolatilityMatrix {
+        SwaptionVolatilityMatrix::new(
+            Date::new(15, Month::January, 2025),
+            Box::new(Actual365Fixed::new()),
+            &[
+                Period::new(1, TimeUnit::Years),
+                Period::new(5, TimeUnit::Years),
+            ],
+            &[
+                Period::new(2, TimeUnit::Years),
+                Period::new(10, TimeUnit::Years),
+            ],
+            volatilities,
+            VolatilityType::ShiftedLognormal,
+            shifts,
+        )
+    }
+
+    #[test]
+    fn quotes_are_interpolated_bilinearly() {
+        let matrix: SwaptionVolatilityMatrix = matrix(
+            &[vec![0.30, 0.22], vec![0.26, 0.18]],
+            &[vec![0.01, 0.02], vec![0.01, 0.02]],
+        );
+        let t1: Time = matrix.option_times()[0];
+        let t5: Time = matrix.option_times()[1];
+        let cases: [(Time, Time, Volatility, Real); 5] = [
+            (t1, 2.0, 0.30, 0.01),
+            (t5, 10.0, 0.18, 0.02),
+            (t1, 6.0, 0.26, 0.015),
+            (0.5 * (t1 + t5), 6.0, 0.24, 0.015),
+            (20.0, 30.0, 0.18, 0.02),
+        ];
+        for (option_time, swap_length, volatility, shift) in cases {
+            let calculated: Volatility = matrix.volatility(option_time, swap_length, 0.03);
+            assert!(
+                (calculated - volatility).abs() < 1.0e-14,
+                "{option_time} x {swap_length}: {calculated} vs {volatility}"
+            );
+            assert!((matrix.shift(option_time, swap_length) - shift).abs() < 1.0e-15);
+        }
+        assert_eq!(matrix.swap_lengths(), &[2.0, 10.0]);
+    }
+
+    #[test]
+    #[allow(clippy::type_complexity)]
+    fn new_panics_on_invalid_quotes() {
+        let cases: [(Vec<Vec<Volatility>>, Vec<Vec<Real>>, &str); 3] = [
+            (vec![vec![0.2, 0.2]], vec![], "missing row"),
+            (
+                vec![vec![0.2, 0.2]; 2],
+                vec![vec![0.0]; 2],
+                "short shift rows",
+            ),
+            (vec![vec![0.2, -0.2]; 2], vec![], "negative volatility"),
+        ];
+        for (volatilities, shifts, label) in cases {
+            let result = std::panic::catch_unwind(|| matrix(&volatilities, &shifts));
+            assert!(result.is_err(), "expected panic for {label}");
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::termstructures::volatility::volatility_type::VolatilityType;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{Rate, Real, Time, Volatility};
+
+/*
+Volatility of European swaptions (QuantLib SwaptionVolatilityStructure)
+as a function of the time to exercise, the length of the underlying
+swap in years and the strike. Times are measured with the structure's
+own day counter from its reference date.
+
+Shifted lognormal volatilities apply to the swap rate plus shift, which
+may depend on the option time and swap length.
+*/
+pub trait SwaptionVolatilityStructure {
+    fn reference_date(&self) -> Date;
+    fn day_counter(&self) -> &dyn DayCounter;
+    fn volatility(&self, option_time: Time, swap_length: Time, strike: Rate) -> Volatility;
+
+    fn volatility_type(&self) -> VolatilityType {
+        VolatilityType::ShiftedLognormal
+    }
+    fn shift(&self, _option_time: Time, _swap_length: Time) -> Real {
+        0.0
+    }
+    fn time_from_reference(&self, date: Date) -> Time {
+        self.day_counter()
+            .year_fraction(self.reference_date(), date)
+    }
+    // Swap length in years, rounded to whole months as in QuantLib
+    fn swap_length(&self, start: Date, end: Date) -> Time {
+        ((end - start) as Real / 365.25 * 12.0).round() / 12.0
+    }
+    fn black_variance(&self, option_time: Time, swap_length: Time, strike: Rate) -> Real {
+        let volatility: Volatility = self.volatility(option_time, swap_length, strike);
+        volatility * volatility * option_time
+    }
+}
@@ -0,0 +1,35 @@
+use std::fmt::{Display, Formatter, Result};
+
+// QuantLib VolatilityType: Black volatility of the displaced rate or normal (basis point) volatility
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum VolatilityType {
+    ShiftedLognormal,
+    Normal,
+}
+
+// Traits
+impl Display for VolatilityType {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let volatility_type: &'static str = match self {
+            VolatilityType::ShiftedLognormal => "ShiftedLognormal",
+            VolatilityType::Normal => "Normal",
+        };
+        write!(f, "{volatility_type}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_volatility_types() {
+        let cases: [(VolatilityType, &str); 2] = [
+            (VolatilityType::ShiftedLognormal, "ShiftedLognormal"),
+            (VolatilityType::Normal, "Normal"),
+        ];
+        for (volatility_type, expected) in cases {
+            assert_eq!(format!("{volatility_type}"), expected);
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use crate::termstructures::yieldcurves::yield_term_structure::{
+    YieldTermStructure, detail::check_time,
+};
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{DiscountFactor, Rate, Time};
+
+// Constant continuously-compounded rate r, so that P(0, t) = exp(-r t) (QuantLib FlatForward)
+pub struct FlatForward {
+    reference_date: Date,
+    rate: Rate,
+    day_counter: Box<dyn DayCounter>,
+}
+impl FlatForward {
+    // Constructor
+    pub fn new(reference_date: Date, rate: Rate, day_counter: Box<dyn DayCounter>) -> FlatForward {
+        FlatForward {
+            reference_date,
+            rate,
+            day_counter,
+        }
+    }
+
+    // Inspectors
+    pub fn rate(&self) -> Rate {
+        self.rate
+    }
+}
+impl YieldTermStructure for FlatForward {
+    fn reference_date(&self) -> Date {
+        self.reference_date
+    }
+    fn day_counter(&self) -> &dyn DayCounter {
+        self.day_counter.as_ref()
+    }
+    fn discount(&self, t: Time) -> DiscountFactor {
+        check_time(t);
+        (-self.rate * t).exp()
+    }
+    fn zero_rate(&self, t: Time) -> Rate {
+        check_time(t);
+        self.rate
+    }
+    fn forward_rate(&self, t1: Time, t2: Time) -> Rate {
+        if t2 <= t1 {
+            panic!("final time ({t2}) must be later than initial time ({t1})");
+        }
+        check_time(t1);
+        self.rate
+    }
+    fn instantaneous_forward(&self, t: Time) -> Rate {
+        check_time(t);
+        self.rate
+    }
+}
+
+// Fixture shared by the tests across the crate
+#[cfg(test)]
+pub(crate) mod testing {
+    use super::FlatForward;
+    use crate::termstructures::yieldcurves::yield_term_structure::YieldTermStructure;
+    use crate::time::date::Date;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::types::Rate;
+    use std::rc::Rc;
+
+    // Flat continuously compounded rate on Actual/365 (Fixed)
+    pub(crate) fn flat_rate(reference_date: Date, rate: Rate) -> Rc<dyn YieldTermStructure> {
+        Rc::new(FlatForward::new(
+            reference_date,
+            rate,
+            Box::new(Actual365Fixed::new()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual360::Actual360;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+
+    // Curve with the default rates of the trait, computed from its discount factors
+    struct Discounts(FlatForward);
+    impl YieldTermStructure for Discounts {
+        fn reference_date(&self) -> Date {
+            self.0.reference_date()
+        }
+        fn day_counter(&self) -> &dyn DayCounter {
+            self.0.day_counter()
+        }
+        fn discount(&self, t: Time) -> DiscountFactor {
+            self.0.discount(t)
+        }
+    }
+
+    #[test]
+    fn discount_factors_and_rates_of_a_flat_curve() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let curve: FlatForward =
+            FlatForward::new(reference_date, 0.03, Box::new(Actual365Fixed::new()));
+        let t: Time = curve.time_from_reference(reference_date + 730);
+        assert_eq!(t, 2.0);
+        assert_eq!(curve.discount(t), (-0.06_f64).exp());
+        assert_eq!(curve.discount_date(reference_date + 730), (-0.06_f64).exp());
+        assert_eq!(curve.discount(0.0), 1.0);
+        assert_eq!(curve.zero_rate(t), 0.03);
+        assert_eq!(curve.forward_rate(1.0, 3.0), 0.03);
+        assert_eq!(curve.instantaneous_forward(5.0), 0.03);
+
+        // Times follow the curve's day counter
+        let curve: FlatForward = FlatForward::new(reference_date, 0.03, Box::new(Actual360::new()));
+        assert_eq!(curve.time_from_reference(reference_date + 720), 2.0);
+    }
+
+    #[test]
+    fn default_rates_are_implied_by_the_discount_factors() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let curve: Discounts = Discounts(FlatForward::new(
+            reference_date,
+            0.03,
+            Box::new(Actual365Fixed::new()),
+        ));
+        let cases: [(Rate, &str); 4] = [
+            (curve.zero_rate(2.0), "zero rate"),
+            (curve.zero_rate(0.0), "zero rate at the reference date"),
+            (curve.forward_rate(1.0, 3.0), "forward rate"),
+            (curve.instantaneous_forward(5.0), "instantaneous forward"),
+        ];
+        for (rate, label) in cases {
+            assert!((rate - 0.03).abs() < 1.0e-12, "{label} = {rate}");
+        }
+    }
+
+    #[test]
+    fn invalid_input_panics() {
+        let reference_date: Date = Date::new(15, Month::January, 2025);
+        let negative_time = std::panic::catch_unwind(|| {
+            FlatForward::new(reference_date, 0.03, Box::new(Actual365Fixed::new())).discount(-1.0)
+        });
+        assert!(negative_time.is_err(), "expected panic for negative time");
+        let swapped_times = std::panic::catch_unwind(|| {
+            FlatForward::new(reference_date, 0.03, Box::new(Actual365Fixed::new()))
+                .forward_rate(2.0, 1.0)
+        });
+        assert!(swapped_times.is_err(), "expected panic for swapped times");
+    }
+}
@@ -0,0 +1,57 @@
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::{DiscountFactor, Rate, Time};
+
+/*
+Interest-rate term structure (QuantLib YieldTermStructure): the
+discount factor P(0, t) for a payment t years after the reference date,
+with times measured by the structure's own day counter.
+
+Zero and forward rates are continuously compounded:
+    z(t) = -ln P(0, t) / t
+    f(t1, t2) = ln(P(0, t1) / P(0, t2)) / (t2 - t1)
+and the instantaneous forward f(t) is taken over [t, t + dt], dt = 1e-4,
+unless a curve knows it exactly. Curves are shared between the models,
+helpers and engines using them as Rc<dyn YieldTermStructure>, which
+plays the part of QuantLib's Handle.
+*/
+pub trait YieldTermStructure {
+    fn reference_date(&self) -> Date;
+    fn day_counter(&self) -> &dyn DayCounter;
+    fn discount(&self, t: Time) -> DiscountFactor;
+
+    fn discount_date(&self, date: Date) -> DiscountFactor {
+        self.discount(self.time_from_reference(date))
+    }
+    fn zero_rate(&self, t: Time) -> Rate {
+        // Short end given by the forward over the first dt
+        let t: Time = t.max(detail::DT);
+        -self.discount(t).ln() / t
+    }
+    fn forward_rate(&self, t1: Time, t2: Time) -> Rate {
+        if t2 <= t1 {
+            panic!("final time ({t2}) must be later than initial time ({t1})");
+        }
+        (self.discount(t1) / self.discount(t2)).ln() / (t2 - t1)
+    }
+    fn instantaneous_forward(&self, t: Time) -> Rate {
+        self.forward_rate(t, t + detail::DT)
+    }
+    fn time_from_reference(&self, date: Date) -> Time {
+        self.day_counter()
+            .year_fraction(self.reference_date(), date)
+    }
+}
+
+pub(crate) mod detail {
+    use crate::types::Time;
+
+    // Time step of the numerical instantaneous forward (QuantLib's dt)
+    pub(crate) const DT: Time = 1.0e-4;
+
+    pub(crate) fn check_time(t: Time) {
+        if t < 0.0 {
+            panic!("negative time ({t}) given");
+        }
+    }
+}
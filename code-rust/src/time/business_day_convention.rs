@@ -0,0 +1,35 @@
+use std::fmt::{Display, Formatter, Result};
+
+/*
+How a date falling on a holiday is rolled to a business day (QuantLib
+BusinessDayConvention):
+    - Following: the next business day
+    - ModifiedFollowing: the next business day, unless it is in the next
+      month, in which case the previous one
+    - Preceding: the previous business day
+    - ModifiedPreceding: the previous business day, unless it is in the
+      previous month, in which case the next one
+    - Unadjusted: the date is kept
+*/
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum BusinessDayConvention {
+    Following,
+    ModifiedFollowing,
+    Preceding,
+    ModifiedPreceding,
+    Unadjusted,
+}
+
+// Traits
+impl Display for BusinessDayConvention {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let convention: &'static str = match self {
+            BusinessDayConvention::Following => "Following",
+            BusinessDayConvention::ModifiedFollowing => "Modified Following",
+            BusinessDayConvention::Preceding => "Preceding",
+            BusinessDayConvention::ModifiedPreceding => "Modified Preceding",
+            BusinessDayConvention::Unadjusted => "Unadjusted",
+        };
+        write!(f, "{convention}")
+    }
+}
@@ -0,0 +1,111 @@
+use crate::time::business_day_convention::BusinessDayConvention;
+use crate::time::date::Date;
+use crate::time::time_unit::TimeUnit;
+
+/*
+Holiday calendar (QuantLib Calendar): which dates are business days,
+and how dates are rolled and advanced on them.
+
+adjust rolls a date to a business day with a convention. advance moves
+a date by business days for TimeUnit::Days, and otherwise by
+Date::advance followed by adjust; with end_of_month, a date whose next business day is in
+another month is moved to the last business day of the target month.
+*/
+pub trait Calendar {
+    fn name(&self) -> String;
+    fn is_business_day(&self, date: Date) -> bool;
+
+    fn is_holiday(&self, date: Date) -> bool {
+        !self.is_business_day(date)
+    }
+    // Last business day of the month of date
+    fn end_of_month(&self, date: Date) -> Date {
+        self.adjust(date.end_of_month(), BusinessDayConvention::Preceding)
+    }
+    // Whether the next business day is in another month
+    fn is_end_of_month(&self, date: Date) -> bool {
+        date.month()
+            != self
+                .adjust(date + 1, BusinessDayConvention::Following)
+                .month()
+    }
+    fn adjust(&self, date: Date, convention: BusinessDayConvention) -> Date {
+        match convention {
+            BusinessDayConvention::Unadjusted => date,
+            BusinessDayConvention::Following | BusinessDayConvention::ModifiedFollowing => {
+                let mut adjusted: Date = date;
+                while self.is_holiday(adjusted) {
+                    adjusted += 1;
+                }
+                if convention == BusinessDayConvention::ModifiedFollowing
+                    && adjusted.month() != date.month()
+                {
+                    return self.adjust(date, BusinessDayConvention::Preceding);
+                }
+                adjusted
+            }
+            BusinessDayConvention::Preceding | BusinessDayConvention::ModifiedPreceding => {
+                let mut adjusted: Date = date;
+                while self.is_holiday(adjusted) {
+                    adjusted -= 1;
+                }
+                if convention == BusinessDayConvention::ModifiedPreceding
+                    && adjusted.month() != date.month()
+                {
+                    return self.adjust(date, BusinessDayConvention::Following);
+                }
+                adjusted
+            }
+        }
+    }
+    fn advance(
+        &self,
+        date: Date,
+        number: i32,
+        units: TimeUnit,
+        convention: BusinessDayConvention,
+        end_of_month: bool,
+    ) -> Date {
+        match units {
+            TimeUnit::Days => {
+                let step: i32 = if number < 0 { -1 } else { 1 };
+                let mut advanced: Date = self.adjust(
+                    date,
+                    if number < 0 {
+                        BusinessDayConvention::Preceding
+                    } else {
+                        BusinessDayConvention::Following
+                    },
+                );
+                for _ in 0..number.abs() {
+                    advanced += step;
+                    while self.is_holiday(advanced) {
+                        advanced += step;
+                    }
+                }
+                advanced
+            }
+            TimeUnit::Weeks => self.adjust(Date::advance(date, number, units), convention),
+            TimeUnit::Months | TimeUnit::Years => {
+                let advanced: Date = Date::advance(date, number, units);
+                if end_of_month && self.is_end_of_month(date) {
+                    return self.end_of_month(advanced);
+                }
+                self.adjust(advanced, convention)
+            }
+        }
+    }
+    // Business days from d1 (included) to d2 (excluded), negative if d2 is before d1
+    fn business_days_between(&self, d1: Date, d2: Date) -> i32 {
+        let (from, to, sign): (Date, Date, i32) = if d1 <= d2 { (d1, d2, 1) } else { (d2, d1, -1) };
+        let mut count: i32 = 0;
+        let mut date: Date = from;
+        while date < to {
+            if self.is_business_day(date) {
+                count += 1;
+            }
+            date += 1;
+        }
+        sign * count
+    }
+}
@@ -0,0 +1,23 @@
+use crate::time::calendar::Calendar;
+use crate::time::date::Date;
+
+/*
+Calendar without holidays (QuantLib NullCalendar): every day, weekends
+included, is a business day, so advancing by days counts calendar days.
+*/
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct NullCalendar;
+impl NullCalendar {
+    // Constructor
+    pub fn new() -> NullCalendar {
+        NullCalendar
+    }
+}
+impl Calendar for NullCalendar {
+    fn name(&self) -> String {
+        String::from("Null")
+    }
+    fn is_business_day(&self, _date: Date) -> bool {
+        true
+    }
+}
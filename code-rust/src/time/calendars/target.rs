@@ -0,0 +1,99 @@
+use crate::time::calendar::Calendar;
+use crate::time::date::{Date, Month};
+use crate::time::weekday::Weekday;
+
+/*
+TARGET calendar of the Eurosystem payment system (QuantLib TARGET),
+used by Euribor and ESTR. Holidays, as observed since 2001: Saturdays,
+Sundays, New Year's Day, Good Friday, Easter Monday, Labour Day
+(1 May), Christmas Day and Boxing Day (26 December).
+*/
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct Target;
+impl Target {
+    // Constructor
+    pub fn new() -> Target {
+        Target
+    }
+}
+impl Calendar for Target {
+    fn name(&self) -> String {
+        String::from("TARGET")
+    }
+    fn is_business_day(&self, date: Date) -> bool {
+        if matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday) {
+            return false;
+        }
+        let (day, month): (i32, Month) = (date.day_of_month(), date.month());
+        let easter_monday: Date = detail::easter_monday(date.year());
+        !((day == 1 && month == Month::January)
+            || date == easter_monday - 3
+            || date == easter_monday
+            || (day == 1 && month == Month::May)
+            || (day == 25 && month == Month::December)
+            || (day == 26 && month == Month::December))
+    }
+}
+
+mod detail {
+    use super::*;
+
+    // Easter Monday of the Gregorian calendar (anonymous algorithm of Meeus, Jones and Butcher)
+    pub(super) fn easter_monday(year: i32) -> Date {
+        let a: i32 = year % 19;
+        let b: i32 = year / 100;
+        let c: i32 = year % 100;
+        let d: i32 = b / 4;
+        let e: i32 = b % 4;
+        let f: i32 = (b + 8) / 25;
+        let g: i32 = (b - f + 1) / 3;
+        let h: i32 = (19 * a + b - d - g + 15) % 30;
+        let i: i32 = c / 4;
+        let k: i32 = c % 4;
+        let l: i32 = (32 + 2 * e + 2 * i - h - k) % 7;
+        let m: i32 = (a + 11 * h + 22 * l) / 451;
+        let month: i32 = (h + l - 7 * m + 114) / 31;
+        let day: i32 = (h + l - 7 * m + 114) % 31 + 1;
+        Date::new(day, Month::from_index(month as usize), year) + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn holidays_of_2025() {
+        let holidays: [Date; 6] = [
+            Date::new(1, Month::January, 2025),
+            Date::new(18, Month::April, 2025),
+            Date::new(21, Month::April, 2025),
+            Date::new(1, Month::May, 2025),
+            Date::new(25, Month::December, 2025),
+            Date::new(26, Month::December, 2025),
+        ];
+        let calendar: Target = Target::new();
+        for date in holidays {
+            assert!(calendar.is_holiday(date), "{date}");
+        }
+        let holidays_in_year: usize = (0..365)
+            .map(|k| Date::new(1, Month::January, 2025) + k)
+            .filter(|date| calendar.is_holiday(*date))
+            .count();
+        // 104 weekend days and the 6 holidays, none of which falls on a weekend in 2025
+        assert_eq!(holidays_in_year, 110);
+    }
+
+    #[test]
+    fn easter_mondays() {
+        let cases: [(i32, Date); 4] = [
+            (2000, Date::new(24, Month::April, 2000)),
+            (2019, Date::new(22, Month::April, 2019)),
+            (2024, Date::new(1, Month::April, 2024)),
+            (2038, Date::new(26, Month::April, 2038)),
+        ];
+        for (year, expected) in cases {
+            assert_eq!(detail::easter_monday(year), expected, "{year}");
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::time::calendar::Calendar;
+use crate::time::date::Date;
+use crate::time::weekday::Weekday;
+
+/*
+Calendar whose only holidays are Saturdays and Sundays (QuantLib
+WeekendsOnly).
+*/
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct WeekendsOnly;
+impl WeekendsOnly {
+    // Constructor
+    pub fn new() -> WeekendsOnly {
+        WeekendsOnly
+    }
+}
+impl Calendar for WeekendsOnly {
+    fn name(&self) -> String {
+        String::from("weekends only")
+    }
+    fn is_business_day(&self, date: Date) -> bool {
+        !matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::business_day_convention::BusinessDayConvention;
+    use crate::time::date::Month;
+    use crate::time::time_unit::TimeUnit;
+
+    #[test]
+    fn dates_are_adjusted_and_advanced_on_weekdays() {
+        let calendar: WeekendsOnly = WeekendsOnly::new();
+        // Saturday 31 May 2025
+        let saturday: Date = Date::new(31, Month::May, 2025);
+        let cases: [(BusinessDayConvention, Date); 5] = [
+            (
+                BusinessDayConvention::Following,
+                Date::new(2, Month::June, 2025),
+            ),
+            (
+                BusinessDayConvention::ModifiedFollowing,
+                Date::new(30, Month::May, 2025),
+            ),
+            (
+                BusinessDayConvention::Preceding,
+                Date::new(30, Month::May, 2025),
+            ),
+            (
+                BusinessDayConvention::ModifiedPreceding,
+                Date::new(30, Month::May, 2025),
+            ),
+            (BusinessDayConvention::Unadjusted, saturday),
+        ];
+        for (convention, expected) in cases {
+            assert_eq!(
+                calendar.adjust(saturday, convention),
+                expected,
+                "{convention}"
+            );
+        }
+        // Sunday 1 June 2025 rolls back into June only with ModifiedPreceding
+        assert_eq!(
+            calendar.adjust(
+                Date::new(1, Month::June, 2025),
+                BusinessDayConvention::ModifiedPreceding
+            ),
+            Date::new(2, Month::June, 2025)
+        );
+
+        let friday: Date = Date::new(30, Month::May, 2025);
+        let following: BusinessDayConvention = BusinessDayConvention::Following;
+        assert_eq!(
+            calendar.advance(friday, 2, TimeUnit::Days, following, false),
+            Date::new(3, Month::June, 2025)
+        );
+        assert_eq!(
+            calendar.advance(
+                Date::new(3, Month::June, 2025),
+                -2,
+                TimeUnit::Days,
+                following,
+                false
+            ),
+            friday
+        );
+        assert_eq!(
+            calendar.advance(saturday, 0, TimeUnit::Days, following, false),
+            Date::new(2, Month::June, 2025)
+        );
+        // The last business day of May moves to month ends only with end_of_month
+        assert_eq!(
+            calendar.advance(friday, 1, TimeUnit::Months, following, false),
+            Date::new(30, Month::June, 2025)
+        );
+        assert_eq!(
+            calendar.advance(friday, 3, TimeUnit::Months, following, true),
+            Date::new(29, Month::August, 2025)
+        );
+        assert_eq!(calendar.business_days_between(friday, friday + 7), 5);
+        assert_eq!(calendar.business_days_between(friday + 7, friday), -5);
+    }
+}
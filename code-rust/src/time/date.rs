@@ -48,9 +48,9 @@ impl Month {
             unsafe --> "the contract necessary to call the operations inside the block has been
             checked by the programmer and is guaranteed to be respected"
             */
-            unsafe { std::mem::transmute(number as u8) }
+            unsafe { std::mem::transmute::<u8, Month>(number as u8) }
         } else {
-            panic!("Month index {} out of range [1,12]", number);
+            panic!("Month index {number} out of range [1,12]");
         }
     }
 }
@@ -75,11 +75,11 @@ impl Display for Month {
             Month::November => "November",
             Month::December => "December",
         };
-        write!(f, "{}", name)
+        write!(f, "{name}")
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Default)]
 pub struct Date {
     // Defines a struct named Date, just like a class in C++ or C# with only data (no methods yet).
     // pub --> public so they can be access by other files like main.rs
@@ -101,7 +101,7 @@ impl Date {
 
         // 1. Year check
         if !(1901..=2199).contains(&year) {
-            panic!("year {} out of bounds, must be [1901,2199]", year);
+            panic!("year {year} out of bounds, must be [1901,2199]");
         }
 
         // 2. Leap year
@@ -129,9 +129,7 @@ impl Date {
     }
     pub fn from_serial_number(serial_number: SerialType) -> Date {
         Date::check_serial_number(serial_number);
-        Date {
-            serial_number: serial_number,
-        }
+        Date { serial_number }
     }
 
     // Helpers (privates)
@@ -143,7 +141,7 @@ impl Date {
 
         // month_index could be 13 when called from month()
         if !((1..=13).contains(&month_index)) {
-            panic!("Month index {} out of range [1,13]", month_index);
+            panic!("Month index {month_index} out of range [1,13]");
         }
 
         const MONTH_OFFSET: [i32; 14] = [
@@ -177,7 +175,7 @@ impl Date {
     }
     fn is_leap(year: Year) -> bool {
         if !((1900..=2200).contains(&year)) {
-            panic!("year {} outside valid range [1900, 2200]", year);
+            panic!("year {year} outside valid range [1900, 2200]");
         }
 
         const YEAR_IS_LEAP: [bool; 301] = [
@@ -260,7 +258,7 @@ impl Date {
         - year_offset(1903) = 731
          */
         if !((1900..=2200).contains(&year)) {
-            panic!("year {} outside valid range [1900,2200]", year);
+            panic!("year {year} outside valid range [1900,2200]");
         }
 
         const YEAR_OFFSET: [i32; 301] = [
@@ -327,6 +325,7 @@ impl Date {
 
         YEAR_OFFSET[(year - 1900) as usize]
     }
+    #[allow(dead_code)]
     fn year_length(year: Year) -> i32 {
         if Date::is_leap(year) { 366 } else { 365 }
     }
@@ -480,10 +479,10 @@ impl Date {
             - First Monday = 5-May-2025.
 
         */
-        if !(nth > 0) {
+        if nth == 0 {
             panic!("The zeroth day of the week is not defined");
         }
-        if !(nth < 6) {
+        if nth >= 6 {
             panic!("No more the 5 weekday in a given month");
         }
 
@@ -499,12 +498,12 @@ impl Date {
 
         Date::new(1 + day_of_week + skip * 7 - first_day_of_week, month, year)
     }
-    pub fn increment(&mut self) -> () {
+    pub fn increment(&mut self) {
         let serial_number: SerialType = self.serial_number + 1;
         Date::check_serial_number(serial_number);
         self.serial_number = serial_number
     }
-    pub fn decrement(&mut self) -> () {
+    pub fn decrement(&mut self) {
         let serial_number: SerialType = self.serial_number - 1;
         Date::check_serial_number(serial_number);
         self.serial_number = serial_number
@@ -532,7 +531,7 @@ impl Date {
                 }
 
                 if !((1900..=2199).contains(&year)) {
-                    panic!("year {} outside valid range [1900,2199]", year);
+                    panic!("year {year} outside valid range [1900,2199]");
                 }
 
                 let month_updated: Month = Month::from_index(month_number as usize);
@@ -550,7 +549,7 @@ impl Date {
                 let month: Month = date.month();
                 let year: i32 = date.year() + number;
                 if !((1900..=2199).contains(&year)) {
-                    panic!("year {} outside valid range [1900,2199]", year);
+                    panic!("year {year} outside valid range [1900,2199]");
                 }
 
                 if day == 29 && month == Month::February && !Date::is_leap(year) {
@@ -601,11 +600,6 @@ impl Sub<Date> for Date {
         self.to_serial_number() - right_hand_side.to_serial_number()
     }
 }
-impl Default for Date {
-    fn default() -> Self {
-        Date { serial_number: 0 }
-    }
-}
 
 // Traits - Day for Date
 impl Add<Day> for Date {
@@ -651,7 +645,7 @@ impl Add<Day> for Date {
 }
 impl AddAssign<Day> for Date {
     // No Output, no new Date returned. SAME DATE modified!
-    fn add_assign(&mut self, right_hand_side: Day) -> () {
+    fn add_assign(&mut self, right_hand_side: Day) {
         let serial_number: SerialType = self.serial_number + right_hand_side;
         Date::check_serial_number(serial_number);
         self.serial_number += right_hand_side
@@ -677,7 +671,7 @@ impl Sub<Day> for Date {
 }
 impl SubAssign<Day> for Date {
     // No Output, no new Date returned. SAME DATE modified!
-    fn sub_assign(&mut self, right_hand_side: Day) -> () {
+    fn sub_assign(&mut self, right_hand_side: Day) {
         let serial_number: SerialType = self.serial_number - right_hand_side;
         Date::check_serial_number(serial_number);
         self.serial_number -= right_hand_side
@@ -815,10 +809,7 @@ pub(crate) mod io {
         detail::IsoDate { date: d }
     }
     pub fn formatted_date<'a>(d: &'a Date, format: &'a str) -> impl Display + 'a {
-        detail::FormattedDate {
-            date: d,
-            format: format,
-        }
+        detail::FormattedDate { date: d, format }
     }
 }
 
@@ -877,26 +868,16 @@ mod tests {
         ];
 
         for (date, exp_day, exp_month, exp_year, label) in cases {
-            assert_eq!(
-                date.day(),
-                exp_day,
-                "Day mismatch ({}) for {:?}",
-                label,
-                date
-            );
+            assert_eq!(date.day(), exp_day, "Day mismatch ({label}) for {date:?}");
             assert_eq!(
                 date.month(),
                 exp_month,
-                "Month mismatch ({}) for {:?}",
-                label,
-                date
+                "Month mismatch ({label}) for {date:?}"
             );
             assert_eq!(
                 date.year(),
                 exp_year,
-                "Year mismatch ({}) for {:?}",
-                label,
-                date
+                "Year mismatch ({label}) for {date:?}"
             );
         }
     }
@@ -938,8 +919,7 @@ mod tests {
 
             assert!(
                 result.is_err(),
-                "Expected panic for invalid year {} but got Ok",
-                year
+                "Expected panic for invalid year {year} but got Ok"
             );
         }
     }
@@ -955,8 +935,7 @@ mod tests {
 
             assert!(
                 result.is_err(),
-                "Expected panic for invalid month {} but got Ok",
-                month_index
+                "Expected panic for invalid month {month_index} but got Ok"
             );
         }
     }
@@ -1017,14 +996,15 @@ mod tests {
 
         for (d1, d2, expected, msg) in cases {
             if expected {
-                assert_eq!(d1, d2, "Expected equality failed: {}", msg);
+                assert_eq!(d1, d2, "Expected equality failed: {msg}");
             } else {
-                assert_ne!(d1, d2, "Expected inequality failed: {}", msg);
+                assert_ne!(d1, d2, "Expected inequality failed: {msg}");
             }
         }
     }
 
     #[test]
+    #[allow(clippy::nonminimal_bool)]
     fn date_comparison_all_ops() {
         let cases: [(Date, Date, std::cmp::Ordering, &'static str); 7] = [
             // --- Day comparisons ---
@@ -1077,23 +1057,23 @@ mod tests {
 
         for (d1, d2, expected, label) in cases {
             let result: std::cmp::Ordering = d1.cmp(&d2);
-            assert_eq!(result, expected, "Failed cmp for {}", label);
+            assert_eq!(result, expected, "Failed cmp for {label}");
             match expected {
                 std::cmp::Ordering::Less => {
-                    assert!(d1 < d2, "Failed < for {}", label);
-                    assert!(d1 <= d2, "Failed <= for {}", label);
-                    assert!(d1 != d2, "Failed != for {}", label);
+                    assert!(d1 < d2, "Failed < for {label}");
+                    assert!(d1 <= d2, "Failed <= for {label}");
+                    assert!(d1 != d2, "Failed != for {label}");
                 }
                 std::cmp::Ordering::Greater => {
-                    assert!(d1 > d2, "Failed > for {}", label);
-                    assert!(d1 >= d2, "Failed >= for {}", label);
-                    assert!(d1 != d2, "Failed != for {}", label);
+                    assert!(d1 > d2, "Failed > for {label}");
+                    assert!(d1 >= d2, "Failed >= for {label}");
+                    assert!(d1 != d2, "Failed != for {label}");
                 }
                 std::cmp::Ordering::Equal => {
-                    assert!(d1 == d2, "Failed == for {}", label);
-                    assert!(d1 <= d2, "Failed <= for {}", label);
-                    assert!(d1 >= d2, "Failed >= for {}", label);
-                    assert!(!(d1 != d2), "Failed != negation for {}", label);
+                    assert!(d1 == d2, "Failed == for {label}");
+                    assert!(d1 <= d2, "Failed <= for {label}");
+                    assert!(d1 >= d2, "Failed >= for {label}");
+                    assert!(!(d1 != d2), "Failed != negation for {label}");
                 }
             }
         }
@@ -1148,11 +1128,7 @@ mod tests {
 
         for (start, delta, expected, label) in cases {
             let result: Date = start + delta;
-            assert_eq!(
-                result, expected,
-                "Failed {}: {} + {} days",
-                label, start, delta
-            );
+            assert_eq!(result, expected, "Failed {label}: {start} + {delta} days");
         }
     }
 
@@ -1283,7 +1259,7 @@ mod tests {
 
         for (num, expected) in cases {
             let derived: Month = Month::from_index(num);
-            assert_eq!(derived, expected, "Failed for number {}", num);
+            assert_eq!(derived, expected, "Failed for number {num}");
         }
     }
 
@@ -1296,34 +1272,33 @@ mod tests {
             });
             assert!(
                 result.is_err(),
-                "Expected panic for invalid month {} but got Ok",
-                num
+                "Expected panic for invalid month {num} but got Ok"
             );
         }
     }
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn is_leap_true() {
         let leap_years: [Year; 4] = [2000, 1928, 1956, 1900]; //1900
         for year in leap_years {
             assert_eq!(
                 Date::is_leap(year),
                 true,
-                "Year {} was incorrectly detected as leap",
-                year
+                "Year {year} was incorrectly detected as leap"
             );
         }
     }
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn is_leap_false() {
         let not_leap_years: [Year; 3] = [1945, 1999, 1901]; //1900 
         for year in not_leap_years {
             assert_eq!(
                 Date::is_leap(year),
                 false,
-                "Year {} was incorrectly detected as not leap",
-                year
+                "Year {year} was incorrectly detected as not leap"
             );
         }
     }
@@ -1339,8 +1314,7 @@ mod tests {
 
             assert!(
                 result.is_err(),
-                "Expected panic for invalid year {} but got Ok",
-                year
+                "Expected panic for invalid year {year} but got Ok"
             );
         }
     }
@@ -1412,8 +1386,7 @@ mod tests {
 
             assert!(
                 result.is_err(),
-                "Expected panic for invalid serial {} but got Ok",
-                n
+                "Expected panic for invalid serial {n} but got Ok"
             );
         }
     }
@@ -1450,12 +1423,7 @@ mod tests {
         ];
 
         for (month, expected) in cases {
-            assert_eq!(
-                format!("{}", month),
-                expected,
-                "Failed for month {:?}",
-                month
-            );
+            assert_eq!(format!("{month}"), expected, "Failed for month {:?}", month);
         }
     }
 
@@ -1476,7 +1444,7 @@ mod tests {
         ];
 
         for (date, expected) in cases {
-            assert_eq!(format!("{}", date), expected, "Failed for date {:?}", date);
+            assert_eq!(format!("{date}"), expected, "Failed for date {:?}", date);
         }
     }
 
@@ -1550,7 +1518,7 @@ mod tests {
 
         for (input, expected) in cases {
             let result: String = format!("{}", io::long_date(&input));
-            assert_eq!(result, expected, "LongDate null failed: date={:?}", input);
+            assert_eq!(result, expected, "LongDate null failed: date={input:?}");
         }
     }
 
@@ -1560,7 +1528,7 @@ mod tests {
 
         for (input, expected) in cases {
             let result: String = format!("{}", io::short_date(&input));
-            assert_eq!(result, expected, "ShortDate null failed: date={:?}", input);
+            assert_eq!(result, expected, "ShortDate null failed: date={input:?}");
         }
     }
 
@@ -1570,7 +1538,7 @@ mod tests {
 
         for (input, expected) in cases {
             let result: String = format!("{}", io::iso_date(&input));
-            assert_eq!(result, expected, "IsoDate null failed: date={:?}", input);
+            assert_eq!(result, expected, "IsoDate null failed: date={input:?}");
         }
     }
     #[test]
@@ -1586,8 +1554,7 @@ mod tests {
             assert_eq!(
                 Date::year_length(year),
                 expected,
-                "Failed for year {:?}",
-                year
+                "Failed for year {year:?}"
             );
         }
     }
@@ -1606,12 +1573,7 @@ mod tests {
         ];
 
         for (date, expected) in cases {
-            assert_eq!(
-                date.is_end_of_month(),
-                expected,
-                "Failed for date {:?}",
-                date
-            );
+            assert_eq!(date.is_end_of_month(), expected, "Failed for date {date:?}");
         }
     }
 
@@ -1634,7 +1596,7 @@ mod tests {
 
         for (input, expected) in cases {
             let result = input.end_of_month();
-            assert_eq!(result, expected, "Failed for date {:?}", input);
+            assert_eq!(result, expected, "Failed for date {input:?}");
         }
     }
 
@@ -1654,15 +1616,13 @@ mod tests {
         // 1. Check equality with chrono-based date
         assert_eq!(
             derived, expected,
-            "todays_date() mismatch: expected {:?}, got {:?}",
-            expected, derived
+            "todays_date() mismatch: expected {expected:?}, got {derived:?}"
         );
 
         // 2. Check range boundaries
         assert!(
             derived >= Date::min_date() && derived <= Date::max_date(),
-            "todays_date() out of valid QuantLib range: {:?}",
-            derived
+            "todays_date() out of valid QuantLib range: {derived:?}"
         );
 
         // 3. Check round-trip serial number consistency
@@ -1698,11 +1658,7 @@ mod tests {
 
         for (mut start, delta, expected) in cases {
             start += delta;
-            assert_eq!(
-                start, expected,
-                "Failed case: start +={} {:?}",
-                delta, start
-            );
+            assert_eq!(start, expected, "Failed case: start +={delta} {start:?}");
         }
     }
 
@@ -1731,11 +1687,7 @@ mod tests {
 
         for (mut start, delta, expected) in cases {
             start -= delta;
-            assert_eq!(
-                start, expected,
-                "Failed case: start -={} {:?}",
-                delta, start
-            );
+            assert_eq!(start, expected, "Failed case: start -={delta} {start:?}");
         }
     }
 
@@ -1839,7 +1791,7 @@ mod tests {
         for (input, expected) in cases {
             let mut d = input;
             d.increment();
-            assert_eq!(d, expected, "Increment failed: start={:?}", input);
+            assert_eq!(d, expected, "Increment failed: start={input:?}");
         }
     }
 
@@ -1856,8 +1808,7 @@ mod tests {
             });
             assert!(
                 result.is_err(),
-                "Increment panic failed: start={:?} did not panic",
-                input
+                "Increment panic failed: start={input:?} did not panic"
             );
         }
     }
@@ -1886,7 +1837,7 @@ mod tests {
         for (input, expected) in cases {
             let mut d = input;
             d.decrement();
-            assert_eq!(d, expected, "Decrement failed: start={:?}", input);
+            assert_eq!(d, expected, "Decrement failed: start={input:?}");
         }
     }
 
@@ -1903,8 +1854,7 @@ mod tests {
             });
             assert!(
                 result.is_err(),
-                "Decrement panic failed: start={:?} did not panic",
-                input
+                "Decrement panic failed: start={input:?} did not panic"
             );
         }
     }
@@ -1933,8 +1883,7 @@ mod tests {
             let result = format!("{}", io::formatted_date(&input, format));
             assert_eq!(
                 result, expected,
-                "Formatted date failed: date={:?}, format={}",
-                input, format
+                "Formatted date failed: date={input:?}, format={format}"
             );
         }
     }
@@ -1947,8 +1896,7 @@ mod tests {
             let result = format!("{}", io::formatted_date(&input, format));
             assert_eq!(
                 result, expected,
-                "Formatted date null failed: date={:?}, format={}",
-                input, format
+                "Formatted date null failed: date={input:?}, format={format}"
             );
         }
     }
@@ -1965,8 +1913,7 @@ mod tests {
             });
             assert!(
                 result.is_err(),
-                "Formatted date invalid case failed: date={:?} did not panic",
-                input
+                "Formatted date invalid case failed: date={input:?} did not panic"
             );
         }
     }
@@ -2082,8 +2029,7 @@ mod tests {
             let derived: Date = Date::advance(start, number, unit);
             assert_eq!(
                 derived, expected,
-                "advance failed: {} | start={:?}, number={}, unit={:?}",
-                label, start, number, unit
+                "advance failed: {label} | start={start:?}, number={number}, unit={unit:?}"
             );
         }
     }
@@ -2124,11 +2070,7 @@ mod tests {
 
             assert!(
                 result.is_err(),
-                "Expected panic but got Ok for case: {} | start={:?}, number={}, unit={:?}",
-                label,
-                start,
-                number,
-                unit
+                "Expected panic but got Ok for case: {label} | start={start:?}, number={number}, unit={unit:?}"
             );
         }
     }
@@ -2154,8 +2096,7 @@ mod tests {
             start += period;
             assert_eq!(
                 start, expected,
-                "Failed add_assign: {} | start={:?}, period={:?}",
-                label, start, period
+                "Failed add_assign: {label} | start={start:?}, period={period:?}"
             );
         }
     }
@@ -2181,8 +2122,7 @@ mod tests {
             let derived = start + period;
             assert_eq!(
                 derived, expected,
-                "Failed add: {} | start={:?}, period={:?}",
-                label, start, period
+                "Failed add: {label} | start={start:?}, period={period:?}"
             );
         }
     }
@@ -2208,8 +2148,7 @@ mod tests {
             start -= period;
             assert_eq!(
                 start, expected,
-                "Failed sub_assign: {} | start={:?}, period={:?}",
-                label, start, period
+                "Failed sub_assign: {label} | start={start:?}, period={period:?}"
             );
         }
     }
@@ -2235,8 +2174,7 @@ mod tests {
             let derived: Date = start - period;
             assert_eq!(
                 derived, expected,
-                "Failed sub: {} | start={:?}, period={:?}",
-                label, start, period
+                "Failed sub: {label} | start={start:?}, period={period:?}"
             );
         }
     }
@@ -2271,11 +2209,12 @@ mod tests {
             for d in input {
                 set.insert(d);
             }
-            assert_eq!(set.len(), expected_len, "HashSet length failed: {}", label);
+            assert_eq!(set.len(), expected_len, "HashSet length failed: {label}");
         }
     }
 
     #[test]
+    #[allow(clippy::type_complexity)]
     fn date_hashmap_works() {
         use std::collections::HashMap;
 
@@ -2303,7 +2242,7 @@ mod tests {
             for (date, value) in input {
                 map.insert(date, value);
             }
-            assert_eq!(map.len(), expected_len, "HashMap length failed: {}", label);
+            assert_eq!(map.len(), expected_len, "HashMap length failed: {label}");
         }
     }
 }
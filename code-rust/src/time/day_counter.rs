@@ -0,0 +1,17 @@
+use crate::time::date::Date;
+use crate::types::Time;
+
+/*
+Day count convention (QuantLib DayCounter): how many days lie between
+two dates and which fraction of a year they represent.
+
+day_count defaults to the actual number of days; conventions such as
+30/360 override it.
+*/
+pub trait DayCounter {
+    fn name(&self) -> String;
+    fn day_count(&self, d1: Date, d2: Date) -> i32 {
+        d2 - d1
+    }
+    fn year_fraction(&self, d1: Date, d2: Date) -> Time;
+}
@@ -0,0 +1,62 @@
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::Time;
+
+/*
+Actual/360: actual days divided by 360 (QuantLib Actual360). The
+variant including the last day counts one more day, as the ISDA
+standard does for the last coupon of a credit default swap.
+*/
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct Actual360 {
+    include_last_day: bool,
+}
+impl Actual360 {
+    // Constructors
+    pub fn new() -> Actual360 {
+        Actual360 {
+            include_last_day: false,
+        }
+    }
+    pub fn including_last_day() -> Actual360 {
+        Actual360 {
+            include_last_day: true,
+        }
+    }
+}
+impl DayCounter for Actual360 {
+    fn name(&self) -> String {
+        if self.include_last_day {
+            String::from("Actual/360 (inc)")
+        } else {
+            String::from("Actual/360")
+        }
+    }
+    fn day_count(&self, d1: Date, d2: Date) -> i32 {
+        (d2 - d1) + self.include_last_day as i32
+    }
+    fn year_fraction(&self, d1: Date, d2: Date) -> Time {
+        self.day_count(d1, d2) as Time / 360.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+
+    #[test]
+    fn year_fraction_is_actual_days_over_360() {
+        let d1: Date = Date::new(20, Month::March, 2025);
+        let d2: Date = Date::new(20, Month::June, 2025);
+        let cases: [(Actual360, &str, i32); 2] = [
+            (Actual360::new(), "Actual/360", 92),
+            (Actual360::including_last_day(), "Actual/360 (inc)", 93),
+        ];
+        for (day_counter, name, days) in cases {
+            assert_eq!(day_counter.name(), name);
+            assert_eq!(day_counter.day_count(d1, d2), days);
+            assert_eq!(day_counter.year_fraction(d1, d2), days as Time / 360.0);
+        }
+    }
+}
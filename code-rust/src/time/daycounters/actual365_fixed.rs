@@ -0,0 +1,58 @@
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::types::Time;
+
+// Actual/365 (Fixed): actual days divided by 365, leap years included (QuantLib Actual365Fixed)
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct Actual365Fixed;
+impl Actual365Fixed {
+    // Constructor
+    pub fn new() -> Actual365Fixed {
+        Actual365Fixed
+    }
+}
+impl DayCounter for Actual365Fixed {
+    fn name(&self) -> String {
+        String::from("Actual/365 (Fixed)")
+    }
+    fn year_fraction(&self, d1: Date, d2: Date) -> Time {
+        self.day_count(d1, d2) as Time / 365.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+
+    #[test]
+    fn year_fraction_is_actual_days_over_365() {
+        let day_counter: Actual365Fixed = Actual365Fixed::new();
+        let cases: [(Date, Date, i32, Time); 3] = [
+            (
+                Date::new(1, Month::January, 2024),
+                Date::new(1, Month::January, 2025),
+                366,
+                366.0 / 365.0,
+            ),
+            (
+                Date::new(15, Month::March, 2023),
+                Date::new(15, Month::September, 2023),
+                184,
+                184.0 / 365.0,
+            ),
+            (
+                Date::new(1, Month::July, 2023),
+                Date::new(1, Month::January, 2023),
+                -181,
+                -181.0 / 365.0,
+            ),
+        ];
+
+        assert_eq!(day_counter.name(), "Actual/365 (Fixed)");
+        for (d1, d2, days, fraction) in cases {
+            assert_eq!(day_counter.day_count(d1, d2), days);
+            assert_eq!(day_counter.year_fraction(d1, d2), fraction);
+        }
+    }
+}
@@ -48,7 +48,7 @@ impl Display for Frequency {
             Frequency::Daily => "Daily",
             Frequency::OtherFrequency => "Unknown frequency",
         };
-        write!(f, "{}", frequency)
+        write!(f, "{frequency}")
     }
 }
 
@@ -76,7 +76,7 @@ mod tests {
 
         for (frequency, expected) in cases {
             assert_eq!(
-                format!("{}", frequency),
+                format!("{frequency}"),
                 expected,
                 "Failed for frequency {:?}",
                 frequency
@@ -99,8 +99,7 @@ mod tests {
             assert_eq!(
                 Frequency::from_nth_times_per_year(input),
                 expected,
-                "Failed for input {}",
-                input
+                "Failed for input {input}"
             );
         }
     }
@@ -112,8 +111,7 @@ mod tests {
             assert_eq!(
                 Frequency::from_nth_times_per_year(input),
                 Frequency::OtherFrequency,
-                "Expected OtherFrequency for input {}",
-                input
+                "Expected OtherFrequency for input {input}"
             );
         }
     }
@@ -47,7 +47,7 @@ impl Period {
     }
     pub fn frequency(&self) -> Frequency {
         // Period -> Frequency
-        let abs_length: u32 = self.length.abs() as u32; // abs because length can be negative
+        let abs_length: u32 = self.length.unsigned_abs(); // abs because length can be negative
         let units: TimeUnit = self.units;
 
         if abs_length == 0 {
@@ -192,6 +192,7 @@ impl Period {
 
 // Traits
 impl PartialOrd for Period {
+    #[allow(clippy::needless_return)]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         if self.length() == 0 {
             if other.length() > 0 {
@@ -276,7 +277,7 @@ impl PartialOrd for Period {
             // The smallest possible value of self is still greater than the largest possible value of other
             return Some(Ordering::Greater);
         } else {
-            panic!("Undecidable comparison between {:?} and {:?}", self, other);
+            panic!("Undecidable comparison between {self:?} and {other:?}");
         }
     }
 }
@@ -289,7 +290,7 @@ impl AddAssign<Period> for Period {
     // AddAssign -> right hand side
     // for Period -> left hand side
     // No Output, no new Date returned. SAME Period modified!
-    fn add_assign(&mut self, rhs: Period) -> () {
+    fn add_assign(&mut self, rhs: Period) {
         // Asssumption:
         // Months + Years --> Months
         // Years + Months --> Months
@@ -313,7 +314,7 @@ impl AddAssign<Period> for Period {
                         self.length = self.length * 12 + rhs.length();
                     }
                     TimeUnit::Weeks | TimeUnit::Days => {
-                        panic!("Impossible addition between {:?} and {:?}", self, rhs)
+                        panic!("Impossible addition between {self:?} and {rhs:?}")
                     }
                 },
                 TimeUnit::Months => match rhs.units() {
@@ -323,7 +324,7 @@ impl AddAssign<Period> for Period {
                         self.length += 12 * rhs.length();
                     }
                     TimeUnit::Weeks | TimeUnit::Days => {
-                        panic!("Impossible addition between {:?} and {:?}", self, rhs)
+                        panic!("Impossible addition between {self:?} and {rhs:?}")
                     }
                 },
                 TimeUnit::Weeks => match rhs.units() {
@@ -334,7 +335,7 @@ impl AddAssign<Period> for Period {
                         self.length = self.length * 7 + rhs.length();
                     }
                     TimeUnit::Years | TimeUnit::Months => {
-                        panic!("Impossible addition between {:?} and {:?}", self, rhs)
+                        panic!("Impossible addition between {self:?} and {rhs:?}")
                     }
                 },
                 TimeUnit::Days => match rhs.units() {
@@ -344,7 +345,7 @@ impl AddAssign<Period> for Period {
                         self.length += 7 * rhs.length();
                     }
                     TimeUnit::Years | TimeUnit::Months => {
-                        panic!("Impossible addition between {:?} and {:?}", self, rhs)
+                        panic!("Impossible addition between {self:?} and {rhs:?}")
                     }
                 },
             }
@@ -368,7 +369,7 @@ impl Neg for Period {
 }
 impl SubAssign<Period> for Period {
     // No Output, no new Date returned. SAME Period modified!
-    fn sub_assign(&mut self, rhs: Period) -> () {
+    fn sub_assign(&mut self, rhs: Period) {
         *self += -rhs
     }
 }
@@ -407,7 +408,7 @@ impl DivAssign<i32> for Period {
             }
 
             if new_length % divider != 0 {
-                panic!("{:?} cannot be divided by {}", self, divider);
+                panic!("{self:?} cannot be divided by {divider}");
             }
 
             self.length = new_length / divider;
@@ -426,7 +427,7 @@ impl Div<i32> for Period {
 }
 impl MulAssign<i32> for Period {
     // No Output, no new Date returned. SAME Period modified!
-    fn mul_assign(&mut self, multiplier: i32) -> () {
+    fn mul_assign(&mut self, multiplier: i32) {
         // Scale the length
         self.length *= multiplier
     }
@@ -479,7 +480,7 @@ mod detail {
                     }
                 }
             };
-            write!(f, "{} {}", length, long_length)
+            write!(f, "{length} {long_length}")
         }
     }
     impl<'a> Display for ShortPeriod<'a> {
@@ -491,7 +492,7 @@ mod detail {
                 TimeUnit::Months => "M",
                 TimeUnit::Years => "Y",
             };
-            write!(f, "{}{}", length, short_length)
+            write!(f, "{length}{short_length}")
         }
     }
 }
@@ -567,8 +568,7 @@ mod tests {
             assert_eq!(
                 (p.length(), p.units()),
                 (len, unit),
-                "Mismatch for {:?}",
-                freq
+                "Mismatch for {freq:?}"
             );
         }
     }
@@ -603,7 +603,7 @@ mod tests {
         ];
 
         for (p, expected_freq) in cases {
-            assert_eq!(p.frequency(), expected_freq, "Mismatch for {:?}", p);
+            assert_eq!(p.frequency(), expected_freq, "Mismatch for {p:?}");
         }
     }
 
@@ -626,7 +626,7 @@ mod tests {
         ];
 
         for (p, expected_freq) in cases {
-            assert_eq!(p.frequency(), expected_freq, "Mismatch for {:?}", p);
+            assert_eq!(p.frequency(), expected_freq, "Mismatch for {p:?}");
         }
     }
 
@@ -643,8 +643,7 @@ mod tests {
             assert_eq!(
                 p.frequency(),
                 expected_freq,
-                "Mismatch for zero-length {:?}",
-                p
+                "Mismatch for zero-length {p:?}"
             );
         }
     }
@@ -663,8 +662,7 @@ mod tests {
             assert_eq!(
                 p.frequency(),
                 Frequency::OtherFrequency,
-                "Expected OtherFrequency for {:?}",
-                p
+                "Expected OtherFrequency for {p:?}"
             );
         }
     }
@@ -930,11 +928,7 @@ mod tests {
             let result: f64 = p.years();
             assert!(
                 (result - expected).abs() < 1e-12,
-                "years(): failed for {:?} {:?}, got {}, expected {}",
-                len,
-                unit,
-                result,
-                expected
+                "years(): failed for {len:?} {unit:?}, got {result}, expected {expected}"
             );
         }
     }
@@ -948,9 +942,7 @@ mod tests {
             let result = panic::catch_unwind(|| p.years());
             assert!(
                 result.is_err(),
-                "years(): expected panic for {:?} {:?}, but got Ok",
-                len,
-                unit
+                "years(): expected panic for {len:?} {unit:?}, but got Ok"
             );
         }
     }
@@ -968,11 +960,7 @@ mod tests {
             let result = p.months();
             assert!(
                 (result - expected).abs() < 1e-12,
-                "months(): failed for {:?} {:?}, got {}, expected {}",
-                len,
-                unit,
-                result,
-                expected
+                "months(): failed for {len:?} {unit:?}, got {result}, expected {expected}"
             );
         }
     }
@@ -986,9 +974,7 @@ mod tests {
             let result = panic::catch_unwind(|| p.months());
             assert!(
                 result.is_err(),
-                "months(): expected panic for {:?} {:?}, but got Ok",
-                len,
-                unit
+                "months(): expected panic for {len:?} {unit:?}, but got Ok"
             );
         }
     }
@@ -1006,11 +992,7 @@ mod tests {
             let result = p.weeks();
             assert!(
                 (result - expected).abs() < 1e-12,
-                "weeks(): failed for {:?} {:?}, got {}, expected {}",
-                len,
-                unit,
-                result,
-                expected
+                "weeks(): failed for {len:?} {unit:?}, got {result}, expected {expected}"
             );
         }
     }
@@ -1024,9 +1006,7 @@ mod tests {
             let result = panic::catch_unwind(|| p.weeks());
             assert!(
                 result.is_err(),
-                "weeks(): expected panic for {:?} {:?}, but got Ok",
-                len,
-                unit
+                "weeks(): expected panic for {len:?} {unit:?}, but got Ok"
             );
         }
     }
@@ -1044,11 +1024,7 @@ mod tests {
             let result: f64 = p.days();
             assert!(
                 (result - expected).abs() < 1e-12,
-                "days(): failed for {:?} {:?}, got {}, expected {}",
-                len,
-                unit,
-                result,
-                expected
+                "days(): failed for {len:?} {unit:?}, got {result}, expected {expected}"
             );
         }
     }
@@ -1062,9 +1038,7 @@ mod tests {
             let result = panic::catch_unwind(|| p.days());
             assert!(
                 result.is_err(),
-                "days(): expected panic for {:?} {:?}, but got Ok",
-                len,
-                unit
+                "days(): expected panic for {len:?} {unit:?}, but got Ok"
             );
         }
     }
@@ -1183,9 +1157,7 @@ mod tests {
             });
             assert!(
                 result.is_err(),
-                "add_assign PANIC expected but got Ok: lhs={:?}, rhs={:?}",
-                lhs,
-                rhs
+                "add_assign PANIC expected but got Ok: lhs={lhs:?}, rhs={rhs:?}"
             );
         }
     }
@@ -1404,9 +1376,7 @@ mod tests {
             });
             assert!(
                 result.is_err(),
-                "div_assign PANIC expected but got Ok: lhs={:?}, divider={}",
-                lhs,
-                divider
+                "div_assign PANIC expected but got Ok: lhs={lhs:?}, divider={divider}"
             );
         }
     }
@@ -1437,6 +1407,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::type_complexity)]
     fn partial_cmp_all_cases() {
         // Result<Ordering, ()>
         // Ok(Ordering) -> the comparison succeeded with a definite result.
@@ -1639,8 +1610,7 @@ mod tests {
 
             assert_eq!(
                 mapped, expected,
-                "Failed case: {} (left={:?}, right={:?})",
-                description, left, right
+                "Failed case: {description} (left={left:?}, right={right:?})"
             );
         }
     }
@@ -1662,9 +1632,7 @@ mod tests {
             assert_eq!(
                 (min_days, max_days),
                 (expected_min, expected_max),
-                "Failed case: {} (period={:?})",
-                description,
-                period
+                "Failed case: {description} (period={period:?})"
             );
         }
     }
@@ -1685,7 +1653,7 @@ mod tests {
         for ((len, unit), expected) in cases {
             let p = Period::new(len, unit);
             let result = format!("{}", io::long_period(&p));
-            assert_eq!(result, expected, "Failed case: {} {:?}", expected, p);
+            assert_eq!(result, expected, "Failed case: {expected} {p:?}");
         }
     }
 
@@ -1701,7 +1669,7 @@ mod tests {
         for ((len, unit), expected) in cases {
             let p: Period = Period::new(len, unit);
             let result: String = format!("{}", io::short_period(&p));
-            assert_eq!(result, expected, "Failed case: {} {:?}", expected, p);
+            assert_eq!(result, expected, "Failed case: {expected} {p:?}");
         }
     }
 
@@ -1716,8 +1684,8 @@ mod tests {
 
         for ((len, unit), expected) in cases {
             let p: Period = Period::new(len, unit);
-            let result: String = format!("{}", p);
-            assert_eq!(result, expected, "Failed case: {} {:?}", expected, p);
+            let result: String = format!("{p}");
+            assert_eq!(result, expected, "Failed case: {expected} {p:?}");
         }
     }
 }
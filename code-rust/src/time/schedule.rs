@@ -0,0 +1,231 @@
+use crate::time::business_day_convention::BusinessDayConvention;
+use crate::time::calendar::Calendar;
+use crate::time::calendars::null_calendar::NullCalendar;
+use crate::time::date::Date;
+use crate::time::period::Period;
+use std::ops::Index;
+
+// Direction in which schedule dates are generated (QuantLib DateGeneration::Rule)
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum DateGenerationRule {
+    // From the termination date backwards: a short first period if needed
+    Backward,
+    // From the effective date forwards: a short final period if needed
+    Forward,
+}
+
+/*
+Schedule of dates every tenor between an effective and a termination
+date (QuantLib Schedule), used for coupon periods, fixing dates and
+time grids.
+
+Unadjusted dates are obtained by moving the effective (or termination)
+date by k * tenor, not by repeatedly adding the tenor, so month ends do
+not drift; with end_of_month, dates generated from a date on the last
+business day of its month stay on month ends. Every date is then
+rolled to a business day of the calendar with the convention, and
+dates that coincide after the adjustment are removed.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct Schedule {
+    tenor: Period,
+    convention: BusinessDayConvention,
+    rule: DateGenerationRule,
+    dates: Vec<Date>,
+}
+impl Schedule {
+    // Constructors
+    pub fn new(
+        effective_date: Date,
+        termination_date: Date,
+        tenor: Period,
+        calendar: &dyn Calendar,
+        convention: BusinessDayConvention,
+        rule: DateGenerationRule,
+        end_of_month: bool,
+    ) -> Schedule {
+        if termination_date <= effective_date {
+            panic!(
+                "termination date ({termination_date}) must be after the effective date ({effective_date})"
+            );
+        }
+        if tenor.length() <= 0 {
+            panic!("non positive tenor ({tenor}) not allowed");
+        }
+        let shifted = |date: Date, k: i32| -> Date {
+            let moved: Date = Date::advance(date, k * tenor.length(), tenor.units());
+            if end_of_month && calendar.is_end_of_month(date) {
+                moved.end_of_month()
+            } else {
+                moved
+            }
+        };
+        let mut unadjusted: Vec<Date> = Vec::new();
+        match rule {
+            DateGenerationRule::Forward => {
+                let mut k: i32 = 0;
+                loop {
+                    let date: Date = shifted(effective_date, k);
+                    if date >= termination_date {
+                        break;
+                    }
+                    unadjusted.push(date);
+                    k += 1;
+                }
+                unadjusted.push(termination_date);
+            }
+            DateGenerationRule::Backward => {
+                let mut k: i32 = 0;
+                loop {
+                    let date: Date = shifted(termination_date, -k);
+                    if date <= effective_date {
+                        break;
+                    }
+                    unadjusted.push(date);
+                    k += 1;
+                }
+                unadjusted.push(effective_date);
+                unadjusted.reverse();
+            }
+        }
+        let mut dates: Vec<Date> = unadjusted
+            .iter()
+            .map(|date| calendar.adjust(*date, convention))
+            .collect();
+        dates.dedup();
+        Schedule {
+            tenor,
+            convention,
+            rule,
+            dates,
+        }
+    }
+    // Forward schedule of unadjusted dates, every day being a business day
+    pub fn unadjusted(effective_date: Date, termination_date: Date, tenor: Period) -> Schedule {
+        Schedule::new(
+            effective_date,
+            termination_date,
+            tenor,
+            &NullCalendar::new(),
+            BusinessDayConvention::Unadjusted,
+            DateGenerationRule::Forward,
+            false,
+        )
+    }
+
+    // Inspectors
+    pub fn tenor(&self) -> Period {
+        self.tenor
+    }
+    pub fn convention(&self) -> BusinessDayConvention {
+        self.convention
+    }
+    pub fn rule(&self) -> DateGenerationRule {
+        self.rule
+    }
+    pub fn dates(&self) -> &[Date] {
+        &self.dates
+    }
+    pub fn len(&self) -> usize {
+        self.dates.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.dates.is_empty()
+    }
+    pub fn start_date(&self) -> Date {
+        self.dates[0]
+    }
+    pub fn end_date(&self) -> Date {
+        self.dates[self.dates.len() - 1]
+    }
+}
+
+// Traits
+impl Index<usize> for Schedule {
+    type Output = Date;
+    fn index(&self, i: usize) -> &Date {
+        &self.dates[i]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::calendars::target::Target;
+    use crate::time::date::Month;
+    use crate::time::time_unit::TimeUnit;
+
+    #[test]
+    fn stubs_follow_the_generation_rule() {
+        let start: Date = Date::new(15, Month::January, 2025);
+        let end: Date = Date::new(15, Month::October, 2026);
+        let cases: [(DateGenerationRule, [Date; 5]); 2] = [
+            (
+                DateGenerationRule::Forward,
+                [
+                    start,
+                    Date::new(15, Month::July, 2025),
+                    Date::new(15, Month::January, 2026),
+                    Date::new(15, Month::July, 2026),
+                    end,
+                ],
+            ),
+            (
+                DateGenerationRule::Backward,
+                [
+                    start,
+                    Date::new(15, Month::April, 2025),
+                    Date::new(15, Month::October, 2025),
+                    Date::new(15, Month::April, 2026),
+                    end,
+                ],
+            ),
+        ];
+        for (rule, expected) in cases {
+            let schedule: Schedule = Schedule::new(
+                start,
+                end,
+                Period::new(6, TimeUnit::Months),
+                &NullCalendar::new(),
+                BusinessDayConvention::Unadjusted,
+                rule,
+                false,
+            );
+            assert_eq!(schedule.dates(), &expected, "{rule:?}");
+        }
+    }
+
+    #[test]
+    fn dates_are_rolled_to_business_days() {
+        // 31 May 2025 is a Saturday, 30 August 2025 a Saturday
+        let schedule: Schedule = Schedule::new(
+            Date::new(28, Month::February, 2025),
+            Date::new(31, Month::August, 2025),
+            Period::new(3, TimeUnit::Months),
+            &Target::new(),
+            BusinessDayConvention::ModifiedFollowing,
+            DateGenerationRule::Backward,
+            true,
+        );
+        assert_eq!(
+            schedule.dates(),
+            &[
+                Date::new(28, Month::February, 2025),
+                Date::new(30, Month::May, 2025),
+                Date::new(29, Month::August, 2025),
+            ]
+        );
+        let result = std::panic::catch_unwind(|| {
+            Schedule::new(
+                Date::new(28, Month::February, 2025),
+                Date::new(28, Month::February, 2025),
+                Period::new(3, TimeUnit::Months),
+                &NullCalendar::new(),
+                BusinessDayConvention::Unadjusted,
+                DateGenerationRule::Forward,
+                false,
+            )
+        });
+        assert!(result.is_err(), "expected panic for an empty schedule");
+    }
+}
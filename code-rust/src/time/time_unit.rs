@@ -27,7 +27,7 @@ impl Display for TimeUnit {
             //TimeUnit::Milliseconds => "Milliseconds",
             //TimeUnit::Microseconds => "Microseconds",
         };
-        write!(f, "{}", time_unit)
+        write!(f, "{time_unit}")
     }
 }
 
@@ -46,7 +46,7 @@ mod tests {
 
         for (time_unit, expected) in cases {
             assert_eq!(
-                format!("{}", time_unit),
+                format!("{time_unit}"),
                 expected,
                 "Failed for time unit {:?}",
                 time_unit
@@ -23,9 +23,9 @@ impl Weekday {
             unsafe --> "the contract necessary to call the operations inside the block has been
             checked by the programmer and is guaranteed to be respected"
             */
-            unsafe { std::mem::transmute(number as u8) }
+            unsafe { std::mem::transmute::<u8, Weekday>(number as u8) }
         } else {
-            panic!("Weekday index {} out of range [1,7]", number);
+            panic!("Weekday index {number} out of range [1,7]");
         }
     }
 }
@@ -77,7 +77,7 @@ mod detail {
                 Weekday::Saturday => "Saturday",
                 Weekday::Sunday => "Sunday",
             };
-            write!(f, "{}", long_weekday)
+            write!(f, "{long_weekday}")
         }
     }
     impl<'a> Display for ShortWeekday<'a> {
@@ -91,7 +91,7 @@ mod detail {
                 Weekday::Saturday => "Sat",
                 Weekday::Sunday => "Sun",
             };
-            write!(f, "{}", short_weekday)
+            write!(f, "{short_weekday}")
         }
     }
     impl<'a> Display for ShortestWeekday<'a> {
@@ -105,7 +105,7 @@ mod detail {
                 Weekday::Saturday => "Sa",
                 Weekday::Sunday => "Su",
             };
-            write!(f, "{}", shortest_weekday)
+            write!(f, "{shortest_weekday}")
         }
     }
 }
@@ -212,7 +212,7 @@ mod tests {
         ];
 
         for (weekday, expected) in cases {
-            assert_eq!(format!("{}", weekday), expected, "Failed for {:?}", weekday);
+            assert_eq!(format!("{weekday}"), expected, "Failed for {:?}", weekday);
         }
     }
 
@@ -241,11 +241,7 @@ mod tests {
                 Weekday::from_index(num);
             });
 
-            assert!(
-                result.is_err(),
-                "Expected panic for input {} but got Ok",
-                num
-            );
+            assert!(result.is_err(), "Expected panic for input {num} but got Ok");
         }
     }
 
@@ -275,9 +271,7 @@ mod tests {
 
             assert!(
                 result.is_err(),
-                "Expected panic for {:?} + {} but got Ok",
-                weekday,
-                addend
+                "Expected panic for {weekday:?} + {addend} but got Ok"
             );
         }
     }
@@ -296,9 +290,7 @@ mod tests {
 
             assert!(
                 result.is_err(),
-                "Expected panic for {:?} - {} but got Ok",
-                weekday,
-                subtrahend
+                "Expected panic for {weekday:?} - {subtrahend} but got Ok"
             );
         }
     }
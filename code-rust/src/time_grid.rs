@@ -0,0 +1,334 @@
+use crate::math::comparison::close_enough;
+use crate::time::date::Date;
+use crate::time::day_counter::DayCounter;
+use crate::time::period::Period;
+use crate::time::schedule::Schedule;
+use crate::types::{Size, Time};
+use std::ops::Index;
+
+/*
+Time grid (QuantLib TimeGrid).
+
+A sorted list of times starting at 0, used to discretize Monte Carlo
+paths and lattices:
+    - new(end, steps): regularly spaced times 0, dt, 2 dt, ..., end
+    - from_mandatory_times(times, steps): the grid goes through every
+      mandatory time (e.g. exercise or fixing times) and is regularly
+      refined in between, with a step close to last / steps.
+      With steps = 0 the smallest gap between mandatory times is used.
+    - from_dates / from_period: same, with mandatory dates converted to
+      times by a day counter, relative to a reference (evaluation) date
+
+index_of only accepts times that are on the grid (e.g. exercise times),
+closest_index and closest_time accept any time.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct TimeGrid {
+    times: Vec<Time>,
+    dt: Vec<Time>,
+    mandatory_times: Vec<Time>,
+}
+impl TimeGrid {
+    // Constructor
+    pub fn new(end: Time, steps: Size) -> TimeGrid {
+        if end <= 0.0 {
+            panic!("negative or null end time ({end}) not allowed");
+        }
+        if steps == 0 {
+            panic!("at least one step is required");
+        }
+        let dt: Time = end / steps as Time;
+        let times: Vec<Time> = (0..=steps).map(|i| dt * i as Time).collect();
+        TimeGrid::from_times(times, vec![end])
+    }
+    pub fn from_mandatory_times(times: &[Time], steps: Size) -> TimeGrid {
+        if times.is_empty() {
+            panic!("empty time sequence");
+        }
+        let mut mandatory_times: Vec<Time> = times.to_vec();
+        mandatory_times.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        if mandatory_times[0] < 0.0 {
+            panic!("negative times not allowed");
+        }
+        mandatory_times.dedup_by(|a, b| close_enough(*a, *b));
+
+        let last: Time = *mandatory_times.last().unwrap();
+        let dt_max: Time = if steps == 0 {
+            let mut previous: Time = 0.0;
+            let mut smallest: Time = Time::MAX;
+            for t in mandatory_times.iter().filter(|t| **t != 0.0) {
+                smallest = smallest.min(t - previous);
+                previous = *t;
+            }
+            smallest
+        } else {
+            last / steps as Time
+        };
+
+        // Regular inner points between consecutive mandatory times
+        let mut grid: Vec<Time> = vec![0.0];
+        let mut period_begin: Time = 0.0;
+        for period_end in mandatory_times.iter().copied() {
+            if period_end != 0.0 {
+                let n_steps: Size = (((period_end - period_begin) / dt_max).round() as Size).max(1);
+                let dt: Time = (period_end - period_begin) / n_steps as Time;
+                for n in 1..=n_steps {
+                    grid.push(period_begin + n as Time * dt);
+                }
+            }
+            period_begin = period_end;
+        }
+        TimeGrid::from_times(grid, mandatory_times)
+    }
+    pub fn from_dates(
+        reference_date: Date,
+        dates: &[Date],
+        day_counter: &dyn DayCounter,
+        steps: Size,
+    ) -> TimeGrid {
+        let times: Vec<Time> = dates
+            .iter()
+            .map(|d| day_counter.year_fraction(reference_date, *d))
+            .collect();
+        TimeGrid::from_mandatory_times(&times, steps)
+    }
+    /*
+    Mandatory dates every tenor from the reference date, up to and
+    including end_date, e.g. monthly fixings with tenor = 1M: the
+    unadjusted schedule between the two dates, without its first date.
+    */
+    pub fn from_period(
+        reference_date: Date,
+        end_date: Date,
+        tenor: Period,
+        day_counter: &dyn DayCounter,
+        steps: Size,
+    ) -> TimeGrid {
+        if end_date <= reference_date {
+            panic!("end date ({end_date}) must be after the reference date ({reference_date})");
+        }
+        let schedule: Schedule = Schedule::unadjusted(reference_date, end_date, tenor);
+        let dates: &[Date] = &schedule.dates()[1..];
+        TimeGrid::from_dates(reference_date, dates, day_counter, steps)
+    }
+
+    // Index of a time on the grid
+    pub fn index_of(&self, t: Time) -> Size {
+        let i: Size = self.closest_index(t);
+        if close_enough(t, self.times[i]) {
+            return i;
+        }
+        if t < self.front() {
+            panic!(
+                "using inadequate time grid: all nodes are later than the required time t = {} (earliest node is t1 = {})",
+                t,
+                self.front()
+            );
+        }
+        if t > self.back() {
+            panic!(
+                "using inadequate time grid: all nodes are earlier than the required time t = {} (latest node is t1 = {})",
+                t,
+                self.back()
+            );
+        }
+        let (j, k): (Size, Size) = if t > self.times[i] {
+            (i, i + 1)
+        } else {
+            (i - 1, i)
+        };
+        panic!(
+            "using inadequate time grid: the nodes closest to the required time t = {} are t1 = {} and t2 = {}",
+            t, self.times[j], self.times[k]
+        );
+    }
+    // Index of the grid time closest to t (the earlier one on ties)
+    pub fn closest_index(&self, t: Time) -> Size {
+        let upper: Size = self.times.partition_point(|x| *x < t);
+        if upper == 0 {
+            return 0;
+        }
+        if upper == self.times.len() {
+            return self.times.len() - 1;
+        }
+        let dt1: Time = self.times[upper] - t;
+        let dt2: Time = t - self.times[upper - 1];
+        if dt1 < dt2 { upper } else { upper - 1 }
+    }
+    pub fn closest_time(&self, t: Time) -> Time {
+        self.times[self.closest_index(t)]
+    }
+
+    // Inspectors
+    pub fn times(&self) -> &[Time] {
+        &self.times
+    }
+    pub fn mandatory_times(&self) -> &[Time] {
+        &self.mandatory_times
+    }
+    // Length of the i-th step, i.e. times[i+1] - times[i]
+    pub fn dt(&self, i: Size) -> Time {
+        self.dt[i]
+    }
+    pub fn len(&self) -> Size {
+        self.times.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.times.is_empty()
+    }
+    pub fn front(&self) -> Time {
+        self.times[0]
+    }
+    pub fn back(&self) -> Time {
+        self.times[self.times.len() - 1]
+    }
+
+    fn from_times(times: Vec<Time>, mandatory_times: Vec<Time>) -> TimeGrid {
+        let dt: Vec<Time> = times.windows(2).map(|w| w[1] - w[0]).collect();
+        TimeGrid {
+            times,
+            dt,
+            mandatory_times,
+        }
+    }
+}
+impl Index<Size> for TimeGrid {
+    type Output = Time;
+
+    fn index(&self, i: Size) -> &Time {
+        &self.times[i]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::date::Month;
+    use crate::time::daycounters::actual365_fixed::Actual365Fixed;
+    use crate::time::time_unit::TimeUnit;
+    use std::panic;
+
+    #[test]
+    fn regular_grid() {
+        let grid: TimeGrid = TimeGrid::new(2.0, 4);
+
+        assert_eq!(grid.times(), &[0.0, 0.5, 1.0, 1.5, 2.0]);
+        assert_eq!(grid.mandatory_times(), &[2.0]);
+        assert_eq!(grid.len(), 5);
+        assert_eq!(grid.front(), 0.0);
+        assert_eq!(grid.back(), 2.0);
+        assert_eq!(grid[3], 1.5);
+        for i in 0..4 {
+            assert_eq!(grid.dt(i), 0.5);
+        }
+    }
+
+    #[test]
+    fn grid_goes_through_mandatory_times() {
+        let cases: [(&[Time], Size, &[Time]); 4] = [
+            // Steps of ~0.25 between sorted, deduplicated mandatory times
+            (&[1.0, 0.5, 0.5], 4, &[0.0, 0.25, 0.5, 0.75, 1.0]),
+            // Uneven periods get the nearest integer number of steps
+            (
+                &[0.3, 1.0],
+                4,
+                &[0.0, 0.3, 0.3 + 0.7 / 3.0, 0.3 + 1.4 / 3.0, 1.0],
+            ),
+            // No steps: the smallest gap drives the spacing
+            (&[0.0, 0.25, 1.0], 0, &[0.0, 0.25, 0.5, 0.75, 1.0]),
+            // Periods shorter than the step still get one step
+            (&[0.1, 1.0], 1, &[0.0, 0.1, 1.0]),
+        ];
+
+        for (mandatory, steps, expected) in cases {
+            let grid: TimeGrid = TimeGrid::from_mandatory_times(mandatory, steps);
+            assert_eq!(grid.len(), expected.len(), "{mandatory:?}");
+            for (t, e) in grid.times().iter().zip(expected.iter()) {
+                assert!((t - e).abs() < 1.0e-15, "{mandatory:?}: {t} vs {e}");
+            }
+            for i in 0..grid.len() - 1 {
+                assert!((grid.dt(i) - (grid[i + 1] - grid[i])).abs() < 1.0e-15);
+            }
+        }
+    }
+
+    #[test]
+    fn index_queries() {
+        let grid: TimeGrid = TimeGrid::from_mandatory_times(&[0.3, 1.0], 4);
+        let cases: [(Time, Size); 6] =
+            [(-1.0, 0), (0.1, 0), (0.2, 1), (0.3, 1), (0.95, 4), (2.0, 4)];
+
+        for (t, expected) in cases {
+            assert_eq!(grid.closest_index(t), expected, "closest index of {t}");
+            assert_eq!(grid.closest_time(t), grid[expected]);
+        }
+        assert_eq!(grid.index_of(0.3), 1);
+        assert_eq!(grid.index_of(1.0), 4);
+        assert_eq!(grid.index_of(0.1 + 0.2), 1);
+        for t in [-0.1, 0.2, 1.5] {
+            let result = panic::catch_unwind(|| grid.index_of(t));
+            assert!(result.is_err(), "expected panic for index_of({t})");
+        }
+    }
+
+    #[test]
+    fn grid_from_dates() {
+        let reference_date: Date = Date::new(15, Month::January, 2024);
+        let dates: [Date; 2] = [
+            Date::new(15, Month::January, 2025),
+            Date::new(15, Month::July, 2024),
+        ];
+        let grid: TimeGrid = TimeGrid::from_dates(reference_date, &dates, &Actual365Fixed, 0);
+
+        assert_eq!(grid.mandatory_times(), &[182.0 / 365.0, 366.0 / 365.0]);
+        assert_eq!(grid.index_of(182.0 / 365.0), 1);
+        assert_eq!(grid.back(), 366.0 / 365.0);
+    }
+
+    #[test]
+    fn grid_from_period() {
+        // Monthly dates from the end of January stay on month ends
+        let reference_date: Date = Date::new(31, Month::January, 2024);
+        let end_date: Date = Date::new(15, Month::May, 2024);
+        let grid: TimeGrid = TimeGrid::from_period(
+            reference_date,
+            end_date,
+            Period::new(1, TimeUnit::Months),
+            &Actual365Fixed,
+            0,
+        );
+
+        let expected: Vec<Time> = [
+            Date::new(29, Month::February, 2024),
+            Date::new(31, Month::March, 2024),
+            Date::new(30, Month::April, 2024),
+            end_date,
+        ]
+        .iter()
+        .map(|d| (*d - reference_date) as Time / 365.0)
+        .collect();
+        assert_eq!(grid.mandatory_times(), expected.as_slice());
+        for t in expected {
+            grid.index_of(t);
+        }
+
+        let result = panic::catch_unwind(|| {
+            TimeGrid::from_period(
+                end_date,
+                reference_date,
+                Period::new(1, TimeUnit::Months),
+                &Actual365Fixed,
+                0,
+            )
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn invalid_grids_panic() {
+        assert!(panic::catch_unwind(|| TimeGrid::new(0.0, 4)).is_err());
+        assert!(panic::catch_unwind(|| TimeGrid::new(1.0, 0)).is_err());
+        assert!(panic::catch_unwind(|| TimeGrid::from_mandatory_times(&[], 4)).is_err());
+        assert!(panic::catch_unwind(|| TimeGrid::from_mandatory_times(&[-1.0, 1.0], 4)).is_err());
+    }
+}
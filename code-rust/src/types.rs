@@ -1 +1,15 @@
+// Custom types, same names as QuantLib (ql/types.hpp).
+// They are just aliases: a Rate is still an f64, but the signature tells
+// the reader what the number means.
 
+pub type Integer = i32;
+pub type Natural = u32;
+pub type Size = usize;
+
+pub type Real = f64;
+pub type Time = f64; // continuous quantity with 1-year units
+pub type DiscountFactor = f64;
+pub type Rate = f64;
+pub type Spread = f64;
+pub type Volatility = f64;
+pub type Probability = f64;
@@ -19,7 +19,7 @@ mod detail {
                     _ => "th",
                 }
             };
-            write!(f, "{}{}", number, suffix)
+            write!(f, "{number}{suffix}")
         }
     }
 }
@@ -29,6 +29,6 @@ pub(crate) mod io {
     use super::detail;
 
     pub fn ordinal(number: usize) -> impl std::fmt::Display {
-        detail::Ordinal { number: number }
+        detail::Ordinal { number }
     }
 }
@@ -47,8 +47,7 @@ fn add_then_subtract_returns_original_date_correctly() {
 
         assert_eq!(
             round_trip, start,
-            "Round-trip failed: {} | start={:?}, delta={}",
-            label, start, delta
+            "Round-trip failed: {label} | start={start:?}, delta={delta}"
         );
     }
 }
@@ -120,8 +119,7 @@ fn date_and_period_month_ends() {
         let result: Date = start + period;
         assert_eq!(
             result, expected,
-            "Failed case: {} | start={:?} + period={:?}",
-            label, start, period
+            "Failed case: {label} | start={start:?} + period={period:?}"
         );
     }
 }
@@ -196,11 +194,7 @@ fn period_normalization_and_date_advance_consistency() {
         assert_eq!(
             (result_a, result_b),
             (expected, expected),
-            "Failed case: {} | start={:?}, a={:?}, b={:?}",
-            label,
-            start,
-            period_days_weeks,
-            period_equivalent
+            "Failed case: {label} | start={start:?}, a={period_days_weeks:?}, b={period_equivalent:?}"
         );
     }
 }